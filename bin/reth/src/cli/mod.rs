@@ -9,9 +9,9 @@ use clap::{value_parser, Parser, Subcommand};
 use reth_chainspec::ChainSpec;
 use reth_cli::chainspec::ChainSpecParser;
 use reth_cli_commands::{
-    config_cmd, db, dump_genesis, import, init_cmd, init_state,
+    config_cmd, db, dump_genesis, export_era, import, import_era, init_cmd, init_state,
     node::{self, NoArgs},
-    p2p, prune, recover, stage,
+    p2p, prune, recover, stage, static_file,
 };
 use reth_cli_runner::CliRunner;
 use reth_db::DatabaseEnv;
@@ -168,10 +168,19 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>, Ext: clap::Args + fmt::Debug> Cl
             Commands::Import(command) => {
                 runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode, _, _>(components))
             }
+            Commands::ImportEra(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode, _, _>(components))
+            }
+            Commands::ExportEra(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode>())
+            }
             Commands::DumpGenesis(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::Db(command) => {
                 runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode>())
             }
+            Commands::StaticFile(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode>())
+            }
             Commands::Stage(command) => runner.run_command_until_exit(|ctx| {
                 command.execute::<EthereumNode, _, _, EthNetworkPrimitives>(ctx, components)
             }),
@@ -216,6 +225,12 @@ pub enum Commands<C: ChainSpecParser, Ext: clap::Args + fmt::Debug> {
     /// This syncs RLP encoded blocks from a file.
     #[command(name = "import")]
     Import(import::ImportCommand<C>),
+    /// This imports blocks from ERA1 files.
+    #[command(name = "import-era")]
+    ImportEra(import_era::ImportEraCommand<C>),
+    /// Exports blocks to ERA1 files.
+    #[command(name = "export-era")]
+    ExportEra(export_era::ExportEraCommand<C>),
     /// Dumps genesis block JSON configuration to stdout.
     DumpGenesis(dump_genesis::DumpGenesisCommand<C>),
     /// Database debugging utilities
@@ -224,6 +239,9 @@ pub enum Commands<C: ChainSpecParser, Ext: clap::Args + fmt::Debug> {
     /// Manipulate individual stages.
     #[command(name = "stage")]
     Stage(stage::Command<C>),
+    /// Static file utilities
+    #[command(name = "static-file")]
+    StaticFile(static_file::Command<C>),
     /// P2P Debugging utilities
     #[command(name = "p2p")]
     P2P(p2p::Command<C>),
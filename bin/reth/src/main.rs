@@ -6,6 +6,7 @@ static ALLOC: reth_cli_util::allocator::Allocator = reth_cli_util::allocator::ne
 use clap::Parser;
 use reth::cli::Cli;
 use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
+use reth_exex_address_index::address_index_exex;
 use reth_node_ethereum::EthereumNode;
 use tracing::info;
 
@@ -19,7 +20,14 @@ fn main() {
 
     if let Err(err) = Cli::<EthereumChainSpecParser>::parse().run(|builder, _| async move {
         info!(target: "reth::cli", "Launching node");
-        let handle = builder.launch_node(EthereumNode::default()).await?;
+        let address_index = builder.config().rpc.rpc_address_index;
+        let handle = builder
+            .node(EthereumNode::default())
+            .install_exex_if(address_index, "address-index", |ctx| async move {
+                Ok(address_index_exex(ctx))
+            })
+            .launch()
+            .await?;
         handle.node_exit_future.await
     }) {
         eprintln!("Error: {err:?}");
@@ -0,0 +1,196 @@
+use clap::Parser;
+use human_bytes::human_bytes;
+use reth_db::{
+    mdbx::DatabaseArguments, open_db, open_db_read_only, version::create_db_version_file,
+    DatabaseEnv,
+};
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+use tracing::info;
+
+/// Name of the MDBX data file in a database directory.
+const DATA_FILE_NAME: &str = "mdbx.dat";
+
+/// Name of the MDBX lock file in a database directory.
+const LOCK_FILE_NAME: &str = "mdbx.lck";
+
+/// Interval at which the progress of the copy is logged.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The arguments for the `reth db compact` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The directory to write the compacted database to, instead of replacing the database.
+    ///
+    /// The database isn't locked while it's copied to another directory, so this can be used
+    /// while the node is running. Without it, the node has to be stopped, since the compacted
+    /// copy replaces the database once it's complete.
+    #[arg(long, value_name = "DIR", verbatim_doc_comment)]
+    output: Option<PathBuf>,
+}
+
+impl Command {
+    /// Execute `db compact` command
+    pub fn execute(self, db_path: &Path, args: DatabaseArguments) -> eyre::Result<()> {
+        if let Some(output) = &self.output {
+            eyre::ensure!(
+                !output.join(DATA_FILE_NAME).exists(),
+                "Database already exists at {}",
+                output.display()
+            );
+            reth_fs_util::create_dir_all(output)?;
+
+            let db = open_db_read_only(db_path, args)?;
+            compact(&db, output)?;
+            create_db_version_file(output)?;
+
+            info!(target: "reth::cli", output=%output.display(), "Database compacted");
+            return Ok(())
+        }
+
+        // the compacted copy is written next to the database, so that it's on the same file system
+        // and can be renamed into place atomically
+        let compact_path = db_path.with_extension("compact");
+        if compact_path.exists() {
+            info!(target: "reth::cli", path=%compact_path.display(), "Removing partial copy");
+            reth_fs_util::remove_dir_all(&compact_path)?;
+        }
+        reth_fs_util::create_dir_all(&compact_path)?;
+
+        // the database is opened in exclusive mode, which fails with `MDBX_BUSY` if it's used by
+        // another process, so that nothing is written to it that would be missing from the copy
+        let db = open_db(db_path, args.with_exclusive(Some(true)))?;
+        compact(&db, &compact_path)?;
+        drop(db);
+
+        reth_fs_util::rename(compact_path.join(DATA_FILE_NAME), db_path.join(DATA_FILE_NAME))?;
+        // the lock file describes the replaced data file, so it's moved out of the way to be
+        // recreated for the compacted one on the next open
+        let lock_file = db_path.join(LOCK_FILE_NAME);
+        if lock_file.exists() {
+            reth_fs_util::rename(lock_file, compact_path.join(LOCK_FILE_NAME))?;
+        }
+        reth_fs_util::remove_dir_all(&compact_path)?;
+
+        info!(target: "reth::cli", path=%db_path.display(), "Database compacted");
+
+        Ok(())
+    }
+}
+
+/// Copies the database with compaction into the given directory, logging the progress of the copy.
+fn compact(db: &DatabaseEnv, dir: &Path) -> eyre::Result<()> {
+    let info = db.info()?;
+    let page_size = db.stat()?.page_size() as u64;
+    let size = info.geometry().current();
+    let used_pages = (info.last_pgno() + 1).saturating_sub(db.freelist()?) as u64;
+    let expected_size = used_pages * page_size;
+    info!(
+        target: "reth::cli",
+        size = %human_bytes(size as f64),
+        expected_size = %human_bytes(expected_size as f64),
+        "Compacting database"
+    );
+
+    let dest = &dir.join(DATA_FILE_NAME);
+    thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel();
+        scope.spawn(move || {
+            let _ = tx.send(db.copy(dest, true));
+        });
+
+        loop {
+            match rx.recv_timeout(PROGRESS_INTERVAL) {
+                Ok(result) => return Ok(result?),
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    let copied = reth_fs_util::metadata(dest).map(|m| m.len()).unwrap_or_default();
+                    let progress = copied as f64 / expected_size.max(1) as f64 * 100.0;
+                    info!(
+                        target: "reth::cli",
+                        copied = %human_bytes(copied as f64),
+                        progress = %format!("{:.2}%", progress.min(100.0)),
+                        "Compacting database"
+                    );
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    eyre::bail!("database copy exited unexpectedly")
+                }
+            }
+        }
+    })?;
+
+    let compacted_size = reth_fs_util::metadata(dest)?.len();
+    info!(
+        target: "reth::cli",
+        size = %human_bytes(size as f64),
+        compacted_size = %human_bytes(compacted_size as f64),
+        reclaimed = %human_bytes(size.saturating_sub(compacted_size) as f64),
+        "Copied database"
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use reth_db::{init_db, tables, ClientVersion};
+    use reth_db_api::{
+        cursor::DbCursorRO,
+        database::Database,
+        transaction::{DbTx, DbTxMut},
+    };
+
+    fn args() -> DatabaseArguments {
+        DatabaseArguments::new(ClientVersion::default())
+    }
+
+    #[test]
+    fn compact_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("db");
+
+        // fill the database and delete most of it again, so that the copy has pages to reclaim
+        let db = init_db(&db_path, args()).unwrap();
+        let tx = db.tx_mut().unwrap();
+        for number in 0..10_000u64 {
+            tx.put::<tables::CanonicalHeaders>(number, B256::with_last_byte(number as u8)).unwrap();
+        }
+        tx.commit().unwrap();
+        let tx = db.tx_mut().unwrap();
+        for number in 100..10_000u64 {
+            tx.delete::<tables::CanonicalHeaders>(number, None).unwrap();
+        }
+        tx.commit().unwrap();
+        drop(db);
+        let size = reth_fs_util::metadata(db_path.join(DATA_FILE_NAME)).unwrap().len();
+
+        Command { output: None }.execute(&db_path, args()).unwrap();
+
+        assert!(!db_path.with_extension("compact").exists());
+        let compacted_size = reth_fs_util::metadata(db_path.join(DATA_FILE_NAME)).unwrap().len();
+        assert!(compacted_size < size);
+
+        // the compacted database can be opened and contains the remaining entries
+        let db = open_db(&db_path, args()).unwrap();
+        let tx = db.tx().unwrap();
+        let entries = tx
+            .cursor_read::<tables::CanonicalHeaders>()
+            .unwrap()
+            .walk(None)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            entries,
+            (0..100u64)
+                .map(|number| (number, B256::with_last_byte(number as u8)))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,451 @@
+use crate::common::CliNodeTypes;
+use alloy_consensus::BlockHeader;
+use alloy_genesis::GenesisAccount;
+use alloy_primitives::{Address, BlockNumber, B256, U256};
+use clap::Parser;
+use reth_chainspec::EthereumHardforks;
+use reth_db::{tables, DatabaseEnv};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbDupCursorRO},
+    models::{AccountBeforeTx, BlockNumberAddress},
+    transaction::DbTx,
+};
+use reth_db_common::{
+    init::{GenesisAccountWithAddress, StateRoot},
+    DbTool,
+};
+use reth_node_builder::NodeTypesWithDBAdapter;
+use reth_primitives::{Account, StorageEntry};
+use reth_provider::{
+    providers::ProviderNodeTypes, BlockNumReader, DBProvider, HeaderProvider, ProviderError,
+    ProviderFactory, PruneCheckpointReader, StageCheckpointReader,
+};
+use reth_prune::PruneSegment;
+use reth_stages::StageId;
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+};
+use tracing::{info, warn};
+
+/// Number of chunks the state is split into, by the first byte of the account address.
+const CHUNKS: usize = 256;
+
+#[derive(Parser, Debug)]
+/// The arguments for the `reth db dump-state` command
+pub struct Command {
+    /// The block to dump the state at.
+    ///
+    /// Defaults to the latest block.
+    #[arg(long, value_name = "BLOCK_NUMBER", verbatim_doc_comment)]
+    block: Option<BlockNumber>,
+
+    /// The file to write the state dump to, in the format accepted by `reth init-state`.
+    ///
+    /// The state is dumped in chunks to a `<FILE>.parts-<BLOCK_NUMBER>` directory first, which
+    /// are combined once all of them are written. Chunks that were already written by an
+    /// interrupted run are reused.
+    #[arg(long, short, value_name = "FILE", verbatim_doc_comment)]
+    output: PathBuf,
+
+    /// Number of threads that dump chunks of the state in parallel.
+    ///
+    /// Defaults to the number of available CPUs.
+    #[arg(long, value_name = "JOBS", verbatim_doc_comment)]
+    jobs: Option<usize>,
+}
+
+impl Command {
+    /// Execute `db dump-state` command
+    pub fn execute<N: CliNodeTypes<ChainSpec: EthereumHardforks>>(
+        self,
+        tool: &DbTool<NodeTypesWithDBAdapter<N, Arc<DatabaseEnv>>>,
+    ) -> eyre::Result<()> {
+        warn!("This command should be run without the node running!");
+        self.dump(&tool.provider_factory)
+    }
+
+    /// Dumps the state of the given provider factory.
+    fn dump<N: ProviderNodeTypes>(self, provider_factory: &ProviderFactory<N>) -> eyre::Result<()> {
+        let provider = provider_factory.provider()?;
+        let best_block = provider.best_block_number()?;
+        let block = self.block.unwrap_or(best_block);
+        if block > best_block {
+            eyre::bail!("cannot dump state at block {block}, latest block is {best_block}");
+        }
+        let state_root = provider
+            .header_by_number(block)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(block.into()))?
+            .state_root();
+
+        // the plain state is at the execution checkpoint, all accounts and storage slots that
+        // changed since the requested block are read from the changesets instead
+        let executed =
+            provider.get_stage_checkpoint(StageId::Execution)?.unwrap_or_default().block_number;
+        if block < executed {
+            for segment in [PruneSegment::AccountHistory, PruneSegment::StorageHistory] {
+                // the checkpoint is the highest block whose changesets were pruned
+                if let Some(pruned) = provider
+                    .get_prune_checkpoint(segment)?
+                    .and_then(|checkpoint| checkpoint.block_number)
+                    .filter(|pruned| block < *pruned)
+                {
+                    eyre::bail!(
+                        "cannot dump state at block {block}, {segment} changesets are pruned up \
+                         to block {pruned}"
+                    );
+                }
+            }
+        }
+        drop(provider);
+
+        let parts_dir = PathBuf::from(format!("{}.parts-{block}", self.output.display()));
+        reth_fs_util::create_dir_all(&parts_dir)?;
+
+        let jobs = self
+            .jobs
+            .or_else(|| thread::available_parallelism().ok().map(|jobs| jobs.get()))
+            .unwrap_or(1)
+            .clamp(1, CHUNKS);
+        info!(target: "reth::cli", block, %state_root, jobs, "Dumping state");
+
+        let dumper = StateDumper { provider_factory, block, executed, parts_dir: &parts_dir };
+        let next_chunk = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            let workers = (0..jobs)
+                .map(|_| {
+                    scope.spawn(|| -> eyre::Result<()> {
+                        loop {
+                            let chunk = next_chunk.fetch_add(1, Ordering::Relaxed);
+                            if chunk >= CHUNKS {
+                                return Ok(())
+                            }
+                            dumper.dump_chunk(chunk as u8)?;
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            workers.into_iter().try_for_each(|worker| worker.join().expect("worker panicked"))
+        })?;
+
+        // combine the chunks
+        let mut writer = BufWriter::new(reth_fs_util::create_file(&self.output)?);
+        serde_json::to_writer(&mut writer, &StateRoot { root: state_root })?;
+        writer.write_all(b"\n")?;
+        for chunk in 0..CHUNKS {
+            io::copy(&mut reth_fs_util::open(chunk_path(&parts_dir, chunk as u8))?, &mut writer)?;
+        }
+        writer.flush()?;
+        reth_fs_util::remove_dir_all(&parts_dir)?;
+
+        info!(target: "reth::cli", output=%self.output.display(), "State dumped");
+
+        Ok(())
+    }
+}
+
+/// Returns the path of the file of the given chunk.
+fn chunk_path(parts_dir: &Path, chunk: u8) -> PathBuf {
+    parts_dir.join(format!("{chunk:03}.jsonl"))
+}
+
+/// Dumps the state at a block, chunk by chunk.
+struct StateDumper<'a, N: ProviderNodeTypes> {
+    provider_factory: &'a ProviderFactory<N>,
+    /// The block to dump the state at.
+    block: BlockNumber,
+    /// The block the plain state is at.
+    executed: BlockNumber,
+    /// Directory of the chunk files.
+    parts_dir: &'a Path,
+}
+
+impl<N: ProviderNodeTypes> StateDumper<'_, N> {
+    /// Dumps the accounts whose address starts with the given byte, unless they were already
+    /// dumped.
+    fn dump_chunk(&self, chunk: u8) -> eyre::Result<()> {
+        let path = chunk_path(self.parts_dir, chunk);
+        if path.exists() {
+            info!(target: "reth::cli", chunk, "Reusing dumped state chunk");
+            return Ok(())
+        }
+
+        let mut accounts = 0;
+        reth_fs_util::atomic_write_file(&path, |file| {
+            accounts = self.write_chunk(BufWriter::new(file), chunk)?;
+            Ok::<_, Box<dyn Error + Send + Sync>>(())
+        })?;
+
+        info!(target: "reth::cli", chunk, accounts, "Dumped state chunk");
+
+        Ok(())
+    }
+
+    /// Writes the accounts whose address starts with the given byte, returns the number of
+    /// written accounts.
+    fn write_chunk(
+        &self,
+        mut writer: impl Write,
+        chunk: u8,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let provider = self.provider_factory.provider()?.disable_long_read_transaction_safety();
+        let tx = provider.tx_ref();
+        let mut storage_cursor = tx.cursor_dup_read::<tables::PlainStorageState>()?;
+
+        let mut start = Address::ZERO;
+        start[0] = chunk;
+        let mut end = Address::repeat_byte(0xff);
+        end[0] = chunk;
+
+        // the first changeset entry after the block holds the value at the block
+        let mut changed_accounts = BTreeMap::<Address, Option<Account>>::new();
+        let mut changed_storages = BTreeMap::<Address, BTreeMap<B256, U256>>::new();
+        let mut account_changeset_cursor = tx.cursor_dup_read::<tables::AccountChangeSets>()?;
+        let mut storage_changeset_cursor = tx.cursor_dup_read::<tables::StorageChangeSets>()?;
+        for block in self.block + 1..=self.executed {
+            let mut entry = account_changeset_cursor.seek_by_key_subkey(block, start)?;
+            while let Some(AccountBeforeTx { address, info }) = entry {
+                if address > end {
+                    break
+                }
+                changed_accounts.entry(address).or_insert(info);
+                entry = account_changeset_cursor.next_dup_val()?;
+            }
+
+            for entry in storage_changeset_cursor
+                .walk_range(BlockNumberAddress((block, start))..=BlockNumberAddress((block, end)))?
+            {
+                let (BlockNumberAddress((_, address)), StorageEntry { key, value }) = entry?;
+                changed_storages.entry(address).or_default().entry(key).or_insert(value);
+            }
+        }
+
+        let mut accounts = 0;
+        let mut write_account = |address: Address,
+                                 account: Option<Account>|
+         -> Result<(), Box<dyn Error + Send + Sync>> {
+            let Some(account) = changed_accounts.get(&address).copied().unwrap_or(account) else {
+                return Ok(())
+            };
+
+            let changed_slots = changed_storages.get(&address);
+            let mut storage = BTreeMap::new();
+            for entry in storage_cursor.walk_dup(Some(address), None)? {
+                let (_, StorageEntry { key, value }) = entry?;
+                if !value.is_zero() && !changed_slots.is_some_and(|slots| slots.contains_key(&key))
+                {
+                    storage.insert(key, B256::from(value.to_be_bytes()));
+                }
+            }
+            for (key, value) in changed_slots.into_iter().flatten() {
+                if !value.is_zero() {
+                    storage.insert(*key, B256::from(value.to_be_bytes()));
+                }
+            }
+
+            let code = account
+                .bytecode_hash
+                .map(|hash| tx.get::<tables::Bytecodes>(hash))
+                .transpose()?
+                .flatten()
+                .map(|bytecode| bytecode.original_bytes());
+
+            let account = GenesisAccountWithAddress {
+                genesis_account: GenesisAccount {
+                    balance: account.balance,
+                    nonce: Some(account.nonce),
+                    code,
+                    storage: (!storage.is_empty()).then_some(storage),
+                    ..Default::default()
+                },
+                address,
+            };
+            serde_json::to_writer(&mut writer, &account)?;
+            writer.write_all(b"\n")?;
+            accounts += 1;
+            Ok(())
+        };
+
+        // merge the accounts of the plain state with the accounts that changed after the block,
+        // which may not exist anymore
+        let mut changed = changed_accounts.keys().peekable();
+        for entry in tx.cursor_read::<tables::PlainAccountState>()?.walk_range(start..=end)? {
+            let (address, account) = entry?;
+            while let Some(changed_address) = changed.next_if(|changed| **changed < address) {
+                write_account(*changed_address, None)?;
+            }
+            changed.next_if_eq(&&address);
+            write_account(address, Some(account))?;
+        }
+        for changed_address in changed {
+            write_account(*changed_address, None)?;
+        }
+        drop(write_account);
+
+        writer.flush()?;
+        Ok(accounts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_genesis::Genesis;
+    use reth_chainspec::{Chain, ChainSpec};
+    use reth_db_api::transaction::DbTxMut;
+    use reth_db_common::init::{init_from_state_dump, init_genesis};
+    use reth_provider::{
+        test_utils::create_test_provider_factory_with_chain_spec, PruneCheckpointWriter,
+        StageCheckpointWriter,
+    };
+    use reth_prune::{PruneCheckpoint, PruneMode};
+    use reth_stages::StageCheckpoint;
+    use std::io::BufReader;
+
+    #[test]
+    fn dump_historical_state() {
+        let address_with_balance = Address::with_last_byte(1);
+        let address_with_storage = Address::with_last_byte(2);
+        let created_address = Address::with_last_byte(3);
+        let storage_key = B256::with_last_byte(1);
+        let created_storage_key = B256::with_last_byte(2);
+        let chain_spec = Arc::new(ChainSpec {
+            chain: Chain::from_id(1),
+            genesis: Genesis {
+                alloc: BTreeMap::from([
+                    (
+                        address_with_balance,
+                        GenesisAccount { balance: U256::from(1), ..Default::default() },
+                    ),
+                    (
+                        address_with_storage,
+                        GenesisAccount {
+                            storage: Some(BTreeMap::from([(storage_key, B256::with_last_byte(1))])),
+                            ..Default::default()
+                        },
+                    ),
+                ]),
+                ..Default::default()
+            },
+            hardforks: Default::default(),
+            paris_block_and_final_difficulty: None,
+            deposit_contract: None,
+            ..Default::default()
+        });
+
+        let factory = create_test_provider_factory_with_chain_spec(chain_spec.clone());
+        let genesis_hash = init_genesis(&factory).unwrap();
+
+        // change the plain state at block 1, with the previous values in the changesets
+        let provider_rw = factory.provider_rw().unwrap();
+        let tx = provider_rw.tx_ref();
+        let old_account = tx.get::<tables::PlainAccountState>(address_with_balance).unwrap();
+        tx.put::<tables::PlainAccountState>(
+            address_with_balance,
+            Account { balance: U256::from(2), ..Default::default() },
+        )
+        .unwrap();
+        tx.put::<tables::PlainAccountState>(created_address, Account::default()).unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            1,
+            AccountBeforeTx { address: address_with_balance, info: old_account },
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            1,
+            AccountBeforeTx { address: created_address, info: None },
+        )
+        .unwrap();
+
+        let old_entry = StorageEntry { key: storage_key, value: U256::from(1) };
+        tx.delete::<tables::PlainStorageState>(address_with_storage, Some(old_entry)).unwrap();
+        tx.put::<tables::PlainStorageState>(
+            address_with_storage,
+            StorageEntry { key: storage_key, value: U256::from(2) },
+        )
+        .unwrap();
+        tx.put::<tables::PlainStorageState>(
+            address_with_storage,
+            StorageEntry { key: created_storage_key, value: U256::from(1) },
+        )
+        .unwrap();
+        tx.put::<tables::StorageChangeSets>(
+            BlockNumberAddress((1, address_with_storage)),
+            old_entry,
+        )
+        .unwrap();
+        tx.put::<tables::StorageChangeSets>(
+            BlockNumberAddress((1, address_with_storage)),
+            StorageEntry { key: created_storage_key, value: U256::ZERO },
+        )
+        .unwrap();
+        provider_rw.save_stage_checkpoint(StageId::Execution, StageCheckpoint::new(1)).unwrap();
+        provider_rw.commit().unwrap();
+
+        // dump the state at genesis
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("state.jsonl");
+        Command { block: Some(0), output: output.clone(), jobs: Some(2) }.dump(&factory).unwrap();
+
+        // the state at genesis can't be dumped once the changesets of block 1 are pruned
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw
+            .save_prune_checkpoint(
+                PruneSegment::StorageHistory,
+                PruneCheckpoint {
+                    block_number: Some(1),
+                    tx_number: None,
+                    prune_mode: PruneMode::Full,
+                },
+            )
+            .unwrap();
+        provider_rw.commit().unwrap();
+        let pruned_output = dir.path().join("pruned.jsonl");
+        let err = Command { block: Some(0), output: pruned_output.clone(), jobs: Some(2) }
+            .dump(&factory)
+            .unwrap_err();
+        assert!(err.to_string().contains("changesets are pruned up to block 1"), "{err}");
+        assert!(!pruned_output.exists());
+
+        // initialize a database without state from the dump, the state root is checked against
+        // the genesis header
+        let factory = create_test_provider_factory_with_chain_spec(chain_spec);
+        init_genesis(&factory).unwrap();
+        let provider_rw = factory.provider_rw().unwrap();
+        let tx = provider_rw.tx_ref();
+        tx.clear::<tables::PlainAccountState>().unwrap();
+        tx.clear::<tables::PlainStorageState>().unwrap();
+        tx.clear::<tables::HashedAccounts>().unwrap();
+        tx.clear::<tables::HashedStorages>().unwrap();
+        tx.clear::<tables::AccountsTrie>().unwrap();
+        tx.clear::<tables::StoragesTrie>().unwrap();
+        let reader = BufReader::new(reth_fs_util::open(&output).unwrap());
+        assert_eq!(
+            init_from_state_dump(reader, &provider_rw, Default::default()).unwrap(),
+            genesis_hash
+        );
+
+        let tx = provider_rw.tx_ref();
+        assert_eq!(
+            tx.get::<tables::PlainAccountState>(address_with_balance).unwrap(),
+            Some(Account { balance: U256::from(1), ..Default::default() })
+        );
+        assert_eq!(tx.get::<tables::PlainAccountState>(created_address).unwrap(), None);
+        assert_eq!(
+            tx.cursor_dup_read::<tables::PlainStorageState>()
+                .unwrap()
+                .walk_dup(Some(address_with_storage), None)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![(address_with_storage, old_entry)]
+        );
+    }
+}
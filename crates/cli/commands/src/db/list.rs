@@ -1,4 +1,5 @@
-use super::tui::DbListTUI;
+use super::tui::{DbListTUI, EntryReader, Source};
+use crate::common::CliNodeTypes;
 use alloy_primitives::hex;
 use clap::Parser;
 use eyre::WrapErr;
@@ -6,8 +7,8 @@ use reth_chainspec::EthereumHardforks;
 use reth_db::{DatabaseEnv, RawValue, TableViewer, Tables};
 use reth_db_api::{database::Database, table::Table};
 use reth_db_common::{DbTool, ListFilter};
-use reth_node_builder::{NodeTypesWithDBAdapter, NodeTypesWithEngine};
-use std::{cell::RefCell, sync::Arc};
+use reth_node_builder::NodeTypesWithDBAdapter;
+use std::sync::Arc;
 use tracing::error;
 
 #[derive(Parser, Debug)]
@@ -53,7 +54,7 @@ pub struct Command {
 
 impl Command {
     /// Execute `db list` command
-    pub fn execute<N: NodeTypesWithEngine<ChainSpec: EthereumHardforks>>(
+    pub fn execute<N: CliNodeTypes<ChainSpec: EthereumHardforks>>(
         self,
         tool: &DbTool<NodeTypesWithDBAdapter<N, Arc<DatabaseEnv>>>,
     ) -> eyre::Result<()> {
@@ -86,12 +87,12 @@ impl Command {
     }
 }
 
-struct ListTableViewer<'a, N: NodeTypesWithEngine> {
+struct ListTableViewer<'a, N: CliNodeTypes> {
     tool: &'a DbTool<NodeTypesWithDBAdapter<N, Arc<DatabaseEnv>>>,
     args: &'a Command,
 }
 
-impl<N: NodeTypesWithEngine> TableViewer<()> for ListTableViewer<'_, N> {
+impl<N: CliNodeTypes> TableViewer<()> for ListTableViewer<'_, N> {
     type Error = eyre::Report;
 
     fn view<T: Table>(&self) -> Result<(), Self::Error> {
@@ -127,11 +128,8 @@ impl<N: NodeTypesWithEngine> TableViewer<()> for ListTableViewer<'_, N> {
                 }
                 Ok(())
             } else {
-                let list_filter = RefCell::new(list_filter);
-                DbListTUI::<_, T>::new(|skip, len| {
-                    list_filter.borrow_mut().update_page(skip, len);
-                    self.tool.list::<T>(&list_filter.borrow()).unwrap().0
-                }, self.args.skip, self.args.len, total_entries, self.args.raw).run()
+                let reader = EntryReader::new(self.tool, Some((self.args.table, list_filter)), self.args.raw);
+                DbListTUI::new(reader, Source::Mdbx(self.args.table), self.args.skip, self.args.len)?.run()
             }
         })??;
 
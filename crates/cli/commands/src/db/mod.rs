@@ -8,7 +8,9 @@ use std::io::{self, Write};
 
 mod checksum;
 mod clear;
+mod compact;
 mod diff;
+mod dump_state;
 mod get;
 mod list;
 mod stats;
@@ -38,6 +40,8 @@ pub enum Subcommands {
     Diff(diff::Command),
     /// Gets the content of a table for the given key
     Get(get::Command),
+    /// Dumps the state at a block in the format accepted by `reth init-state`
+    DumpState(dump_state::Command),
     /// Deletes all database entries
     Drop {
         /// Bypasses the interactive confirmation and drops the database directly
@@ -46,6 +50,8 @@ pub enum Subcommands {
     },
     /// Deletes all table entries
     Clear(clear::Command),
+    /// Compacts the database by copying it without its free pages
+    Compact(compact::Command),
     /// Lists current and local database versions
     Version,
     /// Returns the full database path
@@ -107,6 +113,11 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                     command.execute(&tool)?;
                 });
             }
+            Subcommands::DumpState(command) => {
+                db_ro_exec!(self.env, tool, N, {
+                    command.execute(&tool)?;
+                });
+            }
             Subcommands::Drop { force } => {
                 if !force {
                     // Ask for confirmation
@@ -131,6 +142,9 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                 let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RW)?;
                 command.execute(provider_factory)?;
             }
+            Subcommands::Compact(command) => {
+                command.execute(&db_path, self.env.db.database_args())?;
+            }
             Subcommands::Version => {
                 let local_db_version = match get_db_version(&db_path) {
                     Ok(version) => Some(version),
@@ -173,4 +187,30 @@ mod tests {
         .unwrap();
         assert_eq!(cmd.env.datadir.resolve_datadir(cmd.env.chain.chain).as_ref(), Path::new(&path));
     }
+
+    #[test]
+    fn parse_dump_state() {
+        let cmd = Command::<EthereumChainSpecParser>::try_parse_from([
+            "reth",
+            "dump-state",
+            "--block",
+            "1",
+            "-o",
+            "state.jsonl",
+        ])
+        .unwrap();
+        assert!(matches!(cmd.command, Subcommands::DumpState(_)));
+    }
+
+    #[test]
+    fn parse_compact() {
+        let cmd = Command::<EthereumChainSpecParser>::try_parse_from([
+            "reth",
+            "compact",
+            "--output",
+            "compacted",
+        ])
+        .unwrap();
+        assert!(matches!(cmd.command, Subcommands::Compact(_)));
+    }
 }
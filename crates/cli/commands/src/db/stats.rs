@@ -34,6 +34,16 @@ pub struct Command {
     /// For individual table checksums, use the `reth db checksum` command.
     #[arg(long, default_value_t = false)]
     checksum: bool,
+
+    /// Show the unused space within the pages of each table in the database.
+    ///
+    /// This space can only be reused by writes to the same table, unlike the free pages which are
+    /// reclaimed by `reth db compact`.
+    ///
+    /// WARNING: this option will take a long time to run, as it needs to traverse all pages of the
+    /// database.
+    #[arg(long, default_value_t = false)]
+    page_usage: bool,
 }
 
 impl Command {
@@ -66,19 +76,21 @@ impl Command {
     ) -> eyre::Result<ComfyTable> {
         let mut table = ComfyTable::new();
         table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
-        table.set_header([
-            "Table Name",
-            "# Entries",
-            "Branch Pages",
-            "Leaf Pages",
-            "Overflow Pages",
-            "Total Size",
-        ]);
+        let mut header =
+            vec!["Table Name", "# Entries", "Branch Pages", "Leaf Pages", "Overflow Pages"];
+        if self.page_usage {
+            header.push("Unused Size");
+        }
+        header.push("Total Size");
+        table.set_header(header);
 
         tool.provider_factory.db_ref().view(|tx| {
+            let page_usage = self.page_usage.then(|| tx.inner.page_usage()).transpose()?;
+
             let mut db_tables = Tables::ALL.iter().map(|table| table.name()).collect::<Vec<_>>();
             db_tables.sort();
             let mut total_size = 0;
+            let mut total_unused_size = 0;
             for db_table in db_tables {
                 let table_db = tx.inner.open_db(Some(db_table)).wrap_err("Could not open db.")?;
 
@@ -103,8 +115,16 @@ impl Command {
                     .add_cell(Cell::new(stats.entries()))
                     .add_cell(Cell::new(branch_pages))
                     .add_cell(Cell::new(leaf_pages))
-                    .add_cell(Cell::new(overflow_pages))
-                    .add_cell(Cell::new(human_bytes(table_size as f64)));
+                    .add_cell(Cell::new(overflow_pages));
+                if let Some(page_usage) = &page_usage {
+                    let unused_size = page_usage
+                        .get(db_table)
+                        .map(|usage| usage.unused_bytes)
+                        .unwrap_or_default();
+                    total_unused_size += unused_size;
+                    row.add_cell(Cell::new(human_bytes(unused_size as f64)));
+                }
+                row.add_cell(Cell::new(human_bytes(table_size as f64)));
                 table.add_row(row);
             }
 
@@ -115,27 +135,43 @@ impl Command {
             }
             table.add_row(separator);
 
-            let mut row = Row::new();
-            row.add_cell(Cell::new("Tables"))
-                .add_cell(Cell::new(""))
-                .add_cell(Cell::new(""))
-                .add_cell(Cell::new(""))
-                .add_cell(Cell::new(""))
-                .add_cell(Cell::new(human_bytes(total_size as f64)));
-            table.add_row(row);
+            let summary_row = |name: &str, pages: Option<usize>, unused_size, size: usize| {
+                let mut row = Row::new();
+                row.add_cell(Cell::new(name))
+                    .add_cell(Cell::new(pages.map(|pages| pages.to_string()).unwrap_or_default()))
+                    .add_cell(Cell::new(""))
+                    .add_cell(Cell::new(""))
+                    .add_cell(Cell::new(""));
+                if self.page_usage {
+                    row.add_cell(Cell::new(unused_size));
+                }
+                row.add_cell(Cell::new(human_bytes(size as f64)));
+                row
+            };
+
+            table.add_row(summary_row(
+                "Tables",
+                None,
+                human_bytes(total_unused_size as f64),
+                total_size,
+            ));
 
             let freelist = tx.inner.env().freelist()?;
             let pagesize = tx.inner.db_stat(&mdbx::Database::freelist_db())?.page_size() as usize;
             let freelist_size = freelist * pagesize;
-
-            let mut row = Row::new();
-            row.add_cell(Cell::new("Freelist"))
-                .add_cell(Cell::new(freelist))
-                .add_cell(Cell::new(""))
-                .add_cell(Cell::new(""))
-                .add_cell(Cell::new(""))
-                .add_cell(Cell::new(human_bytes(freelist_size as f64)));
-            table.add_row(row);
+            table.add_row(summary_row("Freelist", Some(freelist), String::new(), freelist_size));
+
+            // Pages on the freelist and pages of the database file after the last used page are
+            // not copied by `reth db compact`
+            let info = tx.inner.env().info()?;
+            let file_pages = info.geometry().current() as usize / pagesize;
+            let reclaimable = (file_pages + freelist).saturating_sub(info.last_pgno() + 1);
+            table.add_row(summary_row(
+                "Reclaimable",
+                Some(reclaimable),
+                String::new(),
+                reclaimable * pagesize,
+            ));
 
             Ok::<(), eyre::Report>(())
         })??;
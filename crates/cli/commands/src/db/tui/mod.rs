@@ -0,0 +1,522 @@
+use alloy_primitives::hex;
+use crossterm::{
+    event::{self, Event, KeyCode, MouseEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::{Backend, CrosstermBackend},
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame, Terminal,
+};
+use reth_provider::providers::ProviderNodeTypes;
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+use tracing::error;
+
+mod reader;
+pub(crate) use reader::{EntryReader, Source};
+use reader::{ListEntry, PageStart};
+
+/// Available keybindings for the [`DbListTUI`]
+static CMDS: [(&str, &str); 11] = [
+    ("q", "Quit"),
+    ("↑", "Entry above"),
+    ("↓", "Entry below"),
+    ("←", "Previous page"),
+    ("→", "Next page"),
+    ("G", "Go to a specific page"),
+    ("/", "Seek to a key"),
+    ("o", "Open a table or static file segment"),
+    ("⏎", "Follow the first link"),
+    ("1-9", "Follow a link"),
+    ("⌫", "Go back"),
+];
+
+/// Modified version of the [`ListState`] struct that exposes the `offset` field.
+/// Used to make the [`DbListTUI`] keys clickable.
+struct ExpListState {
+    pub(crate) offset: usize,
+}
+
+#[derive(Default, Eq, PartialEq)]
+pub(crate) enum ViewMode {
+    /// Normal list view mode
+    #[default]
+    Normal,
+    /// Currently wanting to go to a page
+    GoToPage,
+    /// Currently wanting to seek to a key
+    SeekKey,
+    /// Currently wanting to open a table or static file segment
+    Open,
+}
+
+/// A page of entries of a table or static file segment.
+struct View {
+    /// The listed table or static file segment.
+    source: Source,
+    /// Where the page starts.
+    start: PageStart,
+    /// The total number of entries in the source
+    total_entries: usize,
+    /// The state of the key list.
+    list_state: ListState,
+    /// Entries to show in the TUI.
+    entries: Vec<ListEntry>,
+}
+
+pub(crate) struct DbListTUI<'a, N: ProviderNodeTypes> {
+    /// Reader of the entries of tables and static file segments.
+    reader: EntryReader<'a, N>,
+    /// The amount of entries to show per page
+    count: usize,
+    /// The current view mode
+    mode: ViewMode,
+    /// The current state of the input buffer
+    input: String,
+    /// The currently shown page.
+    view: View,
+    /// Pages that were shown before following links or opening other sources, most recent last.
+    history: Vec<View>,
+    /// Message shown in the footer until the next key press, e.g. an error.
+    status: Option<String>,
+}
+
+impl<'a, N: ProviderNodeTypes> DbListTUI<'a, N> {
+    /// Create a new database list TUI
+    pub(crate) fn new(
+        reader: EntryReader<'a, N>,
+        source: Source,
+        skip: usize,
+        count: usize,
+    ) -> eyre::Result<Self> {
+        let total_entries = reader.total_entries(source)?;
+        Ok(Self {
+            reader,
+            count,
+            mode: ViewMode::Normal,
+            input: String::new(),
+            view: View {
+                source,
+                start: PageStart::Index(skip),
+                total_entries,
+                list_state: ListState::default(),
+                entries: Vec::new(),
+            },
+            history: Vec::new(),
+            status: None,
+        })
+    }
+
+    /// Move to the next list selection
+    fn next(&mut self) {
+        if self.view.entries.is_empty() {
+            return
+        }
+        self.view.list_state.select(Some(
+            self.view
+                .list_state
+                .selected()
+                .map(|i| if i >= self.view.entries.len() - 1 { 0 } else { i + 1 })
+                .unwrap_or(0),
+        ));
+    }
+
+    /// Move to the previous list selection
+    fn previous(&mut self) {
+        if self.view.entries.is_empty() {
+            return
+        }
+        self.view.list_state.select(Some(
+            self.view
+                .list_state
+                .selected()
+                .map(|i| if i == 0 { self.view.entries.len() - 1 } else { i - 1 })
+                .unwrap_or(0),
+        ));
+    }
+
+    fn reset(&mut self) {
+        self.view.list_state.select(Some(0));
+    }
+
+    /// Fetch the next page of items
+    fn next_page(&mut self) {
+        self.view.start = match &self.view.start {
+            PageStart::Index(skip) if skip + self.count < self.view.total_entries => {
+                PageStart::Index(skip + self.count)
+            }
+            PageStart::Key(key, offset) if self.view.entries.len() == self.count => {
+                PageStart::Key(key.clone(), offset + self.count as isize)
+            }
+            _ => return,
+        };
+        self.fetch_page();
+    }
+
+    /// Fetch the previous page of items
+    fn previous_page(&mut self) {
+        self.view.start = match &self.view.start {
+            PageStart::Index(skip) if *skip > 0 => {
+                PageStart::Index(skip.saturating_sub(self.count))
+            }
+            PageStart::Key(key, offset) => {
+                PageStart::Key(key.clone(), offset - self.count as isize)
+            }
+            _ => return,
+        };
+        self.fetch_page();
+    }
+
+    /// Go to a specific page.
+    fn go_to_page(&mut self, page: usize) {
+        self.view.start = PageStart::Index(
+            (self.count * page).min(self.view.total_entries.saturating_sub(self.count)),
+        );
+        self.fetch_page();
+    }
+
+    /// Go to the page that starts with the first entry with a key greater than or equal to the
+    /// entered key.
+    fn seek(&mut self, input: &str) {
+        let page = self
+            .reader
+            .parse_key(self.view.source, input)
+            .and_then(|key| self.reader.start(self.view.source, &key))
+            .and_then(|start| self.reader.fetch(self.view.source, &start, self.count));
+        match page {
+            Ok((entries, _)) if entries.is_empty() => {
+                self.status = Some(format!("No entry with a key from {input}"))
+            }
+            Ok((entries, start)) => {
+                self.view.start = start;
+                self.view.entries = entries;
+                self.reset();
+            }
+            Err(err) => self.status = Some(format!("Invalid key {input}: {err}")),
+        }
+    }
+
+    /// Open a table or static file segment, at the entry with the given encoded key if any.
+    ///
+    /// The current page is kept, so it can be returned to with [`DbListTUI::back`].
+    fn open(&mut self, source: Source, key: Option<&[u8]>) {
+        let view = (|| {
+            let total_entries = self.reader.total_entries(source)?;
+            let start = key.map(|key| self.reader.start(source, key)).transpose()?;
+            Ok::<_, eyre::Report>(View {
+                source,
+                start: start.unwrap_or(PageStart::Index(0)),
+                total_entries,
+                list_state: ListState::default(),
+                entries: Vec::new(),
+            })
+        })();
+
+        match view {
+            Ok(view) => {
+                self.history.push(std::mem::replace(&mut self.view, view));
+                self.fetch_page();
+            }
+            Err(err) => self.status = Some(format!("Failed to open {source}: {err}")),
+        }
+    }
+
+    /// Follow the link with the given index of the selected entry.
+    fn follow(&mut self, index: usize) {
+        let link = self
+            .view
+            .list_state
+            .selected()
+            .and_then(|selected| self.view.entries.get(selected))
+            .and_then(|entry| entry.links.get(index))
+            .cloned();
+        match link {
+            Some(link) => self.open(link.source, Some(&link.key)),
+            None => self.status = Some(format!("No link [{}] for the selected entry", index + 1)),
+        }
+    }
+
+    /// Go back to the page shown before the last followed link or opened source.
+    fn back(&mut self) {
+        if let Some(view) = self.history.pop() {
+            self.view = view;
+        }
+    }
+
+    /// Fetch the current page
+    fn fetch_page(&mut self) {
+        match self.reader.fetch(self.view.source, &self.view.start, self.count) {
+            Ok((entries, start)) => {
+                self.view.entries = entries;
+                self.view.start = start;
+            }
+            Err(err) => {
+                self.view.entries.clear();
+                self.status = Some(format!("Failed to read {}: {err}", self.view.source));
+            }
+        }
+        self.reset();
+    }
+
+    /// Show the [`DbListTUI`] in the terminal.
+    pub(crate) fn run(mut self) -> eyre::Result<()> {
+        // Setup backend
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        // Load initial page
+        self.fetch_page();
+
+        // Run event loop
+        let tick_rate = Duration::from_millis(250);
+        let res = event_loop(&mut terminal, &mut self, tick_rate);
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        // Handle errors
+        if let Err(err) = res {
+            error!("{:?}", err)
+        }
+        Ok(())
+    }
+}
+
+/// Run the event loop
+fn event_loop<B: Backend, N: ProviderNodeTypes>(
+    terminal: &mut Terminal<B>,
+    app: &mut DbListTUI<'_, N>,
+    tick_rate: Duration,
+) -> io::Result<()> {
+    let mut last_tick = Instant::now();
+    let mut running = true;
+    while running {
+        // Render
+        terminal.draw(|f| ui(f, app))?;
+
+        // Calculate timeout
+        let timeout =
+            tick_rate.checked_sub(last_tick.elapsed()).unwrap_or_else(|| Duration::from_secs(0));
+
+        // Poll events
+        if crossterm::event::poll(timeout)? {
+            running = !handle_event(app, event::read()?)?;
+        }
+
+        if last_tick.elapsed() >= tick_rate {
+            last_tick = Instant::now();
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle incoming events
+fn handle_event<N: ProviderNodeTypes>(
+    app: &mut DbListTUI<'_, N>,
+    event: Event,
+) -> io::Result<bool> {
+    if app.mode != ViewMode::Normal {
+        if let Event::Key(key) = event {
+            match key.code {
+                KeyCode::Enter => {
+                    let input = std::mem::take(&mut app.input);
+                    match std::mem::take(&mut app.mode) {
+                        ViewMode::GoToPage => {
+                            if let Ok(page) = input.parse() {
+                                app.go_to_page(page);
+                            }
+                        }
+                        ViewMode::SeekKey => app.seek(input.trim()),
+                        ViewMode::Open => match input.trim().parse() {
+                            Ok(source) => app.open(source, None),
+                            Err(err) => app.status = Some(err),
+                        },
+                        ViewMode::Normal => {}
+                    }
+                }
+                KeyCode::Char(c) => {
+                    app.input.push(c);
+                }
+                KeyCode::Backspace => {
+                    app.input.pop();
+                }
+                KeyCode::Esc => {
+                    app.input.clear();
+                    app.mode = ViewMode::Normal;
+                }
+                _ => {}
+            }
+        }
+
+        return Ok(false)
+    }
+
+    match event {
+        Event::Key(key) => {
+            if key.kind == event::KeyEventKind::Press {
+                app.status = None;
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(true),
+                    KeyCode::Down => app.next(),
+                    KeyCode::Up => app.previous(),
+                    KeyCode::Right => app.next_page(),
+                    KeyCode::Left => app.previous_page(),
+                    KeyCode::Char('G') => {
+                        app.mode = ViewMode::GoToPage;
+                    }
+                    KeyCode::Char('/') => {
+                        app.mode = ViewMode::SeekKey;
+                    }
+                    KeyCode::Char('o') => {
+                        app.mode = ViewMode::Open;
+                    }
+                    KeyCode::Enter => app.follow(0),
+                    KeyCode::Char(c @ '1'..='9') => app.follow(c as usize - '1' as usize),
+                    KeyCode::Backspace => app.back(),
+                    _ => {}
+                }
+            }
+        }
+        Event::Mouse(e) => match e.kind {
+            MouseEventKind::ScrollDown => app.next(),
+            MouseEventKind::ScrollUp => app.previous(),
+            // TODO: This click event can be triggered outside of the list widget.
+            MouseEventKind::Down(_) => {
+                // SAFETY: The pointer to the app's state will always be valid for
+                // reads here, and the source is larger than the destination.
+                //
+                // This is technically unsafe, but because the alignment requirements
+                // in both the source and destination are the same and we can ensure
+                // that the pointer to `app.state` is valid for reads, this is safe.
+                let state: ExpListState = unsafe { std::mem::transmute_copy(&app.view.list_state) };
+                let new_idx = (e.row as usize + state.offset).saturating_sub(1);
+                if new_idx < app.view.entries.len() {
+                    app.view.list_state.select(Some(new_idx));
+                }
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+
+    Ok(false)
+}
+
+/// Render the UI
+fn ui<N: ProviderNodeTypes>(f: &mut Frame<'_>, app: &mut DbListTUI<'_, N>) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(95), Constraint::Percentage(5)].as_ref())
+        .split(f.area());
+
+    // Columns
+    {
+        let inner_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(outer_chunks[0]);
+
+        let view = &mut app.view;
+
+        // entries of pages of seeked keys are numbered by their offset from the key
+        let formatted_keys = view
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let position = match &view.start {
+                    PageStart::Index(skip) => format!(
+                        "{:0>width$}",
+                        i + skip,
+                        width = format!("{}", (skip + app.count).saturating_sub(1)).len()
+                    ),
+                    PageStart::Key(_, offset) => format!("{:+}", offset + i as isize),
+                };
+                ListItem::new(format!("[{position}]: {}", entry.key))
+            })
+            .collect::<Vec<_>>();
+
+        let shown = match &view.start {
+            PageStart::Index(skip) => format!(
+                "Showing entries {}-{}",
+                skip,
+                (skip + view.entries.len()).saturating_sub(1)
+            ),
+            PageStart::Key(key, offset) => format!(
+                "Showing {} entries at offset {offset:+} from key {}",
+                view.entries.len(),
+                hex::encode_prefixed(key)
+            ),
+        };
+        let key_list =
+            List::new(formatted_keys)
+                .block(Block::default().borders(Borders::ALL).title(format!(
+                    "{} ({shown} out of {} entries)",
+                    view.source, view.total_entries
+                )))
+                .style(Style::default().fg(Color::White))
+                .highlight_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::ITALIC))
+                .highlight_symbol("➜ ");
+        f.render_stateful_widget(key_list, inner_chunks[0], &mut view.list_state);
+
+        let value_display = Paragraph::new(
+            view.list_state
+                .selected()
+                .and_then(|selected| view.entries.get(selected))
+                .map(|entry| {
+                    let mut value = entry.value.clone();
+                    if !entry.links.is_empty() {
+                        value.push_str("\n\nLinks:");
+                        for (i, link) in entry.links.iter().enumerate() {
+                            value.push_str(&format!("\n[{}] {link}", i + 1));
+                        }
+                    }
+                    value
+                })
+                .unwrap_or_else(|| "No value selected".to_string()),
+        )
+        .block(Block::default().borders(Borders::ALL).title("Value (JSON)"))
+        .wrap(Wrap { trim: false })
+        .alignment(Alignment::Left);
+        f.render_widget(value_display, inner_chunks[1]);
+    }
+
+    // Footer
+    let footer = match app.mode {
+        ViewMode::Normal => Paragraph::new(app.status.clone().unwrap_or_else(|| {
+            CMDS.iter().map(|(k, v)| format!("[{k}] {v}")).collect::<Vec<_>>().join(" | ")
+        })),
+        ViewMode::GoToPage => Paragraph::new(format!(
+            "Go to page (max {}): {}",
+            app.view.total_entries / app.count,
+            app.input
+        )),
+        ViewMode::SeekKey => Paragraph::new(format!(
+            "Seek to key (JSON, or a 0x-prefixed prefix of the encoded key): {}",
+            app.input
+        )),
+        ViewMode::Open => Paragraph::new(format!(
+            "Open table (e.g. Headers) or static file segment (e.g. headers): {}",
+            app.input
+        )),
+    }
+    .block(Block::default().borders(Borders::ALL))
+    .alignment(match app.mode {
+        ViewMode::Normal => Alignment::Center,
+        _ => Alignment::Left,
+    })
+    .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+    f.render_widget(footer, outer_chunks[1]);
+}
@@ -0,0 +1,577 @@
+//! Reads the entries of database tables and static file segments for the [`DbListTUI`].
+//!
+//! [`DbListTUI`]: super::DbListTUI
+
+use crate::db::get::table_key;
+use alloy_consensus::Header;
+use alloy_primitives::{hex, keccak256, Address, BlockHash, BlockNumber, TxNumber};
+use reth_db::{
+    static_file::{
+        ColumnSelectorOne, ColumnSelectorTwo, HeaderWithHashMask, ReceiptMask, TransactionMask,
+    },
+    RawKey, RawTable, Receipts, TableViewer, Tables, Transactions,
+};
+use reth_db_api::{
+    cursor::DbCursorRO,
+    database::Database,
+    models::StoredBlockBodyIndices,
+    table::{Compress, Decode, Decompress, Encode, Table},
+    transaction::DbTx,
+    DatabaseError,
+};
+use reth_db_common::{DbTool, ListFilter};
+use reth_node_api::{ReceiptTy, TxTy};
+use reth_primitives::Account;
+use reth_primitives_traits::SignedTransaction;
+use reth_provider::{providers::ProviderNodeTypes, StaticFileProviderFactory};
+use reth_static_file_types::StaticFileSegment;
+use std::{cell::RefCell, fmt, str::FromStr};
+
+/// A database table or static file segment that can be listed in the [`DbListTUI`].
+///
+/// [`DbListTUI`]: super::DbListTUI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Source {
+    /// A database table.
+    Mdbx(Tables),
+    /// A static file segment.
+    StaticFile(StaticFileSegment),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mdbx(table) => write!(f, "{table}"),
+            Self::StaticFile(segment) => write!(f, "static file segment {}", segment.as_ref()),
+        }
+    }
+}
+
+impl FromStr for Source {
+    type Err = String;
+
+    /// Parses a table name, e.g. `Headers`, or a static file segment name, e.g. `headers`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .map(Self::Mdbx)
+            .or_else(|_| StaticFileSegment::from_str(s).map(Self::StaticFile))
+            .map_err(|_| format!("unknown table or static file segment: {s:?}"))
+    }
+}
+
+/// A reference from an entry to an entry of another table or static file segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Link {
+    /// The table or static file segment of the entry.
+    pub(crate) source: Source,
+    /// The encoded key of the entry. For static file segments, this is the block or transaction
+    /// number in big endian.
+    pub(crate) key: Vec<u8>,
+}
+
+impl Link {
+    /// Creates a link to the entry of a database table.
+    fn table<K: Encode>(table: Tables, key: K) -> Self {
+        Self { source: Source::Mdbx(table), key: key.encode().as_ref().to_vec() }
+    }
+
+    /// Creates a link to the row of a static file segment.
+    fn static_file(segment: StaticFileSegment, number: u64) -> Self {
+        Self { source: Source::StaticFile(segment), key: number.to_be_bytes().to_vec() }
+    }
+}
+
+impl fmt::Display for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.source {
+            Source::Mdbx(_) => write!(f, "{} {}", self.source, hex::encode_prefixed(&self.key)),
+            Source::StaticFile(_) => {
+                write!(f, "{} #{}", self.source, static_file_number(&self.key))
+            }
+        }
+    }
+}
+
+/// Where a page of entries starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PageStart {
+    /// At the entry with the given index.
+    Index(usize),
+    /// At the given offset from the first entry of a database table with a key that is greater
+    /// than or equal to the given encoded key.
+    ///
+    /// Finding the index of an entry by key requires walking the table up to it, so pages of
+    /// seeked keys are read with a cursor relative to the key instead.
+    Key(Vec<u8>, isize),
+}
+
+/// An entry of a table or static file segment, formatted for the [`DbListTUI`].
+///
+/// [`DbListTUI`]: super::DbListTUI
+#[derive(Debug)]
+pub(crate) struct ListEntry {
+    /// The formatted key.
+    pub(crate) key: String,
+    /// The value, rendered as JSON.
+    pub(crate) value: String,
+    /// Entries of other tables or static file segments that this entry refers to.
+    pub(crate) links: Vec<Link>,
+}
+
+/// Reads the entries of database tables and static file segments.
+pub(crate) struct EntryReader<'a, N: ProviderNodeTypes> {
+    tool: &'a DbTool<N>,
+    /// The table listed by `reth db list` and its filter, which only applies to that table.
+    filtered: Option<(Tables, RefCell<ListFilter>)>,
+    /// Whether to show the raw bytes of database values instead of decoding them.
+    raw: bool,
+}
+
+impl<'a, N: ProviderNodeTypes> EntryReader<'a, N> {
+    /// Creates a new reader, which applies the given filter to the entries of the given table.
+    pub(crate) fn new(
+        tool: &'a DbTool<N>,
+        filtered: Option<(Tables, ListFilter)>,
+        raw: bool,
+    ) -> Self {
+        Self { tool, filtered: filtered.map(|(table, filter)| (table, RefCell::new(filter))), raw }
+    }
+
+    /// Returns the number of entries of the source.
+    pub(crate) fn total_entries(&self, source: Source) -> eyre::Result<usize> {
+        match source {
+            Source::Mdbx(table) => table.view(&EntriesViewer { tool: self.tool }),
+            Source::StaticFile(segment) => {
+                let provider = self.tool.provider_factory.static_file_provider();
+                let highest = match segment {
+                    StaticFileSegment::Headers => provider.get_highest_static_file_block(segment),
+                    StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
+                        provider.get_highest_static_file_tx(segment)
+                    }
+                    StaticFileSegment::BlockMeta => {
+                        eyre::bail!("listing static file segment {segment} isn't supported")
+                    }
+                };
+                Ok(highest.map_or(0, |highest| highest as usize + 1))
+            }
+        }
+    }
+
+    /// Fetches `len` entries of the source, starting at the given entry.
+    ///
+    /// Returns the entries and where they start, which is [`PageStart::Index`] if a page before a
+    /// seeked key reached the first entry.
+    pub(crate) fn fetch(
+        &self,
+        source: Source,
+        start: &PageStart,
+        len: usize,
+    ) -> eyre::Result<(Vec<ListEntry>, PageStart)> {
+        match (source, start) {
+            (Source::Mdbx(table), PageStart::Index(skip)) => Ok((
+                table.view(&FetchViewer { reader: self, table, skip: *skip, len })?,
+                start.clone(),
+            )),
+            (Source::Mdbx(table), PageStart::Key(key, offset)) => {
+                table.view(&SeekViewer { reader: self, table, key, offset: *offset, len })
+            }
+            (Source::StaticFile(segment), PageStart::Index(skip)) => {
+                Ok((self.fetch_static_file(segment, *skip, len)?, start.clone()))
+            }
+            (Source::StaticFile(segment), PageStart::Key(key, offset)) => {
+                let skip = (static_file_number(key) as usize).saturating_add_signed(*offset);
+                Ok((self.fetch_static_file(segment, skip, len)?, PageStart::Index(skip)))
+            }
+        }
+    }
+
+    /// Returns where the page that starts with the first entry of the source with a key that is
+    /// greater than or equal to the given encoded key starts.
+    pub(crate) fn start(&self, source: Source, key: &[u8]) -> eyre::Result<PageStart> {
+        match source {
+            Source::Mdbx(table) => {
+                if self.filtered.as_ref().is_some_and(|(filtered, _)| *filtered == table) {
+                    eyre::bail!("seeking isn't supported in filtered tables")
+                }
+                Ok(PageStart::Key(key.to_vec(), 0))
+            }
+            Source::StaticFile(_) => Ok(PageStart::Index(static_file_number(key) as usize)),
+        }
+    }
+
+    /// Formats an entry of a database table.
+    fn list_entry<T: Table>(
+        &self,
+        table: Tables,
+        key: T::Key,
+        value: T::Value,
+    ) -> eyre::Result<ListEntry> {
+        let encoded_key = key.clone().encode();
+        let mut compressed_value = Vec::new();
+        value.compress_to_buf(&mut compressed_value);
+
+        Ok(ListEntry {
+            key: format!("{key:?}"),
+            value: if self.raw {
+                serde_json::to_string(&compressed_value)?
+            } else {
+                serde_json::to_string_pretty(&value)?
+            },
+            links: table_links(table, encoded_key.as_ref(), &compressed_value)?,
+        })
+    }
+
+    /// Parses a key of the source entered by the user and returns it encoded.
+    ///
+    /// Keys prefixed with `0x` are taken as encoded keys, which may be a prefix of the key of an
+    /// entry. Other keys are parsed as JSON for database tables, and as block or transaction number
+    /// for static file segments.
+    pub(crate) fn parse_key(&self, source: Source, input: &str) -> eyre::Result<Vec<u8>> {
+        if let Some(key) = input.strip_prefix("0x") {
+            return Ok(hex::decode(key)?)
+        }
+
+        match source {
+            Source::Mdbx(table) => table.view(&KeyViewer { input }),
+            Source::StaticFile(_) => Ok(input.parse::<u64>()?.to_be_bytes().to_vec()),
+        }
+    }
+
+    /// Fetches `len` rows of the static file segment, starting at the given block or transaction
+    /// number.
+    fn fetch_static_file(
+        &self,
+        segment: StaticFileSegment,
+        skip: usize,
+        len: usize,
+    ) -> eyre::Result<Vec<ListEntry>> {
+        let provider = self.tool.provider_factory.static_file_provider();
+        let end = (skip + len).min(self.total_entries(Source::StaticFile(segment))?);
+
+        let mut entries = Vec::with_capacity(end.saturating_sub(skip));
+        for number in skip as u64..end as u64 {
+            let jar = match segment {
+                StaticFileSegment::Headers => {
+                    provider.get_segment_provider_from_block(segment, number, None)?
+                }
+                _ => provider.get_segment_provider_from_transaction(segment, number, None)?,
+            };
+            let mut cursor = jar.cursor()?;
+
+            let entry = match segment {
+                StaticFileSegment::Headers => {
+                    cursor.get(number.into(), <HeaderWithHashMask<Header>>::MASK)?.map(|row| {
+                        let header = Header::decompress(row[0])?;
+                        let hash = BlockHash::decompress(row[1])?;
+                        let value = if self.raw {
+                            serde_json::to_string(&row)?
+                        } else {
+                            serde_json::to_string_pretty(
+                                &serde_json::json!({ "header": header, "hash": hash }),
+                            )?
+                        };
+                        let links = vec![
+                            Link::table(Tables::HeaderNumbers, hash),
+                            Link::table(Tables::BlockBodyIndices, number),
+                        ];
+                        Ok::<_, eyre::Report>((value, links))
+                    })
+                }
+                StaticFileSegment::Transactions => {
+                    cursor.get(number.into(), <TransactionMask<TxTy<N>>>::MASK)?.map(|row| {
+                        let transaction = <<Transactions as Table>::Value>::decompress(row[0])?;
+                        let value = if self.raw {
+                            serde_json::to_string(row[0])?
+                        } else {
+                            serde_json::to_string_pretty(&transaction)?
+                        };
+                        let links = vec![
+                            Link::table(Tables::TransactionHashNumbers, *transaction.tx_hash()),
+                            Link::static_file(StaticFileSegment::Receipts, number),
+                        ];
+                        Ok::<_, eyre::Report>((value, links))
+                    })
+                }
+                StaticFileSegment::Receipts => {
+                    cursor.get(number.into(), <ReceiptMask<ReceiptTy<N>>>::MASK)?.map(|row| {
+                        let value = if self.raw {
+                            serde_json::to_string(row[0])?
+                        } else {
+                            serde_json::to_string_pretty(
+                                &<<Receipts as Table>::Value>::decompress(row[0])?,
+                            )?
+                        };
+                        let links =
+                            vec![Link::static_file(StaticFileSegment::Transactions, number)];
+                        Ok::<_, eyre::Report>((value, links))
+                    })
+                }
+                StaticFileSegment::BlockMeta => {
+                    eyre::bail!("listing static file segment {segment} isn't supported")
+                }
+            }
+            .transpose()?;
+
+            if let Some((value, links)) = entry {
+                entries.push(ListEntry { key: number.to_string(), value, links });
+            }
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Returns the block or transaction number of an encoded static file key, which may be a prefix of
+/// the big endian number.
+fn static_file_number(key: &[u8]) -> u64 {
+    let mut number = [0; 8];
+    let len = key.len().min(8);
+    number[..len].copy_from_slice(&key[..len]);
+    u64::from_be_bytes(number)
+}
+
+/// Returns the entries of other tables and static file segments that the given encoded entry of a
+/// database table refers to.
+fn table_links(table: Tables, key: &[u8], value: &[u8]) -> Result<Vec<Link>, DatabaseError> {
+    let links = match table {
+        Tables::CanonicalHeaders => {
+            let number = BlockNumber::decode(key)?;
+            vec![
+                Link::static_file(StaticFileSegment::Headers, number),
+                Link::table(Tables::HeaderNumbers, BlockHash::decompress(value)?),
+            ]
+        }
+        Tables::HeaderNumbers | Tables::TransactionBlocks => {
+            let number = BlockNumber::decompress(value)?;
+            vec![
+                Link::static_file(StaticFileSegment::Headers, number),
+                Link::table(Tables::BlockBodyIndices, number),
+            ]
+        }
+        Tables::BlockBodyIndices => {
+            let number = BlockNumber::decode(key)?;
+            let indices = StoredBlockBodyIndices::decompress(value)?;
+            let mut links = vec![Link::static_file(StaticFileSegment::Headers, number)];
+            if !indices.is_empty() {
+                links
+                    .push(Link::static_file(StaticFileSegment::Transactions, indices.first_tx_num));
+                links.push(Link::static_file(StaticFileSegment::Receipts, indices.first_tx_num));
+            }
+            links
+        }
+        Tables::TransactionHashNumbers => {
+            let number = TxNumber::decompress(value)?;
+            vec![
+                Link::static_file(StaticFileSegment::Transactions, number),
+                Link::static_file(StaticFileSegment::Receipts, number),
+            ]
+        }
+        Tables::PlainAccountState => {
+            let address = Address::decode(key)?;
+            let account = Account::decompress(value)?;
+            let mut links = vec![
+                Link::table(Tables::PlainStorageState, address),
+                Link::table(Tables::HashedAccounts, keccak256(address)),
+            ];
+            if let Some(hash) = account.bytecode_hash {
+                links.push(Link::table(Tables::Bytecodes, hash));
+            }
+            links
+        }
+        Tables::PlainStorageState => {
+            vec![Link::table(Tables::PlainAccountState, Address::decode(key)?)]
+        }
+        _ => Vec::new(),
+    };
+    Ok(links)
+}
+
+/// Fetches a page of entries of a database table.
+struct FetchViewer<'a, 'b, N: ProviderNodeTypes> {
+    reader: &'a EntryReader<'b, N>,
+    table: Tables,
+    skip: usize,
+    len: usize,
+}
+
+impl<N: ProviderNodeTypes> TableViewer<Vec<ListEntry>> for FetchViewer<'_, '_, N> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<Vec<ListEntry>, Self::Error> {
+        let rows = match &self.reader.filtered {
+            Some((table, filter)) if *table == self.table => {
+                filter.borrow_mut().update_page(self.skip, self.len);
+                self.reader.tool.list::<T>(&filter.borrow())?.0
+            }
+            _ => {
+                let filter = ListFilter {
+                    skip: self.skip,
+                    len: self.len,
+                    search: Vec::new(),
+                    min_row_size: 0,
+                    min_key_size: 0,
+                    min_value_size: 0,
+                    reverse: false,
+                    only_count: false,
+                };
+                self.reader.tool.list::<T>(&filter)?.0
+            }
+        };
+
+        rows.into_iter()
+            .map(|(key, value)| self.reader.list_entry::<T>(self.table, key, value))
+            .collect()
+    }
+}
+
+/// Fetches a page of entries of a database table at an offset from the first entry with a key
+/// that is greater than or equal to the given encoded key.
+struct SeekViewer<'a, 'b, N: ProviderNodeTypes> {
+    reader: &'a EntryReader<'b, N>,
+    table: Tables,
+    key: &'a [u8],
+    offset: isize,
+    len: usize,
+}
+
+impl<N: ProviderNodeTypes> TableViewer<(Vec<ListEntry>, PageStart)> for SeekViewer<'_, '_, N> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<(Vec<ListEntry>, PageStart), Self::Error> {
+        let tx = self.reader.tool.provider_factory.db_ref().tx()?;
+        let mut cursor = tx.cursor_read::<RawTable<T>>()?;
+        let mut start = PageStart::Key(self.key.to_vec(), self.offset);
+
+        let mut entry = cursor.seek(RawKey::from_vec(self.key.to_vec()))?;
+        if self.offset < 0 {
+            let mut steps = self.offset.unsigned_abs();
+            if entry.is_none() {
+                // the key is past the last entry, which is the first step back
+                entry = cursor.last()?;
+                steps -= 1;
+            }
+            for _ in 0..steps {
+                match cursor.prev()? {
+                    Some(prev) => entry = Some(prev),
+                    None => {
+                        start = PageStart::Index(0);
+                        entry = cursor.first()?;
+                        break
+                    }
+                }
+            }
+        } else {
+            for _ in 0..self.offset {
+                if entry.is_none() {
+                    break
+                }
+                entry = cursor.next()?;
+            }
+        }
+
+        let mut entries = Vec::with_capacity(self.len);
+        while let Some((key, value)) = entry.take().filter(|_| entries.len() < self.len) {
+            entries.push(self.reader.list_entry::<T>(self.table, key.key()?, value.value()?)?);
+            entry = cursor.next()?;
+        }
+
+        Ok((entries, start))
+    }
+}
+
+/// Counts the entries of a database table.
+struct EntriesViewer<'a, N: ProviderNodeTypes> {
+    tool: &'a DbTool<N>,
+}
+
+impl<N: ProviderNodeTypes> TableViewer<usize> for EntriesViewer<'_, N> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<usize, Self::Error> {
+        Ok(self.tool.provider_factory.db_ref().view(|tx| tx.entries::<T>())??)
+    }
+}
+
+/// Parses a JSON key of a database table and encodes it.
+struct KeyViewer<'a> {
+    input: &'a str,
+}
+
+impl TableViewer<Vec<u8>> for KeyViewer<'_> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<Vec<u8>, Self::Error> {
+        Ok(RawKey::new(table_key::<T>(self.input)?).into_key())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_db_api::transaction::DbTxMut;
+    use reth_provider::test_utils::create_test_provider_factory;
+
+    #[test]
+    fn parse_source() {
+        assert_eq!("Headers".parse::<Source>().unwrap(), Source::Mdbx(Tables::Headers));
+        assert_eq!(
+            "headers".parse::<Source>().unwrap(),
+            Source::StaticFile(StaticFileSegment::Headers)
+        );
+        assert!("Unknown".parse::<Source>().is_err());
+    }
+
+    #[test]
+    fn block_body_indices_links() {
+        let indices = StoredBlockBodyIndices { first_tx_num: 10, tx_count: 2 };
+        let links =
+            table_links(Tables::BlockBodyIndices, &5u64.encode(), &indices.compress()).unwrap();
+        assert_eq!(
+            links,
+            vec![
+                Link::static_file(StaticFileSegment::Headers, 5),
+                Link::static_file(StaticFileSegment::Transactions, 10),
+                Link::static_file(StaticFileSegment::Receipts, 10),
+            ]
+        );
+        assert_eq!(links[1].to_string(), "static file segment transactions #10");
+
+        let empty = StoredBlockBodyIndices { first_tx_num: 10, tx_count: 0 };
+        let links =
+            table_links(Tables::BlockBodyIndices, &5u64.encode(), &empty.compress()).unwrap();
+        assert_eq!(links, vec![Link::static_file(StaticFileSegment::Headers, 5)]);
+    }
+
+    #[test]
+    fn seek_pages() {
+        let factory = create_test_provider_factory();
+        let tx = factory.db_ref().tx_mut().unwrap();
+        for number in 0..10u64 {
+            tx.put::<reth_db::tables::CanonicalHeaders>(
+                number,
+                BlockHash::with_last_byte(number as u8),
+            )
+            .unwrap();
+        }
+        tx.commit().unwrap();
+        let tool = DbTool::new(factory).unwrap();
+        let reader = EntryReader::new(&tool, None, false);
+        let source = Source::Mdbx(Tables::CanonicalHeaders);
+
+        let fetch = |key: u64, offset| {
+            let (entries, start) =
+                reader.fetch(source, &PageStart::Key(key.encode().to_vec(), offset), 3).unwrap();
+            (entries.into_iter().map(|entry| entry.key).collect::<Vec<_>>(), start)
+        };
+        let keys = |keys: &[u64]| keys.iter().map(|key| key.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(fetch(5, 0), (keys(&[5, 6, 7]), PageStart::Key(5u64.encode().to_vec(), 0)));
+        assert_eq!(fetch(5, 3), (keys(&[8, 9]), PageStart::Key(5u64.encode().to_vec(), 3)));
+        assert_eq!(fetch(5, -3), (keys(&[2, 3, 4]), PageStart::Key(5u64.encode().to_vec(), -3)));
+        // pages before the first entry start at the first entry
+        assert_eq!(fetch(5, -6), (keys(&[0, 1, 2]), PageStart::Index(0)));
+        // the last entry is the first step back from a key past the last entry
+        assert_eq!(fetch(20, -2), (keys(&[8, 9]), PageStart::Key(20u64.encode().to_vec(), -2)));
+        assert_eq!(fetch(20, 0).0, keys(&[]));
+    }
+}
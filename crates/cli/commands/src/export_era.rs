@@ -0,0 +1,202 @@
+//! Command that exports blocks from static files into ERA1 files.
+use crate::common::{AccessRights, CliNodeTypes, Environment, EnvironmentArgs};
+use alloy_primitives::BlockNumber;
+use clap::Parser;
+use reth_chainspec::{EthChainSpec, EthereumHardfork, EthereumHardforks, ForkCondition};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_era::{era1_file_name, BlockTuple, Era1Writer, MAX_BLOCKS_PER_ERA1};
+use reth_node_core::version::SHORT_VERSION;
+use reth_primitives_traits::{Block, Receipt};
+use reth_provider::{
+    providers::ProviderNodeTypes, BlockHashReader, BlockReader, ChainSpecProvider, HeaderProvider,
+    ProviderError, ProviderFactory, ReceiptProvider, StageCheckpointReader,
+};
+use reth_stages::StageId;
+use std::{
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+use tracing::info;
+
+/// Exports blocks into ERA1 files.
+///
+/// Every file contains the blocks of one epoch of 8192 blocks, the last file may be partial. Blocks
+/// are only exported up to the last executed block, since their receipts are required, and up to
+/// the last block before the merge.
+///
+/// Blocks before Byzantium can't be exported: their receipts commit to intermediate state roots,
+/// which aren't stored.
+#[derive(Debug, Parser)]
+pub struct ExportEraCommand<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// The first block to export, rounded down to the start of its epoch.
+    #[arg(long, value_name = "BLOCK_NUMBER", default_value_t = 0)]
+    from: BlockNumber,
+
+    /// The last block to export.
+    ///
+    /// Defaults to the last executed block. Capped at the last block before the merge.
+    #[arg(long, value_name = "BLOCK_NUMBER", verbatim_doc_comment)]
+    to: Option<BlockNumber>,
+
+    /// The network name used in the names of the ERA1 files.
+    ///
+    /// Defaults to the name of the chain.
+    #[arg(long, verbatim_doc_comment)]
+    network: Option<String>,
+
+    /// The directory to write the ERA1 files to.
+    #[arg(value_name = "EXPORT_DIR")]
+    path: PathBuf,
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> ExportEraCommand<C> {
+    /// Execute `export-era` command
+    pub async fn execute<N: CliNodeTypes<ChainSpec = C::ChainSpec>>(self) -> eyre::Result<()> {
+        info!(target: "reth::cli", "reth {} starting", SHORT_VERSION);
+
+        let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+
+        let executed = provider_factory
+            .provider()?
+            .get_stage_checkpoint(StageId::Execution)?
+            .unwrap_or_default()
+            .block_number;
+        let mut to = self.to.unwrap_or(executed);
+        if to > executed {
+            eyre::bail!(
+                "cannot export block {to}, receipts are only available up to block {executed}"
+            );
+        }
+        if let Some(paris_block) = paris_block(&provider_factory.chain_spec()) {
+            let Some(last_pre_merge_block) = paris_block.checked_sub(1) else {
+                eyre::bail!("the chain has no blocks before the merge")
+            };
+            if to > last_pre_merge_block {
+                info!(target: "reth::cli", to, last_pre_merge_block, "Capping export at the last block before the merge");
+                to = last_pre_merge_block;
+            }
+        }
+        if self.from > to {
+            eyre::bail!("--from {} is after the last exported block {to}", self.from);
+        }
+
+        let network = self.network.unwrap_or_else(|| self.env.chain.chain().to_string());
+        reth_fs_util::create_dir_all(&self.path)?;
+
+        let epoch_len = MAX_BLOCKS_PER_ERA1 as u64;
+        for epoch in self.from / epoch_len..=to / epoch_len {
+            let start = epoch * epoch_len;
+            let end = (start + epoch_len - 1).min(to);
+            let file =
+                export_era1_file(&provider_factory, &network, epoch, start, end, &self.path)?;
+            info!(target: "reth::cli", file=%file.display(), start, end, "Exported ERA1 file");
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the first block after the merge, if it's known.
+fn paris_block(chain_spec: &impl EthereumHardforks) -> Option<BlockNumber> {
+    match chain_spec.ethereum_fork_activation(EthereumHardfork::Paris) {
+        ForkCondition::Block(block) | ForkCondition::TTD { activation_block_number: block, .. } => {
+            Some(block)
+        }
+        _ => None,
+    }
+}
+
+/// Exports the given blocks of an epoch into an ERA1 file in the given directory.
+///
+/// The blocks need to be between Byzantium and the merge. Returns the path of the written file.
+pub fn export_era1_file<N: ProviderNodeTypes<ChainSpec: EthereumHardforks>>(
+    provider_factory: &ProviderFactory<N>,
+    network: &str,
+    epoch: u64,
+    start: BlockNumber,
+    end: BlockNumber,
+    dir: &Path,
+) -> eyre::Result<PathBuf> {
+    let chain_spec = provider_factory.chain_spec();
+    if !chain_spec.is_byzantium_active_at_block(start) {
+        eyre::bail!(
+            "cannot export block {start}, receipts before Byzantium commit to intermediate state roots that aren't stored"
+        );
+    }
+    if paris_block(&chain_spec).is_some_and(|paris_block| end >= paris_block) {
+        eyre::bail!("cannot export block {end}, ERA1 files only contain blocks before the merge");
+    }
+
+    let provider = provider_factory.provider()?;
+
+    // the name of the file depends on the accumulator, which is only known once all blocks are
+    // written
+    let tmp_path = dir.join(format!("{network}-{epoch:05}.era1.tmp"));
+    let mut writer = Era1Writer::new(BufWriter::new(File::create(&tmp_path)?), start)?;
+    for number in start..=end {
+        let block = provider
+            .block_by_number(number)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(number.into()))?;
+        let hash = provider
+            .block_hash(number)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(number.into()))?;
+        let total_difficulty = provider
+            .header_td_by_number(number)?
+            .ok_or(ProviderError::TotalDifficultyNotFound(number))?;
+        let receipts = provider
+            .receipts_by_block(number.into())?
+            .ok_or_else(|| eyre::eyre!("receipts of block {number} not found"))?;
+
+        writer.append_block(
+            hash,
+            &BlockTuple {
+                header: alloy_rlp::encode(block.header()).into(),
+                body: alloy_rlp::encode(block.body()).into(),
+                receipts: encode_receipts(&receipts).into(),
+                total_difficulty,
+            },
+        )?;
+    }
+    let (_, accumulator) = writer.finish()?;
+
+    let path = dir.join(era1_file_name(network, epoch, accumulator));
+    reth_fs_util::rename(&tmp_path, &path)?;
+    Ok(path)
+}
+
+/// Encodes the receipts of a block as a list of receipts with their logs blooms.
+pub(crate) fn encode_receipts<R: Receipt>(receipts: &[R]) -> Vec<u8> {
+    let mut payload = Vec::new();
+    for receipt in receipts {
+        receipt.rlp_encode_with_bloom(&receipt.bloom(), &mut payload);
+    }
+
+    let mut encoded =
+        Vec::with_capacity(payload.len() + alloy_rlp::length_of_length(payload.len()));
+    alloy_rlp::Header { list: true, payload_length: payload.len() }.encode(&mut encoded);
+    encoded.extend_from_slice(&payload);
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_ethereum_cli::chainspec::{EthereumChainSpecParser, SUPPORTED_CHAINS};
+
+    #[test]
+    fn parse_common_export_era_command_chain_args() {
+        for chain in SUPPORTED_CHAINS {
+            let args: ExportEraCommand<EthereumChainSpecParser> =
+                ExportEraCommand::parse_from(["reth", "--chain", chain, "."]);
+            assert_eq!(
+                Ok(args.env.chain.chain),
+                chain.parse::<reth_chainspec::Chain>(),
+                "failed to parse chain {chain}"
+            );
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! Command that imports pre-merge history from ERA1 files into static files.
+use crate::common::{AccessRights, CliNodeComponents, CliNodeTypes, Environment, EnvironmentArgs};
+use alloy_consensus::{BlockHeader, RlpDecodableReceipt};
+use clap::Parser;
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_consensus::{Consensus, ConsensusError, FullConsensus, HeaderValidator};
+use reth_db::tables;
+use reth_db_api::transaction::DbTxMut;
+use reth_era::{compute_accumulator, Era1Reader, HeaderRecord};
+use reth_node_api::{BlockTy, ReceiptTy};
+use reth_node_core::version::SHORT_VERSION;
+use reth_primitives::{SealedBlock, StaticFileSegment};
+use reth_provider::{
+    providers::ProviderNodeTypes, writer::UnifiedStorageWriter, BlockWriter, ChainSpecProvider,
+    DBProvider, HeaderProvider, ProviderError, ProviderFactory, StageCheckpointReader,
+    StageCheckpointWriter, StaticFileProviderFactory, StaticFileWriter, StorageLocation,
+};
+use reth_stages::{StageCheckpoint, StageId};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tracing::{debug, info};
+
+/// Imports pre-merge blocks from ERA1 files.
+///
+/// Headers and bodies are written straight to static files, without running the online stages.
+///
+/// The receipts of each block from Byzantium on are only verified against its receipts root, they
+/// are not stored. Receipts past the execution checkpoint would be pruned by the execution stage
+/// and the consistency check on the next start, the execution stage writes its own receipts once
+/// it executes the imported blocks. Receipts before Byzantium commit to intermediate state roots
+/// and can't be verified.
+#[derive(Debug, Parser)]
+pub struct ImportEraCommand<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// The path to an ERA1 file or to a directory of ERA1 files.
+    ///
+    /// The files of a directory are imported in the order of their names. Blocks that are
+    /// already stored are skipped.
+    #[arg(value_name = "IMPORT_PATH", verbatim_doc_comment)]
+    path: PathBuf,
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> ImportEraCommand<C> {
+    /// Execute `import-era` command
+    pub async fn execute<N, Comp, F>(self, components: F) -> eyre::Result<()>
+    where
+        N: CliNodeTypes<ChainSpec = C::ChainSpec>,
+        Comp: CliNodeComponents<N>,
+        F: FnOnce(Arc<N::ChainSpec>) -> Comp,
+    {
+        info!(target: "reth::cli", "reth {} starting", SHORT_VERSION);
+
+        let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RW)?;
+
+        let components = components(provider_factory.chain_spec());
+        let consensus = components.consensus().clone();
+
+        let mut total_imported_blocks = 0;
+        for file in era1_files(&self.path)? {
+            info!(target: "reth::cli", file=%file.display(), "Importing ERA1 file");
+            total_imported_blocks += import_era1_file(&provider_factory, &consensus, &file)?;
+        }
+
+        info!(target: "reth::cli", total_imported_blocks, "ERA1 files imported");
+
+        Ok(())
+    }
+}
+
+/// Returns the ERA1 file at the given path, or the ERA1 files in the given directory sorted by
+/// name.
+fn era1_files(path: &Path) -> eyre::Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()])
+    }
+
+    let mut files = Vec::new();
+    for entry in reth_fs_util::read_dir(path)? {
+        let file = entry?.path();
+        if file.extension().is_some_and(|extension| extension == "era1") {
+            files.push(file);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Imports the blocks of the ERA1 file that extend the local chain.
+///
+/// All blocks of the file are validated and checked against the accumulator of the file before
+/// anything is written. Returns the number of imported blocks.
+pub fn import_era1_file<N, C>(
+    provider_factory: &ProviderFactory<N>,
+    consensus: &C,
+    path: &Path,
+) -> eyre::Result<u64>
+where
+    N: ProviderNodeTypes<ChainSpec: EthereumHardforks>,
+    C: FullConsensus<N::Primitives, Error = ConsensusError>,
+{
+    let provider = provider_factory.database_provider_rw()?;
+
+    // the imported blocks must extend the headers and bodies that are already stored
+    let headers = provider.get_stage_checkpoint(StageId::Headers)?.unwrap_or_default();
+    let bodies = provider.get_stage_checkpoint(StageId::Bodies)?.unwrap_or_default();
+    if headers.block_number != bodies.block_number {
+        eyre::bail!(
+            "headers ({}) and bodies ({}) must be synced to the same block before importing ERA1 files",
+            headers.block_number,
+            bodies.block_number
+        );
+    }
+    let mut parent = provider
+        .sealed_header(headers.block_number)?
+        .ok_or_else(|| ProviderError::HeaderNotFound(headers.block_number.into()))?;
+    let mut parent_td = provider
+        .header_td_by_number(parent.number())?
+        .ok_or_else(|| ProviderError::TotalDifficultyNotFound(parent.number()))?;
+
+    let mut reader = Era1Reader::open(path)?;
+    let mut records = Vec::new();
+    let mut blocks = Vec::new();
+    while let Some(tuple) = reader.next_block()? {
+        let block =
+            SealedBlock::<BlockTy<N>>::seal_parts(tuple.decode_header()?, tuple.decode_body()?);
+        records.push(HeaderRecord {
+            block_hash: block.hash(),
+            total_difficulty: tuple.total_difficulty,
+        });
+
+        if block.number() <= parent.number() {
+            continue
+        }
+        if block.number() != parent.number() + 1 || block.parent_hash() != parent.hash() {
+            eyre::bail!("block {} doesn't extend the local chain", block.number());
+        }
+
+        consensus.validate_header(block.sealed_header())?;
+        consensus.validate_header_against_parent(block.sealed_header(), &parent)?;
+        consensus.validate_block_pre_execution(&block)?;
+
+        // receipts of pre-Byzantium blocks aren't committed to by a status, see EIP-658
+        if provider.chain_spec().is_byzantium_active_at_block(block.number()) {
+            if tuple.receipts_root()? != block.receipts_root() {
+                eyre::bail!("receipts of block {} don't match its receipts root", block.number());
+            }
+            decode_receipts::<ReceiptTy<N>>(&tuple.receipts)?;
+        }
+
+        let total_difficulty = parent_td + block.difficulty();
+        if total_difficulty != tuple.total_difficulty {
+            eyre::bail!("invalid total difficulty of block {}", block.number());
+        }
+
+        parent = block.clone_sealed_header();
+        parent_td = total_difficulty;
+        blocks.push((block, total_difficulty));
+    }
+
+    if Some(compute_accumulator(&records)) != reader.accumulator() {
+        eyre::bail!("blocks don't match the accumulator of {}", path.display());
+    }
+
+    let Some(last_block) = blocks.last().map(|(block, _)| block.number()) else {
+        debug!(target: "reth::cli", file=%path.display(), "All blocks of ERA1 file are already stored");
+        return Ok(0)
+    };
+    let total_blocks = blocks.len() as u64;
+
+    let mut header_writer =
+        provider.static_file_provider().latest_writer(StaticFileSegment::Headers)?;
+    let mut bodies = Vec::with_capacity(blocks.len());
+    for (block, total_difficulty) in blocks {
+        let (header, body) = block.split_sealed_header_body();
+        header_writer.append_header(header.header(), total_difficulty, &header.hash())?;
+        provider.tx_ref().put::<tables::HeaderNumbers>(header.hash(), header.number())?;
+        bodies.push((header.number(), Some(body)));
+    }
+    drop(header_writer);
+
+    provider.append_block_bodies(bodies, StorageLocation::StaticFiles)?;
+
+    for stage in [StageId::Headers, StageId::Bodies] {
+        provider.save_stage_checkpoint(stage, StageCheckpoint::new(last_block))?;
+    }
+
+    UnifiedStorageWriter::commit(provider)?;
+
+    info!(target: "reth::cli", file=%path.display(), total_blocks, last_block, "Imported ERA1 file");
+
+    Ok(total_blocks)
+}
+
+/// Decodes the receipts of a block from a list of receipts with their logs blooms.
+fn decode_receipts<R: RlpDecodableReceipt>(encoded: &[u8]) -> alloy_rlp::Result<Vec<R>> {
+    let mut buf = encoded;
+    let mut payload = alloy_rlp::Header::decode_bytes(&mut buf, true)?;
+
+    let mut receipts = Vec::new();
+    while !payload.is_empty() {
+        receipts.push(R::rlp_decode_with_bloom(&mut payload)?.receipt);
+    }
+    Ok(receipts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export_era::encode_receipts;
+    use alloy_primitives::{Bytes, Log, B256};
+    use reth_ethereum_cli::chainspec::{EthereumChainSpecParser, SUPPORTED_CHAINS};
+    use reth_primitives::{Receipt, TxType};
+
+    #[test]
+    fn parse_common_import_era_command_chain_args() {
+        for chain in SUPPORTED_CHAINS {
+            let args: ImportEraCommand<EthereumChainSpecParser> =
+                ImportEraCommand::parse_from(["reth", "--chain", chain, "."]);
+            assert_eq!(
+                Ok(args.env.chain.chain),
+                chain.parse::<reth_chainspec::Chain>(),
+                "failed to parse chain {chain}"
+            );
+        }
+    }
+
+    #[test]
+    fn receipts_roundtrip() {
+        let logs = vec![Log::new_unchecked(Default::default(), vec![B256::ZERO], Bytes::new())];
+        let receipts = vec![
+            Receipt {
+                tx_type: TxType::Legacy,
+                success: true,
+                cumulative_gas_used: 21_000,
+                logs: logs.clone(),
+            },
+            Receipt { tx_type: TxType::Eip1559, success: false, cumulative_gas_used: 42_000, logs },
+        ];
+
+        assert_eq!(decode_receipts::<Receipt>(&encode_receipts(&receipts)).unwrap(), receipts);
+        assert!(decode_receipts::<Receipt>(&encode_receipts::<Receipt>(&[])).unwrap().is_empty());
+    }
+}
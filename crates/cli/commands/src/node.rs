@@ -48,6 +48,13 @@ pub struct NodeCommand<
     #[arg(long, value_name = "SOCKET", value_parser = parse_socket_address, help_heading = "Metrics")]
     pub metrics: Option<SocketAddr>,
 
+    /// Download the headers, block bodies and transactions of the chain history as static files
+    /// from the given server, before syncing the rest of the chain from the network.
+    ///
+    /// The server needs to list its static files in `filelist.json`.
+    #[arg(long, value_name = "URL")]
+    pub static_files_url: Option<String>,
+
     /// Add a new instance of a node.
     ///
     /// Configures the ports of the node to avoid conflicts with the defaults.
@@ -153,6 +160,7 @@ impl<
             config,
             chain,
             metrics,
+            static_files_url,
             instance,
             with_unused_ports,
             network,
@@ -173,6 +181,7 @@ impl<
             config,
             chain,
             metrics,
+            static_files_url,
             instance,
             network,
             rpc,
@@ -285,6 +294,17 @@ mod tests {
         assert_eq!(cmd.metrics, Some(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 9001)));
     }
 
+    #[test]
+    fn parse_static_files_url() {
+        let cmd: NodeCommand = NodeCommand::try_parse_args_from([
+            "reth",
+            "--static-files-url",
+            "http://localhost:8000",
+        ])
+        .unwrap();
+        assert_eq!(cmd.static_files_url.as_deref(), Some("http://localhost:8000"));
+    }
+
     #[test]
     fn parse_config_path() {
         let cmd: NodeCommand =
@@ -152,6 +152,13 @@ impl<C: ChainSpecParser> Command<C> {
                 reset_stage_checkpoint(tx, StageId::TransactionLookup)?;
                 insert_genesis_header(&provider_rw, &self.env.chain)?;
             }
+            StageEnum::LogIndex => {
+                tx.clear::<tables::LogAddressIndex>()?;
+                tx.clear::<tables::LogTopicIndex>()?;
+                reset_prune_checkpoint(tx, PruneSegment::LogIndex)?;
+
+                reset_stage_checkpoint(tx, StageId::IndexLogs)?;
+            }
         }
 
         tx.put::<tables::StageCheckpoints>(StageId::Finish.to_string(), Default::default())?;
@@ -0,0 +1,771 @@
+//! Command that verifies the contents of the static files.
+
+use crate::common::{CliNodeTypes, EnvironmentArgs};
+use alloy_consensus::{BlockHeader, TxReceipt};
+use alloy_primitives::{BlockNumber, Bloom, B256, U256};
+use clap::Parser;
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_db::{
+    open_db, open_db_read_only,
+    static_file::{iter_static_files, HeaderWithHashMask, TotalDifficultyMask},
+    DatabaseEnv,
+};
+use reth_node_api::HeaderTy;
+use reth_node_builder::NodeTypesWithDBAdapter;
+use reth_primitives_traits::{
+    proofs::{calculate_receipt_root, calculate_transaction_root},
+    Sealable,
+};
+use reth_provider::{
+    providers::{ProviderNodeTypes, StaticFileProvider, StaticFileWriter},
+    BlockBodyIndicesProvider, ChainSpecProvider, HeaderProvider, ProviderError, ProviderFactory,
+    ReceiptProvider, StaticFileProviderFactory, TransactionsProvider,
+};
+use reth_static_file_types::{SegmentRangeInclusive, StaticFileSegment};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Duration,
+};
+use tracing::{info, warn};
+
+/// Interval at which the progress of the verification is logged.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Verifies the contents of the static files.
+///
+/// Unlike the consistency check at startup, which only compares the latest static file of every
+/// segment with the database, this reads every row of every static file:
+///
+/// - the offsets of every file must be in order and within its data file,
+/// - every header must hash to its stored hash and be chained to the previous header by its parent
+///   hash and total difficulty,
+/// - the transactions and receipts of every block must match the transactions root, receipts root
+///   and logs bloom of its header.
+///
+/// The receipts root of pre-Byzantium blocks can't be verified, since the intermediate state roots
+/// of their receipts aren't stored.
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// The static file segments to verify.
+    ///
+    /// Defaults to all segments.
+    #[arg(long, value_delimiter = ',', verbatim_doc_comment)]
+    segments: Vec<StaticFileSegment>,
+
+    /// Number of threads that verify static files in parallel.
+    ///
+    /// Defaults to the number of available CPUs.
+    #[arg(long, value_name = "JOBS", verbatim_doc_comment)]
+    jobs: Option<usize>,
+
+    /// Truncate every corrupted segment to the block before its first corrupted block.
+    ///
+    /// The database is left as is, so the node unwinds to the truncated static files on its next
+    /// start and syncs the removed blocks again. The node has to be stopped.
+    #[arg(long, verbatim_doc_comment)]
+    repair: bool,
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C> {
+    /// Execute `static-file verify` command
+    pub async fn execute<N: CliNodeTypes<ChainSpec = C::ChainSpec>>(self) -> eyre::Result<()> {
+        warn!("This command should be run without the node running!");
+
+        // the storage is opened without the consistency check at startup, since it fails on, or
+        // heals, the corruption this command is supposed to report
+        let data_dir = self.env.datadir.clone().resolve_datadir(self.env.chain.chain());
+        let (db, static_file_provider) = if self.repair {
+            (
+                open_db(&data_dir.db(), self.env.db.database_args())?,
+                StaticFileProvider::read_write(data_dir.static_files())?,
+            )
+        } else {
+            (
+                open_db_read_only(&data_dir.db(), self.env.db.database_args())?,
+                StaticFileProvider::read_only(data_dir.static_files(), false)?,
+            )
+        };
+        let provider_factory = ProviderFactory::<NodeTypesWithDBAdapter<N, Arc<DatabaseEnv>>>::new(
+            Arc::new(db),
+            self.env.chain.clone(),
+            static_file_provider,
+        );
+
+        self.verify(&provider_factory)
+    }
+
+    /// Verifies the static files of the given provider factory, and truncates the corrupted
+    /// segments if `--repair` is set.
+    fn verify<N: ProviderNodeTypes>(
+        self,
+        provider_factory: &ProviderFactory<N>,
+    ) -> eyre::Result<()> {
+        let segments = if self.segments.is_empty() {
+            StaticFileSegment::iter().collect()
+        } else {
+            self.segments
+        };
+        let mut jars = Vec::new();
+        for (segment, ranges) in
+            iter_static_files(provider_factory.static_file_provider().directory())?
+        {
+            // Not integrated yet
+            if segment.is_block_meta() || !segments.contains(&segment) {
+                continue
+            }
+            jars.extend(ranges.into_iter().map(|(blocks, _)| Jar { segment, blocks }));
+        }
+        jars.sort_by_key(|jar| (jar.segment, jar.blocks.start()));
+
+        let jobs = self
+            .jobs
+            .or_else(|| thread::available_parallelism().ok().map(|jobs| jobs.get()))
+            .unwrap_or(1)
+            .clamp(1, jars.len().max(1));
+        let total_blocks = jars.iter().map(Jar::len).sum::<u64>();
+        info!(target: "reth::cli", files = jars.len(), blocks = total_blocks, jobs, "Verifying static files");
+
+        let mut reports = verify_jars(provider_factory, &jars, jobs, total_blocks)?;
+        check_headers_chaining(&mut reports);
+
+        // everything from the first corrupted block of a segment onwards has to be synced again
+        let mut first_corrupted = BTreeMap::<StaticFileSegment, BlockNumber>::new();
+        for report in &reports {
+            if let Some(corruption) = &report.corruption {
+                warn!(
+                    target: "reth::cli",
+                    segment = %report.jar.segment,
+                    blocks = %report.jar.blocks,
+                    block = corruption.block,
+                    reason = %corruption.reason,
+                    "Corrupted static file"
+                );
+                first_corrupted
+                    .entry(report.jar.segment)
+                    .and_modify(|block| *block = (*block).min(corruption.block))
+                    .or_insert(corruption.block);
+            }
+        }
+
+        if first_corrupted.is_empty() {
+            info!(target: "reth::cli", files = reports.len(), blocks = total_blocks, "Verified static files");
+            return Ok(())
+        }
+
+        if !self.repair {
+            eyre::bail!(
+                "found corrupted static files in segments {}, run with --repair to truncate them",
+                first_corrupted
+                    .keys()
+                    .map(|segment| segment.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }
+
+        for (segment, block) in first_corrupted {
+            truncate_segment(provider_factory, segment, block)?;
+        }
+        info!(
+            target: "reth::cli",
+            "Truncated static files, the node unwinds to them on its next start and syncs the removed blocks again"
+        );
+
+        Ok(())
+    }
+}
+
+/// A static file of a segment.
+#[derive(Debug, Clone, Copy)]
+struct Jar {
+    segment: StaticFileSegment,
+    /// The blocks in the static file.
+    blocks: SegmentRangeInclusive,
+}
+
+impl Jar {
+    /// Returns the number of blocks in the static file.
+    const fn len(&self) -> u64 {
+        self.blocks.end() - self.blocks.start() + 1
+    }
+}
+
+/// The first corrupted block of a static file.
+#[derive(Debug)]
+struct Corruption {
+    block: BlockNumber,
+    reason: String,
+}
+
+impl Corruption {
+    fn new(block: BlockNumber, reason: impl fmt::Display) -> Self {
+        Self { block, reason: reason.to_string() }
+    }
+}
+
+/// The first and last header of a static file of the headers segment, used to check that
+/// consecutive files are chained.
+#[derive(Debug, Clone, Copy)]
+struct HeadersBoundary {
+    /// Parent hash of the first header.
+    parent_hash: B256,
+    /// Total difficulty of the parent of the first header.
+    parent_td: U256,
+    /// Hash of the last header.
+    hash: B256,
+    /// Total difficulty of the last header.
+    td: U256,
+}
+
+/// The result of verifying a static file.
+#[derive(Debug)]
+struct JarReport {
+    jar: Jar,
+    /// Set for static files of the headers segment without corruption.
+    boundary: Option<HeadersBoundary>,
+    corruption: Option<Corruption>,
+}
+
+/// Verifies the static files on `jobs` threads, logging the progress.
+///
+/// Returns the reports in the order of the static files.
+fn verify_jars<N: ProviderNodeTypes>(
+    provider_factory: &ProviderFactory<N>,
+    jars: &[Jar],
+    jobs: usize,
+    total_blocks: u64,
+) -> eyre::Result<Vec<JarReport>> {
+    let next_jar = AtomicUsize::new(0);
+    let mut reports = Vec::with_capacity(jars.len());
+    thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel();
+        for _ in 0..jobs {
+            let tx = tx.clone();
+            let next_jar = &next_jar;
+            scope.spawn(move || {
+                while let Some(jar) = jars.get(next_jar.fetch_add(1, Ordering::Relaxed)) {
+                    if tx.send(verify_jar(provider_factory, *jar)).is_err() {
+                        return
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut verified_blocks = 0;
+        loop {
+            match rx.recv_timeout(PROGRESS_INTERVAL) {
+                Ok(report) => {
+                    let report = report?;
+                    verified_blocks += report.jar.len();
+                    reports.push(report);
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    let progress = verified_blocks as f64 / total_blocks.max(1) as f64 * 100.0;
+                    info!(
+                        target: "reth::cli",
+                        files = reports.len(),
+                        blocks = verified_blocks,
+                        progress = %format!("{progress:.2}%"),
+                        "Verifying static files"
+                    );
+                }
+                // all workers are done
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Ok::<_, eyre::Report>(()),
+            }
+        }
+    })?;
+
+    reports.sort_by_key(|report| (report.jar.segment, report.jar.blocks.start()));
+    Ok(reports)
+}
+
+/// Verifies a static file.
+///
+/// Errors are only returned if the database can't be read, corrupted rows are reported.
+fn verify_jar<N: ProviderNodeTypes>(
+    provider_factory: &ProviderFactory<N>,
+    jar: Jar,
+) -> eyre::Result<JarReport> {
+    let mut report = JarReport { jar, boundary: None, corruption: None };
+
+    // the rows can't be read if the offsets are broken
+    if let Err(err) =
+        provider_factory.static_file_provider().check_jar_offsets(jar.segment, jar.blocks.start())
+    {
+        report.corruption = Some(Corruption::new(jar.blocks.start(), err));
+        return Ok(report)
+    }
+
+    match jar.segment {
+        StaticFileSegment::Headers => match verify_headers(provider_factory, jar) {
+            Ok(boundary) => report.boundary = Some(boundary),
+            Err(corruption) => report.corruption = Some(corruption),
+        },
+        StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
+            report.corruption = verify_block_bodies(provider_factory, jar)?
+        }
+        StaticFileSegment::BlockMeta => {}
+    }
+
+    Ok(report)
+}
+
+/// Verifies the hash and total difficulty of every header of a static file of the headers
+/// segment, and that it's chained to the previous header.
+fn verify_headers<N: ProviderNodeTypes>(
+    provider_factory: &ProviderFactory<N>,
+    jar: Jar,
+) -> Result<HeadersBoundary, Corruption> {
+    let start = jar.blocks.start();
+    let static_file_provider = provider_factory.static_file_provider();
+    let jar_provider = static_file_provider
+        .get_segment_provider_from_block(jar.segment, start, None)
+        .map_err(|err| Corruption::new(start, err))?;
+    let mut cursor = jar_provider.cursor().map_err(|err| Corruption::new(start, err))?;
+
+    let mut boundary: Option<HeadersBoundary> = None;
+    for number in start..=jar.blocks.end() {
+        let (header, hash) = cursor
+            .get_two::<HeaderWithHashMask<HeaderTy<N>>>(number.into())
+            .map_err(|err| Corruption::new(number, err))?
+            .ok_or_else(|| Corruption::new(number, "header is missing"))?;
+        let td = U256::from(
+            cursor
+                .get_one::<TotalDifficultyMask>(number.into())
+                .map_err(|err| Corruption::new(number, err))?
+                .ok_or_else(|| Corruption::new(number, "total difficulty is missing"))?,
+        );
+        if header.number() != number {
+            return Err(Corruption::new(number, format!("header of block {}", header.number())))
+        }
+        let header_hash = header.hash_slow();
+        if header_hash != hash {
+            return Err(Corruption::new(
+                number,
+                format!("stored hash {hash} doesn't match header hash {header_hash}"),
+            ))
+        }
+
+        let parent_td = td.checked_sub(header.difficulty()).ok_or_else(|| {
+            Corruption::new(
+                number,
+                format!("total difficulty {td} is lower than difficulty {}", header.difficulty()),
+            )
+        })?;
+
+        match &mut boundary {
+            Some(boundary) => {
+                if header.parent_hash() != boundary.hash {
+                    return Err(Corruption::new(
+                        number,
+                        format!(
+                            "parent hash {} doesn't match hash {} of the previous block",
+                            header.parent_hash(),
+                            boundary.hash
+                        ),
+                    ))
+                }
+                if parent_td != boundary.td {
+                    return Err(Corruption::new(
+                        number,
+                        format!(
+                            "total difficulty of the previous block is {} instead of {parent_td}",
+                            boundary.td
+                        ),
+                    ))
+                }
+                boundary.hash = hash;
+                boundary.td = td;
+            }
+            None => {
+                boundary =
+                    Some(HeadersBoundary { parent_hash: header.parent_hash(), parent_td, hash, td })
+            }
+        }
+    }
+
+    boundary.ok_or_else(|| Corruption::new(start, "static file has no headers"))
+}
+
+/// Verifies the transactions or receipts of every block of a static file against the header of
+/// the block.
+fn verify_block_bodies<N: ProviderNodeTypes>(
+    provider_factory: &ProviderFactory<N>,
+    jar: Jar,
+) -> eyre::Result<Option<Corruption>> {
+    let start = jar.blocks.start();
+    let chain_spec = provider_factory.chain_spec();
+    let provider = provider_factory.provider()?.disable_long_read_transaction_safety();
+    let static_file_provider = provider_factory.static_file_provider();
+    let jar_provider =
+        match static_file_provider.get_segment_provider_from_block(jar.segment, start, None) {
+            Ok(jar_provider) => jar_provider,
+            Err(err) => return Ok(Some(Corruption::new(start, err))),
+        };
+
+    for number in start..=jar.blocks.end() {
+        let indices = provider
+            .block_body_indices(number)?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(number))?;
+        // a corrupted header is reported by the verification of the headers segment, but the
+        // transactions and receipts of its block can't be verified either
+        let header = match static_file_provider.header_by_number(number) {
+            Ok(Some(header)) => header,
+            Ok(None) => return Ok(Some(Corruption::new(number, "header is missing"))),
+            Err(err) => {
+                return Ok(Some(Corruption::new(number, format!("header can't be read: {err}"))))
+            }
+        };
+
+        let corruption = if jar.segment.is_receipts() {
+            let receipts = match jar_provider.receipts_by_tx_range(indices.tx_num_range()) {
+                Ok(receipts) => receipts,
+                Err(err) => return Ok(Some(Corruption::new(number, err))),
+            };
+            let receipts_with_bloom =
+                receipts.iter().map(TxReceipt::with_bloom_ref).collect::<Vec<_>>();
+            let logs_bloom =
+                receipts_with_bloom.iter().fold(Bloom::ZERO, |bloom, r| bloom | r.bloom());
+
+            if receipts.len() as u64 != indices.tx_count() {
+                Some(format!("{} of {} receipts are stored", receipts.len(), indices.tx_count()))
+            } else if logs_bloom != header.logs_bloom() {
+                Some("logs bloom doesn't match the header".to_string())
+            } else if chain_spec.is_byzantium_active_at_block(number) &&
+                calculate_receipt_root(&receipts_with_bloom) != header.receipts_root()
+            {
+                Some("receipts root doesn't match the header".to_string())
+            } else {
+                None
+            }
+        } else {
+            let transactions = match jar_provider.transactions_by_tx_range(indices.tx_num_range()) {
+                Ok(transactions) => transactions,
+                Err(err) => return Ok(Some(Corruption::new(number, err))),
+            };
+
+            if transactions.len() as u64 != indices.tx_count() {
+                Some(format!(
+                    "{} of {} transactions are stored",
+                    transactions.len(),
+                    indices.tx_count()
+                ))
+            } else if calculate_transaction_root(&transactions) != header.transactions_root() {
+                Some("transactions root doesn't match the header".to_string())
+            } else {
+                None
+            }
+        };
+
+        if let Some(reason) = corruption {
+            return Ok(Some(Corruption::new(number, reason)))
+        }
+    }
+
+    Ok(None)
+}
+
+/// Checks that the first header of every static file of the headers segment is chained to the
+/// last header of the previous file, since the files are verified independently.
+fn check_headers_chaining(reports: &mut [JarReport]) {
+    for i in 1..reports.len() {
+        let (previous, next) = (&reports[i - 1], &reports[i]);
+        if !previous.jar.segment.is_headers() ||
+            !next.jar.segment.is_headers() ||
+            previous.jar.blocks.end() + 1 != next.jar.blocks.start()
+        {
+            continue
+        }
+        let (Some(previous), Some(next_boundary)) = (previous.boundary, next.boundary) else {
+            continue
+        };
+
+        let reason = if next_boundary.parent_hash != previous.hash {
+            format!(
+                "parent hash {} doesn't match hash {} of the previous block",
+                next_boundary.parent_hash, previous.hash
+            )
+        } else if next_boundary.parent_td != previous.td {
+            format!(
+                "total difficulty of the previous block is {} instead of {}",
+                previous.td, next_boundary.parent_td
+            )
+        } else {
+            continue
+        };
+        let next = &mut reports[i];
+        next.corruption = Some(Corruption::new(next.jar.blocks.start(), reason));
+        next.boundary = None;
+    }
+}
+
+/// Truncates a segment to the block before the given block.
+fn truncate_segment<N: ProviderNodeTypes>(
+    provider_factory: &ProviderFactory<N>,
+    segment: StaticFileSegment,
+    block: BlockNumber,
+) -> eyre::Result<()> {
+    if block == 0 {
+        eyre::bail!("cannot truncate the {segment} segment, since its genesis block is corrupted")
+    }
+    let last_block = block - 1;
+
+    let static_file_provider = provider_factory.static_file_provider();
+    // fetching the writer heals the latest static file of the segment, which may decrease its
+    // highest block
+    let mut writer = static_file_provider.latest_writer(segment)?;
+    if segment.is_headers() {
+        let highest_block =
+            static_file_provider.get_highest_static_file_block(segment).unwrap_or_default();
+        writer.prune_headers(highest_block.saturating_sub(last_block))?;
+    } else {
+        let first_tx = provider_factory
+            .provider()?
+            .block_body_indices(block)?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(block))?
+            .first_tx_num();
+        let to_delete = static_file_provider
+            .get_highest_static_file_tx(segment)
+            .map_or(0, |highest_tx| (highest_tx + 1).saturating_sub(first_tx));
+        if segment.is_receipts() {
+            writer.prune_receipts(to_delete, last_block)?;
+        } else {
+            writer.prune_transactions(to_delete, last_block)?;
+        }
+    }
+    writer.commit()?;
+
+    info!(target: "reth::cli", %segment, last_block, "Truncated static file segment");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_chainspec::ChainSpecBuilder;
+    use reth_db::test_utils::{create_test_rw_db, TempDatabase};
+    use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
+    use reth_nippy_jar::NippyJar;
+    use reth_primitives::{Receipt, SealedBlock};
+    use reth_provider::{test_utils::MockNodeTypesWithDB, BlockWriter, StorageLocation};
+    use reth_static_file_types::SegmentHeader;
+    use reth_testing_utils::generators::{self, random_block, BlockParams};
+    use std::{
+        fs::OpenOptions,
+        io::{Read, Seek, SeekFrom, Write},
+        path::Path,
+    };
+
+    /// Number of blocks in a static file.
+    const BLOCKS_PER_FILE: u64 = 10;
+
+    /// Number of transactions in a block.
+    const TXS_PER_BLOCK: u64 = 2;
+
+    /// Opens the storage with a provider of the static files in `static_files_dir`.
+    fn open_provider_factory(
+        db: Arc<TempDatabase<DatabaseEnv>>,
+        static_files_dir: &Path,
+    ) -> ProviderFactory<MockNodeTypesWithDB> {
+        ProviderFactory::new(
+            db,
+            Arc::new(ChainSpecBuilder::mainnet().byzantium_activated().build()),
+            StaticFileProvider::read_write(static_files_dir)
+                .unwrap()
+                .with_custom_blocks_per_file(BLOCKS_PER_FILE),
+        )
+    }
+
+    /// Writes `blocks` blocks with their transactions and receipts to the static files.
+    fn seed_blocks(provider_factory: &ProviderFactory<MockNodeTypesWithDB>, blocks: u64) {
+        let mut rng = generators::rng();
+        let provider_rw = provider_factory.provider_rw().unwrap();
+        let static_file_provider = provider_factory.static_file_provider();
+
+        let mut parent = B256::ZERO;
+        let mut receipts = Vec::new();
+        for number in 0..blocks {
+            let mut block = random_block(
+                &mut rng,
+                number,
+                BlockParams {
+                    parent: Some(parent),
+                    tx_count: Some(TXS_PER_BLOCK as u8),
+                    ommers_count: Some(0),
+                    ..Default::default()
+                },
+            )
+            .unseal();
+            let block_receipts = (1..=TXS_PER_BLOCK)
+                .map(|i| Receipt {
+                    success: true,
+                    cumulative_gas_used: 21_000 * i,
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>();
+            block.header.receipts_root = calculate_receipt_root(
+                &block_receipts.iter().map(TxReceipt::with_bloom_ref).collect::<Vec<_>>(),
+            );
+            let block = SealedBlock::seal_slow(block);
+            parent = block.hash();
+
+            provider_rw
+                .insert_block(block.try_recover().unwrap(), StorageLocation::StaticFiles)
+                .unwrap();
+            receipts.push(block_receipts);
+        }
+
+        let mut receipts_writer =
+            static_file_provider.latest_writer(StaticFileSegment::Receipts).unwrap();
+        for (number, block_receipts) in receipts.iter().enumerate() {
+            receipts_writer.increment_block(number as u64).unwrap();
+            for (i, receipt) in block_receipts.iter().enumerate() {
+                receipts_writer
+                    .append_receipt(number as u64 * TXS_PER_BLOCK + i as u64, receipt)
+                    .unwrap();
+            }
+        }
+        drop(receipts_writer);
+
+        static_file_provider.commit().unwrap();
+        provider_rw.commit().unwrap();
+    }
+
+    /// Flips the byte at `position` of the value with the given index, relative to the start of
+    /// the value if `position` isn't negative or to its end otherwise, in the data file of the
+    /// static file containing `block`.
+    fn corrupt_value(
+        static_files_dir: &Path,
+        segment: StaticFileSegment,
+        block: BlockNumber,
+        index: usize,
+        position: i64,
+    ) {
+        let start = block / BLOCKS_PER_FILE * BLOCKS_PER_FILE;
+        let blocks = SegmentRangeInclusive::new(start, start + BLOCKS_PER_FILE - 1);
+        let jar =
+            NippyJar::<SegmentHeader>::load(&static_files_dir.join(segment.filename(&blocks)))
+                .unwrap();
+        let reader = jar.open_data_reader().unwrap();
+        let offset = if position < 0 {
+            reader.offset(index + 1).unwrap() as i64 + position
+        } else {
+            reader.offset(index).unwrap() as i64 + position
+        };
+
+        let mut data_file =
+            OpenOptions::new().read(true).write(true).open(jar.data_path()).unwrap();
+        let mut byte = [0];
+        data_file.seek(SeekFrom::Start(offset as u64)).unwrap();
+        data_file.read_exact(&mut byte).unwrap();
+        byte[0] ^= 0xff;
+        data_file.seek(SeekFrom::Start(offset as u64)).unwrap();
+        data_file.write_all(&byte).unwrap();
+        data_file.sync_all().unwrap();
+    }
+
+    #[test]
+    fn parse_verify_args() {
+        let args: Command<EthereumChainSpecParser> =
+            Command::parse_from(["reth", "--segments", "headers,receipts", "--repair"]);
+        assert_eq!(args.segments, [StaticFileSegment::Headers, StaticFileSegment::Receipts]);
+        assert!(args.repair);
+        assert_eq!(args.jobs, None);
+    }
+
+    #[test]
+    fn verify_and_repair_corrupted_static_files() {
+        let db = create_test_rw_db();
+        let static_files_dir = tempfile::tempdir().unwrap();
+        seed_blocks(&open_provider_factory(db.clone(), static_files_dir.path()), 30);
+
+        Command::<EthereumChainSpecParser>::parse_from(["reth", "--jobs", "2"])
+            .verify(&open_provider_factory(db.clone(), static_files_dir.path()))
+            .unwrap();
+
+        // The headers are compressed with lz4, which stores the incompressible hash of block 15
+        // as literals ending with its last byte.
+        corrupt_value(
+            static_files_dir.path(),
+            StaticFileSegment::Headers,
+            15,
+            (15 % BLOCKS_PER_FILE) as usize * StaticFileSegment::Headers.columns() + 2,
+            -1,
+        );
+        // The signature of the first transaction of block 22 follows its flags byte.
+        corrupt_value(
+            static_files_dir.path(),
+            StaticFileSegment::Transactions,
+            22,
+            ((22 % BLOCKS_PER_FILE) * TXS_PER_BLOCK) as usize,
+            1,
+        );
+        // The first receipt of block 7 ends with its cumulative gas used, followed by the number
+        // of its logs.
+        corrupt_value(
+            static_files_dir.path(),
+            StaticFileSegment::Receipts,
+            7,
+            ((7 % BLOCKS_PER_FILE) * TXS_PER_BLOCK) as usize,
+            -2,
+        );
+
+        // without `--repair`, the static files are left as is
+        let provider_factory = open_provider_factory(db.clone(), static_files_dir.path());
+        let err = Command::<EthereumChainSpecParser>::parse_from(["reth"])
+            .verify(&provider_factory)
+            .unwrap_err();
+        assert!(err.to_string().contains("segments headers, transactions, receipts"), "{err}");
+        for segment in [
+            StaticFileSegment::Headers,
+            StaticFileSegment::Transactions,
+            StaticFileSegment::Receipts,
+        ] {
+            assert_eq!(
+                provider_factory.static_file_provider().get_highest_static_file_block(segment),
+                Some(29)
+            );
+        }
+        drop(provider_factory);
+
+        // every segment is truncated to the block before its first corrupted block
+        Command::<EthereumChainSpecParser>::parse_from(["reth", "--repair"])
+            .verify(&open_provider_factory(db.clone(), static_files_dir.path()))
+            .unwrap();
+
+        let provider_factory = open_provider_factory(db, static_files_dir.path());
+        let static_file_provider = provider_factory.static_file_provider();
+        for (segment, last_block) in [
+            (StaticFileSegment::Headers, 14),
+            (StaticFileSegment::Transactions, 21),
+            (StaticFileSegment::Receipts, 6),
+        ] {
+            assert_eq!(
+                static_file_provider.get_highest_static_file_block(segment),
+                Some(last_block),
+                "{segment}"
+            );
+        }
+        assert_eq!(
+            static_file_provider.get_highest_static_file_tx(StaticFileSegment::Receipts),
+            Some(7 * TXS_PER_BLOCK - 1)
+        );
+
+        // the rest of the headers is intact
+        Command::<EthereumChainSpecParser>::parse_from(["reth", "--segments", "headers"])
+            .verify(&provider_factory)
+            .unwrap();
+    }
+}
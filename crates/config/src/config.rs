@@ -4,6 +4,7 @@ use eyre::eyre;
 use reth_network_types::{PeersConfig, SessionsConfig};
 use reth_prune_types::PruneModes;
 use reth_stages_types::ExecutionStageThresholds;
+use reth_transaction_pool::admission::AdmissionConfig;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::{
     ffi::OsStr,
@@ -31,6 +32,8 @@ pub struct Config {
     pub peers: PeersConfig,
     /// Configuration for peer sessions.
     pub sessions: SessionsConfig,
+    /// Configuration for the transaction pool.
+    pub txpool: TxPoolConfig,
 }
 
 impl Config {
@@ -98,6 +101,8 @@ impl Config {
 #[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default)]
 pub struct StageConfig {
+    /// S3 stage configuration.
+    pub s3: S3Config,
     /// Header stage configuration.
     pub headers: HeadersConfig,
     /// Body stage configuration.
@@ -120,6 +125,8 @@ pub struct StageConfig {
     pub index_account_history: IndexHistoryConfig,
     /// Index Storage History stage configuration.
     pub index_storage_history: IndexHistoryConfig,
+    /// Index Logs stage configuration.
+    pub index_logs: IndexHistoryConfig,
     /// Common ETL related configuration.
     pub etl: EtlConfig,
 }
@@ -137,6 +144,24 @@ impl StageConfig {
     }
 }
 
+/// S3 stage configuration.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct S3Config {
+    /// The URL of the server to download static files from before syncing from the network.
+    ///
+    /// The stage is disabled if no URL is set.
+    pub url: Option<String>,
+    /// The maximum number of concurrent requests per downloaded file.
+    pub max_concurrent_requests: u64,
+}
+
+impl Default for S3Config {
+    fn default() -> Self {
+        Self { url: None, max_concurrent_requests: 8 }
+    }
+}
+
 /// Header stage configuration.
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default)]
@@ -409,6 +434,8 @@ impl PruneConfig {
                     receipts,
                     account_history,
                     storage_history,
+                    address_transactions,
+                    log_index,
                     receipts_log_filter,
                 },
         } = other;
@@ -424,6 +451,9 @@ impl PruneConfig {
         self.segments.receipts = self.segments.receipts.or(receipts);
         self.segments.account_history = self.segments.account_history.or(account_history);
         self.segments.storage_history = self.segments.storage_history.or(storage_history);
+        self.segments.address_transactions =
+            self.segments.address_transactions.or(address_transactions);
+        self.segments.log_index = self.segments.log_index.or(log_index);
 
         if self.segments.receipts_log_filter.0.is_empty() && !receipts_log_filter.0.is_empty() {
             self.segments.receipts_log_filter = receipts_log_filter;
@@ -431,6 +461,16 @@ impl PruneConfig {
     }
 }
 
+/// Transaction pool configuration.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct TxPoolConfig {
+    /// Rules for admitting transactions into the pool.
+    ///
+    /// Changes to the rules are picked up by a running node without a restart.
+    pub admission: AdmissionConfig,
+}
+
 /// Helper type to support older versions of Duration deserialization.
 fn deserialize_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
 where
@@ -456,6 +496,7 @@ mod tests {
     use alloy_primitives::Address;
     use reth_network_peers::TrustedPeer;
     use reth_prune_types::{PruneMode, PruneModes, ReceiptsLogPruneConfig};
+    use reth_transaction_pool::admission::RateLimit;
     use std::{collections::BTreeMap, path::Path, str::FromStr, time::Duration};
 
     fn with_tempdir(filename: &str, proc: fn(&std::path::Path)) {
@@ -943,6 +984,8 @@ receipts = 'full'
                 receipts: Some(PruneMode::Distance(1000)),
                 account_history: None,
                 storage_history: Some(PruneMode::Before(5000)),
+                address_transactions: None,
+                log_index: None,
                 receipts_log_filter: ReceiptsLogPruneConfig(BTreeMap::from([(
                     Address::random(),
                     PruneMode::Full,
@@ -958,6 +1001,8 @@ receipts = 'full'
                 receipts: Some(PruneMode::Full),
                 account_history: Some(PruneMode::Distance(2000)),
                 storage_history: Some(PruneMode::Distance(3000)),
+                address_transactions: Some(PruneMode::Distance(4000)),
+                log_index: Some(PruneMode::Distance(5000)),
                 receipts_log_filter: ReceiptsLogPruneConfig(BTreeMap::from([
                     (Address::random(), PruneMode::Distance(1000)),
                     (Address::random(), PruneMode::Before(2000)),
@@ -976,6 +1021,8 @@ receipts = 'full'
         assert_eq!(config1.segments.receipts, Some(PruneMode::Distance(1000)));
         assert_eq!(config1.segments.account_history, Some(PruneMode::Distance(2000)));
         assert_eq!(config1.segments.storage_history, Some(PruneMode::Before(5000)));
+        assert_eq!(config1.segments.address_transactions, Some(PruneMode::Distance(4000)));
+        assert_eq!(config1.segments.log_index, Some(PruneMode::Distance(5000)));
         assert_eq!(config1.segments.receipts_log_filter, original_filter);
     }
 
@@ -996,6 +1043,38 @@ connect_trusted_nodes_only = true
         assert!(conf.peers.trusted_nodes_only);
     }
 
+    #[test]
+    fn test_txpool_admission() {
+        let reth_toml = r#"
+[txpool.admission]
+deny_senders = ["0x0000000000000000000000000000000000000001"]
+deny_selectors = ["0xa9059cbb"]
+reserved_senders = ["0x0000000000000000000000000000000000000002"]
+reserved_slots = 1000
+
+[txpool.admission.external]
+max_txs = 5000
+max_gas = 300000000
+rate_limit = { max_txs = 10, interval = "1s" }
+"#;
+
+        let conf: Config = toml::from_str(reth_toml).unwrap();
+        let admission = &conf.txpool.admission;
+        assert_eq!(admission.deny_senders.len(), 1);
+        assert_eq!(admission.deny_selectors.len(), 1);
+        assert_eq!(admission.reserved_slots, 1000);
+        assert_eq!(admission.external.max_txs, Some(5000));
+        assert_eq!(admission.external.max_gas, Some(300_000_000));
+        assert_eq!(
+            admission.external.rate_limit,
+            Some(RateLimit { max_txs: 10, interval: Duration::from_secs(1) })
+        );
+        assert_eq!(admission.local, Default::default());
+
+        let serialized = toml::to_string(&conf).unwrap();
+        assert_eq!(toml::from_str::<Config>(&serialized).unwrap(), conf);
+    }
+
     #[test]
     fn test_can_support_dns_in_trusted_nodes() {
         let reth_toml = r#"
@@ -16,9 +16,13 @@ use reth_node_builder::{rpc::RethRpcAddOns, FullNode, NodeTypes, NodeTypesWithEn
 use reth_node_core::primitives::SignedTransaction;
 use reth_payload_primitives::{BuiltPayload, PayloadBuilderAttributes};
 use reth_provider::{
-    BlockReader, BlockReaderIdExt, CanonStateSubscriptions, StageCheckpointReader,
+    BlockReader, BlockReaderIdExt, CanonStateSubscriptions, ChangeSetReader,
+    HashedStateRangeReader, PruneCheckpointReader, StageCheckpointReader, StorageChangeSetReader,
+};
+use reth_rpc_eth_api::{
+    helpers::{EthApiSpec, EthTransactions, TraceExt},
+    RpcNodeCore,
 };
-use reth_rpc_eth_api::helpers::{EthApiSpec, EthTransactions, TraceExt};
 use reth_stages_types::StageId;
 use std::{marker::PhantomData, pin::Pin};
 use tokio_stream::StreamExt;
@@ -99,6 +103,10 @@ where
         AddOns::EthApi: EthApiSpec<Provider: BlockReader<Block = BlockTy<Node::Types>>>
             + EthTransactions
             + TraceExt,
+        <AddOns::EthApi as RpcNodeCore>::Provider: ChangeSetReader
+            + HashedStateRangeReader
+            + PruneCheckpointReader
+            + StorageChangeSetReader,
     {
         let mut chain = Vec::with_capacity(length as usize);
         for i in 0..length {
@@ -4,7 +4,10 @@ use alloy_primitives::{Bytes, B256};
 use reth_chainspec::EthereumHardforks;
 use reth_node_api::{BlockTy, FullNodeComponents};
 use reth_node_builder::{rpc::RpcRegistry, NodeTypes};
-use reth_provider::BlockReader;
+use reth_provider::{
+    BlockReader, ChangeSetReader, HashedStateRangeReader, PruneCheckpointReader,
+    StorageChangeSetReader,
+};
 use reth_rpc_api::DebugApiServer;
 use reth_rpc_eth_api::{
     helpers::{EthApiSpec, EthTransactions, TraceExt},
@@ -22,6 +25,8 @@ where
     EthApi: EthApiSpec<Provider: BlockReader<Block = BlockTy<Node::Types>>>
         + EthTransactions
         + TraceExt,
+    EthApi::Provider:
+        ChangeSetReader + HashedStateRangeReader + PruneCheckpointReader + StorageChangeSetReader,
 {
     /// Injects a raw transaction into the node tx pool via RPC server
     pub async fn inject_tx(&self, raw_tx: Bytes) -> Result<B256, EthApi::Error> {
@@ -5,7 +5,10 @@ use alloy_primitives::{TxHash, B256};
 use alloy_rpc_types_engine::ForkchoiceState;
 use eyre::OptionExt;
 use futures_util::{stream::Fuse, StreamExt};
-use reth_engine_primitives::{BeaconEngineMessage, EngineTypes};
+use reth_engine_primitives::{
+    BeaconEngineMessage, EngineTypes, LocalBlockBuilder, LocalMinerError, LocalMinerMessage,
+};
+use reth_engine_tree::engine::EngineApiRequest;
 use reth_payload_builder::PayloadBuilderHandle;
 use reth_payload_primitives::{
     BuiltPayload, EngineApiMessageVersion, PayloadAttributesBuilder, PayloadKind, PayloadTypes,
@@ -19,7 +22,10 @@ use std::{
     time::{Duration, UNIX_EPOCH},
 };
 use tokio::{
-    sync::{mpsc::UnboundedSender, oneshot},
+    sync::{
+        mpsc::{UnboundedReceiver, UnboundedSender},
+        oneshot,
+    },
     time::Interval,
 };
 use tokio_stream::wrappers::ReceiverStream;
@@ -72,6 +78,9 @@ impl Future for MiningMode {
     }
 }
 
+/// Primitive types of the blocks built by the payload builder of the engine.
+type PrimitivesOf<EngineT> = <<EngineT as PayloadTypes>::BuiltPayload as BuiltPayload>::Primitives;
+
 /// Local miner advancing the chain/
 #[derive(Debug)]
 pub struct LocalMiner<EngineT: EngineTypes, B> {
@@ -79,12 +88,27 @@ pub struct LocalMiner<EngineT: EngineTypes, B> {
     payload_attributes_builder: B,
     /// Sender for events to engine.
     to_engine: UnboundedSender<BeaconEngineMessage<EngineT>>,
+    /// Sender for requests to the tree, e.g. already executed blocks to insert.
+    to_tree: UnboundedSender<EngineApiRequest<EngineT, PrimitivesOf<EngineT>>>,
+    /// Receiver for messages from [`LocalMinerHandle`](reth_engine_primitives::LocalMinerHandle)s.
+    from_handle: UnboundedReceiver<LocalMinerMessage<PrimitivesOf<EngineT>>>,
     /// The mining mode for the engine
     mode: MiningMode,
+    /// Whether blocks are mined by the mining mode.
+    auto_mine: bool,
+    /// Whether built payloads that carry their executed block are inserted into the tree directly
+    /// instead of through newPayload.
+    insert_executed_blocks: bool,
     /// The payload builder for the engine
     payload_builder: PayloadBuilderHandle<EngineT>,
     /// Timestamp for the next block.
     last_timestamp: u64,
+    /// Offset of the clock of the miner from the system time in seconds.
+    time_offset: i64,
+    /// Exact timestamp of the next block, if set.
+    next_timestamp: Option<u64>,
+    /// Fixed difference between the timestamps of consecutive blocks, if set.
+    timestamp_interval: Option<u64>,
     /// Stores latest mined blocks.
     last_block_hashes: Vec<B256>,
 }
@@ -99,6 +123,8 @@ where
         provider: impl BlockReader,
         payload_attributes_builder: B,
         to_engine: UnboundedSender<BeaconEngineMessage<EngineT>>,
+        to_tree: UnboundedSender<EngineApiRequest<EngineT, PrimitivesOf<EngineT>>>,
+        from_handle: UnboundedReceiver<LocalMinerMessage<PrimitivesOf<EngineT>>>,
         mode: MiningMode,
         payload_builder: PayloadBuilderHandle<EngineT>,
     ) {
@@ -108,9 +134,16 @@ where
         let miner = Self {
             payload_attributes_builder,
             to_engine,
+            to_tree,
+            from_handle,
             mode,
+            auto_mine: true,
+            insert_executed_blocks: false,
             payload_builder,
             last_timestamp: latest_header.timestamp(),
+            time_offset: 0,
+            next_timestamp: None,
+            timestamp_interval: None,
             last_block_hashes: vec![latest_header.hash()],
         };
 
@@ -124,11 +157,13 @@ where
         loop {
             tokio::select! {
                 // Wait for the interval or the pool to receive a transaction
-                _ = &mut self.mode => {
+                _ = &mut self.mode, if self.auto_mine => {
                     if let Err(e) = self.advance().await {
                         error!(target: "engine::local", "Error advancing the chain: {:?}", e);
                     }
                 }
+                // Handle requests from the dev RPC namespaces
+                Some(msg) = self.from_handle.recv() => self.on_message(msg).await,
                 // send FCU once in a while
                 _ = fcu_interval.tick() => {
                     if let Err(e) = self.update_forkchoice_state().await {
@@ -139,6 +174,142 @@ where
         }
     }
 
+    /// Handles a message from a [`LocalMinerHandle`](reth_engine_primitives::LocalMinerHandle).
+    async fn on_message(&mut self, msg: LocalMinerMessage<PrimitivesOf<EngineT>>) {
+        match msg {
+            LocalMinerMessage::Mine { blocks, interval, tx } => {
+                let _ = tx.send(self.mine(blocks, interval).await);
+            }
+            LocalMinerMessage::InsertBlock { builder, tx } => {
+                let _ = tx.send(self.insert_block(builder).await);
+            }
+            LocalMinerMessage::Revert { block_hash, timestamp, tx } => {
+                let _ = tx.send(self.revert(block_hash, timestamp).await);
+            }
+            LocalMinerMessage::SetAutomine { enabled } => self.auto_mine = enabled,
+            LocalMinerMessage::GetAutomine { tx } => {
+                let _ = tx.send(self.auto_mine);
+            }
+            LocalMinerMessage::SetInsertExecutedBlocks { enabled } => {
+                self.insert_executed_blocks = enabled;
+            }
+            LocalMinerMessage::SetIntervalMining { interval } => match interval {
+                Some(interval) => {
+                    self.mode = MiningMode::interval(interval);
+                    self.auto_mine = true;
+                }
+                None => self.auto_mine = false,
+            },
+            LocalMinerMessage::SetNextBlockTimestamp { timestamp, tx } => {
+                let res = if timestamp <= self.last_timestamp {
+                    Err(LocalMinerError::TimestampTooLow { timestamp, latest: self.last_timestamp })
+                } else {
+                    self.next_timestamp = Some(timestamp);
+                    Ok(())
+                };
+                let _ = tx.send(res);
+            }
+            LocalMinerMessage::IncreaseTime { seconds, tx } => {
+                self.time_offset = self.time_offset.saturating_add_unsigned(seconds);
+                let _ = tx.send(self.time_offset);
+            }
+            LocalMinerMessage::SetTime { timestamp, tx } => {
+                let offset = (timestamp as i64).saturating_sub(unix_timestamp() as i64);
+                let _ = tx.send(offset.saturating_sub(self.time_offset));
+                self.time_offset = offset;
+            }
+            LocalMinerMessage::SetBlockTimestampInterval { interval } => {
+                self.timestamp_interval = interval;
+            }
+        }
+    }
+
+    /// Mines the given number of blocks and makes the last one canonical.
+    async fn mine(&mut self, blocks: u64, interval: Option<u64>) -> Result<(), LocalMinerError> {
+        for i in 0..blocks {
+            if let Some(interval) = interval.filter(|_| i > 0) {
+                self.next_timestamp = Some(self.last_timestamp + interval);
+            }
+            self.advance().await.map_err(|err| LocalMinerError::Internal(err.into()))?;
+        }
+        self.update_forkchoice_state().await.map_err(|err| LocalMinerError::Internal(err.into()))
+    }
+
+    /// Builds a block with the given builder on top of the current head, inserts it into the tree
+    /// and makes it canonical.
+    async fn insert_block(
+        &mut self,
+        builder: LocalBlockBuilder<PrimitivesOf<EngineT>>,
+    ) -> Result<B256, LocalMinerError> {
+        let parent_hash = *self.last_block_hashes.last().expect("at least 1 block exists");
+        let block = builder(parent_hash, self.next_block_timestamp())?;
+        let block_hash = block.recovered_block().hash();
+        let block_parent_hash = block.recovered_block().parent_hash();
+        let timestamp = block.recovered_block().timestamp();
+
+        self.to_tree
+            .send(EngineApiRequest::InsertExecutedBlock(block))
+            .map_err(|_| LocalMinerError::MinerUnavailable)?;
+
+        // the builder may build on an ancestor of the head, in which case the chain is reorged
+        match self.last_block_hashes.iter().rposition(|hash| *hash == block_parent_hash) {
+            Some(idx) => self.last_block_hashes.truncate(idx + 1),
+            None => self.last_block_hashes.clear(),
+        }
+        self.push_block(block_hash, timestamp);
+
+        self.update_forkchoice_state()
+            .await
+            .map_err(|err| LocalMinerError::Internal(err.into()))?;
+
+        Ok(block_hash)
+    }
+
+    /// Unwinds the tree to the given ancestor of the head and makes it canonical again.
+    async fn revert(&mut self, block_hash: B256, timestamp: u64) -> Result<(), LocalMinerError> {
+        let (tx, rx) = oneshot::channel();
+        self.to_tree
+            .send(EngineApiRequest::UnwindCanonicalHead { block_hash, tx })
+            .map_err(|_| LocalMinerError::MinerUnavailable)?;
+        rx.await
+            .map_err(|_| LocalMinerError::MinerUnavailable)?
+            .map_err(LocalMinerError::internal)?;
+
+        match self.last_block_hashes.iter().rposition(|hash| *hash == block_hash) {
+            Some(idx) => self.last_block_hashes.truncate(idx + 1),
+            None => self.last_block_hashes = vec![block_hash],
+        }
+        self.last_timestamp = timestamp;
+        self.next_timestamp = None;
+
+        self.update_forkchoice_state().await.map_err(|err| LocalMinerError::Internal(err.into()))
+    }
+
+    /// Returns the timestamp of the next block.
+    fn next_block_timestamp(&mut self) -> u64 {
+        if let Some(timestamp) = self.next_timestamp.take() {
+            return timestamp
+        }
+        if let Some(interval) = self.timestamp_interval {
+            return self.last_timestamp + interval
+        }
+        std::cmp::max(
+            self.last_timestamp + 1,
+            unix_timestamp().saturating_add_signed(self.time_offset),
+        )
+    }
+
+    /// Records a new head block.
+    fn push_block(&mut self, hash: B256, timestamp: u64) {
+        self.last_timestamp = timestamp;
+        self.last_block_hashes.push(hash);
+        // ensure we keep at most 64 blocks
+        if self.last_block_hashes.len() > 64 {
+            self.last_block_hashes =
+                self.last_block_hashes.split_off(self.last_block_hashes.len() - 64);
+        }
+    }
+
     /// Returns current forkchoice state.
     fn forkchoice_state(&self) -> ForkchoiceState {
         ForkchoiceState {
@@ -174,14 +345,11 @@ where
 
     /// Generates payload attributes for a new block, passes them to FCU and inserts built payload
     /// through newPayload.
+    ///
+    /// If enabled, payloads that carry their executed block are inserted into the tree directly,
+    /// since they may contain impersonated transactions that can't be re-executed.
     async fn advance(&mut self) -> eyre::Result<()> {
-        let timestamp = std::cmp::max(
-            self.last_timestamp + 1,
-            std::time::SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("cannot be earlier than UNIX_EPOCH")
-                .as_secs(),
-        );
+        let timestamp = self.next_block_timestamp();
 
         let (tx, rx) = oneshot::channel();
         self.to_engine.send(BeaconEngineMessage::ForkchoiceUpdated {
@@ -204,26 +372,35 @@ where
             eyre::bail!("No payload")
         };
 
-        let block = payload.block();
+        let block_hash = payload.block().hash();
 
-        let (tx, rx) = oneshot::channel();
-        let payload = EngineT::block_to_payload(payload.block().clone());
-        self.to_engine.send(BeaconEngineMessage::NewPayload { payload, tx })?;
+        if let Some(executed_block) =
+            payload.executed_block().filter(|_| self.insert_executed_blocks)
+        {
+            // the payload builder already executed the block, insert it into the tree directly
+            self.to_tree.send(EngineApiRequest::InsertExecutedBlock(executed_block))?;
+        } else {
+            let (tx, rx) = oneshot::channel();
+            let payload = EngineT::block_to_payload(payload.block().clone());
+            self.to_engine.send(BeaconEngineMessage::NewPayload { payload, tx })?;
 
-        let res = rx.await??;
+            let res = rx.await??;
 
-        if !res.is_valid() {
-            eyre::bail!("Invalid payload")
+            if !res.is_valid() {
+                eyre::bail!("Invalid payload")
+            }
         }
 
-        self.last_timestamp = timestamp;
-        self.last_block_hashes.push(block.hash());
-        // ensure we keep at most 64 blocks
-        if self.last_block_hashes.len() > 64 {
-            self.last_block_hashes =
-                self.last_block_hashes.split_off(self.last_block_hashes.len() - 64);
-        }
+        self.push_block(block_hash, timestamp);
 
         Ok(())
     }
 }
+
+/// Returns the current system time in seconds since the UNIX epoch.
+fn unix_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("cannot be earlier than UNIX_EPOCH")
+        .as_secs()
+}
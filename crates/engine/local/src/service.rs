@@ -18,7 +18,9 @@ use crate::miner::{LocalMiner, MiningMode};
 use futures_util::{Stream, StreamExt};
 use reth_chainspec::EthChainSpec;
 use reth_consensus::{ConsensusError, FullConsensus};
-use reth_engine_primitives::{BeaconConsensusEngineEvent, BeaconEngineMessage, EngineValidator};
+use reth_engine_primitives::{
+    BeaconConsensusEngineEvent, BeaconEngineMessage, EngineValidator, LocalMinerHandle,
+};
 use reth_engine_service::service::EngineMessageStream;
 use reth_engine_tree::{
     chain::{ChainEvent, HandlerEvent},
@@ -39,7 +41,7 @@ use reth_provider::{
 };
 use reth_prune::PrunerWithFactory;
 use reth_stages_api::MetricEventsSender;
-use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 use tracing::error;
 
 /// Provides a local dev service engine that can be used to drive the
@@ -57,6 +59,11 @@ where
     handler: EngineApiRequestHandler<EngineApiRequest<N::Engine, N::Primitives>, N::Primitives>,
     /// Receiver for incoming requests (from the engine API endpoint) that need to be processed.
     incoming_requests: EngineMessageStream<N::Engine>,
+    /// Receiver for requests of the local miner to the tree, e.g. already executed blocks it
+    /// built.
+    miner_requests: UnboundedReceiver<EngineApiRequest<N::Engine, N::Primitives>>,
+    /// Handle to the local miner.
+    miner_handle: LocalMinerHandle<N::Primitives>,
 }
 
 impl<N> LocalEngineService<N>
@@ -112,15 +119,30 @@ where
 
         let handler = EngineApiRequestHandler::new(to_tree_tx, from_tree);
 
+        let (to_tree, miner_requests) = mpsc::unbounded_channel();
+        let (to_miner, from_handle) = mpsc::unbounded_channel();
+
         LocalMiner::spawn_new(
             blockchain_db,
             payload_attributes_builder,
             to_engine,
+            to_tree,
+            from_handle,
             mode,
             payload_builder,
         );
 
-        Self { handler, incoming_requests: from_engine }
+        Self {
+            handler,
+            incoming_requests: from_engine,
+            miner_requests,
+            miner_handle: LocalMinerHandle::new(to_miner),
+        }
+    }
+
+    /// Returns a handle to the local miner.
+    pub fn miner_handle(&self) -> LocalMinerHandle<N::Primitives> {
+        self.miner_handle.clone()
     }
 }
 
@@ -150,6 +172,12 @@ where
             }
         }
 
+        // forward requests of the miner first, e.g. the blocks it built must be known to the tree
+        // before the miner's forkchoice update arrives
+        while let Poll::Ready(Some(req)) = this.miner_requests.poll_recv(cx) {
+            this.handler.on_event(FromEngine::Request(req));
+        }
+
         // forward incoming requests to the handler
         while let Poll::Ready(Some(req)) = this.incoming_requests.poll_next_unpin(cx) {
             this.handler.on_event(FromEngine::Request(req.into()));
@@ -40,6 +40,9 @@ pub use event::*;
 mod invalid_block_hook;
 pub use invalid_block_hook::InvalidBlockHook;
 
+mod local_miner;
+pub use local_miner::*;
+
 /// An execution payload.
 pub trait ExecutionPayload:
     Serialize + DeserializeOwned + Debug + Clone + Send + Sync + 'static
@@ -0,0 +1,277 @@
+//! Types for controlling the local miner of a dev node.
+
+use alloc::boxed::Box;
+use alloy_primitives::B256;
+use core::{fmt, time::Duration};
+use reth_chain_state::ExecutedBlockWithTrieUpdates;
+use reth_primitives::NodePrimitives;
+use tokio::sync::{mpsc::UnboundedSender, oneshot};
+
+/// A function that builds an already executed block.
+///
+/// The function is called with the hash of the current head block and the timestamp the local miner
+/// picked for the next block.
+pub type LocalBlockBuilder<N> =
+    Box<dyn FnOnce(B256, u64) -> Result<ExecutedBlockWithTrieUpdates<N>, LocalMinerError> + Send>;
+
+/// Represents error cases of requests to the local miner.
+#[derive(Debug, thiserror::Error)]
+pub enum LocalMinerError {
+    /// Thrown when the local miner task is unavailable/stopped.
+    #[error("local miner task stopped")]
+    MinerUnavailable,
+    /// Thrown when the requested timestamp of the next block is not greater than the timestamp of
+    /// the latest block.
+    #[error("timestamp {timestamp} is not greater than the latest block timestamp {latest}")]
+    TimestampTooLow {
+        /// The requested timestamp.
+        timestamp: u64,
+        /// The timestamp of the latest block.
+        latest: u64,
+    },
+    /// An internal error occurred while mining.
+    #[error(transparent)]
+    Internal(Box<dyn core::error::Error + Send + Sync>),
+}
+
+impl LocalMinerError {
+    /// Create a new internal error.
+    pub fn internal<E: core::error::Error + Send + Sync + 'static>(e: E) -> Self {
+        Self::Internal(Box::new(e))
+    }
+}
+
+/// A message for the local miner of a dev node.
+pub enum LocalMinerMessage<N: NodePrimitives> {
+    /// Mines blocks, regardless of the mining mode.
+    Mine {
+        /// The number of blocks to mine.
+        blocks: u64,
+        /// The difference between the timestamps of consecutive blocks, if set.
+        interval: Option<u64>,
+        /// The sender for returning the result.
+        tx: oneshot::Sender<Result<(), LocalMinerError>>,
+    },
+    /// Builds a block with the given function and makes it the new head of the chain.
+    InsertBlock {
+        /// The function building the block.
+        builder: LocalBlockBuilder<N>,
+        /// The sender for returning the hash of the inserted block.
+        tx: oneshot::Sender<Result<B256, LocalMinerError>>,
+    },
+    /// Unwinds the chain to an ancestor of the head, which becomes the head again.
+    Revert {
+        /// The hash of the new head.
+        block_hash: B256,
+        /// The timestamp of the new head.
+        timestamp: u64,
+        /// The sender for returning the result.
+        tx: oneshot::Sender<Result<(), LocalMinerError>>,
+    },
+    /// Enables or disables mining by the configured mining mode.
+    SetAutomine {
+        /// Whether mining by the mining mode is enabled.
+        enabled: bool,
+    },
+    /// Returns whether mining by the configured mining mode is enabled.
+    GetAutomine {
+        /// The sender for returning the result.
+        tx: oneshot::Sender<bool>,
+    },
+    /// Enables or disables inserting built blocks into the tree directly instead of through
+    /// newPayload.
+    ///
+    /// This is required for blocks with impersonated transactions, whose signatures don't recover
+    /// to their senders.
+    SetInsertExecutedBlocks {
+        /// Whether built blocks are inserted directly.
+        enabled: bool,
+    },
+    /// Switches to mining blocks at a fixed interval.
+    ///
+    /// If no interval is set, mining by the mining mode is disabled.
+    SetIntervalMining {
+        /// The interval between blocks.
+        interval: Option<Duration>,
+    },
+    /// Sets the exact timestamp of the next block.
+    SetNextBlockTimestamp {
+        /// The timestamp of the next block.
+        timestamp: u64,
+        /// The sender for returning the result.
+        tx: oneshot::Sender<Result<(), LocalMinerError>>,
+    },
+    /// Moves the clock of the miner forward.
+    IncreaseTime {
+        /// The number of seconds to move the clock by.
+        seconds: u64,
+        /// The sender for returning the total offset of the clock in seconds.
+        tx: oneshot::Sender<i64>,
+    },
+    /// Sets the clock of the miner to the given timestamp.
+    SetTime {
+        /// The new current timestamp.
+        timestamp: u64,
+        /// The sender for returning by how many seconds the clock was moved.
+        tx: oneshot::Sender<i64>,
+    },
+    /// Sets a fixed difference between the timestamps of consecutive blocks.
+    ///
+    /// If no interval is set, the timestamps follow the clock of the miner again.
+    SetBlockTimestampInterval {
+        /// The difference between the timestamps of consecutive blocks.
+        interval: Option<u64>,
+    },
+}
+
+impl<N: NodePrimitives> fmt::Debug for LocalMinerMessage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mine { blocks, interval, .. } => f
+                .debug_struct("Mine")
+                .field("blocks", blocks)
+                .field("interval", interval)
+                .finish_non_exhaustive(),
+            Self::InsertBlock { .. } => f.debug_struct("InsertBlock").finish_non_exhaustive(),
+            Self::Revert { block_hash, timestamp, .. } => f
+                .debug_struct("Revert")
+                .field("block_hash", block_hash)
+                .field("timestamp", timestamp)
+                .finish_non_exhaustive(),
+            Self::SetAutomine { enabled } => {
+                f.debug_struct("SetAutomine").field("enabled", enabled).finish()
+            }
+            Self::GetAutomine { .. } => f.debug_struct("GetAutomine").finish_non_exhaustive(),
+            Self::SetInsertExecutedBlocks { enabled } => {
+                f.debug_struct("SetInsertExecutedBlocks").field("enabled", enabled).finish()
+            }
+            Self::SetIntervalMining { interval } => {
+                f.debug_struct("SetIntervalMining").field("interval", interval).finish()
+            }
+            Self::SetNextBlockTimestamp { timestamp, .. } => f
+                .debug_struct("SetNextBlockTimestamp")
+                .field("timestamp", timestamp)
+                .finish_non_exhaustive(),
+            Self::IncreaseTime { seconds, .. } => {
+                f.debug_struct("IncreaseTime").field("seconds", seconds).finish_non_exhaustive()
+            }
+            Self::SetTime { timestamp, .. } => {
+                f.debug_struct("SetTime").field("timestamp", timestamp).finish_non_exhaustive()
+            }
+            Self::SetBlockTimestampInterval { interval } => {
+                f.debug_struct("SetBlockTimestampInterval").field("interval", interval).finish()
+            }
+        }
+    }
+}
+
+/// A clonable sender type that can be used to control the local miner of a dev node.
+#[derive(Debug)]
+pub struct LocalMinerHandle<N: NodePrimitives> {
+    to_miner: UnboundedSender<LocalMinerMessage<N>>,
+}
+
+impl<N: NodePrimitives> Clone for LocalMinerHandle<N> {
+    fn clone(&self) -> Self {
+        Self { to_miner: self.to_miner.clone() }
+    }
+}
+
+impl<N: NodePrimitives> LocalMinerHandle<N> {
+    /// Creates a new local miner handle.
+    pub const fn new(to_miner: UnboundedSender<LocalMinerMessage<N>>) -> Self {
+        Self { to_miner }
+    }
+
+    /// Mines the given number of blocks and waits until they are canonical.
+    ///
+    /// If an interval is set, the timestamps of consecutive blocks differ by the interval.
+    pub async fn mine(&self, blocks: u64, interval: Option<u64>) -> Result<(), LocalMinerError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(LocalMinerMessage::Mine { blocks, interval, tx })?;
+        rx.await.map_err(|_| LocalMinerError::MinerUnavailable)?
+    }
+
+    /// Builds a block with the given function, makes it the new head of the chain and returns its
+    /// hash.
+    ///
+    /// The block does not have to be a child of the current head, in which case the chain is
+    /// reorged to it.
+    pub async fn insert_block(
+        &self,
+        builder: LocalBlockBuilder<N>,
+    ) -> Result<B256, LocalMinerError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(LocalMinerMessage::InsertBlock { builder, tx })?;
+        rx.await.map_err(|_| LocalMinerError::MinerUnavailable)?
+    }
+
+    /// Unwinds the chain to the given ancestor of the head, which becomes the head again, and waits
+    /// until it is canonical.
+    ///
+    /// The blocks above the new head are removed, their transactions are not re-added to the pool.
+    pub async fn revert(&self, block_hash: B256, timestamp: u64) -> Result<(), LocalMinerError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(LocalMinerMessage::Revert { block_hash, timestamp, tx })?;
+        rx.await.map_err(|_| LocalMinerError::MinerUnavailable)?
+    }
+
+    /// Enables or disables mining by the configured mining mode.
+    pub fn set_automine(&self, enabled: bool) -> Result<(), LocalMinerError> {
+        self.send(LocalMinerMessage::SetAutomine { enabled })
+    }
+
+    /// Returns whether mining by the configured mining mode is enabled.
+    pub async fn automine(&self) -> Result<bool, LocalMinerError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(LocalMinerMessage::GetAutomine { tx })?;
+        rx.await.map_err(|_| LocalMinerError::MinerUnavailable)
+    }
+
+    /// Enables or disables inserting built blocks into the tree directly instead of through
+    /// newPayload.
+    pub fn set_insert_executed_blocks(&self, enabled: bool) -> Result<(), LocalMinerError> {
+        self.send(LocalMinerMessage::SetInsertExecutedBlocks { enabled })
+    }
+
+    /// Switches to mining blocks at the given interval, or disables mining by the mining mode if
+    /// no interval is set.
+    pub fn set_interval_mining(&self, interval: Option<Duration>) -> Result<(), LocalMinerError> {
+        self.send(LocalMinerMessage::SetIntervalMining { interval })
+    }
+
+    /// Sets the exact timestamp of the next block.
+    pub async fn set_next_block_timestamp(&self, timestamp: u64) -> Result<(), LocalMinerError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(LocalMinerMessage::SetNextBlockTimestamp { timestamp, tx })?;
+        rx.await.map_err(|_| LocalMinerError::MinerUnavailable)?
+    }
+
+    /// Moves the clock of the miner forward and returns its total offset in seconds.
+    pub async fn increase_time(&self, seconds: u64) -> Result<i64, LocalMinerError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(LocalMinerMessage::IncreaseTime { seconds, tx })?;
+        rx.await.map_err(|_| LocalMinerError::MinerUnavailable)
+    }
+
+    /// Sets the clock of the miner to the given timestamp and returns by how many seconds the clock
+    /// was moved.
+    pub async fn set_time(&self, timestamp: u64) -> Result<i64, LocalMinerError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(LocalMinerMessage::SetTime { timestamp, tx })?;
+        rx.await.map_err(|_| LocalMinerError::MinerUnavailable)
+    }
+
+    /// Sets a fixed difference between the timestamps of consecutive blocks, or removes it if no
+    /// interval is set.
+    pub fn set_block_timestamp_interval(
+        &self,
+        interval: Option<u64>,
+    ) -> Result<(), LocalMinerError> {
+        self.send(LocalMinerMessage::SetBlockTimestampInterval { interval })
+    }
+
+    fn send(&self, msg: LocalMinerMessage<N>) -> Result<(), LocalMinerError> {
+        self.to_miner.send(msg).map_err(|_| LocalMinerError::MinerUnavailable)
+    }
+}
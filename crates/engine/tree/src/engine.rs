@@ -9,6 +9,7 @@ use alloy_primitives::B256;
 use futures::{Stream, StreamExt};
 use reth_chain_state::ExecutedBlockWithTrieUpdates;
 use reth_engine_primitives::{BeaconConsensusEngineEvent, BeaconEngineMessage, EngineTypes};
+use reth_errors::ProviderResult;
 use reth_ethereum_primitives::EthPrimitives;
 use reth_primitives_traits::{Block, NodePrimitives, RecoveredBlock};
 use std::{
@@ -17,7 +18,7 @@ use std::{
     sync::mpsc::Sender,
     task::{ready, Context, Poll},
 };
-use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::{mpsc::UnboundedReceiver, oneshot};
 
 /// A [`ChainHandler`] that advances the chain based on incoming requests (CL engine API).
 ///
@@ -246,6 +247,22 @@ pub enum EngineApiRequest<T: EngineTypes, N: NodePrimitives> {
     Beacon(BeaconEngineMessage<T>),
     /// Request to insert an already executed block, e.g. via payload building.
     InsertExecutedBlock(ExecutedBlockWithTrieUpdates<N>),
+    /// Request to serve an executed block that is currently being built as the pending block.
+    ///
+    /// The block is only accepted if it extends the canonical head at the time the request is
+    /// handled.
+    SetPendingBlock(ExecutedBlockWithTrieUpdates<N>),
+    /// Request to unwind the canonical chain to one of its ancestors, e.g. when a dev chain is
+    /// reverted to a snapshot.
+    ///
+    /// The blocks above the new head are removed from the canonical chain, including the ones that
+    /// were already persisted.
+    UnwindCanonicalHead {
+        /// The hash of the new canonical head.
+        block_hash: B256,
+        /// The sender for returning the result.
+        tx: oneshot::Sender<ProviderResult<()>>,
+    },
 }
 
 impl<T: EngineTypes, N: NodePrimitives> Display for EngineApiRequest<T, N> {
@@ -255,6 +272,12 @@ impl<T: EngineTypes, N: NodePrimitives> Display for EngineApiRequest<T, N> {
             Self::InsertExecutedBlock(block) => {
                 write!(f, "InsertExecutedBlock({:?})", block.recovered_block().num_hash())
             }
+            Self::SetPendingBlock(block) => {
+                write!(f, "SetPendingBlock({:?})", block.recovered_block().num_hash())
+            }
+            Self::UnwindCanonicalHead { block_hash, .. } => {
+                write!(f, "UnwindCanonicalHead({block_hash})")
+            }
         }
     }
 }
@@ -23,7 +23,7 @@ use error::{InsertBlockError, InsertBlockErrorKind, InsertBlockFatalError};
 use metrics::PrewarmThreadMetrics;
 use persistence_state::CurrentPersistenceAction;
 use reth_chain_state::{
-    CanonicalInMemoryState, ExecutedBlock, ExecutedBlockWithTrieUpdates,
+    CanonStateNotification, CanonicalInMemoryState, ExecutedBlock, ExecutedBlockWithTrieUpdates,
     MemoryOverlayStateProvider, NewCanonicalChain,
 };
 use reth_consensus::{Consensus, FullConsensus};
@@ -46,7 +46,7 @@ use reth_primitives_traits::{
     SignedTransaction,
 };
 use reth_provider::{
-    providers::ConsistentDbView, BlockReader, DBProvider, DatabaseProviderFactory,
+    providers::ConsistentDbView, BlockReader, Chain, DBProvider, DatabaseProviderFactory,
     ExecutionOutcome, HashedPostStateProvider, ProviderError, StateCommitmentProvider,
     StateProviderBox, StateProviderFactory, StateReader, StateRootProvider, TransactionVariant,
 };
@@ -1400,6 +1400,22 @@ where
                             BeaconConsensusEngineEvent::CanonicalBlockAdded(block, now.elapsed()),
                         ));
                     }
+                    EngineApiRequest::SetPendingBlock(block) => {
+                        let block_num_hash = block.recovered_block().num_hash();
+                        // only serve payloads that extend the current canonical head, the head
+                        // may have advanced since the payload was built
+                        if self.state.tree_state.canonical_block_hash() ==
+                            block.recovered_block().parent_hash()
+                        {
+                            debug!(target: "engine::tree", pending=?block_num_hash, "updating pending block");
+                            self.canonical_in_memory_state.set_pending_block(block);
+                        } else {
+                            debug!(target: "engine::tree", pending=?block_num_hash, "discarding stale pending block");
+                        }
+                    }
+                    EngineApiRequest::UnwindCanonicalHead { block_hash, tx } => {
+                        let _ = tx.send(self.unwind_canonical_head(block_hash));
+                    }
                     EngineApiRequest::Beacon(request) => {
                         match request {
                             BeaconEngineMessage::ForkchoiceUpdated {
@@ -2213,6 +2229,78 @@ where
         ));
     }
 
+    /// Unwinds the canonical chain to the given ancestor of the canonical head.
+    ///
+    /// The blocks above the new head are removed from the in-memory canonical chain and a removal
+    /// of the persisted ones is scheduled. Listeners are notified with a reorg whose new chain only
+    /// consists of the new head.
+    fn unwind_canonical_head(&mut self, block_hash: B256) -> ProviderResult<()> {
+        let start = Instant::now();
+        let head = self.state.tree_state.current_canonical_head;
+        if head.hash == block_hash {
+            return Ok(())
+        }
+
+        let new_head = self
+            .find_canonical_header(block_hash)?
+            .ok_or(ProviderError::UnknownBlockHash(block_hash))?;
+        if new_head.number() >= head.number {
+            return Err(ProviderError::UnknownBlockHash(block_hash))
+        }
+
+        // collect the blocks above the new head, the new head must be an ancestor of the head
+        let mut old = Vec::new();
+        let mut current = head.hash;
+        while current != block_hash {
+            let block = self
+                .canonical_block_by_hash(current)?
+                .ok_or(ProviderError::HeaderNotFound(current.into()))?;
+            if block.recovered_block().number() <= new_head.number() {
+                return Err(ProviderError::UnknownBlockHash(block_hash))
+            }
+            current = block.recovered_block().parent_hash();
+            old.push(block);
+        }
+        old.reverse();
+        let new = self
+            .canonical_block_by_hash(block_hash)?
+            .ok_or(ProviderError::HeaderNotFound(block_hash.into()))?;
+
+        debug!(target: "engine::tree", new_head=?new_head.num_hash(), unwound=old.len(), "Unwinding canonical head");
+
+        // the persistence task only removes blocks that are persisted by the time the removal runs
+        self.persistence_state.schedule_removal(new_head.number());
+        self.state.tree_state.set_canonical_head(new_head.num_hash());
+
+        let notification = CanonStateNotification::Reorg {
+            old: Arc::new(old.iter().fold(Chain::default(), |mut chain, block| {
+                chain.append_block(
+                    block.recovered_block().clone(),
+                    block.execution_outcome().clone(),
+                );
+                chain
+            })),
+            new: Arc::new(Chain::from_block(
+                new.recovered_block().clone(),
+                new.execution_outcome().clone(),
+                None,
+            )),
+        };
+
+        self.update_reorg_metrics(old.len());
+        self.canonical_in_memory_state
+            .update_chain(NewCanonicalChain::Reorg { new: Vec::new(), old });
+        self.canonical_in_memory_state.set_canonical_head(new_head.clone());
+        self.metrics.tree.canonical_chain_height.set(new_head.number() as f64);
+        self.canonical_in_memory_state.notify_canon_state(notification);
+        self.emit_event(BeaconConsensusEngineEvent::CanonicalChainCommitted(
+            Box::new(new_head),
+            start.elapsed(),
+        ));
+
+        Ok(())
+    }
+
     /// This updates metrics based on the given reorg length.
     fn update_reorg_metrics(&self, old_chain_length: usize) {
         self.metrics.tree.reorgs.increment(1);
@@ -3768,6 +3856,63 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_set_pending_block_after_head_advanced() {
+        let chain_spec = MAINNET.clone();
+        let mut test_block_builder = TestBlockBuilder::eth().with_chain_spec((*chain_spec).clone());
+        let blocks: Vec<_> = test_block_builder.get_executed_blocks(0..3).collect();
+        let head = blocks.last().unwrap().recovered_block().clone();
+        let mut test_harness = TestHarness::new(chain_spec).with_blocks(blocks);
+
+        // a pending block that extends the canonical head is served
+        let pending = test_block_builder.get_executed_block_with_number(3, head.hash());
+        let pending_num_hash = pending.recovered_block().num_hash();
+        test_harness
+            .tree
+            .on_engine_message(FromEngine::Request(EngineApiRequest::SetPendingBlock(pending)))
+            .unwrap();
+        assert_eq!(
+            test_harness.tree.canonical_in_memory_state.pending_block_num_hash(),
+            Some(pending_num_hash)
+        );
+
+        // advance the canonical head to a sibling of the pending block
+        let new_head = test_block_builder.get_executed_block_with_number(3, head.hash());
+        let new_head_hash = new_head.recovered_block().hash();
+        test_harness
+            .tree
+            .on_engine_message(FromEngine::Request(EngineApiRequest::InsertExecutedBlock(new_head)))
+            .unwrap();
+        // consume the CanonicalBlockAdded event
+        test_harness.from_tree_rx.recv().await.unwrap();
+        test_harness.fcu_to(new_head_hash, ForkchoiceStatus::Valid).await;
+        assert_eq!(test_harness.tree.state.tree_state.canonical_block_hash(), new_head_hash);
+
+        // a payload built on top of the previous head is stale and must not be served
+        let stale = test_block_builder.get_executed_block_with_number(3, head.hash());
+        let stale_num_hash = stale.recovered_block().num_hash();
+        test_harness
+            .tree
+            .on_engine_message(FromEngine::Request(EngineApiRequest::SetPendingBlock(stale)))
+            .unwrap();
+        assert_ne!(
+            test_harness.tree.canonical_in_memory_state.pending_block_num_hash(),
+            Some(stale_num_hash)
+        );
+
+        // a payload built on top of the new head is served
+        let pending = test_block_builder.get_executed_block_with_number(4, new_head_hash);
+        let pending_num_hash = pending.recovered_block().num_hash();
+        test_harness
+            .tree
+            .on_engine_message(FromEngine::Request(EngineApiRequest::SetPendingBlock(pending)))
+            .unwrap();
+        assert_eq!(
+            test_harness.tree.canonical_in_memory_state.pending_block_num_hash(),
+            Some(pending_num_hash)
+        );
+    }
+
     #[tokio::test]
     async fn test_engine_request_during_backfill() {
         let tree_config = TreeConfig::default();
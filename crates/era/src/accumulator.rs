@@ -0,0 +1,52 @@
+use crate::MAX_BLOCKS_PER_ERA1;
+use alloy_primitives::{B256, U256};
+use sha2::{Digest, Sha256};
+
+/// Depth of the merkle tree of the accumulator, `log2(MAX_BLOCKS_PER_ERA1)`.
+const ACCUMULATOR_DEPTH: usize = 13;
+
+/// Hash and total difficulty of a block, the accumulator commits to a list of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeaderRecord {
+    /// Hash of the block.
+    pub block_hash: B256,
+    /// Total difficulty of the chain up to and including the block.
+    pub total_difficulty: U256,
+}
+
+impl HeaderRecord {
+    /// Returns the SSZ hash tree root of the record.
+    fn tree_root(&self) -> B256 {
+        hash_pair(self.block_hash.as_slice(), &self.total_difficulty.to_le_bytes::<32>())
+    }
+}
+
+/// Computes the accumulator of the given records, the SSZ hash tree root of a
+/// `List[HeaderRecord, MAX_BLOCKS_PER_ERA1]`.
+///
+/// # Panics
+///
+/// If more than [`MAX_BLOCKS_PER_ERA1`] records are given.
+pub fn compute_accumulator(records: &[HeaderRecord]) -> B256 {
+    assert!(records.len() <= MAX_BLOCKS_PER_ERA1, "too many header records");
+
+    let mut layer = records.iter().map(HeaderRecord::tree_root).collect::<Vec<_>>();
+    let mut zero_hash = B256::ZERO;
+    for _ in 0..ACCUMULATOR_DEPTH {
+        if layer.len() % 2 == 1 {
+            layer.push(zero_hash);
+        }
+        layer = layer.chunks_exact(2).map(|pair| hash_pair(&pair[0][..], &pair[1][..])).collect();
+        zero_hash = hash_pair(&zero_hash[..], &zero_hash[..]);
+    }
+    let root = layer.first().copied().unwrap_or(zero_hash);
+
+    // mix in the length of the list
+    let mut length = [0u8; 32];
+    length[..8].copy_from_slice(&(records.len() as u64).to_le_bytes());
+    hash_pair(&root[..], &length)
+}
+
+fn hash_pair(left: &[u8], right: &[u8]) -> B256 {
+    B256::from_slice(&Sha256::new().chain_update(left).chain_update(right).finalize())
+}
@@ -0,0 +1,83 @@
+//! The `e2store` container format.
+//!
+//! An `e2store` file is a sequence of records. Each record starts with an 8 byte header that
+//! contains the type of the record as `u16`, the length of its data as `u32` and two reserved
+//! bytes that must be zero, all little endian.
+
+use crate::Era1Error;
+use std::io::{self, Read, Write};
+
+/// Size of the header of an `e2store` record.
+pub const HEADER_SIZE: usize = 8;
+
+/// Type of the version record that starts every `e2store` file.
+pub const VERSION: u16 = 0x3265;
+
+/// A single `e2store` record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Type of the record.
+    pub entry_type: u16,
+    /// Data of the record.
+    pub data: Vec<u8>,
+}
+
+impl Entry {
+    /// Creates a new record of the given type.
+    pub const fn new(entry_type: u16, data: Vec<u8>) -> Self {
+        Self { entry_type, data }
+    }
+
+    /// Returns the number of bytes the record occupies in the file, including its header.
+    pub fn encoded_len(&self) -> usize {
+        HEADER_SIZE + self.data.len()
+    }
+
+    /// Writes the record to the given writer.
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Era1Error> {
+        let len = u32::try_from(self.data.len())
+            .map_err(|_| Era1Error::EntryTooLarge(self.data.len()))?;
+
+        let mut header = [0u8; HEADER_SIZE];
+        header[..2].copy_from_slice(&self.entry_type.to_le_bytes());
+        header[2..6].copy_from_slice(&len.to_le_bytes());
+        writer.write_all(&header)?;
+        writer.write_all(&self.data)?;
+        Ok(())
+    }
+
+    /// Reads the next record from the given reader.
+    ///
+    /// Returns `None` if the reader is exhausted.
+    pub fn read<R: Read>(reader: &mut R) -> Result<Option<Self>, Era1Error> {
+        let mut header = [0u8; HEADER_SIZE];
+        if !read_header(reader, &mut header)? {
+            return Ok(None)
+        }
+
+        let entry_type = u16::from_le_bytes([header[0], header[1]]);
+        let len = u32::from_le_bytes([header[2], header[3], header[4], header[5]]) as usize;
+        if header[6..] != [0, 0] {
+            return Err(Era1Error::NonZeroReserved)
+        }
+
+        let mut data = vec![0u8; len];
+        reader.read_exact(&mut data)?;
+        Ok(Some(Self { entry_type, data }))
+    }
+}
+
+/// Fills the header buffer, returns `false` if the reader was exhausted before the first byte.
+fn read_header<R: Read>(reader: &mut R, header: &mut [u8; HEADER_SIZE]) -> io::Result<bool> {
+    let mut read = 0;
+    while read < HEADER_SIZE {
+        match reader.read(&mut header[read..]) {
+            Ok(0) if read == 0 => return Ok(false),
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => read += n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(true)
+}
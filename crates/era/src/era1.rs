@@ -0,0 +1,444 @@
+use crate::{
+    compute_accumulator,
+    e2s::{self, Entry},
+    Era1Error, HeaderRecord,
+};
+use alloy_primitives::{hex, Bytes, B256, U256};
+use alloy_rlp::Decodable;
+use alloy_trie::root::ordered_trie_root_with_encoder;
+use std::{
+    fs::File,
+    io::{BufReader, Read, Write},
+    path::Path,
+};
+
+/// Maximum number of blocks in an ERA1 file.
+pub const MAX_BLOCKS_PER_ERA1: usize = 8192;
+
+/// Type of the record that contains the snappy compressed RLP header of a block.
+const COMPRESSED_HEADER: u16 = 0x03;
+/// Type of the record that contains the snappy compressed RLP body of a block.
+const COMPRESSED_BODY: u16 = 0x04;
+/// Type of the record that contains the snappy compressed RLP receipts of a block.
+const COMPRESSED_RECEIPTS: u16 = 0x05;
+/// Type of the record that contains the total difficulty of a block.
+const TOTAL_DIFFICULTY: u16 = 0x06;
+/// Type of the record that contains the accumulator of the file.
+const ACCUMULATOR: u16 = 0x07;
+/// Type of the record that contains the offsets of the blocks of the file.
+const BLOCK_INDEX: u16 = 0x3266;
+
+/// Returns the canonical name of an ERA1 file: `<network>-<epoch>-<short accumulator>.era1`.
+pub fn era1_file_name(network: &str, epoch: u64, accumulator: B256) -> String {
+    format!("{network}-{epoch:05}-{}.era1", hex::encode(&accumulator[..4]))
+}
+
+/// A block of an ERA1 file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockTuple {
+    /// The RLP encoded header.
+    pub header: Bytes,
+    /// The RLP encoded body.
+    pub body: Bytes,
+    /// The RLP encoded list of receipts, including their logs blooms.
+    pub receipts: Bytes,
+    /// Total difficulty of the chain up to and including the block.
+    pub total_difficulty: U256,
+}
+
+impl BlockTuple {
+    /// Decodes the header of the block.
+    pub fn decode_header<H: Decodable>(&self) -> Result<H, Era1Error> {
+        Ok(H::decode(&mut self.header.as_ref())?)
+    }
+
+    /// Decodes the body of the block.
+    pub fn decode_body<B: Decodable>(&self) -> Result<B, Era1Error> {
+        Ok(B::decode(&mut self.body.as_ref())?)
+    }
+
+    /// Computes the receipts root of the block.
+    ///
+    /// This only splits the encoded list of receipts and doesn't decode the receipts themselves,
+    /// so it also supports pre-Byzantium receipts that commit to an intermediate state root
+    /// instead of a status.
+    pub fn receipts_root(&self) -> Result<B256, Era1Error> {
+        let mut buf = self.receipts.as_ref();
+        let mut payload = alloy_rlp::Header::decode_bytes(&mut buf, true)?;
+
+        let mut receipts = Vec::new();
+        while !payload.is_empty() {
+            let item = payload;
+            let header = alloy_rlp::Header::decode(&mut payload)?;
+            if payload.len() < header.payload_length {
+                return Err(alloy_rlp::Error::InputTooShort.into())
+            }
+            // legacy receipts are lists, typed receipts are strings with their EIP-2718 encoding
+            receipts.push(if header.list {
+                &item[..item.len() - payload.len() + header.payload_length]
+            } else {
+                &payload[..header.payload_length]
+            });
+            payload = &payload[header.payload_length..];
+        }
+
+        Ok(ordered_trie_root_with_encoder(&receipts, |receipt, buf| buf.extend_from_slice(receipt)))
+    }
+}
+
+/// The index of the blocks of an ERA1 file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockIndex {
+    /// Number of the first block of the file.
+    pub starting_number: u64,
+    /// Offsets of the blocks, relative to the start of the block index record.
+    pub offsets: Vec<i64>,
+}
+
+impl BlockIndex {
+    /// Decodes the block index from the data of its record.
+    fn decode(data: &[u8]) -> Result<Self, Era1Error> {
+        let invalid_length = || Era1Error::InvalidLength { kind: "block index", len: data.len() };
+        if data.len() < 16 || data.len() % 8 != 0 {
+            return Err(invalid_length())
+        }
+
+        let mut words =
+            data.chunks_exact(8).map(|word| <[u8; 8]>::try_from(word).expect("8 bytes"));
+        let starting_number = u64::from_le_bytes(words.next().expect("at least 2 words"));
+        let count = u64::from_le_bytes(words.next_back().expect("at least 2 words"));
+        let offsets = words.map(i64::from_le_bytes).collect::<Vec<_>>();
+        if offsets.len() as u64 != count {
+            return Err(invalid_length())
+        }
+
+        Ok(Self { starting_number, offsets })
+    }
+
+    /// Encodes the block index into the data of its record.
+    fn encode(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(16 + self.offsets.len() * 8);
+        data.extend_from_slice(&self.starting_number.to_le_bytes());
+        for offset in &self.offsets {
+            data.extend_from_slice(&offset.to_le_bytes());
+        }
+        data.extend_from_slice(&(self.offsets.len() as u64).to_le_bytes());
+        data
+    }
+}
+
+/// Reads the blocks of an ERA1 file in order.
+///
+/// The accumulator and the block index are only available once all blocks were read, see
+/// [`Era1Reader::accumulator`].
+#[derive(Debug)]
+pub struct Era1Reader<R> {
+    reader: R,
+    /// Number of bytes read so far.
+    position: u64,
+    /// Offsets of the blocks that were read so far.
+    block_offsets: Vec<u64>,
+    /// The accumulator and block index of the file, once all blocks were read.
+    trailer: Option<(B256, BlockIndex)>,
+}
+
+impl Era1Reader<BufReader<File>> {
+    /// Opens the ERA1 file at the given path.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Era1Error> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> Era1Reader<R> {
+    /// Creates a new reader, reading the version record of the file.
+    pub fn new(reader: R) -> Result<Self, Era1Error> {
+        let mut this = Self { reader, position: 0, block_offsets: Vec::new(), trailer: None };
+        let version = this.read_entry(e2s::VERSION)?;
+        if !version.data.is_empty() {
+            return Err(Era1Error::InvalidLength { kind: "version", len: version.data.len() })
+        }
+        Ok(this)
+    }
+
+    /// Reads the next block of the file.
+    ///
+    /// Returns `None` once all blocks were read, after the block index was validated.
+    pub fn next_block(&mut self) -> Result<Option<BlockTuple>, Era1Error> {
+        if self.trailer.is_some() {
+            return Ok(None)
+        }
+
+        let offset = self.position;
+        let entry = self.next_entry()?.ok_or(Era1Error::UnexpectedEof)?;
+        match entry.entry_type {
+            COMPRESSED_HEADER => {
+                if self.block_offsets.len() == MAX_BLOCKS_PER_ERA1 {
+                    return Err(Era1Error::InvalidBlockCount(self.block_offsets.len() + 1))
+                }
+                let header = decompress(&entry.data)?;
+                let body = decompress(&self.read_entry(COMPRESSED_BODY)?.data)?;
+                let receipts = decompress(&self.read_entry(COMPRESSED_RECEIPTS)?.data)?;
+                let total_difficulty = self.read_entry(TOTAL_DIFFICULTY)?.data;
+                if total_difficulty.len() != 32 {
+                    return Err(Era1Error::InvalidLength {
+                        kind: "total difficulty",
+                        len: total_difficulty.len(),
+                    })
+                }
+
+                self.block_offsets.push(offset);
+                Ok(Some(BlockTuple {
+                    header: header.into(),
+                    body: body.into(),
+                    receipts: receipts.into(),
+                    total_difficulty: U256::from_le_slice(&total_difficulty),
+                }))
+            }
+            ACCUMULATOR => {
+                if entry.data.len() != 32 {
+                    return Err(Era1Error::InvalidLength {
+                        kind: "accumulator",
+                        len: entry.data.len(),
+                    })
+                }
+                let accumulator = B256::from_slice(&entry.data);
+
+                let index_position = self.position;
+                let block_index = BlockIndex::decode(&self.read_entry(BLOCK_INDEX)?.data)?;
+                if self.block_offsets.is_empty() {
+                    return Err(Era1Error::InvalidBlockCount(0))
+                }
+                if block_index.offsets.len() != self.block_offsets.len() ||
+                    block_index.offsets.iter().zip(&self.block_offsets).any(
+                        |(relative, offset)| {
+                            index_position.checked_add_signed(*relative) != Some(*offset)
+                        },
+                    )
+                {
+                    return Err(Era1Error::InvalidBlockIndex)
+                }
+                if self.next_entry()?.is_some() {
+                    return Err(Era1Error::TrailingData)
+                }
+
+                self.trailer = Some((accumulator, block_index));
+                Ok(None)
+            }
+            got => Err(Era1Error::UnexpectedEntry { expected: COMPRESSED_HEADER, got }),
+        }
+    }
+
+    /// Returns the accumulator stored in the file, once all blocks were read.
+    ///
+    /// This is not verified against the blocks, see [`compute_accumulator`].
+    pub fn accumulator(&self) -> Option<B256> {
+        self.trailer.as_ref().map(|(accumulator, _)| *accumulator)
+    }
+
+    /// Returns the block index of the file, once all blocks were read.
+    pub fn block_index(&self) -> Option<&BlockIndex> {
+        self.trailer.as_ref().map(|(_, block_index)| block_index)
+    }
+
+    fn next_entry(&mut self) -> Result<Option<Entry>, Era1Error> {
+        let entry = Entry::read(&mut self.reader)?;
+        if let Some(entry) = &entry {
+            self.position += entry.encoded_len() as u64;
+        }
+        Ok(entry)
+    }
+
+    fn read_entry(&mut self, expected: u16) -> Result<Entry, Era1Error> {
+        let entry = self.next_entry()?.ok_or(Era1Error::UnexpectedEof)?;
+        if entry.entry_type != expected {
+            return Err(Era1Error::UnexpectedEntry { expected, got: entry.entry_type })
+        }
+        Ok(entry)
+    }
+}
+
+/// Writes consecutive blocks into an ERA1 file.
+#[derive(Debug)]
+pub struct Era1Writer<W> {
+    writer: W,
+    /// Number of bytes written so far.
+    position: u64,
+    /// Number of the first block of the file.
+    starting_number: u64,
+    /// Offsets of the blocks that were written so far.
+    block_offsets: Vec<u64>,
+    /// Hashes and total difficulties of the blocks that were written so far.
+    header_records: Vec<HeaderRecord>,
+}
+
+impl<W: Write> Era1Writer<W> {
+    /// Creates a new writer for a file that starts with the given block, writing the version
+    /// record of the file.
+    pub fn new(writer: W, starting_number: u64) -> Result<Self, Era1Error> {
+        let mut this = Self {
+            writer,
+            position: 0,
+            starting_number,
+            block_offsets: Vec::new(),
+            header_records: Vec::new(),
+        };
+        this.write_entry(Entry::new(e2s::VERSION, Vec::new()))?;
+        Ok(this)
+    }
+
+    /// Returns the number of blocks that were written so far.
+    pub fn len(&self) -> usize {
+        self.block_offsets.len()
+    }
+
+    /// Returns `true` if no blocks were written yet.
+    pub fn is_empty(&self) -> bool {
+        self.block_offsets.is_empty()
+    }
+
+    /// Appends the next block with the given hash to the file.
+    pub fn append_block(&mut self, block_hash: B256, block: &BlockTuple) -> Result<(), Era1Error> {
+        if self.len() == MAX_BLOCKS_PER_ERA1 {
+            return Err(Era1Error::InvalidBlockCount(self.len() + 1))
+        }
+
+        self.block_offsets.push(self.position);
+        self.header_records
+            .push(HeaderRecord { block_hash, total_difficulty: block.total_difficulty });
+
+        self.write_entry(Entry::new(COMPRESSED_HEADER, compress(&block.header)?))?;
+        self.write_entry(Entry::new(COMPRESSED_BODY, compress(&block.body)?))?;
+        self.write_entry(Entry::new(COMPRESSED_RECEIPTS, compress(&block.receipts)?))?;
+        self.write_entry(Entry::new(
+            TOTAL_DIFFICULTY,
+            block.total_difficulty.to_le_bytes::<32>().to_vec(),
+        ))
+    }
+
+    /// Writes the accumulator and block index of the file.
+    ///
+    /// Returns the underlying writer and the accumulator.
+    pub fn finish(mut self) -> Result<(W, B256), Era1Error> {
+        if self.is_empty() {
+            return Err(Era1Error::InvalidBlockCount(0))
+        }
+
+        let accumulator = compute_accumulator(&self.header_records);
+        self.write_entry(Entry::new(ACCUMULATOR, accumulator.to_vec()))?;
+
+        let index_position = self.position;
+        let block_index = BlockIndex {
+            starting_number: self.starting_number,
+            offsets: self
+                .block_offsets
+                .iter()
+                .map(|offset| *offset as i64 - index_position as i64)
+                .collect(),
+        };
+        self.write_entry(Entry::new(BLOCK_INDEX, block_index.encode()))?;
+        self.writer.flush()?;
+
+        Ok((self.writer, accumulator))
+    }
+
+    fn write_entry(&mut self, entry: Entry) -> Result<(), Era1Error> {
+        entry.write(&mut self.writer)?;
+        self.position += entry.encoded_len() as u64;
+        Ok(())
+    }
+}
+
+/// Compresses the data with the snappy framing format.
+fn compress(data: &[u8]) -> Result<Vec<u8>, Era1Error> {
+    let mut compressed = Vec::new();
+    let mut encoder = snap::write::FrameEncoder::new(&mut compressed);
+    encoder.write_all(data)?;
+    encoder.flush()?;
+    drop(encoder);
+    Ok(compressed)
+}
+
+/// Decompresses data in the snappy framing format.
+fn decompress(data: &[u8]) -> Result<Vec<u8>, Era1Error> {
+    let mut decompressed = Vec::new();
+    snap::read::FrameDecoder::new(data).read_to_end(&mut decompressed)?;
+    Ok(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{
+        constants::EMPTY_ROOT_HASH, proofs::calculate_receipt_root, Eip658Value, Header, Receipt,
+        ReceiptEnvelope, ReceiptWithBloom,
+    };
+    use alloy_primitives::{Bloom, Log};
+    use alloy_rlp::Encodable;
+
+    fn block(number: u64) -> (B256, BlockTuple) {
+        let header = Header { number, difficulty: U256::from(number), ..Default::default() };
+        let tuple = BlockTuple {
+            header: alloy_rlp::encode(&header).into(),
+            // no transactions and ommers
+            body: Bytes::from_static(&[0xc2, 0xc0, 0xc0]),
+            // no receipts
+            receipts: Bytes::from_static(&[0xc0]),
+            total_difficulty: U256::from(number * (number + 1) / 2),
+        };
+        (header.hash_slow(), tuple)
+    }
+
+    #[test]
+    fn era1_roundtrip() {
+        let blocks = (100..110).map(block).collect::<Vec<_>>();
+
+        let mut writer = Era1Writer::new(Vec::new(), 100).unwrap();
+        for (hash, block) in &blocks {
+            writer.append_block(*hash, block).unwrap();
+        }
+        let (file, accumulator) = writer.finish().unwrap();
+        let records = blocks
+            .iter()
+            .map(|(block_hash, block)| HeaderRecord {
+                block_hash: *block_hash,
+                total_difficulty: block.total_difficulty,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(accumulator, compute_accumulator(&records));
+
+        let mut reader = Era1Reader::new(file.as_slice()).unwrap();
+        for (hash, expected) in &blocks {
+            let block = reader.next_block().unwrap().unwrap();
+            assert_eq!(&block, expected);
+            assert_eq!(block.decode_header::<Header>().unwrap().hash_slow(), *hash);
+            assert_eq!(block.receipts_root().unwrap(), EMPTY_ROOT_HASH);
+        }
+        assert!(reader.next_block().unwrap().is_none());
+        assert_eq!(reader.accumulator(), Some(accumulator));
+        assert_eq!(reader.block_index().unwrap().starting_number, 100);
+
+        // a truncated file is rejected
+        let mut reader = Era1Reader::new(&file[..file.len() - 8]).unwrap();
+        assert!(std::iter::from_fn(|| reader.next_block().transpose()).any(|res| res.is_err()));
+    }
+
+    #[test]
+    fn receipts_root() {
+        let logs = vec![Log::new_unchecked(Default::default(), vec![B256::ZERO], Bytes::new())];
+        let receipt = |status| ReceiptWithBloom {
+            receipt: Receipt { status, cumulative_gas_used: 21_000, logs: logs.clone() },
+            logs_bloom: Bloom::ZERO,
+        };
+        let receipts = vec![
+            ReceiptEnvelope::Legacy(receipt(Eip658Value::PostState(B256::with_last_byte(1)))),
+            ReceiptEnvelope::Eip1559(receipt(Eip658Value::Eip658(true))),
+        ];
+
+        let mut encoded = Vec::new();
+        receipts.encode(&mut encoded);
+        let (_, block) = block(1);
+        let block = BlockTuple { receipts: encoded.into(), ..block };
+
+        assert_eq!(block.receipts_root().unwrap(), calculate_receipt_root(&receipts));
+    }
+}
@@ -0,0 +1,47 @@
+use crate::MAX_BLOCKS_PER_ERA1;
+use std::io;
+
+/// Errors that can occur when reading or writing ERA1 files.
+#[derive(Debug, thiserror::Error)]
+pub enum Era1Error {
+    /// Error while reading or writing the file.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// The reserved bytes of a record header are not zero.
+    #[error("reserved bytes of e2store record header are not zero")]
+    NonZeroReserved,
+    /// The data of a record doesn't fit into the record header.
+    #[error("e2store record of {0} bytes is too large")]
+    EntryTooLarge(usize),
+    /// A record of a different type was expected.
+    #[error("expected e2store record of type {expected:#06x}, got {got:#06x}")]
+    UnexpectedEntry {
+        /// The expected record type.
+        expected: u16,
+        /// The record type found in the file.
+        got: u16,
+    },
+    /// The file ended before the block index.
+    #[error("unexpected end of era1 file")]
+    UnexpectedEof,
+    /// The file contains data after the block index.
+    #[error("unexpected data after era1 block index")]
+    TrailingData,
+    /// A record has an invalid length.
+    #[error("invalid length of {kind} record: {len} bytes")]
+    InvalidLength {
+        /// The kind of the record.
+        kind: &'static str,
+        /// The length of the record data.
+        len: usize,
+    },
+    /// The file doesn't contain a valid number of blocks.
+    #[error("era1 file must contain between 1 and {MAX_BLOCKS_PER_ERA1} blocks, got {0}")]
+    InvalidBlockCount(usize),
+    /// The block index doesn't point at the blocks of the file.
+    #[error("block index doesn't match the blocks of the era1 file")]
+    InvalidBlockIndex,
+    /// Failed to decode RLP.
+    #[error(transparent)]
+    Rlp(#[from] alloy_rlp::Error),
+}
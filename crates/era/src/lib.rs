@@ -0,0 +1,29 @@
+//! Reading and writing of ERA1 history archives.
+//!
+//! ERA1 files contain the headers, bodies, receipts and total difficulties of up to
+//! [`MAX_BLOCKS_PER_ERA1`] consecutive pre-merge blocks, together with an accumulator that commits
+//! to the hashes and total difficulties of these blocks. They are based on the `e2store` container
+//! format, see [`e2s`].
+//!
+//! See also <https://github.com/ethereum/go-ethereum/blob/master/internal/era/era.go>.
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+pub mod e2s;
+
+mod accumulator;
+pub use accumulator::{compute_accumulator, HeaderRecord};
+
+mod era1;
+pub use era1::{
+    era1_file_name, BlockIndex, BlockTuple, Era1Reader, Era1Writer, MAX_BLOCKS_PER_ERA1,
+};
+
+mod error;
+pub use error::Era1Error;
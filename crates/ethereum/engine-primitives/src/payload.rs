@@ -9,6 +9,7 @@ use alloy_rpc_types_engine::{
     ExecutionPayloadFieldV2, ExecutionPayloadV1, ExecutionPayloadV3, PayloadAttributes, PayloadId,
 };
 use core::convert::Infallible;
+use reth_chain_state::ExecutedBlockWithTrieUpdates;
 use reth_payload_primitives::{BuiltPayload, PayloadBuilderAttributes};
 use reth_primitives::{EthPrimitives, SealedBlock};
 
@@ -30,6 +31,8 @@ pub struct EthBuiltPayload {
     pub(crate) sidecars: Vec<BlobTransactionSidecar>,
     /// The requests of the payload
     pub(crate) requests: Option<Requests>,
+    /// Block execution data for the payload, if any.
+    pub(crate) executed_block: Option<ExecutedBlockWithTrieUpdates<EthPrimitives>>,
 }
 
 // === impl BuiltPayload ===
@@ -44,7 +47,7 @@ impl EthBuiltPayload {
         fees: U256,
         requests: Option<Requests>,
     ) -> Self {
-        Self { id, block, fees, sidecars: Vec::new(), requests }
+        Self { id, block, fees, sidecars: Vec::new(), requests, executed_block: None }
     }
 
     /// Returns the identifier of the payload.
@@ -80,6 +83,15 @@ impl EthBuiltPayload {
         self.extend_sidecars(sidecars);
         self
     }
+
+    /// Sets the block execution data of the payload.
+    pub fn with_executed_block(
+        mut self,
+        executed_block: ExecutedBlockWithTrieUpdates<EthPrimitives>,
+    ) -> Self {
+        self.executed_block = Some(executed_block);
+        self
+    }
 }
 
 impl BuiltPayload for EthBuiltPayload {
@@ -96,6 +108,10 @@ impl BuiltPayload for EthBuiltPayload {
     fn requests(&self) -> Option<Requests> {
         self.requests.clone()
     }
+
+    fn executed_block(&self) -> Option<ExecutedBlockWithTrieUpdates<EthPrimitives>> {
+        self.executed_block.clone()
+    }
 }
 
 // V1 engine_getPayloadV1 response
@@ -23,8 +23,10 @@ use reth_node_builder::{
     BuilderContext, Node, NodeAdapter, NodeComponentsBuilder, PayloadTypes,
 };
 use reth_provider::{providers::ProviderFactoryBuilder, CanonStateSubscriptions, EthStorage};
-use reth_rpc::{eth::core::EthApiFor, ValidationApi};
-use reth_rpc_api::servers::BlockSubmissionValidationApiServer;
+use reth_rpc::{eth::core::EthApiFor, AnvilApi, MinerApi, ValidationApi};
+use reth_rpc_api::servers::{
+    AnvilApiServer, BlockSubmissionValidationApiServer, HardhatApiServer, MinerApiServer,
+};
 use reth_rpc_builder::config::RethRpcServerConfig;
 use reth_rpc_eth_types::{error::FromEvmError, EthApiError};
 use reth_rpc_server_types::RethRpcModule;
@@ -155,13 +157,45 @@ where
             Arc::new(EthereumEngineValidator::new(ctx.config.chain.clone())),
         );
 
+        let local_miner = ctx.local_miner.clone();
+        let miner_settings = ctx.node.payload_builder().miner_settings();
+        let provider = ctx.node.provider().clone();
+        let pool = ctx.node.pool().clone();
+
         self.inner
-            .launch_add_ons_with(ctx, move |modules, _| {
+            .launch_add_ons_with(ctx, move |modules, _, registry| {
                 modules.merge_if_module_configured(
                     RethRpcModule::Flashbots,
                     validation_api.into_rpc(),
                 )?;
 
+                // the `miner` namespace updates the settings of the payload builder, if supported
+                if let Some(settings) = miner_settings {
+                    let miner_api = MinerApiServer::into_rpc(MinerApi::new(settings));
+                    for method in miner_api.method_names() {
+                        modules.remove_method_from_configured(method);
+                    }
+                    modules.merge_if_module_configured(RethRpcModule::Miner, miner_api)?;
+                }
+
+                // the dev namespaces are only available if the node runs a local miner
+                if let Some(local_miner) = local_miner {
+                    let anvil_api = AnvilApi::new(provider, pool, local_miner);
+                    registry
+                        .eth_api()
+                        .signers()
+                        .write()
+                        .push(Box::new(anvil_api.impersonated_signer()));
+                    modules.merge_if_module_configured(
+                        RethRpcModule::Anvil,
+                        AnvilApiServer::into_rpc(anvil_api.clone()),
+                    )?;
+                    modules.merge_if_module_configured(
+                        RethRpcModule::Hardhat,
+                        HardhatApiServer::into_rpc(anvil_api),
+                    )?;
+                }
+
                 Ok(())
             })
             .await
@@ -287,6 +321,7 @@ where
         let transaction_pool =
             reth_transaction_pool::Pool::eth_pool(validator, blob_store, pool_config);
         info!(target: "reth::cli", "Transaction pool initialized");
+        ctx.spawn_pool_admission_reloader(transaction_pool.clone());
         let transactions_path = data_dir.txpool_transactions();
 
         // spawn txpool maintenance task
@@ -294,8 +329,10 @@ where
             let pool = transaction_pool.clone();
             let chain_events = ctx.provider().canonical_state_stream();
             let client = ctx.provider().clone();
-            let transactions_backup_config =
+            let mut transactions_backup_config =
                 reth_transaction_pool::maintain::LocalTransactionBackupConfig::with_local_txs_backup(transactions_path);
+            transactions_backup_config.journal =
+                ctx.config().txpool.journal_config(data_dir.txpool_journal());
 
             ctx.task_executor().spawn_critical_with_graceful_shutdown_signal(
                 "local transactions backup task",
@@ -48,7 +48,10 @@ impl EthereumPayloadBuilder {
             ctx.provider().clone(),
             pool,
             evm_config,
-            EthereumBuilderConfig::new(conf.extra_data_bytes()).with_gas_limit(conf.gas_limit()),
+            EthereumBuilderConfig::new(conf.extra_data_bytes())
+                .with_gas_limit(conf.gas_limit())
+                // dev nodes insert blocks with impersonated transactions without re-executing them
+                .with_executed_block(ctx.is_dev()),
         ))
     }
 }
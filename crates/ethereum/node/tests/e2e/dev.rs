@@ -1,18 +1,21 @@
-use alloy_eips::eip2718::Encodable2718;
+use alloy_eips::{eip2718::Encodable2718, BlockNumberOrTag};
 use alloy_genesis::Genesis;
-use alloy_primitives::{b256, hex};
+use alloy_primitives::{b256, hex, U256};
+use alloy_provider::{Provider, ProviderBuilder};
 use futures::StreamExt;
 use reth_chainspec::ChainSpec;
 use reth_node_api::{BlockBody, FullNodeComponents, FullNodePrimitives, NodeTypes};
 use reth_node_builder::{
     rpc::RethRpcAddOns, EngineNodeLauncher, FullNode, NodeBuilder, NodeConfig, NodeHandle,
 };
-use reth_node_core::args::DevArgs;
+use reth_node_core::args::{DevArgs, RpcServerArgs};
 use reth_node_ethereum::{node::EthereumAddOns, EthereumNode};
 use reth_provider::{providers::BlockchainProvider, CanonStateSubscriptions};
 use reth_rpc_eth_api::helpers::EthTransactions;
+use reth_rpc_server_types::RethRpcModule;
 use reth_tasks::TaskManager;
-use std::sync::Arc;
+use reth_transaction_pool::TransactionPool;
+use std::{sync::Arc, time::Duration};
 
 #[tokio::test]
 async fn can_run_dev_node() -> eyre::Result<()> {
@@ -43,6 +46,78 @@ async fn can_run_dev_node() -> eyre::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn can_revert_dev_node_to_snapshot() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+    let tasks = TaskManager::current();
+    let exec = tasks.executor();
+
+    let mut rpc = RpcServerArgs::default().with_unused_ports().with_http();
+    rpc.http_api = Some([RethRpcModule::Eth, RethRpcModule::Anvil].into());
+    let node_config = NodeConfig::test()
+        .with_chain(custom_chain())
+        .with_dev(DevArgs { dev: true, ..Default::default() })
+        .with_rpc(rpc);
+    let NodeHandle { node, .. } = NodeBuilder::new(node_config.clone())
+        .testing_node(exec.clone())
+        .with_types_and_provider::<EthereumNode, BlockchainProvider<_>>()
+        .with_components(EthereumNode::components())
+        .with_add_ons(EthereumAddOns::default())
+        .launch_with_fn(|builder| {
+            let launcher = EngineNodeLauncher::new(
+                builder.task_executor().clone(),
+                builder.config().datadir(),
+                Default::default(),
+            );
+            builder.launch_with(launcher)
+        })
+        .await?;
+
+    let url = node.rpc_server_handle().http_url().unwrap().parse()?;
+    let provider = ProviderBuilder::new().on_http(url);
+
+    // take the snapshot on top of an empty block
+    provider.raw_request::<_, ()>("anvil_mine".into(), (Some(U256::from(1)), None::<U256>)).await?;
+    let snapshot = provider.get_block_by_number(1.into(), false.into()).await?.unwrap();
+    let id: U256 = provider.raw_request("anvil_snapshot".into(), [(); 0]).await?;
+
+    // mine a transaction on top of the snapshot
+    let mut notifications = node.provider.canonical_state_stream();
+    let raw_tx = hex!("02f876820a28808477359400847735940082520894ab0840c0e43688012c1adb0f5e3fc665188f83d28a029d394a5d630544000080c080a0a044076b7e67b5deecc63f61a8d7913fab86ca365b344b5759d1fe3563b4c39ea019eab979dd000da04dfc72bb0377c092d30fd9e1cab5ae487de49586cc8b0090");
+    let hash = *provider.send_raw_transaction(&raw_tx).await?.tx_hash();
+    let head = notifications.next().await.unwrap();
+    assert_eq!(head.tip().num_hash().number, 2);
+    assert_eq!(head.tip().body().transactions()[0].trie_hash(), hash);
+
+    let reverted: bool = provider.raw_request("anvil_revert".into(), (id,)).await?;
+    assert!(reverted);
+
+    // the snapshot is the head again
+    assert_eq!(provider.get_block_number().await?, 1);
+    let head = provider.get_block_by_number(BlockNumberOrTag::Latest, false.into()).await?.unwrap();
+    assert_eq!(head.header.hash, snapshot.header.hash);
+
+    // the reverted block and its transaction are gone once the pool and the database caught up
+    let mut attempts = 0;
+    while node.pool.block_info().last_seen_block_hash != snapshot.header.hash ||
+        provider.get_block_by_number(2.into(), false.into()).await?.is_some()
+    {
+        attempts += 1;
+        assert!(attempts < 100, "reverted block is still available");
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(provider.get_transaction_by_hash(hash).await?.is_none());
+    assert!(node.pool.get(&hash).is_none());
+
+    // the reverted transaction is not mined again
+    provider.raw_request::<_, ()>("anvil_mine".into(), (Some(U256::from(1)), None::<U256>)).await?;
+    let block = provider.get_block_by_number(2.into(), false.into()).await?.unwrap();
+    assert_eq!(block.header.parent_hash, snapshot.header.hash);
+    assert!(block.transactions.is_empty());
+
+    Ok(())
+}
+
 async fn assert_chain_advances<N, AddOns>(node: FullNode<N, AddOns>)
 where
     N: FullNodeComponents<Provider: CanonStateSubscriptions>,
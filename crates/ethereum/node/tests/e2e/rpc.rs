@@ -1,6 +1,6 @@
 use crate::utils::eth_payload_attributes;
 use alloy_eips::{calc_next_block_base_fee, eip2718::Encodable2718};
-use alloy_primitives::{Address, B256, U256};
+use alloy_primitives::{Address, Bytes, B256, U128, U256};
 use alloy_provider::{network::EthereumWallet, Provider, ProviderBuilder, SendableTx};
 use alloy_rpc_types_beacon::relay::{
     BidTrace, BuilderBlockValidationRequestV3, BuilderBlockValidationRequestV4,
@@ -13,6 +13,8 @@ use reth_chainspec::{ChainSpecBuilder, MAINNET};
 use reth_e2e_test_utils::setup_engine;
 use reth_node_ethereum::EthereumNode;
 use reth_payload_primitives::BuiltPayload;
+use reth_provider::{DatabaseProviderFactory, PruneCheckpointWriter};
+use reth_prune_types::{PruneCheckpoint, PruneMode, PruneSegment};
 use std::sync::Arc;
 
 alloy_sol_types::sol! {
@@ -267,3 +269,180 @@ async fn test_flashbots_validate_v4() -> eyre::Result<()> {
         .is_err());
     Ok(())
 }
+
+#[tokio::test]
+async fn test_standard_trace_block_to_file() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let chain_spec = Arc::new(
+        ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(serde_json::from_str(include_str!("../assets/genesis.json")).unwrap())
+            .cancun_activated()
+            .build(),
+    );
+
+    let (mut nodes, _tasks, wallet) =
+        setup_engine::<EthereumNode>(1, chain_spec.clone(), false, eth_payload_attributes).await?;
+    let mut node = nodes.pop().unwrap();
+    let provider = ProviderBuilder::new()
+        .wallet(EthereumWallet::new(wallet.gen().swap_remove(0)))
+        .on_http(node.rpc_url());
+
+    let builder = GasWaster::deploy_builder(&provider, U256::from(2)).send().await?;
+    node.advance_block().await?;
+    let receipt = builder.get_receipt().await?;
+    let block_hash = receipt.block_hash.unwrap();
+
+    let files = provider
+        .raw_request::<_, Vec<String>>(
+            "debug_standardTraceBlockToFile".into(),
+            (block_hash, serde_json::json!({ "enableMemory": true })),
+        )
+        .await?;
+    assert_eq!(files.len(), 1);
+
+    // one line per step, followed by the summary
+    let trace = std::fs::read_to_string(&files[0])?;
+    let lines = trace
+        .lines()
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+    let (summary, steps) = lines.split_last().unwrap();
+    assert_eq!(summary["pass"], true);
+    assert!(steps.iter().all(|step| step["pc"].is_u64() && step["memory"].is_string()));
+    // the constructor stores two slots
+    assert_eq!(steps.iter().filter(|step| step["opName"] == "SSTORE").count(), 2);
+
+    // the traced transaction must be in the block
+    assert!(provider
+        .raw_request::<_, Vec<String>>(
+            "debug_standardTraceBlockToFile".into(),
+            (block_hash, serde_json::json!({ "txHash": B256::ZERO })),
+        )
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_modified_accounts_and_intermediate_roots() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let chain_spec = Arc::new(
+        ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(serde_json::from_str(include_str!("../assets/genesis.json")).unwrap())
+            .cancun_activated()
+            .build(),
+    );
+
+    let (mut nodes, _tasks, wallet) =
+        setup_engine::<EthereumNode>(1, chain_spec.clone(), false, eth_payload_attributes).await?;
+    let mut node = nodes.pop().unwrap();
+    let signer = wallet.gen().swap_remove(0);
+    let sender = signer.address();
+    let provider =
+        ProviderBuilder::new().wallet(EthereumWallet::new(signer)).on_http(node.rpc_url());
+
+    let builder = GasWaster::deploy_builder(&provider, U256::from(2)).send().await?;
+    node.advance_block().await?;
+    let receipt = builder.get_receipt().await?;
+    let block_hash = receipt.block_hash.unwrap();
+    let block_number = receipt.block_number.unwrap();
+    let contract = receipt.contract_address.unwrap();
+
+    let modified = provider
+        .raw_request::<_, Vec<Address>>("debug_getModifiedAccountsByNumber".into(), (block_number,))
+        .await?;
+    assert!(modified.contains(&sender));
+    assert!(modified.contains(&contract));
+    let by_hash = provider
+        .raw_request::<_, Vec<Address>>("debug_getModifiedAccountsByHash".into(), (block_hash,))
+        .await?;
+    assert_eq!(by_hash, modified);
+
+    // the root after the last transaction is the state root of the block
+    let roots = provider
+        .raw_request::<_, Vec<B256>>("debug_intermediateRoots".into(), (block_hash,))
+        .await?;
+    let block = provider.get_block_by_hash(block_hash, false.into()).await?.unwrap();
+    assert_eq!(roots, vec![block.header.state_root]);
+
+    let err = provider
+        .raw_request::<_, Vec<Address>>("debug_getModifiedAccountsByNumber".into(), (0, 1_000_000))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("block range exceeds"), "{err}");
+
+    // the changesets of the block are gone once the account history is pruned
+    let provider_rw = node.inner.provider.database_provider_rw()?;
+    provider_rw.save_prune_checkpoint(
+        PruneSegment::AccountHistory,
+        PruneCheckpoint {
+            block_number: Some(block_number),
+            tx_number: None,
+            prune_mode: PruneMode::Before(block_number + 1),
+        },
+    )?;
+    provider_rw.commit()?;
+
+    let err = provider
+        .raw_request::<_, Vec<Address>>("debug_getModifiedAccountsByNumber".into(), (block_number,))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("pruned"), "{err}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_miner_settings_apply_to_built_blocks() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let chain_spec = Arc::new(
+        ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(serde_json::from_str(include_str!("../assets/genesis.json")).unwrap())
+            .cancun_activated()
+            .build(),
+    );
+
+    let (mut nodes, _tasks, wallet) =
+        setup_engine::<EthereumNode>(1, chain_spec.clone(), false, eth_payload_attributes).await?;
+    let mut node = nodes.pop().unwrap();
+    let provider = ProviderBuilder::new()
+        .wallet(EthereumWallet::new(wallet.gen().swap_remove(0)))
+        .on_http(node.rpc_url());
+
+    let extra_data = Bytes::from_static(b"reth-miner");
+    assert!(provider.raw_request::<_, bool>("miner_setExtra".into(), (&extra_data,)).await?);
+    assert!(
+        provider
+            .raw_request::<_, bool>("miner_setGasPrice".into(), (U128::from(2_000_000_000),))
+            .await?
+    );
+
+    // the tip is below the minimum priority fee of the builder
+    let tx = TransactionRequest::default()
+        .to(Address::with_last_byte(0x42))
+        .value(U256::from(1))
+        .max_priority_fee_per_gas(1_000_000_000)
+        .max_fee_per_gas(100_000_000_000);
+    let pending = provider.send_transaction(tx).await?;
+
+    let (payload, _) = node.advance_block().await?;
+    assert_eq!(payload.block().header().extra_data, extra_data);
+    assert!(payload.block().body().transactions.is_empty());
+
+    // the transaction is included once the minimum priority fee is lowered
+    assert!(provider.raw_request::<_, bool>("miner_setGasPrice".into(), (U128::ZERO,)).await?);
+    let (payload, _) = node.advance_block().await?;
+    assert_eq!(payload.block().header().extra_data, extra_data);
+    assert_eq!(payload.block().body().transactions.len(), 1);
+    let receipt = pending.get_receipt().await?;
+    assert_eq!(receipt.block_hash, Some(payload.block().hash()));
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use alloy_eips::eip1559::ETHEREUM_BLOCK_GAS_LIMIT_30M;
 use alloy_primitives::Bytes;
+use parking_lot::RwLock;
+use reth_payload_primitives::MinerSettings;
 use reth_primitives_traits::constants::GAS_LIMIT_BOUND_DIVISOR;
+use std::sync::Arc;
 
 /// Settings for the Ethereum builder.
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -9,12 +12,22 @@ pub struct EthereumBuilderConfig {
     pub extra_data: Bytes,
     /// Desired gas limit.
     pub desired_gas_limit: u64,
+    /// Minimum priority fee per gas a transaction must pay to be included.
+    pub min_priority_fee: u128,
+    /// Whether built payloads carry the executed block, so that the local miner of a dev node can
+    /// insert them into the tree without re-executing them.
+    pub executed_block: bool,
 }
 
 impl EthereumBuilderConfig {
     /// Create new payload builder config.
     pub const fn new(extra_data: Bytes) -> Self {
-        Self { extra_data, desired_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT_30M }
+        Self {
+            extra_data,
+            desired_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT_30M,
+            min_priority_fee: 0,
+            executed_block: false,
+        }
     }
 
     /// Set desired gas limit.
@@ -22,6 +35,18 @@ impl EthereumBuilderConfig {
         self.desired_gas_limit = desired_gas_limit;
         self
     }
+
+    /// Set the minimum priority fee per gas for included transactions.
+    pub const fn with_min_priority_fee(mut self, min_priority_fee: u128) -> Self {
+        self.min_priority_fee = min_priority_fee;
+        self
+    }
+
+    /// Set whether built payloads carry the executed block.
+    pub const fn with_executed_block(mut self, executed_block: bool) -> Self {
+        self.executed_block = executed_block;
+        self
+    }
 }
 
 impl EthereumBuilderConfig {
@@ -37,6 +62,47 @@ impl EthereumBuilderConfig {
     }
 }
 
+/// An [`EthereumBuilderConfig`] that can be updated while the node is running.
+///
+/// This type is shareable: the payload builder reads the current config whenever it builds a
+/// payload and the `miner` RPC namespace updates it via [`MinerSettings`].
+#[derive(Debug, Clone)]
+pub struct SharedEthereumBuilderConfig {
+    inner: Arc<RwLock<EthereumBuilderConfig>>,
+}
+
+impl SharedEthereumBuilderConfig {
+    /// Creates a new shared config with the given initial config.
+    pub fn new(config: EthereumBuilderConfig) -> Self {
+        Self { inner: Arc::new(RwLock::new(config)) }
+    }
+
+    /// Returns a snapshot of the current config.
+    pub fn get(&self) -> EthereumBuilderConfig {
+        self.inner.read().clone()
+    }
+}
+
+impl From<EthereumBuilderConfig> for SharedEthereumBuilderConfig {
+    fn from(config: EthereumBuilderConfig) -> Self {
+        Self::new(config)
+    }
+}
+
+impl MinerSettings for SharedEthereumBuilderConfig {
+    fn set_extra_data(&self, extra_data: Bytes) {
+        self.inner.write().extra_data = extra_data;
+    }
+
+    fn set_min_priority_fee(&self, min_priority_fee: u128) {
+        self.inner.write().min_priority_fee = min_priority_fee;
+    }
+
+    fn set_gas_limit(&self, gas_limit: u64) {
+        self.inner.write().desired_gas_limit = gas_limit;
+    }
+}
+
 /// Calculate the gas limit for the next block based on parent and desired gas limits.
 /// Ref: <https://github.com/ethereum/go-ethereum/blob/88cbfab332c96edfbe99d161d9df6a40721bd786/core/block_validator.go#L166>
 pub fn calculate_block_gas_limit(parent_gas_limit: u64, desired_gas_limit: u64) -> u64 {
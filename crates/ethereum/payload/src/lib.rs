@@ -16,6 +16,7 @@ use reth_basic_payload_builder::{
     commit_withdrawals, is_better_payload, BuildArguments, BuildOutcome, PayloadBuilder,
     PayloadConfig,
 };
+use reth_chain_state::{ExecutedBlock, ExecutedBlockWithTrieUpdates};
 use reth_chainspec::{ChainSpec, ChainSpecProvider, EthChainSpec, EthereumHardforks};
 use reth_errors::RethError;
 use reth_ethereum_primitives::{Block, BlockBody, Receipt, TransactionSigned};
@@ -27,10 +28,10 @@ use reth_evm_ethereum::{eip6110::parse_deposits_from_receipts, EthEvmConfig};
 use reth_execution_types::ExecutionOutcome;
 use reth_payload_builder::{EthBuiltPayload, EthPayloadBuilderAttributes};
 use reth_payload_builder_primitives::PayloadBuilderError;
-use reth_payload_primitives::PayloadBuilderAttributes;
+use reth_payload_primitives::{MinerSettings, PayloadBuilderAttributes};
 use reth_primitives_traits::{
     proofs::{self},
-    Block as _, SignedTransaction,
+    Block as _, RecoveredBlock, SignedTransaction,
 };
 use reth_revm::{
     database::StateProviderDatabase,
@@ -58,7 +59,7 @@ type BestTransactionsIter<Pool> = Box<
 >;
 
 /// Ethereum payload builder
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct EthereumPayloadBuilder<Pool, Client, EvmConfig = EthEvmConfig> {
     /// Client providing access to node state.
     client: Client,
@@ -67,18 +68,23 @@ pub struct EthereumPayloadBuilder<Pool, Client, EvmConfig = EthEvmConfig> {
     /// The type responsible for creating the evm.
     evm_config: EvmConfig,
     /// Payload builder configuration.
-    builder_config: EthereumBuilderConfig,
+    builder_config: SharedEthereumBuilderConfig,
 }
 
 impl<Pool, Client, EvmConfig> EthereumPayloadBuilder<Pool, Client, EvmConfig> {
     /// `EthereumPayloadBuilder` constructor.
-    pub const fn new(
+    pub fn new(
         client: Client,
         pool: Pool,
         evm_config: EvmConfig,
-        builder_config: EthereumBuilderConfig,
+        builder_config: impl Into<SharedEthereumBuilderConfig>,
     ) -> Self {
-        Self { client, pool, evm_config, builder_config }
+        Self { client, pool, evm_config, builder_config: builder_config.into() }
+    }
+
+    /// Returns the payload builder configuration that can be updated while the node is running.
+    pub const fn builder_config(&self) -> &SharedEthereumBuilderConfig {
+        &self.builder_config
     }
 }
 
@@ -92,12 +98,13 @@ where
         &self,
         config: &PayloadConfig<EthPayloadBuilderAttributes>,
         parent: &Header,
+        builder_config: &EthereumBuilderConfig,
     ) -> Result<EvmEnv<EvmConfig::Spec>, EvmConfig::Error> {
         let next_attributes = NextBlockEnvAttributes {
             timestamp: config.attributes.timestamp(),
             suggested_fee_recipient: config.attributes.suggested_fee_recipient(),
             prev_randao: config.attributes.prev_randao(),
-            gas_limit: self.builder_config.gas_limit(parent.gas_limit),
+            gas_limit: builder_config.gas_limit(parent.gas_limit),
         };
         self.evm_config.next_evm_env(parent, next_attributes)
     }
@@ -117,15 +124,16 @@ where
         &self,
         args: BuildArguments<EthPayloadBuilderAttributes, EthBuiltPayload>,
     ) -> Result<BuildOutcome<EthBuiltPayload>, PayloadBuilderError> {
+        let builder_config = self.builder_config.get();
         let evm_env = self
-            .evm_env(&args.config, &args.config.parent_header)
+            .evm_env(&args.config, &args.config.parent_header, &builder_config)
             .map_err(PayloadBuilderError::other)?;
 
         default_ethereum_payload(
             self.evm_config.clone(),
             self.client.clone(),
             self.pool.clone(),
-            self.builder_config.clone(),
+            builder_config,
             args,
             evm_env,
             |attributes| self.pool.best_transactions_with_attributes(attributes),
@@ -138,15 +146,16 @@ where
     ) -> Result<EthBuiltPayload, PayloadBuilderError> {
         let args = BuildArguments::new(Default::default(), config, Default::default(), None);
 
+        let builder_config = self.builder_config.get();
         let evm_env = self
-            .evm_env(&args.config, &args.config.parent_header)
+            .evm_env(&args.config, &args.config.parent_header, &builder_config)
             .map_err(PayloadBuilderError::other)?;
 
         default_ethereum_payload(
             self.evm_config.clone(),
             self.client.clone(),
             self.pool.clone(),
-            self.builder_config.clone(),
+            builder_config,
             args,
             evm_env,
             |attributes| self.pool.best_transactions_with_attributes(attributes),
@@ -154,6 +163,10 @@ where
         .into_payload()
         .ok_or_else(|| PayloadBuilderError::MissingPayload)
     }
+
+    fn miner_settings(&self) -> Option<Arc<dyn MinerSettings>> {
+        Some(Arc::new(self.builder_config.clone()))
+    }
 }
 
 /// Constructs an Ethereum transaction payload using the best transactions from the pool.
@@ -192,6 +205,7 @@ where
     let base_fee = evm_env.block_env.basefee;
 
     let mut executed_txs = Vec::new();
+    let mut executed_senders = Vec::new();
 
     let mut best_txs = best_txs(BestTransactionsAttributes::new(
         base_fee,
@@ -236,6 +250,14 @@ where
         blob_params.as_ref().map(|params| params.max_blob_count).unwrap_or_default();
 
     while let Some(pool_tx) = best_txs.next() {
+        // ensure the transaction pays the configured minimum tip
+        if pool_tx.effective_tip_per_gas(base_fee).unwrap_or_default() <
+            builder_config.min_priority_fee
+        {
+            best_txs.mark_invalid(&pool_tx, InvalidPoolTransactionError::Underpriced);
+            continue
+        }
+
         // ensure we still have capacity for this transaction
         if cumulative_gas_used + pool_tx.gas_limit() > block_gas_limit {
             // we can't fit this transaction into the block, so we need to mark it as invalid
@@ -342,6 +364,7 @@ where
         total_fees += U256::from(miner_fee) * U256::from(gas_used);
 
         // append transaction to the block body
+        executed_senders.push(tx.signer());
         executed_txs.push(tx.into_tx());
     }
 
@@ -399,7 +422,9 @@ where
 
     // calculate the state root
     let hashed_state = db.database.db.hashed_post_state(execution_outcome.state());
-    let (state_root, _) = {
+    // the hashed state is only retained if the executed block is returned with the payload
+    let executed_hashed_state = builder_config.executed_block.then(|| hashed_state.clone());
+    let (state_root, trie_output) = {
         db.database.inner().state_root_with_updates(hashed_state).inspect_err(|err| {
             warn!(target: "payload_builder",
                 parent_hash=%parent_header.hash(),
@@ -474,7 +499,23 @@ where
     let sealed_block = Arc::new(block.seal_slow());
     debug!(target: "payload_builder", id=%attributes.id, sealed_block_header = ?sealed_block.sealed_header(), "sealed built block");
 
+    // create the executed block data, if requested
+    let executed_block = executed_hashed_state.map(|hashed_state| ExecutedBlockWithTrieUpdates {
+        block: ExecutedBlock {
+            recovered_block: Arc::new(RecoveredBlock::new_sealed(
+                sealed_block.as_ref().clone(),
+                executed_senders,
+            )),
+            execution_output: Arc::new(execution_outcome),
+            hashed_state: Arc::new(hashed_state),
+        },
+        trie: Arc::new(trie_output),
+    });
+
     let mut payload = EthBuiltPayload::new(attributes.id, sealed_block, total_fees, requests);
+    if let Some(executed_block) = executed_block {
+        payload = payload.with_executed_block(executed_block);
+    }
 
     // extend the payload with the blob sidecars from the executed txs
     payload.extend_sidecars(blob_sidecars.into_iter().map(Arc::unwrap_or_clone));
@@ -82,6 +82,25 @@ impl Receipt {
         })
     }
 
+    /// Returns length of RLP-encoded receipt fields without the bloom filter and without an RLP
+    /// header.
+    pub fn rlp_encoded_fields_length_without_bloom(&self) -> usize {
+        (self.tx_type as u8).length() +
+            self.success.length() +
+            self.cumulative_gas_used.length() +
+            self.logs.length()
+    }
+
+    /// RLP-encodes receipt fields without the bloom filter and without an RLP header.
+    ///
+    /// This is the `eth/69` network encoding: `[tx-type, status, cumulative-gas, logs]`.
+    pub fn rlp_encode_fields_without_bloom(&self, out: &mut dyn BufMut) {
+        (self.tx_type as u8).encode(out);
+        self.success.encode(out);
+        self.cumulative_gas_used.encode(out);
+        self.logs.encode(out);
+    }
+
     /// Calculates the receipt root for a header for the reference type of [Receipt].
     ///
     /// NOTE: Prefer `proofs::calculate_receipt_root` if you have log blooms memoized.
@@ -152,6 +171,42 @@ impl RlpDecodableReceipt for Receipt {
     }
 }
 
+/// RLP-encodes the receipt without the bloom filter, as used by `eth/69` `Receipts` messages.
+impl Encodable for Receipt {
+    fn encode(&self, out: &mut dyn BufMut) {
+        Header { list: true, payload_length: self.rlp_encoded_fields_length_without_bloom() }
+            .encode(out);
+        self.rlp_encode_fields_without_bloom(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.rlp_encoded_fields_length_without_bloom();
+        Header { list: true, payload_length }.length() + payload_length
+    }
+}
+
+impl Decodable for Receipt {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+
+        let remaining = buf.len();
+
+        let tx_type = TxType::decode(buf)?;
+        let success = Decodable::decode(buf)?;
+        let cumulative_gas_used = Decodable::decode(buf)?;
+        let logs = Decodable::decode(buf)?;
+
+        if buf.len() + header.payload_length != remaining {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+
+        Ok(Self { tx_type, success, cumulative_gas_used, logs })
+    }
+}
+
 impl TxReceipt for Receipt {
     type Log = Log;
 
@@ -289,6 +344,34 @@ mod tests {
         assert_eq!(receipt, expected);
     }
 
+    #[test]
+    fn receipt_without_bloom_roundtrip() {
+        let receipt = Receipt {
+            tx_type: TxType::Eip1559,
+            cumulative_gas_used: 0x1u64,
+            logs: vec![Log::new_unchecked(
+                address!("0000000000000000000000000000000000000011"),
+                vec![b256!("000000000000000000000000000000000000000000000000000000000000dead")],
+                bytes!("0100ff"),
+            )],
+            success: true,
+        };
+
+        let expected = hex!("f842020101f83df83b940000000000000000000000000000000000000011e1a0000000000000000000000000000000000000000000000000000000000000dead830100ff");
+        let mut data = Vec::with_capacity(receipt.length());
+        receipt.encode(&mut data);
+        assert_eq!(receipt.length(), expected.len());
+        assert_eq!(data, expected);
+
+        let decoded = Receipt::decode(&mut &data[..]).unwrap();
+        assert_eq!(decoded, receipt);
+
+        let legacy = Receipt { tx_type: TxType::Legacy, ..receipt };
+        let mut data = Vec::new();
+        legacy.encode(&mut data);
+        assert_eq!(Receipt::decode(&mut &data[..]).unwrap(), legacy);
+    }
+
     #[test]
     fn gigantic_receipt() {
         let receipt = Receipt {
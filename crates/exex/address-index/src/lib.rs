@@ -0,0 +1,177 @@
+//! Execution extension that maintains an index of address appearances.
+//!
+//! An address appears in a transaction if it is the sender or the recipient of the transaction,
+//! or if it takes part in any of the internal calls made by the transaction, either as the caller
+//! or as the callee. Contracts created by the transaction are recorded as well.
+//!
+//! The index is written to the [`AddressTransactions`](reth_provider::AddressTransactionsWriter)
+//! tables of the node database, and its progress is stored as a stage checkpoint under
+//! [`ADDRESS_INDEX_STAGE_ID`]. It backs the `ots_searchTransactionsBefore` and
+//! `ots_searchTransactionsAfter` RPC methods, and can be pruned with the `AddressTransactions`
+//! prune segment.
+//!
+//! Every transaction is re-executed with a call tracer, so backfilling the index requires the
+//! historical state of all blocks that are indexed.
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod tracer;
+pub use tracer::transaction_appearances;
+
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{Address, BlockNumber, TxNumber};
+use eyre::eyre;
+use futures_util::TryStreamExt;
+use reth_chainspec::EthereumHardforks;
+use reth_evm::ConfigureEvmFor;
+use reth_exex::{ExExContext, ExExHead};
+use reth_node_api::{FullNodeComponents, NodePrimitives, NodeTypes};
+use reth_provider::{
+    AddressTransactionsWriter, BlockBodyIndicesProvider, BlockHashReader, Chain, ChainSpecProvider,
+    DBProvider, DatabaseProviderFactory, StageCheckpointReader, StageCheckpointWriter,
+    StateProviderFactory, ADDRESS_INDEX_STAGE_ID,
+};
+use reth_stages_types::StageCheckpoint;
+use std::collections::BTreeSet;
+use tracing::{debug, info};
+
+/// The address index execution extension.
+///
+/// Resumes from the last indexed block, indexes all committed blocks and unwinds the index on
+/// reverts.
+pub async fn address_index_exex<Node>(mut ctx: ExExContext<Node>) -> eyre::Result<()>
+where
+    Node: FullNodeComponents<Types: NodeTypes<ChainSpec: EthereumHardforks>>,
+    <Node::Provider as DatabaseProviderFactory>::ProviderRW:
+        AddressTransactionsWriter + StageCheckpointWriter,
+{
+    let head = init_index(ctx.provider())?;
+    info!(target: "exex::address_index", ?head, "Starting address index");
+    ctx.set_notifications_with_head(ExExHead { block: head });
+
+    while let Some(notification) = ctx.notifications.try_next().await? {
+        if let Some(reverted) = notification.reverted_chain() {
+            unwind_chain(ctx.provider(), &reverted)?;
+        }
+
+        if let Some(committed) = notification.committed_chain() {
+            index_chain(ctx.provider(), ctx.evm_config(), &committed)?;
+            ctx.send_finished_height(committed.tip().num_hash())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the last indexed block, marking the index as enabled if it wasn't yet.
+fn init_index<P>(provider: &P) -> eyre::Result<BlockNumHash>
+where
+    P: DatabaseProviderFactory<ProviderRW: StageCheckpointWriter>
+        + StageCheckpointReader
+        + BlockHashReader,
+{
+    let block_number = match provider.get_stage_checkpoint(ADDRESS_INDEX_STAGE_ID)? {
+        Some(checkpoint) => checkpoint.block_number,
+        None => {
+            // Genesis has no transactions, so it's always indexed.
+            save_checkpoint(provider, 0)?;
+            0
+        }
+    };
+    let block_hash = provider
+        .block_hash(block_number)?
+        .ok_or_else(|| eyre!("canonical hash of block {block_number} not found"))?;
+
+    Ok(BlockNumHash::new(block_number, block_hash))
+}
+
+/// Records the address appearances of all transactions in the chain.
+fn index_chain<P, Evm, N>(provider: &P, evm_config: &Evm, chain: &Chain<N>) -> eyre::Result<()>
+where
+    P: DatabaseProviderFactory<ProviderRW: AddressTransactionsWriter + StageCheckpointWriter>
+        + BlockBodyIndicesProvider
+        + StateProviderFactory
+        + ChainSpecProvider<ChainSpec: EthereumHardforks>,
+    Evm: ConfigureEvmFor<N>,
+    N: NodePrimitives,
+{
+    let mut next_tx_number = first_tx_number(provider, chain.first().number())?;
+    let mut appearances: Vec<(TxNumber, BTreeSet<Address>)> = Vec::new();
+    for block in chain.blocks_iter() {
+        let state = provider.history_by_block_hash(block.parent_hash())?;
+        let block_appearances =
+            transaction_appearances::<_, N, _>(evm_config, provider.chain_spec(), state, block)?;
+        for addresses in block_appearances {
+            appearances.push((next_tx_number, addresses));
+            next_tx_number += 1;
+        }
+    }
+    debug!(
+        target: "exex::address_index",
+        range = ?chain.range(),
+        transactions = appearances.len(),
+        "Indexing address appearances"
+    );
+
+    let provider_rw = provider.database_provider_rw()?;
+    provider_rw.insert_address_appearances(appearances)?;
+    provider_rw.save_stage_checkpoint(
+        ADDRESS_INDEX_STAGE_ID,
+        StageCheckpoint::new(chain.tip().number()),
+    )?;
+    provider_rw.commit()?;
+
+    Ok(())
+}
+
+/// Removes the address appearances of all transactions in the chain.
+fn unwind_chain<P, N>(provider: &P, chain: &Chain<N>) -> eyre::Result<()>
+where
+    P: DatabaseProviderFactory<ProviderRW: AddressTransactionsWriter + StageCheckpointWriter>
+        + BlockBodyIndicesProvider,
+    N: NodePrimitives,
+{
+    let fork_block = chain.fork_block();
+    let from_tx = first_tx_number(provider, fork_block.number + 1)?;
+
+    let provider_rw = provider.database_provider_rw()?;
+    let unwound = provider_rw.unwind_address_appearances(from_tx)?;
+    provider_rw
+        .save_stage_checkpoint(ADDRESS_INDEX_STAGE_ID, StageCheckpoint::new(fork_block.number))?;
+    provider_rw.commit()?;
+    debug!(target: "exex::address_index", range = ?chain.range(), %unwound, "Unwound address appearances");
+
+    Ok(())
+}
+
+/// Returns the number of the first transaction in the given block, based on the body indices of
+/// its parent.
+fn first_tx_number<P: BlockBodyIndicesProvider>(
+    provider: &P,
+    block_number: BlockNumber,
+) -> eyre::Result<TxNumber> {
+    let Some(parent) = block_number.checked_sub(1) else { return Ok(0) };
+    let indices = provider
+        .block_body_indices(parent)?
+        .ok_or_else(|| eyre!("body indices of block {parent} not found"))?;
+    Ok(indices.next_tx_num())
+}
+
+/// Saves the index checkpoint at the given block.
+fn save_checkpoint<P>(provider: &P, block_number: BlockNumber) -> eyre::Result<()>
+where
+    P: DatabaseProviderFactory<ProviderRW: StageCheckpointWriter>,
+{
+    let provider_rw = provider.database_provider_rw()?;
+    provider_rw
+        .save_stage_checkpoint(ADDRESS_INDEX_STAGE_ID, StageCheckpoint::new(block_number))?;
+    provider_rw.commit()?;
+    Ok(())
+}
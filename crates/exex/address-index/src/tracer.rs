@@ -0,0 +1,56 @@
+use alloy_consensus::{BlockHeader, Transaction};
+use alloy_primitives::Address;
+use eyre::eyre;
+use reth_chainspec::EthereumHardforks;
+use reth_evm::{system_calls::SystemCaller, ConfigureEvm, ConfigureEvmEnv, ConfigureEvmFor, Evm};
+use reth_primitives_traits::{BlockBody, NodePrimitives, RecoveredBlock, SignedTransaction};
+use reth_provider::StateProviderBox;
+use reth_revm::{database::StateProviderDatabase, db::CacheDB};
+use revm::{context_interface::result::ResultAndState, DatabaseCommit};
+use revm_inspectors::tracing::{TracingInspector, TracingInspectorConfig};
+use std::{collections::BTreeSet, sync::Arc};
+
+/// Re-executes all transactions of the block on top of the given parent state and returns the
+/// addresses that appear in each transaction, in the order of the transactions in the block.
+pub fn transaction_appearances<Evm, N, ChainSpec>(
+    evm_config: &Evm,
+    chain_spec: Arc<ChainSpec>,
+    state: StateProviderBox,
+    block: &RecoveredBlock<N::Block>,
+) -> eyre::Result<Vec<BTreeSet<Address>>>
+where
+    Evm: ConfigureEvmFor<N>,
+    N: NodePrimitives,
+    ChainSpec: EthereumHardforks,
+{
+    let mut db = CacheDB::new(StateProviderDatabase::new(state));
+    let evm_env = evm_config.evm_env(block.header());
+
+    let mut system_caller = SystemCaller::new(evm_config.clone(), chain_spec);
+    system_caller.pre_block_beacon_root_contract_call(
+        &mut db,
+        &evm_env,
+        block.parent_beacon_block_root(),
+    )?;
+    system_caller.pre_block_blockhashes_contract_call(&mut db, &evm_env, block.parent_hash())?;
+
+    let mut appearances = Vec::with_capacity(block.body().transaction_count());
+    for (sender, tx) in block.transactions_with_sender() {
+        let mut inspector = TracingInspector::new(TracingInspectorConfig::default_parity());
+        let ResultAndState { state, .. } = evm_config
+            .evm_with_env_and_inspector(&mut db, evm_env.clone(), &mut inspector)
+            .transact(evm_config.tx_env(tx, *sender))
+            .map_err(|err| eyre!("failed to trace transaction {}: {err}", tx.tx_hash()))?;
+        db.commit(state);
+
+        let mut addresses = BTreeSet::from([*sender]);
+        addresses.extend(tx.to());
+        for node in inspector.into_traces().into_nodes() {
+            addresses.insert(node.trace.caller);
+            addresses.insert(node.trace.address);
+        }
+        appearances.push(addresses);
+    }
+
+    Ok(appearances)
+}
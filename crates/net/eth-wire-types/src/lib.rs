@@ -12,7 +12,7 @@
 extern crate alloc;
 
 mod status;
-pub use status::{Status, StatusBuilder, StatusEth69};
+pub use status::{BlockRangeUpdate, Status, StatusBuilder, StatusEth69, StatusMessage};
 
 pub mod version;
 pub use version::{EthVersion, ProtocolVersion};
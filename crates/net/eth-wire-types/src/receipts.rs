@@ -1,7 +1,7 @@
-//! Implements the `GetReceipts` and `Receipts` message types.
+//! Implements the `GetReceipts`, `Receipts` and `eth/69` `Receipts` message types.
 
 use alloc::vec::Vec;
-use alloy_consensus::{ReceiptWithBloom, RlpDecodableReceipt, RlpEncodableReceipt};
+use alloy_consensus::{ReceiptWithBloom, RlpDecodableReceipt, RlpEncodableReceipt, TxReceipt};
 use alloy_primitives::B256;
 use alloy_rlp::{RlpDecodableWrapper, RlpEncodableWrapper};
 use reth_codecs_derive::add_arbitrary_tests;
@@ -46,10 +46,60 @@ impl<T: RlpDecodableReceipt> alloy_rlp::Decodable for Receipts<T> {
     }
 }
 
+/// The `eth/69` response to [`GetReceipts`], containing receipt lists that correspond to each
+/// block requested.
+///
+/// Unlike [`Receipts`], the receipts don't include the bloom filter, which is recomputed from the
+/// logs by the receiving peer. Each receipt is encoded as
+/// `[tx-type, post-state-or-status, cumulative-gas, logs]`.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
+pub struct Receipts69<T = Receipt>(
+    /// Each receipt list should correspond to a block hash in the request.
+    pub Vec<Vec<T>>,
+);
+
+impl<T: TxReceipt> Receipts69<T> {
+    /// Converts the receipts into [`Receipts`] by computing the bloom filter of each receipt.
+    pub fn into_with_bloom(self) -> Receipts<T> {
+        Receipts(
+            self.0
+                .into_iter()
+                .map(|receipts| {
+                    receipts
+                        .into_iter()
+                        .map(|receipt| ReceiptWithBloom { logs_bloom: receipt.bloom(), receipt })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<T: alloy_rlp::Encodable> alloy_rlp::Encodable for Receipts69<T> {
+    #[inline]
+    fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+        self.0.encode(out)
+    }
+    #[inline]
+    fn length(&self) -> usize {
+        self.0.length()
+    }
+}
+
+impl<T: alloy_rlp::Decodable> alloy_rlp::Decodable for Receipts69<T> {
+    #[inline]
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        alloy_rlp::Decodable::decode(buf).map(Self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{message::RequestPair, GetReceipts, Receipts};
+    use crate::{message::RequestPair, GetReceipts, Receipts, Receipts69};
     use alloy_consensus::TxType;
     use alloy_primitives::{hex, Log};
     use alloy_rlp::{Decodable, Encodable};
@@ -70,6 +120,32 @@ mod tests {
         assert_eq!(receipts, decoded);
     }
 
+    #[test]
+    fn receipts69_into_with_bloom() {
+        let receipt = Receipt {
+            tx_type: TxType::Eip1559,
+            success: true,
+            cumulative_gas_used: 21000,
+            logs: vec![Log::new_unchecked(
+                hex!("0000000000000000000000000000000000000011").into(),
+                vec![],
+                hex!("0100ff")[..].into(),
+            )],
+        };
+        let receipts = Receipts69(vec![vec![receipt.clone()]]);
+
+        let mut out = vec![];
+        receipts.encode(&mut out);
+        assert_eq!(Receipts69::decode(&mut out.as_slice()).unwrap(), receipts);
+
+        let with_bloom = receipts.into_with_bloom();
+        assert_eq!(
+            with_bloom,
+            Receipts(vec![vec![ReceiptWithBloom { logs_bloom: receipt.bloom(), receipt }]])
+        );
+        assert_ne!(with_bloom.0[0][0].logs_bloom, Default::default());
+    }
+
     #[test]
     // Test vector from: https://eips.ethereum.org/EIPS/eip-2481
     fn encode_get_receipts() {
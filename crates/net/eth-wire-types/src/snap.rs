@@ -7,6 +7,8 @@
 
 use alloc::vec::Vec;
 use alloy_primitives::{Bytes, B256};
+use alloy_rlp::{Buf, BufMut, Decodable, Encodable, Header, RlpDecodable, RlpEncodable};
+use reth_codecs_derive::add_arbitrary_tests;
 
 /// Message IDs for the snap sync protocol
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,9 +32,38 @@ pub enum SnapMessageId {
     TrieNodes = 0x07,
 }
 
+impl Encodable for SnapMessageId {
+    fn encode(&self, out: &mut dyn BufMut) {
+        out.put_u8(*self as u8);
+    }
+    fn length(&self) -> usize {
+        1
+    }
+}
+
+impl Decodable for SnapMessageId {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let id = match buf.first().ok_or(alloy_rlp::Error::InputTooShort)? {
+            0x00 => Self::GetAccountRange,
+            0x01 => Self::AccountRange,
+            0x02 => Self::GetStorageRanges,
+            0x03 => Self::StorageRanges,
+            0x04 => Self::GetByteCodes,
+            0x05 => Self::ByteCodes,
+            0x06 => Self::GetTrieNodes,
+            0x07 => Self::TrieNodes,
+            _ => return Err(alloy_rlp::Error::Custom("Invalid message ID")),
+        };
+        buf.advance(1);
+        Ok(id)
+    }
+}
+
 /// Request for a range of accounts from the state trie.
 // https://github.com/ethereum/devp2p/blob/master/caps/snap.md#getaccountrange-0x00
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct GetAccountRangeMessage {
     /// Request ID to match up responses with
     pub request_id: u64,
@@ -47,7 +78,9 @@ pub struct GetAccountRangeMessage {
 }
 
 /// Account data in the response.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct AccountData {
     /// Hash of the account address (trie path)
     pub hash: B256,
@@ -57,7 +90,9 @@ pub struct AccountData {
 
 /// Response containing a number of consecutive accounts and the Merkle proofs for the entire range.
 // http://github.com/ethereum/devp2p/blob/master/caps/snap.md#accountrange-0x01
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct AccountRangeMessage {
     /// ID of the request this is a response for
     pub request_id: u64,
@@ -69,7 +104,9 @@ pub struct AccountRangeMessage {
 
 /// Request for the storage slots of multiple accounts' storage tries.
 // https://github.com/ethereum/devp2p/blob/master/caps/snap.md#getstorageranges-0x02
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct GetStorageRangesMessage {
     /// Request ID to match up responses with
     pub request_id: u64,
@@ -86,7 +123,9 @@ pub struct GetStorageRangesMessage {
 }
 
 /// Storage slot data in the response.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct StorageData {
     /// Hash of the storage slot key (trie path)
     pub hash: B256,
@@ -98,7 +137,9 @@ pub struct StorageData {
 /// and optionally the merkle proofs for the last range (boundary proofs) if it only partially
 /// covers the storage trie.
 // https://github.com/ethereum/devp2p/blob/master/caps/snap.md#storageranges-0x03
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct StorageRangesMessage {
     /// ID of the request this is a response for
     pub request_id: u64,
@@ -110,7 +151,9 @@ pub struct StorageRangesMessage {
 
 /// Request to get a number of requested contract codes.
 // https://github.com/ethereum/devp2p/blob/master/caps/snap.md#getbytecodes-0x04
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct GetByteCodesMessage {
     /// Request ID to match up responses with
     pub request_id: u64,
@@ -122,7 +165,9 @@ pub struct GetByteCodesMessage {
 
 /// Response containing a number of requested contract codes.
 // https://github.com/ethereum/devp2p/blob/master/caps/snap.md#bytecodes-0x05
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct ByteCodesMessage {
     /// ID of the request this is a response for
     pub request_id: u64,
@@ -131,7 +176,11 @@ pub struct ByteCodesMessage {
 }
 
 /// Path in the trie for an account and its storage
+///
+/// This is encoded as a flat list of the account path followed by the slot paths.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct TriePath {
     /// Path in the account trie
     pub account_path: Bytes,
@@ -139,9 +188,54 @@ pub struct TriePath {
     pub slot_paths: Vec<Bytes>,
 }
 
+impl TriePath {
+    fn payload_length(&self) -> usize {
+        self.account_path.length() + self.slot_paths.iter().map(Encodable::length).sum::<usize>()
+    }
+}
+
+impl Encodable for TriePath {
+    fn encode(&self, out: &mut dyn BufMut) {
+        Header { list: true, payload_length: self.payload_length() }.encode(out);
+        self.account_path.encode(out);
+        for slot_path in &self.slot_paths {
+            slot_path.encode(out);
+        }
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.payload_length();
+        payload_length + alloy_rlp::length_of_length(payload_length)
+    }
+}
+
+impl Decodable for TriePath {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString)
+        }
+        if buf.len() < header.payload_length {
+            return Err(alloy_rlp::Error::InputTooShort)
+        }
+
+        let mut payload = &buf[..header.payload_length];
+        let account_path = Bytes::decode(&mut payload)?;
+        let mut slot_paths = Vec::new();
+        while !payload.is_empty() {
+            slot_paths.push(Bytes::decode(&mut payload)?);
+        }
+        buf.advance(header.payload_length);
+
+        Ok(Self { account_path, slot_paths })
+    }
+}
+
 /// Request a number of state (either account or storage) Merkle trie nodes by path
 // https://github.com/ethereum/devp2p/blob/master/caps/snap.md#gettrienodes-0x06
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct GetTrieNodesMessage {
     /// Request ID to match up responses with
     pub request_id: u64,
@@ -155,7 +249,9 @@ pub struct GetTrieNodesMessage {
 
 /// Response containing a number of requested state trie nodes
 // https://github.com/ethereum/devp2p/blob/master/caps/snap.md#trienodes-0x07
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
 pub struct TrieNodesMessage {
     /// ID of the request this is a response for
     pub request_id: u64,
@@ -200,4 +296,99 @@ impl SnapProtocolMessage {
             Self::TrieNodes(_) => SnapMessageId::TrieNodes,
         }
     }
+
+    /// Decodes a message from the given buffer, which starts with the message ID.
+    pub fn decode_message(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let message = match SnapMessageId::decode(buf)? {
+            SnapMessageId::GetAccountRange => {
+                Self::GetAccountRange(GetAccountRangeMessage::decode(buf)?)
+            }
+            SnapMessageId::AccountRange => Self::AccountRange(AccountRangeMessage::decode(buf)?),
+            SnapMessageId::GetStorageRanges => {
+                Self::GetStorageRanges(GetStorageRangesMessage::decode(buf)?)
+            }
+            SnapMessageId::StorageRanges => Self::StorageRanges(StorageRangesMessage::decode(buf)?),
+            SnapMessageId::GetByteCodes => Self::GetByteCodes(GetByteCodesMessage::decode(buf)?),
+            SnapMessageId::ByteCodes => Self::ByteCodes(ByteCodesMessage::decode(buf)?),
+            SnapMessageId::GetTrieNodes => Self::GetTrieNodes(GetTrieNodesMessage::decode(buf)?),
+            SnapMessageId::TrieNodes => Self::TrieNodes(TrieNodesMessage::decode(buf)?),
+        };
+        Ok(message)
+    }
+}
+
+impl Encodable for SnapProtocolMessage {
+    /// Encodes the message into bytes. The message ID is encoded as a single byte and prepended
+    /// to the message.
+    fn encode(&self, out: &mut dyn BufMut) {
+        self.message_id().encode(out);
+        match self {
+            Self::GetAccountRange(message) => message.encode(out),
+            Self::AccountRange(message) => message.encode(out),
+            Self::GetStorageRanges(message) => message.encode(out),
+            Self::StorageRanges(message) => message.encode(out),
+            Self::GetByteCodes(message) => message.encode(out),
+            Self::ByteCodes(message) => message.encode(out),
+            Self::GetTrieNodes(message) => message.encode(out),
+            Self::TrieNodes(message) => message.encode(out),
+        }
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = match self {
+            Self::GetAccountRange(message) => message.length(),
+            Self::AccountRange(message) => message.length(),
+            Self::GetStorageRanges(message) => message.length(),
+            Self::StorageRanges(message) => message.length(),
+            Self::GetByteCodes(message) => message.length(),
+            Self::ByteCodes(message) => message.length(),
+            Self::GetTrieNodes(message) => message.length(),
+            Self::TrieNodes(message) => message.length(),
+        };
+        self.message_id().length() + payload_length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn trie_path_is_flat_list() {
+        let path = TriePath {
+            account_path: Bytes::from_static(&[0x12]),
+            slot_paths: vec![Bytes::from_static(&[0x34]), Bytes::from_static(&[0x00, 0x56])],
+        };
+        let encoded = alloy_rlp::encode(&path);
+        assert_eq!(encoded, hex!("c6123481820056"));
+        assert_eq!(encoded.len(), path.length());
+        assert_eq!(TriePath::decode(&mut &encoded[..]).unwrap(), path);
+
+        let path = TriePath { account_path: Bytes::from_static(&[0x12]), slot_paths: vec![] };
+        assert_eq!(alloy_rlp::encode(&path), hex!("c112"));
+    }
+
+    #[test]
+    fn snap_message_roundtrip() {
+        let message = SnapProtocolMessage::GetAccountRange(GetAccountRangeMessage {
+            request_id: 1,
+            root_hash: B256::repeat_byte(0x01),
+            starting_hash: B256::ZERO,
+            limit_hash: B256::repeat_byte(0xff),
+            response_bytes: 512 * 1024,
+        });
+        let encoded = alloy_rlp::encode(&message);
+        assert_eq!(encoded[0], SnapMessageId::GetAccountRange as u8);
+        assert_eq!(encoded.len(), message.length());
+        assert_eq!(SnapProtocolMessage::decode_message(&mut &encoded[..]).unwrap(), message);
+
+        let message = SnapProtocolMessage::TrieNodes(TrieNodesMessage {
+            request_id: 2,
+            nodes: vec![Bytes::from_static(&[0xc0]), Bytes::new()],
+        });
+        let encoded = alloy_rlp::encode(&message);
+        assert_eq!(encoded[0], SnapMessageId::TrieNodes as u8);
+        assert_eq!(SnapProtocolMessage::decode_message(&mut &encoded[..]).unwrap(), message);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::EthVersion;
 use alloy_chains::{Chain, NamedChain};
 use alloy_primitives::{hex, B256, U256};
-use alloy_rlp::{RlpDecodable, RlpEncodable};
+use alloy_rlp::{BufMut, Decodable, Encodable, RlpDecodable, RlpEncodable};
 use core::fmt::{Debug, Display};
 use reth_chainspec::{EthChainSpec, Hardforks, MAINNET};
 use reth_codecs_derive::add_arbitrary_tests;
@@ -213,8 +213,10 @@ impl StatusBuilder {
     }
 }
 
-/// Similar to [`Status`], but for `eth/69` version, which does not contain
-/// the `total_difficulty` field.
+/// Similar to [`Status`], but for `eth/69` version, which does not contain the
+/// `total_difficulty` field and advertises the range of blocks the peer can serve instead.
+///
+/// See also <https://eips.ethereum.org/EIPS/eip-7642>
 #[derive(Copy, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
@@ -228,9 +230,6 @@ pub struct StatusEth69 {
     /// [EIP155](https://eips.ethereum.org/EIPS/eip-155#list-of-chain-ids).
     pub chain: Chain,
 
-    /// The highest difficulty block hash the peer has seen
-    pub blockhash: B256,
-
     /// The genesis hash of the peer's chain.
     pub genesis: B256,
 
@@ -240,6 +239,42 @@ pub struct StatusEth69 {
     /// [EIP-2124](https://github.com/ethereum/EIPs/blob/master/EIPS/eip-2124.md).
     /// This was added in [`eth/64`](https://eips.ethereum.org/EIPS/eip-2364)
     pub forkid: ForkId,
+
+    /// The earliest block the peer can serve.
+    pub earliest: u64,
+
+    /// The latest block the peer can serve.
+    pub latest: u64,
+
+    /// The hash of the latest block the peer can serve.
+    pub blockhash: B256,
+}
+
+impl StatusEth69 {
+    /// Creates the `eth/69` status from the given [`Status`] and the range of blocks this node can
+    /// serve.
+    ///
+    /// The `blockhash` of the [`Status`] is expected to be the hash of the `latest` block.
+    pub const fn from_status(status: Status, earliest: u64, latest: u64) -> Self {
+        Self {
+            version: EthVersion::Eth69,
+            chain: status.chain,
+            genesis: status.genesis,
+            forkid: status.forkid,
+            earliest,
+            latest,
+            blockhash: status.blockhash,
+        }
+    }
+
+    /// Returns the range of blocks the peer can serve as a [`BlockRangeUpdate`].
+    pub const fn block_range(&self) -> BlockRangeUpdate {
+        BlockRangeUpdate {
+            earliest: self.earliest,
+            latest: self.latest,
+            latest_hash: self.blockhash,
+        }
+    }
 }
 
 impl Display for StatusEth69 {
@@ -248,8 +283,14 @@ impl Display for StatusEth69 {
         let hexed_genesis = hex::encode(self.genesis);
         write!(
             f,
-            "Status {{ version: {}, chain: {}, blockhash: {}, genesis: {}, forkid: {:X?} }}",
-            self.version, self.chain, hexed_blockhash, hexed_genesis, self.forkid
+            "Status {{ version: {}, chain: {}, genesis: {}, forkid: {:X?}, earliest: {}, latest: {}, blockhash: {} }}",
+            self.version,
+            self.chain,
+            hexed_genesis,
+            self.forkid,
+            self.earliest,
+            self.latest,
+            hexed_blockhash,
         )
     }
 }
@@ -261,22 +302,26 @@ impl Debug for StatusEth69 {
         if f.alternate() {
             write!(
                 f,
-                "Status {{\n\tversion: {:?},\n\tchain: {:?},\n\tblockhash: {},\n\tgenesis: {},\n\tforkid: {:X?}\n}}",
+                "Status {{\n\tversion: {:?},\n\tchain: {:?},\n\tgenesis: {},\n\tforkid: {:X?},\n\tearliest: {},\n\tlatest: {},\n\tblockhash: {}\n}}",
                 self.version,
                 self.chain,
-                hexed_blockhash,
                 hexed_genesis,
-                self.forkid
+                self.forkid,
+                self.earliest,
+                self.latest,
+                hexed_blockhash,
             )
         } else {
             write!(
                 f,
-                "Status {{ version: {:?}, chain: {:?}, blockhash: {}, genesis: {}, forkid: {:X?} }}",
+                "Status {{ version: {:?}, chain: {:?}, genesis: {}, forkid: {:X?}, earliest: {}, latest: {}, blockhash: {} }}",
                 self.version,
                 self.chain,
-                hexed_blockhash,
                 hexed_genesis,
-                self.forkid
+                self.forkid,
+                self.earliest,
+                self.latest,
+                hexed_blockhash,
             )
         }
     }
@@ -285,25 +330,187 @@ impl Debug for StatusEth69 {
 // <https://etherscan.io/block/0>
 impl Default for StatusEth69 {
     fn default() -> Self {
-        Status::default().into()
+        Self::from_status(Status::default(), 0, 0)
     }
 }
 
-impl From<Status> for StatusEth69 {
-    fn from(status: Status) -> Self {
-        Self {
-            version: EthVersion::Eth69,
-            chain: status.chain,
-            blockhash: status.blockhash,
-            genesis: status.genesis,
-            forkid: status.forkid,
+/// The `BlockRangeUpdate` message of `eth/69`, which announces the range of blocks a peer can
+/// serve.
+///
+/// Peers send this whenever their range changes, so that requests for blocks outside of it can be
+/// avoided.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
+pub struct BlockRangeUpdate {
+    /// The earliest block the peer can serve.
+    pub earliest: u64,
+    /// The latest block the peer can serve.
+    pub latest: u64,
+    /// The hash of the latest block the peer can serve.
+    pub latest_hash: B256,
+}
+
+impl BlockRangeUpdate {
+    /// Returns true if the range is well-formed, i.e. it doesn't end before it starts.
+    pub const fn is_valid(&self) -> bool {
+        self.earliest <= self.latest
+    }
+
+    /// Returns true if the peer can serve the given block.
+    pub const fn contains(&self, block: u64) -> bool {
+        self.earliest <= block && block <= self.latest
+    }
+}
+
+/// The status message that is exchanged in the handshake, which depends on the negotiated
+/// [`EthVersion`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatusMessage {
+    /// The [`Status`] message of versions prior to `eth/69`.
+    Legacy(Status),
+    /// The [`StatusEth69`] message of `eth/69`.
+    Eth69(StatusEth69),
+}
+
+impl StatusMessage {
+    /// Creates the status message for the version of the given [`Status`].
+    ///
+    /// If the version is `eth/69`, the given range of blocks this node can serve is included.
+    pub const fn new(status: Status, earliest: u64, latest: u64) -> Self {
+        if status.version.is_eth69() {
+            Self::Eth69(StatusEth69::from_status(status, earliest, latest))
+        } else {
+            Self::Legacy(status)
+        }
+    }
+
+    /// Decodes the status message of the given [`EthVersion`].
+    pub fn decode_versioned(version: EthVersion, buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        if version.is_eth69() {
+            StatusEth69::decode(buf).map(Self::Eth69)
+        } else {
+            Status::decode(buf).map(Self::Legacy)
+        }
+    }
+
+    /// Returns the protocol version.
+    pub const fn version(&self) -> EthVersion {
+        match self {
+            Self::Legacy(status) => status.version,
+            Self::Eth69(status) => status.version,
+        }
+    }
+
+    /// Returns the chain id.
+    pub const fn chain(&self) -> &Chain {
+        match self {
+            Self::Legacy(status) => &status.chain,
+            Self::Eth69(status) => &status.chain,
+        }
+    }
+
+    /// Returns the genesis hash.
+    pub const fn genesis(&self) -> B256 {
+        match self {
+            Self::Legacy(status) => status.genesis,
+            Self::Eth69(status) => status.genesis,
+        }
+    }
+
+    /// Returns the fork id.
+    pub const fn forkid(&self) -> ForkId {
+        match self {
+            Self::Legacy(status) => status.forkid,
+            Self::Eth69(status) => status.forkid,
         }
     }
+
+    /// Returns the hash of the best block.
+    pub const fn blockhash(&self) -> B256 {
+        match self {
+            Self::Legacy(status) => status.blockhash,
+            Self::Eth69(status) => status.blockhash,
+        }
+    }
+
+    /// Returns the total difficulty, which is not included in `eth/69`.
+    pub const fn total_difficulty(&self) -> Option<U256> {
+        match self {
+            Self::Legacy(status) => Some(status.total_difficulty),
+            Self::Eth69(_) => None,
+        }
+    }
+
+    /// Returns the advertised range of blocks the peer can serve, which is only included in
+    /// `eth/69`.
+    pub const fn block_range(&self) -> Option<BlockRangeUpdate> {
+        match self {
+            Self::Legacy(_) => None,
+            Self::Eth69(status) => Some(status.block_range()),
+        }
+    }
+
+    /// Converts the message into a [`Status`].
+    ///
+    /// The total difficulty is zero for `eth/69`, since it's not exchanged anymore.
+    pub const fn into_status(self) -> Status {
+        match self {
+            Self::Legacy(status) => status,
+            Self::Eth69(status) => Status {
+                version: status.version,
+                chain: status.chain,
+                total_difficulty: U256::ZERO,
+                blockhash: status.blockhash,
+                genesis: status.genesis,
+                forkid: status.forkid,
+            },
+        }
+    }
+}
+
+impl Encodable for StatusMessage {
+    fn encode(&self, out: &mut dyn BufMut) {
+        match self {
+            Self::Legacy(status) => status.encode(out),
+            Self::Eth69(status) => status.encode(out),
+        }
+    }
+
+    fn length(&self) -> usize {
+        match self {
+            Self::Legacy(status) => status.length(),
+            Self::Eth69(status) => status.length(),
+        }
+    }
+}
+
+impl Display for StatusMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Legacy(status) => Display::fmt(status, f),
+            Self::Eth69(status) => Display::fmt(status, f),
+        }
+    }
+}
+
+impl From<Status> for StatusMessage {
+    fn from(status: Status) -> Self {
+        Self::Legacy(status)
+    }
+}
+
+impl From<StatusEth69> for StatusMessage {
+    fn from(status: StatusEth69) -> Self {
+        Self::Eth69(status)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{EthVersion, Status, StatusEth69};
+    use crate::{BlockRangeUpdate, EthVersion, Status, StatusEth69, StatusMessage};
     use alloy_consensus::constants::MAINNET_GENESIS_HASH;
     use alloy_genesis::Genesis;
     use alloy_primitives::{hex, B256, U256};
@@ -356,56 +563,70 @@ mod tests {
         let status = StatusEth69 {
             version: EthVersion::Eth69,
             chain: Chain::from_named(NamedChain::Mainnet),
-            blockhash: B256::from_str(
-                "feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d",
-            )
-            .unwrap(),
             genesis: MAINNET_GENESIS_HASH,
             forkid: ForkId { hash: ForkHash([0xb7, 0x15, 0x07, 0x7d]), next: 0 },
-        };
-        let status_converted: StatusEth69 = Status {
-            version: EthVersion::Eth69,
-            chain: Chain::from_named(NamedChain::Mainnet),
-            total_difficulty: U256::from(36206751599115524359527u128),
+            earliest: 0,
+            latest: 21_000_000,
             blockhash: B256::from_str(
                 "feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d",
             )
             .unwrap(),
-            genesis: MAINNET_GENESIS_HASH,
-            forkid: ForkId { hash: ForkHash([0xb7, 0x15, 0x07, 0x7d]), next: 0 },
-        }
-        .into();
+        };
+        let status_converted = StatusEth69::from_status(
+            Status {
+                version: EthVersion::Eth69,
+                chain: Chain::from_named(NamedChain::Mainnet),
+                total_difficulty: U256::from(36206751599115524359527u128),
+                blockhash: B256::from_str(
+                    "feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d",
+                )
+                .unwrap(),
+                genesis: MAINNET_GENESIS_HASH,
+                forkid: ForkId { hash: ForkHash([0xb7, 0x15, 0x07, 0x7d]), next: 0 },
+            },
+            0,
+            21_000_000,
+        );
         assert_eq!(status, status_converted);
     }
 
     #[test]
     fn encode_eth69_status_message() {
-        let expected = hex!("f84b4501a0feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13da0d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3c684b715077d80");
+        let expected = hex!("f8514501a0d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3c684b715077d80808401406f40a0feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d");
         let status = StatusEth69 {
             version: EthVersion::Eth69,
             chain: Chain::from_named(NamedChain::Mainnet),
+            genesis: MAINNET_GENESIS_HASH,
+            forkid: ForkId { hash: ForkHash([0xb7, 0x15, 0x07, 0x7d]), next: 0 },
+            earliest: 0,
+            latest: 21_000_000,
             blockhash: B256::from_str(
                 "feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d",
             )
             .unwrap(),
-            genesis: MAINNET_GENESIS_HASH,
-            forkid: ForkId { hash: ForkHash([0xb7, 0x15, 0x07, 0x7d]), next: 0 },
         };
 
         let mut rlp_status = vec![];
         status.encode(&mut rlp_status);
         assert_eq!(rlp_status, expected);
 
-        let status: StatusEth69 = Status::builder()
-            .chain(Chain::from_named(NamedChain::Mainnet))
-            .blockhash(
-                B256::from_str("feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d")
+        let status = StatusMessage::new(
+            Status::builder()
+                .version(EthVersion::Eth69)
+                .chain(Chain::from_named(NamedChain::Mainnet))
+                .blockhash(
+                    B256::from_str(
+                        "feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d",
+                    )
                     .unwrap(),
-            )
-            .genesis(MAINNET_GENESIS_HASH)
-            .forkid(ForkId { hash: ForkHash([0xb7, 0x15, 0x07, 0x7d]), next: 0 })
-            .build()
-            .into();
+                )
+                .genesis(MAINNET_GENESIS_HASH)
+                .forkid(ForkId { hash: ForkHash([0xb7, 0x15, 0x07, 0x7d]), next: 0 })
+                .build(),
+            0,
+            21_000_000,
+        );
+        assert!(matches!(status, StatusMessage::Eth69(_)));
         let mut rlp_status = vec![];
         status.encode(&mut rlp_status);
         assert_eq!(rlp_status, expected);
@@ -413,19 +634,57 @@ mod tests {
 
     #[test]
     fn decode_eth69_status_message() {
-        let data = hex!("0xf84b4501a0feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13da0d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3c684b715077d80");
+        let data = hex!("0xf8514501a0d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3c684b715077d80808401406f40a0feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d");
         let expected = StatusEth69 {
             version: EthVersion::Eth69,
             chain: Chain::from_named(NamedChain::Mainnet),
+            genesis: MAINNET_GENESIS_HASH,
+            forkid: ForkId { hash: ForkHash([0xb7, 0x15, 0x07, 0x7d]), next: 0 },
+            earliest: 0,
+            latest: 21_000_000,
             blockhash: B256::from_str(
                 "feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d",
             )
             .unwrap(),
-            genesis: MAINNET_GENESIS_HASH,
-            forkid: ForkId { hash: ForkHash([0xb7, 0x15, 0x07, 0x7d]), next: 0 },
         };
         let status = StatusEth69::decode(&mut &data[..]).unwrap();
         assert_eq!(status, expected);
+
+        let status = StatusMessage::decode_versioned(EthVersion::Eth69, &mut &data[..]).unwrap();
+        assert_eq!(status, StatusMessage::Eth69(expected));
+        assert_eq!(
+            status.block_range(),
+            Some(BlockRangeUpdate {
+                earliest: 0,
+                latest: 21_000_000,
+                latest_hash: expected.blockhash
+            })
+        );
+        assert_eq!(status.total_difficulty(), None);
+    }
+
+    #[test]
+    fn block_range_update_roundtrip() {
+        let expected = hex!(
+            "e7808401406f40a0feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d"
+        );
+        let update = BlockRangeUpdate {
+            earliest: 0,
+            latest: 21_000_000,
+            latest_hash: B256::from_str(
+                "feb27336ca7923f8fab3bd617fcb6e75841538f71c1bcfc267d7838489d9e13d",
+            )
+            .unwrap(),
+        };
+        let mut rlp = vec![];
+        update.encode(&mut rlp);
+        assert_eq!(rlp, expected);
+        assert_eq!(BlockRangeUpdate::decode(&mut &expected[..]).unwrap(), update);
+
+        assert!(update.is_valid());
+        assert!(update.contains(21_000_000));
+        assert!(!update.contains(21_000_001));
+        assert!(!BlockRangeUpdate { earliest: 2, latest: 1, ..update }.is_valid());
     }
 
     #[test]
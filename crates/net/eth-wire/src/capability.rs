@@ -161,7 +161,7 @@ impl SharedCapability {
     /// Returns the number of protocol messages supported by this capability.
     pub const fn num_messages(&self) -> u8 {
         match self {
-            Self::Eth { version: _version, .. } => EthMessageID::max() + 1,
+            Self::Eth { version, .. } => EthMessageID::message_count(*version),
             Self::UnknownCapability { messages, .. } => *messages,
         }
     }
@@ -442,6 +442,34 @@ mod tests {
         assert!(capabilities.supports_eth_v66());
         assert!(capabilities.supports_eth_v67());
         assert!(capabilities.supports_eth_v68());
+        assert!(!capabilities.supports_eth_v69());
+
+        let capabilities: Capabilities = vec![Capability::new_static("eth", 69)].into();
+        assert!(capabilities.supports_eth());
+        assert!(capabilities.supports_eth_v69());
+    }
+
+    #[test]
+    fn test_eth69_reserves_block_range_update() {
+        let cap = Capability::new_static("snap", 1);
+        let proto = Protocol::new(cap.clone(), 8);
+
+        // eth/68 reserves the message ids up to `Receipts`
+        let local_capabilities = vec![EthVersion::Eth68.into(), proto.clone()];
+        let peer_capabilities = vec![EthVersion::Eth68.into(), cap.clone()];
+        let shared = SharedCapabilities::try_new(local_capabilities, peer_capabilities).unwrap();
+        let snap = shared.find_by_relative_offset(EthMessageID::Receipts as u8 + 1).unwrap();
+        assert_eq!(snap.name(), "snap");
+
+        // eth/69 additionally reserves `BlockRangeUpdate`
+        let local_capabilities = vec![EthVersion::Eth69.into(), proto];
+        let peer_capabilities = vec![EthVersion::Eth69.into(), cap];
+        let shared = SharedCapabilities::try_new(local_capabilities, peer_capabilities).unwrap();
+        let eth = shared.find_by_relative_offset(EthMessageID::BlockRangeUpdate as u8).unwrap();
+        assert_eq!(eth.name(), "eth");
+        let snap =
+            shared.find_by_relative_offset(EthMessageID::BlockRangeUpdate as u8 + 1).unwrap();
+        assert_eq!(snap.name(), "snap");
     }
 
     #[test]
@@ -38,6 +38,14 @@ pub enum EthStreamError {
         /// The number of transaction sizes.
         sizes_len: usize,
     },
+    /// Received a `BlockRangeUpdate` message with an invalid range.
+    #[error("invalid block range update: earliest {earliest} > latest {latest}")]
+    InvalidBlockRange {
+        /// The earliest block number of the range.
+        earliest: u64,
+        /// The latest block number of the range.
+        latest: u64,
+    },
     /// Error when data is not received from peer for a prolonged period.
     #[error("never received data from remote peer")]
     StreamTimeout,
@@ -102,4 +110,12 @@ pub enum EthHandshakeError {
         /// The maximum allowed bit length for the total difficulty.
         maximum: usize,
     },
+    #[error("invalid block range in status message: earliest {earliest} > latest {latest}")]
+    /// The range of blocks advertised in an `eth/69` status message ends before it starts.
+    InvalidBlockRange {
+        /// The earliest block advertised by the peer.
+        earliest: u64,
+        /// The latest block advertised by the peer.
+        latest: u64,
+    },
 }
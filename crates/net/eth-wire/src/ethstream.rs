@@ -4,7 +4,7 @@ use crate::{
     message::{EthBroadcastMessage, ProtocolBroadcastMessage},
     p2pstream::HANDSHAKE_TIMEOUT,
     CanDisconnect, DisconnectReason, EthMessage, EthNetworkPrimitives, EthVersion, ProtocolMessage,
-    Status,
+    StatusMessage,
 };
 use alloy_primitives::bytes::{Bytes, BytesMut};
 use alloy_rlp::Encodable;
@@ -58,21 +58,24 @@ where
     /// Consumes the [`UnauthedEthStream`] and returns an [`EthStream`] after the `Status`
     /// handshake is completed successfully. This also returns the `Status` message sent by the
     /// remote peer.
+    ///
+    /// The [`StatusMessage`] must match the version of the negotiated `eth` capability, since the
+    /// `eth/69` status has a different format.
     pub async fn handshake<N: NetworkPrimitives>(
         self,
-        status: Status,
+        status: StatusMessage,
         fork_filter: ForkFilter,
-    ) -> Result<(EthStream<S, N>, Status), EthStreamError> {
+    ) -> Result<(EthStream<S, N>, StatusMessage), EthStreamError> {
         self.handshake_with_timeout(status, fork_filter, HANDSHAKE_TIMEOUT).await
     }
 
     /// Wrapper around handshake which enforces a timeout.
     pub async fn handshake_with_timeout<N: NetworkPrimitives>(
         self,
-        status: Status,
+        status: StatusMessage,
         fork_filter: ForkFilter,
         timeout_limit: Duration,
-    ) -> Result<(EthStream<S, N>, Status), EthStreamError> {
+    ) -> Result<(EthStream<S, N>, StatusMessage), EthStreamError> {
         timeout(timeout_limit, Self::handshake_without_timeout(self, status, fork_filter))
             .await
             .map_err(|_| EthStreamError::StreamTimeout)?
@@ -81,9 +84,9 @@ where
     /// Handshake with no timeout
     pub async fn handshake_without_timeout<N: NetworkPrimitives>(
         mut self,
-        status: Status,
+        status: StatusMessage,
         fork_filter: ForkFilter,
-    ) -> Result<(EthStream<S, N>, Status), EthStreamError> {
+    ) -> Result<(EthStream<S, N>, StatusMessage), EthStreamError> {
         trace!(
             %status,
             "sending eth status to peer"
@@ -113,7 +116,7 @@ where
             return Err(EthStreamError::MessageTooBig(their_msg.len()))
         }
 
-        let version = status.version;
+        let version = status.version();
         let msg = match ProtocolMessage::<N>::decode_message(version, &mut their_msg.as_ref()) {
             Ok(m) => m,
             Err(err) => {
@@ -131,45 +134,59 @@ where
                     status=%resp,
                     "validating incoming eth status from peer"
                 );
-                if status.genesis != resp.genesis {
+                if status.genesis() != resp.genesis() {
                     self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
                     return Err(EthHandshakeError::MismatchedGenesis(
-                        GotExpected { expected: status.genesis, got: resp.genesis }.into(),
+                        GotExpected { expected: status.genesis(), got: resp.genesis() }.into(),
                     )
                     .into())
                 }
 
-                if status.version != resp.version {
+                if status.version() != resp.version() {
                     self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
                     return Err(EthHandshakeError::MismatchedProtocolVersion(GotExpected {
-                        got: resp.version,
-                        expected: status.version,
+                        got: resp.version(),
+                        expected: status.version(),
                     })
                     .into())
                 }
 
-                if status.chain != resp.chain {
+                if status.chain() != resp.chain() {
                     self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
                     return Err(EthHandshakeError::MismatchedChain(GotExpected {
-                        got: resp.chain,
-                        expected: status.chain,
+                        got: *resp.chain(),
+                        expected: *status.chain(),
                     })
                     .into())
                 }
 
                 // TD at mainnet block #7753254 is 76 bits. If it becomes 100 million times
                 // larger, it will still fit within 160 bits
-                if status.total_difficulty.bit_len() > 160 {
-                    self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
-                    return Err(EthHandshakeError::TotalDifficultyBitLenTooLarge {
-                        got: status.total_difficulty.bit_len(),
-                        maximum: 160,
+                if let Some(total_difficulty) = status.total_difficulty() {
+                    if total_difficulty.bit_len() > 160 {
+                        self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
+                        return Err(EthHandshakeError::TotalDifficultyBitLenTooLarge {
+                            got: total_difficulty.bit_len(),
+                            maximum: 160,
+                        }
+                        .into())
+                    }
+                }
+
+                // eth/69 peers advertise the range of blocks they can serve instead
+                if let Some(range) = resp.block_range() {
+                    if !range.is_valid() {
+                        self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
+                        return Err(EthHandshakeError::InvalidBlockRange {
+                            earliest: range.earliest,
+                            latest: range.latest,
+                        }
+                        .into())
                     }
-                    .into())
                 }
 
                 if let Err(err) =
-                    fork_filter.validate(resp.forkid).map_err(EthHandshakeError::InvalidFork)
+                    fork_filter.validate(resp.forkid()).map_err(EthHandshakeError::InvalidFork)
                 {
                     self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
                     return Err(err.into())
@@ -377,7 +394,7 @@ mod tests {
         hello::DEFAULT_TCP_PORT,
         p2pstream::UnauthedP2PStream,
         EthMessage, EthStream, EthVersion, HelloMessageWithProtocols, PassthroughCodec,
-        ProtocolVersion, Status,
+        ProtocolVersion, Status, StatusMessage,
     };
     use alloy_chains::NamedChain;
     use alloy_primitives::{bytes::Bytes, B256, U256};
@@ -417,12 +434,12 @@ mod tests {
             let (incoming, _) = listener.accept().await.unwrap();
             let stream = PassthroughCodec::default().framed(incoming);
             let (_, their_status) = UnauthedEthStream::new(stream)
-                .handshake::<EthNetworkPrimitives>(status_clone, fork_filter_clone)
+                .handshake::<EthNetworkPrimitives>(status_clone.into(), fork_filter_clone)
                 .await
                 .unwrap();
 
             // just make sure it equals our status (our status is a clone of their status)
-            assert_eq!(their_status, status_clone);
+            assert_eq!(their_status, StatusMessage::Legacy(status_clone));
         });
 
         let outgoing = TcpStream::connect(local_addr).await.unwrap();
@@ -430,12 +447,12 @@ mod tests {
 
         // try to connect
         let (_, their_status) = UnauthedEthStream::new(sink)
-            .handshake::<EthNetworkPrimitives>(status, fork_filter)
+            .handshake::<EthNetworkPrimitives>(status.into(), fork_filter)
             .await
             .unwrap();
 
         // their status is a clone of our status, these should be equal
-        assert_eq!(their_status, status);
+        assert_eq!(their_status, StatusMessage::Legacy(status));
 
         // wait for it to finish
         handle.await.unwrap();
@@ -466,12 +483,12 @@ mod tests {
             let (incoming, _) = listener.accept().await.unwrap();
             let stream = PassthroughCodec::default().framed(incoming);
             let (_, their_status) = UnauthedEthStream::new(stream)
-                .handshake::<EthNetworkPrimitives>(status_clone, fork_filter_clone)
+                .handshake::<EthNetworkPrimitives>(status_clone.into(), fork_filter_clone)
                 .await
                 .unwrap();
 
             // just make sure it equals our status, and that the handshake succeeded
-            assert_eq!(their_status, status_clone);
+            assert_eq!(their_status, StatusMessage::Legacy(status_clone));
         });
 
         let outgoing = TcpStream::connect(local_addr).await.unwrap();
@@ -479,12 +496,12 @@ mod tests {
 
         // try to connect
         let (_, their_status) = UnauthedEthStream::new(sink)
-            .handshake::<EthNetworkPrimitives>(status, fork_filter)
+            .handshake::<EthNetworkPrimitives>(status.into(), fork_filter)
             .await
             .unwrap();
 
         // their status is a clone of our status, these should be equal
-        assert_eq!(their_status, status);
+        assert_eq!(their_status, StatusMessage::Legacy(status));
 
         // await the other handshake
         handle.await.unwrap();
@@ -515,7 +532,7 @@ mod tests {
             let (incoming, _) = listener.accept().await.unwrap();
             let stream = PassthroughCodec::default().framed(incoming);
             let handshake_res = UnauthedEthStream::new(stream)
-                .handshake::<EthNetworkPrimitives>(status_clone, fork_filter_clone)
+                .handshake::<EthNetworkPrimitives>(status_clone.into(), fork_filter_clone)
                 .await;
 
             // make sure the handshake fails due to td too high
@@ -532,7 +549,7 @@ mod tests {
 
         // try to connect
         let handshake_res = UnauthedEthStream::new(sink)
-            .handshake::<EthNetworkPrimitives>(status, fork_filter)
+            .handshake::<EthNetworkPrimitives>(status.into(), fork_filter)
             .await;
 
         // this handshake should also fail due to td too high
@@ -547,6 +564,101 @@ mod tests {
         handle.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn can_handshake_eth69() {
+        let genesis = B256::random();
+        let fork_filter = ForkFilter::new(Head::default(), genesis, 0, Vec::new());
+
+        let status = Status {
+            version: EthVersion::Eth69,
+            chain: NamedChain::Mainnet.into(),
+            total_difficulty: U256::ZERO,
+            blockhash: B256::random(),
+            genesis,
+            // Pass the current fork id.
+            forkid: fork_filter.current(),
+        };
+        let status = StatusMessage::new(status, 0, 100);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+
+        let fork_filter_clone = fork_filter.clone();
+        let handle = tokio::spawn(async move {
+            let (incoming, _) = listener.accept().await.unwrap();
+            let stream = PassthroughCodec::default().framed(incoming);
+            let (stream, their_status) = UnauthedEthStream::new(stream)
+                .handshake::<EthNetworkPrimitives>(status, fork_filter_clone)
+                .await
+                .unwrap();
+
+            assert_eq!(stream.version(), EthVersion::Eth69);
+            assert_eq!(their_status, status);
+        });
+
+        let outgoing = TcpStream::connect(local_addr).await.unwrap();
+        let sink = PassthroughCodec::default().framed(outgoing);
+
+        let (_, their_status) = UnauthedEthStream::new(sink)
+            .handshake::<EthNetworkPrimitives>(status, fork_filter)
+            .await
+            .unwrap();
+
+        assert_eq!(their_status.total_difficulty(), None);
+        assert_eq!(their_status.block_range().map(|range| range.latest), Some(100));
+
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn fail_handshake_on_invalid_block_range() {
+        let genesis = B256::random();
+        let fork_filter = ForkFilter::new(Head::default(), genesis, 0, Vec::new());
+
+        let status = Status {
+            version: EthVersion::Eth69,
+            chain: NamedChain::Mainnet.into(),
+            total_difficulty: U256::ZERO,
+            blockhash: B256::random(),
+            genesis,
+            // Pass the current fork id.
+            forkid: fork_filter.current(),
+        };
+        let valid_status = StatusMessage::new(status, 0, 100);
+        let invalid_status = StatusMessage::new(status, 101, 100);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+
+        let fork_filter_clone = fork_filter.clone();
+        let handle = tokio::spawn(async move {
+            let (incoming, _) = listener.accept().await.unwrap();
+            let stream = PassthroughCodec::default().framed(incoming);
+            let handshake_res = UnauthedEthStream::new(stream)
+                .handshake::<EthNetworkPrimitives>(valid_status, fork_filter_clone)
+                .await;
+
+            // the peer advertised a range that ends before it starts
+            assert!(matches!(
+                handshake_res,
+                Err(EthStreamError::EthHandshakeError(EthHandshakeError::InvalidBlockRange {
+                    earliest: 101,
+                    latest: 100
+                }))
+            ));
+        });
+
+        let outgoing = TcpStream::connect(local_addr).await.unwrap();
+        let sink = PassthroughCodec::default().framed(outgoing);
+
+        // the peer disconnects after receiving the invalid status, so this result is irrelevant
+        let _ = UnauthedEthStream::new(sink)
+            .handshake::<EthNetworkPrimitives>(invalid_status, fork_filter)
+            .await;
+
+        handle.await.unwrap();
+    }
+
     #[tokio::test]
     async fn can_write_and_read_cleartext() {
         let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
@@ -668,7 +780,7 @@ mod tests {
             let unauthed_stream = UnauthedP2PStream::new(stream);
             let (p2p_stream, _) = unauthed_stream.handshake(server_hello).await.unwrap();
             let (mut eth_stream, _) = UnauthedEthStream::new(p2p_stream)
-                .handshake(status_copy, fork_filter_clone)
+                .handshake(status_copy.into(), fork_filter_clone)
                 .await
                 .unwrap();
 
@@ -697,7 +809,7 @@ mod tests {
         let (p2p_stream, _) = unauthed_stream.handshake(client_hello).await.unwrap();
 
         let (mut client_stream, _) =
-            UnauthedEthStream::new(p2p_stream).handshake(status, fork_filter).await.unwrap();
+            UnauthedEthStream::new(p2p_stream).handshake(status.into(), fork_filter).await.unwrap();
 
         client_stream.send(test_msg).await.unwrap();
 
@@ -732,12 +844,12 @@ mod tests {
             let (incoming, _) = listener.accept().await.unwrap();
             let stream = PassthroughCodec::default().framed(incoming);
             let (_, their_status) = UnauthedEthStream::new(stream)
-                .handshake::<EthNetworkPrimitives>(status_clone, fork_filter_clone)
+                .handshake::<EthNetworkPrimitives>(status_clone.into(), fork_filter_clone)
                 .await
                 .unwrap();
 
             // just make sure it equals our status (our status is a clone of their status)
-            assert_eq!(their_status, status_clone);
+            assert_eq!(their_status, StatusMessage::Legacy(status_clone));
         });
 
         let outgoing = TcpStream::connect(local_addr).await.unwrap();
@@ -746,7 +858,7 @@ mod tests {
         // try to connect
         let handshake_result = UnauthedEthStream::new(sink)
             .handshake_with_timeout::<EthNetworkPrimitives>(
-                status,
+                status.into(),
                 fork_filter,
                 Duration::from_secs(1),
             )
@@ -20,7 +20,8 @@ use crate::{
     capability::{SharedCapabilities, SharedCapability, UnsupportedCapabilityError},
     errors::{EthStreamError, P2PStreamError},
     p2pstream::DisconnectP2P,
-    CanDisconnect, Capability, DisconnectReason, EthStream, P2PStream, Status, UnauthedEthStream,
+    CanDisconnect, Capability, DisconnectReason, EthStream, P2PStream, StatusMessage,
+    UnauthedEthStream,
 };
 use bytes::{Bytes, BytesMut};
 use futures::{Sink, SinkExt, Stream, StreamExt, TryStream, TryStreamExt};
@@ -207,9 +208,9 @@ impl<St> RlpxProtocolMultiplexer<St> {
     /// primary protocol.
     pub async fn into_eth_satellite_stream<N: NetworkPrimitives>(
         self,
-        status: Status,
+        status: StatusMessage,
         fork_filter: ForkFilter,
-    ) -> Result<(RlpxSatelliteStream<St, EthStream<ProtocolProxy, N>>, Status), EthStreamError>
+    ) -> Result<(RlpxSatelliteStream<St, EthStream<ProtocolProxy, N>>, StatusMessage), EthStreamError>
     where
         St: Stream<Item = io::Result<BytesMut>> + Sink<Bytes, Error = io::Error> + Unpin,
     {
@@ -695,7 +696,7 @@ mod tests {
                 UnauthedP2PStream::new(stream).handshake(server_hello).await.unwrap();
 
             let (_eth_stream, _) = UnauthedEthStream::new(p2p_stream)
-                .handshake::<EthNetworkPrimitives>(other_status, other_fork_filter)
+                .handshake::<EthNetworkPrimitives>(other_status.into(), other_fork_filter)
                 .await
                 .unwrap();
 
@@ -711,7 +712,7 @@ mod tests {
                 eth.capability().as_ref(),
                 move |proxy| async move {
                     UnauthedEthStream::new(proxy)
-                        .handshake::<EthNetworkPrimitives>(status, fork_filter)
+                        .handshake::<EthNetworkPrimitives>(status.into(), fork_filter)
                         .await
                 },
             )
@@ -735,7 +736,10 @@ mod tests {
             let (conn, _) = UnauthedP2PStream::new(stream).handshake(server_hello).await.unwrap();
 
             let (mut st, _their_status) = RlpxProtocolMultiplexer::new(conn)
-                .into_eth_satellite_stream::<EthNetworkPrimitives>(other_status, other_fork_filter)
+                .into_eth_satellite_stream::<EthNetworkPrimitives>(
+                    other_status.into(),
+                    other_fork_filter,
+                )
                 .await
                 .unwrap();
 
@@ -766,7 +770,7 @@ mod tests {
 
         let conn = connect_passthrough(local_addr, test_hello().0).await;
         let (mut st, _their_status) = RlpxProtocolMultiplexer::new(conn)
-            .into_eth_satellite_stream::<EthNetworkPrimitives>(status, fork_filter)
+            .into_eth_satellite_stream::<EthNetworkPrimitives>(status.into(), fork_filter)
             .await
             .unwrap();
 
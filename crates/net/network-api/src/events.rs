@@ -4,7 +4,7 @@ use reth_eth_wire_types::{
     message::RequestPair, BlockBodies, BlockHeaders, Capabilities, DisconnectReason, EthMessage,
     EthNetworkPrimitives, EthVersion, GetBlockBodies, GetBlockHeaders, GetNodeData,
     GetPooledTransactions, GetReceipts, NetworkPrimitives, NodeData, PooledTransactions, Receipts,
-    Status,
+    Receipts69, Status,
 };
 use reth_ethereum_forks::ForkId;
 use reth_network_p2p::error::{RequestError, RequestResult};
@@ -227,6 +227,17 @@ pub enum PeerRequest<N: NetworkPrimitives = EthNetworkPrimitives> {
         /// The channel to send the response for receipts.
         response: oneshot::Sender<RequestResult<Receipts<N::Receipt>>>,
     },
+    /// Requests receipts from the peer without bloom filters.
+    ///
+    /// This is the `eth/69` variant of [`PeerRequest::GetReceipts`].
+    ///
+    /// The response should be sent through the channel.
+    GetReceipts69 {
+        /// The request for receipts.
+        request: GetReceipts,
+        /// The channel to send the response for receipts.
+        response: oneshot::Sender<RequestResult<Receipts69<N::Receipt>>>,
+    },
 }
 
 // === impl PeerRequest ===
@@ -245,6 +256,7 @@ impl<N: NetworkPrimitives> PeerRequest<N> {
             Self::GetPooledTransactions { response, .. } => response.send(Err(err)).ok(),
             Self::GetNodeData { response, .. } => response.send(Err(err)).ok(),
             Self::GetReceipts { response, .. } => response.send(Err(err)).ok(),
+            Self::GetReceipts69 { response, .. } => response.send(Err(err)).ok(),
         };
     }
 
@@ -266,7 +278,7 @@ impl<N: NetworkPrimitives> PeerRequest<N> {
             Self::GetNodeData { request, .. } => {
                 EthMessage::GetNodeData(RequestPair { request_id, message: request.clone() })
             }
-            Self::GetReceipts { request, .. } => {
+            Self::GetReceipts { request, .. } | Self::GetReceipts69 { request, .. } => {
                 EthMessage::GetReceipts(RequestPair { request_id, message: request.clone() })
             }
         }
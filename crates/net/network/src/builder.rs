@@ -2,6 +2,8 @@
 
 use crate::{
     eth_requests::EthRequestHandler,
+    snap::SnapProtocolHandler,
+    snap_requests::SnapRequestHandler,
     transactions::{TransactionsManager, TransactionsManagerConfig},
     NetworkHandle, NetworkManager,
 };
@@ -14,6 +16,10 @@ use tokio::sync::mpsc;
 /// 256 requests with malicious 10MB body requests is 2.6GB which can be absorbed by the node.
 pub(crate) const ETH_REQUEST_CHANNEL_CAPACITY: usize = 256;
 
+/// We set the max channel capacity of the `SnapRequestHandler` to 256, like the
+/// `EthRequestHandler`, since the responses are capped at the same soft limit.
+pub(crate) const SNAP_REQUEST_CHANNEL_CAPACITY: usize = 256;
+
 /// A builder that can configure all components of the network.
 #[allow(missing_debug_implementations)]
 pub struct NetworkBuilder<Tx, Eth, N: NetworkPrimitives = EthNetworkPrimitives> {
@@ -66,6 +72,14 @@ impl<Tx, Eth, N: NetworkPrimitives> NetworkBuilder<Tx, Eth, N> {
         NetworkBuilder { network, request_handler, transactions }
     }
 
+    /// Creates a new [`SnapRequestHandler`] and registers the `snap/1` protocol with the network,
+    /// so that peers can snap sync from the state of the client.
+    pub fn snap_request_handler<Client>(&mut self, client: Client) -> SnapRequestHandler<Client> {
+        let (tx, rx) = mpsc::channel(SNAP_REQUEST_CHANNEL_CAPACITY);
+        self.network.add_rlpx_sub_protocol(SnapProtocolHandler::new(tx));
+        SnapRequestHandler::new(client, rx)
+    }
+
     /// Creates a new [`TransactionsManager`] and wires it to the network.
     pub fn transactions<Pool: TransactionPool>(
         self,
@@ -10,7 +10,7 @@ use alloy_rlp::Encodable;
 use futures::StreamExt;
 use reth_eth_wire::{
     BlockBodies, BlockHeaders, EthNetworkPrimitives, GetBlockBodies, GetBlockHeaders, GetNodeData,
-    GetReceipts, HeadersDirection, NetworkPrimitives, NodeData, Receipts,
+    GetReceipts, HeadersDirection, NetworkPrimitives, NodeData, Receipts, Receipts69,
 };
 use reth_network_api::test_utils::PeersHandle;
 use reth_network_p2p::error::RequestResult;
@@ -190,6 +190,36 @@ where
     ) {
         self.metrics.eth_receipts_requests_received_total.increment(1);
 
+        let receipts = self.get_receipts_response(request, |receipts_by_block| {
+            receipts_by_block.into_iter().map(ReceiptWithBloom::from).collect::<Vec<_>>()
+        });
+
+        let _ = response.send(Ok(Receipts(receipts)));
+    }
+
+    /// Handles a `GetReceipts` request from an `eth/69` peer, which expects the receipts without
+    /// the bloom filter.
+    fn on_receipts69_request(
+        &self,
+        _peer_id: PeerId,
+        request: GetReceipts,
+        response: oneshot::Sender<RequestResult<Receipts69<C::Receipt>>>,
+    ) {
+        self.metrics.eth_receipts_requests_received_total.increment(1);
+
+        let receipts = self.get_receipts_response(request, |receipts_by_block| receipts_by_block);
+
+        let _ = response.send(Ok(Receipts69(receipts)));
+    }
+
+    /// Looks up the receipts of the requested blocks and converts them with the given function.
+    ///
+    /// Stops at the first unknown block or once the response limits are reached.
+    fn get_receipts_response<T, F>(&self, request: GetReceipts, transform_fn: F) -> Vec<Vec<T>>
+    where
+        F: Fn(Vec<C::Receipt>) -> Vec<T>,
+        T: Encodable,
+    {
         let mut receipts = Vec::new();
 
         let mut total_bytes = 0;
@@ -198,8 +228,7 @@ where
             if let Some(receipts_by_block) =
                 self.client.receipts_by_block(BlockHashOrNumber::Hash(hash)).unwrap_or_default()
             {
-                let receipt =
-                    receipts_by_block.into_iter().map(ReceiptWithBloom::from).collect::<Vec<_>>();
+                let receipt = transform_fn(receipts_by_block);
 
                 total_bytes += receipt.length();
                 receipts.push(receipt);
@@ -212,7 +241,7 @@ where
             }
         }
 
-        let _ = response.send(Ok(Receipts(receipts)));
+        receipts
     }
 }
 
@@ -252,6 +281,9 @@ where
                     IncomingEthRequest::GetReceipts { peer_id, request, response } => {
                         this.on_receipts_request(peer_id, request, response)
                     }
+                    IncomingEthRequest::GetReceipts69 { peer_id, request, response } => {
+                        this.on_receipts69_request(peer_id, request, response)
+                    }
                 }
             },
         );
@@ -315,4 +347,15 @@ pub enum IncomingEthRequest<N: NetworkPrimitives = EthNetworkPrimitives> {
         /// The channel sender for the response containing receipts.
         response: oneshot::Sender<RequestResult<Receipts<N::Receipt>>>,
     },
+    /// Request Receipts from an `eth/69` peer, which are served without the bloom filter.
+    ///
+    /// The response should be sent through the channel.
+    GetReceipts69 {
+        /// The ID of the peer to request receipts from.
+        peer_id: PeerId,
+        /// The specific receipts requested.
+        request: GetReceipts,
+        /// The channel sender for the response containing receipts.
+        response: oneshot::Sender<RequestResult<Receipts69<N::Receipt>>>,
+    },
 }
@@ -4,7 +4,8 @@ mod client;
 
 pub use client::FetchClient;
 
-use crate::message::BlockRequest;
+use crate::{message::BlockRequest, session::BlockRangeInfo};
+use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::B256;
 use futures::StreamExt;
 use reth_eth_wire::{EthNetworkPrimitives, GetBlockBodies, GetBlockHeaders, NetworkPrimitives};
@@ -80,6 +81,7 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
         best_hash: B256,
         best_number: u64,
         timeout: Arc<AtomicU64>,
+        range_info: Option<BlockRangeInfo>,
     ) {
         self.peers.insert(
             peer_id,
@@ -89,6 +91,7 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
                 best_number,
                 timeout,
                 last_response_likely_bad: false,
+                range_info,
             },
         );
     }
@@ -133,8 +136,14 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
     /// Returns the _next_ idle peer that's ready to accept a request,
     /// prioritizing those with the lowest timeout/latency and those that recently responded with
     /// adequate data.
-    fn next_best_peer(&self) -> Option<PeerId> {
-        let mut idle = self.peers.iter().filter(|(_, peer)| peer.state.is_idle());
+    ///
+    /// If a block number is requested, peers that advertised a block range (`eth/69`) which
+    /// doesn't contain the block are skipped.
+    fn next_best_peer(&self, requested_block: Option<u64>) -> Option<PeerId> {
+        let mut idle = self
+            .peers
+            .iter()
+            .filter(|(_, peer)| peer.state.is_idle() && peer.has_block(requested_block));
 
         let mut best_peer = idle.next()?;
 
@@ -163,7 +172,14 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
             return PollAction::NoRequests
         }
 
-        let Some(peer_id) = self.next_best_peer() else { return PollAction::NoPeersAvailable };
+        // prefer peers that advertised the requested block, but fall back to any idle peer instead
+        // of stalling the queue, since the advertised ranges are only updated periodically
+        let requested_block = self.queued_requests.front().and_then(|req| req.block_number());
+        let Some(peer_id) =
+            self.next_best_peer(requested_block).or_else(|| self.next_best_peer(None))
+        else {
+            return PollAction::NoPeersAvailable
+        };
 
         let request = self.queued_requests.pop_front().expect("not empty");
         let request = self.prepare_block_request(peer_id, request);
@@ -245,6 +261,11 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
     ///
     /// Caution: this expects that the peer is _not_ closed.
     fn followup_request(&mut self, peer_id: PeerId) -> Option<BlockResponseOutcome> {
+        let requested_block = self.queued_requests.front()?.block_number();
+        if !self.peers.get(&peer_id).is_some_and(|peer| peer.has_block(requested_block)) {
+            // the peer can't serve the next request, leave it to another peer
+            return None
+        }
         let req = self.queued_requests.pop_front()?;
         let req = self.prepare_block_request(peer_id, req);
         Some(BlockResponseOutcome::Request(peer_id, req))
@@ -347,12 +368,25 @@ struct Peer {
     /// downloaded), but we still want to avoid requesting from the same peer again if it has the
     /// lowest timeout.
     last_response_likely_bad: bool,
+    /// The range of blocks the peer advertised, if it negotiated `eth/69`.
+    range_info: Option<BlockRangeInfo>,
 }
 
 impl Peer {
     fn timeout(&self) -> u64 {
         self.timeout.load(Ordering::Relaxed)
     }
+
+    /// Returns `false` if the peer advertised a block range that doesn't contain the requested
+    /// block.
+    ///
+    /// Peers without range information are assumed to have the block.
+    fn has_block(&self, requested_block: Option<u64>) -> bool {
+        match (requested_block, &self.range_info) {
+            (Some(block), Some(range_info)) => range_info.contains(block),
+            _ => true,
+        }
+    }
 }
 
 /// Tracks the state of an individual peer
@@ -442,6 +476,17 @@ impl<N: NetworkPrimitives> DownloadRequest<N> {
     const fn is_normal_priority(&self) -> bool {
         self.get_priority().is_normal()
     }
+
+    /// Returns the number of the first requested block, if known.
+    const fn block_number(&self) -> Option<u64> {
+        match self {
+            Self::GetBlockHeaders {
+                request: HeadersRequest { start: BlockHashOrNumber::Number(number), .. },
+                ..
+            } => Some(*number),
+            _ => None,
+        }
+    }
 }
 
 /// An action the syncer can emit.
@@ -503,20 +548,20 @@ mod tests {
         // Add a few random peers
         let peer1 = B512::random();
         let peer2 = B512::random();
-        fetcher.new_active_peer(peer1, B256::random(), 1, Arc::new(AtomicU64::new(1)));
-        fetcher.new_active_peer(peer2, B256::random(), 2, Arc::new(AtomicU64::new(1)));
+        fetcher.new_active_peer(peer1, B256::random(), 1, Arc::new(AtomicU64::new(1)), None);
+        fetcher.new_active_peer(peer2, B256::random(), 2, Arc::new(AtomicU64::new(1)), None);
 
-        let first_peer = fetcher.next_best_peer().unwrap();
+        let first_peer = fetcher.next_best_peer(None).unwrap();
         assert!(first_peer == peer1 || first_peer == peer2);
         // Pending disconnect for first_peer
         fetcher.on_pending_disconnect(&first_peer);
         // first_peer now isn't idle, so we should get other peer
-        let second_peer = fetcher.next_best_peer().unwrap();
+        let second_peer = fetcher.next_best_peer(None).unwrap();
         assert!(first_peer == peer1 || first_peer == peer2);
         assert_ne!(first_peer, second_peer);
         // without idle peers, returns None
         fetcher.on_pending_disconnect(&second_peer);
-        assert_eq!(fetcher.next_best_peer(), None);
+        assert_eq!(fetcher.next_best_peer(None), None);
     }
 
     #[tokio::test]
@@ -531,18 +576,81 @@ mod tests {
 
         let peer2_timeout = Arc::new(AtomicU64::new(300));
 
-        fetcher.new_active_peer(peer1, B256::random(), 1, Arc::new(AtomicU64::new(30)));
-        fetcher.new_active_peer(peer2, B256::random(), 2, Arc::clone(&peer2_timeout));
-        fetcher.new_active_peer(peer3, B256::random(), 3, Arc::new(AtomicU64::new(50)));
+        fetcher.new_active_peer(peer1, B256::random(), 1, Arc::new(AtomicU64::new(30)), None);
+        fetcher.new_active_peer(peer2, B256::random(), 2, Arc::clone(&peer2_timeout), None);
+        fetcher.new_active_peer(peer3, B256::random(), 3, Arc::new(AtomicU64::new(50)), None);
 
         // Must always get peer1 (lowest timeout)
-        assert_eq!(fetcher.next_best_peer(), Some(peer1));
-        assert_eq!(fetcher.next_best_peer(), Some(peer1));
+        assert_eq!(fetcher.next_best_peer(None), Some(peer1));
+        assert_eq!(fetcher.next_best_peer(None), Some(peer1));
         // peer2's timeout changes below peer1's
         peer2_timeout.store(10, Ordering::Relaxed);
         // Then we get peer 2 always (now lowest)
-        assert_eq!(fetcher.next_best_peer(), Some(peer2));
-        assert_eq!(fetcher.next_best_peer(), Some(peer2));
+        assert_eq!(fetcher.next_best_peer(None), Some(peer2));
+        assert_eq!(fetcher.next_best_peer(None), Some(peer2));
+    }
+
+    #[tokio::test]
+    async fn test_peer_block_range() {
+        let manager = PeersManager::new(PeersConfig::default());
+        let mut fetcher =
+            StateFetcher::<EthNetworkPrimitives>::new(manager.handle(), Default::default());
+        let peer1 = B512::random();
+        let peer2 = B512::random();
+
+        // peer1 is faster but only serves recent history
+        let range = BlockRangeInfo::new(100, 200, B256::random());
+        fetcher.new_active_peer(
+            peer1,
+            B256::random(),
+            200,
+            Arc::new(AtomicU64::new(10)),
+            Some(range.clone()),
+        );
+        fetcher.new_active_peer(peer2, B256::random(), 200, Arc::new(AtomicU64::new(50)), None);
+
+        assert_eq!(fetcher.next_best_peer(None), Some(peer1));
+        assert_eq!(fetcher.next_best_peer(Some(150)), Some(peer1));
+        assert_eq!(fetcher.next_best_peer(Some(50)), Some(peer2));
+        assert_eq!(fetcher.next_best_peer(Some(201)), Some(peer2));
+
+        // range updates received by the session are visible to the fetcher
+        range.update(0, 300, B256::random());
+        assert_eq!(fetcher.next_best_peer(Some(50)), Some(peer1));
+        assert_eq!(fetcher.next_best_peer(Some(250)), Some(peer1));
+    }
+
+    #[tokio::test]
+    async fn test_block_range_fallback() {
+        let manager = PeersManager::new(PeersConfig::default());
+        let mut fetcher =
+            StateFetcher::<EthNetworkPrimitives>::new(manager.handle(), Default::default());
+        let peer1 = B512::random();
+        fetcher.new_active_peer(
+            peer1,
+            B256::random(),
+            200,
+            Arc::new(AtomicU64::new(10)),
+            Some(BlockRangeInfo::new(100, 200, B256::random())),
+        );
+
+        // the only idle peer doesn't advertise the block, but the request is still sent
+        let (tx, _rx) = oneshot::channel();
+        fetcher.queued_requests.push_back(DownloadRequest::GetBlockHeaders {
+            request: HeadersRequest {
+                start: 50u64.into(),
+                limit: 1,
+                direction: Default::default(),
+            },
+            response: tx,
+            priority: Priority::default(),
+        });
+        assert_eq!(fetcher.next_best_peer(Some(50)), None);
+        assert!(matches!(
+            fetcher.poll_action(),
+            PollAction::Ready(FetchAction::BlockRequest { peer_id, .. }) if peer_id == peer1
+        ));
+        assert!(fetcher.queued_requests.is_empty());
     }
 
     #[tokio::test]
@@ -602,6 +710,7 @@ mod tests {
             Default::default(),
             Default::default(),
             Default::default(),
+            None,
         );
 
         let (req, header) = request_pair();
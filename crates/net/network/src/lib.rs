@@ -129,6 +129,8 @@ pub mod import;
 pub mod message;
 pub mod peers;
 pub mod protocol;
+pub mod snap;
+pub mod snap_requests;
 pub mod transactions;
 
 mod budget;
@@ -153,9 +155,9 @@ pub use reth_network_api::{
 pub use reth_network_p2p::sync::{NetworkSyncUpdater, SyncState};
 pub use reth_network_types::{PeersConfig, SessionsConfig};
 pub use session::{
-    ActiveSessionHandle, ActiveSessionMessage, Direction, EthRlpxConnection, PeerInfo,
-    PendingSessionEvent, PendingSessionHandle, PendingSessionHandshakeError, SessionCommand,
-    SessionEvent, SessionId, SessionManager,
+    ActiveSessionHandle, ActiveSessionMessage, BlockRangeInfo, Direction, EthRlpxConnection,
+    PeerInfo, PendingSessionEvent, PendingSessionHandle, PendingSessionHandshakeError,
+    SessionCommand, SessionEvent, SessionId, SessionManager,
 };
 
 pub use builder::NetworkBuilder;
@@ -37,7 +37,7 @@ use crate::{
 };
 use futures::{Future, StreamExt};
 use parking_lot::Mutex;
-use reth_eth_wire::{DisconnectReason, EthNetworkPrimitives, NetworkPrimitives};
+use reth_eth_wire::{BlockRangeUpdate, DisconnectReason, EthNetworkPrimitives, NetworkPrimitives};
 use reth_fs_util::{self as fs, FsPathError};
 use reth_metrics::common::mpsc::UnboundedMeteredSender;
 use reth_network_api::{
@@ -291,7 +291,7 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
 
         let num_active_peers = Arc::new(AtomicUsize::new(0));
 
-        let sessions = SessionManager::new(
+        let mut sessions = SessionManager::new(
             secret_key,
             sessions_config,
             executor,
@@ -301,6 +301,15 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             extra_protocols,
         );
 
+        // advertise the available blocks up to the status' block to eth/69 peers
+        if let Ok(Some(latest)) = client.block_number(status.blockhash) {
+            sessions.set_local_block_range(BlockRangeUpdate {
+                earliest: client.earliest_block_number().unwrap_or_default(),
+                latest,
+                latest_hash: status.blockhash,
+            });
+        }
+
         let state = NetworkState::new(
             crate::state::BlockNumReader::new(client),
             discovery,
@@ -507,6 +516,13 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
                     response,
                 })
             }
+            PeerRequest::GetReceipts69 { request, response } => {
+                self.delegate_eth_request(IncomingEthRequest::GetReceipts69 {
+                    peer_id,
+                    request,
+                    response,
+                })
+            }
             PeerRequest::GetPooledTransactions { request, response } => {
                 self.notify_tx_manager(NetworkTransactionEvent::GetPooledTransactions {
                     peer_id,
@@ -590,7 +606,7 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
                     msg,
                 });
             }
-            PeerMessage::SendTransactions(_) => {
+            PeerMessage::SendTransactions(_) | PeerMessage::BlockRangeUpdate(_) => {
                 unreachable!("Not emitted by session")
             }
             PeerMessage::Other(other) => {
@@ -667,6 +683,10 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
                 let _ = tx.send(self.status());
             }
             NetworkHandleMessage::StatusUpdate { head } => {
+                // older blocks may have been pruned since the last update
+                if let Ok(earliest) = self.swarm.state().client().earliest_block_number() {
+                    self.swarm.sessions_mut().set_earliest_block(earliest);
+                }
                 if let Some(transition) = self.swarm.sessions_mut().on_status_update(head) {
                     self.swarm.state_mut().update_fork_id(transition.current);
                 }
@@ -16,7 +16,7 @@ use crate::{
     session::{
         conn::EthRlpxConnection,
         handle::{ActiveSessionMessage, SessionCommand},
-        SessionId,
+        BlockRangeInfo, SessionId,
     },
 };
 use alloy_primitives::Sealable;
@@ -114,6 +114,10 @@ pub(crate) struct ActiveSession<N: NetworkPrimitives> {
     /// Used to reserve a slot to guarantee that the termination message is delivered
     pub(crate) terminate_message:
         Option<(PollSender<ActiveSessionMessage<N>>, ActiveSessionMessage<N>)>,
+    /// The range of blocks the remote peer can serve, if it negotiated `eth/69`.
+    ///
+    /// This is shared with the fetcher and updated on received `BlockRangeUpdate` messages.
+    pub(crate) range_info: Option<BlockRangeInfo>,
 }
 
 impl<N: NetworkPrimitives> ActiveSession<N> {
@@ -250,11 +254,36 @@ impl<N: NetworkPrimitives> ActiveSession<N> {
                 on_response!(resp, GetNodeData)
             }
             EthMessage::GetReceipts(req) => {
-                on_request!(req, Receipts, GetReceipts)
+                if self.conn.version().is_eth69() {
+                    on_request!(req, Receipts69, GetReceipts69)
+                } else {
+                    on_request!(req, Receipts, GetReceipts)
+                }
             }
             EthMessage::Receipts(resp) => {
                 on_response!(resp, GetReceipts)
             }
+            EthMessage::Receipts69(resp) => {
+                // eth/69 receipts don't include the bloom, so we compute it here
+                let RequestPair { request_id, message } = resp;
+                let resp = RequestPair { request_id, message: message.into_with_bloom() };
+                on_response!(resp, GetReceipts)
+            }
+            EthMessage::BlockRangeUpdate(msg) => {
+                if !msg.is_valid() {
+                    return OnIncomingMessageOutcome::BadMessage {
+                        error: EthStreamError::InvalidBlockRange {
+                            earliest: msg.earliest,
+                            latest: msg.latest,
+                        },
+                        message: EthMessage::BlockRangeUpdate(msg),
+                    }
+                }
+                if let Some(range_info) = &self.range_info {
+                    range_info.update(msg.earliest, msg.latest, msg.latest_hash);
+                }
+                OnIncomingMessageOutcome::Ok
+            }
         }
     }
 
@@ -275,10 +304,20 @@ impl<N: NetworkPrimitives> ActiveSession<N> {
     fn on_internal_peer_message(&mut self, msg: PeerMessage<N>) {
         match msg {
             PeerMessage::NewBlockHashes(msg) => {
-                self.queued_outgoing.push_back(EthMessage::NewBlockHashes(msg).into());
+                // block announcements were removed in eth/69
+                if !self.conn.version().is_eth69() {
+                    self.queued_outgoing.push_back(EthMessage::NewBlockHashes(msg).into());
+                }
             }
             PeerMessage::NewBlock(msg) => {
-                self.queued_outgoing.push_back(EthBroadcastMessage::NewBlock(msg.block).into());
+                if !self.conn.version().is_eth69() {
+                    self.queued_outgoing.push_back(EthBroadcastMessage::NewBlock(msg.block).into());
+                }
+            }
+            PeerMessage::BlockRangeUpdate(msg) => {
+                if self.conn.version().is_eth69() {
+                    self.queued_outgoing.push_back(EthMessage::BlockRangeUpdate(msg).into());
+                }
             }
             PeerMessage::PooledTransactions(msg) => {
                 if msg.is_valid_for_version(self.conn.version()) {
@@ -847,7 +886,7 @@ mod tests {
     use reth_ecies::stream::ECIESStream;
     use reth_eth_wire::{
         EthNetworkPrimitives, EthStream, GetBlockBodies, HelloMessageWithProtocols, P2PStream,
-        Status, StatusBuilder, UnauthedEthStream, UnauthedP2PStream,
+        Status, StatusBuilder, StatusMessage, UnauthedEthStream, UnauthedP2PStream,
     };
     use reth_network_peers::pk2id;
     use reth_network_types::session::config::PROTOCOL_BREACH_REQUEST_TIMEOUT;
@@ -893,7 +932,7 @@ mod tests {
             F: FnOnce(EthStream<P2PStream<ECIESStream<TcpStream>>, N>) -> O + Send + 'static,
             O: Future<Output = ()> + Send + Sync,
         {
-            let status = self.status;
+            let mut status = self.status;
             let fork_filter = self.fork_filter.clone();
             let local_peer_id = self.local_peer_id;
             let mut hello = self.hello.clone();
@@ -905,8 +944,9 @@ mod tests {
 
                 let (p2p_stream, _) = UnauthedP2PStream::new(sink).handshake(hello).await.unwrap();
 
+                status.set_eth_version(p2p_stream.shared_capabilities().eth_version().unwrap());
                 let (client_stream, _) = UnauthedEthStream::new(p2p_stream)
-                    .handshake(status, fork_filter)
+                    .handshake(StatusMessage::new(status, 0, 0), fork_filter)
                     .await
                     .unwrap();
                 f(client_stream).await
@@ -928,6 +968,7 @@ mod tests {
                 self.secret_key,
                 self.hello.clone(),
                 self.status,
+                Default::default(),
                 self.fork_filter.clone(),
                 Default::default(),
             ));
@@ -974,6 +1015,7 @@ mod tests {
                         )),
                         protocol_breach_request_timeout: PROTOCOL_BREACH_REQUEST_TIMEOUT,
                         terminate_message: None,
+                        range_info: None,
                     }
                 }
                 ev => {
@@ -2,7 +2,7 @@
 
 use crate::{
     message::PeerMessage,
-    session::{conn::EthRlpxConnection, Direction, SessionId},
+    session::{conn::EthRlpxConnection, BlockRangeInfo, Direction, SessionId},
     PendingSessionHandshakeError,
 };
 use reth_ecies::ECIESError;
@@ -174,6 +174,8 @@ pub enum PendingSessionEvent<N: NetworkPrimitives> {
         capabilities: Arc<Capabilities>,
         /// The Status message the peer sent for the `eth` handshake
         status: Arc<Status>,
+        /// The range of blocks the peer advertised in its `eth/69` status message
+        range_info: Option<BlockRangeInfo>,
         /// The actual connection stream which can be used to send and receive `eth` protocol
         /// messages
         conn: EthRlpxConnection<N>,
@@ -4,6 +4,7 @@ mod active;
 mod conn;
 mod counter;
 mod handle;
+mod types;
 
 use active::QueuedOutgoingMessages;
 pub use conn::EthRlpxConnection;
@@ -11,6 +12,7 @@ pub use handle::{
     ActiveSessionHandle, ActiveSessionMessage, PendingSessionEvent, PendingSessionHandle,
     SessionCommand,
 };
+pub use types::BlockRangeInfo;
 
 pub use reth_network_api::{Direction, PeerInfo};
 
@@ -33,9 +35,9 @@ use counter::SessionCounter;
 use futures::{future::Either, io, FutureExt, StreamExt};
 use reth_ecies::{stream::ECIESStream, ECIESError};
 use reth_eth_wire::{
-    errors::EthStreamError, multiplex::RlpxProtocolMultiplexer, Capabilities, DisconnectReason,
-    EthVersion, HelloMessageWithProtocols, NetworkPrimitives, Status, UnauthedEthStream,
-    UnauthedP2PStream,
+    errors::EthStreamError, multiplex::RlpxProtocolMultiplexer, BlockRangeUpdate, Capabilities,
+    DisconnectReason, EthVersion, HelloMessageWithProtocols, NetworkPrimitives, Status,
+    StatusMessage, UnauthedEthStream, UnauthedP2PStream,
 };
 use reth_ethereum_forks::{ForkFilter, ForkId, ForkTransition, Head};
 use reth_metrics::common::mpsc::MeteredPollSender;
@@ -54,6 +56,10 @@ use tokio_stream::wrappers::ReceiverStream;
 use tokio_util::sync::PollSender;
 use tracing::{debug, instrument, trace};
 
+/// The number of blocks the local head has to advance before a new `BlockRangeUpdate` is
+/// announced to `eth/69` peers.
+const BLOCK_RANGE_UPDATE_INTERVAL: u64 = 32;
+
 /// Internal identifier for active sessions.
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Hash)]
 pub struct SessionId(usize);
@@ -78,6 +84,10 @@ pub struct SessionManager<N: NetworkPrimitives> {
     secret_key: SecretKey,
     /// The `Status` message to send to peers.
     status: Status,
+    /// The range of blocks the local node can serve, announced to `eth/69` peers.
+    local_block_range: BlockRangeUpdate,
+    /// The latest block of the range that was last announced to `eth/69` peers.
+    last_announced_block_range: u64,
     /// The `HelloMessage` message to send to peers.
     hello_message: HelloMessageWithProtocols,
     /// The [`ForkFilter`] used to validate the peer's `Status` message.
@@ -141,6 +151,12 @@ impl<N: NetworkPrimitives> SessionManager<N> {
             pending_session_timeout: config.pending_session_timeout,
             secret_key,
             status,
+            local_block_range: BlockRangeUpdate {
+                earliest: 0,
+                latest: 0,
+                latest_hash: status.blockhash,
+            },
+            last_announced_block_range: 0,
             hello_message,
             fork_filter,
             session_command_buffer: config.session_command_buffer,
@@ -175,6 +191,24 @@ impl<N: NetworkPrimitives> SessionManager<N> {
         self.status
     }
 
+    /// Returns the range of blocks the local node advertises to `eth/69` peers.
+    pub const fn local_block_range(&self) -> BlockRangeUpdate {
+        self.local_block_range
+    }
+
+    /// Sets the range of blocks the local node advertises to `eth/69` peers.
+    pub(crate) fn set_local_block_range(&mut self, block_range: BlockRangeUpdate) {
+        self.local_block_range = block_range;
+        self.last_announced_block_range = block_range.latest;
+    }
+
+    /// Sets the earliest block the local node advertises to `eth/69` peers.
+    ///
+    /// The new range is announced with the next one of the head.
+    pub(crate) fn set_earliest_block(&mut self, earliest: u64) {
+        self.local_block_range.earliest = earliest;
+    }
+
     /// Returns the secret key used for authenticating sessions.
     pub const fn secret_key(&self) -> SecretKey {
         self.secret_key
@@ -217,11 +251,34 @@ impl<N: NetworkPrimitives> SessionManager<N> {
     pub(crate) fn on_status_update(&mut self, head: Head) -> Option<ForkTransition> {
         self.status.blockhash = head.hash;
         self.status.total_difficulty = head.total_difficulty;
+        self.local_block_range.latest = head.number;
+        self.local_block_range.latest_hash = head.hash;
         let transition = self.fork_filter.set_head(head);
         self.status.forkid = self.fork_filter.current();
+        self.maybe_announce_block_range();
         transition
     }
 
+    /// Announces the local block range to all `eth/69` peers if the local head advanced by at
+    /// least [`BLOCK_RANGE_UPDATE_INTERVAL`] blocks since the last announcement.
+    fn maybe_announce_block_range(&mut self) {
+        let latest = self.local_block_range.latest;
+        if latest.saturating_sub(self.last_announced_block_range) < BLOCK_RANGE_UPDATE_INTERVAL {
+            return
+        }
+        self.last_announced_block_range = latest;
+
+        let peers = self
+            .active_sessions
+            .iter()
+            .filter(|(_, session)| session.version.is_eth69())
+            .map(|(peer_id, _)| *peer_id)
+            .collect::<Vec<_>>();
+        for peer_id in peers {
+            self.send_message(&peer_id, PeerMessage::BlockRangeUpdate(self.local_block_range));
+        }
+    }
+
     /// An incoming TCP connection was received. This starts the authentication process to turn this
     /// stream into an active peer session.
     ///
@@ -247,6 +304,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
         let secret_key = self.secret_key;
         let hello_message = self.hello_message.clone();
         let status = self.status;
+        let block_range = self.local_block_range;
         let fork_filter = self.fork_filter.clone();
         let extra_handlers = self.extra_protocols.on_incoming(remote_addr);
         self.spawn(pending_session_with_timeout(
@@ -264,6 +322,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                 secret_key,
                 hello_message,
                 status,
+                block_range,
                 fork_filter,
                 extra_handlers,
             ),
@@ -289,6 +348,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
             let hello_message = self.hello_message.clone();
             let fork_filter = self.fork_filter.clone();
             let status = self.status;
+            let block_range = self.local_block_range;
             let extra_handlers = self.extra_protocols.on_outgoing(remote_addr, remote_peer_id);
             self.spawn(pending_session_with_timeout(
                 self.pending_session_timeout,
@@ -305,6 +365,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                     secret_key,
                     hello_message,
                     status,
+                    block_range,
                     fork_filter,
                     extra_handlers,
                 ),
@@ -469,6 +530,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                 capabilities,
                 conn,
                 status,
+                range_info,
                 direction,
                 client_id,
             } => {
@@ -534,6 +596,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                     internal_request_timeout: Arc::clone(&timeout),
                     protocol_breach_request_timeout: self.protocol_breach_request_timeout,
                     terminate_message: None,
+                    range_info: range_info.clone(),
                 };
 
                 self.spawn(session);
@@ -570,6 +633,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                     messages,
                     direction,
                     timeout,
+                    range_info,
                 })
             }
             PendingSessionEvent::Disconnected { remote_addr, session_id, direction, error } => {
@@ -683,6 +747,8 @@ pub enum SessionEvent<N: NetworkPrimitives> {
         /// The maximum time that the session waits for a response from the peer before timing out
         /// the connection
         timeout: Arc<AtomicU64>,
+        /// The range of blocks the peer advertised, if it negotiated `eth/69`
+        range_info: Option<BlockRangeInfo>,
     },
     /// The peer was already connected with another session.
     AlreadyConnected {
@@ -819,6 +885,7 @@ pub(crate) async fn start_pending_incoming_session<N: NetworkPrimitives>(
     secret_key: SecretKey,
     hello: HelloMessageWithProtocols,
     status: Status,
+    block_range: BlockRangeUpdate,
     fork_filter: ForkFilter,
     extra_handlers: RlpxSubProtocolHandlers,
 ) {
@@ -832,6 +899,7 @@ pub(crate) async fn start_pending_incoming_session<N: NetworkPrimitives>(
         Direction::Incoming,
         hello,
         status,
+        block_range,
         fork_filter,
         extra_handlers,
     )
@@ -850,6 +918,7 @@ async fn start_pending_outbound_session<N: NetworkPrimitives>(
     secret_key: SecretKey,
     hello: HelloMessageWithProtocols,
     status: Status,
+    block_range: BlockRangeUpdate,
     fork_filter: ForkFilter,
     extra_handlers: RlpxSubProtocolHandlers,
 ) {
@@ -882,6 +951,7 @@ async fn start_pending_outbound_session<N: NetworkPrimitives>(
         Direction::Outgoing(remote_peer_id),
         hello,
         status,
+        block_range,
         fork_filter,
         extra_handlers,
     )
@@ -900,6 +970,7 @@ async fn authenticate<N: NetworkPrimitives>(
     direction: Direction,
     hello: HelloMessageWithProtocols,
     status: Status,
+    block_range: BlockRangeUpdate,
     fork_filter: ForkFilter,
     extra_handlers: RlpxSubProtocolHandlers,
 ) {
@@ -929,6 +1000,7 @@ async fn authenticate<N: NetworkPrimitives>(
         direction,
         hello,
         status,
+        block_range,
         fork_filter,
         extra_handlers,
     )
@@ -981,6 +1053,7 @@ async fn authenticate_stream<N: NetworkPrimitives>(
     direction: Direction,
     mut hello: HelloMessageWithProtocols,
     mut status: Status,
+    block_range: BlockRangeUpdate,
     fork_filter: ForkFilter,
     mut extra_handlers: RlpxSubProtocolHandlers,
 ) -> PendingSessionEvent<N> {
@@ -1013,11 +1086,12 @@ async fn authenticate_stream<N: NetworkPrimitives>(
         }
     };
 
+    // Before trying status handshake, set up the version to negotiated shared version
+    status.set_eth_version(eth_version);
+    let status = StatusMessage::new(status, block_range.earliest, block_range.latest);
+
     let (conn, their_status) = if p2p_stream.shared_capabilities().len() == 1 {
         // if the hello handshake was successful we can try status handshake
-        //
-        // Before trying status handshake, set up the version to negotiated shared version
-        status.set_eth_version(eth_version);
         let eth_unauthed = UnauthedEthStream::new(p2p_stream);
         let (eth_stream, their_status) = match eth_unauthed.handshake(status, fork_filter).await {
             Ok(stream_res) => stream_res,
@@ -1068,7 +1142,8 @@ async fn authenticate_stream<N: NetworkPrimitives>(
         local_addr,
         peer_id: their_hello.id,
         capabilities: Arc::new(Capabilities::from(their_hello.capabilities)),
-        status: Arc::new(their_status),
+        range_info: their_status.block_range().map(BlockRangeInfo::from),
+        status: Arc::new(their_status.into_status()),
         conn,
         direction,
         client_id: their_hello.client_version,
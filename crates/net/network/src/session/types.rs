@@ -0,0 +1,86 @@
+//! Shared types for network sessions.
+
+use alloy_primitives::B256;
+use parking_lot::RwLock;
+use reth_eth_wire::BlockRangeUpdate;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+/// Information about the range of blocks available from a peer.
+///
+/// This is advertised by `eth/69` peers in their `Status` message and updated via
+/// `BlockRangeUpdate` messages. The info is shared between the peer's session and the state
+/// fetcher, so updates received by the session are visible when choosing peers for requests.
+#[derive(Debug, Clone)]
+pub struct BlockRangeInfo {
+    /// The inner range information.
+    inner: Arc<BlockRangeInfoInner>,
+}
+
+impl BlockRangeInfo {
+    /// Creates a new range information.
+    pub fn new(earliest: u64, latest: u64, latest_hash: B256) -> Self {
+        Self {
+            inner: Arc::new(BlockRangeInfoInner {
+                earliest: AtomicU64::new(earliest),
+                latest: AtomicU64::new(latest),
+                latest_hash: RwLock::new(latest_hash),
+            }),
+        }
+    }
+
+    /// Returns true if the block number is within the range of blocks available from the peer.
+    pub fn contains(&self, block_number: u64) -> bool {
+        (self.earliest()..=self.latest()).contains(&block_number)
+    }
+
+    /// Returns the earliest block number available from the peer.
+    pub fn earliest(&self) -> u64 {
+        self.inner.earliest.load(Ordering::Relaxed)
+    }
+
+    /// Returns the latest block number available from the peer.
+    pub fn latest(&self) -> u64 {
+        self.inner.latest.load(Ordering::Relaxed)
+    }
+
+    /// Returns the hash of the latest block available from the peer.
+    pub fn latest_hash(&self) -> B256 {
+        *self.inner.latest_hash.read()
+    }
+
+    /// Updates the range information.
+    pub fn update(&self, earliest: u64, latest: u64, latest_hash: B256) {
+        self.inner.earliest.store(earliest, Ordering::Relaxed);
+        self.inner.latest.store(latest, Ordering::Relaxed);
+        *self.inner.latest_hash.write() = latest_hash;
+    }
+
+    /// Returns the range as a [`BlockRangeUpdate`] message.
+    pub fn to_message(&self) -> BlockRangeUpdate {
+        BlockRangeUpdate {
+            earliest: self.earliest(),
+            latest: self.latest(),
+            latest_hash: self.latest_hash(),
+        }
+    }
+}
+
+impl From<BlockRangeUpdate> for BlockRangeInfo {
+    fn from(range: BlockRangeUpdate) -> Self {
+        Self::new(range.earliest, range.latest, range.latest_hash)
+    }
+}
+
+/// Inner structure containing the range information with atomic and thread-safe fields.
+#[derive(Debug)]
+struct BlockRangeInfoInner {
+    /// The earliest block which is available.
+    earliest: AtomicU64,
+    /// The latest block which is available.
+    latest: AtomicU64,
+    /// Latest available block's hash.
+    latest_hash: RwLock<B256>,
+}
@@ -0,0 +1,216 @@
+//! Support for serving the `snap/1` protocol as an additional `RLPx` subprotocol.
+//!
+//! The [`SnapProtocolHandler`] announces the `snap` capability to peers and forwards the requests
+//! received on each connection to the
+//! [`SnapRequestHandler`](crate::snap_requests::SnapRequestHandler) which answers them from the
+//! state.
+//!
+//! See also <https://github.com/ethereum/devp2p/blob/master/caps/snap.md>
+
+use crate::{
+    protocol::{ConnectionHandler, OnNotSupported, ProtocolHandler},
+    snap_requests::IncomingSnapRequest,
+};
+use alloy_primitives::bytes::BytesMut;
+use alloy_rlp::Encodable;
+use futures::{future::BoxFuture, stream::FuturesUnordered, FutureExt, Stream, StreamExt};
+use reth_eth_wire::{
+    capability::SharedCapabilities, multiplex::ProtocolConnection, protocol::Protocol, Capability,
+};
+use reth_eth_wire_types::{SnapMessageId, SnapProtocolMessage};
+use reth_network_api::{Direction, PeerId};
+use std::{
+    net::SocketAddr,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::{mpsc, oneshot};
+use tracing::{debug, trace};
+
+/// The protocol handler of the `snap/1` protocol.
+///
+/// This announces the protocol on all connections and serves the requests of peers that share it.
+#[derive(Debug, Clone)]
+pub struct SnapProtocolHandler {
+    /// Sender half of the channel to the request handler.
+    to_request_handler: mpsc::Sender<IncomingSnapRequest>,
+}
+
+impl SnapProtocolHandler {
+    /// Creates a new protocol handler that forwards the requests of peers to the given channel.
+    pub const fn new(to_request_handler: mpsc::Sender<IncomingSnapRequest>) -> Self {
+        Self { to_request_handler }
+    }
+
+    /// Returns the capability of the `snap/1` protocol.
+    pub const fn capability() -> Capability {
+        Capability::new_static("snap", 1)
+    }
+
+    /// Returns the `snap/1` protocol.
+    pub const fn protocol() -> Protocol {
+        Protocol::new(Self::capability(), SnapMessageId::TrieNodes as u8 + 1)
+    }
+
+    fn connection_handler(&self) -> SnapConnectionHandler {
+        SnapConnectionHandler { to_request_handler: self.to_request_handler.clone() }
+    }
+}
+
+impl ProtocolHandler for SnapProtocolHandler {
+    type ConnectionHandler = SnapConnectionHandler;
+
+    fn on_incoming(&self, _socket_addr: SocketAddr) -> Option<Self::ConnectionHandler> {
+        Some(self.connection_handler())
+    }
+
+    fn on_outgoing(
+        &self,
+        _socket_addr: SocketAddr,
+        _peer_id: PeerId,
+    ) -> Option<Self::ConnectionHandler> {
+        Some(self.connection_handler())
+    }
+}
+
+/// The connection handler of the `snap/1` protocol.
+#[derive(Debug)]
+pub struct SnapConnectionHandler {
+    to_request_handler: mpsc::Sender<IncomingSnapRequest>,
+}
+
+impl ConnectionHandler for SnapConnectionHandler {
+    type Connection = SnapConnection;
+
+    fn protocol(&self) -> Protocol {
+        SnapProtocolHandler::protocol()
+    }
+
+    fn on_unsupported_by_peer(
+        self,
+        _supported: &SharedCapabilities,
+        _direction: Direction,
+        _peer_id: PeerId,
+    ) -> OnNotSupported {
+        // snap is optional, the peer can still use eth
+        OnNotSupported::KeepAlive
+    }
+
+    fn into_connection(
+        self,
+        _direction: Direction,
+        peer_id: PeerId,
+        conn: ProtocolConnection,
+    ) -> Self::Connection {
+        SnapConnection {
+            peer_id,
+            conn,
+            to_request_handler: self.to_request_handler,
+            pending_responses: Default::default(),
+        }
+    }
+}
+
+/// A `snap/1` connection with a peer.
+///
+/// Requests of the peer are forwarded to the request handler and this yields the encoded responses
+/// once they are ready. The connection is closed if the peer sends a message that can't be decoded.
+#[must_use = "Connection does nothing unless polled."]
+pub struct SnapConnection {
+    /// The peer of the connection.
+    peer_id: PeerId,
+    /// The messages received from the peer.
+    conn: ProtocolConnection,
+    /// Sender half of the channel to the request handler.
+    to_request_handler: mpsc::Sender<IncomingSnapRequest>,
+    /// Responses that are being served by the request handler.
+    pending_responses: FuturesUnordered<BoxFuture<'static, Option<SnapProtocolMessage>>>,
+}
+
+impl SnapConnection {
+    /// Forwards the request to the request handler and tracks its response.
+    fn on_request(&mut self, message: SnapProtocolMessage) {
+        let peer_id = self.peer_id;
+        let (request, response) = match message {
+            SnapProtocolMessage::GetAccountRange(request) => {
+                let (tx, rx) = oneshot::channel();
+                (
+                    IncomingSnapRequest::GetAccountRange { peer_id, request, response: tx },
+                    rx.map(|res| res.ok().map(SnapProtocolMessage::AccountRange)).boxed(),
+                )
+            }
+            SnapProtocolMessage::GetStorageRanges(request) => {
+                let (tx, rx) = oneshot::channel();
+                (
+                    IncomingSnapRequest::GetStorageRanges { peer_id, request, response: tx },
+                    rx.map(|res| res.ok().map(SnapProtocolMessage::StorageRanges)).boxed(),
+                )
+            }
+            SnapProtocolMessage::GetByteCodes(request) => {
+                let (tx, rx) = oneshot::channel();
+                (
+                    IncomingSnapRequest::GetByteCodes { peer_id, request, response: tx },
+                    rx.map(|res| res.ok().map(SnapProtocolMessage::ByteCodes)).boxed(),
+                )
+            }
+            SnapProtocolMessage::GetTrieNodes(request) => {
+                let (tx, rx) = oneshot::channel();
+                (
+                    IncomingSnapRequest::GetTrieNodes { peer_id, request, response: tx },
+                    rx.map(|res| res.ok().map(SnapProtocolMessage::TrieNodes)).boxed(),
+                )
+            }
+            message => {
+                // we never send requests, so responses are unsolicited
+                trace!(target: "net::snap", ?peer_id, id=?message.message_id(), "Ignoring unsolicited snap response");
+                return
+            }
+        };
+
+        if self.to_request_handler.try_send(request).is_err() {
+            // the request handler is saturated or gone, the peer can retry with another peer
+            debug!(target: "net::snap", ?peer_id, "Dropping snap request");
+            return
+        }
+        self.pending_responses.push(response);
+    }
+}
+
+impl Stream for SnapConnection {
+    type Item = BytesMut;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Poll::Ready(Some(response)) = this.pending_responses.poll_next_unpin(cx) {
+                if let Some(response) = response {
+                    let mut buf = BytesMut::with_capacity(response.length());
+                    response.encode(&mut buf);
+                    return Poll::Ready(Some(buf))
+                }
+                continue
+            }
+
+            let Poll::Ready(message) = this.conn.poll_next_unpin(cx) else { return Poll::Pending };
+            let Some(message) = message else { return Poll::Ready(None) };
+
+            match SnapProtocolMessage::decode_message(&mut &message[..]) {
+                Ok(message) => this.on_request(message),
+                Err(err) => {
+                    debug!(target: "net::snap", peer_id=?this.peer_id, %err, "Failed to decode snap message");
+                    return Poll::Ready(None)
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for SnapConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SnapConnection")
+            .field("peer_id", &self.peer_id)
+            .field("pending_responses", &self.pending_responses.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,892 @@
+//! Serving of the state for the `snap/1` protocol.
+
+use crate::budget::DEFAULT_BUDGET_TRY_DRAIN_STREAM;
+use alloy_consensus::{constants::KECCAK_EMPTY, BlockHeader};
+use alloy_primitives::{BlockNumber, Bytes, B256, U256};
+use alloy_rlp::RlpEncodable;
+use futures::{stream::FuturesUnordered, StreamExt};
+use reth_eth_wire_types::{
+    AccountData, AccountRangeMessage, ByteCodesMessage, GetAccountRangeMessage,
+    GetByteCodesMessage, GetStorageRangesMessage, GetTrieNodesMessage, StorageData,
+    StorageRangesMessage, TrieNodesMessage, TriePath,
+};
+use reth_network_peers::PeerId;
+use reth_storage_api::{
+    errors::provider::ProviderResult, BlockNumReader, HashedStateRangeReader, HeaderProvider,
+    StateProviderFactory,
+};
+use reth_trie::{MultiProofTargets, Nibbles, TrieAccount, EMPTY_ROOT_HASH};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::{
+    sync::{mpsc::Receiver, oneshot},
+    task::JoinHandle,
+};
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::debug;
+
+// Limits: <https://github.com/ethereum/go-ethereum/blob/v1.15.0/eth/protocols/snap/handler.go#L36-L54>
+
+/// Maximum size of replies to data retrievals: 2MB
+///
+/// Requests with a larger soft limit are capped to this.
+const SOFT_RESPONSE_LIMIT: usize = 2 * 1024 * 1024;
+
+/// Maximum number of bytecodes to serve.
+///
+/// Used to limit lookups.
+const MAX_CODE_LOOKUPS: usize = 1024;
+
+/// Maximum number of trie nodes to serve.
+///
+/// Used to limit lookups.
+const MAX_TRIE_NODE_LOOKUPS: usize = 1024;
+
+/// Number of most recent blocks whose state is served.
+///
+/// Requests for the state root of an older block are answered with an empty response.
+const MAX_STATE_LOOKBACK: u64 = 128;
+
+/// Number of entries that are read from the hashed state at once while serving a range.
+const RANGE_BATCH_SIZE: usize = 256;
+
+/// Maximum number of requests that are served at the same time.
+///
+/// Incoming requests are not polled while this many are in progress.
+const MAX_CONCURRENT_REQUESTS: usize = 4;
+
+/// Manages `snap` requests on top of the p2p network.
+///
+/// Accounts and storage slots are served from the hashed state and the range proofs are generated
+/// from the state trie, for the state of one of the [`MAX_STATE_LOOKBACK`] most recent blocks.
+/// Requests are served on blocking tasks, since they read the database and compute proofs.
+///
+/// This can be spawned to another task and is supposed to be run as background service.
+#[derive(Debug)]
+#[must_use = "Manager does nothing unless polled."]
+pub struct SnapRequestHandler<C> {
+    /// Serves the requests from the state of the client.
+    responder: SnapResponder<C>,
+    /// Incoming requests from the [`SnapConnection`](crate::snap::SnapConnection)s.
+    incoming_requests: ReceiverStream<IncomingSnapRequest>,
+    /// Requests that are currently being served.
+    inflight_requests: FuturesUnordered<JoinHandle<()>>,
+}
+
+// === impl SnapRequestHandler ===
+impl<C> SnapRequestHandler<C> {
+    /// Create a new instance
+    pub fn new(client: C, incoming: Receiver<IncomingSnapRequest>) -> Self {
+        Self {
+            responder: SnapResponder { client },
+            incoming_requests: ReceiverStream::new(incoming),
+            inflight_requests: FuturesUnordered::new(),
+        }
+    }
+}
+
+impl<C> SnapRequestHandler<C>
+where
+    C: BlockNumReader
+        + HeaderProvider
+        + HashedStateRangeReader
+        + StateProviderFactory
+        + Clone
+        + 'static,
+{
+    /// Spawns a blocking task that serves the request.
+    fn on_request(&mut self, request: IncomingSnapRequest) {
+        let responder = self.responder.clone();
+        self.inflight_requests
+            .push(tokio::task::spawn_blocking(move || responder.respond(request)));
+    }
+}
+
+/// An endless future.
+///
+/// This should be spawned or used as part of `tokio::select!`.
+impl<C> Future for SnapRequestHandler<C>
+where
+    C: BlockNumReader
+        + HeaderProvider
+        + HashedStateRangeReader
+        + StateProviderFactory
+        + Clone
+        + Unpin
+        + 'static,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        for _ in 0..DEFAULT_BUDGET_TRY_DRAIN_STREAM {
+            // drain finished requests to make room for new ones, this also registers the waker
+            // for the ones that were just spawned
+            while let Poll::Ready(Some(result)) = this.inflight_requests.poll_next_unpin(cx) {
+                if let Err(err) = result {
+                    debug!(target: "net::snap", %err, "Failed to serve snap request");
+                }
+            }
+
+            if this.inflight_requests.len() >= MAX_CONCURRENT_REQUESTS {
+                // woken up again once one of the requests is done
+                return Poll::Pending
+            }
+
+            match this.incoming_requests.poll_next_unpin(cx) {
+                Poll::Ready(Some(request)) => this.on_request(request),
+                Poll::Ready(None) => return Poll::Ready(()),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        // budget exhausted, make sure we're woken up again
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Answers `snap` requests from the state of the client.
+#[derive(Debug, Clone)]
+struct SnapResponder<C> {
+    /// The client type that can interact with the chain.
+    client: C,
+}
+
+impl<C> SnapResponder<C>
+where
+    C: BlockNumReader + HeaderProvider + HashedStateRangeReader + StateProviderFactory,
+{
+    /// Returns the number of the recent block with the given state root, if any.
+    fn find_state(&self, state_root: B256) -> ProviderResult<Option<BlockNumber>> {
+        let best_number = self.client.best_block_number()?;
+        for number in (best_number.saturating_sub(MAX_STATE_LOOKBACK)..=best_number).rev() {
+            let Some(header) = self.client.header_by_number(number)? else { continue };
+            if header.state_root() == state_root {
+                return Ok(Some(number))
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the accounts of the requested range with the boundary proofs.
+    fn get_account_range_response(
+        &self,
+        request: GetAccountRangeMessage,
+    ) -> ProviderResult<AccountRangeMessage> {
+        let GetAccountRangeMessage {
+            request_id,
+            root_hash,
+            starting_hash,
+            limit_hash,
+            response_bytes,
+        } = request;
+        let mut response =
+            AccountRangeMessage { request_id, accounts: Vec::new(), proof: Vec::new() };
+
+        let Some(number) = self.find_state(root_hash)? else { return Ok(response) };
+        let response_bytes = soft_response_limit(response_bytes);
+
+        self.client.with_hashed_state_at(number, |state| {
+            let mut total_bytes = 0;
+            let mut start = Some(starting_hash);
+            'range: while let Some(next) = start {
+                let accounts = state.accounts_range(next, RANGE_BATCH_SIZE)?;
+                start = next_batch_start(&accounts);
+
+                for (hashed_address, account) in accounts {
+                    let body = slim_account_body(&account);
+                    total_bytes += B256::len_bytes() + body.len();
+                    response.accounts.push(AccountData { hash: hashed_address, body });
+
+                    if hashed_address >= limit_hash || total_bytes >= response_bytes {
+                        break 'range
+                    }
+                }
+            }
+
+            // prove the start of the range and the last account, which also proves that there
+            // are no accounts in between that were left out
+            let mut targets = MultiProofTargets::default();
+            targets.entry(starting_hash).or_default();
+            if let Some(last) = response.accounts.last() {
+                targets.entry(last.hash).or_default();
+            }
+            let proof = state.state_multiproof(targets)?;
+            response.proof = proof
+                .account_subtree
+                .into_nodes_sorted()
+                .into_iter()
+                .map(|(_, node)| node)
+                .collect();
+
+            Ok(())
+        })?;
+
+        Ok(response)
+    }
+
+    /// Returns the storage slots of the requested accounts, with the boundary proofs of the last
+    /// storage range if it doesn't cover the entire storage.
+    fn get_storage_ranges_response(
+        &self,
+        request: GetStorageRangesMessage,
+    ) -> ProviderResult<StorageRangesMessage> {
+        let GetStorageRangesMessage {
+            request_id,
+            root_hash,
+            account_hashes,
+            starting_hash,
+            limit_hash,
+            response_bytes,
+        } = request;
+        let mut response =
+            StorageRangesMessage { request_id, slots: Vec::new(), proof: Vec::new() };
+
+        let Some(number) = self.find_state(root_hash)? else { return Ok(response) };
+        let response_bytes = soft_response_limit(response_bytes);
+
+        self.client.with_hashed_state_at(number, |state| {
+            let mut total_bytes = 0;
+            for (idx, hashed_address) in account_hashes.into_iter().enumerate() {
+                if total_bytes >= response_bytes {
+                    break
+                }
+
+                // the requested range only applies to the first account, the storage of the
+                // other accounts is served from the start
+                let (origin, limit) = if idx == 0 {
+                    (starting_hash, limit_hash)
+                } else {
+                    (B256::ZERO, B256::repeat_byte(0xff))
+                };
+
+                let mut slots = Vec::new();
+                let mut aborted = false;
+                let mut start = Some(origin);
+                'range: while let Some(next) = start {
+                    let batch = state.storage_range(hashed_address, next, RANGE_BATCH_SIZE)?;
+                    start = next_batch_start(&batch);
+
+                    for (hashed_slot, value) in batch {
+                        if total_bytes >= response_bytes {
+                            aborted = true;
+                            break 'range
+                        }
+
+                        let data = Bytes::from(alloy_rlp::encode(value));
+                        total_bytes += B256::len_bytes() + data.len();
+                        slots.push(StorageData { hash: hashed_slot, data });
+
+                        if hashed_slot >= limit {
+                            break 'range
+                        }
+                    }
+                }
+
+                // only a partial storage range is proven, a complete one can be verified against
+                // the storage root of the account
+                if origin != B256::ZERO || (aborted && !slots.is_empty()) {
+                    let mut targets = MultiProofTargets::default();
+                    let hashed_slots = targets.entry(hashed_address).or_default();
+                    hashed_slots.insert(origin);
+                    if let Some(last) = slots.last() {
+                        hashed_slots.insert(last.hash);
+                    }
+                    let mut proof = state.state_multiproof(targets)?;
+                    if let Some(storage_proof) = proof.storages.remove(&hashed_address) {
+                        response.proof = storage_proof
+                            .subtree
+                            .into_nodes_sorted()
+                            .into_iter()
+                            .map(|(_, node)| node)
+                            .collect();
+                    }
+                }
+
+                response.slots.push(slots);
+                if aborted {
+                    break
+                }
+            }
+
+            Ok(())
+        })?;
+
+        Ok(response)
+    }
+
+    /// Returns the requested bytecodes, in order and skipping unknown ones.
+    fn get_byte_codes_response(
+        &self,
+        request: GetByteCodesMessage,
+    ) -> ProviderResult<ByteCodesMessage> {
+        let GetByteCodesMessage { request_id, hashes, response_bytes } = request;
+        let response_bytes = soft_response_limit(response_bytes);
+
+        let state = self.client.latest()?;
+        let mut codes = Vec::new();
+        let mut total_bytes = 0;
+        for hash in hashes.into_iter().take(MAX_CODE_LOOKUPS) {
+            let code = if hash == KECCAK_EMPTY {
+                Bytes::new()
+            } else if let Some(code) = state.bytecode_by_hash(&hash)? {
+                code.original_bytes()
+            } else {
+                continue
+            };
+
+            total_bytes += code.len();
+            codes.push(code);
+
+            if total_bytes >= response_bytes {
+                break
+            }
+        }
+
+        Ok(ByteCodesMessage { request_id, codes })
+    }
+
+    /// Returns the requested trie nodes, in order and stopping at the first missing one.
+    fn get_trie_nodes_response(
+        &self,
+        request: GetTrieNodesMessage,
+    ) -> ProviderResult<TrieNodesMessage> {
+        let GetTrieNodesMessage { request_id, root_hash, paths, response_bytes } = request;
+        let mut response = TrieNodesMessage { request_id, nodes: Vec::new() };
+
+        let Some(number) = self.find_state(root_hash)? else { return Ok(response) };
+        let response_bytes = soft_response_limit(response_bytes);
+
+        // the nodes are looked up in a proof of a key that has the requested path as prefix
+        let mut targets = MultiProofTargets::default();
+        let mut lookups = Vec::new();
+        'paths: for TriePath { account_path, slot_paths } in paths {
+            if slot_paths.is_empty() {
+                let Some((key, path)) = decode_compact_path(&account_path) else { break };
+                targets.entry(key).or_default();
+                lookups.push((None, path));
+            } else {
+                // the account path of storage nodes is the full hashed address
+                if account_path.len() != B256::len_bytes() {
+                    break
+                }
+                let hashed_address = B256::from_slice(&account_path);
+                let hashed_slots = targets.entry(hashed_address).or_default();
+                for slot_path in slot_paths {
+                    let Some((key, path)) = decode_compact_path(&slot_path) else { break 'paths };
+                    hashed_slots.insert(key);
+                    lookups.push((Some(hashed_address), path));
+                }
+            }
+
+            if lookups.len() >= MAX_TRIE_NODE_LOOKUPS {
+                lookups.truncate(MAX_TRIE_NODE_LOOKUPS);
+                break
+            }
+        }
+
+        if lookups.is_empty() {
+            return Ok(response)
+        }
+
+        let proof =
+            self.client.with_hashed_state_at(number, |state| state.state_multiproof(targets))?;
+        let mut total_bytes = 0;
+        for (hashed_address, path) in lookups {
+            let node = match hashed_address {
+                None => proof.account_subtree.get(&path),
+                Some(hashed_address) => {
+                    proof.storages.get(&hashed_address).and_then(|proof| proof.subtree.get(&path))
+                }
+            };
+            let Some(node) = node else { break };
+
+            total_bytes += node.len();
+            response.nodes.push(node.clone());
+
+            if total_bytes >= response_bytes {
+                break
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Serves the request, answering with an empty response if the state can't be read.
+    fn respond(&self, request: IncomingSnapRequest) {
+        match request {
+            IncomingSnapRequest::GetAccountRange { peer_id, request, response } => {
+                let request_id = request.request_id;
+                let result = self.get_account_range_response(request).unwrap_or_else(|err| {
+                    debug!(target: "net::snap", ?peer_id, %err, "Failed to serve account range");
+                    AccountRangeMessage { request_id, accounts: Vec::new(), proof: Vec::new() }
+                });
+                let _ = response.send(result);
+            }
+            IncomingSnapRequest::GetStorageRanges { peer_id, request, response } => {
+                let request_id = request.request_id;
+                let result = self.get_storage_ranges_response(request).unwrap_or_else(|err| {
+                    debug!(target: "net::snap", ?peer_id, %err, "Failed to serve storage ranges");
+                    StorageRangesMessage { request_id, slots: Vec::new(), proof: Vec::new() }
+                });
+                let _ = response.send(result);
+            }
+            IncomingSnapRequest::GetByteCodes { peer_id, request, response } => {
+                let request_id = request.request_id;
+                let result = self.get_byte_codes_response(request).unwrap_or_else(|err| {
+                    debug!(target: "net::snap", ?peer_id, %err, "Failed to serve bytecodes");
+                    ByteCodesMessage { request_id, codes: Vec::new() }
+                });
+                let _ = response.send(result);
+            }
+            IncomingSnapRequest::GetTrieNodes { peer_id, request, response } => {
+                let request_id = request.request_id;
+                let result = self.get_trie_nodes_response(request).unwrap_or_else(|err| {
+                    debug!(target: "net::snap", ?peer_id, %err, "Failed to serve trie nodes");
+                    TrieNodesMessage { request_id, nodes: Vec::new() }
+                });
+                let _ = response.send(result);
+            }
+        }
+    }
+}
+
+/// All `snap` requests delegated by the [`SnapConnection`](crate::snap::SnapConnection)s.
+#[derive(Debug)]
+pub enum IncomingSnapRequest {
+    /// Request a range of accounts from the peer.
+    ///
+    /// The response should be sent through the channel.
+    GetAccountRange {
+        /// The ID of the peer that sent the request.
+        peer_id: PeerId,
+        /// The requested account range.
+        request: GetAccountRangeMessage,
+        /// The channel sender for the response containing the accounts.
+        response: oneshot::Sender<AccountRangeMessage>,
+    },
+    /// Request storage ranges from the peer.
+    ///
+    /// The response should be sent through the channel.
+    GetStorageRanges {
+        /// The ID of the peer that sent the request.
+        peer_id: PeerId,
+        /// The requested storage ranges.
+        request: GetStorageRangesMessage,
+        /// The channel sender for the response containing the storage slots.
+        response: oneshot::Sender<StorageRangesMessage>,
+    },
+    /// Request bytecodes from the peer.
+    ///
+    /// The response should be sent through the channel.
+    GetByteCodes {
+        /// The ID of the peer that sent the request.
+        peer_id: PeerId,
+        /// The requested bytecodes.
+        request: GetByteCodesMessage,
+        /// The channel sender for the response containing the bytecodes.
+        response: oneshot::Sender<ByteCodesMessage>,
+    },
+    /// Request trie nodes from the peer.
+    ///
+    /// The response should be sent through the channel.
+    GetTrieNodes {
+        /// The ID of the peer that sent the request.
+        peer_id: PeerId,
+        /// The requested trie nodes.
+        request: GetTrieNodesMessage,
+        /// The channel sender for the response containing the trie nodes.
+        response: oneshot::Sender<TrieNodesMessage>,
+    },
+}
+
+/// An account in the slim format of the `snap` protocol, where the empty storage root and code
+/// hash are omitted.
+#[derive(RlpEncodable)]
+struct SlimAccount {
+    nonce: u64,
+    balance: U256,
+    storage_root: Bytes,
+    code_hash: Bytes,
+}
+
+/// Returns the slim encoding of the account.
+fn slim_account_body(account: &TrieAccount) -> Bytes {
+    let storage_root = if account.storage_root == EMPTY_ROOT_HASH {
+        Bytes::new()
+    } else {
+        Bytes::copy_from_slice(account.storage_root.as_slice())
+    };
+    let code_hash = if account.code_hash == KECCAK_EMPTY {
+        Bytes::new()
+    } else {
+        Bytes::copy_from_slice(account.code_hash.as_slice())
+    };
+    alloy_rlp::encode(SlimAccount {
+        nonce: account.nonce,
+        balance: account.balance,
+        storage_root,
+        code_hash,
+    })
+    .into()
+}
+
+/// Caps the requested soft limit of the response size.
+fn soft_response_limit(response_bytes: u64) -> usize {
+    usize::try_from(response_bytes).unwrap_or(usize::MAX).min(SOFT_RESPONSE_LIMIT)
+}
+
+/// Returns the key to continue reading a range at, if the batch was full.
+fn next_batch_start<T>(batch: &[(B256, T)]) -> Option<B256> {
+    if batch.len() < RANGE_BATCH_SIZE {
+        return None
+    }
+    let (last, _) = batch.last()?;
+    U256::from_be_bytes(last.0).checked_add(U256::from(1)).map(B256::from)
+}
+
+/// Decodes a trie path in compact encoding.
+///
+/// Returns the hashed key that has the path as prefix, padded with zeros, and the path itself.
+fn decode_compact_path(compact: &[u8]) -> Option<(B256, Nibbles)> {
+    let (flag, rest) = compact.split_first()?;
+
+    let mut nibbles = Vec::with_capacity(rest.len() * 2 + 1);
+    // the flag nibble marks an odd number of nibbles, with the first one stored next to it
+    if flag & 0x10 != 0 {
+        nibbles.push(flag & 0x0f);
+    }
+    for byte in rest {
+        nibbles.push(byte >> 4);
+        nibbles.push(byte & 0x0f);
+    }
+    if nibbles.len() > B256::len_bytes() * 2 {
+        return None
+    }
+
+    let mut key = B256::ZERO;
+    for (idx, nibble) in nibbles.iter().enumerate() {
+        key.0[idx / 2] |= if idx % 2 == 0 { nibble << 4 } else { *nibble };
+    }
+    Some((key, Nibbles::unpack(key).slice(..nibbles.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::Header;
+    use alloy_primitives::{b256, hex, keccak256, Address};
+    use reth_primitives::{Account, Block, SealedBlock};
+    use reth_provider::{
+        providers::BlockchainProvider,
+        test_utils::{
+            create_test_provider_factory, ExtendedAccount, MockEthProvider, MockNodeTypesWithDB,
+        },
+        BlockWriter, StateWriter, StorageLocation,
+    };
+    use reth_storage_api::StateProofProvider;
+    use reth_trie::{HashedPostState, HashedStorage, TrieInput};
+    use tokio::sync::mpsc;
+
+    fn responder<C>(client: C) -> SnapResponder<C> {
+        SnapResponder { client }
+    }
+
+    /// Writes the hashed state as the state of the genesis block and returns a provider for it,
+    /// together with the state root.
+    fn provider_with_state(
+        accounts: &[(Address, Account)],
+        storages: &[(Address, Vec<(B256, U256)>)],
+    ) -> (BlockchainProvider<MockNodeTypesWithDB>, B256) {
+        let factory = create_test_provider_factory();
+        let state = HashedPostState::default()
+            .with_accounts(
+                accounts.iter().map(|(address, account)| (keccak256(address), Some(*account))),
+            )
+            .with_storages(storages.iter().map(|(address, slots)| {
+                let slots = slots.iter().map(|(slot, value)| (keccak256(slot), *value));
+                (keccak256(address), HashedStorage::from_iter(false, slots))
+            }));
+
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.write_hashed_state(&state.into_sorted()).unwrap();
+        provider_rw.commit().unwrap();
+        let state_root = factory.latest().unwrap().state_root(HashedPostState::default()).unwrap();
+
+        let block = SealedBlock::seal_slow(Block {
+            header: Header { state_root, ..Default::default() },
+            body: Default::default(),
+        });
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.insert_block(block.try_recover().unwrap(), StorageLocation::Database).unwrap();
+        provider_rw.commit().unwrap();
+
+        (BlockchainProvider::new(factory).unwrap(), state_root)
+    }
+
+    #[test]
+    fn serve_byte_codes() {
+        let provider = MockEthProvider::default();
+        let code = Bytes::from_static(&hex!("6080604052"));
+        provider.add_account(
+            Address::random(),
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(code.clone()),
+        );
+        let responder = responder(provider);
+
+        // unknown codes are skipped
+        let hashes = vec![B256::random(), keccak256(&code), KECCAK_EMPTY];
+        let response = responder
+            .get_byte_codes_response(GetByteCodesMessage {
+                request_id: 1,
+                hashes: hashes.clone(),
+                response_bytes: 1024,
+            })
+            .unwrap();
+        assert_eq!(
+            response,
+            ByteCodesMessage { request_id: 1, codes: vec![code.clone(), Bytes::new()] }
+        );
+
+        // the soft limit is reached with the first code
+        let response = responder
+            .get_byte_codes_response(GetByteCodesMessage {
+                request_id: 2,
+                hashes,
+                response_bytes: 1,
+            })
+            .unwrap();
+        assert_eq!(response, ByteCodesMessage { request_id: 2, codes: vec![code] });
+    }
+
+    #[test]
+    fn unknown_state_root() {
+        let provider = MockEthProvider::default();
+        let state_root = B256::repeat_byte(0x01);
+        provider.add_header(B256::random(), Header { state_root, ..Default::default() });
+        let responder = responder(provider);
+
+        assert_eq!(responder.find_state(state_root).unwrap(), Some(0));
+
+        let response = responder
+            .get_account_range_response(GetAccountRangeMessage {
+                request_id: 1,
+                root_hash: B256::repeat_byte(0x02),
+                starting_hash: B256::ZERO,
+                limit_hash: B256::repeat_byte(0xff),
+                response_bytes: 1024,
+            })
+            .unwrap();
+        assert_eq!(
+            response,
+            AccountRangeMessage { request_id: 1, accounts: Vec::new(), proof: Vec::new() }
+        );
+    }
+
+    #[test]
+    fn compact_paths() {
+        // even number of nibbles
+        let (key, path) = decode_compact_path(&hex!("001234")).unwrap();
+        assert_eq!(key, b256!("1234000000000000000000000000000000000000000000000000000000000000"));
+        assert_eq!(path, Nibbles::from_nibbles([0x1, 0x2, 0x3, 0x4]));
+
+        // odd number of nibbles
+        let (key, path) = decode_compact_path(&hex!("1123")).unwrap();
+        assert_eq!(key, b256!("1230000000000000000000000000000000000000000000000000000000000000"));
+        assert_eq!(path, Nibbles::from_nibbles([0x1, 0x2, 0x3]));
+
+        // root
+        let (key, path) = decode_compact_path(&hex!("00")).unwrap();
+        assert_eq!(key, B256::ZERO);
+        assert!(path.is_empty());
+
+        // too long
+        assert!(decode_compact_path(&[0u8; 34]).is_none());
+        assert!(decode_compact_path(&[]).is_none());
+    }
+
+    #[test]
+    fn slim_accounts() {
+        let account = TrieAccount {
+            nonce: 1,
+            balance: U256::from(2),
+            storage_root: EMPTY_ROOT_HASH,
+            code_hash: KECCAK_EMPTY,
+        };
+        assert_eq!(slim_account_body(&account), Bytes::from_static(&hex!("c401028080")));
+
+        let account = TrieAccount { storage_root: B256::repeat_byte(0x11), ..account };
+        let body = slim_account_body(&account);
+        assert_eq!(body.len(), 1 + 2 + 33 + 1);
+        assert_eq!(&body[3..], &[&[0xa0][..], &[0x11; 32], &[0x80]].concat()[..]);
+    }
+
+    #[test]
+    fn batch_start() {
+        let full = vec![(B256::ZERO, ()); RANGE_BATCH_SIZE];
+        assert_eq!(next_batch_start(&full), Some(B256::with_last_byte(1)));
+        assert_eq!(next_batch_start(&full[1..]), None);
+
+        let last = vec![(B256::repeat_byte(0xff), ()); RANGE_BATCH_SIZE];
+        assert_eq!(next_batch_start(&last), None);
+    }
+
+    #[tokio::test]
+    async fn serve_account_range() {
+        let accounts = (0..100u64)
+            .map(|nonce| (Address::random(), Account { nonce, ..Default::default() }))
+            .collect::<Vec<_>>();
+        let (provider, state_root) = provider_with_state(&accounts, &[]);
+        let mut hashed_addresses =
+            accounts.iter().map(|(address, _)| keccak256(address)).collect::<Vec<_>>();
+        hashed_addresses.sort_unstable();
+
+        // requests are served through the handler
+        let (tx, rx) = mpsc::channel(1);
+        tokio::spawn(SnapRequestHandler::new(provider.clone(), rx));
+        let (response_tx, response_rx) = oneshot::channel();
+        tx.send(IncomingSnapRequest::GetAccountRange {
+            peer_id: PeerId::random(),
+            request: GetAccountRangeMessage {
+                request_id: 1,
+                root_hash: state_root,
+                starting_hash: B256::ZERO,
+                limit_hash: B256::repeat_byte(0xff),
+                response_bytes: SOFT_RESPONSE_LIMIT as u64,
+            },
+            response: response_tx,
+        })
+        .await
+        .unwrap();
+        let response = response_rx.await.unwrap();
+        assert_eq!(
+            response.accounts.iter().map(|account| account.hash).collect::<Vec<_>>(),
+            hashed_addresses
+        );
+
+        // the range is cut off after the soft limit and starts at the requested hash
+        let responder = responder(provider.clone());
+        let response = responder
+            .get_account_range_response(GetAccountRangeMessage {
+                request_id: 2,
+                root_hash: state_root,
+                starting_hash: hashed_addresses[10],
+                limit_hash: B256::repeat_byte(0xff),
+                response_bytes: 1,
+            })
+            .unwrap();
+        assert_eq!(response.accounts.len(), 1);
+        assert_eq!(response.accounts[0].hash, hashed_addresses[10]);
+
+        // the range stops at the limit hash
+        let response = responder
+            .get_account_range_response(GetAccountRangeMessage {
+                request_id: 3,
+                root_hash: state_root,
+                starting_hash: hashed_addresses[10],
+                limit_hash: hashed_addresses[19],
+                response_bytes: SOFT_RESPONSE_LIMIT as u64,
+            })
+            .unwrap();
+        assert_eq!(
+            response.accounts.iter().map(|account| account.hash).collect::<Vec<_>>(),
+            &hashed_addresses[10..20]
+        );
+    }
+
+    #[test]
+    fn account_range_proof() {
+        let accounts = (0..100u64)
+            .map(|nonce| (Address::random(), Account { nonce, ..Default::default() }))
+            .collect::<Vec<_>>();
+        let (provider, state_root) = provider_with_state(&accounts, &[]);
+        let mut hashed_addresses =
+            accounts.iter().map(|(address, _)| (keccak256(address), *address)).collect::<Vec<_>>();
+        hashed_addresses.sort_unstable();
+
+        let response = responder(provider.clone())
+            .get_account_range_response(GetAccountRangeMessage {
+                request_id: 1,
+                root_hash: state_root,
+                starting_hash: hashed_addresses[10].0,
+                limit_hash: hashed_addresses[19].0,
+                response_bytes: SOFT_RESPONSE_LIMIT as u64,
+            })
+            .unwrap();
+
+        // the proofs of the first and last account of the range are part of the response
+        let state = provider.latest().unwrap();
+        for (_, address) in [hashed_addresses[10], hashed_addresses[19]] {
+            let proof = state.proof(TrieInput::default(), address, &[]).unwrap();
+            proof.verify(state_root).unwrap();
+            assert!(proof.proof.iter().all(|node| response.proof.contains(node)));
+        }
+    }
+
+    #[test]
+    fn serve_storage_ranges() {
+        let first = Address::random();
+        let second = Address::random();
+        let first_slots =
+            (0..300u64).map(|value| (B256::random(), U256::from(value + 1))).collect::<Vec<_>>();
+        let second_slots = vec![(B256::random(), U256::from(1)), (B256::random(), U256::from(2))];
+        let (provider, state_root) = provider_with_state(
+            &[(first, Account::default()), (second, Account::default())],
+            &[(first, first_slots.clone()), (second, second_slots)],
+        );
+        let mut hashed_slots =
+            first_slots.iter().map(|(slot, _)| (keccak256(slot), *slot)).collect::<Vec<_>>();
+        hashed_slots.sort_unstable();
+
+        // complete ranges spanning several batches are served without proofs
+        let responder = responder(provider.clone());
+        let response = responder
+            .get_storage_ranges_response(GetStorageRangesMessage {
+                request_id: 1,
+                root_hash: state_root,
+                account_hashes: vec![keccak256(first), keccak256(second)],
+                starting_hash: B256::ZERO,
+                limit_hash: B256::repeat_byte(0xff),
+                response_bytes: SOFT_RESPONSE_LIMIT as u64,
+            })
+            .unwrap();
+        assert_eq!(response.slots.len(), 2);
+        assert_eq!(
+            response.slots[0].iter().map(|slot| slot.hash).collect::<Vec<_>>(),
+            hashed_slots.iter().map(|(hash, _)| *hash).collect::<Vec<_>>()
+        );
+        assert_eq!(response.slots[1].len(), 2);
+        assert!(response.proof.is_empty());
+
+        // a partial range is proven
+        let response = responder
+            .get_storage_ranges_response(GetStorageRangesMessage {
+                request_id: 2,
+                root_hash: state_root,
+                account_hashes: vec![keccak256(first)],
+                starting_hash: hashed_slots[10].0,
+                limit_hash: hashed_slots[19].0,
+                response_bytes: SOFT_RESPONSE_LIMIT as u64,
+            })
+            .unwrap();
+        assert_eq!(
+            response.slots[0].iter().map(|slot| slot.hash).collect::<Vec<_>>(),
+            hashed_slots[10..20].iter().map(|(hash, _)| *hash).collect::<Vec<_>>()
+        );
+
+        let proof = provider
+            .latest()
+            .unwrap()
+            .proof(TrieInput::default(), first, &[hashed_slots[10].1, hashed_slots[19].1])
+            .unwrap();
+        proof.verify(state_root).unwrap();
+        for storage_proof in &proof.storage_proofs {
+            assert!(storage_proof.proof.iter().all(|node| response.proof.contains(node)));
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     fetch::{BlockResponseOutcome, FetchAction, StateFetcher},
     message::{BlockRequest, NewBlockMessage, PeerResponse, PeerResponseResult},
     peers::{PeerAction, PeersManager},
+    session::BlockRangeInfo,
     FetchClient,
 };
 use alloy_consensus::BlockHeader;
@@ -123,6 +124,11 @@ impl<N: NetworkPrimitives> NetworkState<N> {
         &mut self.discovery
     }
 
+    /// Returns access to the client used to look up block numbers.
+    pub(crate) fn client(&self) -> &dyn reth_storage_api::BlockNumReader {
+        &**self.client
+    }
+
     /// Returns access to the [`PeersManager`]
     pub(crate) const fn peers(&self) -> &PeersManager {
         &self.peers_manager
@@ -149,13 +155,22 @@ impl<N: NetworkPrimitives> NetworkState<N> {
         status: Arc<Status>,
         request_tx: PeerRequestSender<PeerRequest<N>>,
         timeout: Arc<AtomicU64>,
+        range_info: Option<BlockRangeInfo>,
     ) {
         debug_assert!(!self.active_peers.contains_key(&peer), "Already connected; not possible");
 
-        // find the corresponding block number
-        let block_number =
-            self.client.block_number(status.blockhash).ok().flatten().unwrap_or_default();
-        self.state_fetcher.new_active_peer(peer, status.blockhash, block_number, timeout);
+        // find the corresponding block number, eth/69 peers advertise it as part of their range
+        let block_number = match &range_info {
+            Some(range_info) => range_info.latest(),
+            None => self.client.block_number(status.blockhash).ok().flatten().unwrap_or_default(),
+        };
+        self.state_fetcher.new_active_peer(
+            peer,
+            status.blockhash,
+            block_number,
+            timeout,
+            range_info,
+        );
 
         self.active_peers.insert(
             peer,
@@ -613,6 +628,7 @@ mod tests {
             Arc::default(),
             peer_tx,
             Arc::new(AtomicU64::new(1)),
+            None,
         );
 
         assert!(state.active_peers.contains_key(&peer_id));
@@ -53,9 +53,10 @@ use reth_primitives::{transaction::SignedTransactionIntoRecoveredExt, Transactio
 use reth_primitives_traits::SignedTransaction;
 use reth_tokio_util::EventStream;
 use reth_transaction_pool::{
+    admission::AdmissionSource,
     error::{PoolError, PoolResult},
     GetPooledTransactionLimit, PoolTransaction, PropagateKind, PropagatedTransactions,
-    TransactionPool, ValidPoolTransaction,
+    TransactionOrigin, TransactionPool, ValidPoolTransaction,
 };
 use std::{
     collections::{hash_map::Entry, HashMap, HashSet},
@@ -1276,7 +1277,13 @@ where
                 trace!(target: "net::tx::propagation", new_txs_len=?new_txs.len(), "Importing new transactions");
                 let import = Box::pin(async move {
                     let added = new_txs.len();
-                    let res = pool.add_external_transactions(new_txs).await;
+                    let res = pool
+                        .add_transactions_from(
+                            TransactionOrigin::External,
+                            AdmissionSource::Peer(peer_id),
+                            new_txs,
+                        )
+                        .await;
 
                     // update metrics
                     metric_pending_pool_imports.decrement(added as f64);
@@ -5,7 +5,9 @@ use alloy_rpc_types_engine::JwtSecret;
 use reth_basic_payload_builder::PayloadBuilder;
 use reth_consensus::{ConsensusError, FullConsensus};
 use reth_db_api::{database_metrics::DatabaseMetrics, Database};
-use reth_engine_primitives::{BeaconConsensusEngineEvent, BeaconConsensusEngineHandle};
+use reth_engine_primitives::{
+    BeaconConsensusEngineEvent, BeaconConsensusEngineHandle, LocalMinerHandle,
+};
 use reth_evm::{execute::BlockExecutorProvider, ConfigureEvmFor};
 use reth_network_api::FullNetwork;
 use reth_node_core::node_config::NodeConfig;
@@ -130,6 +132,8 @@ pub struct AddOnsContext<'a, N: FullNodeComponents> {
     pub engine_events: EventSender<BeaconConsensusEngineEvent<<N::Types as NodeTypes>::Primitives>>,
     /// JWT secret for the node.
     pub jwt_secret: JwtSecret,
+    /// Handle to the local miner, only set for dev nodes.
+    pub local_miner: Option<LocalMinerHandle<<N::Types as NodeTypes>::Primitives>>,
 }
 
 /// Customizable node add-on types.
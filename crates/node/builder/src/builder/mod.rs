@@ -35,9 +35,16 @@ use reth_provider::{
     ChainSpecProvider, FullProvider,
 };
 use reth_tasks::TaskExecutor;
-use reth_transaction_pool::{PoolConfig, PoolTransaction, TransactionPool};
+use reth_transaction_pool::{
+    blobstore::BlobStore, Pool, PoolConfig, PoolTransaction, TransactionOrdering, TransactionPool,
+    TransactionValidator,
+};
 use secp256k1::SecretKey;
-use std::sync::Arc;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 use tracing::{info, trace, warn};
 
 pub mod add_ons;
@@ -577,6 +584,9 @@ where
     }
 }
 
+/// How often the reth.toml config is checked for changes of the pool's admission rules.
+const POOL_ADMISSION_RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+
 /// Captures the necessary context for building the components of the node.
 pub struct BuilderContext<Node: FullNodeTypes> {
     /// The current head of the blockchain at launch.
@@ -637,9 +647,65 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         self.config().dev.dev
     }
 
+    /// Returns the path to the reth.toml config file.
+    pub fn config_path(&self) -> PathBuf {
+        self.config().config.clone().unwrap_or_else(|| self.config().datadir().config())
+    }
+
     /// Returns the transaction pool config of the node.
+    ///
+    /// The admission rules of the pool are taken from the reth.toml config.
     pub fn pool_config(&self) -> PoolConfig {
-        self.config().txpool.pool_config()
+        let mut config = self.config().txpool.pool_config();
+        config.admission = self.reth_config().txpool.admission.clone();
+        config
+    }
+
+    /// Spawns a task that applies changes of the admission rules in the reth.toml config to the
+    /// given pool.
+    pub fn spawn_pool_admission_reloader<V, T, S>(&self, pool: Pool<V, T, S>)
+    where
+        V: TransactionValidator + 'static,
+        T: TransactionOrdering<Transaction = V::Transaction>,
+        S: BlobStore,
+    {
+        let path = self.config_path();
+        let mut admission = self.reth_config().txpool.admission.clone();
+
+        self.executor.spawn(Box::pin(async move {
+            async fn modified(path: &Path) -> Option<SystemTime> {
+                tokio::fs::metadata(path).await.and_then(|m| m.modified()).ok()
+            }
+
+            let mut last_modified = modified(&path).await;
+            let mut interval = tokio::time::interval(POOL_ADMISSION_RELOAD_INTERVAL);
+
+            loop {
+                interval.tick().await;
+
+                let current = modified(&path).await;
+                if current.is_none() || current == last_modified {
+                    continue
+                }
+                last_modified = current;
+
+                let config = tokio::fs::read_to_string(&path)
+                    .await
+                    .map_err(eyre::Report::from)
+                    .and_then(|s| Ok(toml::from_str::<reth_config::Config>(&s)?));
+                match config {
+                    Ok(config) if config.txpool.admission != admission => {
+                        admission = config.txpool.admission;
+                        pool.set_admission_config(admission.clone());
+                        info!(target: "reth::cli", ?path, "Reloaded transaction pool admission rules");
+                    }
+                    Ok(_) => {}
+                    Err(err) => {
+                        warn!(target: "reth::cli", %err, ?path, "Failed to reload transaction pool admission rules");
+                    }
+                }
+            }
+        }));
     }
 
     /// Loads `EnvKzgSettings::Default`.
@@ -698,13 +764,20 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
             + 'static,
         Node::Provider: BlockReaderFor<N>,
     {
-        let (handle, network, txpool, eth) = builder
-            .transactions(pool, tx_config)
-            .request_handler(self.provider().clone())
-            .split_with_handle();
+        let mut builder =
+            builder.transactions(pool, tx_config).request_handler(self.provider().clone());
+        let snap = self
+            .config()
+            .network
+            .serve_snap
+            .then(|| builder.snap_request_handler(self.provider().clone()));
+        let (handle, network, txpool, eth) = builder.split_with_handle();
 
         self.executor.spawn_critical("p2p txpool", txpool);
         self.executor.spawn_critical("p2p eth request handler", eth);
+        if let Some(snap) = snap {
+            self.executor.spawn_critical("p2p snap request handler", snap);
+        }
 
         let default_peers_path = self.config().datadir().known_peers();
         let known_peers_file = self.config().network.persistent_peers_file(default_peers_path);
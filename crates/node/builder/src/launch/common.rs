@@ -113,6 +113,9 @@ impl LaunchContext {
 
         // Update the config with the command line arguments
         toml_config.peers.trusted_nodes_only = config.network.trusted_only;
+        if let Some(url) = &config.static_files_url {
+            toml_config.stages.s3.url = Some(url.clone());
+        }
 
         Ok(toml_config)
     }
@@ -389,6 +392,7 @@ where
             StaticFileProvider::read_write(self.data_dir().static_files())?,
         )
         .with_prune_modes(self.prune_modes())
+        .with_log_index(self.node_config().rpc.rpc_log_index)
         .with_static_files_metrics();
 
         let has_receipt_pruning =
@@ -826,8 +830,9 @@ where
             .block_number;
 
         // Skip the first stage as we've already retrieved it and comparing all other checkpoints
-        // against it.
-        for stage_id in StageId::ALL.iter().skip(1) {
+        // against it. The log index stage is only part of the pipeline if the index is enabled.
+        let log_index_stage = self.node_config().rpc.rpc_log_index.then_some(&StageId::IndexLogs);
+        for stage_id in StageId::ALL.iter().skip(1).chain(log_index_stage) {
             let stage_checkpoint = self
                 .blockchain_db()
                 .get_stage_checkpoint(*stage_id)?
@@ -1068,6 +1073,12 @@ mod tests {
                     storage_history_full: false,
                     storage_history_distance: None,
                     storage_history_before: None,
+                    address_transactions_full: false,
+                    address_transactions_distance: None,
+                    address_transactions_before: None,
+                    log_index_full: false,
+                    log_index_distance: None,
+                    log_index_before: None,
                     receipts_log_filter: vec![],
                 },
                 ..NodeConfig::test()
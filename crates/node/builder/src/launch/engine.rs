@@ -32,7 +32,7 @@ use reth_tokio_util::EventSender;
 use reth_tracing::tracing::{debug, error, info};
 use std::sync::Arc;
 use tokio::sync::{mpsc::unbounded_channel, oneshot};
-use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::wrappers::{UnboundedReceiverStream, WatchStream};
 
 use crate::{
     common::{Attached, LaunchContextWith, WithConfigs},
@@ -204,12 +204,13 @@ where
         // extract the jwt secret from the args if possible
         let jwt_secret = ctx.auth_jwt_secret()?;
 
-        let add_ons_ctx = AddOnsContext {
+        let mut add_ons_ctx = AddOnsContext {
             node: ctx.node_adapter().clone(),
             config: ctx.node_config(),
             beacon_engine_handle: beacon_engine_handle.clone(),
             jwt_secret,
             engine_events: event_sender.clone(),
+            local_miner: None,
         };
         let engine_payload_validator = add_ons.engine_validator(&add_ons_ctx).await?;
 
@@ -231,6 +232,7 @@ where
                 LocalPayloadAttributesBuilder::new(ctx.chain_spec()),
                 ctx.components().evm_config().clone(),
             );
+            add_ons_ctx.local_miner = Some(eth_service.miner_handle());
 
             Either::Left(eth_service)
         } else {
@@ -326,6 +328,15 @@ where
             .map_err(|e| eyre::eyre!("Failed to subscribe to payload builder events: {:?}", e))?
             .into_built_payload_stream()
             .fuse();
+        // the payload that is currently being built is served as the pending block
+        let mut pending_payloads = WatchStream::from_changes(
+            ctx.components()
+                .payload_builder_handle()
+                .subscribe_pending_payload()
+                .await
+                .map_err(|e| eyre::eyre!("Failed to subscribe to pending payloads: {:?}", e))?,
+        )
+        .fuse();
         let chainspec = ctx.chain_spec();
         let (exit, rx) = oneshot::channel();
         let terminate_after_backfill = ctx.terminate_after_initial_backfill();
@@ -352,6 +363,14 @@ where
                             }
                         }
                     }
+                    payload = pending_payloads.select_next_some() => {
+                        if let Some(executed_block) = payload.and_then(|payload| payload.executed_block()) {
+                            // the tree only accepts the block if it extends its canonical head
+                            if let Either::Right(eth_service) = &mut engine_service {
+                                eth_service.orchestrator_mut().handler_mut().handler_mut().on_event(EngineApiRequest::SetPendingBlock(executed_block).into());
+                            }
+                        }
+                    }
                     event = engine_service.next() => {
                         let Some(event) = event else { break };
                         debug!(target: "reth::cli", "Event: {event}");
@@ -8,7 +8,7 @@ use std::{
 
 use crate::{BeaconConsensusEngineEvent, BeaconConsensusEngineHandle, EthApiBuilderCtx};
 use alloy_rpc_types::engine::{ClientVersionV1, ExecutionData};
-use futures::TryFutureExt;
+use futures::{StreamExt, TryFutureExt};
 use reth_chainspec::EthereumHardforks;
 use reth_node_api::{
     AddOnsContext, BlockTy, EngineTypes, EngineValidator, FullNodeComponents, NodeAddOns,
@@ -439,17 +439,27 @@ where
         ext: F,
     ) -> eyre::Result<RpcHandle<N, EthApi>>
     where
-        F: FnOnce(&mut TransportRpcModules, &mut AuthRpcModule) -> eyre::Result<()>,
+        F: FnOnce(
+            &mut TransportRpcModules,
+            &mut AuthRpcModule,
+            &mut RpcRegistry<N, EthApi>,
+        ) -> eyre::Result<()>,
     {
         let Self { eth_api_builder, engine_api_builder, hooks, .. } = self;
 
         let engine_api = engine_api_builder.build_engine_api(&ctx).await?;
-        let AddOnsContext { node, config, beacon_engine_handle, jwt_secret, engine_events } = ctx;
+        let AddOnsContext { node, config, beacon_engine_handle, jwt_secret, engine_events, .. } =
+            ctx;
 
         info!(target: "reth::cli", "Engine API handler initialized");
 
         let auth_config = config.rpc.auth_server_config(jwt_secret)?;
-        let module_config = config.rpc.transport_rpc_module_config();
+        let mut module_config = config.rpc.transport_rpc_module_config();
+        if config.rpc.rpc_debug_trace_dir.is_none() {
+            if let Some(module_config) = module_config.config_mut() {
+                module_config.debug_mut().trace_dir = config.datadir().debug_traces();
+            }
+        }
         debug!(target: "reth::cli", http=?module_config.http(), ws=?module_config.ws(), "Using RPC module config");
 
         let (mut modules, mut auth_module, registry) = RpcModuleBuilder::default()
@@ -467,6 +477,17 @@ where
             registry.eth_api().with_dev_accounts();
         }
 
+        // keep the blocks rejected by the engine for the `debug` namespace
+        let bad_block_store = registry.bad_block_store().clone();
+        let mut engine_events_stream = engine_events.new_listener();
+        node.task_executor().spawn(async move {
+            while let Some(event) = engine_events_stream.next().await {
+                if let BeaconConsensusEngineEvent::InvalidBlock(block) = event {
+                    bad_block_store.insert(block.as_ref());
+                }
+            }
+        });
+
         let mut registry = RpcRegistry { registry };
         let ctx = RpcContext {
             node: node.clone(),
@@ -478,7 +499,7 @@ where
 
         let RpcHooks { on_rpc_started, extend_rpc_modules } = hooks;
 
-        ext(ctx.modules, ctx.auth_module)?;
+        ext(ctx.modules, ctx.auth_module, ctx.registry)?;
         extend_rpc_modules.extend_rpc_modules(ctx)?;
 
         let server_config = config.rpc.rpc_server_config();
@@ -544,7 +565,7 @@ where
     type Handle = RpcHandle<N, EthApi>;
 
     async fn launch_add_ons(self, ctx: AddOnsContext<'_, N>) -> eyre::Result<Self::Handle> {
-        self.launch_add_ons_with(ctx, |_, _| Ok(())).await
+        self.launch_add_ons_with(ctx, |_, _, _| Ok(())).await
     }
 }
 
@@ -16,8 +16,12 @@ use reth_network_p2p::{
     bodies::downloader::BodyDownloader, headers::downloader::HeaderDownloader, BlockClient,
 };
 use reth_node_api::HeaderTy;
-use reth_provider::{providers::ProviderNodeTypes, ProviderFactory};
-use reth_stages::{prelude::DefaultStages, stages::ExecutionStage, Pipeline, StageSet};
+use reth_provider::{providers::ProviderNodeTypes, ProviderFactory, StaticFileProviderFactory};
+use reth_stages::{
+    prelude::DefaultStages,
+    stages::{ExecutionStage, IndexLogsStage, S3Stage},
+    Pipeline, StageId, StageSet,
+};
 use reth_static_file::StaticFileProducer;
 use reth_tasks::TaskExecutor;
 use reth_tracing::tracing::debug;
@@ -100,29 +104,56 @@ where
     let (tip_tx, tip_rx) = watch::channel(B256::ZERO);
 
     let prune_modes = prune_config.map(|prune| prune.segments).unwrap_or_default();
+    let log_index_prune_mode = prune_modes.log_index;
+
+    let mut stages = DefaultStages::new(
+        provider_factory.clone(),
+        tip_rx,
+        Arc::clone(&consensus),
+        header_downloader,
+        body_downloader,
+        executor.clone(),
+        stage_config.clone(),
+        prune_modes,
+    )
+    .set(ExecutionStage::new(
+        executor,
+        consensus,
+        stage_config.execution.into(),
+        stage_config.execution_external_clean_threshold(),
+        exex_manager_handle,
+    ));
+
+    // Download the static files of the chain history before syncing from the network.
+    if let Some(url) = &stage_config.s3.url {
+        debug!(target: "reth::cli", url, "Configuring pipeline to download static files");
+        stages = stages.add_before(
+            S3Stage::new(
+                provider_factory.static_file_provider().directory().to_path_buf(),
+                url.clone(),
+                stage_config.s3.max_concurrent_requests,
+            ),
+            StageId::Headers,
+        );
+    }
+
+    // Index the logs of the chain history, if the log index is enabled.
+    if provider_factory.log_index() {
+        debug!(target: "reth::cli", "Configuring pipeline to index logs");
+        stages = stages.add_after(
+            IndexLogsStage::new(
+                stage_config.index_logs,
+                stage_config.etl.clone(),
+                log_index_prune_mode,
+            ),
+            StageId::IndexAccountHistory,
+        );
+    }
 
     let pipeline = builder
         .with_tip_sender(tip_tx)
         .with_metrics_tx(metrics_tx)
-        .add_stages(
-            DefaultStages::new(
-                provider_factory.clone(),
-                tip_rx,
-                Arc::clone(&consensus),
-                header_downloader,
-                body_downloader,
-                executor.clone(),
-                stage_config.clone(),
-                prune_modes,
-            )
-            .set(ExecutionStage::new(
-                executor,
-                consensus,
-                stage_config.execution.into(),
-                stage_config.execution_external_clean_threshold(),
-                exex_manager_handle,
-            )),
-        )
+        .add_stages(stages)
         .build(provider_factory, static_file_producer);
 
     Ok(pipeline)
@@ -154,6 +154,12 @@ pub struct NetworkArgs {
     /// If flag is set, but no value is passed, the default interface for docker `eth0` is tried.
     #[arg(long = "net-if.experimental", conflicts_with = "addr", value_name = "IF_NAME")]
     pub net_if: Option<String>,
+
+    /// Serve the `snap/1` protocol to peers.
+    ///
+    /// Peers can then snap sync from the state of the most recent 128 blocks.
+    #[arg(long = "snap", verbatim_doc_comment)]
+    pub serve_snap: bool,
 }
 
 impl NetworkArgs {
@@ -332,6 +338,7 @@ impl Default for NetworkArgs {
             max_seen_tx_history: DEFAULT_MAX_COUNT_TRANSACTIONS_SEEN_BY_PEER,
             max_capacity_cache_txns_pending_fetch: DEFAULT_MAX_CAPACITY_CACHE_PENDING_FETCH,
             net_if: None,
+            serve_snap: false,
         }
     }
 }
@@ -82,6 +82,32 @@ pub struct PruningArgs {
     #[arg(long = "prune.storagehistory.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["storage_history_full", "storage_history_distance"])]
     pub storage_history_before: Option<BlockNumber>,
 
+    // Address Transactions
+    /// Prunes the whole address transactions index.
+    #[arg(long = "prune.addresstransactions.full", conflicts_with_all = &["address_transactions_distance", "address_transactions_before"])]
+    pub address_transactions_full: bool,
+    /// Prune the address transactions index before the `head-N` block number. In other words, keep
+    /// last N + 1 blocks.
+    #[arg(long = "prune.addresstransactions.distance", value_name = "BLOCKS", conflicts_with_all = &["address_transactions_full", "address_transactions_before"])]
+    pub address_transactions_distance: Option<u64>,
+    /// Prune the address transactions index before the specified block number. The specified block
+    /// number is not pruned.
+    #[arg(long = "prune.addresstransactions.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["address_transactions_full", "address_transactions_distance"])]
+    pub address_transactions_before: Option<BlockNumber>,
+
+    // Log Index
+    /// Prunes the whole log index.
+    #[arg(long = "prune.logindex.full", conflicts_with_all = &["log_index_distance", "log_index_before"])]
+    pub log_index_full: bool,
+    /// Prune the log index before the `head-N` block number. In other words, keep last N + 1
+    /// blocks.
+    #[arg(long = "prune.logindex.distance", value_name = "BLOCKS", conflicts_with_all = &["log_index_full", "log_index_before"])]
+    pub log_index_distance: Option<u64>,
+    /// Prune the log index before the specified block number. The specified block number is not
+    /// pruned.
+    #[arg(long = "prune.logindex.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["log_index_full", "log_index_distance"])]
+    pub log_index_before: Option<BlockNumber>,
+
     // Receipts Log Filter
     /// Configure receipts log filter. Format:
     /// <`address`>:<`prune_mode`>[,<`address`>:<`prune_mode`>...] Where <`prune_mode`> can be
@@ -98,19 +124,22 @@ impl PruningArgs {
 
         // If --full is set, use full node defaults.
         if self.full {
+            // prune all receipts if chain doesn't have deposit contract specified in chain spec
+            let receipts = chain_spec
+                .deposit_contract()
+                .map(|contract| PruneMode::Before(contract.block))
+                .or(Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)));
             config = PruneConfig {
                 block_interval: config.block_interval,
                 segments: PruneModes {
                     sender_recovery: Some(PruneMode::Full),
                     transaction_lookup: None,
-                    // prune all receipts if chain doesn't have deposit contract specified in chain
-                    // spec
-                    receipts: chain_spec
-                        .deposit_contract()
-                        .map(|contract| PruneMode::Before(contract.block))
-                        .or(Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE))),
+                    receipts,
                     account_history: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
                     storage_history: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
+                    address_transactions: None,
+                    // the log index is built from receipts, so keep it for the same range
+                    log_index: receipts,
                     receipts_log_filter: ReceiptsLogPruneConfig(
                         chain_spec
                             .deposit_contract()
@@ -141,6 +170,12 @@ impl PruningArgs {
         if let Some(mode) = self.storage_history_prune_mode() {
             config.segments.storage_history = Some(mode);
         }
+        if let Some(mode) = self.address_transactions_prune_mode() {
+            config.segments.address_transactions = Some(mode);
+        }
+        if let Some(mode) = self.log_index_prune_mode() {
+            config.segments.log_index = Some(mode);
+        }
 
         Some(config)
     }
@@ -203,6 +238,30 @@ impl PruningArgs {
             None
         }
     }
+
+    const fn address_transactions_prune_mode(&self) -> Option<PruneMode> {
+        if self.address_transactions_full {
+            Some(PruneMode::Full)
+        } else if let Some(distance) = self.address_transactions_distance {
+            Some(PruneMode::Distance(distance))
+        } else if let Some(block_number) = self.address_transactions_before {
+            Some(PruneMode::Before(block_number))
+        } else {
+            None
+        }
+    }
+
+    const fn log_index_prune_mode(&self) -> Option<PruneMode> {
+        if self.log_index_full {
+            Some(PruneMode::Full)
+        } else if let Some(distance) = self.log_index_distance {
+            Some(PruneMode::Distance(distance))
+        } else if let Some(block_number) = self.log_index_before {
+            Some(PruneMode::Before(block_number))
+        } else {
+            None
+        }
+    }
 }
 
 pub(crate) fn parse_receipts_log_filter(
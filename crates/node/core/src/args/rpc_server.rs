@@ -190,6 +190,26 @@ pub struct RpcServerArgs {
     #[arg(long = "builder.disallow", value_name = "PATH", value_parser = reth_cli_util::parsers::read_json_from_file::<HashSet<Address>>)]
     pub builder_disallow: Option<HashSet<Address>>,
 
+    /// Maintain an index of the transactions that each address appears in.
+    ///
+    /// The index is required by `ots_searchTransactionsBefore` and `ots_searchTransactionsAfter`.
+    /// It can be pruned with the `--prune.addresstransactions.*` arguments.
+    #[arg(long = "rpc.address-index", default_value_t = false)]
+    pub rpc_address_index: bool,
+
+    /// Maintain an index of the blocks that contain logs of each address and topic.
+    ///
+    /// `eth_getLogs` and log filters use the index instead of checking the logs bloom of every
+    /// block in the queried range. It can be pruned with the `--prune.logindex.*` arguments.
+    #[arg(long = "rpc.log-index", default_value_t = false)]
+    pub rpc_log_index: bool,
+
+    /// The directory the traces of `debug_standardTraceBlockToFile` are written to.
+    ///
+    /// Defaults to the `debug-traces` directory of the datadir.
+    #[arg(long = "rpc.debug-trace-dir", value_name = "PATH")]
+    pub rpc_debug_trace_dir: Option<PathBuf>,
+
     /// State cache configuration.
     #[command(flatten)]
     pub rpc_state_cache: RpcStateCacheArgs,
@@ -332,6 +352,9 @@ impl Default for RpcServerArgs {
             rpc_state_cache: RpcStateCacheArgs::default(),
             rpc_proof_permits: constants::DEFAULT_PROOF_PERMITS,
             builder_disallow: Default::default(),
+            rpc_address_index: false,
+            rpc_log_index: false,
+            rpc_debug_trace_dir: None,
         }
     }
 }
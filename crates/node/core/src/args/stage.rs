@@ -50,4 +50,8 @@ pub enum StageEnum {
     ///
     /// Manages historical data related to storage.
     StorageHistory,
+    /// The log index stage within the pipeline.
+    ///
+    /// Indexes the logs emitted in each block by their address and topics.
+    LogIndex,
 }
@@ -7,6 +7,7 @@ use clap::Args;
 use reth_cli_util::parse_duration_from_secs_or_ms;
 use reth_transaction_pool::{
     blobstore::disk::DEFAULT_MAX_CACHED_BLOBS,
+    journal::{JournalScope, TransactionJournalConfig, DEFAULT_REJOURNAL_INTERVAL},
     maintain::MAX_QUEUED_TRANSACTION_LIFETIME,
     pool::{NEW_TX_LISTENER_BUFFER_SIZE, PENDING_TX_LISTENER_BUFFER_SIZE},
     validate::DEFAULT_MAX_TX_INPUT_BYTES,
@@ -15,7 +16,7 @@ use reth_transaction_pool::{
     REPLACE_BLOB_PRICE_BUMP, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
     TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT, TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
 };
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 /// Parameters for debugging purposes
 #[derive(Debug, Clone, Args, PartialEq, Eq)]
@@ -106,6 +107,36 @@ pub struct TxPoolArgs {
     /// Maximum amount of time non-executable transaction are queued.
     #[arg(long = "txpool.lifetime", value_parser = parse_duration_from_secs_or_ms, default_value = "10800", value_name = "DURATION")]
     pub max_queued_lifetime: Duration,
+
+    /// Disables the continuous journal of local transactions.
+    ///
+    /// If disabled, local transactions are only persisted on graceful shutdown.
+    #[arg(long = "txpool.disable-journal")]
+    pub disable_journal: bool,
+
+    /// Interval at which the transaction journal is compacted.
+    #[arg(long = "txpool.rejournal", value_parser = parse_duration_from_secs_or_ms, default_value = "3600", value_name = "DURATION")]
+    pub rejournal_interval: Duration,
+
+    /// Journal all pending transactions, not only local ones.
+    #[arg(long = "txpool.journal-all")]
+    pub journal_all: bool,
+}
+
+impl TxPoolArgs {
+    /// Returns the transaction journal configuration for the given journal path, or `None` if the
+    /// journal is disabled.
+    pub fn journal_config(&self, path: PathBuf) -> Option<TransactionJournalConfig> {
+        if self.disable_journal {
+            return None
+        }
+        let scope = if self.journal_all { JournalScope::AllPending } else { JournalScope::Local };
+        Some(
+            TransactionJournalConfig::new(path)
+                .with_rejournal_interval(self.rejournal_interval)
+                .with_scope(scope),
+        )
+    }
 }
 
 impl Default for TxPoolArgs {
@@ -134,6 +165,9 @@ impl Default for TxPoolArgs {
             new_tx_listener_buffer_size: NEW_TX_LISTENER_BUFFER_SIZE,
             max_new_pending_txs_notifications: MAX_NEW_PENDING_TXS_NOTIFICATIONS,
             max_queued_lifetime: MAX_QUEUED_TRANSACTION_LIFETIME,
+            disable_journal: false,
+            rejournal_interval: DEFAULT_REJOURNAL_INTERVAL,
+            journal_all: false,
         }
     }
 }
@@ -174,6 +208,7 @@ impl RethTransactionPoolConfig for TxPoolArgs {
             new_tx_listener_buffer_size: self.new_tx_listener_buffer_size,
             max_new_pending_txs_notifications: self.max_new_pending_txs_notifications,
             max_queued_lifetime: self.max_queued_lifetime,
+            admission: Default::default(),
         }
     }
 }
@@ -227,4 +262,29 @@ mod tests {
 
         assert!(result.is_err(), "Expected an error for invalid duration");
     }
+
+    #[test]
+    fn txpool_parse_journal() {
+        let path = PathBuf::from("journal.rlp");
+
+        let args = CommandParser::<TxPoolArgs>::parse_from(["reth"]).args;
+        let config = args.journal_config(path.clone()).unwrap();
+        assert_eq!(config.rejournal_interval, Duration::from_secs(60 * 60));
+        assert_eq!(config.scope, JournalScope::Local);
+
+        let args = CommandParser::<TxPoolArgs>::parse_from([
+            "reth",
+            "--txpool.rejournal",
+            "600",
+            "--txpool.journal-all",
+        ])
+        .args;
+        let config = args.journal_config(path.clone()).unwrap();
+        assert_eq!(config.rejournal_interval, Duration::from_secs(600));
+        assert_eq!(config.scope, JournalScope::AllPending);
+
+        let args =
+            CommandParser::<TxPoolArgs>::parse_from(["reth", "--txpool.disable-journal"]).args;
+        assert!(args.journal_config(path).is_none());
+    }
 }
@@ -330,6 +330,13 @@ impl<D> ChainPath<D> {
         self.data_dir().join("txpool-transactions-backup.rlp")
     }
 
+    /// Returns the path to the transaction pool journal file
+    ///
+    /// `<DIR>/<CHAIN_ID>/txpool-transactions-journal.rlp`
+    pub fn txpool_journal(&self) -> PathBuf {
+        self.data_dir().join("txpool-transactions-journal.rlp")
+    }
+
     /// Returns the path to the config file for this chain.
     ///
     /// `<DIR>/<CHAIN_ID>/reth.toml`
@@ -351,6 +358,13 @@ impl<D> ChainPath<D> {
         self.data_dir().join("invalid_block_hooks")
     }
 
+    /// Returns the path to the directory of the traces written by the `debug` RPC namespace.
+    ///
+    /// `<DIR>/<CHAIN_ID>/debug-traces`
+    pub fn debug_traces(&self) -> PathBuf {
+        self.data_dir().join("debug-traces")
+    }
+
     /// Returns the path to the ExEx WAL directory for this chain.
     pub fn exex_wal(&self) -> PathBuf {
         self.data_dir().join("exex/wal")
@@ -103,6 +103,10 @@ pub struct NodeConfig<ChainSpec> {
     /// The metrics will be served at the given interface and port.
     pub metrics: Option<SocketAddr>,
 
+    /// The URL of a server to download the static files of the chain history from, before
+    /// syncing the rest of the chain from the network.
+    pub static_files_url: Option<String>,
+
     /// Add a new instance of a node.
     ///
     /// Configures the ports of the node to avoid conflicts with the defaults.
@@ -163,6 +167,7 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             config: None,
             chain,
             metrics: None,
+            static_files_url: None,
             instance: 1,
             network: NetworkArgs::default(),
             rpc: RpcServerArgs::default(),
@@ -220,6 +225,12 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
         self
     }
 
+    /// Set the URL to download the static files from
+    pub fn with_static_files_url(mut self, url: impl Into<String>) -> Self {
+        self.static_files_url = Some(url.into());
+        self
+    }
+
     /// Set the instance for the node
     pub const fn with_instance(mut self, instance: u16) -> Self {
         self.instance = instance;
@@ -455,6 +466,7 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             datadir: self.datadir,
             config: self.config,
             metrics: self.metrics,
+            static_files_url: self.static_files_url,
             instance: self.instance,
             network: self.network,
             rpc: self.rpc,
@@ -481,6 +493,7 @@ impl<ChainSpec> Clone for NodeConfig<ChainSpec> {
             chain: self.chain.clone(),
             config: self.config.clone(),
             metrics: self.metrics,
+            static_files_url: self.static_files_url.clone(),
             instance: self.instance,
             network: self.network.clone(),
             rpc: self.rpc.clone(),
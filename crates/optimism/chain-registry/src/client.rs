@@ -1,28 +1,37 @@
-//! Directory Manager downloads and manages files from the op-superchain-registry
+//! Directory Manager reads files from a snapshot of the op-superchain-registry, and downloads them
+//! into it
 
+use crate::{ChainConfig, RegistryGenesis};
+use alloy_genesis::Genesis;
+use alloy_primitives::{Bytes, B256};
 use eyre::Context;
 use reth_fs_util as fs;
 use reth_fs_util::Result;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
-use std::path::{Path, PathBuf};
+use std::{
+    io::{BufReader, Read, Write},
+    path::{Path, PathBuf},
+};
 use tracing::{debug, trace};
 use zstd::{dict::DecoderDictionary, stream::read::Decoder};
 
-/// Directory manager that handles caching and downloading of genesis files
+/// Directory manager for a snapshot of the superchain registry at a single revision.
+///
+/// Reading files never downloads them, chains are added to the snapshot with
+/// [`Self::fetch_chain`].
 #[derive(Debug)]
 pub struct SuperChainRegistryManager {
     base_path: PathBuf,
 }
 
 impl SuperChainRegistryManager {
-    const DICT_URL: &'static str = "https://raw.githubusercontent.com/ethereum-optimism/superchain-registry/main/superchain/extra/dictionary";
-    const GENESIS_BASE_URL: &'static str = "https://raw.githubusercontent.com/ethereum-optimism/superchain-registry/main/superchain/extra/genesis";
+    const BASE_URL: &'static str =
+        "https://raw.githubusercontent.com/ethereum-optimism/superchain-registry";
 
     /// Create a new registry manager with the given base path
-    pub fn new(base_path: impl Into<PathBuf>) -> Result<Self> {
-        let base_path = base_path.into();
-        fs::create_dir_all(&base_path)?;
-        Ok(Self { base_path })
+    pub fn new(base_path: impl Into<PathBuf>) -> Self {
+        Self { base_path: base_path.into() }
     }
 
     /// Get the path to the dictionary file
@@ -35,9 +44,22 @@ impl SuperChainRegistryManager {
         self.base_path.join(network_type).join(format!("{}.json.zst", network))
     }
 
+    /// Get the path to the contract code with the given code hash.
+    pub fn bytecode_path(&self, code_hash: B256) -> PathBuf {
+        self.base_path.join("bytecodes").join(format!("{code_hash:#x}.bin.zst"))
+    }
+
+    /// Get the path to the chain config for the given network (`mainnet`, `zora`).
+    pub fn config_path(&self, network_type: &str, network: &str) -> PathBuf {
+        self.base_path.join("configs").join(network_type).join(format!("{}.toml", network))
+    }
+
     /// Read file from the given path
-    fn read_file(&self, path: &Path) -> Result<Vec<u8>> {
-        fs::read(path)
+    fn read_file(&self, path: &Path) -> eyre::Result<Vec<u8>> {
+        if !path.exists() {
+            eyre::bail!("{} is missing from the superchain registry snapshot", path.display());
+        }
+        Ok(fs::read(path)?)
     }
 
     /// Save data to the given path
@@ -45,55 +67,159 @@ impl SuperChainRegistryManager {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
-        fs::write(path, data)
+        // an interrupted download must not leave a partial file in the snapshot
+        fs::atomic_write_file(path, |file| file.write_all(data))
     }
 
-    /// Download a file from the given URL
-    fn download_file(&self, url: &str, path: &Path) -> eyre::Result<Vec<u8>> {
+    /// Download a file of the registry at the given revision, unless it's already in the snapshot
+    async fn download_file(&self, revision: &str, file: &str, path: &Path) -> eyre::Result<()> {
         if path.exists() {
-            debug!(target: "reth::cli", path = ?path.display() ,"Reading from cache");
-            return Ok(self.read_file(path)?);
+            debug!(target: "reth::cli", path = ?path.display(), "Already in snapshot");
+            return Ok(())
         }
 
+        let url = format!("{}/{revision}/superchain/{file}", Self::BASE_URL);
         trace!(target: "reth::cli", url = ?url ,"Downloading from URL");
-        let response = reqwest::blocking::get(url).context("Failed to download file")?;
+        let response = reqwest::get(&url).await.context("Failed to download file")?;
 
         if !response.status().is_success() {
-            eyre::bail!("Failed to download: Status {}", response.status());
+            eyre::bail!("Failed to download {url}: Status {}", response.status());
         }
 
-        let bytes = response.bytes()?.to_vec();
+        let bytes = response.bytes().await?;
         self.save_file(path, &bytes)?;
 
-        Ok(bytes)
+        Ok(())
     }
 
-    /// Download and update the dictionary
-    fn update_dictionary(&self) -> eyre::Result<Vec<u8>> {
-        let path = self.dictionary_path();
-        self.download_file(Self::DICT_URL, &path)
+    /// Download all files of a network (`mainnet`, `zora`) at the given revision of the registry
+    /// into the snapshot, unless they're already in it.
+    ///
+    /// Files of a revision never change, so a snapshot must only be filled from one revision.
+    pub async fn fetch_chain(
+        &self,
+        revision: &str,
+        network_type: &str,
+        network: &str,
+    ) -> eyre::Result<()> {
+        self.download_file(revision, "extra/dictionary", &self.dictionary_path()).await?;
+        self.download_file(
+            revision,
+            &format!("configs/{network_type}/{network}.toml"),
+            &self.config_path(network_type, network),
+        )
+        .await?;
+        self.download_file(
+            revision,
+            &format!("extra/genesis/{network_type}/{network}.json.zst"),
+            &self.genesis_path(network_type, network),
+        )
+        .await?;
+
+        let dictionary = self.get_dictionary()?;
+        let genesis: RegistryGenesis = self.get_genesis_as(&dictionary, network_type, network)?;
+        for code_hash in genesis.code_hashes() {
+            self.download_file(
+                revision,
+                &format!("extra/bytecodes/{code_hash:#x}.bin.zst"),
+                &self.bytecode_path(code_hash),
+            )
+            .await?;
+        }
+
+        Ok(())
     }
 
-    /// Get genesis data for a network, downloading it if necessary
-    pub fn get_genesis(&self, network_type: &str, network: &str) -> eyre::Result<Value> {
-        let dict_bytes = self.update_dictionary()?;
+    /// Read the given file and decompress it with the registry dictionary
+    fn get_compressed<T>(
+        &self,
+        dictionary: &DecoderDictionary<'_>,
+        path: &Path,
+        decode: impl FnOnce(Decoder<'_, BufReader<&[u8]>>) -> eyre::Result<T>,
+    ) -> eyre::Result<T> {
+        let compressed_bytes = self.read_file(path)?;
+        trace!(target: "reth::cli", bytes = ?compressed_bytes.len(), path = ?path.display(), "Got compressed file");
+
+        let decoder = Decoder::with_prepared_dictionary(&compressed_bytes[..], dictionary)
+            .context("Failed to create decoder with dictionary")?;
+
+        decode(decoder)
+    }
+
+    /// Load the registry dictionary
+    fn get_dictionary(&self) -> eyre::Result<DecoderDictionary<'static>> {
+        let dict_bytes = self.read_file(&self.dictionary_path())?;
         trace!(target: "reth::cli", bytes = ?dict_bytes.len(),"Got dictionary");
 
-        let dictionary = DecoderDictionary::copy(&dict_bytes);
+        Ok(DecoderDictionary::copy(&dict_bytes))
+    }
 
-        let url = format!("{}/{}/{}.json.zst", Self::GENESIS_BASE_URL, network_type, network);
+    /// Get genesis file of a network in the given format
+    fn get_genesis_as<T: DeserializeOwned>(
+        &self,
+        dictionary: &DecoderDictionary<'_>,
+        network_type: &str,
+        network: &str,
+    ) -> eyre::Result<T> {
         let path = self.genesis_path(network_type, network);
 
-        let compressed_bytes = self.download_file(&url, &path)?;
-        trace!(target: "reth::cli", bytes = ?compressed_bytes.len(),"Got genesis file");
+        self.get_compressed(dictionary, &path, |decoder| {
+            serde_json::from_reader(decoder)
+                .with_context(|| format!("Failed to parse JSON: {path:?}"))
+        })
+    }
 
-        let decoder = Decoder::with_prepared_dictionary(&compressed_bytes[..], &dictionary)
-            .context("Failed to create decoder with dictionary")?;
+    /// Get genesis data for a network
+    pub fn get_genesis(&self, network_type: &str, network: &str) -> eyre::Result<Value> {
+        let dictionary = self.get_dictionary()?;
+        self.get_genesis_as(&dictionary, network_type, network)
+    }
 
-        let json: Value = serde_json::from_reader(decoder)
-            .with_context(|| format!("Failed to parse JSON: {path:?}"))?;
+    /// Get the contract code with the given code hash
+    fn get_bytecode(
+        &self,
+        dictionary: &DecoderDictionary<'_>,
+        code_hash: B256,
+    ) -> eyre::Result<Bytes> {
+        let path = self.bytecode_path(code_hash);
+
+        self.get_compressed(dictionary, &path, |mut decoder| {
+            let mut code = Vec::new();
+            decoder
+                .read_to_end(&mut code)
+                .with_context(|| format!("Failed to decompress bytecode: {path:?}"))?;
+            Ok(code.into())
+        })
+    }
+
+    /// Get the chain config of a network
+    pub fn get_chain_config(&self, network_type: &str, network: &str) -> eyre::Result<ChainConfig> {
+        let path = self.config_path(network_type, network);
 
-        Ok(json)
+        let bytes = self.read_file(&path)?;
+        let raw = std::str::from_utf8(&bytes)
+            .with_context(|| format!("Failed to read chain config: {path:?}"))?;
+
+        toml::from_str(raw).with_context(|| format!("Failed to parse TOML: {path:?}"))
+    }
+
+    /// Get the chain config and the full [`Genesis`] of a network.
+    ///
+    /// The hardforks and EIP-1559 parameters of the chain config are part of the returned genesis
+    /// config.
+    pub fn get_chain(
+        &self,
+        network_type: &str,
+        network: &str,
+    ) -> eyre::Result<(ChainConfig, Genesis)> {
+        let config = self.get_chain_config(network_type, network)?;
+
+        let dictionary = self.get_dictionary()?;
+        let genesis: RegistryGenesis = self.get_genesis_as(&dictionary, network_type, network)?;
+        let genesis =
+            genesis.into_genesis(&config, |code_hash| self.get_bytecode(&dictionary, code_hash))?;
+
+        Ok((config, genesis))
     }
 }
 
@@ -102,22 +228,23 @@ mod tests {
     use super::*;
     use eyre::Result;
 
-    #[test]
-    fn test_directory_manager() -> Result<()> {
+    #[tokio::test]
+    async fn test_directory_manager() -> Result<()> {
         let dir = tempfile::tempdir()?;
         // Create a temporary directory for testing
-        let manager = SuperChainRegistryManager::new(dir.path())?;
+        let manager = SuperChainRegistryManager::new(dir.path());
 
         assert!(!manager.genesis_path("mainnet", "base").exists());
-        // Test downloading genesis data
-        let json_data = manager.get_genesis("mainnet", "base")?;
-        assert!(json_data.is_object(), "Parsed JSON should be an object");
+        // Reading never downloads files
+        assert!(manager.get_genesis("mainnet", "base").is_err());
 
+        // Test downloading genesis data
+        manager.fetch_chain("main", "mainnet", "base").await?;
         assert!(manager.genesis_path("mainnet", "base").exists());
 
-        // Test using cached data
-        let cached_json_data = manager.get_genesis("mainnet", "base")?;
-        assert!(cached_json_data.is_object(), "Cached JSON should be an object");
+        // Test using the snapshot
+        let json_data = manager.get_genesis("mainnet", "base")?;
+        assert!(json_data.is_object(), "Parsed JSON should be an object");
 
         Ok(())
     }
@@ -0,0 +1,134 @@
+//! Chain configs of the op-superchain-registry
+
+use alloy_primitives::B256;
+use serde::Deserialize;
+
+/// Chain config of a chain in the superchain registry, e.g. `superchain/configs/mainnet/zora.toml`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ChainConfig {
+    /// Name of the chain.
+    pub name: String,
+    /// Chain ID of the chain.
+    pub chain_id: u64,
+    /// Activation timestamps of the OP Stack hardforks.
+    #[serde(default)]
+    pub hardforks: HardforkConfig,
+    /// EIP-1559 parameters of the chain.
+    #[serde(default)]
+    pub optimism: Option<OptimismConfig>,
+    /// Genesis of the chain.
+    #[serde(default)]
+    pub genesis: Option<GenesisConfig>,
+}
+
+impl ChainConfig {
+    /// Returns the expected hash of the L2 genesis block, if listed.
+    pub fn genesis_hash(&self) -> Option<B256> {
+        self.genesis.as_ref().map(|genesis| genesis.l2.hash)
+    }
+}
+
+/// Activation timestamps of the OP Stack hardforks.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct HardforkConfig {
+    /// Canyon activation timestamp.
+    pub canyon_time: Option<u64>,
+    /// Delta activation timestamp.
+    pub delta_time: Option<u64>,
+    /// Ecotone activation timestamp.
+    pub ecotone_time: Option<u64>,
+    /// Fjord activation timestamp.
+    pub fjord_time: Option<u64>,
+    /// Granite activation timestamp.
+    pub granite_time: Option<u64>,
+    /// Holocene activation timestamp.
+    pub holocene_time: Option<u64>,
+    /// Isthmus activation timestamp.
+    pub isthmus_time: Option<u64>,
+}
+
+/// EIP-1559 parameters of an OP Stack chain.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct OptimismConfig {
+    /// EIP-1559 elasticity multiplier.
+    pub eip1559_elasticity: u64,
+    /// EIP-1559 base fee max change denominator.
+    pub eip1559_denominator: u64,
+    /// EIP-1559 base fee max change denominator since Canyon.
+    pub eip1559_denominator_canyon: Option<u64>,
+}
+
+/// Genesis section of a registry chain config.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GenesisConfig {
+    /// Timestamp of the L2 genesis block.
+    pub l2_time: u64,
+    /// The L2 genesis block.
+    pub l2: BlockId,
+}
+
+/// Hash and number of a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct BlockId {
+    /// Hash of the block.
+    pub hash: B256,
+    /// Number of the block.
+    pub number: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn parse_chain_config() {
+        let raw = r#"
+name = "Zora"
+public_rpc = "https://rpc.zora.energy"
+superchain_level = 1
+chain_id = 7777777
+block_time = 2
+
+[hardforks]
+  canyon_time = 1704992401 # Thu 11 Jan 2024 17:00:01 UTC
+  delta_time = 1708560000 # Thu 22 Feb 2024 00:00:00 UTC
+  ecotone_time = 1710374401 # Thu 14 Mar 2024 00:00:01 UTC
+  fjord_time = 1720627201 # Wed 10 Jul 2024 16:00:01 UTC
+  granite_time = 1726070401 # Wed 11 Sep 2024 16:00:01 UTC
+  holocene_time = 1736445601 # Thu 9 Jan 2025 18:00:01 UTC
+
+[optimism]
+  eip1559_elasticity = 6
+  eip1559_denominator = 50
+  eip1559_denominator_canyon = 250
+
+[genesis]
+  l2_time = 1686693839
+  [genesis.l1]
+    hash = "0xbdbd2847f7aa5f7cd1bd4c9f904057f4ba0b498c7e380199c01d240e3a41a84f"
+    number = 17473923
+  [genesis.l2]
+    hash = "0x47555a45a1af8d4728ca337a1e48375a83919b1ea16591e070a07388b7364e29"
+    number = 0
+"#;
+        let config: ChainConfig = toml::from_str(raw).unwrap();
+        assert_eq!(config.name, "Zora");
+        assert_eq!(config.chain_id, 7777777);
+        assert_eq!(config.hardforks.canyon_time, Some(1704992401));
+        assert_eq!(config.hardforks.holocene_time, Some(1736445601));
+        assert_eq!(config.hardforks.isthmus_time, None);
+        assert_eq!(
+            config.optimism,
+            Some(OptimismConfig {
+                eip1559_elasticity: 6,
+                eip1559_denominator: 50,
+                eip1559_denominator_canyon: Some(250),
+            })
+        );
+        assert_eq!(
+            config.genesis_hash(),
+            Some(b256!("47555a45a1af8d4728ca337a1e48375a83919b1ea16591e070a07388b7364e29"))
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Genesis files of the op-superchain-registry
+
+use crate::ChainConfig;
+use alloy_genesis::{Genesis, GenesisAccount};
+use alloy_primitives::{Address, Bytes, B256, KECCAK256_EMPTY, U256};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Genesis as stored in the superchain registry, e.g.
+/// `superchain/extra/genesis/mainnet/zora.json.zst`.
+///
+/// Contract code isn't part of the genesis, accounts only reference it by code hash.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistryGenesis {
+    /// Nonce of the genesis block.
+    pub nonce: u64,
+    /// Timestamp of the genesis block.
+    pub timestamp: u64,
+    /// Extra data of the genesis block.
+    #[serde(deserialize_with = "deserialize_extra_data")]
+    pub extra_data: Bytes,
+    /// Gas limit of the genesis block.
+    pub gas_limit: u64,
+    /// Difficulty of the genesis block.
+    pub difficulty: U256,
+    /// Mix hash of the genesis block.
+    pub mix_hash: B256,
+    /// Coinbase of the genesis block.
+    pub coinbase: Address,
+    /// Number of the genesis block.
+    #[serde(default)]
+    pub number: u64,
+    /// Base fee of the genesis block.
+    #[serde(default)]
+    pub base_fee_per_gas: Option<U256>,
+    /// Excess blob gas of the genesis block.
+    #[serde(default)]
+    pub excess_blob_gas: Option<u64>,
+    /// Blob gas used of the genesis block.
+    #[serde(default)]
+    pub blob_gas_used: Option<u64>,
+    /// Genesis allocations.
+    #[serde(default)]
+    pub alloc: BTreeMap<Address, RegistryGenesisAccount>,
+    /// State root of the genesis block, set if the genesis state isn't part of the registry.
+    #[serde(default)]
+    pub state_hash: Option<B256>,
+}
+
+/// Account of a [`RegistryGenesis`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistryGenesisAccount {
+    /// Hash of the account's code.
+    #[serde(default)]
+    pub code_hash: Option<B256>,
+    /// Storage of the account.
+    #[serde(default)]
+    pub storage: Option<BTreeMap<B256, B256>>,
+    /// Balance of the account.
+    #[serde(default)]
+    pub balance: Option<U256>,
+    /// Nonce of the account.
+    #[serde(default)]
+    pub nonce: Option<u64>,
+}
+
+impl RegistryGenesisAccount {
+    /// Returns the hash of the account's code, if it has code.
+    fn code_hash(&self) -> Option<B256> {
+        self.code_hash.filter(|hash| !hash.is_zero() && *hash != KECCAK256_EMPTY)
+    }
+}
+
+impl RegistryGenesis {
+    /// Returns the hashes of the contract code referenced by the genesis accounts.
+    pub fn code_hashes(&self) -> BTreeSet<B256> {
+        self.alloc.values().filter_map(RegistryGenesisAccount::code_hash).collect()
+    }
+
+    /// Converts the registry genesis into a [`Genesis`] for the chain with the given config.
+    ///
+    /// The hardfork activations and EIP-1559 parameters of the chain config are added to the
+    /// genesis config in the format expected by op-geth. Contract code is resolved by code hash
+    /// with the given closure.
+    pub fn into_genesis(
+        self,
+        config: &ChainConfig,
+        mut code_by_hash: impl FnMut(B256) -> eyre::Result<Bytes>,
+    ) -> eyre::Result<Genesis> {
+        if self.state_hash.is_some() && self.alloc.is_empty() {
+            eyre::bail!("genesis state of {} is not part of the superchain registry", config.name)
+        }
+
+        let mut alloc = BTreeMap::new();
+        for (address, account) in self.alloc {
+            let code = account.code_hash().map(&mut code_by_hash).transpose()?;
+            alloc.insert(
+                address,
+                GenesisAccount {
+                    nonce: account.nonce,
+                    balance: account.balance.unwrap_or_default(),
+                    code,
+                    storage: account.storage,
+                    ..Default::default()
+                },
+            );
+        }
+
+        let hardforks = &config.hardforks;
+        let chain_config = serde_json::json!({
+            "chainId": config.chain_id,
+            "homesteadBlock": 0,
+            "eip150Block": 0,
+            "eip155Block": 0,
+            "eip158Block": 0,
+            "byzantiumBlock": 0,
+            "constantinopleBlock": 0,
+            "petersburgBlock": 0,
+            "istanbulBlock": 0,
+            "muirGlacierBlock": 0,
+            "berlinBlock": 0,
+            "londonBlock": 0,
+            "arrowGlacierBlock": 0,
+            "grayGlacierBlock": 0,
+            "mergeNetsplitBlock": 0,
+            "shanghaiTime": hardforks.canyon_time,
+            "cancunTime": hardforks.ecotone_time,
+            "pragueTime": hardforks.isthmus_time,
+            "terminalTotalDifficulty": 0,
+            "terminalTotalDifficultyPassed": true,
+            "bedrockBlock": self.number,
+            "regolithTime": 0,
+            "canyonTime": hardforks.canyon_time,
+            "ecotoneTime": hardforks.ecotone_time,
+            "fjordTime": hardforks.fjord_time,
+            "graniteTime": hardforks.granite_time,
+            "holoceneTime": hardforks.holocene_time,
+            "isthmusTime": hardforks.isthmus_time,
+            "optimism": config.optimism.as_ref().map(|optimism| serde_json::json!({
+                "eip1559Elasticity": optimism.eip1559_elasticity,
+                "eip1559Denominator": optimism.eip1559_denominator,
+                "eip1559DenominatorCanyon": optimism.eip1559_denominator_canyon,
+            })),
+        });
+
+        Ok(Genesis {
+            config: serde_json::from_value(chain_config)?,
+            nonce: self.nonce,
+            timestamp: self.timestamp,
+            extra_data: self.extra_data,
+            gas_limit: self.gas_limit,
+            difficulty: self.difficulty,
+            mix_hash: self.mix_hash,
+            coinbase: self.coinbase,
+            alloc,
+            base_fee_per_gas: self.base_fee_per_gas.map(|fee| fee.to()),
+            excess_blob_gas: self.excess_blob_gas,
+            blob_gas_used: self.blob_gas_used,
+            number: Some(self.number),
+        })
+    }
+}
+
+/// Deserializes the extra data, which the registry encodes as base64, also accepting hex.
+fn deserialize_extra_data<'de, D>(deserializer: D) -> Result<Bytes, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(raw) = Option::<String>::deserialize(deserializer)? else { return Ok(Bytes::new()) };
+    if raw.starts_with("0x") {
+        return raw.parse().map_err(serde::de::Error::custom)
+    }
+    STANDARD.decode(raw).map(Into::into).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HardforkConfig, OptimismConfig};
+    use alloy_primitives::{address, b256, bytes};
+
+    fn chain_config() -> ChainConfig {
+        ChainConfig {
+            name: "Test".to_string(),
+            chain_id: 1234,
+            hardforks: HardforkConfig {
+                canyon_time: Some(0),
+                ecotone_time: Some(10),
+                fjord_time: Some(20),
+                ..Default::default()
+            },
+            optimism: Some(OptimismConfig {
+                eip1559_elasticity: 6,
+                eip1559_denominator: 50,
+                eip1559_denominator_canyon: Some(250),
+            }),
+            genesis: None,
+        }
+    }
+
+    #[test]
+    fn registry_genesis_into_genesis() {
+        let raw = r#"{
+            "nonce": 0,
+            "timestamp": 1686693839,
+            "extraData": "QkVEUk9DSw==",
+            "gasLimit": 30000000,
+            "difficulty": "0x0",
+            "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "coinbase": "0x4200000000000000000000000000000000000011",
+            "number": 0,
+            "gasUsed": 0,
+            "parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "baseFeePerGas": "0x3b9aca00",
+            "alloc": {
+                "0x4200000000000000000000000000000000000015": {
+                    "codeHash": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                    "storage": {
+                        "0x0000000000000000000000000000000000000000000000000000000000000000": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                    }
+                },
+                "0x0000000000000000000000000000000000000001": {
+                    "balance": "0x1"
+                }
+            }
+        }"#;
+        let registry_genesis: RegistryGenesis = serde_json::from_str(raw).unwrap();
+        assert_eq!(registry_genesis.extra_data, bytes!("424544524f434b"));
+
+        let genesis = registry_genesis
+            .into_genesis(&chain_config(), |hash| {
+                assert_eq!(
+                    hash,
+                    b256!("1111111111111111111111111111111111111111111111111111111111111111")
+                );
+                Ok(bytes!("6080"))
+            })
+            .unwrap();
+
+        assert_eq!(genesis.config.chain_id, 1234);
+        assert_eq!(genesis.config.shanghai_time, Some(0));
+        assert_eq!(genesis.config.cancun_time, Some(10));
+        assert_eq!(genesis.config.prague_time, None);
+        assert_eq!(genesis.config.extra_fields.get("fjordTime"), Some(&serde_json::json!(20)));
+        assert_eq!(genesis.base_fee_per_gas, Some(1_000_000_000));
+
+        let predeploy = &genesis.alloc[&address!("4200000000000000000000000000000000000015")];
+        assert_eq!(predeploy.code, Some(bytes!("6080")));
+        assert_eq!(predeploy.storage.as_ref().map(|storage| storage.len()), Some(1));
+        let account = &genesis.alloc[&address!("0000000000000000000000000000000000000001")];
+        assert_eq!(account.balance, U256::from(1));
+        assert_eq!(account.code, None);
+    }
+
+    #[test]
+    fn registry_genesis_without_state() {
+        let raw = r#"{
+            "nonce": 0,
+            "timestamp": 1686068903,
+            "extraData": "0x",
+            "gasLimit": 30000000,
+            "difficulty": "0x1",
+            "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "coinbase": "0x0000000000000000000000000000000000000000",
+            "number": 105235063,
+            "alloc": {},
+            "stateHash": "0xeddb4c1786789419153a27c4c80ff44a2226b6eda04f7e22ce5bae892ea568eb"
+        }"#;
+        let registry_genesis: RegistryGenesis = serde_json::from_str(raw).unwrap();
+        assert!(registry_genesis.into_genesis(&chain_config(), |_| unreachable!()).is_err());
+    }
+}
@@ -11,5 +11,46 @@
 //! Downloads and maintains config for different chains which
 //! are part of the op superchain
 mod client;
+mod config;
+mod genesis;
 
 pub use client::*;
+pub use config::*;
+pub use genesis::*;
+
+/// Resolves a chain name to the network type and network name used by the superchain registry.
+///
+/// Chains on sepolia are addressed with a `-sepolia` or `_sepolia` suffix, e.g. `zora-sepolia`
+/// resolves to (`sepolia`, `zora`), all other names resolve to `mainnet` chains. Returns `None`
+/// if the name can't be a registry chain.
+pub fn parse_chain_name(name: &str) -> Option<(&'static str, &str)> {
+    let valid = |name: &str| {
+        !name.is_empty() &&
+            name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    };
+
+    let (network_type, network) =
+        match name.strip_suffix("-sepolia").or_else(|| name.strip_suffix("_sepolia")) {
+            Some(network) => ("sepolia", network),
+            None => ("mainnet", name),
+        };
+
+    valid(network).then_some((network_type, network))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_registry_chain_names() {
+        assert_eq!(parse_chain_name("zora"), Some(("mainnet", "zora")));
+        assert_eq!(parse_chain_name("unichain-sepolia"), Some(("sepolia", "unichain")));
+        assert_eq!(parse_chain_name("mode_sepolia"), Some(("sepolia", "mode")));
+        assert_eq!(parse_chain_name("arena-z"), Some(("mainnet", "arena-z")));
+        assert_eq!(parse_chain_name("-sepolia"), None);
+        assert_eq!(parse_chain_name("genesis.json"), None);
+        assert_eq!(parse_chain_name("./zora"), None);
+        assert_eq!(parse_chain_name(""), None);
+    }
+}
@@ -34,7 +34,7 @@ use reth_chainspec::{
     DepositContract, EthChainSpec, EthereumHardforks, ForkFilter, ForkId, Hardforks, Head,
 };
 use reth_ethereum_forks::{ChainHardforks, EthereumHardfork, ForkCondition, Hardfork};
-use reth_network_peers::NodeRecord;
+use reth_network_peers::{op_nodes, op_testnet_nodes, NodeRecord};
 use reth_optimism_forks::{OpHardfork, OpHardforks};
 use reth_primitives_traits::{sync::LazyLock, SealedHeader};
 
@@ -227,7 +227,17 @@ impl EthChainSpec for OpChainSpec {
     }
 
     fn bootnodes(&self) -> Option<Vec<NodeRecord>> {
-        self.inner.bootnodes()
+        self.inner.bootnodes().or_else(|| {
+            // chains of the superchain share the OP bootnodes
+            let chain = self.inner.chain.named()?;
+            chain.is_optimism().then(|| {
+                if chain.is_testnet() {
+                    op_testnet_nodes()
+                } else {
+                    op_nodes()
+                }
+            })
+        })
     }
 
     fn is_optimism(&self) -> bool {
@@ -423,6 +433,7 @@ impl OpGenesisInfo {
 
 #[cfg(test)]
 mod tests {
+    use alloy_chains::NamedChain;
     use alloy_genesis::{ChainConfig, Genesis};
     use alloy_primitives::b256;
     use reth_chainspec::{test_fork_ids, BaseFeeParams, BaseFeeParamsKind};
@@ -964,6 +975,20 @@ mod tests {
         assert_eq!(expected_hardforks.len(), hardforks.len());
     }
 
+    #[test]
+    fn superchain_bootnodes() {
+        let chain_spec = |chain: NamedChain| {
+            OpChainSpec::from(Genesis {
+                config: ChainConfig { chain_id: chain as u64, ..Default::default() },
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(chain_spec(NamedChain::Zora).bootnodes(), Some(op_nodes()));
+        assert_eq!(chain_spec(NamedChain::ZoraSepolia).bootnodes(), Some(op_testnet_nodes()));
+        assert_eq!(chain_spec(NamedChain::Dev).bootnodes(), None);
+    }
+
     #[test]
     fn json_genesis() {
         let geth_genesis = r#"
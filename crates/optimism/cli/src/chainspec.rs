@@ -1,8 +1,22 @@
+use eyre::WrapErr;
+use reth_chainspec::EthChainSpec;
 use reth_cli::chainspec::{parse_genesis, ChainSpecParser};
+use reth_node_core::dirs::cache_dir;
+use reth_optimism_chain_registry::{parse_chain_name, SuperChainRegistryManager};
 use reth_optimism_chainspec::{
     OpChainSpec, BASE_MAINNET, BASE_SEPOLIA, OP_DEV, OP_MAINNET, OP_SEPOLIA,
 };
-use std::sync::Arc;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Environment variable that points to the directory of the superchain registry snapshots.
+pub const SUPERCHAIN_REGISTRY_DIR_ENV: &str = "RETH_SUPERCHAIN_REGISTRY_DIR";
+
+/// Name of the file in the superchain registry directory that holds the commit of the snapshot in
+/// use.
+const PINNED_COMMIT_FILE: &str = "COMMIT";
 
 /// Optimism chain specification parser.
 #[derive(Debug, Clone, Default)]
@@ -29,8 +43,12 @@ impl ChainSpecParser for OpChainSpecParser {
 
 /// Clap value parser for [`OpChainSpec`]s.
 ///
-/// The value parser matches either a known chain, the path
-/// to a json file, or a json formatted string in-memory. The json needs to be a Genesis struct.
+/// The value parser matches either a known chain, the name of a chain fetched from the superchain
+/// registry (e.g. `zora` or `unichain-sepolia`), the path to a json file, or a json formatted
+/// string in-memory. The json needs to be a Genesis struct.
+///
+/// Chains of the superchain registry are only read from the local snapshot, see
+/// [`superchain_chain_spec`].
 pub fn chain_value_parser(s: &str) -> eyre::Result<Arc<OpChainSpec>, eyre::Error> {
     Ok(match s {
         "dev" => OP_DEV.clone(),
@@ -38,10 +56,82 @@ pub fn chain_value_parser(s: &str) -> eyre::Result<Arc<OpChainSpec>, eyre::Error
         "optimism_sepolia" | "optimism-sepolia" => OP_SEPOLIA.clone(),
         "base" => BASE_MAINNET.clone(),
         "base_sepolia" | "base-sepolia" => BASE_SEPOLIA.clone(),
-        _ => Arc::new(parse_genesis(s)?.into()),
+        _ => match parse_chain_name(s).filter(|_| !Path::new(s).exists()) {
+            Some((network_type, network)) => {
+                Arc::new(superchain_chain_spec(network_type, network)?)
+            }
+            None => Arc::new(parse_genesis(s)?.into()),
+        },
+    })
+}
+
+/// Returns the directory of the superchain registry snapshots.
+///
+/// This is the directory set by [`SUPERCHAIN_REGISTRY_DIR_ENV`], or the `superchain-registry`
+/// directory in the reth cache directory by default. It holds a snapshot of the registry per
+/// commit, and the commit of the snapshot in use, which is set by `op-reth fetch-superchain`.
+pub fn superchain_registry_dir() -> eyre::Result<PathBuf> {
+    std::env::var_os(SUPERCHAIN_REGISTRY_DIR_ENV)
+        .map(PathBuf::from)
+        .or_else(|| cache_dir().map(|dir| dir.join("superchain-registry")))
+        .ok_or_else(|| eyre::eyre!("failed to resolve the superchain registry directory"))
+}
+
+/// Returns the commit of the superchain registry snapshot in use, if any chain was fetched.
+pub fn pinned_superchain_commit(dir: &Path) -> eyre::Result<Option<String>> {
+    let path = dir.join(PINNED_COMMIT_FILE);
+    if !path.exists() {
+        return Ok(None)
+    }
+    Ok(Some(reth_fs_util::read_to_string(path)?.trim().to_string()))
+}
+
+/// Sets the commit of the superchain registry snapshot in use.
+pub fn pin_superchain_commit(dir: &Path, commit: &str) -> eyre::Result<()> {
+    Ok(reth_fs_util::write(dir.join(PINNED_COMMIT_FILE), commit)?)
+}
+
+/// Loads the [`OpChainSpec`] of a chain from the superchain registry snapshot in use.
+///
+/// This only reads the snapshot, chains are downloaded into it with `op-reth fetch-superchain`.
+pub fn superchain_chain_spec(network_type: &str, network: &str) -> eyre::Result<OpChainSpec> {
+    let dir = superchain_registry_dir()?;
+    let commit = pinned_superchain_commit(&dir)?.ok_or_else(|| {
+        eyre::eyre!(
+            "{network_type}/{network} is not in the superchain registry snapshot, fetch it with `op-reth fetch-superchain`"
+        )
+    })?;
+
+    let registry = SuperChainRegistryManager::new(dir.join(&commit));
+    load_superchain_chain_spec(&registry, network_type, network).wrap_err_with(|| {
+        format!(
+            "failed to load {network_type}/{network} from the superchain registry at {commit}, fetch it with `op-reth fetch-superchain`"
+        )
     })
 }
 
+/// Loads the [`OpChainSpec`] of a chain from the given superchain registry snapshot, and checks
+/// its genesis hash against the one listed in the registry.
+pub fn load_superchain_chain_spec(
+    registry: &SuperChainRegistryManager,
+    network_type: &str,
+    network: &str,
+) -> eyre::Result<OpChainSpec> {
+    let (config, genesis) = registry.get_chain(network_type, network)?;
+
+    let chain_spec = OpChainSpec::from(genesis);
+    if let Some(expected) = config.genesis_hash() {
+        let genesis_hash = chain_spec.genesis_hash();
+        eyre::ensure!(
+            genesis_hash == expected,
+            "genesis hash mismatch for {}: expected {expected}, got {genesis_hash}",
+            config.name
+        );
+    }
+
+    Ok(chain_spec)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
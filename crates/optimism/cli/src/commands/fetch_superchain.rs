@@ -0,0 +1,83 @@
+//! Command that downloads a chain of the superchain registry.
+
+use crate::chainspec::{
+    load_superchain_chain_spec, pin_superchain_commit, pinned_superchain_commit,
+    superchain_registry_dir,
+};
+use clap::Parser;
+use reth_chainspec::EthChainSpec;
+use reth_optimism_chain_registry::{parse_chain_name, SuperChainRegistryManager};
+use tracing::{info, warn};
+
+/// Downloads a chain of the superchain registry at a pinned commit, so that it can be selected
+/// with `--chain`.
+///
+/// The files of the chain are downloaded into a snapshot of the registry at the commit, and the
+/// snapshot is only used once the genesis hash of the chain matches the one listed in the
+/// registry. Fetching a chain at another commit refreshes the registry: chains fetched at the
+/// previous commit need to be fetched again.
+#[derive(Debug, Parser)]
+pub struct FetchSuperchainCommand {
+    /// The full hash of the superchain registry commit to download the chain from.
+    #[arg(long, value_name = "COMMIT", value_parser = parse_commit)]
+    commit: String,
+
+    /// The name of the chain in the superchain registry, e.g. `zora` or `unichain-sepolia`.
+    #[arg(value_name = "CHAIN")]
+    chain: String,
+}
+
+impl FetchSuperchainCommand {
+    /// Execute `fetch-superchain` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        let (network_type, network) = parse_chain_name(&self.chain)
+            .ok_or_else(|| eyre::eyre!("{} is not a superchain registry chain", self.chain))?;
+
+        let dir = superchain_registry_dir()?;
+        let registry = SuperChainRegistryManager::new(dir.join(&self.commit));
+
+        info!(target: "reth::cli", chain = %self.chain, commit = %self.commit, "Fetching chain from the superchain registry");
+        registry.fetch_chain(&self.commit, network_type, network).await?;
+        let chain_spec = load_superchain_chain_spec(&registry, network_type, network)?;
+
+        if let Some(previous) =
+            pinned_superchain_commit(&dir)?.filter(|previous| *previous != self.commit)
+        {
+            warn!(target: "reth::cli", %previous, commit = %self.commit, "Switching the superchain registry to another commit, chains of the previous commit need to be fetched again");
+        }
+        pin_superchain_commit(&dir, &self.commit)?;
+
+        info!(target: "reth::cli", chain = %self.chain, genesis_hash = %chain_spec.genesis_hash(), "Fetched chain from the superchain registry");
+
+        Ok(())
+    }
+}
+
+/// Parses a full commit hash, so that the registry can't be fetched from a moving branch.
+fn parse_commit(s: &str) -> eyre::Result<String> {
+    if s.len() != 40 || !s.chars().all(|c| c.is_ascii_hexdigit()) {
+        eyre::bail!("{s} is not a full commit hash")
+    }
+    Ok(s.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fetch_superchain_command() {
+        let commit = "0123456789abcdef0123456789ABCDEF01234567";
+        let command =
+            FetchSuperchainCommand::parse_from(["op-reth", "--commit", commit, "zora-sepolia"]);
+        assert_eq!(command.commit, commit.to_ascii_lowercase());
+        assert_eq!(command.chain, "zora-sepolia");
+
+        for commit in ["main", "0123456", "0123456789abcdef0123456789abcdef0123456g"] {
+            assert!(FetchSuperchainCommand::try_parse_from([
+                "op-reth", "--commit", commit, "zora"
+            ])
+            .is_err());
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::chainspec::OpChainSpecParser;
 use clap::Subcommand;
+use fetch_superchain::FetchSuperchainCommand;
 use import::ImportOpCommand;
 use import_receipts::ImportReceiptsOpCommand;
 use reth_cli::chainspec::ChainSpecParser;
@@ -10,6 +11,7 @@ use reth_cli_commands::{
 };
 use std::fmt;
 
+pub mod fetch_superchain;
 pub mod import;
 pub mod import_receipts;
 pub mod init_state;
@@ -36,6 +38,9 @@ pub enum Commands<Spec: ChainSpecParser = OpChainSpecParser, Ext: clap::Args + f
     /// This imports RLP encoded receipts from a file.
     #[command(name = "import-receipts-op")]
     ImportReceiptsOp(ImportReceiptsOpCommand<Spec>),
+    /// Downloads a chain of the superchain registry at a pinned commit.
+    #[command(name = "fetch-superchain")]
+    FetchSuperchain(FetchSuperchainCommand),
     /// Dumps genesis block JSON configuration to stdout.
     DumpGenesis(dump_genesis::DumpGenesisCommand<Spec>),
     /// Database debugging utilities
@@ -167,6 +167,7 @@ where
             Commands::ImportReceiptsOp(command) => {
                 runner.run_blocking_until_ctrl_c(command.execute::<OpNode>())
             }
+            Commands::FetchSuperchain(command) => runner.run_until_ctrl_c(command.execute()),
             Commands::DumpGenesis(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::Db(command) => runner.run_blocking_until_ctrl_c(command.execute::<OpNode>()),
             Commands::Stage(command) => runner.run_command_until_exit(|ctx| {
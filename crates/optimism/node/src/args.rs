@@ -2,6 +2,11 @@
 
 //! clap [Args](clap::Args) for optimism rollup configuration
 
+use reth_cli_util::parse_duration_from_secs_or_ms;
+use reth_optimism_payload_builder::flashblocks::DEFAULT_FLASHBLOCKS_INTERVAL;
+use reth_optimism_txpool::{conditional::DEFAULT_MAX_CONDITIONAL_COST, supervisor::SafetyLevel};
+use std::time::Duration;
+
 /// Parameters for rollup configuration
 #[derive(Debug, Clone, PartialEq, Eq, clap::Args)]
 #[command(next_help_heading = "Rollup")]
@@ -37,6 +42,50 @@ pub struct RollupArgs {
     /// Enable transaction conditional support on sequencer
     #[arg(long = "rollup.enable-tx-conditional", default_value = "false")]
     pub enable_tx_conditional: bool,
+
+    /// Maximum accepted cost of a transaction conditional, conditionals exceeding it are rejected
+    #[arg(
+        long = "rollup.tx-conditional-max-cost",
+        value_name = "COST",
+        default_value_t = DEFAULT_MAX_CONDITIONAL_COST
+    )]
+    pub tx_conditional_max_cost: u64,
+
+    /// HTTP endpoint of the supervisor that cross-chain messages of transactions are validated
+    /// against. Enables interop validation in the transaction pool and payload builder.
+    #[arg(long = "rollup.supervisor-http", value_name = "SUPERVISOR_HTTP_URL")]
+    pub supervisor_http: Option<String>,
+
+    /// Minimum safety level cross-chain messages must have to be accepted
+    #[arg(
+        long = "rollup.supervisor-safety-level",
+        value_name = "SAFETY_LEVEL",
+        default_value_t = SafetyLevel::CrossUnsafe
+    )]
+    pub supervisor_safety_level: SafetyLevel,
+
+    /// Enable publishing flashblocks of the payloads built by the sequencer over the
+    /// `flashblocks` websocket subscription
+    #[arg(long = "rollup.enable-flashblocks", default_value = "false")]
+    pub enable_flashblocks: bool,
+
+    /// Interval at which flashblocks of the payload that is being built are published.
+    ///
+    /// Interval is specified in seconds or in milliseconds if the value ends with `ms`.
+    /// A flashblock is only published if the payload job built a payload with new transactions
+    /// since the previous flashblock.
+    #[arg(
+        long = "rollup.flashblocks-interval",
+        value_parser = parse_duration_from_secs_or_ms,
+        default_value = "250ms",
+        value_name = "DURATION"
+    )]
+    pub flashblocks_interval: Duration,
+
+    /// Websocket endpoint of the sequencer to receive flashblocks from. Preconfirmed transactions
+    /// of the pending flashblock are served by `eth_getTransactionReceipt`.
+    #[arg(long = "rollup.flashblocks-url", value_name = "WS_URL")]
+    pub flashblocks_url: Option<String>,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -49,6 +98,12 @@ impl Default for RollupArgs {
             compute_pending_block: false,
             discovery_v4: false,
             enable_tx_conditional: false,
+            tx_conditional_max_cost: DEFAULT_MAX_CONDITIONAL_COST,
+            supervisor_http: None,
+            supervisor_safety_level: SafetyLevel::CrossUnsafe,
+            enable_flashblocks: false,
+            flashblocks_interval: DEFAULT_FLASHBLOCKS_INTERVAL,
+            flashblocks_url: None,
         }
     }
 }
@@ -128,6 +183,63 @@ mod tests {
         assert_eq!(args, expected_args);
     }
 
+    #[test]
+    fn test_parse_optimism_tx_conditional_max_cost() {
+        let expected_args = RollupArgs { tx_conditional_max_cost: 100, ..Default::default() };
+        let args = CommandParser::<RollupArgs>::parse_from([
+            "reth",
+            "--rollup.tx-conditional-max-cost",
+            "100",
+        ])
+        .args;
+        assert_eq!(args, expected_args);
+    }
+
+    #[test]
+    fn test_parse_optimism_supervisor_args() {
+        let expected_args = RollupArgs {
+            supervisor_http: Some("http://localhost:8545".into()),
+            supervisor_safety_level: SafetyLevel::CrossSafe,
+            ..Default::default()
+        };
+        let args = CommandParser::<RollupArgs>::parse_from([
+            "reth",
+            "--rollup.supervisor-http",
+            "http://localhost:8545",
+            "--rollup.supervisor-safety-level",
+            "safe",
+        ])
+        .args;
+        assert_eq!(args, expected_args);
+    }
+
+    #[test]
+    fn test_parse_optimism_flashblocks_args() {
+        let expected_args = RollupArgs {
+            enable_flashblocks: true,
+            flashblocks_interval: Duration::from_millis(200),
+            ..Default::default()
+        };
+        let args = CommandParser::<RollupArgs>::parse_from([
+            "reth",
+            "--rollup.enable-flashblocks",
+            "--rollup.flashblocks-interval",
+            "200ms",
+        ])
+        .args;
+        assert_eq!(args, expected_args);
+
+        let expected_args =
+            RollupArgs { flashblocks_url: Some("ws://host:port".into()), ..Default::default() };
+        let args = CommandParser::<RollupArgs>::parse_from([
+            "reth",
+            "--rollup.flashblocks-url",
+            "ws://host:port",
+        ])
+        .args;
+        assert_eq!(args, expected_args);
+    }
+
     #[test]
     fn test_parse_optimism_many_args() {
         let expected_args = RollupArgs {
@@ -31,15 +31,22 @@ use reth_optimism_forks::OpHardforks;
 use reth_optimism_payload_builder::{
     builder::OpPayloadTransactions,
     config::{OpBuilderConfig, OpDAConfig},
+    flashblocks::FlashblocksPublisher,
 };
 use reth_optimism_primitives::{DepositReceipt, OpPrimitives, OpReceipt, OpTransactionSigned};
 use reth_optimism_rpc::{
     eth::ext::OpEthExtApi,
+    flashblocks::{FlashblocksApiServer, OpFlashblocksApi, PendingFlashblocks},
     miner::{MinerApiExtServer, OpMinerExtApi},
     witness::{DebugExecutionWitnessApiServer, OpDebugWitnessApi},
     OpEthApi, OpEthApiError, SequencerClient,
 };
-use reth_optimism_txpool::conditional::MaybeConditionalTransaction;
+use reth_optimism_txpool::{
+    conditional::{MaybeConditionalTransaction, DEFAULT_MAX_CONDITIONAL_COST},
+    interop::MaybeInteropTransaction,
+    supervisor::{CachedSupervisorClient, SafetyLevel, SupervisorClient},
+    OpPooledTx,
+};
 use reth_provider::{providers::ProviderFactoryBuilder, CanonStateSubscriptions, EthStorage};
 use reth_rpc_eth_api::ext::L2EthApiExtServer;
 use reth_rpc_eth_types::error::FromEvmError;
@@ -69,12 +76,18 @@ pub struct OpNode {
     ///
     /// By default no throttling is applied.
     pub da_config: OpDAConfig,
+    /// Publishes the flashblocks of the payloads built by the OP builder, if enabled.
+    ///
+    /// Shared between the payload builder and the `flashblocks` rpc namespace.
+    pub flashblocks: Option<FlashblocksPublisher<OpPrimitives>>,
 }
 
 impl OpNode {
     /// Creates a new instance of the Optimism node type.
     pub fn new(args: RollupArgs) -> Self {
-        Self { args, da_config: OpDAConfig::default() }
+        let flashblocks =
+            args.enable_flashblocks.then(|| FlashblocksPublisher::new(args.flashblocks_interval));
+        Self { args, da_config: OpDAConfig::default(), flashblocks }
     }
 
     /// Configure the data availability configuration for the OP builder.
@@ -109,10 +122,20 @@ impl OpNode {
             .node_types::<Node>()
             .pool(
                 OpPoolBuilder::default()
-                    .with_enable_tx_conditional(self.args.enable_tx_conditional),
+                    .with_enable_tx_conditional(self.args.enable_tx_conditional)
+                    .with_supervisor(
+                        self.args.supervisor_http.clone(),
+                        self.args.supervisor_safety_level,
+                    ),
             )
             .payload(
-                OpPayloadBuilder::new(compute_pending_block).with_da_config(self.da_config.clone()),
+                OpPayloadBuilder::new(compute_pending_block)
+                    .with_da_config(self.da_config.clone())
+                    .with_flashblocks(self.flashblocks.clone())
+                    .with_supervisor(
+                        self.args.supervisor_http.clone(),
+                        self.args.supervisor_safety_level,
+                    ),
             )
             .network(OpNetworkBuilder {
                 disable_txpool_gossip,
@@ -189,6 +212,9 @@ where
             .with_sequencer(self.args.sequencer_http.clone())
             .with_da_config(self.da_config.clone())
             .with_enable_tx_conditional(self.args.enable_tx_conditional)
+            .with_tx_conditional_max_cost(self.args.tx_conditional_max_cost)
+            .with_flashblocks(self.flashblocks.clone())
+            .with_flashblocks_url(self.args.flashblocks_url.clone())
             .build()
     }
 }
@@ -222,6 +248,12 @@ pub struct OpAddOns<N: FullNodeComponents> {
     pub sequencer_client: Option<SequencerClient>,
     /// Enable transaction conditionals.
     enable_tx_conditional: bool,
+    /// Maximum accepted cost of a transaction conditional.
+    tx_conditional_max_cost: u64,
+    /// Publishes the flashblocks of the payloads built by this node, if enabled.
+    flashblocks: Option<FlashblocksPublisher<OpPrimitives>>,
+    /// Pending flashblocks of the sequencer and the websocket endpoint they are received from.
+    pending_flashblocks: Option<(PendingFlashblocks, String)>,
 }
 
 impl<N: FullNodeComponents<Types: NodeTypes<Primitives = OpPrimitives>>> Default for OpAddOns<N> {
@@ -249,7 +281,7 @@ where
         Evm: ConfigureEvmEnv<TxEnv = revm_optimism::OpTransaction<TxEnv>>,
     >,
     OpEthApiError: FromEvmError<N::Evm>,
-    <<N as FullNodeComponents>::Pool as TransactionPool>::Transaction: MaybeConditionalTransaction,
+    <<N as FullNodeComponents>::Pool as TransactionPool>::Transaction: OpPooledTx,
 {
     type Handle = RpcHandle<N, OpEthApi<N>>;
 
@@ -257,7 +289,21 @@ where
         self,
         ctx: reth_node_api::AddOnsContext<'_, N>,
     ) -> eyre::Result<Self::Handle> {
-        let Self { rpc_add_ons, da_config, sequencer_client, enable_tx_conditional } = self;
+        let Self {
+            rpc_add_ons,
+            da_config,
+            sequencer_client,
+            enable_tx_conditional,
+            tx_conditional_max_cost,
+            flashblocks,
+            pending_flashblocks,
+        } = self;
+
+        if let Some((pending_flashblocks, url)) = pending_flashblocks {
+            info!(target: "reth::cli", %url, "Following flashblocks of the sequencer");
+            ctx.node.task_executor().spawn(pending_flashblocks.follow(url));
+        }
+        let flashblocks_ext = flashblocks.map(OpFlashblocksApi::new);
 
         let builder = reth_optimism_payload_builder::OpPayloadBuilder::new(
             ctx.node.pool().clone(),
@@ -277,9 +323,10 @@ where
             sequencer_client,
             ctx.node.pool().clone(),
             ctx.node.provider().clone(),
-        );
+        )
+        .with_max_conditional_cost(tx_conditional_max_cost);
         rpc_add_ons
-            .launch_add_ons_with(ctx, move |modules, auth_modules| {
+            .launch_add_ons_with(ctx, move |modules, auth_modules, _| {
                 debug!(target: "reth::cli", "Installing debug payload witness rpc endpoint");
                 modules.merge_if_module_configured(RethRpcModule::Debug, debug_ext.into_rpc())?;
 
@@ -303,6 +350,14 @@ where
                     )?;
                 }
 
+                if let Some(flashblocks_ext) = flashblocks_ext {
+                    // flashblocks are streamed over subscriptions, which require a ws or ipc
+                    // transport
+                    debug!(target: "reth::cli", "Installing flashblocks subscription");
+                    modules.merge_ws(flashblocks_ext.clone().into_rpc())?;
+                    modules.merge_ipc(flashblocks_ext.into_rpc())?;
+                }
+
                 Ok(())
             })
             .await
@@ -321,7 +376,7 @@ where
         Evm: ConfigureEvm<TxEnv = revm_optimism::OpTransaction<TxEnv>>,
     >,
     OpEthApiError: FromEvmError<N::Evm>,
-    <<N as FullNodeComponents>::Pool as TransactionPool>::Transaction: MaybeConditionalTransaction,
+    <<N as FullNodeComponents>::Pool as TransactionPool>::Transaction: OpPooledTx,
 {
     type EthApi = OpEthApi<N>;
 
@@ -358,6 +413,12 @@ pub struct OpAddOnsBuilder {
     da_config: Option<OpDAConfig>,
     /// Enable transaction conditionals.
     enable_tx_conditional: bool,
+    /// Maximum accepted cost of a transaction conditional.
+    tx_conditional_max_cost: Option<u64>,
+    /// Publishes the flashblocks of the payloads built by this node, if enabled.
+    flashblocks: Option<FlashblocksPublisher<OpPrimitives>>,
+    /// Websocket endpoint of the sequencer to receive flashblocks from.
+    flashblocks_url: Option<String>,
 }
 
 impl OpAddOnsBuilder {
@@ -378,6 +439,28 @@ impl OpAddOnsBuilder {
         self.enable_tx_conditional = enable_tx_conditional;
         self
     }
+
+    /// Configure the maximum accepted cost of a transaction conditional.
+    pub const fn with_tx_conditional_max_cost(mut self, tx_conditional_max_cost: u64) -> Self {
+        self.tx_conditional_max_cost = Some(tx_conditional_max_cost);
+        self
+    }
+
+    /// Configure the [`FlashblocksPublisher`] of the payload builder, installs the `flashblocks`
+    /// namespace if set.
+    pub fn with_flashblocks(
+        mut self,
+        flashblocks: Option<FlashblocksPublisher<OpPrimitives>>,
+    ) -> Self {
+        self.flashblocks = flashblocks;
+        self
+    }
+
+    /// Configure the websocket endpoint of the sequencer to receive flashblocks from.
+    pub fn with_flashblocks_url(mut self, flashblocks_url: Option<String>) -> Self {
+        self.flashblocks_url = flashblocks_url;
+        self
+    }
 }
 
 impl OpAddOnsBuilder {
@@ -386,13 +469,26 @@ impl OpAddOnsBuilder {
     where
         N: FullNodeComponents<Types: NodeTypes<Primitives = OpPrimitives>>,
     {
-        let Self { sequencer_client, da_config, enable_tx_conditional } = self;
+        let Self {
+            sequencer_client,
+            da_config,
+            enable_tx_conditional,
+            tx_conditional_max_cost,
+            flashblocks,
+            flashblocks_url,
+        } = self;
 
         let sequencer_client_clone = sequencer_client.clone();
+        let pending_flashblocks = flashblocks_url.map(|url| (PendingFlashblocks::default(), url));
+        let pending_flashblocks_clone =
+            pending_flashblocks.as_ref().map(|(pending, _)| pending.clone());
         OpAddOns {
             rpc_add_ons: RpcAddOns::new(
                 move |ctx| {
-                    OpEthApi::<N>::builder().with_sequencer(sequencer_client_clone).build(ctx)
+                    OpEthApi::<N>::builder()
+                        .with_sequencer(sequencer_client_clone)
+                        .with_flashblocks(pending_flashblocks_clone)
+                        .build(ctx)
                 },
                 Default::default(),
                 Default::default(),
@@ -400,6 +496,10 @@ impl OpAddOnsBuilder {
             da_config: da_config.unwrap_or_default(),
             sequencer_client,
             enable_tx_conditional,
+            tx_conditional_max_cost: tx_conditional_max_cost
+                .unwrap_or(DEFAULT_MAX_CONDITIONAL_COST),
+            flashblocks,
+            pending_flashblocks,
         }
     }
 }
@@ -438,6 +538,10 @@ pub struct OpPoolBuilder<T = crate::txpool::OpPooledTransaction> {
     pub pool_config_overrides: PoolBuilderConfigOverrides,
     /// Enable transaction conditionals.
     pub enable_tx_conditional: bool,
+    /// HTTP endpoint of the supervisor that cross-chain messages are validated against.
+    pub supervisor_http: Option<String>,
+    /// Minimum safety level of cross-chain messages.
+    pub supervisor_safety_level: SafetyLevel,
     /// Marker for the pooled transaction type.
     _pd: core::marker::PhantomData<T>,
 }
@@ -447,6 +551,8 @@ impl<T> Default for OpPoolBuilder<T> {
         Self {
             pool_config_overrides: Default::default(),
             enable_tx_conditional: false,
+            supervisor_http: None,
+            supervisor_safety_level: SafetyLevel::default(),
             _pd: Default::default(),
         }
     }
@@ -457,19 +563,41 @@ impl<T> OpPoolBuilder<T> {
         self.enable_tx_conditional = enable_tx_conditional;
         self
     }
+
+    fn with_supervisor(
+        mut self,
+        supervisor_http: Option<String>,
+        supervisor_safety_level: SafetyLevel,
+    ) -> Self {
+        self.supervisor_http = supervisor_http;
+        self.supervisor_safety_level = supervisor_safety_level;
+        self
+    }
 }
 
 impl<Node, T> PoolBuilder<Node> for OpPoolBuilder<T>
 where
     Node: FullNodeTypes<Types: NodeTypes<ChainSpec: OpHardforks>>,
-    T: EthPoolTransaction<Consensus = TxTy<Node::Types>> + MaybeConditionalTransaction,
+    T: EthPoolTransaction<Consensus = TxTy<Node::Types>>
+        + MaybeConditionalTransaction
+        + MaybeInteropTransaction,
 {
     type Pool = OpTransactionPool<Node::Provider, DiskFileBlobStore, T>;
 
     async fn build_pool(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::Pool> {
-        let Self { pool_config_overrides, .. } = self;
+        let Self {
+            pool_config_overrides,
+            enable_tx_conditional,
+            supervisor_http,
+            supervisor_safety_level,
+            ..
+        } = self;
         let data_dir = ctx.config().datadir();
         let blob_store = DiskFileBlobStore::open(data_dir.blobstore(), Default::default())?;
+        let supervisor_client = supervisor_http
+            .map(|supervisor_http| SupervisorClient::new(supervisor_http, supervisor_safety_level))
+            .transpose()?;
+        let enable_interop = supervisor_client.is_some();
 
         let validator = TransactionValidationTaskExecutor::eth_builder(ctx.provider().clone())
             .no_eip4844()
@@ -482,10 +610,14 @@ where
             )
             .build_with_tasks(ctx.task_executor().clone(), blob_store.clone())
             .map(|validator| {
-                OpTransactionValidator::new(validator)
+                let validator = OpTransactionValidator::new(validator)
                     // In --dev mode we can't require gas fees because we're unable to decode
                     // the L1 block info
-                    .require_l1_data_gas_fee(!ctx.config().dev.dev)
+                    .require_l1_data_gas_fee(!ctx.config().dev.dev);
+                match supervisor_client {
+                    Some(supervisor_client) => validator.with_supervisor_client(supervisor_client),
+                    None => validator,
+                }
             });
 
         let transaction_pool = reth_transaction_pool::Pool::new(
@@ -495,6 +627,7 @@ where
             pool_config_overrides.apply(ctx.pool_config()),
         );
         info!(target: "reth::cli", "Transaction pool initialized");
+        ctx.spawn_pool_admission_reloader(transaction_pool.clone());
         let transactions_path = data_dir.txpool_transactions();
 
         // spawn txpool maintenance tasks
@@ -502,8 +635,10 @@ where
             let pool = transaction_pool.clone();
             let chain_events = ctx.provider().canonical_state_stream();
             let client = ctx.provider().clone();
-            let transactions_backup_config =
+            let mut transactions_backup_config =
                 reth_transaction_pool::maintain::LocalTransactionBackupConfig::with_local_txs_backup(transactions_path);
+            transactions_backup_config.journal =
+                ctx.config().txpool.journal_config(data_dir.txpool_journal());
 
             ctx.task_executor().spawn_critical_with_graceful_shutdown_signal(
                 "local transactions backup task",
@@ -529,12 +664,13 @@ where
             );
             debug!(target: "reth::cli", "Spawned txpool maintenance task");
 
-            if self.enable_tx_conditional {
+            if enable_tx_conditional || enable_interop {
                 // spawn the Op txpool maintenance task
                 let chain_events = ctx.provider().canonical_state_stream();
                 ctx.task_executor().spawn_critical(
                     "Op txpool maintenance task",
                     reth_optimism_txpool::maintain::maintain_transaction_pool_future(
+                        ctx.provider().clone(),
                         pool,
                         chain_events,
                     ),
@@ -565,13 +701,27 @@ pub struct OpPayloadBuilder<Txs = ()> {
     /// This data availability configuration specifies constraints for the payload builder
     /// when assembling payloads
     pub da_config: OpDAConfig,
+    /// Publishes the flashblocks of the built payloads, if enabled.
+    pub flashblocks: Option<FlashblocksPublisher<OpPrimitives>>,
+    /// HTTP endpoint of the supervisor that cross-chain messages are re-checked against when
+    /// transactions are included.
+    pub supervisor_http: Option<String>,
+    /// Minimum safety level of cross-chain messages.
+    pub supervisor_safety_level: SafetyLevel,
 }
 
 impl OpPayloadBuilder {
     /// Create a new instance with the given `compute_pending_block` flag and data availability
     /// config.
     pub fn new(compute_pending_block: bool) -> Self {
-        Self { compute_pending_block, best_transactions: (), da_config: OpDAConfig::default() }
+        Self {
+            compute_pending_block,
+            best_transactions: (),
+            da_config: OpDAConfig::default(),
+            flashblocks: None,
+            supervisor_http: None,
+            supervisor_safety_level: SafetyLevel::default(),
+        }
     }
 
     /// Configure the data availability configuration for the OP payload builder.
@@ -585,8 +735,44 @@ impl<Txs> OpPayloadBuilder<Txs> {
     /// Configures the type responsible for yielding the transactions that should be included in the
     /// payload.
     pub fn with_transactions<T>(self, best_transactions: T) -> OpPayloadBuilder<T> {
-        let Self { compute_pending_block, da_config, .. } = self;
-        OpPayloadBuilder { compute_pending_block, best_transactions, da_config }
+        let Self {
+            compute_pending_block,
+            da_config,
+            flashblocks,
+            supervisor_http,
+            supervisor_safety_level,
+            ..
+        } = self;
+        OpPayloadBuilder {
+            compute_pending_block,
+            best_transactions,
+            da_config,
+            flashblocks,
+            supervisor_http,
+            supervisor_safety_level,
+        }
+    }
+
+    /// Configure the [`FlashblocksPublisher`] that publishes the flashblocks of the built
+    /// payloads.
+    pub fn with_flashblocks(
+        mut self,
+        flashblocks: Option<FlashblocksPublisher<OpPrimitives>>,
+    ) -> Self {
+        self.flashblocks = flashblocks;
+        self
+    }
+
+    /// Configure the supervisor that the cross-chain messages of transactions are re-checked
+    /// against when they are included in a payload.
+    pub fn with_supervisor(
+        mut self,
+        supervisor_http: Option<String>,
+        supervisor_safety_level: SafetyLevel,
+    ) -> Self {
+        self.supervisor_http = supervisor_http;
+        self.supervisor_safety_level = supervisor_safety_level;
+        self
     }
 
     /// A helper method to initialize [`reth_optimism_payload_builder::OpPayloadBuilder`] with the
@@ -614,21 +800,33 @@ impl<Txs> OpPayloadBuilder<Txs> {
                 Primitives = OpPrimitives,
             >,
         >,
-        Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TxTy<Node::Types>>>
+        Pool: TransactionPool<Transaction: OpPooledTx<Consensus = TxTy<Node::Types>>>
             + Unpin
             + 'static,
         Evm: ConfigureEvmFor<PrimitivesTy<Node::Types>>,
         Txs: OpPayloadTransactions<Pool::Transaction>,
     {
-        let payload_builder = reth_optimism_payload_builder::OpPayloadBuilder::with_builder_config(
-            pool,
-            ctx.provider().clone(),
-            evm_config,
-            BasicOpReceiptBuilder::default(),
-            OpBuilderConfig { da_config: self.da_config.clone() },
-        )
-        .with_transactions(self.best_transactions.clone())
-        .set_compute_pending_block(self.compute_pending_block);
+        let mut payload_builder =
+            reth_optimism_payload_builder::OpPayloadBuilder::with_builder_config(
+                pool,
+                ctx.provider().clone(),
+                evm_config,
+                BasicOpReceiptBuilder::default(),
+                OpBuilderConfig { da_config: self.da_config.clone() },
+            )
+            .with_transactions(self.best_transactions.clone())
+            .set_compute_pending_block(self.compute_pending_block);
+        if let Some(flashblocks) = self.flashblocks.clone() {
+            ctx.task_executor()
+                .spawn_critical("flashblocks publisher", flashblocks.clone().publish_on_interval());
+            payload_builder = payload_builder.with_flashblocks(flashblocks);
+        }
+        if let Some(supervisor_http) = &self.supervisor_http {
+            let supervisor_client =
+                SupervisorClient::new(supervisor_http, self.supervisor_safety_level)?;
+            payload_builder =
+                payload_builder.with_supervisor(CachedSupervisorClient::new(supervisor_client));
+        }
         Ok(payload_builder)
     }
 }
@@ -642,9 +840,7 @@ where
             Primitives = OpPrimitives,
         >,
     >,
-    Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TxTy<Node::Types>>>
-        + Unpin
-        + 'static,
+    Pool: TransactionPool<Transaction: OpPooledTx<Consensus = TxTy<Node::Types>>> + Unpin + 'static,
     Txs: OpPayloadTransactions<Pool::Transaction>,
 {
     type PayloadBuilder = reth_optimism_payload_builder::OpPayloadBuilder<
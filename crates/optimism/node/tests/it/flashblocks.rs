@@ -0,0 +1,111 @@
+//! Node builder test that publishes flashblocks of the built payloads.
+
+use alloy_eips::eip2718::Encodable2718;
+use alloy_genesis::Genesis;
+use alloy_primitives::Bytes;
+use reth_chainspec::EthChainSpec;
+use reth_db::test_utils::create_test_rw_db_with_path;
+use reth_e2e_test_utils::{
+    node::NodeTestContext, transaction::TransactionTestContext, wallet::Wallet,
+};
+use reth_node_builder::{EngineNodeLauncher, NodeBuilder, NodeConfig};
+use reth_node_core::args::DatadirArgs;
+use reth_optimism_chainspec::OpChainSpecBuilder;
+use reth_optimism_node::{args::RollupArgs, utils::optimism_payload_attributes, OpNode};
+use reth_tasks::TaskManager;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::Mutex;
+
+#[tokio::test]
+async fn test_flashblocks_extend_to_final_block() {
+    reth_tracing::init_test_tracing();
+
+    let genesis: Genesis = serde_json::from_str(include_str!("../assets/genesis.json")).unwrap();
+    let chain_spec =
+        Arc::new(OpChainSpecBuilder::base_mainnet().genesis(genesis).ecotone_activated().build());
+    let wallet = Arc::new(Mutex::new(Wallet::default().with_chain_id(chain_spec.chain().into())));
+
+    let op_node = OpNode::new(RollupArgs {
+        enable_flashblocks: true,
+        flashblocks_interval: Duration::from_millis(10),
+        ..Default::default()
+    });
+    let mut flashblocks = op_node.flashblocks.as_ref().unwrap().subscribe();
+
+    // Configure and launch the node.
+    let config = NodeConfig::new(chain_spec).with_datadir_args(DatadirArgs {
+        datadir: reth_db::test_utils::tempdir_path().into(),
+        ..Default::default()
+    });
+    let db = create_test_rw_db_with_path(
+        config
+            .datadir
+            .datadir
+            .unwrap_or_chain_default(config.chain.chain(), config.datadir.clone())
+            .db(),
+    );
+    let tasks = TaskManager::current();
+    let node_handle = NodeBuilder::new(config.clone())
+        .with_database(db)
+        .node(op_node)
+        .launch_with_fn(|builder| {
+            let launcher = EngineNodeLauncher::new(
+                tasks.executor(),
+                builder.config.datadir(),
+                Default::default(),
+            );
+            builder.launch_with(launcher)
+        })
+        .await
+        .expect("Failed to launch node");
+
+    // Advance the chain with a single block.
+    let block_payloads = NodeTestContext::new(node_handle.node, optimism_payload_attributes)
+        .await
+        .unwrap()
+        .advance(1, |_| {
+            let wallet = wallet.clone();
+            Box::pin(async move {
+                let mut wallet = wallet.lock().await;
+                let tx_fut = TransactionTestContext::optimism_l1_block_info_tx(
+                    wallet.chain_id,
+                    wallet.inner.clone(),
+                    wallet.inner_nonce,
+                );
+                wallet.inner_nonce += 1;
+                tx_fut.await
+            })
+        })
+        .await
+        .unwrap();
+    let (block_payload, _) = block_payloads.first().unwrap();
+    let block = block_payload.block();
+    let block_transactions = block
+        .body()
+        .transactions
+        .iter()
+        .map(|tx| Bytes::from(tx.encoded_2718()))
+        .collect::<Vec<_>>();
+
+    // The best payload is published on the next tick, the flashblocks of the payload must add
+    // up to the final block.
+    let mut transactions = Vec::new();
+    loop {
+        let flashblock = tokio::time::timeout(Duration::from_secs(5), flashblocks.recv())
+            .await
+            .expect("flashblock not published")
+            .unwrap();
+        assert_eq!(flashblock.payload_id, block_payload.id());
+        assert_eq!(flashblock.index == 0, flashblock.base.is_some());
+        if let Some(base) = &flashblock.base {
+            assert_eq!(base.block_number, block.number);
+            assert_eq!(base.parent_hash, block.parent_hash);
+        }
+        transactions.extend(flashblock.diff.transactions.iter().cloned());
+        assert!(block_transactions.starts_with(&transactions));
+        if flashblock.diff.block_hash == block.hash() {
+            break
+        }
+    }
+    assert_eq!(transactions, block_transactions);
+}
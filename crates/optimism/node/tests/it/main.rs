@@ -3,6 +3,9 @@
 #[cfg(feature = "optimism")]
 mod builder;
 
+#[cfg(feature = "optimism")]
+mod flashblocks;
+
 #[cfg(feature = "optimism")]
 mod priority;
 
@@ -3,18 +3,20 @@
 use crate::{
     config::{OpBuilderConfig, OpDAConfig},
     error::OpPayloadBuilderError,
+    flashblocks::FlashblocksPublisher,
     payload::{OpBuiltPayload, OpPayloadBuilderAttributes},
     OpPayloadPrimitives,
 };
 use alloy_consensus::{
-    constants::EMPTY_WITHDRAWALS, Eip658Value, Header, Transaction, Typed2718,
-    EMPTY_OMMER_ROOT_HASH,
+    conditional::BlockConditionalAttributes, constants::EMPTY_WITHDRAWALS, Eip658Value, Header,
+    Transaction, Typed2718, EMPTY_OMMER_ROOT_HASH,
 };
 use alloy_eips::{eip4895::Withdrawals, merge::BEACON_NONCE};
 use alloy_primitives::{Address, Bytes, B256, U256};
 use alloy_rlp::Encodable;
 use alloy_rpc_types_debug::ExecutionWitness;
 use alloy_rpc_types_engine::PayloadId;
+use alloy_rpc_types_eth::erc4337::{AccountStorage, TransactionConditional};
 use op_alloy_consensus::OpDepositReceipt;
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
 use reth_basic_payload_builder::*;
@@ -31,9 +33,18 @@ use reth_optimism_forks::OpHardforks;
 use reth_optimism_primitives::{
     transaction::signed::OpTransaction, ADDRESS_L2_TO_L1_MESSAGE_PASSER,
 };
+use reth_optimism_txpool::{
+    conditional::MaybeConditionalTransaction,
+    interop::MaybeInteropTransaction,
+    supervisor::{parse_access_list_items_to_inbox_entries, CachedSupervisorClient},
+    OpPooledTx,
+};
 use reth_payload_builder_primitives::PayloadBuilderError;
 use reth_payload_primitives::PayloadBuilderAttributes;
-use reth_payload_util::{BestPayloadTransactions, NoopPayloadTransactions, PayloadTransactions};
+use reth_payload_util::{
+    BestPayloadTransactions, NoopPayloadTransactions, PayloadTransactions,
+    PayloadTransactionsChain, PayloadTransactionsFixed,
+};
 use reth_primitives::{
     transaction::SignedTransactionIntoRecoveredExt, BlockBody, NodePrimitives, SealedHeader,
 };
@@ -80,6 +91,11 @@ pub struct OpPayloadBuilder<Pool, Client, EvmConfig: ConfigureEvm, N: NodePrimit
     /// Node primitive types.
     pub receipt_builder:
         Arc<dyn OpReceiptBuilder<N::SignedTx, HaltReasonFor<EvmConfig>, Receipt = N::Receipt>>,
+    /// Publishes flashblocks of the payloads that are being built, if enabled.
+    pub flashblocks: Option<FlashblocksPublisher<N>>,
+    /// Supervisor that the cross-chain messages of pool transactions are re-checked against when
+    /// they are included, if interop is enabled.
+    pub supervisor: Option<CachedSupervisorClient>,
 }
 
 impl<Pool, Client, EvmConfig, N> OpPayloadBuilder<Pool, Client, EvmConfig, N>
@@ -123,6 +139,8 @@ where
             evm_config,
             config,
             best_transactions: (),
+            flashblocks: None,
+            supervisor: None,
         }
     }
 }
@@ -143,7 +161,15 @@ impl<Pool, Client, EvmConfig: ConfigureEvm, N: NodePrimitives, Txs>
         best_transactions: T,
     ) -> OpPayloadBuilder<Pool, Client, EvmConfig, N, T> {
         let Self {
-            pool, client, compute_pending_block, evm_config, config, receipt_builder, ..
+            pool,
+            client,
+            compute_pending_block,
+            evm_config,
+            config,
+            receipt_builder,
+            flashblocks,
+            supervisor,
+            ..
         } = self;
         OpPayloadBuilder {
             pool,
@@ -153,9 +179,25 @@ impl<Pool, Client, EvmConfig: ConfigureEvm, N: NodePrimitives, Txs>
             best_transactions,
             config,
             receipt_builder,
+            flashblocks,
+            supervisor,
         }
     }
 
+    /// Publishes flashblocks of the payloads that are being built through the given
+    /// [`FlashblocksPublisher`].
+    pub fn with_flashblocks(mut self, flashblocks: FlashblocksPublisher<N>) -> Self {
+        self.flashblocks = Some(flashblocks);
+        self
+    }
+
+    /// Re-checks the cross-chain messages of pool transactions against the given supervisor
+    /// before they are included.
+    pub fn with_supervisor(mut self, supervisor: CachedSupervisorClient) -> Self {
+        self.supervisor = Some(supervisor);
+        self
+    }
+
     /// Enables the rollup's compute pending block configuration option.
     pub const fn compute_pending_block(self) -> Self {
         self.set_compute_pending_block(true)
@@ -169,7 +211,7 @@ impl<Pool, Client, EvmConfig: ConfigureEvm, N: NodePrimitives, Txs>
 
 impl<Pool, Client, EvmConfig, N, T> OpPayloadBuilder<Pool, Client, EvmConfig, N, T>
 where
-    Pool: TransactionPool<Transaction: PoolTransaction<Consensus = N::SignedTx>>,
+    Pool: TransactionPool<Transaction: OpPooledTx<Consensus = N::SignedTx>>,
     Client: StateProviderFactory + ChainSpecProvider<ChainSpec: EthChainSpec + OpHardforks>,
     N: OpPayloadPrimitives,
     EvmConfig: ConfigureEvmFor<N>,
@@ -188,7 +230,7 @@ where
         best: impl FnOnce(BestTransactionsAttributes) -> Txs + Send + Sync + 'a,
     ) -> Result<BuildOutcome<OpBuiltPayload<N>>, PayloadBuilderError>
     where
-        Txs: PayloadTransactions<Transaction: PoolTransaction<Consensus = N::SignedTx>>,
+        Txs: PayloadTransactions<Transaction: OpPooledTx<Consensus = N::SignedTx>>,
     {
         let evm_env = self
             .evm_env(&args.config.attributes, &args.config.parent_header)
@@ -205,6 +247,7 @@ where
             cancel,
             best_payload,
             receipt_builder: self.receipt_builder.clone(),
+            supervisor: self.supervisor.clone(),
         };
 
         let builder = OpBuilder::new(best);
@@ -263,6 +306,7 @@ where
             cancel: Default::default(),
             best_payload: Default::default(),
             receipt_builder: self.receipt_builder.clone(),
+            supervisor: self.supervisor.clone(),
         };
 
         let state_provider = self.client.state_by_block_hash(ctx.parent().hash())?;
@@ -280,7 +324,7 @@ impl<Pool, Client, EvmConfig, N, Txs> PayloadBuilder
 where
     Client: StateProviderFactory + ChainSpecProvider<ChainSpec: EthChainSpec + OpHardforks> + Clone,
     N: OpPayloadPrimitives,
-    Pool: TransactionPool<Transaction: PoolTransaction<Consensus = N::SignedTx>>,
+    Pool: TransactionPool<Transaction: OpPooledTx<Consensus = N::SignedTx>>,
     EvmConfig: ConfigureEvmFor<N>,
     Txs: OpPayloadTransactions<Pool::Transaction>,
 {
@@ -292,7 +336,42 @@ where
         args: BuildArguments<Self::Attributes, Self::BuiltPayload>,
     ) -> Result<BuildOutcome<Self::BuiltPayload>, PayloadBuilderError> {
         let pool = self.pool.clone();
-        self.build_payload(args, |attrs| self.best_transactions.best_transactions(pool, attrs))
+        let Some(flashblocks) = &self.flashblocks else {
+            return self
+                .build_payload(args, |attrs| self.best_transactions.best_transactions(pool, attrs))
+        };
+
+        // transactions of already published flashblocks are preconfirmed and must be executed
+        // before any other transactions of the pool
+        let preconfirmed: Vec<_> = flashblocks
+            .preconfirmed_transactions(
+                args.config.payload_id(),
+                args.config.attributes.transactions.len(),
+            )
+            .into_iter()
+            .filter_map(|tx| Pool::Transaction::try_from_consensus(tx).ok())
+            .collect();
+        let outcome = self.build_payload(args, |attrs| {
+            PayloadTransactionsChain::new(
+                PayloadTransactionsFixed::new(preconfirmed),
+                None,
+                self.best_transactions.best_transactions(pool, attrs),
+                None,
+            )
+        })?;
+
+        match outcome {
+            BuildOutcome::Better { payload, cached_reads } => {
+                if flashblocks.on_new_payload(&payload) {
+                    Ok(BuildOutcome::Better { payload, cached_reads })
+                } else {
+                    // a preconfirmed transaction couldn't be included, keep the previous payload
+                    // which extends all published flashblocks
+                    Ok(BuildOutcome::Aborted { fees: payload.fees(), cached_reads })
+                }
+            }
+            outcome => Ok(outcome),
+        }
     }
 
     fn on_missing_payload(
@@ -359,7 +438,7 @@ impl<Txs> OpBuilder<'_, Txs> {
     ) -> Result<BuildOutcomeKind<ExecutedPayload<N>>, PayloadBuilderError>
     where
         N: OpPayloadPrimitives,
-        Txs: PayloadTransactions<Transaction: PoolTransaction<Consensus = N::SignedTx>>,
+        Txs: PayloadTransactions<Transaction: OpPooledTx<Consensus = N::SignedTx>>,
         EvmConfig: ConfigureEvmFor<N>,
         ChainSpec: EthChainSpec + OpHardforks,
         DB: Database<Error = ProviderError> + AsRef<P>,
@@ -433,7 +512,7 @@ impl<Txs> OpBuilder<'_, Txs> {
         EvmConfig: ConfigureEvmFor<N>,
         ChainSpec: EthChainSpec + OpHardforks,
         N: OpPayloadPrimitives,
-        Txs: PayloadTransactions<Transaction: PoolTransaction<Consensus = N::SignedTx>>,
+        Txs: PayloadTransactions<Transaction: OpPooledTx<Consensus = N::SignedTx>>,
         DB: Database<Error = ProviderError> + AsRef<P>,
         P: StateRootProvider + HashedPostStateProvider + StorageRootProvider,
     {
@@ -559,7 +638,7 @@ impl<Txs> OpBuilder<'_, Txs> {
         EvmConfig: ConfigureEvmFor<N>,
         ChainSpec: EthChainSpec + OpHardforks,
         N: OpPayloadPrimitives,
-        Txs: PayloadTransactions<Transaction: PoolTransaction<Consensus = N::SignedTx>>,
+        Txs: PayloadTransactions<Transaction: OpPooledTx<Consensus = N::SignedTx>>,
         DB: Database<Error = ProviderError> + AsRef<P>,
         P: StateProofProvider + StorageRootProvider,
     {
@@ -678,6 +757,8 @@ pub struct OpPayloadBuilderCtx<EvmConfig: ConfigureEvm, ChainSpec, N: NodePrimit
     /// Receipt builder.
     pub receipt_builder:
         Arc<dyn OpReceiptBuilder<N::SignedTx, HaltReasonFor<EvmConfig>, Receipt = N::Receipt>>,
+    /// Supervisor that the cross-chain messages of pool transactions are re-checked against.
+    pub supervisor: Option<CachedSupervisorClient>,
 }
 
 impl<EvmConfig, ChainSpec, N> OpPayloadBuilderCtx<EvmConfig, ChainSpec, N>
@@ -794,6 +875,22 @@ where
         is_better_payload(self.best_payload.as_ref(), total_fees)
     }
 
+    /// Returns `true` if the cross-chain messages of the given pool transaction are valid when
+    /// executed at the given timestamp, according to the configured supervisor.
+    ///
+    /// Transactions without cross-chain messages are always valid.
+    pub fn is_interop_valid<T: OpPooledTx>(&self, tx: &T, timestamp: u64) -> bool {
+        let Some(supervisor) = &self.supervisor else { return true };
+        let Some(access_list) = tx.access_list() else { return true };
+        let inbox_entries = parse_access_list_items_to_inbox_entries(access_list.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        if inbox_entries.is_empty() {
+            return true
+        }
+        supervisor.is_valid_at_blocking(*tx.hash(), &inbox_entries, timestamp)
+    }
+
     /// Ensure that the create2deployer is force-deployed at the canyon transition. Optimism
     /// blocks will always have at least a single transaction in them (the L1 info transaction),
     /// so we can safely assume that this will always be triggered upon the transition and that
@@ -971,25 +1068,61 @@ where
     /// Executes the given best transactions and updates the execution info.
     ///
     /// Returns `Ok(Some(())` if the job was cancelled.
-    pub fn execute_best_transactions<DB>(
+    pub fn execute_best_transactions<DB, P>(
         &self,
         info: &mut ExecutionInfo<N>,
         db: &mut State<DB>,
         mut best_txs: impl PayloadTransactions<
-            Transaction: PoolTransaction<Consensus = EvmConfig::Transaction>,
+            Transaction: OpPooledTx<Consensus = EvmConfig::Transaction>,
         >,
     ) -> Result<Option<()>, PayloadBuilderError>
     where
-        DB: Database<Error = ProviderError>,
+        DB: Database<Error = ProviderError> + AsRef<P>,
+        P: StorageRootProvider,
     {
         let block_gas_limit = self.block_gas_limit();
         let block_da_limit = self.da_config.max_da_block_size();
         let tx_da_limit = self.da_config.max_da_tx_size();
         let base_fee = self.base_fee();
 
+        let block_attr = BlockConditionalAttributes {
+            number: self.block_number(),
+            timestamp: self.attributes().timestamp(),
+        };
+
         let mut evm = self.evm_config.evm_with_env(&mut *db, self.evm_env.clone());
 
         while let Some(tx) = best_txs.next(()) {
+            // skip transactions whose conditional doesn't allow inclusion in this block
+            if !tx.matches_block_attributes(&block_attr) {
+                trace!(target: "payload_builder", tx_hash=?tx.hash(), "skipping transaction with violated conditional");
+                best_txs.mark_invalid(tx.sender(), tx.nonce());
+                continue
+            }
+
+            // skip transactions whose known accounts don't match the state the transaction would
+            // be executed on, which includes the changes of the already executed transactions
+            if let Some(conditional) = tx.conditional() {
+                if !conditional.known_accounts.is_empty() &&
+                    !self.known_accounts_match(conditional, evm.db_mut())?
+                {
+                    trace!(target: "payload_builder", tx_hash=?tx.hash(), "skipping transaction with mismatched known accounts");
+                    best_txs.mark_invalid(tx.sender(), tx.nonce());
+                    continue
+                }
+            }
+
+            // skip transactions whose cross-chain messages are no longer valid, the messages are
+            // re-checked against the supervisor since they may have been invalidated after the
+            // transaction was validated
+            if !tx.is_interop_valid_at(block_attr.timestamp) ||
+                !self.is_interop_valid(&tx, block_attr.timestamp)
+            {
+                trace!(target: "payload_builder", tx_hash=?tx.hash(), "skipping transaction with invalid cross-chain messages");
+                best_txs.mark_invalid(tx.sender(), tx.nonce());
+                continue
+            }
+
             let tx = tx.into_consensus();
             if info.is_tx_over_limits(tx.tx(), block_gas_limit, tx_da_limit, block_da_limit) {
                 // we can't fit this transaction into the block, so we need to mark it as
@@ -1060,4 +1193,50 @@ where
 
         Ok(None)
     }
+
+    /// Returns `true` if the storage of all known accounts of the [`TransactionConditional`]
+    /// matches the in-progress state of the payload.
+    ///
+    /// Unlike the pool, which checks the known accounts against the latest block, this includes
+    /// the changes of the transactions that were already executed in this payload.
+    pub fn known_accounts_match<DB, P>(
+        &self,
+        conditional: &TransactionConditional,
+        db: &mut State<DB>,
+    ) -> Result<bool, ProviderError>
+    where
+        DB: Database<Error = ProviderError> + AsRef<P>,
+        P: StorageRootProvider,
+    {
+        for (address, storage) in &conditional.known_accounts {
+            match storage {
+                AccountStorage::RootHash(expected) => {
+                    // apply the storage changes of this payload to the parent's storage
+                    let hashed_storage = db
+                        .cache
+                        .accounts
+                        .get(address)
+                        .map(|account| {
+                            HashedStorage::from_plain_storage(
+                                account.status,
+                                account.account.iter().flat_map(|account| account.storage.iter()),
+                            )
+                        })
+                        .unwrap_or_default();
+                    if db.database.as_ref().storage_root(*address, hashed_storage)? != *expected {
+                        return Ok(false)
+                    }
+                }
+                AccountStorage::Slots(slots) => {
+                    for (slot, expected) in slots {
+                        let value = db.storage(*address, U256::from_be_bytes(slot.0))?;
+                        if B256::from(value) != *expected {
+                            return Ok(false)
+                        }
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
 }
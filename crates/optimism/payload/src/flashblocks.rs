@@ -0,0 +1,426 @@
+//! Flashblocks: partial payloads that are streamed while a block is being built.
+//!
+//! Instead of only yielding the complete payload once it is resolved, the sequencer publishes a
+//! flashblock of the best payload built so far at a fixed interval. Each flashblock contains the
+//! transactions and receipts that were added since the previous one, together with the roots of
+//! the partial block.
+//!
+//! Published transactions are preconfirmed: subsequent builds of the same payload execute them
+//! first, and builds that don't extend the published transactions are discarded, so the final
+//! block always extends all flashblocks that were published for it.
+
+use crate::OpBuiltPayload;
+use alloy_consensus::BlockHeader;
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{Address, Bloom, Bytes, B256, U256};
+use alloy_rpc_types_engine::PayloadId;
+use parking_lot::Mutex;
+use reth_primitives::{NodePrimitives, Recovered};
+use reth_primitives_traits::{BlockBody as _, SignedTransaction};
+use serde::{Deserialize, Serialize};
+use std::{future::Future, sync::Arc, time::Duration};
+use tokio::{sync::broadcast, time::MissedTickBehavior};
+use tracing::{debug, warn};
+
+/// The default interval at which flashblocks are published.
+pub const DEFAULT_FLASHBLOCKS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Number of flashblocks a subscriber can lag behind before it misses flashblocks.
+const FLASHBLOCKS_CHANNEL_SIZE: usize = 64;
+
+/// Fields of the block that are fixed once the payload job started.
+///
+/// These are only included in the first flashblock of a payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionPayloadBaseV1 {
+    /// Hash of the parent block.
+    pub parent_hash: B256,
+    /// Recipient of the priority fees.
+    pub fee_recipient: Address,
+    /// The `prevRandao` of the block.
+    pub prev_randao: B256,
+    /// Number of the block.
+    pub block_number: u64,
+    /// Gas limit of the block.
+    pub gas_limit: u64,
+    /// Timestamp of the block.
+    pub timestamp: u64,
+    /// Extra data of the block.
+    pub extra_data: Bytes,
+    /// Base fee of the block.
+    pub base_fee_per_gas: U256,
+}
+
+/// The changes of a payload since the previous flashblock.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionPayloadFlashblockDeltaV1<R> {
+    /// State root of the partial block.
+    pub state_root: B256,
+    /// Receipts root of the partial block.
+    pub receipts_root: B256,
+    /// Logs bloom of the partial block.
+    pub logs_bloom: Bloom,
+    /// Cumulative gas used by the partial block.
+    pub gas_used: u64,
+    /// Hash of the partial block.
+    pub block_hash: B256,
+    /// EIP-2718 encoded transactions that were added since the previous flashblock.
+    pub transactions: Vec<Bytes>,
+    /// Receipts of the transactions that were added since the previous flashblock.
+    pub receipts: Vec<R>,
+}
+
+/// A partial payload that is published while the payload is being built.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlashblocksPayloadV1<R> {
+    /// Identifier of the payload the flashblock belongs to.
+    pub payload_id: PayloadId,
+    /// Index of the flashblock within the payload, starting at 0.
+    pub index: u64,
+    /// Fixed fields of the block, only set for the first flashblock of a payload.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<ExecutionPayloadBaseV1>,
+    /// The changes since the previous flashblock.
+    pub diff: ExecutionPayloadFlashblockDeltaV1<R>,
+}
+
+/// Publishes flashblocks for the payloads built by the
+/// [`OpPayloadBuilder`](crate::OpPayloadBuilder).
+///
+/// The payload builder records every better payload via [`FlashblocksPublisher::on_new_payload`]
+/// and the task returned by [`FlashblocksPublisher::publish_on_interval`] publishes the best
+/// payload so far on a fixed cadence.
+///
+/// This type is shareable: subscribers, e.g. the `flashblocks` RPC namespace, receive the
+/// flashblocks via [`FlashblocksPublisher::subscribe`].
+#[derive(Debug, Clone)]
+pub struct FlashblocksPublisher<N: NodePrimitives> {
+    inner: Arc<FlashblocksPublisherInner<N>>,
+}
+
+impl<N: NodePrimitives> FlashblocksPublisher<N> {
+    /// Creates a new publisher that publishes a flashblock every `interval`.
+    pub fn new(interval: Duration) -> Self {
+        let (sender, _) = broadcast::channel(FLASHBLOCKS_CHANNEL_SIZE);
+        Self {
+            inner: Arc::new(FlashblocksPublisherInner {
+                interval,
+                sender,
+                payload: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Returns the interval at which flashblocks are published.
+    pub fn interval(&self) -> Duration {
+        self.inner.interval
+    }
+
+    /// Returns a new receiver for all flashblocks published from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<FlashblocksPayloadV1<N::Receipt>>> {
+        self.inner.sender.subscribe()
+    }
+
+    /// Returns the transactions that were already published for the given payload, excluding the
+    /// first `sequencer_txs` transactions that were forced by the payload attributes.
+    ///
+    /// Builds of the payload must execute these transactions before any other pool transactions.
+    pub fn preconfirmed_transactions(
+        &self,
+        payload_id: PayloadId,
+        sequencer_txs: usize,
+    ) -> Vec<Recovered<N::SignedTx>> {
+        match self.inner.payload.lock().as_ref() {
+            Some(payload) if payload.payload_id == payload_id => {
+                payload.published.iter().skip(sequencer_txs).cloned().collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Records a better payload of the payload job, which is published with the next flashblock.
+    ///
+    /// Returns `false` and discards the payload if it doesn't extend the transactions that were
+    /// already published for the payload job. The payload builder must not use such a payload,
+    /// since the final block has to include all published transactions.
+    pub fn on_new_payload(&self, payload: &OpBuiltPayload<N>) -> bool {
+        let mut state = self.inner.payload.lock();
+        match state.as_mut() {
+            Some(current) if current.payload_id == payload.id() => {
+                if !extends(&current.published, payload) {
+                    warn!(target: "payload_builder", id=%payload.id(), "payload doesn't extend published flashblocks");
+                    return false
+                }
+                current.best = Some(payload.clone());
+            }
+            _ => {
+                // first payload of a new payload job
+                *state = Some(PublishedPayload {
+                    payload_id: payload.id(),
+                    next_index: 0,
+                    published: Vec::new(),
+                    best: Some(payload.clone()),
+                })
+            }
+        }
+        true
+    }
+
+    /// Publishes the transactions the best payload added since the previous flashblock.
+    ///
+    /// Does nothing if there is no payload or nothing new to publish.
+    pub fn publish(&self) {
+        let mut state = self.inner.payload.lock();
+        let Some(current) = state.as_mut() else { return };
+        let Some(payload) = current.best.take() else { return };
+        let Some(executed) = payload.executed_block.as_ref() else { return };
+        let block = executed.recovered_block();
+        let Some(receipts) = executed.execution_output.receipts.first() else { return };
+
+        let start = current.published.len();
+        let transactions = block.body().transactions();
+        if current.next_index > 0 && transactions.len() == start {
+            // nothing to publish
+            return
+        }
+
+        let header = block.header();
+        let base = (current.next_index == 0).then(|| ExecutionPayloadBaseV1 {
+            parent_hash: header.parent_hash(),
+            fee_recipient: header.beneficiary(),
+            prev_randao: header.mix_hash().unwrap_or_default(),
+            block_number: header.number(),
+            gas_limit: header.gas_limit(),
+            timestamp: header.timestamp(),
+            extra_data: header.extra_data().clone(),
+            base_fee_per_gas: U256::from(header.base_fee_per_gas().unwrap_or_default()),
+        });
+
+        let flashblock = FlashblocksPayloadV1 {
+            payload_id: payload.id(),
+            index: current.next_index,
+            base,
+            diff: ExecutionPayloadFlashblockDeltaV1 {
+                state_root: header.state_root(),
+                receipts_root: header.receipts_root(),
+                logs_bloom: header.logs_bloom(),
+                gas_used: header.gas_used(),
+                block_hash: block.hash(),
+                transactions: transactions[start..]
+                    .iter()
+                    .map(|tx| tx.encoded_2718().into())
+                    .collect(),
+                receipts: receipts[start..].to_vec(),
+            },
+        };
+
+        current.published.extend(
+            block
+                .transactions_with_sender()
+                .skip(start)
+                .map(|(sender, tx)| Recovered::new_unchecked(tx.clone(), *sender)),
+        );
+        current.next_index += 1;
+
+        debug!(target: "payload_builder", id=%payload.id(), index=flashblock.index, txs=flashblock.diff.transactions.len(), "publishing flashblock");
+        let _ = self.inner.sender.send(Arc::new(flashblock));
+    }
+
+    /// Returns a future that publishes a flashblock of the best payload every
+    /// [`Self::interval`].
+    ///
+    /// The future never resolves and is expected to be spawned.
+    pub fn publish_on_interval(self) -> impl Future<Output = ()> + Send + 'static {
+        async move {
+            let mut interval = tokio::time::interval(self.interval());
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                self.publish();
+            }
+        }
+    }
+}
+
+/// Returns `true` if the block of the payload starts with the given published transactions.
+fn extends<N: NodePrimitives>(
+    published: &[Recovered<N::SignedTx>],
+    payload: &OpBuiltPayload<N>,
+) -> bool {
+    let transactions = payload.block().body().transactions();
+    transactions.len() >= published.len() &&
+        published
+            .iter()
+            .zip(transactions)
+            .all(|(published, tx)| published.tx_hash() == tx.tx_hash())
+}
+
+#[derive(Debug)]
+struct FlashblocksPublisherInner<N: NodePrimitives> {
+    /// Interval at which flashblocks are published.
+    interval: Duration,
+    /// Sender half of the flashblocks channel.
+    sender: broadcast::Sender<Arc<FlashblocksPayloadV1<N::Receipt>>>,
+    /// The payload that is currently being published.
+    payload: Mutex<Option<PublishedPayload<N>>>,
+}
+
+/// Tracks the flashblocks that were published for a payload.
+#[derive(Debug)]
+struct PublishedPayload<N: NodePrimitives> {
+    /// Identifier of the payload.
+    payload_id: PayloadId,
+    /// Index of the next flashblock.
+    next_index: u64,
+    /// All transactions of the block that were published so far.
+    published: Vec<Recovered<N::SignedTx>>,
+    /// The best payload that wasn't published yet.
+    best: Option<OpBuiltPayload<N>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{BlockBody, Header, TxLegacy};
+    use alloy_primitives::PrimitiveSignature as Signature;
+    use reth_chain_state::{ExecutedBlock, ExecutedBlockWithTrieUpdates};
+    use reth_execution_types::ExecutionOutcome;
+    use reth_optimism_primitives::{OpBlock, OpPrimitives, OpReceipt, OpTransactionSigned};
+    use reth_primitives_traits::RecoveredBlock;
+
+    /// Returns a payload of the given job with a transaction for each of the given nonces.
+    fn payload(payload_id: PayloadId, nonces: &[u64]) -> OpBuiltPayload<OpPrimitives> {
+        let transactions = nonces
+            .iter()
+            .map(|nonce| {
+                let tx = TxLegacy { nonce: *nonce, ..Default::default() };
+                OpTransactionSigned::new_unhashed(tx.into(), Signature::test_signature())
+            })
+            .collect::<Vec<_>>();
+        let receipts = transactions.iter().map(|_| OpReceipt::Legacy(Default::default())).collect();
+        let senders = vec![Address::ZERO; transactions.len()];
+        let block = OpBlock {
+            header: Header { number: 1, ..Default::default() },
+            body: BlockBody { transactions, ommers: Vec::new(), withdrawals: None },
+        };
+        let block = RecoveredBlock::new_unhashed(block, senders);
+        let sealed_block = Arc::new(block.clone_sealed_block());
+        let executed_block = ExecutedBlockWithTrieUpdates {
+            block: ExecutedBlock {
+                recovered_block: Arc::new(block),
+                execution_output: Arc::new(ExecutionOutcome {
+                    receipts: vec![receipts],
+                    ..Default::default()
+                }),
+                hashed_state: Default::default(),
+            },
+            trie: Default::default(),
+        };
+        OpBuiltPayload::new(payload_id, sealed_block, U256::ZERO, Some(executed_block))
+    }
+
+    #[test]
+    fn publish_best_payload() {
+        let publisher = FlashblocksPublisher::<OpPrimitives>::new(DEFAULT_FLASHBLOCKS_INTERVAL);
+        let mut flashblocks = publisher.subscribe();
+        let id = PayloadId::new([1; 8]);
+
+        // nothing to publish yet
+        publisher.publish();
+        assert!(flashblocks.try_recv().is_err());
+
+        // only the best payload of an interval is published
+        assert!(publisher.on_new_payload(&payload(id, &[0])));
+        assert!(publisher.on_new_payload(&payload(id, &[0, 1])));
+        publisher.publish();
+        let flashblock = flashblocks.try_recv().unwrap();
+        assert_eq!(flashblock.index, 0);
+        assert_eq!(flashblock.base.as_ref().unwrap().block_number, 1);
+        assert_eq!(flashblock.diff.transactions.len(), 2);
+        assert_eq!(flashblock.diff.receipts.len(), 2);
+        assert_eq!(publisher.preconfirmed_transactions(id, 0).len(), 2);
+        assert_eq!(publisher.preconfirmed_transactions(id, 1).len(), 1);
+
+        // no new payload since the previous flashblock
+        publisher.publish();
+        assert!(flashblocks.try_recv().is_err());
+
+        // the next flashblock only contains the new transactions
+        assert!(publisher.on_new_payload(&payload(id, &[0, 1, 2])));
+        publisher.publish();
+        let flashblock = flashblocks.try_recv().unwrap();
+        assert_eq!(flashblock.index, 1);
+        assert!(flashblock.base.is_none());
+        assert_eq!(flashblock.diff.transactions.len(), 1);
+
+        // a new payload job starts over
+        let next_id = PayloadId::new([2; 8]);
+        assert!(publisher.on_new_payload(&payload(next_id, &[5])));
+        assert!(publisher.preconfirmed_transactions(id, 0).is_empty());
+        publisher.publish();
+        let flashblock = flashblocks.try_recv().unwrap();
+        assert_eq!(flashblock.payload_id, next_id);
+        assert_eq!(flashblock.index, 0);
+    }
+
+    #[test]
+    fn reject_payload_not_extending_published() {
+        let publisher = FlashblocksPublisher::<OpPrimitives>::new(DEFAULT_FLASHBLOCKS_INTERVAL);
+        let mut flashblocks = publisher.subscribe();
+        let id = PayloadId::new([1; 8]);
+
+        assert!(publisher.on_new_payload(&payload(id, &[0, 1])));
+        publisher.publish();
+        flashblocks.try_recv().unwrap();
+
+        // a payload that dropped or replaced a published transaction is rejected
+        assert!(!publisher.on_new_payload(&payload(id, &[0])));
+        assert!(!publisher.on_new_payload(&payload(id, &[0, 2, 3])));
+        publisher.publish();
+        assert!(flashblocks.try_recv().is_err());
+
+        // a payload that extends the published transactions is accepted
+        assert!(publisher.on_new_payload(&payload(id, &[0, 1, 3])));
+        publisher.publish();
+        let flashblock = flashblocks.try_recv().unwrap();
+        assert_eq!(flashblock.index, 1);
+        assert_eq!(flashblock.diff.transactions.len(), 1);
+    }
+
+    #[test]
+    fn serde_flashblock() {
+        let flashblock = FlashblocksPayloadV1::<OpReceipt> {
+            payload_id: PayloadId::new([1; 8]),
+            index: 0,
+            base: Some(ExecutionPayloadBaseV1 {
+                parent_hash: B256::with_last_byte(1),
+                fee_recipient: Address::with_last_byte(2),
+                prev_randao: B256::with_last_byte(3),
+                block_number: 10,
+                gas_limit: 30_000_000,
+                timestamp: 1_700_000_000,
+                extra_data: Bytes::new(),
+                base_fee_per_gas: U256::from(7),
+            }),
+            diff: ExecutionPayloadFlashblockDeltaV1 {
+                state_root: B256::with_last_byte(4),
+                receipts_root: B256::with_last_byte(5),
+                logs_bloom: Bloom::ZERO,
+                gas_used: 21_000,
+                block_hash: B256::with_last_byte(6),
+                transactions: vec![Bytes::from_static(&[0x7e, 0x01])],
+                receipts: vec![OpReceipt::Eip1559(Default::default())],
+            },
+        };
+
+        let json = serde_json::to_string(&flashblock).unwrap();
+        assert_eq!(
+            serde_json::from_str::<FlashblocksPayloadV1<OpReceipt>>(&json).unwrap(),
+            flashblock
+        );
+
+        let next = FlashblocksPayloadV1 { index: 1, base: None, ..flashblock };
+        let json = serde_json::to_value(&next).unwrap();
+        assert!(json.get("base").is_none());
+    }
+}
@@ -14,6 +14,7 @@
 pub mod builder;
 pub use builder::OpPayloadBuilder;
 pub mod error;
+pub mod flashblocks;
 pub mod payload;
 pub use payload::{OpBuiltPayload, OpPayloadAttributes, OpPayloadBuilderAttributes};
 mod traits;
@@ -3,7 +3,7 @@ use alloy_consensus::{
     RlpEncodableReceipt, TxReceipt, Typed2718,
 };
 use alloy_primitives::{Bloom, Log};
-use alloy_rlp::{BufMut, Decodable, Header};
+use alloy_rlp::{BufMut, Decodable, Encodable, Header};
 use op_alloy_consensus::{OpDepositReceipt, OpTxType};
 use reth_primitives_traits::InMemorySize;
 
@@ -75,6 +75,45 @@ impl OpReceipt {
         Header { list: true, payload_length: self.rlp_encoded_fields_length(bloom) }
     }
 
+    /// Returns length of RLP-encoded receipt fields without the bloom filter and without an RLP
+    /// header.
+    pub fn rlp_encoded_fields_length_without_bloom(&self) -> usize {
+        let receipt = self.as_receipt();
+        let mut len = (self.tx_type() as u8).length() +
+            receipt.status.length() +
+            receipt.cumulative_gas_used.length() +
+            receipt.logs.length();
+        if let Self::Deposit(receipt) = self {
+            if let Some(deposit_nonce) = receipt.deposit_nonce {
+                len += deposit_nonce.length();
+            }
+            if let Some(deposit_receipt_version) = receipt.deposit_receipt_version {
+                len += deposit_receipt_version.length();
+            }
+        }
+        len
+    }
+
+    /// RLP-encodes receipt fields without the bloom filter and without an RLP header.
+    ///
+    /// This is the `eth/69` network encoding: `[tx-type, status, cumulative-gas, logs]`, followed
+    /// by the deposit nonce and receipt version of deposit receipts, if set.
+    pub fn rlp_encode_fields_without_bloom(&self, out: &mut dyn BufMut) {
+        let receipt = self.as_receipt();
+        (self.tx_type() as u8).encode(out);
+        receipt.status.encode(out);
+        receipt.cumulative_gas_used.encode(out);
+        receipt.logs.encode(out);
+        if let Self::Deposit(receipt) = self {
+            if let Some(deposit_nonce) = receipt.deposit_nonce {
+                deposit_nonce.encode(out);
+            }
+            if let Some(deposit_receipt_version) = receipt.deposit_receipt_version {
+                deposit_receipt_version.encode(out);
+            }
+        }
+    }
+
     /// RLP-decodes the receipt from the provided buffer. This does not expect a type byte or
     /// network header.
     pub fn rlp_decode_inner(
@@ -173,6 +212,61 @@ impl RlpDecodableReceipt for OpReceipt {
     }
 }
 
+/// RLP-encodes the receipt without the bloom filter, as used by `eth/69` `Receipts` messages.
+impl Encodable for OpReceipt {
+    fn encode(&self, out: &mut dyn BufMut) {
+        Header { list: true, payload_length: self.rlp_encoded_fields_length_without_bloom() }
+            .encode(out);
+        self.rlp_encode_fields_without_bloom(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.rlp_encoded_fields_length_without_bloom();
+        Header { list: true, payload_length }.length() + payload_length
+    }
+}
+
+impl Decodable for OpReceipt {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+
+        let remaining = buf.len();
+
+        let tx_type = OpTxType::decode(buf)?;
+        let status = Decodable::decode(buf)?;
+        let cumulative_gas_used = Decodable::decode(buf)?;
+        let logs = Decodable::decode(buf)?;
+        let inner = Receipt { status, cumulative_gas_used, logs };
+
+        let this = match tx_type {
+            OpTxType::Legacy => Self::Legacy(inner),
+            OpTxType::Eip2930 => Self::Eip2930(inner),
+            OpTxType::Eip1559 => Self::Eip1559(inner),
+            OpTxType::Eip7702 => Self::Eip7702(inner),
+            OpTxType::Deposit => {
+                let consumed = remaining - buf.len();
+                let deposit_nonce = (consumed < header.payload_length)
+                    .then(|| Decodable::decode(buf))
+                    .transpose()?;
+                let consumed = remaining - buf.len();
+                let deposit_receipt_version = (consumed < header.payload_length)
+                    .then(|| Decodable::decode(buf))
+                    .transpose()?;
+                Self::Deposit(OpDepositReceipt { inner, deposit_nonce, deposit_receipt_version })
+            }
+        };
+
+        if buf.len() + header.payload_length != remaining {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+
+        Ok(this)
+    }
+}
+
 impl TxReceipt for OpReceipt {
     type Log = Log;
 
@@ -454,6 +548,42 @@ mod tests {
         assert_eq!(buf, &data[..]);
     }
 
+    #[test]
+    fn deposit_receipt_without_bloom_roundtrip() {
+        let data = hex!("cb7e0182b741c0833d3bbf01");
+
+        let expected = OpReceipt::Deposit(OpDepositReceipt {
+            inner: Receipt {
+                status: Eip658Value::Eip658(true),
+                cumulative_gas_used: 46913,
+                logs: vec![],
+            },
+            deposit_nonce: Some(4012991),
+            deposit_receipt_version: Some(1),
+        });
+
+        let receipt = OpReceipt::decode(&mut &data[..]).unwrap();
+        assert_eq!(receipt, expected);
+
+        let mut buf = Vec::with_capacity(expected.length());
+        expected.encode(&mut buf);
+        assert_eq!(buf, &data[..]);
+
+        // pre-regolith deposit receipts have neither nonce nor version
+        let expected = OpReceipt::Deposit(OpDepositReceipt {
+            inner: Receipt {
+                status: Eip658Value::Eip658(true),
+                cumulative_gas_used: 46913,
+                logs: vec![],
+            },
+            deposit_nonce: None,
+            deposit_receipt_version: None,
+        });
+        let mut buf = Vec::new();
+        expected.encode(&mut buf);
+        assert_eq!(OpReceipt::decode(&mut &buf[..]).unwrap(), expected);
+    }
+
     #[test]
     fn gigantic_receipt() {
         let receipt = OpReceipt::Legacy(Receipt {
@@ -103,6 +103,9 @@ pub enum TxConditionalErr {
     /// Invalid conditional parameters
     #[error("invalid conditional parameters")]
     InvalidCondition,
+    /// The storage of the known accounts of the conditional does not match the state
+    #[error("known accounts do not match the state")]
+    KnownAccountsMismatch,
 }
 
 impl From<TxConditionalErr> for jsonrpsee_types::error::ErrorObject<'static> {
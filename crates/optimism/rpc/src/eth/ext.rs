@@ -6,15 +6,34 @@ use alloy_eips::BlockNumberOrTag;
 use alloy_primitives::{Bytes, B256};
 use alloy_rpc_types_eth::erc4337::TransactionConditional;
 use jsonrpsee_core::RpcResult;
-use reth_optimism_txpool::conditional::MaybeConditionalTransaction;
+use reth_metrics::{
+    metrics::{Counter, Histogram},
+    Metrics,
+};
+use reth_optimism_txpool::conditional::{
+    known_accounts_match, MaybeConditionalTransaction, DEFAULT_MAX_CONDITIONAL_COST,
+};
 use reth_provider::{BlockReaderIdExt, StateProviderFactory};
 use reth_rpc_eth_api::L2EthApiExtServer;
-use reth_rpc_eth_types::utils::recover_raw_transaction;
+use reth_rpc_eth_types::{utils::recover_raw_transaction, EthApiError};
 use reth_transaction_pool::{PoolTransaction, TransactionOrigin, TransactionPool};
 use std::sync::Arc;
 
-/// Maximum execution const for conditional transactions.
-const MAX_CONDITIONAL_EXECUTION_COST: u64 = 5000;
+/// Metrics for `eth_sendRawTransactionConditional`.
+#[derive(Metrics)]
+#[metrics(scope = "optimism_rpc.eth_ext")]
+struct OpEthExtApiMetrics {
+    /// The cost of submitted transaction conditionals.
+    conditional_cost: Histogram,
+    /// Number of conditional transactions rejected because their cost exceeds the limit.
+    rejected_cost_exceeded: Counter,
+    /// Number of conditional transactions rejected because the conditional is not met.
+    rejected_invalid_condition: Counter,
+    /// Number of conditional transactions forwarded to the sequencer.
+    forwarded: Counter,
+    /// Number of conditional transactions added to the local pool.
+    added_to_pool: Counter,
+}
 
 #[derive(Debug)]
 struct OpEthExtApiInner<Pool, Provider> {
@@ -22,11 +41,13 @@ struct OpEthExtApiInner<Pool, Provider> {
     pool: Pool,
     /// The provider type used to interact with the node.
     provider: Provider,
+    /// Metrics for submitted conditional transactions.
+    metrics: OpEthExtApiMetrics,
 }
 
 impl<Pool, Provider> OpEthExtApiInner<Pool, Provider> {
     fn new(pool: Pool, provider: Provider) -> Self {
-        Self { pool, provider }
+        Self { pool, provider, metrics: OpEthExtApiMetrics::default() }
     }
 
     #[inline]
@@ -49,6 +70,8 @@ pub struct OpEthExtApi<Pool, Provider> {
     /// Sequencer client, configured to forward submitted transactions to sequencer of given OP
     /// network.
     sequencer_client: Option<SequencerClient>,
+    /// The maximum accepted cost of a transaction conditional.
+    max_conditional_cost: u64,
     inner: Arc<OpEthExtApiInner<Pool, Provider>>,
 }
 
@@ -59,7 +82,13 @@ where
     /// Creates a new [`OpEthExtApi`].
     pub fn new(sequencer_client: Option<SequencerClient>, pool: Pool, provider: Provider) -> Self {
         let inner = Arc::new(OpEthExtApiInner::new(pool, provider));
-        Self { sequencer_client, inner }
+        Self { sequencer_client, max_conditional_cost: DEFAULT_MAX_CONDITIONAL_COST, inner }
+    }
+
+    /// Sets the maximum accepted cost of a transaction conditional.
+    pub const fn with_max_conditional_cost(mut self, max_conditional_cost: u64) -> Self {
+        self.max_conditional_cost = max_conditional_cost;
+        self
     }
 
     /// Returns the configured sequencer client, if any.
@@ -76,36 +105,35 @@ where
     fn provider(&self) -> &Provider {
         self.inner.provider()
     }
+
+    #[inline]
+    fn metrics(&self) -> &OpEthExtApiMetrics {
+        &self.inner.metrics
+    }
 }
 
-#[async_trait::async_trait]
-impl<Pool, Provider> L2EthApiExtServer for OpEthExtApi<Pool, Provider>
+impl<Pool, Provider> OpEthExtApi<Pool, Provider>
 where
     Provider: BlockReaderIdExt + StateProviderFactory + Clone + 'static,
-    Pool: TransactionPool<Transaction: MaybeConditionalTransaction> + 'static,
 {
-    async fn send_raw_transaction_conditional(
+    /// Validates the conditional against the latest header and state.
+    fn validate_conditional(
         &self,
-        bytes: Bytes,
-        condition: TransactionConditional,
-    ) -> RpcResult<B256> {
+        condition: &TransactionConditional,
+    ) -> Result<(), OpEthApiError> {
         // calculate and validate cost
         let cost = condition.cost();
-        if cost > MAX_CONDITIONAL_EXECUTION_COST {
+        self.metrics().conditional_cost.record(cost as f64);
+        if cost > self.max_conditional_cost {
+            self.metrics().rejected_cost_exceeded.increment(1);
             return Err(TxConditionalErr::ConditionalCostExceeded.into());
         }
 
-        let recovered_tx = recover_raw_transaction(&bytes).map_err(|_| {
-            OpEthApiError::Eth(reth_rpc_eth_types::EthApiError::FailedToDecodeSignedTransaction)
-        })?;
-
-        let mut tx = <Pool as TransactionPool>::Transaction::from_pooled(recovered_tx);
-
         // get current header
         let header_not_found = || {
-            OpEthApiError::Eth(reth_rpc_eth_types::EthApiError::HeaderNotFound(
-                alloy_eips::BlockId::Number(BlockNumberOrTag::Latest),
-            ))
+            OpEthApiError::Eth(EthApiError::HeaderNotFound(alloy_eips::BlockId::Number(
+                BlockNumberOrTag::Latest,
+            )))
         };
         let header = self
             .provider()
@@ -114,13 +142,48 @@ where
             .ok_or_else(header_not_found)?;
 
         // check condition against header
-        if !condition.has_exceeded_block_number(header.header().number()) ||
-            !condition.has_exceeded_timestamp(header.header().timestamp())
+        if condition.has_exceeded_block_number(header.header().number()) ||
+            condition.has_exceeded_timestamp(header.header().timestamp())
         {
+            self.metrics().rejected_invalid_condition.increment(1);
             return Err(TxConditionalErr::InvalidCondition.into());
         }
 
-        // TODO: check condition against state
+        // check condition against state
+        if !condition.known_accounts.is_empty() {
+            let state = self
+                .provider()
+                .state_by_block_hash(header.hash())
+                .map_err(|err| OpEthApiError::Eth(err.into()))?;
+            if !known_accounts_match(condition, &*state)
+                .map_err(|err| OpEthApiError::Eth(err.into()))?
+            {
+                self.metrics().rejected_invalid_condition.increment(1);
+                return Err(TxConditionalErr::KnownAccountsMismatch.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<Pool, Provider> L2EthApiExtServer for OpEthExtApi<Pool, Provider>
+where
+    Provider: BlockReaderIdExt + StateProviderFactory + Clone + 'static,
+    Pool: TransactionPool<Transaction: MaybeConditionalTransaction> + 'static,
+{
+    async fn send_raw_transaction_conditional(
+        &self,
+        bytes: Bytes,
+        condition: TransactionConditional,
+    ) -> RpcResult<B256> {
+        self.validate_conditional(&condition)?;
+
+        let recovered_tx = recover_raw_transaction(&bytes)
+            .map_err(|_| OpEthApiError::Eth(EthApiError::FailedToDecodeSignedTransaction))?;
+
+        let mut tx = <Pool as TransactionPool>::Transaction::from_pooled(recovered_tx);
 
         if let Some(sequencer) = self.sequencer_client() {
             // If we have a sequencer client, forward the transaction
@@ -128,13 +191,18 @@ where
                 .forward_raw_transaction_conditional(bytes.as_ref(), condition)
                 .await
                 .map_err(OpEthApiError::Sequencer)?;
+            self.metrics().forwarded.increment(1);
             Ok(*tx.hash())
         } else {
-            // otherwise, add to pool with the appended conditional
+            // otherwise, add to pool with the appended conditional, it is re-checked on every
+            // canonical update and before it is included in a payload
             tx.set_conditional(condition);
-            let hash = self.pool().add_transaction(TransactionOrigin::External, tx).await.map_err(
-                |e| OpEthApiError::Eth(reth_rpc_eth_types::EthApiError::PoolError(e.into())),
-            )?;
+            let hash = self
+                .pool()
+                .add_transaction(TransactionOrigin::External, tx)
+                .await
+                .map_err(|e| OpEthApiError::Eth(EthApiError::PoolError(e.into())))?;
+            self.metrics().added_to_pool.increment(1);
             Ok(hash)
         }
     }
@@ -39,7 +39,7 @@ use reth_tasks::{
 use reth_transaction_pool::TransactionPool;
 use std::{fmt, sync::Arc};
 
-use crate::{OpEthApiError, SequencerClient};
+use crate::{OpEthApiError, PendingFlashblocks, SequencerClient};
 
 /// Adapter for [`EthApiInner`], which holds all the data required to serve core `eth_` API.
 pub type EthApiNodeBackend<N> = EthApiInner<
@@ -89,6 +89,11 @@ where
         self.inner.sequencer_client()
     }
 
+    /// Returns the pending flashblocks of the sequencer, if configured.
+    pub fn flashblocks(&self) -> Option<&PendingFlashblocks> {
+        self.inner.flashblocks()
+    }
+
     /// Build a [`OpEthApi`] using [`OpEthApiBuilder`].
     pub const fn builder() -> OpEthApiBuilder {
         OpEthApiBuilder::new()
@@ -281,6 +286,8 @@ struct OpEthApiInner<N: OpNodeCore> {
     /// Sequencer client, configured to forward submitted transactions to sequencer of given OP
     /// network.
     sequencer_client: Option<SequencerClient>,
+    /// Pending flashblocks of the sequencer, used to serve preconfirmations.
+    flashblocks: Option<PendingFlashblocks>,
 }
 
 impl<N: OpNodeCore> OpEthApiInner<N> {
@@ -293,6 +300,11 @@ impl<N: OpNodeCore> OpEthApiInner<N> {
     const fn sequencer_client(&self) -> Option<&SequencerClient> {
         self.sequencer_client.as_ref()
     }
+
+    /// Returns the pending flashblocks of the sequencer, if configured.
+    const fn flashblocks(&self) -> Option<&PendingFlashblocks> {
+        self.flashblocks.as_ref()
+    }
 }
 
 /// A type that knows how to build a [`OpEthApi`].
@@ -301,12 +313,14 @@ pub struct OpEthApiBuilder {
     /// Sequencer client, configured to forward submitted transactions to sequencer of given OP
     /// network.
     sequencer_client: Option<SequencerClient>,
+    /// Pending flashblocks of the sequencer, used to serve preconfirmations.
+    flashblocks: Option<PendingFlashblocks>,
 }
 
 impl OpEthApiBuilder {
     /// Creates a [`OpEthApiBuilder`] instance from [`EthApiBuilderCtx`].
     pub const fn new() -> Self {
-        Self { sequencer_client: None }
+        Self { sequencer_client: None, flashblocks: None }
     }
 
     /// With a [`SequencerClient`].
@@ -314,6 +328,12 @@ impl OpEthApiBuilder {
         self.sequencer_client = sequencer_client;
         self
     }
+
+    /// With the [`PendingFlashblocks`] of the sequencer.
+    pub fn with_flashblocks(mut self, flashblocks: Option<PendingFlashblocks>) -> Self {
+        self.flashblocks = flashblocks;
+        self
+    }
 }
 
 impl OpEthApiBuilder {
@@ -350,7 +370,11 @@ impl OpEthApiBuilder {
         );
 
         OpEthApi {
-            inner: Arc::new(OpEthApiInner { eth_api, sequencer_client: self.sequencer_client }),
+            inner: Arc::new(OpEthApiInner {
+                eth_api,
+                sequencer_client: self.sequencer_client,
+                flashblocks: self.flashblocks,
+            }),
         }
     }
 }
@@ -23,7 +23,7 @@ use reth_rpc_eth_api::{
     types::RpcTypes,
     EthApiTypes, FromEthApiError, FromEvmError, RpcNodeCore,
 };
-use reth_rpc_eth_types::{EthApiError, PendingBlock};
+use reth_rpc_eth_types::{EthApiError, PendingBlock, PendingBlockEnvOrigin};
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
 use revm::{
     context::BlockEnv,
@@ -73,6 +73,13 @@ where
         )>,
         Self::Error,
     > {
+        if let PendingBlockEnvOrigin::ActualPending(block, receipts) =
+            self.pending_block_env_and_cfg()?.origin
+        {
+            // the pending block received from the CL or built by the local payload builder
+            return Ok(Some((block, receipts)))
+        }
+
         // See: <https://github.com/ethereum-optimism/op-geth/blob/f2e69450c6eec9c35d56af91389a1c47737206ca/miner/worker.go#L367-L375>
         let latest = self
             .provider()
@@ -1,29 +1,39 @@
 //! Loads and formats OP transaction RPC response.
 
 use alloy_consensus::Transaction as _;
+use alloy_eips::BlockNumHash;
 use alloy_primitives::{Bytes, PrimitiveSignature as Signature, Sealable, Sealed, B256};
 use alloy_rpc_types_eth::TransactionInfo;
 use op_alloy_consensus::OpTxEnvelope;
 use op_alloy_rpc_types::{OpTransactionRequest, Transaction};
+use reth_chainspec::ChainSpecProvider;
 use reth_node_api::FullNodeComponents;
+use reth_optimism_chainspec::OpChainSpec;
 use reth_optimism_primitives::{OpReceipt, OpTransactionSigned};
 use reth_primitives::Recovered;
 use reth_provider::{
-    BlockReader, BlockReaderIdExt, ProviderTx, ReceiptProvider, TransactionsProvider,
+    BlockNumReader, BlockReader, BlockReaderIdExt, ProviderTx, ReceiptProvider,
+    TransactionsProvider,
 };
 use reth_rpc_eth_api::{
-    helpers::{EthSigner, EthTransactions, LoadTransaction, SpawnBlocking},
-    FromEthApiError, FullEthApiTypes, RpcNodeCore, RpcNodeCoreExt, TransactionCompat,
+    helpers::{EthSigner, EthTransactions, LoadReceipt, LoadTransaction, SpawnBlocking},
+    FromEthApiError, FullEthApiTypes, RpcNodeCore, RpcNodeCoreExt, RpcReceipt, TransactionCompat,
 };
 use reth_rpc_eth_types::{utils::recover_raw_transaction, EthApiError};
-use reth_transaction_pool::{PoolTransaction, TransactionOrigin, TransactionPool};
+use reth_transaction_pool::{
+    admission::AdmissionSource, PoolTransaction, TransactionOrigin, TransactionPool,
+};
+use std::net::IpAddr;
 
 use crate::{eth::OpNodeCore, OpEthApi, OpEthApiError, SequencerClient};
 
 impl<N> EthTransactions for OpEthApi<N>
 where
     Self: LoadTransaction<Provider: BlockReaderIdExt>,
-    N: OpNodeCore<Provider: BlockReader<Transaction = ProviderTx<Self::Provider>>>,
+    N: OpNodeCore<
+        Provider: BlockReader<Transaction = ProviderTx<Self::Provider>>
+                      + ChainSpecProvider<ChainSpec = OpChainSpec>,
+    >,
 {
     fn signers(&self) -> &parking_lot::RwLock<Vec<Box<dyn EthSigner<ProviderTx<Self::Provider>>>>> {
         self.inner.eth_api.signers()
@@ -32,7 +42,11 @@ where
     /// Decodes and recovers the transaction and submits it to the pool.
     ///
     /// Returns the hash of the transaction.
-    async fn send_raw_transaction(&self, tx: Bytes) -> Result<B256, Self::Error> {
+    async fn send_raw_transaction_from(
+        &self,
+        tx: Bytes,
+        client: Option<IpAddr>,
+    ) -> Result<B256, Self::Error> {
         let recovered = recover_raw_transaction(&tx)?;
         let pool_transaction = <Self::Pool as TransactionPool>::Transaction::from_pooled(recovered);
 
@@ -46,14 +60,46 @@ where
         }
 
         // submit the transaction to the pool with a `Local` origin
-        let hash = self
-            .pool()
-            .add_transaction(TransactionOrigin::Local, pool_transaction)
-            .await
-            .map_err(Self::Error::from_eth_err)?;
+        let hash = match client {
+            Some(ip) => {
+                self.pool()
+                    .add_transaction_from(
+                        TransactionOrigin::Local,
+                        AdmissionSource::Remote(ip),
+                        pool_transaction,
+                    )
+                    .await
+            }
+            None => self.pool().add_transaction(TransactionOrigin::Local, pool_transaction).await,
+        }
+        .map_err(Self::Error::from_eth_err)?;
 
         Ok(hash)
     }
+
+    /// Returns the transaction receipt for the given hash.
+    ///
+    /// If the transaction isn't included in a block yet, this falls back to the preconfirmations
+    /// of the sequencer's pending flashblock, if configured and it builds on the latest block.
+    async fn transaction_receipt(
+        &self,
+        hash: B256,
+    ) -> Result<Option<RpcReceipt<Self::NetworkTypes>>, Self::Error>
+    where
+        Self: LoadReceipt + 'static,
+    {
+        if let Some((tx, meta, receipt)) = self.load_transaction_and_receipt(hash).await? {
+            return self.build_transaction_receipt(tx, meta, receipt).await.map(Some)
+        }
+
+        let Some(flashblocks) = self.inner.flashblocks() else { return Ok(None) };
+        let latest = self.provider().chain_info().map_err(Self::Error::from_eth_err)?;
+        flashblocks.transaction_receipt(
+            hash,
+            &self.provider().chain_spec(),
+            BlockNumHash::new(latest.best_number, latest.best_hash),
+        )
+    }
 }
 
 impl<N> LoadTransaction for OpEthApi<N>
@@ -0,0 +1,336 @@
+//! Support for flashblocks: streaming them from the sequencer and serving preconfirmations from
+//! them on RPC nodes.
+
+use crate::{OpEthApiError, OpReceiptBuilder};
+use alloy_consensus::transaction::TransactionMeta;
+use alloy_eips::{eip2718::Decodable2718, BlockNumHash};
+use alloy_primitives::B256;
+use alloy_rpc_types_engine::PayloadId;
+use jsonrpsee::{
+    core::{
+        client::{ClientError, Subscription, SubscriptionClientT},
+        rpc_params, SubscriptionResult,
+    },
+    proc_macros::rpc,
+    ws_client::WsClientBuilder,
+    PendingSubscriptionSink, SubscriptionMessage,
+};
+use jsonrpsee_core::async_trait;
+use op_alloy_rpc_types::OpTransactionReceipt;
+use parking_lot::RwLock;
+use reth_optimism_chainspec::OpChainSpec;
+use reth_optimism_payload_builder::flashblocks::{
+    ExecutionPayloadBaseV1, FlashblocksPayloadV1, FlashblocksPublisher,
+};
+use reth_optimism_primitives::{OpPrimitives, OpReceipt, OpTransactionSigned};
+use reth_primitives_traits::SignedTransaction;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, warn};
+
+/// Delay before reconnecting to the sequencer after the flashblocks subscription ended.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// The `flashblocks` namespace, served by sequencers that publish flashblocks.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "flashblocks"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "flashblocks"))]
+pub trait FlashblocksApi {
+    /// Creates a subscription that streams the flashblocks of the payloads built by this node.
+    #[subscription(
+        name = "subscribe" => "flashblock",
+        unsubscribe = "unsubscribe",
+        item = FlashblocksPayloadV1<OpReceipt>
+    )]
+    async fn subscribe(&self) -> SubscriptionResult;
+}
+
+/// Implementation of the `flashblocks` namespace.
+#[derive(Debug, Clone)]
+pub struct OpFlashblocksApi {
+    /// Receives the flashblocks of the payload builder.
+    flashblocks: FlashblocksPublisher<OpPrimitives>,
+}
+
+impl OpFlashblocksApi {
+    /// Creates a new instance of the `flashblocks` namespace.
+    pub const fn new(flashblocks: FlashblocksPublisher<OpPrimitives>) -> Self {
+        Self { flashblocks }
+    }
+}
+
+#[async_trait]
+impl FlashblocksApiServer for OpFlashblocksApi {
+    async fn subscribe(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        let sink = pending.accept().await?;
+        let mut flashblocks = self.flashblocks.subscribe();
+        loop {
+            tokio::select! {
+                _ = sink.closed() => {
+                    // connection dropped
+                    break
+                }
+                flashblock = flashblocks.recv() => match flashblock {
+                    Ok(flashblock) => {
+                        let msg = SubscriptionMessage::from_json(&*flashblock)?;
+                        if sink.send(msg).await.is_err() {
+                            break
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        // the subscriber resyncs with the first flashblock of the next payload
+                        debug!(target: "rpc::flashblocks", skipped, "flashblocks subscriber lagged behind");
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The flashblocks that were received for the payload the sequencer is currently building.
+#[derive(Debug, Clone)]
+pub struct PendingFlashblock {
+    /// Identifier of the payload.
+    pub payload_id: PayloadId,
+    /// Index of the last received flashblock.
+    pub index: u64,
+    /// Fixed fields of the block.
+    pub base: ExecutionPayloadBaseV1,
+    /// Hash of the partial block as of the last received flashblock.
+    pub block_hash: B256,
+    /// All preconfirmed transactions of the block.
+    pub transactions: Vec<OpTransactionSigned>,
+    /// Receipts of the preconfirmed transactions.
+    pub receipts: Vec<OpReceipt>,
+}
+
+/// Tracks the pending flashblock of the sequencer.
+///
+/// This type is shareable: it is updated by the task that follows the sequencer's flashblocks,
+/// see [`PendingFlashblocks::follow`], and read by the `eth_` namespace to serve
+/// preconfirmations.
+#[derive(Debug, Clone, Default)]
+pub struct PendingFlashblocks {
+    inner: Arc<RwLock<Option<PendingFlashblock>>>,
+}
+
+impl PendingFlashblocks {
+    /// Returns the current pending flashblock, if any.
+    pub fn pending(&self) -> Option<PendingFlashblock> {
+        self.inner.read().clone()
+    }
+
+    /// Applies a flashblock received from the sequencer.
+    ///
+    /// The first flashblock of a payload replaces the pending flashblock, subsequent ones extend
+    /// it. If a flashblock was missed, the pending flashblock is discarded until the next payload.
+    pub fn on_flashblock(&self, flashblock: FlashblocksPayloadV1<OpReceipt>) {
+        let FlashblocksPayloadV1 { payload_id, index, base, diff } = flashblock;
+        let mut pending = self.inner.write();
+
+        let transactions = match diff
+            .transactions
+            .iter()
+            .map(|tx| OpTransactionSigned::decode_2718(&mut tx.as_ref()))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(transactions) if transactions.len() == diff.receipts.len() => transactions,
+            _ => {
+                warn!(target: "rpc::flashblocks", %payload_id, index, "received invalid flashblock");
+                pending.take();
+                return
+            }
+        };
+
+        if let Some(base) = base {
+            *pending = Some(PendingFlashblock {
+                payload_id,
+                index,
+                base,
+                block_hash: diff.block_hash,
+                transactions,
+                receipts: diff.receipts,
+            });
+            return
+        }
+
+        match pending.as_mut() {
+            Some(current) if current.payload_id == payload_id && current.index + 1 == index => {
+                current.index = index;
+                current.block_hash = diff.block_hash;
+                current.transactions.extend(transactions);
+                current.receipts.extend(diff.receipts);
+            }
+            _ => {
+                debug!(target: "rpc::flashblocks", %payload_id, index, "missed flashblock, waiting for next payload");
+                pending.take();
+            }
+        }
+    }
+
+    /// Returns the receipt of a transaction that was preconfirmed by the pending flashblock.
+    ///
+    /// The pending flashblock is treated like the pending block: it is only used if it builds on
+    /// top of the given latest block, and its receipts refer to the partial block as of the last
+    /// received flashblock.
+    ///
+    /// Returns `None` if the transaction isn't preconfirmed or the pending flashblock doesn't
+    /// build on the latest block, e.g. because it was already included or reorged out.
+    pub fn transaction_receipt(
+        &self,
+        hash: B256,
+        chain_spec: &OpChainSpec,
+        latest: BlockNumHash,
+    ) -> Result<Option<OpTransactionReceipt>, OpEthApiError> {
+        let pending = self.inner.read();
+        let Some(pending) = pending.as_ref().filter(|pending| {
+            pending.base.parent_hash == latest.hash &&
+                pending.base.block_number == latest.number + 1
+        }) else {
+            return Ok(None)
+        };
+        let Some(index) = pending.transactions.iter().position(|tx| *tx.tx_hash() == hash) else {
+            return Ok(None)
+        };
+
+        let meta = TransactionMeta {
+            tx_hash: hash,
+            index: index as u64,
+            block_hash: pending.block_hash,
+            block_number: pending.base.block_number,
+            base_fee: Some(pending.base.base_fee_per_gas.saturating_to()),
+            excess_blob_gas: None,
+            timestamp: pending.base.timestamp,
+        };
+        // the first transaction of the block is the L1 info deposit
+        let mut l1_block_info =
+            reth_optimism_evm::extract_l1_info_from_tx(&pending.transactions[0])
+                .map_err(OpEthApiError::from)?;
+
+        Ok(Some(
+            OpReceiptBuilder::new(
+                chain_spec,
+                &pending.transactions[index],
+                meta,
+                &pending.receipts[index],
+                &pending.receipts,
+                &mut l1_block_info,
+            )?
+            .build(),
+        ))
+    }
+
+    /// Follows the flashblocks of the sequencer at the given websocket endpoint.
+    ///
+    /// Reconnects if the subscription ends, this future never resolves.
+    pub async fn follow(self, url: String) {
+        loop {
+            if let Err(err) = self.subscribe(&url).await {
+                warn!(target: "rpc::flashblocks", %err, %url, "flashblocks subscription failed");
+            }
+            // the pending flashblock can't be kept up to date without the subscription
+            self.inner.write().take();
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    /// Subscribes to the flashblocks of the sequencer and applies them until the subscription
+    /// ends.
+    async fn subscribe(&self, url: &str) -> Result<(), ClientError> {
+        let client = WsClientBuilder::default().build(url).await?;
+        let mut subscription: Subscription<FlashblocksPayloadV1<OpReceipt>> = client
+            .subscribe("flashblocks_subscribe", rpc_params![], "flashblocks_unsubscribe")
+            .await?;
+        debug!(target: "rpc::flashblocks", %url, "subscribed to flashblocks");
+
+        while let Some(flashblock) = subscription.next().await {
+            self.on_flashblock(flashblock?);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Receipt, TxLegacy};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{Bytes, PrimitiveSignature as Signature, U256};
+    use reth_optimism_payload_builder::flashblocks::ExecutionPayloadFlashblockDeltaV1;
+
+    fn flashblock(index: u64, nonces: &[u64]) -> FlashblocksPayloadV1<OpReceipt> {
+        let transactions = nonces
+            .iter()
+            .map(|nonce| {
+                let tx = TxLegacy { nonce: *nonce, ..Default::default() };
+                OpTransactionSigned::new_unhashed(tx.into(), Signature::test_signature())
+            })
+            .collect::<Vec<_>>();
+        FlashblocksPayloadV1 {
+            payload_id: PayloadId::new([1; 8]),
+            index,
+            base: (index == 0).then(|| ExecutionPayloadBaseV1 {
+                parent_hash: B256::ZERO,
+                fee_recipient: Default::default(),
+                prev_randao: B256::ZERO,
+                block_number: 1,
+                gas_limit: 30_000_000,
+                timestamp: 1,
+                extra_data: Bytes::new(),
+                base_fee_per_gas: U256::from(1),
+            }),
+            diff: ExecutionPayloadFlashblockDeltaV1 {
+                state_root: B256::ZERO,
+                receipts_root: B256::ZERO,
+                logs_bloom: Default::default(),
+                gas_used: 0,
+                block_hash: B256::with_last_byte(index as u8),
+                receipts: transactions
+                    .iter()
+                    .map(|_| OpReceipt::Legacy(Receipt::default()))
+                    .collect(),
+                transactions: transactions.iter().map(|tx| tx.encoded_2718().into()).collect(),
+            },
+        }
+    }
+
+    #[test]
+    fn apply_flashblocks() {
+        let flashblocks = PendingFlashblocks::default();
+
+        flashblocks.on_flashblock(flashblock(0, &[0]));
+        flashblocks.on_flashblock(flashblock(1, &[1, 2]));
+        let pending = flashblocks.pending().unwrap();
+        assert_eq!(pending.index, 1);
+        assert_eq!(pending.block_hash, B256::with_last_byte(1));
+        assert_eq!(pending.transactions.len(), 3);
+        assert_eq!(pending.receipts.len(), 3);
+
+        // a missed flashblock discards the pending flashblock
+        flashblocks.on_flashblock(flashblock(3, &[3]));
+        assert!(flashblocks.pending().is_none());
+
+        // until the next payload starts
+        flashblocks.on_flashblock(flashblock(2, &[3]));
+        assert!(flashblocks.pending().is_none());
+        flashblocks.on_flashblock(flashblock(0, &[0]));
+        assert_eq!(flashblocks.pending().unwrap().transactions.len(), 1);
+    }
+
+    #[test]
+    fn receipt_of_outdated_flashblock() {
+        let flashblocks = PendingFlashblocks::default();
+        flashblocks.on_flashblock(flashblock(0, &[0]));
+        let hash = *flashblocks.pending().unwrap().transactions[0].tx_hash();
+        let chain_spec = reth_optimism_chainspec::OP_MAINNET.clone();
+
+        // the flashblock doesn't build on the latest block
+        let latest = BlockNumHash::new(0, B256::with_last_byte(1));
+        assert!(flashblocks.transaction_receipt(hash, &chain_spec, latest).unwrap().is_none());
+
+        // the block of the flashblock is already the latest block
+        let latest = BlockNumHash::new(1, B256::with_last_byte(1));
+        assert!(flashblocks.transaction_receipt(hash, &chain_spec, latest).unwrap().is_none());
+    }
+}
@@ -13,6 +13,7 @@
 pub mod engine;
 pub mod error;
 pub mod eth;
+pub mod flashblocks;
 pub mod miner;
 pub mod sequencer;
 pub mod witness;
@@ -22,4 +23,7 @@ pub use engine::OpEngineApiClient;
 pub use engine::{OpEngineApi, OpEngineApiServer};
 pub use error::{OpEthApiError, OpInvalidTransactionError, SequencerClientError};
 pub use eth::{OpEthApi, OpReceiptBuilder};
+#[cfg(feature = "client")]
+pub use flashblocks::FlashblocksApiClient;
+pub use flashblocks::{FlashblocksApiServer, OpFlashblocksApi, PendingFlashblocks};
 pub use sequencer::SequencerClient;
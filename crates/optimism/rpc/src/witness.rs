@@ -9,6 +9,7 @@ use reth_evm::{ConfigureEvm, ConfigureEvmFor};
 use reth_node_api::NodePrimitives;
 use reth_optimism_chainspec::OpChainSpec;
 use reth_optimism_payload_builder::{OpPayloadBuilder, OpPayloadPrimitives};
+use reth_optimism_txpool::OpPooledTx;
 use reth_primitives::SealedHeader;
 use reth_provider::{
     BlockReaderIdExt, NodePrimitivesProvider, ProviderError, ProviderResult, StateProviderFactory,
@@ -16,7 +17,7 @@ use reth_provider::{
 pub use reth_rpc_api::DebugExecutionWitnessApiServer;
 use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
 use reth_tasks::TaskSpawner;
-use reth_transaction_pool::{PoolTransaction, TransactionPool};
+use reth_transaction_pool::TransactionPool;
 use std::{fmt::Debug, sync::Arc};
 use tokio::sync::{oneshot, Semaphore};
 
@@ -59,9 +60,7 @@ impl<Pool, Provider, EvmConfig> DebugExecutionWitnessApiServer<OpPayloadAttribut
     for OpDebugWitnessApi<Pool, Provider, EvmConfig>
 where
     Pool: TransactionPool<
-            Transaction: PoolTransaction<
-                Consensus = <Provider::Primitives as NodePrimitives>::SignedTx,
-            >,
+            Transaction: OpPooledTx<Consensus = <Provider::Primitives as NodePrimitives>::SignedTx>,
         > + 'static,
     Provider: BlockReaderIdExt<Header = reth_primitives::Header>
         + NodePrimitivesProvider<Primitives: OpPayloadPrimitives>
@@ -1,7 +1,13 @@
 //! Additional support for pooled transactions with [`TransactionConditional`]
 
 use alloy_consensus::conditional::BlockConditionalAttributes;
-use alloy_rpc_types_eth::erc4337::TransactionConditional;
+use alloy_primitives::B256;
+use alloy_rpc_types_eth::erc4337::{AccountStorage, TransactionConditional};
+use reth_storage_api::{errors::provider::ProviderResult, StateProvider};
+
+/// The default maximum cost of a [`TransactionConditional`], see
+/// [`TransactionConditional::cost`].
+pub const DEFAULT_MAX_CONDITIONAL_COST: u64 = 5000;
 
 /// Helper trait that allows attaching a [`TransactionConditional`].
 pub trait MaybeConditionalTransaction {
@@ -16,6 +22,23 @@ pub trait MaybeConditionalTransaction {
         self.conditional().map(|tc| tc.has_exceeded_block_attributes(block_attr)).unwrap_or(false)
     }
 
+    /// Check if the conditional allows inclusion in a block with the given attributes.
+    ///
+    /// Returns `true` if no conditional is attached.
+    fn matches_block_attributes(&self, block_attr: &BlockConditionalAttributes) -> bool {
+        self.conditional().map(|tc| tc.matches_block_attributes(block_attr)).unwrap_or(true)
+    }
+
+    /// Check if the known accounts of the conditional match the given state.
+    ///
+    /// Returns `true` if no conditional is attached.
+    fn matches_known_accounts<S: StateProvider + ?Sized>(&self, state: &S) -> ProviderResult<bool>
+    where
+        Self: Sized,
+    {
+        self.conditional().map_or(Ok(true), |tc| known_accounts_match(tc, state))
+    }
+
     /// Helper that sets the conditional and returns the instance again
     fn with_conditional(mut self, conditional: TransactionConditional) -> Self
     where
@@ -25,3 +48,84 @@ pub trait MaybeConditionalTransaction {
         self
     }
 }
+
+/// Returns `true` if the storage of all known accounts of the [`TransactionConditional`] matches
+/// the given state.
+///
+/// An account's storage either has to match the expected storage root, or every listed slot has
+/// to hold the expected value. Unset slots are treated as zero.
+pub fn known_accounts_match<S: StateProvider + ?Sized>(
+    conditional: &TransactionConditional,
+    state: &S,
+) -> ProviderResult<bool> {
+    for (address, storage) in &conditional.known_accounts {
+        match storage {
+            AccountStorage::RootHash(expected) => {
+                if state.storage_root(*address, Default::default())? != *expected {
+                    return Ok(false)
+                }
+            }
+            AccountStorage::Slots(slots) => {
+                for (slot, expected) in slots {
+                    let value = state.storage(*address, *slot)?.unwrap_or_default();
+                    if B256::from(value) != *expected {
+                        return Ok(false)
+                    }
+                }
+            }
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::constants::EMPTY_ROOT_HASH;
+    use alloy_primitives::{Address, U256};
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+
+    #[test]
+    fn known_account_slots() {
+        let provider = MockEthProvider::default();
+        let address = Address::random();
+        let slot = B256::with_last_byte(1);
+        provider.add_account(
+            address,
+            ExtendedAccount::new(0, U256::ZERO).extend_storage([(slot, U256::from(7))]),
+        );
+
+        let mut conditional = TransactionConditional::default();
+        conditional.known_accounts.insert(
+            address,
+            AccountStorage::Slots([(slot, B256::from(U256::from(7)))].into_iter().collect()),
+        );
+        assert!(known_accounts_match(&conditional, &provider).unwrap());
+
+        // unset slots are zero
+        conditional.known_accounts.insert(
+            address,
+            AccountStorage::Slots([(B256::with_last_byte(2), B256::ZERO)].into_iter().collect()),
+        );
+        assert!(known_accounts_match(&conditional, &provider).unwrap());
+
+        conditional.known_accounts.insert(
+            address,
+            AccountStorage::Slots([(slot, B256::from(U256::from(8)))].into_iter().collect()),
+        );
+        assert!(!known_accounts_match(&conditional, &provider).unwrap());
+    }
+
+    #[test]
+    fn known_account_storage_root() {
+        let provider = MockEthProvider::default();
+        let address = Address::random();
+
+        let mut conditional = TransactionConditional::default();
+        conditional.known_accounts.insert(address, AccountStorage::RootHash(EMPTY_ROOT_HASH));
+        assert!(known_accounts_match(&conditional, &provider).unwrap());
+
+        conditional.known_accounts.insert(address, AccountStorage::RootHash(B256::random()));
+        assert!(!known_accounts_match(&conditional, &provider).unwrap());
+    }
+}
@@ -0,0 +1,28 @@
+//! Additional support for pooled transactions that execute cross-chain messages.
+
+/// Helper trait that allows tracking until when the cross-chain messages of a transaction are
+/// valid.
+pub trait MaybeInteropTransaction {
+    /// Sets the timestamp until which the cross-chain messages of the transaction are valid.
+    fn set_interop_deadline(&mut self, deadline: u64);
+
+    /// Returns the timestamp until which the cross-chain messages of the transaction are valid.
+    ///
+    /// Returns `None` if the transaction doesn't execute any cross-chain messages.
+    fn interop_deadline(&self) -> Option<u64>;
+
+    /// Returns `true` if the cross-chain messages of the transaction are valid at the given
+    /// timestamp, or if the transaction doesn't execute any cross-chain messages.
+    fn is_interop_valid_at(&self, timestamp: u64) -> bool {
+        self.interop_deadline().is_none_or(|deadline| timestamp <= deadline)
+    }
+
+    /// Helper that sets the interop deadline and returns the instance again
+    fn with_interop_deadline(mut self, deadline: u64) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_interop_deadline(deadline);
+        self
+    }
+}
@@ -14,9 +14,11 @@ mod validator;
 pub use validator::{OpL1BlockInfo, OpTransactionValidator};
 
 pub mod conditional;
+pub mod interop;
 mod transaction;
-pub use transaction::OpPooledTransaction;
+pub use transaction::{OpPooledTransaction, OpPooledTx};
 pub mod maintain;
+pub mod supervisor;
 
 use reth_transaction_pool::{CoinbaseTipOrdering, Pool, TransactionValidationTaskExecutor};
 
@@ -1,12 +1,17 @@
 //! Support for maintaining the state of the transaction pool
 
-use crate::conditional::MaybeConditionalTransaction;
+use crate::{
+    conditional::{known_accounts_match, MaybeConditionalTransaction},
+    interop::MaybeInteropTransaction,
+};
 use alloy_consensus::{conditional::BlockConditionalAttributes, BlockHeader};
 use futures_util::{future::BoxFuture, FutureExt, Stream, StreamExt};
 use reth_chain_state::CanonStateNotification;
 use reth_metrics::{metrics::Counter, Metrics};
 use reth_primitives_traits::NodePrimitives;
+use reth_storage_api::StateProviderFactory;
 use reth_transaction_pool::TransactionPool;
+use tracing::warn;
 
 /// Transaction pool maintenance metrics
 #[derive(Metrics)]
@@ -15,6 +20,12 @@ struct MaintainPoolMetrics {
     /// Counter indicating the number of conditional transactions removed from
     /// the pool because of exceeded block attributes.
     removed_tx_conditional: Counter,
+    /// Counter indicating the number of conditional transactions removed from
+    /// the pool because the storage of their known accounts changed.
+    removed_tx_conditional_known_accounts: Counter,
+    /// Counter indicating the number of interop transactions removed from the pool because
+    /// their cross-chain messages are no longer guaranteed to be valid.
+    removed_tx_interop: Counter,
 }
 
 impl MaintainPoolMetrics {
@@ -22,33 +33,52 @@ impl MaintainPoolMetrics {
     fn inc_removed_tx_conditional(&self, count: usize) {
         self.removed_tx_conditional.increment(count as u64);
     }
+
+    #[inline]
+    fn inc_removed_tx_conditional_known_accounts(&self, count: usize) {
+        self.removed_tx_conditional_known_accounts.increment(count as u64);
+    }
+
+    #[inline]
+    fn inc_removed_tx_interop(&self, count: usize) {
+        self.removed_tx_interop.increment(count as u64);
+    }
 }
 
 /// Returns a spawnable future for maintaining the state of the transaction pool.
-pub fn maintain_transaction_pool_future<N, Pool, St>(
+pub fn maintain_transaction_pool_future<N, Client, Pool, St>(
+    client: Client,
     pool: Pool,
     events: St,
 ) -> BoxFuture<'static, ()>
 where
     N: NodePrimitives,
+    Client: StateProviderFactory + Send + 'static,
     Pool: TransactionPool + 'static,
-    Pool::Transaction: MaybeConditionalTransaction,
+    Pool::Transaction: MaybeConditionalTransaction + MaybeInteropTransaction,
     St: Stream<Item = CanonStateNotification<N>> + Send + Unpin + 'static,
 {
     async move {
-        maintain_transaction_pool(pool, events).await;
+        maintain_transaction_pool(client, pool, events).await;
     }
     .boxed()
 }
 
 /// Maintains the state of the transaction pool by handling new blocks and reorgs.
 ///
-/// This listens for any new blocks and reorgs and updates the transaction pool's state accordingly
-pub async fn maintain_transaction_pool<N, Pool, St>(pool: Pool, mut events: St)
-where
+/// This listens for any new blocks and reorgs and updates the transaction pool's state accordingly:
+/// conditional transactions are removed once the block attributes exceed their conditional, or
+/// once the storage of their known accounts no longer matches the state of the new tip. Interop
+/// transactions are removed once the new tip is past their interop deadline.
+pub async fn maintain_transaction_pool<N, Client, Pool, St>(
+    client: Client,
+    pool: Pool,
+    mut events: St,
+) where
     N: NodePrimitives,
+    Client: StateProviderFactory,
     Pool: TransactionPool,
-    Pool::Transaction: MaybeConditionalTransaction,
+    Pool::Transaction: MaybeConditionalTransaction + MaybeInteropTransaction,
     St: Stream<Item = CanonStateNotification<N>> + Send + Unpin + 'static,
 {
     let metrics = MaintainPoolMetrics::default();
@@ -63,15 +93,67 @@ where
                 timestamp: new.tip().timestamp(),
             };
             let mut to_remove = Vec::new();
-            for tx in &pool.pooled_transactions() {
+            let mut to_remove_interop = Vec::new();
+            let mut to_check = Vec::new();
+            for tx in pool.pooled_transactions() {
                 if tx.transaction.has_exceeded_block_attributes(&block_attr) {
                     to_remove.push(*tx.hash());
+                } else if !tx.transaction.is_interop_valid_at(block_attr.timestamp) {
+                    to_remove_interop.push(*tx.hash());
+                } else if let Some(conditional) = tx
+                    .transaction
+                    .conditional()
+                    .filter(|conditional| !conditional.known_accounts.is_empty())
+                {
+                    to_check.push((*tx.hash(), conditional.clone()));
                 }
             }
             if !to_remove.is_empty() {
                 metrics.inc_removed_tx_conditional(to_remove.len());
                 let _ = pool.remove_transactions(to_remove);
             }
+            if !to_remove_interop.is_empty() {
+                metrics.inc_removed_tx_interop(to_remove_interop.len());
+                let _ = pool.remove_transactions(to_remove_interop);
+            }
+
+            if to_check.is_empty() {
+                continue
+            }
+            let state = match client.state_by_block_hash(new.tip().hash()) {
+                Ok(state) => state,
+                Err(err) => {
+                    warn!(target: "txpool", %err, "failed to get state to check conditional transactions");
+                    continue
+                }
+            };
+            // computing storage roots is expensive, so the known accounts are checked on a blocking
+            // task
+            let to_remove = tokio::task::spawn_blocking(move || {
+                let mut to_remove = Vec::new();
+                for (hash, conditional) in to_check {
+                    match known_accounts_match(&conditional, &*state) {
+                        Ok(true) => {}
+                        Ok(false) => to_remove.push(hash),
+                        Err(err) => {
+                            warn!(target: "txpool", %err, %hash, "failed to check known accounts of conditional transaction");
+                        }
+                    }
+                }
+                to_remove
+            })
+            .await;
+            let to_remove = match to_remove {
+                Ok(to_remove) => to_remove,
+                Err(err) => {
+                    warn!(target: "txpool", %err, "failed to check known accounts of conditional transactions");
+                    continue
+                }
+            };
+            if !to_remove.is_empty() {
+                metrics.inc_removed_tx_conditional_known_accounts(to_remove.len());
+                let _ = pool.remove_transactions(to_remove);
+            }
         }
     }
 }
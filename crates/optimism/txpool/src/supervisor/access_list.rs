@@ -0,0 +1,36 @@
+use alloy_eips::eip2930::AccessListItem;
+use alloy_primitives::{address, Address, B256};
+
+/// Address of the `CrossL2Inbox` predeploy.
+pub const CROSS_L2_INBOX_ADDRESS: Address = address!("4200000000000000000000000000000000000022");
+
+/// Returns the inbox entries of all executing messages declared in the given access list items.
+///
+/// An executing message is declared by an access list item of the [`CROSS_L2_INBOX_ADDRESS`],
+/// every storage key of such an item is an inbox entry that has to be checked against the
+/// supervisor.
+pub fn parse_access_list_items_to_inbox_entries<'a>(
+    items: impl IntoIterator<Item = &'a AccessListItem>,
+) -> impl Iterator<Item = &'a B256> {
+    items
+        .into_iter()
+        .filter(|item| item.address == CROSS_L2_INBOX_ADDRESS)
+        .flat_map(|item| item.storage_keys.iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_inbox_entries() {
+        let entry = B256::with_last_byte(1);
+        let items = vec![
+            AccessListItem { address: Address::with_last_byte(1), storage_keys: vec![B256::ZERO] },
+            AccessListItem { address: CROSS_L2_INBOX_ADDRESS, storage_keys: vec![entry] },
+        ];
+
+        let entries = parse_access_list_items_to_inbox_entries(&items).collect::<Vec<_>>();
+        assert_eq!(entries, vec![&entry]);
+    }
+}
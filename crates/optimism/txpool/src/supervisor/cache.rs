@@ -0,0 +1,145 @@
+use crate::supervisor::{ExecutingDescriptor, SupervisorClient};
+use alloy_primitives::{map::HashMap, TxHash, B256};
+use parking_lot::Mutex;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tracing::trace;
+
+/// The default time for which the outcome of a supervisor check is reused.
+pub const DEFAULT_SUPERVISOR_CACHE_TTL: Duration = Duration::from_secs(1);
+
+/// A [`SupervisorClient`] that caches the outcome of access list checks per transaction for a
+/// short time.
+///
+/// This is used by the payload builder, which re-checks the cross-chain messages of every
+/// transaction it includes, but may rebuild the same payload multiple times per block.
+#[derive(Debug, Clone)]
+pub struct CachedSupervisorClient {
+    /// The supervisor client.
+    client: SupervisorClient,
+    /// How long the outcome of a check is reused.
+    ttl: Duration,
+    /// Outcome of recent checks by transaction hash.
+    checked: Arc<Mutex<HashMap<TxHash, (Instant, bool)>>>,
+}
+
+impl CachedSupervisorClient {
+    /// Creates a new [`CachedSupervisorClient`] that reuses the outcome of checks for
+    /// [`DEFAULT_SUPERVISOR_CACHE_TTL`].
+    pub fn new(client: SupervisorClient) -> Self {
+        Self::with_ttl(client, DEFAULT_SUPERVISOR_CACHE_TTL)
+    }
+
+    /// Creates a new [`CachedSupervisorClient`] that reuses the outcome of checks for the given
+    /// duration.
+    pub fn with_ttl(client: SupervisorClient, ttl: Duration) -> Self {
+        Self { client, ttl, checked: Default::default() }
+    }
+
+    /// Returns the underlying [`SupervisorClient`].
+    pub const fn client(&self) -> &SupervisorClient {
+        &self.client
+    }
+
+    /// Returns `true` if the given inbox entries of the transaction are valid when executed at
+    /// the given timestamp.
+    ///
+    /// The supervisor is only queried if there is no recent outcome for the transaction. Failed
+    /// requests are treated as invalid messages.
+    pub async fn is_valid_at(
+        &self,
+        tx_hash: TxHash,
+        inbox_entries: &[B256],
+        timestamp: u64,
+    ) -> bool {
+        if let Some(valid) = self.cached(&tx_hash) {
+            return valid
+        }
+
+        let valid = match self
+            .client
+            .check_access_list(inbox_entries, ExecutingDescriptor::new(timestamp, None))
+            .await
+        {
+            Ok(()) => true,
+            Err(err) => {
+                trace!(target: "txpool::interop", %err, ?tx_hash, "cross-chain messages rejected");
+                false
+            }
+        };
+
+        let now = Instant::now();
+        let mut checked = self.checked.lock();
+        checked.retain(|_, (checked_at, _)| now.duration_since(*checked_at) < self.ttl);
+        checked.insert(tx_hash, (now, valid));
+        valid
+    }
+
+    /// Blocking version of [`Self::is_valid_at`].
+    ///
+    /// This must be called from a blocking thread of a multi-threaded tokio runtime, as the
+    /// payload builder does. Returns `false` if there is no runtime.
+    pub fn is_valid_at_blocking(
+        &self,
+        tx_hash: TxHash,
+        inbox_entries: &[B256],
+        timestamp: u64,
+    ) -> bool {
+        let Ok(handle) = tokio::runtime::Handle::try_current() else { return false };
+        tokio::task::block_in_place(|| {
+            handle.block_on(self.is_valid_at(tx_hash, inbox_entries, timestamp))
+        })
+    }
+
+    /// Returns the outcome of a check of the transaction that hasn't expired yet.
+    fn cached(&self, tx_hash: &TxHash) -> Option<bool> {
+        self.checked
+            .lock()
+            .get(tx_hash)
+            .filter(|(checked_at, _)| checked_at.elapsed() < self.ttl)
+            .map(|(_, valid)| *valid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::supervisor::{spawn_mock_supervisor, SafetyLevel, INVALID_INBOX_ENTRY};
+
+    #[tokio::test]
+    async fn reuses_recent_outcome() {
+        let (url, _handle) = spawn_mock_supervisor().await;
+        let client = SupervisorClient::new(&url, SafetyLevel::CrossUnsafe).unwrap();
+        let tx_hash = TxHash::with_last_byte(1);
+
+        let cached = CachedSupervisorClient::with_ttl(client.clone(), Duration::from_secs(60));
+        assert!(cached.is_valid_at(tx_hash, &[B256::with_last_byte(1)], 1000).await);
+        // the outcome of the previous check is reused
+        assert!(cached.is_valid_at(tx_hash, &[INVALID_INBOX_ENTRY], 1000).await);
+
+        let uncached = CachedSupervisorClient::with_ttl(client, Duration::ZERO);
+        assert!(uncached.is_valid_at(tx_hash, &[B256::with_last_byte(1)], 1000).await);
+        // the supervisor is queried again
+        assert!(!uncached.is_valid_at(tx_hash, &[INVALID_INBOX_ENTRY], 1000).await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn blocking_check() {
+        let (url, _handle) = spawn_mock_supervisor().await;
+        let client = SupervisorClient::new(&url, SafetyLevel::CrossUnsafe).unwrap();
+        let cached = CachedSupervisorClient::new(client);
+
+        assert!(cached.is_valid_at_blocking(
+            TxHash::with_last_byte(1),
+            &[B256::with_last_byte(1)],
+            1000
+        ));
+        assert!(!cached.is_valid_at_blocking(
+            TxHash::with_last_byte(2),
+            &[INVALID_INBOX_ENTRY],
+            1000
+        ));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::supervisor::{ExecutingDescriptor, InteropTxValidatorError, SafetyLevel};
+use alloy_primitives::B256;
+use jsonrpsee::{
+    core::{client::ClientT, rpc_params},
+    http_client::{HttpClient, HttpClientBuilder},
+};
+use std::time::Duration;
+
+/// The default timeout of requests to the supervisor.
+pub const DEFAULT_SUPERVISOR_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// A client for the `supervisor` RPC namespace of an `op-supervisor`.
+#[derive(Debug, Clone)]
+pub struct SupervisorClient {
+    /// The HTTP client.
+    client: HttpClient,
+    /// The minimum safety level cross-chain messages must have.
+    safety: SafetyLevel,
+}
+
+impl SupervisorClient {
+    /// Creates a new [`SupervisorClient`] for the given endpoint that requires cross-chain
+    /// messages to have at least the given safety level.
+    pub fn new(
+        supervisor_endpoint: impl AsRef<str>,
+        safety: SafetyLevel,
+    ) -> Result<Self, InteropTxValidatorError> {
+        Self::with_timeout(supervisor_endpoint, safety, DEFAULT_SUPERVISOR_TIMEOUT)
+    }
+
+    /// Creates a new [`SupervisorClient`] with the given request timeout.
+    pub fn with_timeout(
+        supervisor_endpoint: impl AsRef<str>,
+        safety: SafetyLevel,
+        timeout: Duration,
+    ) -> Result<Self, InteropTxValidatorError> {
+        let client =
+            HttpClientBuilder::default().request_timeout(timeout).build(supervisor_endpoint)?;
+        Ok(Self { client, safety })
+    }
+
+    /// Returns the minimum safety level cross-chain messages must have.
+    pub const fn safety(&self) -> SafetyLevel {
+        self.safety
+    }
+
+    /// Checks the given inbox entries against the supervisor.
+    ///
+    /// Returns an [`InteropTxValidatorError::InvalidEntry`] if any of the messages doesn't have
+    /// the configured safety level, or isn't valid in the context of the executing descriptor.
+    pub async fn check_access_list(
+        &self,
+        inbox_entries: &[B256],
+        executing_descriptor: ExecutingDescriptor,
+    ) -> Result<(), InteropTxValidatorError> {
+        self.client
+            .request::<(), _>(
+                "supervisor_checkAccessList",
+                rpc_params![inbox_entries, self.safety, executing_descriptor],
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use jsonrpsee::{
+        server::{Server, ServerHandle},
+        types::ErrorObjectOwned,
+        RpcModule,
+    };
+
+    /// Inbox entry that the mock supervisor rejects.
+    pub(crate) const INVALID_INBOX_ENTRY: B256 = B256::repeat_byte(0xff);
+
+    /// Starts a mock supervisor that rejects [`INVALID_INBOX_ENTRY`] and messages that don't
+    /// have at least the cross-unsafe safety level.
+    pub(crate) async fn spawn_mock_supervisor() -> (String, ServerHandle) {
+        let mut module = RpcModule::new(());
+        module
+            .register_method("supervisor_checkAccessList", |params, _, _| {
+                let (entries, safety, _descriptor) =
+                    params.parse::<(Vec<B256>, SafetyLevel, ExecutingDescriptor)>()?;
+                if safety == SafetyLevel::LocalUnsafe {
+                    return Err(ErrorObjectOwned::owned(
+                        -321501,
+                        "unsupported safety level",
+                        None::<()>,
+                    ))
+                }
+                if entries.contains(&INVALID_INBOX_ENTRY) {
+                    return Err(ErrorObjectOwned::owned(-320500, "conflicting data", None::<()>))
+                }
+                Ok::<_, ErrorObjectOwned>(())
+            })
+            .unwrap();
+
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap());
+        (url, server.start(module))
+    }
+
+    #[tokio::test]
+    async fn check_access_list() {
+        let (url, _handle) = spawn_mock_supervisor().await;
+        let client = SupervisorClient::new(&url, SafetyLevel::CrossUnsafe).unwrap();
+        let descriptor = ExecutingDescriptor::new(1000, Some(3600));
+
+        client.check_access_list(&[B256::with_last_byte(1)], descriptor).await.unwrap();
+
+        let err = client.check_access_list(&[INVALID_INBOX_ENTRY], descriptor).await.unwrap_err();
+        assert!(matches!(err, InteropTxValidatorError::InvalidEntry { code: -320500, .. }));
+
+        let client = SupervisorClient::new(&url, SafetyLevel::LocalUnsafe).unwrap();
+        let err =
+            client.check_access_list(&[B256::with_last_byte(1)], descriptor).await.unwrap_err();
+        assert!(matches!(err, InteropTxValidatorError::InvalidEntry { code: -321501, .. }));
+    }
+
+    #[tokio::test]
+    async fn check_access_list_unreachable() {
+        let client = SupervisorClient::new("http://127.0.0.1:1", SafetyLevel::CrossUnsafe).unwrap();
+        let err = client
+            .check_access_list(&[B256::with_last_byte(1)], ExecutingDescriptor::new(1000, None))
+            .await
+            .unwrap_err();
+        assert!(!matches!(err, InteropTxValidatorError::InvalidEntry { .. }));
+    }
+}
@@ -0,0 +1,46 @@
+use core::any::Any;
+use jsonrpsee::core::ClientError;
+use reth_transaction_pool::error::PoolTransactionError;
+
+/// Errors that can occur while validating cross-chain messages against the supervisor.
+#[derive(Debug, thiserror::Error)]
+pub enum InteropTxValidatorError {
+    /// The supervisor rejected an executing message of the transaction.
+    #[error("invalid cross-chain message: {message} (code {code})")]
+    InvalidEntry {
+        /// Error code returned by the supervisor.
+        code: i32,
+        /// Error message returned by the supervisor.
+        message: String,
+    },
+    /// The supervisor didn't respond in time.
+    #[error("supervisor request timed out")]
+    Timeout,
+    /// Failed to reach the supervisor.
+    #[error("supervisor request failed: {0}")]
+    Client(ClientError),
+}
+
+impl From<ClientError> for InteropTxValidatorError {
+    fn from(err: ClientError) -> Self {
+        match err {
+            ClientError::Call(err) => {
+                Self::InvalidEntry { code: err.code(), message: err.message().to_string() }
+            }
+            ClientError::RequestTimeout => Self::Timeout,
+            err => Self::Client(err),
+        }
+    }
+}
+
+impl PoolTransactionError for InteropTxValidatorError {
+    fn is_bad_transaction(&self) -> bool {
+        // messages can become invalid with a reorg of the initiating chain and peers can track
+        // the supervisor at a different safety level
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
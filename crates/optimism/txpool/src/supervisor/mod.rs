@@ -0,0 +1,26 @@
+//! Validation of cross-chain messages against an `op-supervisor`.
+//!
+//! With OP Stack interop, transactions execute messages of other chains by calling the
+//! `CrossL2Inbox` predeploy. The messages are declared in the transaction's access list and have
+//! to be checked against a supervisor, which tracks the state of all chains in the dependency set.
+
+mod access_list;
+pub use access_list::{parse_access_list_items_to_inbox_entries, CROSS_L2_INBOX_ADDRESS};
+
+mod cache;
+pub use cache::{CachedSupervisorClient, DEFAULT_SUPERVISOR_CACHE_TTL};
+
+mod client;
+#[cfg(test)]
+pub(crate) use client::tests::{spawn_mock_supervisor, INVALID_INBOX_ENTRY};
+pub use client::{SupervisorClient, DEFAULT_SUPERVISOR_TIMEOUT};
+
+mod errors;
+pub use errors::InteropTxValidatorError;
+
+mod types;
+pub use types::{ExecutingDescriptor, SafetyLevel};
+
+/// How long the cross-chain messages of a transaction must remain valid after they have been
+/// checked against the supervisor, in seconds.
+pub const TRANSACTION_VALIDITY_WINDOW_SECS: u64 = 3600;
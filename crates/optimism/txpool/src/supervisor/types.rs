@@ -0,0 +1,102 @@
+use core::{fmt, str::FromStr};
+use serde::{Deserialize, Serialize};
+
+/// Safety level of a cross-chain message, as tracked by the supervisor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SafetyLevel {
+    /// The message is finalized.
+    #[serde(rename = "finalized")]
+    Finalized,
+    /// The message and all of its dependencies are derived from L1.
+    #[serde(rename = "safe")]
+    CrossSafe,
+    /// The message is derived from L1, its dependencies may not be.
+    #[serde(rename = "local-safe")]
+    LocalSafe,
+    /// The message and all of its dependencies are known to the supervisor.
+    #[default]
+    #[serde(rename = "cross-unsafe")]
+    CrossUnsafe,
+    /// The message is known to the supervisor, its dependencies may not be.
+    #[serde(rename = "unsafe")]
+    LocalUnsafe,
+}
+
+impl SafetyLevel {
+    /// Returns the name of the safety level as used by the supervisor RPC.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Finalized => "finalized",
+            Self::CrossSafe => "safe",
+            Self::LocalSafe => "local-safe",
+            Self::CrossUnsafe => "cross-unsafe",
+            Self::LocalUnsafe => "unsafe",
+        }
+    }
+}
+
+impl fmt::Display for SafetyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SafetyLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "finalized" => Self::Finalized,
+            "safe" => Self::CrossSafe,
+            "local-safe" => Self::LocalSafe,
+            "cross-unsafe" => Self::CrossUnsafe,
+            "unsafe" => Self::LocalUnsafe,
+            _ => return Err(format!("unknown safety level: {s}")),
+        })
+    }
+}
+
+/// Describes the context in which cross-chain messages are executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutingDescriptor {
+    /// Timestamp at which the messages are executed.
+    #[serde(with = "alloy_serde::quantity")]
+    pub timestamp: u64,
+    /// Duration in seconds after the timestamp for which the messages must remain valid.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub timeout: Option<u64>,
+}
+
+impl ExecutingDescriptor {
+    /// Creates a new [`ExecutingDescriptor`].
+    pub const fn new(timestamp: u64, timeout: Option<u64>) -> Self {
+        Self { timestamp, timeout }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safety_level_roundtrip() {
+        for level in [
+            SafetyLevel::Finalized,
+            SafetyLevel::CrossSafe,
+            SafetyLevel::LocalSafe,
+            SafetyLevel::CrossUnsafe,
+            SafetyLevel::LocalUnsafe,
+        ] {
+            assert_eq!(level.to_string().parse::<SafetyLevel>().unwrap(), level);
+            assert_eq!(serde_json::to_string(&level).unwrap(), format!("\"{level}\""));
+        }
+    }
+
+    #[test]
+    fn serde_executing_descriptor() {
+        let descriptor = ExecutingDescriptor::new(1000, Some(3600));
+        let json = serde_json::to_string(&descriptor).unwrap();
+        assert_eq!(json, r#"{"timestamp":"0x3e8","timeout":"0xe10"}"#);
+        assert_eq!(serde_json::from_str::<ExecutingDescriptor>(&json).unwrap(), descriptor);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::conditional::MaybeConditionalTransaction;
+use crate::{conditional::MaybeConditionalTransaction, interop::MaybeInteropTransaction};
 use alloy_consensus::{
     transaction::Recovered, BlobTransactionSidecar, BlobTransactionValidationError, Typed2718,
 };
@@ -33,6 +33,9 @@ pub struct OpPooledTransaction<
 
     /// Optional conditional attached to this transaction.
     conditional: Option<Box<TransactionConditional>>,
+
+    /// Timestamp until which the cross-chain messages of this transaction are valid, if any.
+    interop_deadline: Option<u64>,
 }
 
 impl<Cons: SignedTransaction, Pooled> OpPooledTransaction<Cons, Pooled> {
@@ -42,6 +45,7 @@ impl<Cons: SignedTransaction, Pooled> OpPooledTransaction<Cons, Pooled> {
             inner: EthPooledTransaction::new(transaction, encoded_length),
             estimated_tx_compressed_size: Default::default(),
             conditional: None,
+            interop_deadline: None,
             _pd: core::marker::PhantomData,
         }
     }
@@ -72,6 +76,28 @@ impl<Cons, Pooled> MaybeConditionalTransaction for OpPooledTransaction<Cons, Poo
     }
 }
 
+impl<Cons, Pooled> MaybeInteropTransaction for OpPooledTransaction<Cons, Pooled> {
+    fn set_interop_deadline(&mut self, deadline: u64) {
+        self.interop_deadline = Some(deadline)
+    }
+
+    fn interop_deadline(&self) -> Option<u64> {
+        self.interop_deadline
+    }
+}
+
+/// Helper trait for pool transactions that can carry a [`TransactionConditional`] and execute
+/// cross-chain messages, as required by the OP payload builder.
+pub trait OpPooledTx:
+    MaybeConditionalTransaction + MaybeInteropTransaction + PoolTransaction
+{
+}
+
+impl<T> OpPooledTx for T where
+    T: MaybeConditionalTransaction + MaybeInteropTransaction + PoolTransaction
+{
+}
+
 impl<Cons, Pooled> PoolTransaction for OpPooledTransaction<Cons, Pooled>
 where
     Cons: SignedTransaction + From<Pooled>,
@@ -237,17 +263,27 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{OpPooledTransaction, OpTransactionValidator};
-    use alloy_consensus::transaction::Recovered;
-    use alloy_eips::eip2718::Encodable2718;
-    use alloy_primitives::{PrimitiveSignature as Signature, TxKind, U256};
+    use crate::{
+        interop::MaybeInteropTransaction,
+        supervisor::{
+            spawn_mock_supervisor, SafetyLevel, SupervisorClient, CROSS_L2_INBOX_ADDRESS,
+            INVALID_INBOX_ENTRY,
+        },
+        OpPooledTransaction, OpTransactionValidator,
+    };
+    use alloy_consensus::{transaction::Recovered, TxEip1559};
+    use alloy_eips::{
+        eip2718::Encodable2718,
+        eip2930::{AccessList, AccessListItem},
+    };
+    use alloy_primitives::{Address, PrimitiveSignature as Signature, TxKind, B256, U256};
     use op_alloy_consensus::{OpTypedTransaction, TxDeposit};
     use reth_optimism_chainspec::OP_MAINNET;
     use reth_optimism_primitives::OpTransactionSigned;
-    use reth_provider::test_utils::MockEthProvider;
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
     use reth_transaction_pool::{
         blobstore::InMemoryBlobStore, validate::EthTransactionValidatorBuilder, TransactionOrigin,
-        TransactionValidationOutcome,
+        TransactionValidationOutcome, TransactionValidator,
     };
     #[test]
     fn validate_optimism_transaction() {
@@ -283,4 +319,56 @@ mod tests {
         };
         assert_eq!(err.to_string(), "transaction type not supported");
     }
+
+    #[tokio::test]
+    async fn validate_interop_transaction() {
+        let (url, _handle) = spawn_mock_supervisor().await;
+
+        let signer = Address::random();
+        let client = MockEthProvider::default().with_chain_spec(OP_MAINNET.clone());
+        client.add_account(signer, ExtendedAccount::new(0, U256::from(u64::MAX)));
+        let validator = EthTransactionValidatorBuilder::new(client)
+            .no_shanghai()
+            .no_cancun()
+            .build(InMemoryBlobStore::default());
+        let validator = OpTransactionValidator::new(validator)
+            .require_l1_data_gas_fee(false)
+            .with_supervisor_client(SupervisorClient::new(&url, SafetyLevel::CrossUnsafe).unwrap());
+
+        let interop_tx = |inbox_entry: B256| {
+            let tx = OpTypedTransaction::Eip1559(TxEip1559 {
+                chain_id: 10,
+                gas_limit: 100_000,
+                max_fee_per_gas: 1_000_000_000,
+                max_priority_fee_per_gas: 1_000_000,
+                to: TxKind::Call(Address::random()),
+                access_list: AccessList(vec![AccessListItem {
+                    address: CROSS_L2_INBOX_ADDRESS,
+                    storage_keys: vec![inbox_entry],
+                }]),
+                ..Default::default()
+            });
+            let signed_tx = OpTransactionSigned::new_unhashed(tx, Signature::test_signature());
+            let signed_recovered = Recovered::new_unchecked(signed_tx, signer);
+            let len = signed_recovered.encode_2718_len();
+            OpPooledTransaction::new(signed_recovered, len)
+        };
+
+        let outcome = validator
+            .validate_transaction(TransactionOrigin::External, interop_tx(B256::with_last_byte(1)))
+            .await;
+        let TransactionValidationOutcome::Valid { transaction, .. } = outcome else {
+            panic!("Expected valid transaction")
+        };
+        assert!(transaction.transaction().interop_deadline().is_some());
+
+        let outcome = validator
+            .validate_transaction(TransactionOrigin::External, interop_tx(INVALID_INBOX_ENTRY))
+            .await;
+        let err = match outcome {
+            TransactionValidationOutcome::Invalid(_, err) => err,
+            _ => panic!("Expected invalid transaction"),
+        };
+        assert!(err.to_string().starts_with("invalid cross-chain message"));
+    }
 }
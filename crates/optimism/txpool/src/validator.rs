@@ -1,3 +1,10 @@
+use crate::{
+    interop::MaybeInteropTransaction,
+    supervisor::{
+        parse_access_list_items_to_inbox_entries, ExecutingDescriptor, SupervisorClient,
+        TRANSACTION_VALIDITY_WINDOW_SECS,
+    },
+};
 use alloy_consensus::{BlockHeader, Transaction};
 use alloy_eips::Encodable2718;
 use parking_lot::RwLock;
@@ -9,13 +16,16 @@ use reth_primitives_traits::{
 };
 use reth_storage_api::{BlockReaderIdExt, StateProviderFactory};
 use reth_transaction_pool::{
-    EthPoolTransaction, EthTransactionValidator, TransactionOrigin, TransactionValidationOutcome,
-    TransactionValidator,
+    error::InvalidPoolTransactionError, EthPoolTransaction, EthTransactionValidator,
+    TransactionOrigin, TransactionValidationOutcome, TransactionValidator,
 };
 use revm_optimism::L1BlockInfo;
-use std::sync::{
-    atomic::{AtomicU64, Ordering},
-    Arc,
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 /// Tracks additional infos for the current block.
@@ -40,6 +50,9 @@ pub struct OpTransactionValidator<Client, Tx> {
     /// derived from the tracked L1 block info that is extracted from the first transaction in the
     /// L2 block.
     require_l1_data_gas_fee: bool,
+    /// Client for the supervisor that cross-chain messages are validated against, if interop is
+    /// enabled.
+    supervisor_client: Option<SupervisorClient>,
 }
 
 impl<Client, Tx> OpTransactionValidator<Client, Tx> {
@@ -77,6 +90,16 @@ impl<Client, Tx> OpTransactionValidator<Client, Tx> {
     pub const fn requires_l1_data_gas_fee(&self) -> bool {
         self.require_l1_data_gas_fee
     }
+
+    /// Validates cross-chain messages of transactions against the given supervisor.
+    pub fn with_supervisor_client(self, supervisor_client: SupervisorClient) -> Self {
+        Self { supervisor_client: Some(supervisor_client), ..self }
+    }
+
+    /// Returns the configured supervisor client, if any.
+    pub const fn supervisor_client(&self) -> Option<&SupervisorClient> {
+        self.supervisor_client.as_ref()
+    }
 }
 
 impl<Client, Tx> OpTransactionValidator<Client, Tx>
@@ -108,7 +131,12 @@ where
         inner: EthTransactionValidator<Client, Tx>,
         block_info: OpL1BlockInfo,
     ) -> Self {
-        Self { inner, block_info: Arc::new(block_info), require_l1_data_gas_fee: true }
+        Self {
+            inner,
+            block_info: Arc::new(block_info),
+            require_l1_data_gas_fee: true,
+            supervisor_client: None,
+        }
     }
 
     /// Update the L1 block info for the given header and system transaction, if any.
@@ -215,10 +243,78 @@ where
     }
 }
 
+impl<Client, Tx> OpTransactionValidator<Client, Tx>
+where
+    Client: ChainSpecProvider<ChainSpec: OpHardforks> + StateProviderFactory + BlockReaderIdExt,
+    Tx: EthPoolTransaction + MaybeInteropTransaction,
+{
+    /// Validates the cross-chain messages of a transaction against the configured supervisor.
+    ///
+    /// This expects the outcome of [`Self::validate_one`] and only checks valid transactions that
+    /// execute cross-chain messages. The supervisor is asked whether the messages are valid now
+    /// and remain valid for [`TRANSACTION_VALIDITY_WINDOW_SECS`], the transaction is tagged with
+    /// the resulting interop deadline.
+    pub async fn validate_interop(
+        &self,
+        outcome: TransactionValidationOutcome<Tx>,
+    ) -> TransactionValidationOutcome<Tx> {
+        let Some(supervisor_client) = &self.supervisor_client else { return outcome };
+
+        let TransactionValidationOutcome::Valid {
+            balance,
+            state_nonce,
+            transaction: mut valid_tx,
+            propagate,
+        } = outcome
+        else {
+            return outcome
+        };
+
+        let inbox_entries = valid_tx
+            .transaction()
+            .access_list()
+            .map(|access_list| {
+                parse_access_list_items_to_inbox_entries(access_list.iter())
+                    .copied()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        if !inbox_entries.is_empty() {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs()
+                .max(self.block_timestamp());
+            let descriptor =
+                ExecutingDescriptor::new(timestamp, Some(TRANSACTION_VALIDITY_WINDOW_SECS));
+
+            if let Err(err) = supervisor_client.check_access_list(&inbox_entries, descriptor).await
+            {
+                return TransactionValidationOutcome::Invalid(
+                    valid_tx.into_transaction(),
+                    InvalidPoolTransactionError::Other(Box::new(err)),
+                )
+            }
+
+            valid_tx
+                .transaction_mut()
+                .set_interop_deadline(timestamp + TRANSACTION_VALIDITY_WINDOW_SECS);
+        }
+
+        TransactionValidationOutcome::Valid {
+            balance,
+            state_nonce,
+            transaction: valid_tx,
+            propagate,
+        }
+    }
+}
+
 impl<Client, Tx> TransactionValidator for OpTransactionValidator<Client, Tx>
 where
     Client: ChainSpecProvider<ChainSpec: OpHardforks> + StateProviderFactory + BlockReaderIdExt,
-    Tx: EthPoolTransaction,
+    Tx: EthPoolTransaction + MaybeInteropTransaction,
 {
     type Transaction = Tx;
 
@@ -227,14 +323,19 @@ where
         origin: TransactionOrigin,
         transaction: Self::Transaction,
     ) -> TransactionValidationOutcome<Self::Transaction> {
-        self.validate_one(origin, transaction)
+        self.validate_interop(self.validate_one(origin, transaction)).await
     }
 
     async fn validate_transactions(
         &self,
         transactions: Vec<(TransactionOrigin, Self::Transaction)>,
     ) -> Vec<TransactionValidationOutcome<Self::Transaction>> {
-        self.validate_all(transactions)
+        futures_util::future::join_all(
+            self.validate_all(transactions)
+                .into_iter()
+                .map(|outcome| self.validate_interop(outcome)),
+        )
+        .await
     }
 
     fn on_new_head_block<B>(&self, new_tip_block: &SealedBlock<B>)
@@ -18,7 +18,7 @@ use reth_chainspec::EthereumHardforks;
 use reth_evm::state_change::post_block_withdrawals_balance_increments;
 use reth_payload_builder::{KeepPayloadJobAlive, PayloadId, PayloadJob, PayloadJobGenerator};
 use reth_payload_builder_primitives::PayloadBuilderError;
-use reth_payload_primitives::{BuiltPayload, PayloadBuilderAttributes, PayloadKind};
+use reth_payload_primitives::{BuiltPayload, MinerSettings, PayloadBuilderAttributes, PayloadKind};
 use reth_primitives::{NodePrimitives, SealedHeader};
 use reth_primitives_traits::{proofs, HeaderTy};
 use reth_provider::{BlockReaderIdExt, CanonStateNotification, StateProviderFactory};
@@ -453,6 +453,10 @@ where
         }
     }
 
+    fn built_payload(&self) -> Option<Self::BuiltPayload> {
+        self.best_payload.payload().cloned()
+    }
+
     fn payload_attributes(&self) -> Result<Self::PayloadAttributes, PayloadBuilderError> {
         Ok(self.config.attributes.clone())
     }
@@ -849,6 +853,12 @@ pub trait PayloadBuilder: Send + Sync + Clone {
         &self,
         config: PayloadConfig<Self::Attributes, HeaderForPayload<Self::BuiltPayload>>,
     ) -> Result<Self::BuiltPayload, PayloadBuilderError>;
+
+    /// Returns the settings of this builder that can be updated while the node is running, if
+    /// supported.
+    fn miner_settings(&self) -> Option<Arc<dyn MinerSettings>> {
+        None
+    }
 }
 
 /// Tells the payload builder how to react to payload request if there's no payload available yet.
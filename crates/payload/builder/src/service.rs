@@ -24,6 +24,7 @@ use std::{
 use tokio::sync::{
     broadcast, mpsc,
     oneshot::{self, Receiver},
+    watch,
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::{debug, info, trace, warn};
@@ -82,6 +83,14 @@ where
     ) -> Option<Result<T::PayloadBuilderAttributes, PayloadBuilderError>> {
         self.inner.payload_attributes(id).await
     }
+
+    /// Returns a receiver for the best payload of the most recent payload job, see
+    /// [`PayloadBuilderHandle::subscribe_pending_payload`].
+    pub async fn subscribe_pending_payload(
+        &self,
+    ) -> Result<watch::Receiver<Option<T::BuiltPayload>>, PayloadBuilderError> {
+        self.inner.subscribe_pending_payload().await
+    }
 }
 
 impl<T> PayloadStore<T>
@@ -166,6 +175,19 @@ impl<T: PayloadTypes> PayloadBuilderHandle<T> {
         Ok(PayloadEvents { receiver: rx.await? })
     }
 
+    /// Sends a message to the service to subscribe to the best payload of the most recent payload
+    /// job.
+    ///
+    /// The receiver is updated whenever the job built a better payload. This is the payload the
+    /// node is about to propose and can be used to serve the `pending` block.
+    pub async fn subscribe_pending_payload(
+        &self,
+    ) -> Result<watch::Receiver<Option<T::BuiltPayload>>, PayloadBuilderError> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.to_service.send(PayloadServiceCommand::SubscribePendingPayload(tx));
+        Ok(rx.await?)
+    }
+
     /// Returns the payload attributes associated with the given identifier.
     ///
     /// Note: this returns the attributes of the payload and does not resolve the job.
@@ -218,6 +240,10 @@ where
     chain_events: St,
     /// Payload events handler, used to broadcast and subscribe to payload events.
     payload_events: broadcast::Sender<Events<T>>,
+    /// Identifier of the most recently started payload job.
+    latest_payload_id: Option<PayloadId>,
+    /// The best payload of the most recently started payload job.
+    pending_payload: watch::Sender<Option<T::BuiltPayload>>,
 }
 
 const PAYLOAD_EVENTS_BUFFER_SIZE: usize = 20;
@@ -240,6 +266,7 @@ where
     pub fn new(generator: Gen, chain_events: St) -> (Self, PayloadBuilderHandle<T>) {
         let (service_tx, command_rx) = mpsc::unbounded_channel();
         let (payload_events, _) = broadcast::channel(PAYLOAD_EVENTS_BUFFER_SIZE);
+        let (pending_payload, _) = watch::channel(None);
 
         let service = Self {
             generator,
@@ -249,6 +276,8 @@ where
             metrics: Default::default(),
             chain_events,
             payload_events,
+            latest_payload_id: None,
+            pending_payload,
         };
 
         let handle = service.handle();
@@ -279,6 +308,35 @@ where
         res
     }
 
+    /// Publishes the best payload of the most recently started job if it changed.
+    fn update_pending_payload(&self) {
+        if self.pending_payload.receiver_count() == 0 {
+            // nobody is interested in the pending payload
+            return
+        }
+        let Some(id) = self.latest_payload_id else { return };
+        let Some(payload) = self
+            .payload_jobs
+            .iter()
+            .find(|(_, job_id)| *job_id == id)
+            .and_then(|(job, _)| job.built_payload())
+        else {
+            return
+        };
+        let payload: T::BuiltPayload = payload.into();
+
+        self.pending_payload.send_if_modified(|pending| {
+            if pending
+                .as_ref()
+                .is_some_and(|pending| pending.block().hash() == payload.block().hash())
+            {
+                return false
+            }
+            *pending = Some(payload);
+            true
+        });
+    }
+
     /// Returns the best payload for the given identifier that has been built so far and terminates
     /// the job if requested.
     fn resolve(
@@ -386,6 +444,8 @@ where
                 }
             }
 
+            this.update_pending_payload();
+
             // marker for exit condition
             let mut new_job = false;
 
@@ -407,6 +467,7 @@ where
                                     this.metrics.inc_initiated_jobs();
                                     new_job = true;
                                     this.payload_jobs.push((job, id));
+                                    this.latest_payload_id = Some(id);
                                     this.payload_events.send(Events::Attributes(attr.clone())).ok();
                                 }
                                 Err(err) => {
@@ -434,6 +495,9 @@ where
                         let new_rx = this.payload_events.subscribe();
                         let _ = tx.send(new_rx);
                     }
+                    PayloadServiceCommand::SubscribePendingPayload(tx) => {
+                        let _ = tx.send(this.pending_payload.subscribe());
+                    }
                 }
             }
 
@@ -466,6 +530,8 @@ pub enum PayloadServiceCommand<T: PayloadTypes> {
     ),
     /// Payload service events
     Subscribe(oneshot::Sender<broadcast::Receiver<Events<T>>>),
+    /// Subscribe to the best payload of the most recent payload job
+    SubscribePendingPayload(oneshot::Sender<watch::Receiver<Option<T::BuiltPayload>>>),
 }
 
 impl<T> fmt::Debug for PayloadServiceCommand<T>
@@ -485,6 +551,40 @@ where
             }
             Self::Resolve(f0, f1, _f2) => f.debug_tuple("Resolve").field(&f0).field(&f1).finish(),
             Self::Subscribe(f0) => f.debug_tuple("Subscribe").field(&f0).finish(),
+            Self::SubscribePendingPayload(f0) => {
+                f.debug_tuple("SubscribePendingPayload").field(&f0).finish()
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::spawn_test_payload_service, EthPayloadBuilderAttributes};
+    use alloy_primitives::{Address, B256};
+    use alloy_rpc_types::engine::PayloadAttributes;
+    use reth_ethereum_engine_primitives::EthEngineTypes;
+
+    #[tokio::test]
+    async fn subscribe_pending_payload() {
+        let handle = spawn_test_payload_service::<EthEngineTypes>();
+        let mut pending = handle.subscribe_pending_payload().await.unwrap();
+        assert!(pending.borrow().is_none());
+
+        let attributes = EthPayloadBuilderAttributes::new(
+            B256::ZERO,
+            PayloadAttributes {
+                timestamp: 1,
+                prev_randao: B256::ZERO,
+                suggested_fee_recipient: Address::ZERO,
+                withdrawals: None,
+                parent_beacon_block_root: None,
+            },
+        );
+        let id = handle.send_new_payload(attributes).await.unwrap().unwrap();
+
+        pending.changed().await.unwrap();
+        assert_eq!(pending.borrow().as_ref().unwrap().id(), id);
+    }
+}
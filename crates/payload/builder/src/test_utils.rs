@@ -94,6 +94,10 @@ impl PayloadJob for TestPayloadJob {
         ))
     }
 
+    fn built_payload(&self) -> Option<EthBuiltPayload> {
+        self.best_payload().ok()
+    }
+
     fn payload_attributes(&self) -> Result<EthPayloadBuilderAttributes, PayloadBuilderError> {
         Ok(self.attr.clone())
     }
@@ -33,6 +33,14 @@ pub trait PayloadJob: Future<Output = Result<(), PayloadBuilderError>> + Send +
     /// Note: This is never called by the CL.
     fn best_payload(&self) -> Result<Self::BuiltPayload, PayloadBuilderError>;
 
+    /// Returns the best payload that has been built so far, if any.
+    ///
+    /// Unlike [`PayloadJob::best_payload`], this never builds a payload on demand and returns
+    /// `None` if the job hasn't built a payload yet.
+    fn built_payload(&self) -> Option<Self::BuiltPayload> {
+        None
+    }
+
     /// Returns the payload attributes for the payload being built.
     fn payload_attributes(&self) -> Result<Self::PayloadAttributes, PayloadBuilderError>;
 
@@ -25,7 +25,8 @@ pub use error::{
 /// [`PayloadAttributes`] trait for ethereum mainnet and optimism types.
 mod traits;
 pub use traits::{
-    BuiltPayload, PayloadAttributes, PayloadAttributesBuilder, PayloadBuilderAttributes,
+    BuiltPayload, MinerSettings, PayloadAttributes, PayloadAttributesBuilder,
+    PayloadBuilderAttributes,
 };
 
 mod payload;
@@ -3,7 +3,7 @@ use alloy_eips::{
     eip4895::{Withdrawal, Withdrawals},
     eip7685::Requests,
 };
-use alloy_primitives::{Address, B256, U256};
+use alloy_primitives::{Address, Bytes, B256, U256};
 use alloy_rpc_types_engine::{PayloadAttributes as EthPayloadAttributes, PayloadId};
 use core::fmt;
 use reth_chain_state::ExecutedBlockWithTrieUpdates;
@@ -126,3 +126,19 @@ pub trait PayloadAttributesBuilder<Attributes>: Send + Sync + 'static {
     /// Return a new payload attribute from the builder.
     fn build(&self, timestamp: u64) -> Attributes;
 }
+
+/// Settings of a payload builder that can be updated while the node is running, e.g. via the
+/// `miner` RPC namespace.
+///
+/// Updated settings apply to all payloads that are built afterwards.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait MinerSettings: Send + Sync + fmt::Debug {
+    /// Sets the extra data of built blocks.
+    fn set_extra_data(&self, extra_data: Bytes);
+
+    /// Sets the minimum priority fee per gas a transaction must pay to be included.
+    fn set_min_priority_fee(&self, min_priority_fee: u128);
+
+    /// Sets the gas limit that the gas limit of built blocks moves toward.
+    fn set_gas_limit(&self, gas_limit: u64);
+}
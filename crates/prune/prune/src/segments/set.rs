@@ -1,6 +1,6 @@
 use crate::segments::{
-    AccountHistory, ReceiptsByLogs, Segment, SenderRecovery, StorageHistory, TransactionLookup,
-    UserReceipts,
+    AccountHistory, AddressTransactions, LogIndex, ReceiptsByLogs, Segment, SenderRecovery,
+    StorageHistory, TransactionLookup, UserReceipts,
 };
 use alloy_eips::eip2718::Encodable2718;
 use reth_db::{table::Value, transaction::DbTxMut};
@@ -64,6 +64,8 @@ where
             receipts,
             account_history,
             storage_history,
+            address_transactions,
+            log_index,
             receipts_log_filter,
         } = prune_modes;
 
@@ -78,6 +80,10 @@ where
             .segment_opt(account_history.map(AccountHistory::new))
             // Storage history
             .segment_opt(storage_history.map(StorageHistory::new))
+            // Address transactions
+            .segment_opt(address_transactions.map(AddressTransactions::new))
+            // Log index, must be pruned before the receipts it's built from
+            .segment_opt(log_index.map(LogIndex::new))
             // User receipts
             .segment_opt(receipts.map(UserReceipts::new))
             // Receipts by logs
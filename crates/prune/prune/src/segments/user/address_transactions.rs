@@ -0,0 +1,215 @@
+use crate::{
+    db_ext::DbTxPruneExt,
+    segments::{user::history::prune_history_indices, PruneInput, Segment},
+    PrunerError,
+};
+use itertools::Itertools;
+use reth_db::{tables, transaction::DbTxMut};
+use reth_db_api::models::ShardedKey;
+use reth_provider::{BlockReader, DBProvider};
+use reth_prune_types::{
+    PruneMode, PrunePurpose, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
+};
+use rustc_hash::FxHashMap;
+use tracing::{instrument, trace};
+
+/// Number of address transactions tables to prune in one step.
+///
+/// The address index consists of two tables: [`tables::AddressAppearances`] and
+/// [`tables::AddressTransactions`]. We want to prune them to the same transaction number.
+const ADDRESS_TRANSACTIONS_TABLES_TO_PRUNE: usize = 2;
+
+#[derive(Debug)]
+pub struct AddressTransactions {
+    mode: PruneMode,
+}
+
+impl AddressTransactions {
+    pub const fn new(mode: PruneMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl<Provider> Segment<Provider> for AddressTransactions
+where
+    Provider: DBProvider<Tx: DbTxMut> + BlockReader,
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::AddressTransactions
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        Some(self.mode)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::User
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let tx_range = match input.get_next_tx_num_range(provider)? {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No address transactions to prune");
+                return Ok(SegmentOutput::done())
+            }
+        };
+        let tx_range_end = *tx_range.end();
+
+        let mut limiter = if let Some(limit) = input.limiter.deleted_entries_limit() {
+            input.limiter.set_deleted_entries_limit(limit / ADDRESS_TRANSACTIONS_TABLES_TO_PRUNE)
+        } else {
+            input.limiter
+        };
+        if limiter.is_limit_reached() {
+            return Ok(SegmentOutput::not_done(
+                limiter.interrupt_reason(),
+                input.previous_checkpoint.map(SegmentOutputCheckpoint::from_prune_checkpoint),
+            ))
+        }
+
+        let mut last_appearance_pruned_transaction = None;
+        // Deleted appearances (addresses) with the highest transaction number deleted for that
+        // address.
+        let mut highest_deleted_addresses = FxHashMap::default();
+        let (pruned_appearances, done) =
+            provider.tx_ref().prune_table_with_range::<tables::AddressAppearances>(
+                tx_range,
+                &mut limiter,
+                |_| false,
+                |(tx_number, address)| {
+                    highest_deleted_addresses.insert(address, tx_number);
+                    last_appearance_pruned_transaction = Some(tx_number);
+                },
+            )?;
+        trace!(target: "pruner", pruned = %pruned_appearances, %done, "Pruned address transactions (appearances)");
+
+        let last_pruned_transaction = last_appearance_pruned_transaction
+            // If there's more appearances to prune, set the checkpoint transaction number to
+            // previous, so we could finish pruning its appearances on the next run.
+            .map(|tx_number| if done { tx_number } else { tx_number.saturating_sub(1) })
+            .unwrap_or(tx_range_end);
+
+        // Sort highest deleted transaction numbers by address and turn them into sharded keys.
+        let highest_sharded_keys = highest_deleted_addresses
+            .into_iter()
+            .sorted_unstable() // Unstable is fine because no equal keys exist in the map
+            .map(|(address, tx_number)| {
+                ShardedKey::new(address, tx_number.min(last_pruned_transaction))
+            });
+        let outcomes = prune_history_indices::<Provider, tables::AddressTransactions, _>(
+            provider,
+            highest_sharded_keys,
+            |a, b| a.key == b.key,
+        )?;
+        trace!(target: "pruner", ?outcomes, %done, "Pruned address transactions (indices)");
+
+        let last_pruned_block = provider
+            .transaction_block(last_pruned_transaction)?
+            .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))?
+            // If there's more appearances to prune, set the checkpoint block number to previous,
+            // so we could finish pruning its appearances on the next run.
+            .checked_sub(if done { 0 } else { 1 });
+
+        let progress = limiter.progress(done);
+
+        Ok(SegmentOutput {
+            progress,
+            pruned: pruned_appearances + outcomes.deleted,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: last_pruned_block,
+                tx_number: Some(last_pruned_transaction),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{AddressTransactions, PruneInput, PruneLimiter, Segment, SegmentOutput};
+    use alloy_primitives::{Address, B256};
+    use assert_matches::assert_matches;
+    use reth_db::{tables, transaction::DbTx};
+    use reth_db_api::models::ShardedKey;
+    use reth_provider::{AddressTransactionsWriter, DBProvider, DatabaseProviderFactory};
+    use reth_prune_types::{PruneMode, PruneProgress};
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn prune() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+        let tx_count = blocks.iter().map(|block| block.transaction_count()).sum::<usize>() as u64;
+
+        let sender = Address::random();
+        let recipient = Address::random();
+        {
+            let provider = db.factory.database_provider_rw().unwrap();
+            provider
+                .insert_address_appearances((0..tx_count).map(|tx_number| {
+                    let mut addresses = BTreeSet::from([sender]);
+                    if tx_number % 2 == 0 {
+                        addresses.insert(recipient);
+                    }
+                    (tx_number, addresses)
+                }))
+                .unwrap();
+            provider.commit().unwrap();
+        }
+
+        let to_block = 5;
+        let last_pruned_tx = db
+            .factory
+            .provider()
+            .unwrap()
+            .tx_ref()
+            .get::<tables::BlockBodyIndices>(to_block)
+            .unwrap()
+            .unwrap()
+            .last_tx_num();
+
+        let prune_mode = PruneMode::Before(to_block + 1);
+        let input =
+            PruneInput { previous_checkpoint: None, to_block, limiter: PruneLimiter::default() };
+        let segment = AddressTransactions::new(prune_mode);
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, input).unwrap();
+        assert_matches!(
+            result,
+            SegmentOutput { progress: PruneProgress::Finished, checkpoint: Some(checkpoint), .. }
+                if checkpoint.block_number == Some(to_block) &&
+                    checkpoint.tx_number == Some(last_pruned_tx)
+        );
+        provider.commit().expect("commit");
+
+        let appearances = db.table::<tables::AddressAppearances>().unwrap();
+        assert!(appearances.iter().all(|(tx_number, _)| *tx_number > last_pruned_tx));
+
+        let shards = db.table::<tables::AddressTransactions>().unwrap();
+        assert_eq!(
+            shards
+                .iter()
+                .find(|(key, _)| *key == ShardedKey::last(sender))
+                .map(|(_, list)| list.iter().collect::<Vec<_>>()),
+            Some((last_pruned_tx + 1..tx_count).collect())
+        );
+        assert_eq!(
+            shards
+                .iter()
+                .find(|(key, _)| *key == ShardedKey::last(recipient))
+                .map(|(_, list)| list.iter().collect::<Vec<_>>()),
+            Some((last_pruned_tx + 1..tx_count).filter(|tx_number| tx_number % 2 == 0).collect())
+        );
+    }
+}
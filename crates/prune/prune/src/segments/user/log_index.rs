@@ -0,0 +1,211 @@
+use crate::{
+    segments::{user::history::prune_history_indices, PruneInput, Segment},
+    PrunerError,
+};
+use alloy_consensus::TxReceipt;
+use itertools::Itertools;
+use reth_db::{tables, transaction::DbTxMut};
+use reth_db_api::models::ShardedKey;
+use reth_provider::{BlockReader, DBProvider};
+use reth_prune_types::{
+    PruneMode, PrunePurpose, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
+};
+use rustc_hash::FxHashMap;
+use tracing::{instrument, trace};
+
+/// Prunes the log index.
+///
+/// The index has no changesets, so the keys to prune are collected from the logs in the receipts
+/// of the pruned blocks. This segment must run before the receipts of the same blocks are pruned.
+#[derive(Debug)]
+pub struct LogIndex {
+    mode: PruneMode,
+}
+
+impl LogIndex {
+    pub const fn new(mode: PruneMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl<Provider> Segment<Provider> for LogIndex
+where
+    Provider: DBProvider<Tx: DbTxMut> + BlockReader,
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::LogIndex
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        Some(self.mode)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::User
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let range = match input.get_next_block_range() {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No log index to prune");
+                return Ok(SegmentOutput::done())
+            }
+        };
+        let range_end = *range.end();
+
+        let mut limiter = input.limiter;
+        if limiter.is_limit_reached() {
+            return Ok(SegmentOutput::not_done(
+                limiter.interrupt_reason(),
+                input.previous_checkpoint.map(SegmentOutputCheckpoint::from_prune_checkpoint),
+            ))
+        }
+
+        // Addresses and topics of the logs in the pruned blocks with the highest block number
+        // they appear in.
+        let mut highest_deleted_addresses = FxHashMap::default();
+        let mut highest_deleted_topics = FxHashMap::default();
+        let mut last_pruned_block = range_end;
+        let mut done = true;
+        for (block_number, body_indices) in
+            range.clone().zip(provider.block_body_indices_range(range)?)
+        {
+            // Receipts of a block are always walked as a whole, every log counts as one entry.
+            for receipt in provider.receipts_by_tx_range(body_indices.tx_num_range())? {
+                for log in receipt.logs() {
+                    highest_deleted_addresses.insert(log.address, block_number);
+                    for topic in log.topics() {
+                        highest_deleted_topics.insert(*topic, block_number);
+                    }
+                    limiter.increment_deleted_entries_count();
+                }
+            }
+
+            if block_number < range_end && limiter.is_limit_reached() {
+                last_pruned_block = block_number;
+                done = false;
+                break
+            }
+        }
+        trace!(
+            target: "pruner",
+            addresses = %highest_deleted_addresses.len(),
+            topics = %highest_deleted_topics.len(),
+            %done,
+            "Collected log index keys"
+        );
+
+        // Sort highest deleted block numbers by key and turn them into sharded keys.
+        // We did not use `BTreeMap` from the beginning, because it's inefficient for hashes.
+        let address_outcomes = prune_history_indices::<Provider, tables::LogAddressIndex, _>(
+            provider,
+            highest_deleted_addresses
+                .into_iter()
+                .sorted_unstable() // Unstable is fine because no equal keys exist in the map
+                .map(|(address, block_number)| ShardedKey::new(address, block_number)),
+            |a, b| a.key == b.key,
+        )?;
+        trace!(target: "pruner", ?address_outcomes, %done, "Pruned log index (addresses)");
+
+        let topic_outcomes = prune_history_indices::<Provider, tables::LogTopicIndex, _>(
+            provider,
+            highest_deleted_topics
+                .into_iter()
+                .sorted_unstable() // Unstable is fine because no equal keys exist in the map
+                .map(|(topic, block_number)| ShardedKey::new(topic, block_number)),
+            |a, b| a.key == b.key,
+        )?;
+        trace!(target: "pruner", ?topic_outcomes, %done, "Pruned log index (topics)");
+
+        let progress = limiter.progress(done);
+
+        Ok(SegmentOutput {
+            progress,
+            pruned: address_outcomes.deleted + topic_outcomes.deleted,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: Some(last_pruned_block),
+                tx_number: None,
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{LogIndex, PruneInput, PruneLimiter, Segment, SegmentOutput};
+    use alloy_primitives::B256;
+    use assert_matches::assert_matches;
+    use reth_db::tables;
+    use reth_provider::{DatabaseProviderFactory, LogIndexWriter};
+    use reth_prune_types::{PruneMode, PruneProgress};
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{
+        self, random_block_range, random_log, random_receipt, BlockRangeParams,
+    };
+
+    #[test]
+    fn prune() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let mut receipts = Vec::new();
+        let mut logs = Vec::new();
+        for block in &blocks {
+            for transaction in &block.body().transactions {
+                let mut receipt = random_receipt(&mut rng, transaction, Some(0));
+                receipt.logs.push(random_log(&mut rng, None, Some(2)));
+                logs.extend(receipt.logs.iter().map(|log| (block.number, log.clone())));
+                receipts.push((receipts.len() as u64, receipt));
+            }
+        }
+        db.insert_receipts(receipts).expect("insert receipts");
+
+        {
+            let provider = db.factory.database_provider_rw().unwrap();
+            provider
+                .insert_log_indices(logs.iter().map(|(block_number, log)| (*block_number, log)))
+                .unwrap();
+            provider.commit().unwrap();
+        }
+
+        let to_block = 5;
+        let prune_mode = PruneMode::Before(to_block + 1);
+        let input =
+            PruneInput { previous_checkpoint: None, to_block, limiter: PruneLimiter::default() };
+        let segment = LogIndex::new(prune_mode);
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, input).unwrap();
+        assert_matches!(
+            result,
+            SegmentOutput { progress: PruneProgress::Finished, checkpoint: Some(checkpoint), .. }
+                if checkpoint.block_number == Some(to_block) && checkpoint.tx_number.is_none()
+        );
+        provider.commit().expect("commit");
+
+        // Every log is emitted by a different address with different topics, so only the keys of
+        // logs in the blocks above the pruned one are left.
+        let expected_logs = logs.iter().filter(|(block_number, _)| *block_number > to_block);
+
+        let addresses = db.table::<tables::LogAddressIndex>().unwrap();
+        assert_eq!(addresses.len(), expected_logs.clone().count());
+        assert!(addresses
+            .iter()
+            .all(|(_, blocks)| blocks.iter().all(|block_number| block_number > to_block)));
+
+        let topics = db.table::<tables::LogTopicIndex>().unwrap();
+        assert_eq!(topics.len(), expected_logs.count() * 2);
+        assert!(topics
+            .iter()
+            .all(|(_, blocks)| blocks.iter().all(|block_number| block_number > to_block)));
+    }
+}
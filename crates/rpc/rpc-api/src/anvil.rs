@@ -39,7 +39,7 @@ pub trait AnvilApi {
     async fn anvil_set_interval_mining(&self, interval: u64) -> RpcResult<()>;
 
     /// Removes transactions from the pool.
-    #[method(name = "anvil_dropTransaction")]
+    #[method(name = "dropTransaction")]
     async fn anvil_drop_transaction(&self, tx_hash: B256) -> RpcResult<Option<B256>>;
 
     /// Resets the fork to a fresh forked state, and optionally update the fork config.
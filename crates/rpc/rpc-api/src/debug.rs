@@ -1,8 +1,10 @@
+use crate::{BadBlock, StateDump, StdTraceConfig, StorageRangeResult};
 use alloy_eips::{BlockId, BlockNumberOrTag};
 use alloy_genesis::ChainConfig;
+use alloy_json_rpc::RpcObject;
 use alloy_primitives::{Address, Bytes, B256};
 use alloy_rpc_types_debug::ExecutionWitness;
-use alloy_rpc_types_eth::{transaction::TransactionRequest, Block, Bundle, StateContext};
+use alloy_rpc_types_eth::{transaction::TransactionRequest, Bundle, StateContext};
 use alloy_rpc_types_trace::geth::{
     BlockTraceResult, GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, TraceResult,
 };
@@ -11,7 +13,7 @@ use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 /// Debug rpc interface.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "debug"))]
 #[cfg_attr(feature = "client", rpc(server, client, namespace = "debug"))]
-pub trait DebugApi {
+pub trait DebugApi<B: RpcObject> {
     /// Returns an RLP-encoded header.
     #[method(name = "getRawHeader")]
     async fn raw_header(&self, block_id: BlockId) -> RpcResult<Bytes>;
@@ -35,8 +37,10 @@ pub trait DebugApi {
     async fn raw_receipts(&self, block_id: BlockId) -> RpcResult<Vec<Bytes>>;
 
     /// Returns an array of recent bad blocks that the client has seen on the network.
+    ///
+    /// Each entry contains the block, its hash and its RLP encoding.
     #[method(name = "getBadBlocks")]
-    async fn bad_blocks(&self) -> RpcResult<Vec<Block>>;
+    async fn bad_blocks(&self) -> RpcResult<Vec<BadBlock<B>>>;
 
     /// Returns the structured logs created during the execution of EVM between two blocks
     /// (excluding start) as a JSON object.
@@ -164,7 +168,8 @@ pub trait DebugApi {
     /// in the page and the items have keys that come after the `start` key (hashed address).
     ///
     /// If incompletes is false, then accounts for which the key preimage (i.e: the address) doesn't
-    /// exist in db are skipped. NB: geth by default does not store preimages.
+    /// exist in db are skipped. NB: geth by default does not store preimages, and reth never does,
+    /// so an error is returned if incompletes is false.
     #[method(name = "accountRange")]
     async fn debug_account_range(
         &self,
@@ -174,7 +179,7 @@ pub trait DebugApi {
         nocode: bool,
         nostorage: bool,
         incompletes: bool,
-    ) -> RpcResult<()>;
+    ) -> RpcResult<StateDump>;
 
     /// Turns on block profiling for the given duration and writes profile data to disk. It uses a
     /// profile rate of 1 for most accurate information. If a different rate is desired, set the
@@ -254,17 +259,18 @@ pub trait DebugApi {
     async fn debug_get_modified_accounts_by_hash(
         &self,
         start_hash: B256,
-        end_hash: B256,
-    ) -> RpcResult<()>;
+        end_hash: Option<B256>,
+    ) -> RpcResult<Vec<Address>>;
 
     /// Returns all accounts that have changed between the two blocks specified. A change is defined
-    /// as a difference in nonce, balance, code hash or storage hash.
+    /// as a difference in nonce, balance, code hash or storage hash. With one parameter, returns
+    /// the list of accounts modified in the specified block.
     #[method(name = "getModifiedAccountsByNumber")]
     async fn debug_get_modified_accounts_by_number(
         &self,
         start_number: u64,
-        end_number: u64,
-    ) -> RpcResult<()>;
+        end_number: Option<u64>,
+    ) -> RpcResult<Vec<Address>>;
 
     /// Turns on Go runtime tracing for the given duration and writes trace data to disk.
     #[method(name = "goTrace")]
@@ -276,8 +282,8 @@ pub trait DebugApi {
     async fn debug_intermediate_roots(
         &self,
         block_hash: B256,
-        opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()>;
+        opts: Option<GethDebugTracingOptions>,
+    ) -> RpcResult<Vec<B256>>;
 
     /// Returns detailed runtime memory statistics.
     #[method(name = "memStats")]
@@ -330,22 +336,25 @@ pub trait DebugApi {
     #[method(name = "stacks")]
     async fn debug_stacks(&self) -> RpcResult<()>;
 
-    /// Used to obtain info about a block.
+    /// This method is similar to `debug_standardTraceBlockToFile`, but can be used to obtain info
+    /// about a block which has been rejected as invalid (for some reason).
     #[method(name = "standardTraceBadBlockToFile")]
     async fn debug_standard_trace_bad_block_to_file(
         &self,
-        block: BlockNumberOrTag,
-        opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()>;
+        block_hash: B256,
+        opts: Option<StdTraceConfig>,
+    ) -> RpcResult<Vec<String>>;
 
-    /// This method is similar to `debug_standardTraceBlockToFile`, but can be used to obtain info
-    /// about a block which has been rejected as invalid (for some reason).
+    /// Replays the block with the given hash and writes the EIP-3155 trace of each transaction, or
+    /// only of the transaction selected in the options, to a separate file.
+    ///
+    /// Returns the paths of the created files.
     #[method(name = "standardTraceBlockToFile")]
     async fn debug_standard_trace_block_to_file(
         &self,
-        block: BlockNumberOrTag,
-        opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()>;
+        block_hash: B256,
+        opts: Option<StdTraceConfig>,
+    ) -> RpcResult<Vec<String>>;
 
     /// Turns on CPU profiling indefinitely, writing to the given file.
     #[method(name = "startCPUProfile")]
@@ -374,7 +383,7 @@ pub trait DebugApi {
         contract_address: Address,
         key_start: B256,
         max_result: u64,
-    ) -> RpcResult<()>;
+    ) -> RpcResult<StorageRangeResult>;
 
     /// Returns the structured logs created during the execution of EVM against a block pulled
     /// from the pool of bad ones and returns them as a JSON object. For the second parameter see
@@ -383,8 +392,8 @@ pub trait DebugApi {
     async fn debug_trace_bad_block(
         &self,
         block_hash: B256,
-        opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()>;
+        opts: Option<GethDebugTracingOptions>,
+    ) -> RpcResult<Vec<TraceResult>>;
 
     /// Sets the logging verbosity ceiling. Log messages with level up to and including the given
     /// level will be printed.
@@ -0,0 +1,92 @@
+//! Types of the `debug` namespace that follow the format of geth.
+
+use alloy_primitives::{Address, Bytes, B256, U256};
+use serde::{Deserialize, Serialize};
+use serde_with::{base64::Base64, serde_as};
+use std::collections::BTreeMap;
+
+/// An account of a [`StateDump`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpAccount {
+    /// The balance of the account.
+    pub balance: U256,
+    /// The nonce of the account.
+    pub nonce: u64,
+    /// The storage root of the account.
+    pub root: B256,
+    /// The code hash of the account.
+    pub code_hash: B256,
+    /// The code of the account, omitted if code was not requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    /// The storage of the account by hashed slot, omitted if storage was not requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<BTreeMap<B256, U256>>,
+    /// The address of the account, if its preimage is known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    /// The hashed address of the account.
+    #[serde(default, rename = "key", skip_serializing_if = "Option::is_none")]
+    pub address_hash: Option<B256>,
+}
+
+/// A page of accounts of the state at a block, returned by `debug_accountRange`.
+#[serde_as]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateDump {
+    /// The state root of the block.
+    pub root: B256,
+    /// The accounts keyed by address, or by `pre(<hashed address>)` if the address is unknown.
+    pub accounts: BTreeMap<String, DumpAccount>,
+    /// The hashed address of the first account of the next page, if there are more accounts.
+    ///
+    /// Like geth, this is serialized as base64.
+    #[serde_as(as = "Option<Base64>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<Bytes>,
+}
+
+/// A storage slot of a [`StorageRangeResult`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageResult {
+    /// The storage key, if its preimage is known.
+    pub key: Option<B256>,
+    /// The value of the slot.
+    pub value: B256,
+}
+
+/// A page of storage slots of an account, returned by `debug_storageRangeAt`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageRangeResult {
+    /// The storage slots keyed by hashed slot.
+    pub storage: BTreeMap<B256, StorageResult>,
+    /// The hashed slot of the first slot of the next page, if there are more slots.
+    pub next_key: Option<B256>,
+}
+
+/// A block that failed validation, returned by `debug_getBadBlocks`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BadBlock<B> {
+    /// The hash of the block.
+    pub hash: B256,
+    /// The block with its full transactions, in the format of the network's `eth` namespace.
+    ///
+    /// Like geth, this is `null` if the block can't be converted, e.g. because the senders of its
+    /// transactions can't be recovered.
+    pub block: Option<B>,
+    /// The RLP encoding of the block.
+    pub rlp: Bytes,
+}
+
+/// Options of `debug_standardTraceBlockToFile` and `debug_standardTraceBadBlockToFile`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct StdTraceConfig {
+    /// Whether to include the memory in the trace.
+    pub enable_memory: bool,
+    /// The hash of the only transaction to trace, if set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<B256>,
+}
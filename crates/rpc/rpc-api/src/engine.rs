@@ -277,7 +277,7 @@ pub trait EngineEthApi<B: RpcObject, R: RpcObject> {
     async fn block_receipts(&self, block_id: BlockId) -> RpcResult<Option<Vec<R>>>;
 
     /// Sends signed transaction, returning its hash.
-    #[method(name = "sendRawTransaction")]
+    #[method(name = "sendRawTransaction", with_extensions)]
     async fn send_raw_transaction(&self, bytes: Bytes) -> RpcResult<B256>;
 
     /// Returns the receipt of a transaction by transaction hash.
@@ -17,6 +17,7 @@
 mod admin;
 mod anvil;
 mod debug;
+mod debug_types;
 mod engine;
 mod ganache;
 mod hardhat;
@@ -34,12 +35,23 @@ mod web3;
 /// re-export of all server traits
 pub use servers::*;
 
+pub use debug_types::{
+    BadBlock, DumpAccount, StateDump, StdTraceConfig, StorageRangeResult, StorageResult,
+};
+pub use otterscan::TransactionsWithReceipts;
+pub use txpool::{
+    TxpoolEvent, TxpoolFeeSimulation, TxpoolHistoryEntry, TxpoolParkedReason, TxpoolPropagateKind,
+    TxpoolSubPool, TxpoolTransactionEvent, TxpoolTransactionStatus,
+};
+
 /// Aggregates all server traits.
 pub mod servers {
     pub use crate::{
         admin::AdminApiServer,
+        anvil::AnvilApiServer,
         debug::{DebugApiServer, DebugExecutionWitnessApiServer},
         engine::{EngineApiServer, EngineEthApiServer, IntoEngineApiRpcModule},
+        hardhat::HardhatApiServer,
         mev::{MevFullApiServer, MevSimApiServer},
         miner::MinerApiServer,
         net::NetApiServer,
@@ -2,10 +2,28 @@ use alloy_eips::BlockId;
 use alloy_json_rpc::RpcObject;
 use alloy_primitives::{Address, Bytes, TxHash, B256};
 use alloy_rpc_types_trace::otterscan::{
-    BlockDetails, ContractCreator, InternalOperation, OtsBlockTransactions, TraceEntry,
-    TransactionsWithReceipts,
+    BlockDetails, ContractCreator, InternalOperation, OtsBlockTransactions, OtsTransactionReceipt,
+    TraceEntry,
 };
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// A page of the transactions of an address with their receipts, returned by
+/// `ots_searchTransactionsBefore` and `ots_searchTransactionsAfter`.
+///
+/// The transactions are in the format of the network's `eth` namespace.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionsWithReceipts<T> {
+    /// The transactions, from the newest to the oldest.
+    pub txs: Vec<T>,
+    /// The receipts of the transactions.
+    pub receipts: Vec<OtsTransactionReceipt>,
+    /// Whether the page contains the most recent transactions of the address.
+    pub first_page: bool,
+    /// Whether the page contains the oldest transactions of the address.
+    pub last_page: bool,
+}
 
 /// Otterscan rpc interface.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "ots"))]
@@ -69,7 +87,7 @@ pub trait Otterscan<T: RpcObject, H: RpcObject> {
         address: Address,
         block_number: u64,
         page_size: usize,
-    ) -> RpcResult<TransactionsWithReceipts>;
+    ) -> RpcResult<TransactionsWithReceipts<T>>;
 
     /// Gets paginated inbound/outbound transaction calls for a certain address.
     #[method(name = "searchTransactionsAfter")]
@@ -78,7 +96,7 @@ pub trait Otterscan<T: RpcObject, H: RpcObject> {
         address: Address,
         block_number: u64,
         page_size: usize,
-    ) -> RpcResult<TransactionsWithReceipts>;
+    ) -> RpcResult<TransactionsWithReceipts<T>>;
 
     /// Gets the transaction hash for a certain sender address, given its nonce.
     #[method(name = "getTransactionBySenderAndNonce")]
@@ -1,7 +1,112 @@
 use alloy_json_rpc::RpcObject;
-use alloy_primitives::Address;
+use alloy_primitives::{Address, TxHash, B256, U128, U64};
 use alloy_rpc_types_txpool::{TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolStatus};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_network_peers::PeerId;
+use serde::{Deserialize, Serialize};
+
+/// The projected state of the pool for hypothetical fees, returned by `txpool_simulateFees`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolFeeSimulation {
+    /// Transactions that would be pending, in the order they would be included in a block.
+    pub pending: Vec<TxHash>,
+    /// Transactions that would be evicted because the sub-pool limits are exceeded.
+    pub evicted: Vec<TxHash>,
+}
+
+/// The status of a transaction, returned by `txpool_transactionStatus` and `txpool_statusFrom`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolTransactionStatus {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// The sub-pool the transaction is in, `None` if it left the pool.
+    pub subpool: Option<TxpoolSubPool>,
+    /// The reasons why the transaction is parked, empty if it is pending or left the pool.
+    pub parked_reasons: Vec<TxpoolParkedReason>,
+    /// Recent events of the transaction, oldest first.
+    pub history: Vec<TxpoolHistoryEntry>,
+}
+
+/// The sub-pool of a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TxpoolSubPool {
+    /// Transactions that can't be included yet, e.g. because of a nonce gap.
+    Queued,
+    /// Transactions that can be included in the next block.
+    Pending,
+    /// Transactions with a fee cap below the base fee of the next block.
+    BaseFee,
+    /// Blob transactions that can't be included in the next block.
+    Blob,
+}
+
+/// The reason why a transaction is parked and not pending.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TxpoolParkedReason {
+    /// A transaction of the same sender with a lower nonce is parked.
+    ParkedAncestor,
+    /// There is a gap between the sender's on chain nonce and the transaction's nonce.
+    NonceGap,
+    /// The sender's balance does not cover the cumulative cost of this and prior transactions.
+    InsufficientBalance,
+    /// The transaction's gas limit exceeds the block gas limit.
+    ExceedsBlockGasLimit,
+    /// The transaction's `maxFeePerGas` is below the base fee of the pending block.
+    FeeCapBelowBaseFee,
+    /// The transaction's `maxFeePerBlobGas` is below the blob fee of the pending block.
+    BlobFeeCapBelowBlobFee,
+}
+
+/// A recorded event in the history of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolHistoryEntry {
+    /// Unix timestamp in seconds at which the event happened.
+    pub timestamp: u64,
+    /// What happened to the transaction.
+    pub event: TxpoolEvent,
+}
+
+/// An event of a transaction, streamed by `txpool_subscribeTransactionEvents`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolTransactionEvent {
+    /// The hash of the transaction the event belongs to.
+    pub hash: TxHash,
+    /// What happened to the transaction.
+    pub event: TxpoolEvent,
+}
+
+/// What happened to a transaction in the pool.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TxpoolEvent {
+    /// The transaction was added to the pending sub-pool.
+    Pending,
+    /// The transaction was added to the queued sub-pool.
+    Queued,
+    /// The transaction was included in the block with this hash.
+    Mined(B256),
+    /// The transaction was replaced by the transaction with this hash.
+    Replaced(TxHash),
+    /// The transaction was dropped due to the configured limits.
+    Discarded,
+    /// The transaction became invalid.
+    Invalid,
+    /// The transaction was propagated to peers.
+    Propagated(Vec<TxpoolPropagateKind>),
+}
+
+/// How a transaction was propagated to a peer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TxpoolPropagateKind {
+    /// The full transaction was sent to the peer.
+    Full(PeerId),
+    /// Only the hash of the transaction was announced to the peer.
+    Hash(PeerId),
+}
 
 /// Txpool rpc interface.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "txpool"))]
@@ -34,4 +139,41 @@ pub trait TxPoolApi<T: RpcObject> {
     /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_content) for more details
     #[method(name = "content")]
     async fn txpool_content(&self) -> RpcResult<TxpoolContent<T>>;
+
+    /// Returns the sub-pool of the transaction with the given hash, the reasons why it is parked
+    /// if it is not pending and its recent event history, such as replacements.
+    ///
+    /// After the transaction left the pool, e.g. because it was mined, its history is returned
+    /// while it is still tracked. Returns `null` if the transaction is neither in the pool nor
+    /// tracked.
+    #[method(name = "transactionStatus")]
+    async fn txpool_transaction_status(
+        &self,
+        hash: TxHash,
+    ) -> RpcResult<Option<TxpoolTransactionStatus>>;
+
+    /// Returns the status of all transactions of this address that are in the pool, see
+    /// `txpool_transactionStatus`.
+    #[method(name = "statusFrom")]
+    async fn txpool_status_from(&self, from: Address) -> RpcResult<Vec<TxpoolTransactionStatus>>;
+
+    /// Returns the pending transactions and the eviction victims of the pool if the pending block
+    /// had the given base fee and blob fee, without modifying the pool.
+    ///
+    /// If the blob fee is omitted, the currently tracked blob fee is used.
+    #[method(name = "simulateFees")]
+    async fn txpool_simulate_fees(
+        &self,
+        base_fee: U64,
+        blob_fee: Option<U128>,
+    ) -> RpcResult<TxpoolFeeSimulation>;
+
+    /// Creates a subscription that streams all events of transactions in the pool, e.g. when a
+    /// transaction is added, promoted, replaced, discarded or mined.
+    #[subscription(
+        name = "subscribeTransactionEvents" => "transactionEvent",
+        unsubscribe = "unsubscribeTransactionEvents",
+        item = TxpoolTransactionEvent
+    )]
+    async fn txpool_subscribe_transaction_events(&self) -> jsonrpsee::core::SubscriptionResult;
 }
@@ -2,7 +2,7 @@ use std::{net::SocketAddr, path::PathBuf};
 
 use jsonrpsee::server::ServerBuilder;
 use reth_node_core::{args::RpcServerArgs, utils::get_or_create_jwt_secret_from_path};
-use reth_rpc::ValidationApiConfig;
+use reth_rpc::{DebugApiConfig, ValidationApiConfig};
 use reth_rpc_eth_types::{EthConfig, EthStateCacheConfig, GasPriceOracleConfig};
 use reth_rpc_layer::{JwtError, JwtSecret};
 use reth_rpc_server_types::RpcModuleSelection;
@@ -31,6 +31,9 @@ pub trait RethRpcServerConfig {
     /// The configured ethereum RPC settings.
     fn flashbots_config(&self) -> ValidationApiConfig;
 
+    /// The configured debug RPC settings.
+    fn debug_config(&self) -> DebugApiConfig;
+
     /// Returns state cache configuration.
     fn state_cache_config(&self) -> EthStateCacheConfig;
 
@@ -112,6 +115,14 @@ impl RethRpcServerConfig for RpcServerArgs {
         }
     }
 
+    fn debug_config(&self) -> DebugApiConfig {
+        let mut config = DebugApiConfig::default();
+        if let Some(trace_dir) = &self.rpc_debug_trace_dir {
+            config.trace_dir = trace_dir.clone();
+        }
+        config
+    }
+
     fn state_cache_config(&self) -> EthStateCacheConfig {
         EthStateCacheConfig {
             max_blocks: self.rpc_state_cache.max_blocks,
@@ -134,8 +145,11 @@ impl RethRpcServerConfig for RpcServerArgs {
     }
 
     fn transport_rpc_module_config(&self) -> TransportRpcModuleConfig {
-        let mut config = TransportRpcModuleConfig::default()
-            .with_config(RpcModuleConfig::new(self.eth_config(), self.flashbots_config()));
+        let mut config = TransportRpcModuleConfig::default().with_config(RpcModuleConfig::new(
+            self.eth_config(),
+            self.flashbots_config(),
+            self.debug_config(),
+        ));
 
         if self.http {
             config = config.with_http(
@@ -20,6 +20,13 @@ pub enum ServerKind {
 }
 
 impl ServerKind {
+    /// Returns the address of the server.
+    pub const fn addr(&self) -> SocketAddr {
+        match self {
+            Self::Http(addr) | Self::WS(addr) | Self::WsHttp(addr) | Self::Auth(addr) => *addr,
+        }
+    }
+
     /// Returns the appropriate flags for each variant.
     pub const fn flags(&self) -> &'static str {
         match self {
@@ -188,7 +188,8 @@ use jsonrpsee::{
     core::RegisterMethodError,
     server::{
         middleware::rpc::{RpcService, RpcServiceT},
-        AlreadyStoppedError, IdProvider, RpcServiceBuilder, ServerHandle,
+        serve_with_graceful_shutdown, stop_channel, AlreadyStoppedError, HttpRequest, IdProvider,
+        RpcServiceBuilder, ServerHandle, TowerServiceBuilder,
     },
     Methods, RpcModule,
 };
@@ -198,25 +199,30 @@ use reth_evm::{execute::BlockExecutorProvider, ConfigureEvm};
 use reth_network_api::{noop::NoopNetwork, NetworkInfo, Peers};
 use reth_primitives::NodePrimitives;
 use reth_provider::{
-    AccountReader, BlockReader, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    FullRpcProvider, ProviderBlock, ProviderHeader, ProviderReceipt, StateProviderFactory,
+    AccountReader, AddressTransactionsReader, BlockReader, CanonStateSubscriptions,
+    ChainSpecProvider, ChangeSetReader, FullRpcProvider, HashedStateRangeReader, LogIndexReader,
+    ProviderBlock, ProviderHeader, ProviderReceipt, PruneCheckpointReader, StageCheckpointReader,
+    StateProviderFactory, StorageChangeSetReader,
 };
 use reth_rpc::{
-    AdminApi, DebugApi, EngineEthApi, EthBundle, MinerApi, NetApi, OtterscanApi, RPCApi, RethApi,
-    TraceApi, TxPoolApi, ValidationApiConfig, Web3Api,
+    AdminApi, BadBlockStore, DebugApi, DebugApiConfig, EngineEthApi, EthBundle, MinerApi, NetApi,
+    OtterscanApi, RPCApi, RethApi, TraceApi, TxPoolApi, ValidationApiConfig, Web3Api,
 };
 use reth_rpc_api::servers::*;
 use reth_rpc_eth_api::{
-    helpers::{Call, EthApiSpec, EthTransactions, LoadPendingBlock, TraceExt},
+    helpers::{Call, EthApiSpec, EthTransactions, LoadPendingBlock, SpawnBlocking, TraceExt},
     EthApiServer, EthApiTypes, FullEthApiServer, RpcBlock, RpcHeader, RpcReceipt, RpcTransaction,
 };
 use reth_rpc_eth_types::{EthConfig, EthStateCache, EthSubscriptionIdProvider};
 use reth_rpc_layer::{AuthLayer, Claims, CompressionLayer, JwtAuthValidator, JwtSecret};
+use reth_rpc_server_types::ClientAddr;
 use reth_tasks::{pool::BlockingTaskGuard, TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::{noop::NoopTransactionPool, TransactionPool};
 use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
 use tower::Layer;
 use tower_http::cors::CorsLayer;
+use tracing::debug;
 
 pub use cors::CorsDomainError;
 
@@ -288,6 +294,13 @@ where
             Header = <BlockExecutor::Primitives as NodePrimitives>::BlockHeader,
         > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>,
     >,
+    EthApi::Provider: AddressTransactionsReader
+        + ChangeSetReader
+        + HashedStateRangeReader
+        + LogIndexReader
+        + PruneCheckpointReader
+        + StageCheckpointReader
+        + StorageChangeSetReader,
     BlockExecutor: BlockExecutorProvider,
 {
     let module_config = module_config.into();
@@ -607,6 +620,13 @@ where
                 Header = <BlockExecutor::Primitives as NodePrimitives>::BlockHeader,
             > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>,
         >,
+        EthApi::Provider: AddressTransactionsReader
+            + ChangeSetReader
+            + HashedStateRangeReader
+            + LogIndexReader
+            + PruneCheckpointReader
+            + StageCheckpointReader
+            + StorageChangeSetReader,
     {
         let Self { provider, pool, network, executor, evm_config, block_executor, consensus } =
             self;
@@ -708,6 +728,13 @@ where
                 Header = <BlockExecutor::Primitives as NodePrimitives>::BlockHeader,
             > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>,
         >,
+        EthApi::Provider: AddressTransactionsReader
+            + ChangeSetReader
+            + HashedStateRangeReader
+            + LogIndexReader
+            + PruneCheckpointReader
+            + StageCheckpointReader
+            + StorageChangeSetReader,
         Pool: TransactionPool<Transaction = <EthApi::Pool as TransactionPool>::Transaction>,
     {
         let mut modules = TransportRpcModules::default();
@@ -753,6 +780,8 @@ pub struct RpcModuleConfig {
     eth: EthConfig,
     /// `flashbots` namespace settings
     flashbots: ValidationApiConfig,
+    /// `debug` namespace settings
+    debug: DebugApiConfig,
 }
 
 // === impl RpcModuleConfig ===
@@ -764,8 +793,12 @@ impl RpcModuleConfig {
     }
 
     /// Returns a new RPC module config given the eth namespace config
-    pub const fn new(eth: EthConfig, flashbots: ValidationApiConfig) -> Self {
-        Self { eth, flashbots }
+    pub const fn new(
+        eth: EthConfig,
+        flashbots: ValidationApiConfig,
+        debug: DebugApiConfig,
+    ) -> Self {
+        Self { eth, flashbots, debug }
     }
 
     /// Get a reference to the eth namespace config
@@ -777,6 +810,16 @@ impl RpcModuleConfig {
     pub fn eth_mut(&mut self) -> &mut EthConfig {
         &mut self.eth
     }
+
+    /// Get a reference to the debug namespace config
+    pub const fn debug(&self) -> &DebugApiConfig {
+        &self.debug
+    }
+
+    /// Get a mutable reference to the debug namespace config
+    pub fn debug_mut(&mut self) -> &mut DebugApiConfig {
+        &mut self.debug
+    }
 }
 
 /// Configures [`RpcModuleConfig`]
@@ -784,6 +827,7 @@ impl RpcModuleConfig {
 pub struct RpcModuleConfigBuilder {
     eth: Option<EthConfig>,
     flashbots: Option<ValidationApiConfig>,
+    debug: Option<DebugApiConfig>,
 }
 
 // === impl RpcModuleConfigBuilder ===
@@ -801,10 +845,20 @@ impl RpcModuleConfigBuilder {
         self
     }
 
+    /// Configures a custom debug namespace config
+    pub fn debug(mut self, debug: DebugApiConfig) -> Self {
+        self.debug = Some(debug);
+        self
+    }
+
     /// Consumes the type and creates the [`RpcModuleConfig`]
     pub fn build(self) -> RpcModuleConfig {
-        let Self { eth, flashbots } = self;
-        RpcModuleConfig { eth: eth.unwrap_or_default(), flashbots: flashbots.unwrap_or_default() }
+        let Self { eth, flashbots, debug } = self;
+        RpcModuleConfig {
+            eth: eth.unwrap_or_default(),
+            flashbots: flashbots.unwrap_or_default(),
+            debug: debug.unwrap_or_default(),
+        }
     }
 
     /// Get a reference to the eth namespace config, if any
@@ -845,6 +899,10 @@ pub struct RpcRegistryInner<
     eth: EthHandlers<Provider, EthApi>,
     /// to put trace calls behind semaphore
     blocking_pool_guard: BlockingTaskGuard,
+    /// The blocks that were rejected as invalid, served by the `debug` namespace
+    bad_block_store: BadBlockStore,
+    /// `debug` namespace settings
+    debug_config: DebugApiConfig,
     /// Contains the [Methods] of a module
     modules: HashMap<RethRpcModule, Methods>,
 }
@@ -904,6 +962,8 @@ where
             consensus,
             modules: Default::default(),
             blocking_pool_guard,
+            bad_block_store: Default::default(),
+            debug_config: config.debug,
             block_executor,
         }
     }
@@ -925,6 +985,14 @@ where
         &self.eth
     }
 
+    /// Returns the [`BadBlockStore`] of the `debug` namespace.
+    ///
+    /// Blocks that are rejected as invalid should be inserted into the store to make them available
+    /// through `debug_getBadBlocks` and `debug_traceBadBlock`.
+    pub const fn bad_block_store(&self) -> &BadBlockStore {
+        &self.bad_block_store
+    }
+
     /// Returns the [`EthStateCache`] frontend
     ///
     /// This will spawn exactly one [`EthStateCache`] service if this is the first time the cache is
@@ -1035,6 +1103,7 @@ where
     pub fn register_ots(&mut self) -> &mut Self
     where
         EthApi: TraceExt + EthTransactions,
+        EthApi::Provider: AddressTransactionsReader + StageCheckpointReader,
     {
         let otterscan_api = self.otterscan_api();
         self.modules.insert(RethRpcModule::Ots, otterscan_api.into_rpc().into());
@@ -1050,6 +1119,10 @@ where
     where
         EthApi: EthApiSpec + EthTransactions + TraceExt,
         BlockExecutor::Primitives: NodePrimitives<Block = ProviderBlock<EthApi::Provider>>,
+        EthApi::Provider: ChangeSetReader
+            + HashedStateRangeReader
+            + PruneCheckpointReader
+            + StorageChangeSetReader,
     {
         let debug_api = self.debug_api();
         self.modules.insert(RethRpcModule::Debug, debug_api.into_rpc().into());
@@ -1158,6 +1231,8 @@ where
             self.eth_api().clone(),
             self.blocking_pool_guard.clone(),
             self.block_executor.clone(),
+            self.bad_block_store.clone(),
+            self.debug_config.clone(),
         )
     }
 
@@ -1197,6 +1272,13 @@ where
             Header = <BlockExecutor::Primitives as NodePrimitives>::BlockHeader,
         > + CanonStateSubscriptions<Primitives = BlockExecutor::Primitives>,
     >,
+    EthApi::Provider: AddressTransactionsReader
+        + ChangeSetReader
+        + HashedStateRangeReader
+        + LogIndexReader
+        + PruneCheckpointReader
+        + StageCheckpointReader
+        + StorageChangeSetReader,
     BlockExecutor: BlockExecutorProvider,
     Consensus: FullConsensus<BlockExecutor::Primitives, Error = ConsensusError> + Clone + 'static,
 {
@@ -1285,6 +1367,8 @@ where
                             eth_api.clone(),
                             self.blocking_pool_guard.clone(),
                             self.block_executor.clone(),
+                            self.bad_block_store.clone(),
+                            self.debug_config.clone(),
                         )
                         .into_rpc()
                         .into(),
@@ -1317,6 +1401,7 @@ where
                         RethRpcModule::Txpool => TxPoolApi::new(
                             self.eth.api.pool().clone(),
                             self.eth.api.tx_resp_builder().clone(),
+                            self.eth.api.tracing_task_pool().clone(),
                         )
                         .into_rpc()
                         .into(),
@@ -1344,6 +1429,8 @@ where
                                 .into_rpc()
                                 .into()
                         }
+                        // only relevant for dev nodes and configured in `EthereumAddOns`
+                        RethRpcModule::Anvil | RethRpcModule::Hardhat => Default::default(),
                     })
                     .clone()
             })
@@ -1360,8 +1447,9 @@ where
 ///
 /// Http and WS share the same settings: [`ServerBuilder`].
 ///
-/// Once the [`RpcModule`] is built via [`RpcModuleBuilder`] the servers can be started, See also
-/// [`ServerBuilder::build`] and [`Server::start`](jsonrpsee::server::Server::start).
+/// Once the [`RpcModule`] is built via [`RpcModuleBuilder`] the servers can be started. The http
+/// and ws servers insert the [`ClientAddr`] of the connection into the extensions of every
+/// request.
 #[derive(Debug)]
 pub struct RpcServerConfig<RpcMiddleware = Identity> {
     /// Configs for JSON-RPC Http.
@@ -1637,13 +1725,7 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             modules.config.ensure_ws_http_identical()?;
 
             if let Some(builder) = self.http_server_config {
-                let server = builder
-                    .set_http_middleware(
-                        tower::ServiceBuilder::new()
-                            .option_layer(Self::maybe_cors_layer(cors)?)
-                            .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
-                            .option_layer(Self::maybe_compression_layer()),
-                    )
+                let builder = builder
                     .set_rpc_middleware(
                         self.rpc_middleware.clone().layer(
                             modules
@@ -1654,16 +1736,18 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                                 .unwrap_or_default(),
                         ),
                     )
-                    .build(http_socket_addr)
-                    .await
-                    .map_err(|err| {
-                        RpcError::server_error(err, ServerKind::WsHttp(http_socket_addr))
-                    })?;
-                let addr = server.local_addr().map_err(|err| {
-                    RpcError::server_error(err, ServerKind::WsHttp(http_socket_addr))
-                })?;
+                    .to_service_builder();
+                let cors = Self::maybe_cors_layer(cors)?;
+                let (listener, addr) = bind_server(ServerKind::WsHttp(http_socket_addr)).await?;
                 if let Some(module) = modules.http.as_ref().or(modules.ws.as_ref()) {
-                    let handle = server.start(module.clone());
+                    let handle = start_server(
+                        listener,
+                        builder,
+                        module.clone(),
+                        cors,
+                        Self::maybe_jwt_layer(self.jwt_secret),
+                        Self::maybe_compression_layer(),
+                    );
                     http_handle = Some(handle.clone());
                     ws_handle = Some(handle);
                 }
@@ -1685,58 +1769,56 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         let mut http_server = None;
 
         if let Some(builder) = self.ws_server_config {
-            let server = builder
+            let builder = builder
                 .ws_only()
-                .set_http_middleware(
-                    tower::ServiceBuilder::new()
-                        .option_layer(Self::maybe_cors_layer(self.ws_cors_domains.clone())?)
-                        .option_layer(Self::maybe_jwt_layer(self.jwt_secret)),
-                )
                 .set_rpc_middleware(
                     self.rpc_middleware
                         .clone()
                         .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default()),
                 )
-                .build(ws_socket_addr)
-                .await
-                .map_err(|err| RpcError::server_error(err, ServerKind::WS(ws_socket_addr)))?;
-
-            let addr = server
-                .local_addr()
-                .map_err(|err| RpcError::server_error(err, ServerKind::WS(ws_socket_addr)))?;
+                .to_service_builder();
+            let cors = Self::maybe_cors_layer(self.ws_cors_domains.clone())?;
+            let (listener, addr) = bind_server(ServerKind::WS(ws_socket_addr)).await?;
 
             ws_local_addr = Some(addr);
-            ws_server = Some(server);
+            ws_server = Some((listener, builder, cors));
         }
 
         if let Some(builder) = self.http_server_config {
-            let server = builder
-                .http_only()
-                .set_http_middleware(
-                    tower::ServiceBuilder::new()
-                        .option_layer(Self::maybe_cors_layer(self.ws_cors_domains.clone())?)
-                        .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
-                        .option_layer(Self::maybe_compression_layer()),
-                )
-                .set_rpc_middleware(
-                    self.rpc_middleware.clone().layer(
+            let builder =
+                builder
+                    .http_only()
+                    .set_rpc_middleware(self.rpc_middleware.clone().layer(
                         modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
-                    ),
-                )
-                .build(http_socket_addr)
-                .await
-                .map_err(|err| RpcError::server_error(err, ServerKind::Http(http_socket_addr)))?;
-            let local_addr = server
-                .local_addr()
-                .map_err(|err| RpcError::server_error(err, ServerKind::Http(http_socket_addr)))?;
-            http_local_addr = Some(local_addr);
-            http_server = Some(server);
+                    ))
+                    .to_service_builder();
+            let cors = Self::maybe_cors_layer(self.ws_cors_domains.clone())?;
+            let (listener, addr) = bind_server(ServerKind::Http(http_socket_addr)).await?;
+
+            http_local_addr = Some(addr);
+            http_server = Some((listener, builder, cors));
         }
 
-        http_handle = http_server
-            .map(|http_server| http_server.start(modules.http.clone().expect("http server error")));
-        ws_handle = ws_server
-            .map(|ws_server| ws_server.start(modules.ws.clone().expect("ws server error")));
+        http_handle = http_server.map(|(listener, builder, cors)| {
+            start_server(
+                listener,
+                builder,
+                modules.http.clone().expect("http server error"),
+                cors,
+                Self::maybe_jwt_layer(self.jwt_secret),
+                Self::maybe_compression_layer(),
+            )
+        });
+        ws_handle = ws_server.map(|(listener, builder, cors)| {
+            start_server(
+                listener,
+                builder,
+                modules.ws.clone().expect("ws server error"),
+                cors,
+                Self::maybe_jwt_layer(self.jwt_secret),
+                None,
+            )
+        });
         Ok(RpcServerHandle {
             http_local_addr,
             ws_local_addr,
@@ -1749,6 +1831,73 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
     }
 }
 
+/// Binds the listener of a http and/or ws server to the address of the given [`ServerKind`].
+///
+/// Returns the listener and the address it is bound to.
+async fn bind_server(kind: ServerKind) -> Result<(TcpListener, SocketAddr), RpcError> {
+    let listener =
+        TcpListener::bind(kind.addr()).await.map_err(|err| RpcError::server_error(err, kind))?;
+    let local_addr = listener.local_addr().map_err(|err| RpcError::server_error(err, kind))?;
+    Ok((listener, local_addr))
+}
+
+/// Serves the module to the connections of the listener until the returned handle is stopped.
+///
+/// Unlike [`Server::start`](jsonrpsee::server::Server::start), this inserts the [`ClientAddr`] of
+/// the connection into the extensions of every request, e.g. for the per client rate limits of the
+/// transaction pool.
+fn start_server<RpcMiddleware>(
+    listener: TcpListener,
+    builder: TowerServiceBuilder<RpcMiddleware, Identity>,
+    module: RpcModule<()>,
+    cors: Option<CorsLayer>,
+    jwt: Option<AuthLayer<JwtAuthValidator>>,
+    compression: Option<CompressionLayer>,
+) -> ServerHandle
+where
+    RpcMiddleware: Layer<RpcService> + Clone + Send + 'static,
+    for<'a> <RpcMiddleware as Layer<RpcService>>::Service: Send + Sync + 'static + RpcServiceT<'a>,
+{
+    let (stop_handle, server_handle) = stop_channel();
+    let methods = Methods::from(module);
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, client) = tokio::select! {
+                res = listener.accept() => match res {
+                    Ok(conn) => conn,
+                    Err(err) => {
+                        debug!(target: "rpc", %err, "Failed to accept connection");
+                        continue
+                    }
+                },
+                _ = stop_handle.clone().shutdown() => break,
+            };
+
+            let service = builder
+                .clone()
+                .set_http_middleware(
+                    tower::ServiceBuilder::new()
+                        .map_request(move |mut request: HttpRequest| {
+                            request.extensions_mut().insert(ClientAddr(client));
+                            request
+                        })
+                        .option_layer(cors.clone())
+                        .option_layer(jwt.clone())
+                        .option_layer(compression.clone()),
+                )
+                .build(methods.clone(), stop_handle.clone());
+            tokio::spawn(serve_with_graceful_shutdown(
+                stream,
+                service,
+                stop_handle.clone().shutdown(),
+            ));
+        }
+    });
+
+    server_handle
+}
+
 /// Holds modules to be installed per transport type
 ///
 /// # Example
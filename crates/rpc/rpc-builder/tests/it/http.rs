@@ -361,11 +361,20 @@ where
 {
     let block_id = BlockId::Number(BlockNumberOrTag::default());
 
-    DebugApiClient::raw_header(client, block_id).await.unwrap();
-    DebugApiClient::raw_block(client, block_id).await.unwrap_err();
-    DebugApiClient::raw_transaction(client, B256::default()).await.unwrap();
-    DebugApiClient::raw_receipts(client, block_id).await.unwrap();
-    assert!(is_unimplemented(DebugApiClient::bad_blocks(client).await.err().unwrap()));
+    DebugApiClient::<Block>::raw_header(client, block_id).await.unwrap();
+    DebugApiClient::<Block>::raw_block(client, block_id).await.unwrap_err();
+    DebugApiClient::<Block>::raw_transaction(client, B256::default()).await.unwrap();
+    DebugApiClient::<Block>::raw_receipts(client, block_id).await.unwrap();
+    DebugApiClient::<Block>::bad_blocks(client).await.unwrap();
+    DebugApiClient::<Block>::debug_trace_bad_block(client, B256::default(), None)
+        .await
+        .unwrap_err();
+    DebugApiClient::<Block>::debug_standard_trace_block_to_file(client, B256::default(), None)
+        .await
+        .unwrap_err();
+    DebugApiClient::<Block>::debug_standard_trace_bad_block_to_file(client, B256::default(), None)
+        .await
+        .unwrap_err();
 }
 
 async fn test_basic_net_calls<C>(client: &C)
@@ -1,16 +1,19 @@
 use crate::utils::{test_address, test_rpc_builder};
 use alloy_rpc_types_eth::{Block, Header, Receipt, Transaction};
 use jsonrpsee::{
+    core::client::ClientT,
+    rpc_params,
     server::{middleware::rpc::RpcServiceT, RpcServiceBuilder},
     types::Request,
-    MethodResponse,
+    MethodResponse, RpcModule,
 };
 use reth_rpc::EthApi;
 use reth_rpc_builder::{RpcServerConfig, TransportRpcModuleConfig};
 use reth_rpc_eth_api::EthApiClient;
-use reth_rpc_server_types::RpcModuleSelection;
+use reth_rpc_server_types::{ClientAddr, RpcModuleSelection};
 use std::{
     future::Future,
+    net::IpAddr,
     pin::Pin,
     sync::{
         atomic::{AtomicUsize, Ordering},
@@ -79,3 +82,29 @@ async fn test_rpc_middleware() {
     let count = mylayer.count.load(Ordering::Relaxed);
     assert_eq!(count, 1);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_client_addr_extension() {
+    let builder = test_rpc_builder();
+    let mut modules = builder.build(
+        TransportRpcModuleConfig::set_http(RpcModuleSelection::Standard),
+        Box::new(EthApi::with_spawner),
+    );
+    let mut module = RpcModule::new(());
+    module
+        .register_method("test_clientAddr", |_, _, ext| {
+            ext.get::<ClientAddr>().map(|client| client.0.ip().to_string()).unwrap_or_default()
+        })
+        .unwrap();
+    modules.merge_configured(module).unwrap();
+
+    let handle = RpcServerConfig::http(Default::default())
+        .with_http_address(test_address())
+        .start(&modules)
+        .await
+        .unwrap();
+
+    let client = handle.http_client().unwrap();
+    let ip: String = client.request("test_clientAddr", rpc_params![]).await.unwrap();
+    assert!(ip.parse::<IpAddr>().unwrap().is_loopback());
+}
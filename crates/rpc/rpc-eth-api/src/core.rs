@@ -12,8 +12,8 @@ use alloy_rpc_types_eth::{
     StateContext, SyncStatus, Work,
 };
 use alloy_serde::JsonStorageKey;
-use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, Extensions};
+use reth_rpc_server_types::{result::internal_rpc_err, ClientAddr, ToRpcResult};
 use tracing::trace;
 
 use crate::{
@@ -334,7 +334,9 @@ pub trait EthApi<T: RpcObject, B: RpcObject, R: RpcObject, H: RpcObject> {
     async fn send_transaction(&self, request: TransactionRequest) -> RpcResult<B256>;
 
     /// Sends signed transaction, returning its hash.
-    #[method(name = "sendRawTransaction")]
+    ///
+    /// The [`ClientAddr`] in the extensions of the request is passed to the pool.
+    #[method(name = "sendRawTransaction", with_extensions)]
     async fn send_raw_transaction(&self, bytes: Bytes) -> RpcResult<B256>;
 
     /// Returns an Ethereum specific signature with: sign(keccak256("\x19Ethereum Signed Message:\n"
@@ -775,9 +777,10 @@ where
     }
 
     /// Handler for: `eth_sendRawTransaction`
-    async fn send_raw_transaction(&self, tx: Bytes) -> RpcResult<B256> {
+    async fn send_raw_transaction(&self, ext: &Extensions, tx: Bytes) -> RpcResult<B256> {
         trace!(target: "rpc::eth", ?tx, "Serving eth_sendRawTransaction");
-        Ok(EthTransactions::send_raw_transaction(self, tx).await?)
+        let client = ext.get::<ClientAddr>().map(|client| client.0.ip());
+        Ok(EthTransactions::send_raw_transaction_from(self, tx, client).await?)
     }
 
     /// Handler for: `eth_sign`
@@ -16,10 +16,10 @@ use reth_evm::{
     NextBlockEnvAttributes,
 };
 use reth_primitives::{InvalidTransactionError, RecoveredBlock};
-use reth_primitives_traits::Receipt;
+use reth_primitives_traits::{BlockBody as _, Receipt};
 use reth_provider::{
     BlockReader, BlockReaderIdExt, ChainSpecProvider, ProviderBlock, ProviderError, ProviderHeader,
-    ProviderReceipt, ProviderTx, ReceiptProvider, StateProviderFactory,
+    ProviderReceipt, ProviderTx, ReceiptProviderIdExt, StateProviderFactory,
 };
 use reth_revm::{
     database::StateProviderDatabase,
@@ -72,6 +72,9 @@ pub trait LoadPendingBlock:
 
     /// Configures the [`EvmEnv`] for the pending block
     ///
+    /// The pending block is either the block received from the CL or the payload the local
+    /// payload builder is currently building on top of the canonical head.
+    ///
     /// If no pending block is available, this will derive it from the `latest` block
     #[expect(clippy::type_complexity)]
     fn pending_block_env_and_cfg(
@@ -87,10 +90,13 @@ pub trait LoadPendingBlock:
         if let Some(block) =
             self.provider().pending_block_with_senders().map_err(Self::Error::from_eth_err)?
         {
+            // the pending block is not part of the canonical chain, so its receipts must be looked
+            // up by tag
             if let Some(receipts) = self
                 .provider()
-                .receipts_by_block(block.hash().into())
+                .receipts_by_block_id(BlockNumberOrTag::Pending.into())
                 .map_err(Self::Error::from_eth_err)?
+                .filter(|receipts| receipts.len() == block.body().transaction_count())
             {
                 // Note: for the PENDING block we assume it is past the known merge block and
                 // thus this will not fail when looking up the total
@@ -23,7 +23,7 @@ use reth_provider::{
 use reth_rpc_eth_types::{utils::binary_search, EthApiError, SignError, TransactionSource};
 use reth_rpc_types_compat::transaction::TransactionCompat;
 use reth_transaction_pool::{PoolTransaction, TransactionOrigin, TransactionPool};
-use std::sync::Arc;
+use std::{net::IpAddr, sync::Arc};
 
 /// Transaction related functions for the [`EthApiServer`](crate::EthApiServer) trait in
 /// the `eth_` namespace.
@@ -60,6 +60,20 @@ pub trait EthTransactions: LoadTransaction<Provider: BlockReaderIdExt> {
     fn send_raw_transaction(
         &self,
         tx: Bytes,
+    ) -> impl Future<Output = Result<B256, Self::Error>> + Send {
+        self.send_raw_transaction_from(tx, None)
+    }
+
+    /// Decodes and recovers the transaction and submits it to the pool on behalf of the RPC
+    /// client with the given address, if known.
+    ///
+    /// The address is passed to the admission rules of the pool, which can rate limit clients.
+    ///
+    /// Returns the hash of the transaction.
+    fn send_raw_transaction_from(
+        &self,
+        tx: Bytes,
+        client: Option<IpAddr>,
     ) -> impl Future<Output = Result<B256, Self::Error>> + Send;
 
     /// Returns the transaction by hash.
@@ -0,0 +1,8 @@
+use std::net::SocketAddr;
+
+/// The address of the client that sent an RPC request.
+///
+/// The http and ws servers insert this into the extensions of every request, so that handlers
+/// that are registered with extensions can tell clients apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClientAddr(pub SocketAddr);
@@ -12,6 +12,9 @@
 pub mod constants;
 pub mod result;
 
+mod client;
+pub use client::ClientAddr;
+
 mod module;
 pub use module::{RethRpcModule, RpcModuleSelection};
 
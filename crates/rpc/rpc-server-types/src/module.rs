@@ -273,6 +273,10 @@ pub enum RethRpcModule {
     Miner,
     /// `mev_` module
     Mev,
+    /// `anvil_` module, only available on dev nodes
+    Anvil,
+    /// `hardhat_` module, only available on dev nodes
+    Hardhat,
 }
 
 // === impl RethRpcModule ===
@@ -324,6 +328,8 @@ impl FromStr for RethRpcModule {
             "flashbots" => Self::Flashbots,
             "miner" => Self::Miner,
             "mev" => Self::Mev,
+            "anvil" => Self::Anvil,
+            "hardhat" => Self::Hardhat,
             _ => return Err(ParseError::VariantNotFound),
         })
     }
@@ -574,5 +580,12 @@ mod test {
         let result = RpcModuleSelection::from_str("unknown");
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), ParseError::VariantNotFound);
+
+        // Test dev selections: "anvil,hardhat"
+        let result = RpcModuleSelection::from_str("anvil,hardhat");
+        assert!(result.is_ok());
+        let expected_selection =
+            RpcModuleSelection::from([RethRpcModule::Anvil, RethRpcModule::Hardhat]);
+        assert_eq!(result.unwrap(), expected_selection);
     }
 }
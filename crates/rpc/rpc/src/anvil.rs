@@ -0,0 +1,884 @@
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
+use alloy_consensus::{
+    constants::EMPTY_WITHDRAWALS, BlockHeader, Header, EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH,
+};
+use alloy_dyn_abi::TypedData;
+use alloy_eips::{
+    eip2718::{Decodable2718, Encodable2718},
+    eip4895::Withdrawals,
+    eip7685::{Requests, EMPTY_REQUESTS_HASH},
+    eip7840::BlobParams,
+    merge::BEACON_NONCE,
+    BlockId,
+};
+use alloy_primitives::{Address, Bytes, PrimitiveSignature as Signature, B256, U256};
+use alloy_rpc_types_anvil::{Forking, Metadata, MineOptions, NodeInfo};
+use alloy_rpc_types_eth::{Block, TransactionRequest};
+use async_trait::async_trait;
+use jsonrpsee::{core::RpcResult, types::ErrorObjectOwned};
+use parking_lot::{Mutex, RwLock};
+use reth_chain_state::{ExecutedBlock, ExecutedBlockWithTrieUpdates};
+use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks};
+use reth_engine_primitives::{LocalMinerError, LocalMinerHandle};
+use reth_primitives::{BlockBody, EthPrimitives, TransactionSigned};
+use reth_primitives_traits::{Block as _, RecoveredBlock};
+use reth_provider::{
+    BlockReaderIdExt, ExecutionOutcome, HeaderProvider, ProviderError, StateProviderFactory,
+};
+use reth_revm::{
+    database::StateProviderDatabase,
+    db::{states::bundle_state::BundleRetention, State},
+};
+use reth_rpc_api::{AnvilApiServer, HardhatApiServer};
+use reth_rpc_eth_api::helpers::{signer::Result as SignResult, EthSigner};
+use reth_rpc_eth_types::{EthApiError, SignError};
+use reth_rpc_server_types::result::internal_rpc_err;
+use reth_transaction_pool::TransactionPool;
+use revm::{
+    state::{Account, Bytecode, EvmState, EvmStorageSlot},
+    Database, DatabaseCommit,
+};
+
+/// `anvil` and `hardhat` API implementation for dev nodes.
+///
+/// State modifications are committed as empty blocks without transactions that are built on top of
+/// the current head and inserted through the [`LocalMinerHandle`]. These blocks do not apply any
+/// system calls, hence they can not be re-executed by other nodes.
+pub struct AnvilApi<Provider, Pool> {
+    inner: Arc<AnvilApiInner<Provider, Pool>>,
+}
+
+// === impl AnvilApi ===
+
+impl<Provider, Pool> AnvilApi<Provider, Pool> {
+    /// Create a new instance of the [`AnvilApi`]
+    pub fn new(provider: Provider, pool: Pool, miner: LocalMinerHandle<EthPrimitives>) -> Self {
+        let inner = Arc::new(AnvilApiInner {
+            provider,
+            pool,
+            miner,
+            impersonated: ImpersonatedSigner::default(),
+            snapshots: Mutex::new(Vec::new()),
+        });
+        Self { inner }
+    }
+
+    /// The provider that can interact with the chain.
+    pub fn provider(&self) -> &Provider {
+        &self.inner.provider
+    }
+
+    /// The transaction pool.
+    pub fn pool(&self) -> &Pool {
+        &self.inner.pool
+    }
+
+    /// Returns the signer for the impersonated accounts.
+    ///
+    /// This needs to be added to the signers of the `eth` API, so that `eth_sendTransaction` can
+    /// send transactions from impersonated accounts.
+    pub fn impersonated_signer(&self) -> ImpersonatedSigner {
+        self.inner.impersonated.clone()
+    }
+
+    /// Starts impersonating the given account.
+    fn impersonate(&self, address: Address) -> RpcResult<()> {
+        self.enable_executed_blocks()?;
+        self.inner.impersonated.impersonate(address);
+        Ok(())
+    }
+
+    /// Enables or disables impersonating all accounts.
+    fn set_auto_impersonate(&self, enabled: bool) -> RpcResult<()> {
+        if enabled {
+            self.enable_executed_blocks()?;
+        }
+        self.inner.impersonated.set_auto_impersonate(enabled);
+        Ok(())
+    }
+
+    /// Makes the miner insert the blocks it builds without re-executing them, which is required
+    /// for blocks with transactions of impersonated accounts.
+    ///
+    /// This stays enabled when impersonation is stopped, since the pool may still contain such
+    /// transactions.
+    fn enable_executed_blocks(&self) -> RpcResult<()> {
+        self.inner.miner.set_insert_executed_blocks(true).map_err(miner_error)
+    }
+}
+
+impl<Provider, Pool> AnvilApi<Provider, Pool>
+where
+    Provider: BlockReaderIdExt<Header = Header>
+        + StateProviderFactory
+        + ChainSpecProvider<ChainSpec: EthChainSpec + EthereumHardforks>
+        + Clone
+        + 'static,
+    Pool: TransactionPool + 'static,
+{
+    /// Mines the given number of blocks, at least one.
+    async fn mine(&self, blocks: Option<U256>, interval: Option<U256>) -> RpcResult<()> {
+        let blocks = blocks.map_or(1, |blocks| blocks.saturating_to::<u64>().max(1));
+        let interval = interval.map(|interval| interval.saturating_to());
+        self.inner.miner.mine(blocks, interval).await.map_err(miner_error)
+    }
+
+    /// Commits the given account changes in a new block.
+    async fn modify_account(&self, address: Address, change: AccountChange) -> RpcResult<()> {
+        let provider = self.provider().clone();
+        self.inner
+            .miner
+            .insert_block(Box::new(move |parent_hash, timestamp| {
+                build_dev_block(&provider, parent_hash, timestamp, vec![(address, change)])
+            }))
+            .await
+            .map_err(miner_error)?;
+        Ok(())
+    }
+
+    /// Takes a snapshot of the chain and returns its id.
+    fn snapshot(&self) -> RpcResult<U256> {
+        let head = self
+            .provider()
+            .latest_header()
+            .map_err(EthApiError::from)?
+            .ok_or(EthApiError::HeaderNotFound(BlockId::latest()))?;
+
+        let mut snapshots = self.inner.snapshots.lock();
+        snapshots.push(head.hash());
+        Ok(U256::from(snapshots.len() - 1))
+    }
+
+    /// Reverts the chain to the snapshot with the given id.
+    ///
+    /// The snapshot and all snapshots taken after it are removed. The snapshotted block becomes the
+    /// head again, the blocks above it are removed and their transactions are dropped.
+    async fn revert(&self, id: U256) -> RpcResult<bool> {
+        let snapshot = {
+            let mut snapshots = self.inner.snapshots.lock();
+            let Some(id) = usize::try_from(id).ok().filter(|id| *id < snapshots.len()) else {
+                return Ok(false)
+            };
+            let snapshot = snapshots[id];
+            snapshots.truncate(id);
+            snapshot
+        };
+
+        let header = self
+            .provider()
+            .header(&snapshot)
+            .map_err(EthApiError::from)?
+            .ok_or(EthApiError::HeaderNotFound(snapshot.into()))?;
+        self.inner.miner.revert(snapshot, header.timestamp()).await.map_err(miner_error)?;
+        Ok(true)
+    }
+
+    /// Removes the transaction with the given hash from the pool.
+    fn drop_transaction(&self, tx_hash: B256) -> Option<B256> {
+        self.pool().remove_transactions(vec![tx_hash]).pop().map(|tx| *tx.hash())
+    }
+}
+
+#[async_trait]
+impl<Provider, Pool> AnvilApiServer for AnvilApi<Provider, Pool>
+where
+    Provider: BlockReaderIdExt<Header = Header>
+        + StateProviderFactory
+        + ChainSpecProvider<ChainSpec: EthChainSpec + EthereumHardforks>
+        + Clone
+        + 'static,
+    Pool: TransactionPool + 'static,
+{
+    /// Handler for `anvil_impersonateAccount`
+    async fn anvil_impersonate_account(&self, address: Address) -> RpcResult<()> {
+        self.impersonate(address)
+    }
+
+    /// Handler for `anvil_stopImpersonatingAccount`
+    async fn anvil_stop_impersonating_account(&self, address: Address) -> RpcResult<()> {
+        self.inner.impersonated.stop_impersonating(address);
+        Ok(())
+    }
+
+    /// Handler for `anvil_autoImpersonateAccount`
+    async fn anvil_auto_impersonate_account(&self, enabled: bool) -> RpcResult<()> {
+        self.set_auto_impersonate(enabled)
+    }
+
+    /// Handler for `anvil_getAutomine`
+    async fn anvil_get_automine(&self) -> RpcResult<bool> {
+        self.inner.miner.automine().await.map_err(miner_error)
+    }
+
+    /// Handler for `anvil_mine`
+    async fn anvil_mine(&self, blocks: Option<U256>, interval: Option<U256>) -> RpcResult<()> {
+        self.mine(blocks, interval).await
+    }
+
+    /// Handler for `anvil_setAutomine`
+    async fn anvil_set_automine(&self, enabled: bool) -> RpcResult<()> {
+        self.inner.miner.set_automine(enabled).map_err(miner_error)
+    }
+
+    /// Handler for `anvil_setIntervalMining`
+    async fn anvil_set_interval_mining(&self, interval: u64) -> RpcResult<()> {
+        let interval = (interval > 0).then(|| Duration::from_secs(interval));
+        self.inner.miner.set_interval_mining(interval).map_err(miner_error)
+    }
+
+    /// Handler for `anvil_dropTransaction`
+    async fn anvil_drop_transaction(&self, tx_hash: B256) -> RpcResult<Option<B256>> {
+        Ok(self.drop_transaction(tx_hash))
+    }
+
+    /// Handler for `anvil_reset`
+    async fn anvil_reset(&self, _fork: Option<Forking>) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("anvil_reset is not supported").into())
+    }
+
+    /// Handler for `anvil_setRpcUrl`
+    async fn anvil_set_rpc_url(&self, _url: String) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("anvil_setRpcUrl is not supported").into())
+    }
+
+    /// Handler for `anvil_setBalance`
+    async fn anvil_set_balance(&self, address: Address, balance: U256) -> RpcResult<()> {
+        self.modify_account(address, AccountChange::Balance(balance)).await
+    }
+
+    /// Handler for `anvil_setCode`
+    async fn anvil_set_code(&self, address: Address, code: Bytes) -> RpcResult<()> {
+        self.modify_account(address, AccountChange::Code(code)).await
+    }
+
+    /// Handler for `anvil_setNonce`
+    async fn anvil_set_nonce(&self, address: Address, nonce: U256) -> RpcResult<()> {
+        self.modify_account(address, AccountChange::Nonce(nonce.saturating_to())).await
+    }
+
+    /// Handler for `anvil_setStorageAt`
+    async fn anvil_set_storage_at(
+        &self,
+        address: Address,
+        slot: U256,
+        value: B256,
+    ) -> RpcResult<bool> {
+        self.modify_account(address, AccountChange::Storage(slot, value.into())).await?;
+        Ok(true)
+    }
+
+    /// Handler for `anvil_setCoinbase`
+    async fn anvil_set_coinbase(&self, _address: Address) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("anvil_setCoinbase is not supported").into())
+    }
+
+    /// Handler for `anvil_setChainId`
+    async fn anvil_set_chain_id(&self, _chain_id: u64) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("anvil_setChainId is not supported").into())
+    }
+
+    /// Handler for `anvil_setLoggingEnabled`
+    async fn anvil_set_logging_enabled(&self, _enabled: bool) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("anvil_setLoggingEnabled is not supported").into())
+    }
+
+    /// Handler for `anvil_setMinGasPrice`
+    async fn anvil_set_min_gas_price(&self, _gas_price: U256) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("anvil_setMinGasPrice is not supported").into())
+    }
+
+    /// Handler for `anvil_setNextBlockBaseFeePerGas`
+    async fn anvil_set_next_block_base_fee_per_gas(&self, _base_fee: U256) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("anvil_setNextBlockBaseFeePerGas is not supported").into())
+    }
+
+    /// Handler for `anvil_setTime`
+    async fn anvil_set_time(&self, timestamp: u64) -> RpcResult<u64> {
+        let moved = self.inner.miner.set_time(timestamp).await.map_err(miner_error)?;
+        Ok(moved.max(0) as u64)
+    }
+
+    /// Handler for `anvil_dumpState`
+    async fn anvil_dump_state(&self) -> RpcResult<Bytes> {
+        Err(EthApiError::Unsupported("anvil_dumpState is not supported").into())
+    }
+
+    /// Handler for `anvil_loadState`
+    async fn anvil_load_state(&self, _state: Bytes) -> RpcResult<bool> {
+        Err(EthApiError::Unsupported("anvil_loadState is not supported").into())
+    }
+
+    /// Handler for `anvil_nodeInfo`
+    async fn anvil_node_info(&self) -> RpcResult<NodeInfo> {
+        Err(EthApiError::Unsupported("anvil_nodeInfo is not supported").into())
+    }
+
+    /// Handler for `anvil_metadata`
+    async fn anvil_metadata(&self) -> RpcResult<Metadata> {
+        Err(EthApiError::Unsupported("anvil_metadata is not supported").into())
+    }
+
+    /// Handler for `anvil_snapshot`
+    async fn anvil_snapshot(&self) -> RpcResult<U256> {
+        self.snapshot()
+    }
+
+    /// Handler for `anvil_revert`
+    async fn anvil_revert(&self, id: U256) -> RpcResult<bool> {
+        self.revert(id).await
+    }
+
+    /// Handler for `anvil_increaseTime`
+    async fn anvil_increase_time(&self, seconds: U256) -> RpcResult<i64> {
+        self.inner.miner.increase_time(seconds.saturating_to()).await.map_err(miner_error)
+    }
+
+    /// Handler for `anvil_setNextBlockTimestamp`
+    async fn anvil_set_next_block_timestamp(&self, seconds: u64) -> RpcResult<()> {
+        self.inner.miner.set_next_block_timestamp(seconds).await.map_err(miner_error)
+    }
+
+    /// Handler for `anvil_setBlockGasLimit`
+    async fn anvil_set_block_gas_limit(&self, _gas_limit: U256) -> RpcResult<bool> {
+        Err(EthApiError::Unsupported("anvil_setBlockGasLimit is not supported").into())
+    }
+
+    /// Handler for `anvil_setBlockTimestampInterval`
+    async fn anvil_set_block_timestamp_interval(&self, seconds: u64) -> RpcResult<()> {
+        self.inner.miner.set_block_timestamp_interval(Some(seconds)).map_err(miner_error)
+    }
+
+    /// Handler for `anvil_removeBlockTimestampInterval`
+    async fn anvil_remove_block_timestamp_interval(&self) -> RpcResult<bool> {
+        self.inner.miner.set_block_timestamp_interval(None).map_err(miner_error)?;
+        Ok(true)
+    }
+
+    /// Handler for `anvil_mine_detailed`
+    async fn anvil_mine_detailed(&self, _opts: Option<MineOptions>) -> RpcResult<Vec<Block>> {
+        Err(EthApiError::Unsupported("anvil_mine_detailed is not supported").into())
+    }
+
+    /// Handler for `anvil_enableTraces`
+    async fn anvil_enable_traces(&self) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("anvil_enableTraces is not supported").into())
+    }
+
+    /// Handler for `anvil_removePoolTransactions`
+    async fn anvil_remove_pool_transactions(&self, address: Address) -> RpcResult<()> {
+        self.pool().remove_transactions_by_sender(address);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Provider, Pool> HardhatApiServer for AnvilApi<Provider, Pool>
+where
+    Provider: BlockReaderIdExt<Header = Header>
+        + StateProviderFactory
+        + ChainSpecProvider<ChainSpec: EthChainSpec + EthereumHardforks>
+        + Clone
+        + 'static,
+    Pool: TransactionPool + 'static,
+{
+    /// Handler for `hardhat_dropTransaction`
+    async fn hardhat_drop_transaction(&self, tx_hash: B256) -> RpcResult<bool> {
+        Ok(self.drop_transaction(tx_hash).is_some())
+    }
+
+    /// Handler for `hardhat_impersonateAccount`
+    async fn hardhat_impersonate_account(&self, address: Address) -> RpcResult<()> {
+        self.impersonate(address)
+    }
+
+    /// Handler for `hardhat_getAutomine`
+    async fn hardhat_get_automine(&self) -> RpcResult<bool> {
+        self.inner.miner.automine().await.map_err(miner_error)
+    }
+
+    /// Handler for `hardhat_metadata`
+    async fn hardhat_metadata(&self) -> RpcResult<Metadata> {
+        Err(EthApiError::Unsupported("hardhat_metadata is not supported").into())
+    }
+
+    /// Handler for `hardhat_mine`
+    async fn hardhat_mine(&self, blocks: Option<U256>, interval: Option<U256>) -> RpcResult<()> {
+        self.mine(blocks, interval).await
+    }
+
+    /// Handler for `hardhat_reset`
+    async fn hardhat_reset(&self, _fork: Option<Forking>) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("hardhat_reset is not supported").into())
+    }
+
+    /// Handler for `hardhat_setBalance`
+    async fn hardhat_set_balance(&self, address: Address, balance: U256) -> RpcResult<()> {
+        self.modify_account(address, AccountChange::Balance(balance)).await
+    }
+
+    /// Handler for `hardhat_setCode`
+    async fn hardhat_set_code(&self, address: Address, code: Bytes) -> RpcResult<()> {
+        self.modify_account(address, AccountChange::Code(code)).await
+    }
+
+    /// Handler for `hardhat_setCoinbase`
+    async fn hardhat_set_coinbase(&self, _address: Address) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("hardhat_setCoinbase is not supported").into())
+    }
+
+    /// Handler for `hardhat_setLoggingEnabled`
+    async fn hardhat_set_logging_enabled(&self, _enabled: bool) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("hardhat_setLoggingEnabled is not supported").into())
+    }
+
+    /// Handler for `hardhat_setMinGasPrice`
+    async fn hardhat_set_min_gas_price(&self, _gas_price: U256) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("hardhat_setMinGasPrice is not supported").into())
+    }
+
+    /// Handler for `hardhat_setNextBlockBaseFeePerGas`
+    async fn hardhat_set_next_block_base_fee_per_gas(
+        &self,
+        _base_fee_per_gas: U256,
+    ) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("hardhat_setNextBlockBaseFeePerGas is not supported").into())
+    }
+
+    /// Handler for `hardhat_setPrevRandao`
+    async fn hardhat_set_prev_randao(&self, _prev_randao: B256) -> RpcResult<()> {
+        Err(EthApiError::Unsupported("hardhat_setPrevRandao is not supported").into())
+    }
+
+    /// Handler for `hardhat_setNonce`
+    async fn hardhat_set_nonce(&self, address: Address, nonce: U256) -> RpcResult<()> {
+        self.modify_account(address, AccountChange::Nonce(nonce.saturating_to())).await
+    }
+
+    /// Handler for `hardhat_setStorageAt`
+    async fn hardhat_set_storage_at(
+        &self,
+        address: Address,
+        slot: U256,
+        value: B256,
+    ) -> RpcResult<()> {
+        self.modify_account(address, AccountChange::Storage(slot, value.into())).await
+    }
+
+    /// Handler for `hardhat_stopImpersonatingAccount`
+    async fn hardhat_stop_impersonating_account(&self, address: Address) -> RpcResult<()> {
+        self.inner.impersonated.stop_impersonating(address);
+        Ok(())
+    }
+}
+
+impl<Provider, Pool> std::fmt::Debug for AnvilApi<Provider, Pool> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnvilApi").finish_non_exhaustive()
+    }
+}
+
+impl<Provider, Pool> Clone for AnvilApi<Provider, Pool> {
+    fn clone(&self) -> Self {
+        Self { inner: Arc::clone(&self.inner) }
+    }
+}
+
+struct AnvilApiInner<Provider, Pool> {
+    /// The provider that can interact with the chain.
+    provider: Provider,
+    /// The transaction pool.
+    pool: Pool,
+    /// Handle to the local miner of the dev node.
+    miner: LocalMinerHandle<EthPrimitives>,
+    /// The accounts that are impersonated.
+    impersonated: ImpersonatedSigner,
+    /// Hashes of the head blocks at the time of the snapshots, indexed by snapshot id.
+    snapshots: Mutex<Vec<B256>>,
+}
+
+/// A modification of an account.
+#[derive(Debug, Clone)]
+enum AccountChange {
+    Balance(U256),
+    Nonce(u64),
+    Code(Bytes),
+    Storage(U256, U256),
+}
+
+/// Builds an empty block on top of the given parent that applies the given account changes.
+fn build_dev_block<Provider>(
+    provider: &Provider,
+    parent_hash: B256,
+    timestamp: u64,
+    changes: Vec<(Address, AccountChange)>,
+) -> Result<ExecutedBlockWithTrieUpdates<EthPrimitives>, LocalMinerError>
+where
+    Provider: BlockReaderIdExt<Header = Header>
+        + StateProviderFactory
+        + ChainSpecProvider<ChainSpec: EthChainSpec + EthereumHardforks>,
+{
+    let chain_spec = provider.chain_spec();
+    let parent = provider
+        .sealed_header_by_hash(parent_hash)
+        .map_err(LocalMinerError::internal)?
+        .ok_or_else(|| LocalMinerError::internal(ProviderError::BlockHashNotFound(parent_hash)))?;
+    let state_provider =
+        provider.state_by_block_hash(parent_hash).map_err(LocalMinerError::internal)?;
+
+    let mut db = State::builder()
+        .with_database_ref(StateProviderDatabase::new(&state_provider))
+        .with_bundle_update()
+        .build();
+    // accounts can be emptied by the changes, but must not be removed
+    db.set_state_clear_flag(false);
+
+    for (address, change) in changes {
+        let info = db
+            .load_cache_account(address)
+            .map_err(LocalMinerError::internal)?
+            .account_info()
+            .unwrap_or_default();
+        let mut account = Account::from(info);
+        match change {
+            AccountChange::Balance(balance) => account.info.balance = balance,
+            AccountChange::Nonce(nonce) => account.info.nonce = nonce,
+            AccountChange::Code(code) => {
+                let bytecode = Bytecode::new_raw(code);
+                account.info.code_hash = bytecode.hash_slow();
+                account.info.code = Some(bytecode);
+            }
+            AccountChange::Storage(slot, value) => {
+                let original = db.storage(address, slot).map_err(LocalMinerError::internal)?;
+                account.storage.insert(slot, EvmStorageSlot::new_changed(original, value));
+            }
+        }
+        account.mark_touch();
+        db.commit(EvmState::from_iter([(address, account)]));
+    }
+
+    db.merge_transitions(BundleRetention::Reverts);
+
+    let number = parent.number() + 1;
+    let execution_outcome = ExecutionOutcome::new(
+        db.take_bundle(),
+        vec![Vec::new()],
+        number,
+        vec![Requests::default()],
+    );
+    let hashed_state = state_provider.hashed_post_state(execution_outcome.state());
+    let (state_root, trie_updates) = state_provider
+        .state_root_with_updates(hashed_state.clone())
+        .map_err(LocalMinerError::internal)?;
+
+    let is_shanghai = chain_spec.is_shanghai_active_at_timestamp(timestamp);
+    let is_cancun = chain_spec.is_cancun_active_at_timestamp(timestamp);
+    let is_prague = chain_spec.is_prague_active_at_timestamp(timestamp);
+
+    let excess_blob_gas = is_cancun
+        .then(|| {
+            if chain_spec.is_cancun_active_at_timestamp(parent.timestamp()) {
+                parent.maybe_next_block_excess_blob_gas(
+                    chain_spec.blob_params_at_timestamp(timestamp),
+                )
+            } else {
+                // for the first post-fork block, both parent.blob_gas_used and
+                // parent.excess_blob_gas are evaluated as 0
+                Some(BlobParams::cancun().next_block_excess_blob_gas(0, 0))
+            }
+        })
+        .flatten();
+
+    let header = Header {
+        parent_hash,
+        ommers_hash: EMPTY_OMMER_ROOT_HASH,
+        beneficiary: Address::ZERO,
+        state_root,
+        transactions_root: EMPTY_ROOT_HASH,
+        receipts_root: EMPTY_ROOT_HASH,
+        withdrawals_root: is_shanghai.then_some(EMPTY_WITHDRAWALS),
+        logs_bloom: Default::default(),
+        timestamp,
+        mix_hash: B256::ZERO,
+        nonce: BEACON_NONCE.into(),
+        base_fee_per_gas: parent
+            .next_block_base_fee(chain_spec.base_fee_params_at_timestamp(timestamp)),
+        number,
+        gas_limit: parent.gas_limit(),
+        difficulty: U256::ZERO,
+        gas_used: 0,
+        blob_gas_used: is_cancun.then_some(0),
+        excess_blob_gas,
+        extra_data: Default::default(),
+        parent_beacon_block_root: is_cancun.then_some(B256::ZERO),
+        requests_hash: is_prague.then_some(EMPTY_REQUESTS_HASH),
+    };
+    let block = reth_primitives::Block {
+        header,
+        body: BlockBody {
+            transactions: Vec::new(),
+            ommers: Vec::new(),
+            withdrawals: is_shanghai.then(Withdrawals::default),
+        },
+    };
+
+    Ok(ExecutedBlockWithTrieUpdates {
+        block: ExecutedBlock {
+            recovered_block: Arc::new(RecoveredBlock::new_sealed(block.seal_slow(), Vec::new())),
+            execution_output: Arc::new(execution_outcome),
+            hashed_state: Arc::new(hashed_state),
+        },
+        trie: Arc::new(trie_updates),
+    })
+}
+
+/// Converts an error of the local miner into an RPC error.
+fn miner_error(err: LocalMinerError) -> ErrorObjectOwned {
+    internal_rpc_err(err.to_string())
+}
+
+/// An [`EthSigner`] for the accounts impersonated through the `anvil` and `hardhat` APIs.
+///
+/// Transactions of impersonated accounts carry a placeholder signature that does not recover to
+/// the sender. They are only valid on dev nodes, whose miner inserts the blocks it builds with the
+/// senders known from the pool once an account is impersonated.
+#[derive(Debug, Clone, Default)]
+pub struct ImpersonatedSigner {
+    accounts: Arc<RwLock<ImpersonatedAccounts>>,
+}
+
+impl ImpersonatedSigner {
+    /// Starts impersonating the given account.
+    pub fn impersonate(&self, address: Address) {
+        self.accounts.write().accounts.insert(address);
+    }
+
+    /// Stops impersonating the given account.
+    pub fn stop_impersonating(&self, address: Address) {
+        self.accounts.write().accounts.remove(&address);
+    }
+
+    /// Enables or disables impersonating all accounts.
+    pub fn set_auto_impersonate(&self, enabled: bool) {
+        self.accounts.write().all = enabled;
+    }
+}
+
+#[async_trait]
+impl<T: Decodable2718> EthSigner<T> for ImpersonatedSigner {
+    fn accounts(&self) -> Vec<Address> {
+        self.accounts.read().accounts.iter().copied().collect()
+    }
+
+    fn is_signer_for(&self, addr: &Address) -> bool {
+        let accounts = self.accounts.read();
+        accounts.all || accounts.accounts.contains(addr)
+    }
+
+    async fn sign(&self, _address: Address, _message: &[u8]) -> SignResult<Signature> {
+        Err(SignError::CouldNotSign)
+    }
+
+    async fn sign_transaction(
+        &self,
+        request: TransactionRequest,
+        address: &Address,
+    ) -> SignResult<T> {
+        let tx = request.build_typed_tx().map_err(|_| SignError::InvalidTransactionRequest)?;
+
+        // encode the sender into the signature, so that the hashes of transactions from different
+        // impersonated accounts never collide
+        let signature =
+            Signature::new(U256::from_be_slice(address.as_slice()), U256::from(1), false);
+        let tx = TransactionSigned::new_unhashed(tx.into(), signature);
+
+        // decode transaction into signed transaction type
+        let encoded = tx.encoded_2718();
+        T::decode_2718(&mut encoded.as_ref()).map_err(|_| SignError::InvalidTransactionRequest)
+    }
+
+    fn sign_typed_data(&self, _address: Address, _payload: &TypedData) -> SignResult<Signature> {
+        Err(SignError::CouldNotSign)
+    }
+}
+
+#[derive(Debug, Default)]
+struct ImpersonatedAccounts {
+    /// The impersonated accounts.
+    accounts: HashSet<Address>,
+    /// Whether all accounts are impersonated.
+    all: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{keccak256, TxKind};
+    use reth_engine_primitives::LocalMinerMessage;
+    use reth_primitives_traits::SignedTransaction;
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+    use tokio::sync::mpsc;
+
+    /// Adds a block with the given number as the new head of the provider and returns its hash.
+    fn add_head(provider: &MockEthProvider, number: u64) -> B256 {
+        let header = Header {
+            number,
+            timestamp: number,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        };
+        let hash = header.hash_slow();
+        provider.add_block(hash, reth_primitives::Block { header, body: Default::default() });
+        hash
+    }
+
+    /// Returns an [`AnvilApi`] and the receiver of the messages it sends to the miner.
+    fn anvil_api(
+        provider: MockEthProvider,
+    ) -> (
+        AnvilApi<MockEthProvider, TestPool>,
+        mpsc::UnboundedReceiver<LocalMinerMessage<EthPrimitives>>,
+    ) {
+        let (to_miner, from_api) = mpsc::unbounded_channel();
+        (AnvilApi::new(provider, testing_pool(), LocalMinerHandle::new(to_miner)), from_api)
+    }
+
+    #[test]
+    fn build_dev_block_applies_changes() {
+        let provider = MockEthProvider::default();
+        let parent_hash = add_head(&provider, 1);
+        let address = Address::with_last_byte(1);
+        provider.add_account(address, ExtendedAccount::new(1, U256::from(10)));
+        let state_root = B256::with_last_byte(2);
+        provider.add_state_root(state_root);
+
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        let block = build_dev_block(
+            &provider,
+            parent_hash,
+            12,
+            vec![
+                (address, AccountChange::Balance(U256::from(100))),
+                (address, AccountChange::Nonce(5)),
+                (address, AccountChange::Code(code.clone())),
+                (address, AccountChange::Storage(U256::from(1), U256::from(2))),
+            ],
+        )
+        .unwrap();
+
+        let header = block.recovered_block().header();
+        assert_eq!(header.number, 2);
+        assert_eq!(header.parent_hash, parent_hash);
+        assert_eq!(header.timestamp, 12);
+        assert_eq!(header.state_root, state_root);
+        assert!(block.recovered_block().body().transactions.is_empty());
+
+        let outcome = block.execution_outcome();
+        let account = outcome.account(&address).unwrap().unwrap();
+        assert_eq!(account.balance, U256::from(100));
+        assert_eq!(account.nonce, 5);
+        assert_eq!(account.bytecode_hash, Some(keccak256(&code)));
+        assert_eq!(outcome.storage(&address, U256::from(1)), Some(U256::from(2)));
+    }
+
+    #[test]
+    fn build_dev_block_keeps_emptied_accounts() {
+        let provider = MockEthProvider::default();
+        let parent_hash = add_head(&provider, 1);
+        let address = Address::with_last_byte(1);
+        provider.add_account(address, ExtendedAccount::new(0, U256::from(10)));
+
+        let block = build_dev_block(
+            &provider,
+            parent_hash,
+            12,
+            vec![(address, AccountChange::Balance(U256::ZERO))],
+        )
+        .unwrap();
+
+        let account = block.execution_outcome().account(&address).unwrap();
+        assert_eq!(account.map(|account| account.balance), Some(U256::ZERO));
+    }
+
+    #[tokio::test]
+    async fn revert_to_snapshot() {
+        let provider = MockEthProvider::default();
+        let first = add_head(&provider, 1);
+        let (api, mut from_api) = anvil_api(provider.clone());
+
+        assert_eq!(api.snapshot().unwrap(), U256::ZERO);
+        add_head(&provider, 2);
+        assert_eq!(api.snapshot().unwrap(), U256::from(1));
+
+        // answer the request of the revert like the miner
+        let miner = tokio::spawn(async move {
+            let Some(LocalMinerMessage::Revert { block_hash, timestamp, tx }) =
+                from_api.recv().await
+            else {
+                panic!("expected the chain to be reverted")
+            };
+            let _ = tx.send(Ok(()));
+            (block_hash, timestamp)
+        });
+
+        assert!(api.revert(U256::ZERO).await.unwrap());
+        // the snapshotted block itself becomes the head again
+        assert_eq!(miner.await.unwrap(), (first, 1));
+
+        // the snapshot and all later snapshots are removed
+        assert!(!api.revert(U256::from(1)).await.unwrap());
+        assert!(!api.revert(U256::ZERO).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn impersonate_accounts() {
+        let (api, mut from_api) = anvil_api(MockEthProvider::default());
+        let signer = api.impersonated_signer();
+        let address = Address::with_last_byte(1);
+        let other = Address::with_last_byte(2);
+
+        assert!(!EthSigner::<TransactionSigned>::is_signer_for(&signer, &address));
+        api.impersonate(address).unwrap();
+        assert!(EthSigner::<TransactionSigned>::is_signer_for(&signer, &address));
+        assert!(!EthSigner::<TransactionSigned>::is_signer_for(&signer, &other));
+        assert_eq!(EthSigner::<TransactionSigned>::accounts(&signer), vec![address]);
+
+        // the miner has to insert the blocks it builds directly from now on
+        assert!(matches!(
+            from_api.try_recv(),
+            Ok(LocalMinerMessage::SetInsertExecutedBlocks { enabled: true })
+        ));
+
+        api.set_auto_impersonate(true).unwrap();
+        assert!(EthSigner::<TransactionSigned>::is_signer_for(&signer, &other));
+        api.set_auto_impersonate(false).unwrap();
+        signer.stop_impersonating(address);
+        assert!(!EthSigner::<TransactionSigned>::is_signer_for(&signer, &address));
+        assert!(!EthSigner::<TransactionSigned>::is_signer_for(&signer, &other));
+    }
+
+    #[tokio::test]
+    async fn sign_impersonated_transaction() {
+        let signer = ImpersonatedSigner::default();
+        let address = Address::with_last_byte(1);
+        signer.impersonate(address);
+
+        let request = TransactionRequest {
+            chain_id: Some(1),
+            from: Some(address),
+            to: Some(TxKind::Call(Address::with_last_byte(2))),
+            gas: Some(21_000),
+            gas_price: Some(1_000_000_000),
+            value: Some(U256::from(1)),
+            nonce: Some(0),
+            ..Default::default()
+        };
+        let tx: TransactionSigned = signer.sign_transaction(request, &address).await.unwrap();
+
+        // the sender is encoded in the signature
+        assert_eq!(tx.signature().r(), U256::from_be_slice(address.as_slice()));
+        assert_ne!(tx.recover_signer().ok(), Some(address));
+    }
+}
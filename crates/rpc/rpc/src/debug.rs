@@ -1,12 +1,12 @@
 use alloy_consensus::BlockHeader;
 use alloy_eips::{eip2718::Encodable2718, BlockId, BlockNumberOrTag};
 use alloy_genesis::ChainConfig;
-use alloy_primitives::{Address, Bytes, B256};
+use alloy_primitives::{hex, keccak256, Address, Bytes, B256};
 use alloy_rlp::{Decodable, Encodable};
 use alloy_rpc_types_debug::ExecutionWitness;
 use alloy_rpc_types_eth::{
-    state::EvmOverrides, transaction::TransactionRequest, Block as RpcBlock, BlockError, Bundle,
-    StateContext, TransactionInfo,
+    state::EvmOverrides, transaction::TransactionRequest, BlockError, BlockTransactionsKind,
+    Bundle, StateContext, TransactionInfo,
 };
 use alloy_rpc_types_trace::geth::{
     call::FlatCallFrame, BlockTraceResult, FourByteFrame, GethDebugBuiltInTracerType,
@@ -15,38 +15,75 @@ use alloy_rpc_types_trace::geth::{
 };
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
+use parking_lot::RwLock;
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_errors::RethError;
 use reth_evm::{
     execute::{BlockExecutorProvider, Executor},
-    ConfigureEvmEnv, EvmEnv,
+    ConfigureEvm, ConfigureEvmEnv, Evm, EvmEnv,
 };
-use reth_primitives::{NodePrimitives, ReceiptWithBloom, RecoveredBlock};
-use reth_primitives_traits::{Block as _, BlockBody, SignedTransaction};
+use reth_primitives::{NodePrimitives, ReceiptWithBloom, RecoveredBlock, SealedBlock};
+use reth_primitives_traits::{Block as BlockTrait, BlockBody, SignedTransaction};
 use reth_provider::{
-    BlockIdReader, BlockReaderIdExt, ChainSpecProvider, HeaderProvider, ProviderBlock,
-    ReceiptProviderIdExt, StateProofProvider, StateProvider, StateProviderFactory,
+    BlockIdReader, BlockNumReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader,
+    HashedPostStateProvider, HashedStateRangeReader, HashedStateRanges, HeaderProvider,
+    ProviderBlock, ProviderError, PruneCheckpointReader, ReceiptProviderIdExt, StateProofProvider,
+    StateProvider, StateProviderFactory, StateRootProvider, StorageChangeSetReader,
     TransactionVariant,
 };
+use reth_prune_types::PruneSegment;
 use reth_revm::{
     database::StateProviderDatabase,
-    db::{CacheDB, State},
+    db::{states::bundle_state::BundleRetention, CacheDB, State},
     witness::ExecutionWitnessRecord,
 };
-use reth_rpc_api::DebugApiServer;
+use reth_rpc_api::{
+    BadBlock, DebugApiServer, DumpAccount, StateDump, StdTraceConfig, StorageRangeResult,
+    StorageResult,
+};
 use reth_rpc_eth_api::{
     helpers::{EthTransactions, TraceExt},
-    EthApiTypes, FromEthApiError, RpcNodeCore,
+    EthApiTypes, FromEthApiError, FromEvmError, RpcBlock, RpcNodeCore,
 };
 use reth_rpc_eth_types::{EthApiError, StateCacheDb};
 use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
+use reth_rpc_types_compat::block::from_block;
 use reth_tasks::pool::BlockingTaskGuard;
-use revm::{context_interface::Transaction, state::EvmState, DatabaseCommit};
+use revm::{
+    context_interface::Transaction, inspector::inspectors::TracerEip3155, state::EvmState,
+    DatabaseCommit,
+};
 use revm_inspectors::tracing::{
     FourByteInspector, MuxInspector, TracingInspector, TracingInspectorConfig, TransactionContext,
 };
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    fs::File,
+    io::BufWriter,
+    path::PathBuf,
+    sync::Arc,
+};
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
 
+/// The maximum number of accounts returned by `debug_accountRange`.
+const ACCOUNT_RANGE_MAX_RESULTS: usize = 256;
+
+/// The maximum number of storage slots returned by `debug_accountRange`, over all accounts.
+const ACCOUNT_RANGE_MAX_STORAGE_SLOTS: usize = 10_000;
+
+/// The maximum number of blocks `debug_getModifiedAccountsBy*` reads the changesets of.
+const MODIFIED_ACCOUNTS_MAX_BLOCKS: u64 = 10_000;
+
+/// The maximum number of changeset entries `debug_getModifiedAccountsBy*` reads, over all blocks.
+const MODIFIED_ACCOUNTS_MAX_CHANGES: usize = 1_000_000;
+
+/// The maximum number of changed accounts and storage slots `debug_intermediateRoots` hashes
+/// into the state roots, over all transactions.
+///
+/// The root after each transaction is computed from all changes of the block so far.
+const INTERMEDIATE_ROOTS_MAX_STATE_CHANGES: usize = 10_000_000;
+
 /// `debug` API implementation.
 ///
 /// This type provides the functionality for handling `debug` related requests.
@@ -62,8 +99,16 @@ impl<Eth, BlockExecutor> DebugApi<Eth, BlockExecutor> {
         eth: Eth,
         blocking_task_guard: BlockingTaskGuard,
         block_executor: BlockExecutor,
+        bad_block_store: BadBlockStore,
+        config: DebugApiConfig,
     ) -> Self {
-        let inner = Arc::new(DebugApiInner { eth_api: eth, blocking_task_guard, block_executor });
+        let inner = Arc::new(DebugApiInner {
+            eth_api: eth,
+            blocking_task_guard,
+            block_executor,
+            bad_block_store,
+            config,
+        });
         Self { inner }
     }
 
@@ -159,7 +204,16 @@ where
             .map_err(Eth::Error::from_eth_err)?;
 
         let evm_env = self.eth_api().evm_config().evm_env(block.header());
+        let block = self.recover_block(block)?;
+
+        self.trace_block(Arc::new(block), evm_env, opts).await
+    }
 
+    /// Recovers the senders of the transactions of a block that is not necessarily stored.
+    fn recover_block(
+        &self,
+        block: ProviderBlock<Eth::Provider>,
+    ) -> Result<RecoveredBlock<ProviderBlock<Eth::Provider>>, Eth::Error> {
         // Depending on EIP-2 we need to recover the transactions differently
         let senders =
             if self.provider().chain_spec().is_homestead_active_at_block(block.header().number()) {
@@ -182,7 +236,166 @@ where
                     .collect()
             };
 
-        self.trace_block(Arc::new(block.into_recovered_with_signers(senders)), evm_env, opts).await
+        Ok(block.into_recovered_with_signers(senders))
+    }
+
+    /// Returns the block with the given hash from the [`BadBlockStore`].
+    fn bad_block(&self, hash: B256) -> Result<Option<ProviderBlock<Eth::Provider>>, Eth::Error> {
+        self.inner
+            .bad_block_store
+            .get(&hash)
+            .map(|rlp| {
+                Decodable::decode(&mut rlp.as_ref())
+                    .map_err(BlockError::RlpDecodeRawBlock)
+                    .map_err(Eth::Error::from_eth_err)
+            })
+            .transpose()
+    }
+
+    /// Returns the blocks of the [`BadBlockStore`], most recent first.
+    ///
+    /// Like geth, every bad block is returned with its hash and its RLP encoding. The block is
+    /// `None` if the senders of its transactions can't be recovered.
+    pub fn debug_bad_blocks(&self) -> Result<Vec<BadBlock<RpcBlock<Eth::NetworkTypes>>>, Eth::Error>
+    where
+        Eth: EthTransactions,
+    {
+        let mut bad_blocks = Vec::new();
+        for (hash, rlp) in self.inner.bad_block_store.blocks() {
+            let block: ProviderBlock<Eth::Provider> = Decodable::decode(&mut rlp.as_ref())
+                .map_err(BlockError::RlpDecodeRawBlock)
+                .map_err(Eth::Error::from_eth_err)?;
+            let block = self
+                .recover_block(block)
+                .ok()
+                .map(|block| {
+                    from_block(block, BlockTransactionsKind::Full, self.eth_api().tx_resp_builder())
+                })
+                .transpose()?;
+            bad_blocks.push(BadBlock { hash, block, rlp });
+        }
+        Ok(bad_blocks)
+    }
+
+    /// Replays a block from the [`BadBlockStore`] on top of the state of its parent and returns
+    /// the trace of each transaction.
+    ///
+    /// Note, the parent of this block must be present, or it will fail.
+    pub async fn debug_trace_bad_block(
+        &self,
+        block_hash: B256,
+        opts: GethDebugTracingOptions,
+    ) -> Result<Vec<TraceResult>, Eth::Error> {
+        let block =
+            self.bad_block(block_hash)?.ok_or(EthApiError::HeaderNotFound(block_hash.into()))?;
+
+        let evm_env = self.eth_api().evm_config().evm_env(block.header());
+        let block = self.recover_block(block)?;
+
+        self.trace_block(Arc::new(block), evm_env, opts).await
+    }
+
+    /// Replays a block and writes the EIP-3155 trace of each transaction, or only of the
+    /// transaction selected in the options, to a separate file in the configured trace directory.
+    ///
+    /// Returns the paths of the created files.
+    async fn standard_trace_block_to_file(
+        &self,
+        block: Arc<RecoveredBlock<ProviderBlock<Eth::Provider>>>,
+        evm_env: EvmEnv<<Eth::Evm as ConfigureEvmEnv>::Spec>,
+        opts: StdTraceConfig,
+    ) -> Result<Vec<String>, Eth::Error> {
+        if let Some(tx_hash) = opts.tx_hash {
+            if !block.body().transactions().iter().any(|tx| *tx.tx_hash() == tx_hash) {
+                return Err(EthApiError::InvalidParams(format!(
+                    "transaction {tx_hash} not found in block {}",
+                    block.hash()
+                ))
+                .into())
+            }
+        }
+
+        let trace_dir = self.inner.config.trace_dir.clone();
+
+        let this = self.clone();
+        self.eth_api()
+            .spawn_with_state_at_block(block.parent_hash().into(), move |state| {
+                std::fs::create_dir_all(&trace_dir)
+                    .map_err(|err| EthApiError::Internal(RethError::other(err)))?;
+
+                let mut files = Vec::new();
+                let mut db = CacheDB::new(StateProviderDatabase::new(state));
+
+                this.eth_api().apply_pre_execution_changes(&block, &mut db, &evm_env)?;
+
+                for (index, (signer, tx)) in block.transactions_with_sender().enumerate() {
+                    let tx_hash = *tx.tx_hash();
+                    let tx_env = this.eth_api().evm_config().tx_env(tx, *signer);
+
+                    if opts.tx_hash.is_some_and(|hash| hash != tx_hash) {
+                        let (res, _) = this.eth_api().transact(&mut db, evm_env.clone(), tx_env)?;
+                        db.commit(res.state);
+                        continue
+                    }
+
+                    // the steps are written to the file as they are executed
+                    let path = trace_dir.join(format!(
+                        "block_0x{}-{index}-0x{}.jsonl",
+                        hex::encode(&block.hash()[..4]),
+                        hex::encode(&tx_hash[..4])
+                    ));
+                    let file = File::create(&path)
+                        .map_err(|err| EthApiError::Internal(RethError::other(err)))?;
+                    let mut inspector = TracerEip3155::new(Box::new(BufWriter::new(file)));
+                    if opts.enable_memory {
+                        inspector = inspector.with_memory();
+                    }
+
+                    let (res, _) =
+                        this.eth_api().inspect(&mut db, evm_env.clone(), tx_env, &mut inspector)?;
+                    db.commit(res.state);
+                    files.push(path.display().to_string());
+                }
+
+                Ok(files)
+            })
+            .await
+    }
+
+    /// Replays a block and writes the EIP-3155 trace of its transactions to files.
+    ///
+    /// See also [`StdTraceConfig`].
+    pub async fn debug_standard_trace_block_to_file(
+        &self,
+        block_hash: B256,
+        opts: StdTraceConfig,
+    ) -> Result<Vec<String>, Eth::Error> {
+        let ((evm_env, _), block) = futures::try_join!(
+            self.eth_api().evm_env_at(block_hash.into()),
+            self.eth_api().block_with_senders(block_hash.into()),
+        )?;
+
+        let block = block.ok_or(EthApiError::HeaderNotFound(block_hash.into()))?;
+
+        self.standard_trace_block_to_file(block, evm_env, opts).await
+    }
+
+    /// Replays a block from the [`BadBlockStore`] on top of the state of its parent and writes the
+    /// EIP-3155 trace of its transactions to files.
+    ///
+    /// Note, the parent of this block must be present, or it will fail.
+    pub async fn debug_standard_trace_bad_block_to_file(
+        &self,
+        block_hash: B256,
+        opts: StdTraceConfig,
+    ) -> Result<Vec<String>, Eth::Error> {
+        let block =
+            self.bad_block(block_hash)?.ok_or(EthApiError::HeaderNotFound(block_hash.into()))?;
+
+        let evm_env = self.eth_api().evm_config().evm_env(block.header());
+        let block = self.recover_block(block)?;
+
+        self.standard_trace_block_to_file(Arc::new(block), evm_env, opts).await
     }
 
     /// Replays a block and returns the trace of each transaction.
@@ -671,6 +884,403 @@ where
             .original_bytes())
     }
 
+    /// Returns a page of the accounts of the state after the given block, ordered by their hashed
+    /// addresses and starting at the hashed address `start`.
+    ///
+    /// The database does not store the preimages of hashed addresses, so like geth without
+    /// preimages, accounts can only be dumped if `incompletes` is set and are keyed by
+    /// `pre(<hashed address>)`.
+    ///
+    /// The page ends before the first account whose storage would exceed
+    /// [`ACCOUNT_RANGE_MAX_STORAGE_SLOTS`] slots in total, unless `nostorage` is set.
+    pub async fn debug_account_range(
+        &self,
+        block_number: BlockNumberOrTag,
+        start: Bytes,
+        max_results: u64,
+        nocode: bool,
+        nostorage: bool,
+        incompletes: bool,
+    ) -> Result<StateDump, Eth::Error>
+    where
+        Eth::Provider: HashedStateRangeReader,
+    {
+        if start.len() > 32 {
+            return Err(EthApiError::InvalidParams(format!(
+                "start key is longer than 32 bytes: {start}"
+            ))
+            .into())
+        }
+        if !incompletes {
+            return Err(EthApiError::InvalidParams(
+                "accounts without address preimages can only be dumped with incompletes set"
+                    .to_string(),
+            )
+            .into())
+        }
+        let start = B256::right_padding_from(&start);
+        let limit = match usize::try_from(max_results) {
+            Ok(0) | Err(_) => ACCOUNT_RANGE_MAX_RESULTS,
+            Ok(max_results) => max_results.min(ACCOUNT_RANGE_MAX_RESULTS),
+        };
+
+        let header = self
+            .provider()
+            .header_by_number_or_tag(block_number)
+            .map_err(Eth::Error::from_eth_err)?
+            .ok_or(EthApiError::HeaderNotFound(block_number.into()))?;
+        let mut dump = StateDump { root: header.state_root(), ..Default::default() };
+
+        let number = header.number();
+        self.eth_api()
+            .spawn_blocking_io(move |eth_api| {
+                let provider = eth_api.provider();
+                let code_state = provider.latest().map_err(Eth::Error::from_eth_err)?;
+                provider
+                    .with_hashed_state_at(number, |state: &dyn HashedStateRanges| {
+                        let mut accounts = state.accounts_range(start, limit + 1)?;
+                        if accounts.len() > limit {
+                            dump.next =
+                                accounts.pop().map(|(hashed_address, _)| hashed_address.into());
+                        }
+
+                        let mut remaining_slots = ACCOUNT_RANGE_MAX_STORAGE_SLOTS;
+                        for (hashed_address, account) in accounts {
+                            let storage = if nostorage {
+                                None
+                            } else {
+                                let storage = state.storage_range(
+                                    hashed_address,
+                                    B256::ZERO,
+                                    remaining_slots + 1,
+                                )?;
+                                if storage.len() > remaining_slots {
+                                    if dump.accounts.is_empty() {
+                                        return Ok(Err(hashed_address))
+                                    }
+                                    // continue the next page with this account
+                                    dump.next = Some(hashed_address.into());
+                                    break
+                                }
+                                remaining_slots -= storage.len();
+                                (!storage.is_empty()).then(|| storage.into_iter().collect())
+                            };
+                            let code = if nocode {
+                                None
+                            } else {
+                                code_state
+                                    .bytecode_by_hash(&account.code_hash)?
+                                    .map(|code| code.original_bytes())
+                            };
+
+                            dump.accounts.insert(
+                                format!("pre({hashed_address})"),
+                                DumpAccount {
+                                    balance: account.balance,
+                                    nonce: account.nonce,
+                                    root: account.storage_root,
+                                    code_hash: account.code_hash,
+                                    code,
+                                    storage,
+                                    address: None,
+                                    address_hash: Some(hashed_address),
+                                },
+                            );
+                        }
+
+                        Ok(Ok(dump))
+                    })
+                    .map_err(Eth::Error::from_eth_err)?
+                    .map_err(|hashed_address| {
+                        EthApiError::InvalidParams(format!(
+                            "storage of account {hashed_address} exceeds \
+                             {ACCOUNT_RANGE_MAX_STORAGE_SLOTS} slots, dump it with nostorage set \
+                             and debug_storageRangeAt"
+                        ))
+                        .into()
+                    })
+            })
+            .await
+    }
+
+    /// Returns a page of the storage of the given account in the state before the transaction at
+    /// the given index of the block, ordered by hashed slot and starting at the hashed slot
+    /// `key_start`.
+    ///
+    /// The database does not store the preimages of hashed slots, so the keys of the returned
+    /// slots are unknown.
+    pub async fn debug_storage_range_at(
+        &self,
+        block_hash: B256,
+        tx_idx: usize,
+        contract_address: Address,
+        key_start: B256,
+        max_result: u64,
+    ) -> Result<StorageRangeResult, Eth::Error>
+    where
+        Eth::Provider: HashedStateRangeReader,
+    {
+        let limit = usize::try_from(max_result).unwrap_or(usize::MAX);
+        let block = self
+            .eth_api()
+            .block_with_senders(block_hash.into())
+            .await?
+            .ok_or(EthApiError::HeaderNotFound(block_hash.into()))?;
+        if block.number() == 0 {
+            return Err(EthApiError::InvalidParams("no transaction in genesis".to_string()).into())
+        }
+        let tx_count = block.body().transactions().len();
+        if tx_idx > 0 && tx_idx >= tx_count {
+            return Err(EthApiError::InvalidParams(format!(
+                "transaction index {tx_idx} out of range for block {block_hash}"
+            ))
+            .into())
+        }
+        let (evm_env, _) = self.eth_api().evm_env_at(block_hash.into()).await?;
+        let parent_hash = block.parent_hash();
+        let hashed_address = keccak256(contract_address);
+
+        let this = self.clone();
+        self.eth_api()
+            .spawn_with_state_at_block(parent_hash.into(), move |state| {
+                // replay the transactions before the target transaction on top of the parent state
+                let mut db = State::builder()
+                    .with_database(StateProviderDatabase::new(&state))
+                    .with_bundle_update()
+                    .build();
+                this.eth_api().apply_pre_execution_changes(&block, &mut db, &evm_env)?;
+                let target_tx_hash = block
+                    .body()
+                    .transactions()
+                    .get(tx_idx)
+                    .map(|tx| *tx.tx_hash())
+                    .unwrap_or_default();
+                this.eth_api().replay_transactions_until(
+                    &mut db,
+                    evm_env,
+                    block.transactions_with_sender(),
+                    target_tx_hash,
+                )?;
+                db.merge_transitions(BundleRetention::PlainState);
+                let changes =
+                    state.hashed_post_state(&db.bundle_state).storages.remove(&hashed_address);
+
+                let mut storage = BTreeMap::new();
+                if !changes.as_ref().is_some_and(|changes| changes.wiped) {
+                    // Every changed slot removes at most one slot from the page, so fetching one
+                    // more slot than changed slots past the page is enough to fill the page and
+                    // find the next key.
+                    let changed = changes.as_ref().map_or(0, |changes| changes.storage.len());
+                    let slots = this
+                        .provider()
+                        .with_hashed_state_at_hash(parent_hash, |ranges: &dyn HashedStateRanges| {
+                            ranges.storage_range(
+                                hashed_address,
+                                key_start,
+                                limit.saturating_add(changed).saturating_add(1),
+                            )
+                        })
+                        .map_err(Eth::Error::from_eth_err)?;
+                    storage.extend(slots);
+                }
+                for (hashed_slot, value) in changes.into_iter().flat_map(|changes| changes.storage)
+                {
+                    if hashed_slot < key_start {
+                        continue
+                    }
+                    if value.is_zero() {
+                        storage.remove(&hashed_slot);
+                    } else {
+                        storage.insert(hashed_slot, value);
+                    }
+                }
+
+                let mut slots = storage.into_iter();
+                Ok(StorageRangeResult {
+                    storage: slots
+                        .by_ref()
+                        .take(limit)
+                        .map(|(hashed_slot, value)| {
+                            (hashed_slot, StorageResult { key: None, value: value.into() })
+                        })
+                        .collect(),
+                    next_key: slots.next().map(|(hashed_slot, _)| hashed_slot),
+                })
+            })
+            .await
+    }
+
+    /// Re-executes a block on top of the state of its parent and returns the state root after
+    /// each transaction.
+    ///
+    /// Like in geth, the block can also be a block from the [`BadBlockStore`], which makes it
+    /// possible to bisect state root mismatches.
+    pub async fn debug_intermediate_roots(
+        &self,
+        block_hash: B256,
+    ) -> Result<Vec<B256>, Eth::Error> {
+        let block = match self.eth_api().block_with_senders(block_hash.into()).await? {
+            Some(block) => block,
+            None => {
+                let block = self
+                    .bad_block(block_hash)?
+                    .ok_or(EthApiError::HeaderNotFound(block_hash.into()))?;
+                Arc::new(self.recover_block(block)?)
+            }
+        };
+        let evm_env = self.eth_api().evm_config().evm_env(block.header());
+
+        let this = self.clone();
+        self.eth_api()
+            .spawn_with_state_at_block(block.parent_hash().into(), move |state| {
+                let mut db = State::builder()
+                    .with_database(StateProviderDatabase::new(&state))
+                    .with_bundle_update()
+                    .build();
+                this.eth_api().apply_pre_execution_changes(&block, &mut db, &evm_env)?;
+
+                let mut roots = Vec::with_capacity(block.body().transaction_count());
+                let mut remaining_changes = INTERMEDIATE_ROOTS_MAX_STATE_CHANGES;
+                let mut evm = this.eth_api().evm_config().evm_with_env(&mut db, evm_env);
+                for (sender, tx) in block.transactions_with_sender() {
+                    let tx_env = this.eth_api().evm_config().tx_env(tx, *sender);
+                    evm.transact_commit(tx_env).map_err(Eth::Error::from_evm_err)?;
+
+                    // the changes of all transactions so far on top of the state of the parent
+                    let db = evm.db_mut();
+                    db.merge_transitions(BundleRetention::PlainState);
+                    let hashed_state = state.hashed_post_state(&db.bundle_state);
+
+                    let changes = hashed_state.accounts.len() +
+                        hashed_state.storages.values().map(|s| s.storage.len()).sum::<usize>();
+                    remaining_changes =
+                        remaining_changes.checked_sub(changes).ok_or_else(|| {
+                            EthApiError::InvalidParams(format!(
+                                "state changes exceed {INTERMEDIATE_ROOTS_MAX_STATE_CHANGES} \
+                                 over all intermediate roots"
+                            ))
+                        })?;
+
+                    roots.push(state.state_root(hashed_state).map_err(Eth::Error::from_eth_err)?);
+                }
+
+                Ok(roots)
+            })
+            .await
+    }
+
+    /// Returns the addresses of the accounts modified in the blocks after `start_number` up to and
+    /// including `end_number`, or only in block `start_number` if no end is given.
+    ///
+    /// An account counts as modified if its nonce, balance, code or storage changed, which is read
+    /// from the account and storage changesets of the blocks.
+    ///
+    /// At most [`MODIFIED_ACCOUNTS_MAX_BLOCKS`] blocks and [`MODIFIED_ACCOUNTS_MAX_CHANGES`]
+    /// changeset entries are read. Returns an error if the changesets of the blocks are pruned.
+    pub async fn debug_get_modified_accounts_by_number(
+        &self,
+        start_number: u64,
+        end_number: Option<u64>,
+    ) -> Result<Vec<Address>, Eth::Error>
+    where
+        Eth::Provider: ChangeSetReader + PruneCheckpointReader + StorageChangeSetReader,
+    {
+        let blocks = match end_number {
+            Some(end_number) if start_number >= end_number => {
+                return Err(EthApiError::InvalidParams(format!(
+                    "start block height ({start_number}) must be less than end block height \
+                     ({end_number})"
+                ))
+                .into())
+            }
+            Some(end_number) => start_number + 1..=end_number,
+            None => start_number..=start_number,
+        };
+
+        if blocks.end() - blocks.start() >= MODIFIED_ACCOUNTS_MAX_BLOCKS {
+            return Err(EthApiError::InvalidParams(format!(
+                "block range exceeds {MODIFIED_ACCOUNTS_MAX_BLOCKS} blocks"
+            ))
+            .into())
+        }
+
+        // the changesets of blocks that don't exist yet are empty
+        let best_number = self.provider().best_block_number().map_err(Eth::Error::from_eth_err)?;
+        if *blocks.end() > best_number {
+            return Err(EthApiError::HeaderNotFound((*blocks.end()).into()).into())
+        }
+
+        // the changesets of pruned blocks are empty as well
+        for segment in [PruneSegment::AccountHistory, PruneSegment::StorageHistory] {
+            let pruned = self
+                .provider()
+                .get_prune_checkpoint(segment)
+                .map_err(Eth::Error::from_eth_err)?
+                .and_then(|checkpoint| checkpoint.block_number);
+            if pruned.is_some_and(|pruned| *blocks.start() <= pruned) {
+                return Err(Eth::Error::from_eth_err(ProviderError::StateAtBlockPruned(
+                    *blocks.start(),
+                )))
+            }
+        }
+
+        self.eth_api()
+            .spawn_blocking_io(move |eth_api| {
+                let provider = eth_api.provider();
+                let mut accounts = BTreeSet::new();
+                let mut remaining_changes = MODIFIED_ACCOUNTS_MAX_CHANGES;
+                let mut consume = |changes: usize| {
+                    remaining_changes =
+                        remaining_changes.checked_sub(changes).ok_or_else(|| {
+                            EthApiError::InvalidParams(format!(
+                                "changesets exceed {MODIFIED_ACCOUNTS_MAX_CHANGES} entries"
+                            ))
+                        })?;
+                    Ok::<_, Eth::Error>(())
+                };
+                for block_number in blocks {
+                    let account_changes = provider
+                        .account_block_changeset(block_number)
+                        .map_err(Eth::Error::from_eth_err)?;
+                    consume(account_changes.len())?;
+                    accounts.extend(account_changes.into_iter().map(|change| change.address));
+
+                    // accounts whose storage changed don't necessarily have an account change
+                    let storage_changes = provider
+                        .storage_changeset(block_number)
+                        .map_err(Eth::Error::from_eth_err)?;
+                    consume(storage_changes.len())?;
+                    accounts.extend(storage_changes.into_iter().map(|(key, _)| key.address()));
+                }
+                Ok(accounts.into_iter().collect())
+            })
+            .await
+    }
+
+    /// Same as [`Self::debug_get_modified_accounts_by_number`], but with the blocks given by their
+    /// hashes.
+    ///
+    /// Only blocks of the canonical chain have changesets.
+    pub async fn debug_get_modified_accounts_by_hash(
+        &self,
+        start_hash: B256,
+        end_hash: Option<B256>,
+    ) -> Result<Vec<Address>, Eth::Error>
+    where
+        Eth::Provider: ChangeSetReader + PruneCheckpointReader + StorageChangeSetReader,
+    {
+        let block_number = |hash: B256| -> Result<u64, Eth::Error> {
+            self.provider()
+                .block_number(hash)
+                .map_err(Eth::Error::from_eth_err)?
+                .ok_or_else(|| EthApiError::HeaderNotFound(hash.into()).into())
+        };
+        let start_number = block_number(start_hash)?;
+        let end_number = end_hash.map(block_number).transpose()?;
+
+        self.debug_get_modified_accounts_by_number(start_number, end_number).await
+    }
+
     /// Executes the configured transaction with the environment on the given database.
     ///
     /// It optionally takes fused inspector ([`TracingInspector::fused`]) to avoid re-creating the
@@ -842,9 +1452,12 @@ where
 }
 
 #[async_trait]
-impl<Eth, BlockExecutor> DebugApiServer for DebugApi<Eth, BlockExecutor>
+impl<Eth, BlockExecutor> DebugApiServer<RpcBlock<Eth::NetworkTypes>>
+    for DebugApi<Eth, BlockExecutor>
 where
     Eth: EthApiTypes + EthTransactions + TraceExt + 'static,
+    Eth::Provider:
+        ChangeSetReader + HashedStateRangeReader + PruneCheckpointReader + StorageChangeSetReader,
     BlockExecutor:
         BlockExecutorProvider<Primitives: NodePrimitives<Block = ProviderBlock<Eth::Provider>>>,
 {
@@ -917,8 +1530,8 @@ where
     }
 
     /// Handler for `debug_getBadBlocks`
-    async fn bad_blocks(&self) -> RpcResult<Vec<RpcBlock>> {
-        Err(internal_rpc_err("unimplemented"))
+    async fn bad_blocks(&self) -> RpcResult<Vec<BadBlock<RpcBlock<Eth::NetworkTypes>>>> {
+        Self::debug_bad_blocks(self).map_err(Into::into)
     }
 
     /// Handler for `debug_traceChain`
@@ -1025,14 +1638,24 @@ where
 
     async fn debug_account_range(
         &self,
-        _block_number: BlockNumberOrTag,
-        _start: Bytes,
-        _max_results: u64,
-        _nocode: bool,
-        _nostorage: bool,
-        _incompletes: bool,
-    ) -> RpcResult<()> {
-        Ok(())
+        block_number: BlockNumberOrTag,
+        start: Bytes,
+        max_results: u64,
+        nocode: bool,
+        nostorage: bool,
+        incompletes: bool,
+    ) -> RpcResult<StateDump> {
+        Self::debug_account_range(
+            self,
+            block_number,
+            start,
+            max_results,
+            nocode,
+            nostorage,
+            incompletes,
+        )
+        .await
+        .map_err(Into::into)
     }
 
     async fn debug_block_profile(&self, _file: String, _seconds: u64) -> RpcResult<()> {
@@ -1097,18 +1720,22 @@ where
 
     async fn debug_get_modified_accounts_by_hash(
         &self,
-        _start_hash: B256,
-        _end_hash: B256,
-    ) -> RpcResult<()> {
-        Ok(())
+        start_hash: B256,
+        end_hash: Option<B256>,
+    ) -> RpcResult<Vec<Address>> {
+        Self::debug_get_modified_accounts_by_hash(self, start_hash, end_hash)
+            .await
+            .map_err(Into::into)
     }
 
     async fn debug_get_modified_accounts_by_number(
         &self,
-        _start_number: u64,
-        _end_number: u64,
-    ) -> RpcResult<()> {
-        Ok(())
+        start_number: u64,
+        end_number: Option<u64>,
+    ) -> RpcResult<Vec<Address>> {
+        Self::debug_get_modified_accounts_by_number(self, start_number, end_number)
+            .await
+            .map_err(Into::into)
     }
 
     async fn debug_go_trace(&self, _file: String, _seconds: u64) -> RpcResult<()> {
@@ -1117,10 +1744,11 @@ where
 
     async fn debug_intermediate_roots(
         &self,
-        _block_hash: B256,
-        _opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()> {
-        Ok(())
+        block_hash: B256,
+        _opts: Option<GethDebugTracingOptions>,
+    ) -> RpcResult<Vec<B256>> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_intermediate_roots(self, block_hash).await.map_err(Into::into)
     }
 
     async fn debug_mem_stats(&self) -> RpcResult<()> {
@@ -1167,20 +1795,28 @@ where
         Ok(())
     }
 
+    /// Handler for `debug_standardTraceBadBlockToFile`
     async fn debug_standard_trace_bad_block_to_file(
         &self,
-        _block: BlockNumberOrTag,
-        _opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()> {
-        Ok(())
+        block_hash: B256,
+        opts: Option<StdTraceConfig>,
+    ) -> RpcResult<Vec<String>> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_standard_trace_bad_block_to_file(self, block_hash, opts.unwrap_or_default())
+            .await
+            .map_err(Into::into)
     }
 
+    /// Handler for `debug_standardTraceBlockToFile`
     async fn debug_standard_trace_block_to_file(
         &self,
-        _block: BlockNumberOrTag,
-        _opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()> {
-        Ok(())
+        block_hash: B256,
+        opts: Option<StdTraceConfig>,
+    ) -> RpcResult<Vec<String>> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_standard_trace_block_to_file(self, block_hash, opts.unwrap_or_default())
+            .await
+            .map_err(Into::into)
     }
 
     async fn debug_start_cpu_profile(&self, _file: String) -> RpcResult<()> {
@@ -1201,21 +1837,35 @@ where
 
     async fn debug_storage_range_at(
         &self,
-        _block_hash: B256,
-        _tx_idx: usize,
-        _contract_address: Address,
-        _key_start: B256,
-        _max_result: u64,
-    ) -> RpcResult<()> {
-        Ok(())
-    }
-
+        block_hash: B256,
+        tx_idx: usize,
+        contract_address: Address,
+        key_start: B256,
+        max_result: u64,
+    ) -> RpcResult<StorageRangeResult> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_storage_range_at(
+            self,
+            block_hash,
+            tx_idx,
+            contract_address,
+            key_start,
+            max_result,
+        )
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Handler for `debug_traceBadBlock`
     async fn debug_trace_bad_block(
         &self,
-        _block_hash: B256,
-        _opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()> {
-        Ok(())
+        block_hash: B256,
+        opts: Option<GethDebugTracingOptions>,
+    ) -> RpcResult<Vec<TraceResult>> {
+        let _permit = self.acquire_trace_permit().await;
+        Self::debug_trace_bad_block(self, block_hash, opts.unwrap_or_default())
+            .await
+            .map_err(Into::into)
     }
 
     async fn debug_verbosity(&self, _level: usize) -> RpcResult<()> {
@@ -1258,4 +1908,110 @@ struct DebugApiInner<Eth, BlockExecutor> {
     blocking_task_guard: BlockingTaskGuard,
     /// block executor for debug & trace apis
     block_executor: BlockExecutor,
+    /// The blocks that were rejected as invalid.
+    bad_block_store: BadBlockStore,
+    /// The configuration of the `debug` API.
+    config: DebugApiConfig,
+}
+
+/// Configuration of the `debug` API.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DebugApiConfig {
+    /// The directory the files of `debug_standardTraceBlockToFile` and
+    /// `debug_standardTraceBadBlockToFile` are written to.
+    pub trace_dir: PathBuf,
+}
+
+impl Default for DebugApiConfig {
+    fn default() -> Self {
+        Self { trace_dir: std::env::temp_dir() }
+    }
+}
+
+/// The number of blocks kept by a [`BadBlockStore`] by default, same as in geth.
+const DEFAULT_BAD_BLOCK_LIMIT: usize = 10;
+
+/// A bounded store of the most recent blocks that were rejected as invalid, served by
+/// `debug_getBadBlocks` and `debug_traceBadBlock`.
+///
+/// The blocks are kept RLP encoded, which makes the store independent of the block type of the
+/// node. Once the store is full, the oldest block is evicted.
+#[derive(Clone, Debug)]
+pub struct BadBlockStore {
+    /// The hashes and RLP encodings of the blocks, oldest first.
+    blocks: Arc<RwLock<VecDeque<(B256, Bytes)>>>,
+    /// The maximum number of blocks.
+    limit: usize,
+}
+
+impl BadBlockStore {
+    /// Creates a new store that keeps up to `limit` blocks.
+    pub fn new(limit: usize) -> Self {
+        Self { blocks: Default::default(), limit }
+    }
+
+    /// Inserts a bad block, evicting the oldest blocks if the store is full.
+    pub fn insert<B: BlockTrait>(&self, block: &SealedBlock<B>) {
+        let mut blocks = self.blocks.write();
+        if blocks.iter().any(|(hash, _)| *hash == block.hash()) {
+            return
+        }
+        blocks.push_back((block.hash(), alloy_rlp::encode(block).into()));
+        while blocks.len() > self.limit {
+            blocks.pop_front();
+        }
+    }
+
+    /// Returns the RLP encoded block with the given hash.
+    pub fn get(&self, hash: &B256) -> Option<Bytes> {
+        self.blocks
+            .read()
+            .iter()
+            .find(|(block_hash, _)| block_hash == hash)
+            .map(|(_, rlp)| rlp.clone())
+    }
+
+    /// Returns the hashes and RLP encodings of all blocks, most recent first.
+    pub fn blocks(&self) -> Vec<(B256, Bytes)> {
+        self.blocks.read().iter().rev().cloned().collect()
+    }
+}
+
+impl Default for BadBlockStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_BAD_BLOCK_LIMIT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::Header;
+
+    fn block(number: u64) -> SealedBlock<reth_primitives::Block> {
+        SealedBlock::seal_slow(reth_primitives::Block {
+            header: Header { number, ..Default::default() },
+            body: Default::default(),
+        })
+    }
+
+    #[test]
+    fn bad_block_store_evicts_oldest() {
+        let store = BadBlockStore::new(2);
+        let blocks = (0..3).map(block).collect::<Vec<_>>();
+
+        store.insert(&blocks[0]);
+        store.insert(&blocks[1]);
+        // inserting a known block again does not change the order
+        store.insert(&blocks[0]);
+        store.insert(&blocks[2]);
+
+        let hashes = store.blocks().into_iter().map(|(hash, _)| hash).collect::<Vec<_>>();
+        assert_eq!(hashes, vec![blocks[2].hash(), blocks[1].hash()]);
+        assert!(store.get(&blocks[0].hash()).is_none());
+
+        let rlp = store.get(&blocks[1].hash()).unwrap();
+        let decoded: reth_primitives::Block = Decodable::decode(&mut rlp.as_ref()).unwrap();
+        assert_eq!(SealedBlock::seal_slow(decoded), blocks[1]);
+    }
 }
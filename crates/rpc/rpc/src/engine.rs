@@ -5,7 +5,7 @@ use alloy_rpc_types_eth::{
     EIP1186AccountProofResponse, Filter, Log, SyncStatus,
 };
 use alloy_serde::JsonStorageKey;
-use jsonrpsee::core::RpcResult as Result;
+use jsonrpsee::{core::RpcResult as Result, Extensions};
 use reth_rpc_api::{EngineEthApiServer, EthApiServer, EthFilterApiServer};
 /// Re-export for convenience
 pub use reth_rpc_engine_api::EngineApi;
@@ -111,8 +111,8 @@ where
     }
 
     /// Handler for: `eth_sendRawTransaction`
-    async fn send_raw_transaction(&self, bytes: Bytes) -> Result<B256> {
-        self.eth.send_raw_transaction(bytes).instrument(engine_span!()).await
+    async fn send_raw_transaction(&self, ext: &Extensions, bytes: Bytes) -> Result<B256> {
+        self.eth.send_raw_transaction(ext, bytes).instrument(engine_span!()).await
     }
 
     async fn transaction_receipt(
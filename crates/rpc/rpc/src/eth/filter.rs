@@ -11,8 +11,8 @@ use jsonrpsee::{core::RpcResult, server::IdProvider};
 use reth_chainspec::ChainInfo;
 use reth_primitives::RecoveredBlock;
 use reth_provider::{
-    BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, HeaderProvider, ProviderBlock,
-    ProviderError, ProviderReceipt,
+    BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, HeaderProvider, LogIndexReader,
+    ProviderBlock, ProviderError, ProviderReceipt,
 };
 use reth_rpc_eth_api::{
     EthApiTypes, EthFilterApiServer, FullEthApiTypes, RpcNodeCoreExt, RpcTransaction,
@@ -26,7 +26,7 @@ use reth_rpc_server_types::{result::rpc_error_with_code, ToRpcResult};
 use reth_tasks::TaskSpawner;
 use reth_transaction_pool::{NewSubpoolTransactionStream, PoolTransaction, TransactionPool};
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap},
     fmt,
     iter::StepBy,
     ops::RangeInclusive,
@@ -155,7 +155,7 @@ where
 
 impl<Eth> EthFilter<Eth>
 where
-    Eth: FullEthApiTypes<Provider: BlockReader + BlockIdReader> + RpcNodeCoreExt,
+    Eth: FullEthApiTypes<Provider: BlockReader + BlockIdReader + LogIndexReader> + RpcNodeCoreExt,
 {
     /// Access the underlying provider.
     fn provider(&self) -> &Eth::Provider {
@@ -262,7 +262,7 @@ where
 #[async_trait]
 impl<Eth> EthFilterApiServer<RpcTransaction<Eth::NetworkTypes>> for EthFilter<Eth>
 where
-    Eth: FullEthApiTypes + RpcNodeCoreExt<Provider: BlockIdReader> + 'static,
+    Eth: FullEthApiTypes + RpcNodeCoreExt<Provider: BlockIdReader + LogIndexReader> + 'static,
 {
     /// Handler for `eth_newFilter`
     async fn new_filter(&self, filter: Filter) -> RpcResult<FilterId> {
@@ -381,7 +381,8 @@ struct EthFilterInner<Eth: EthApiTypes> {
 
 impl<Eth> EthFilterInner<Eth>
 where
-    Eth: RpcNodeCoreExt<Provider: BlockIdReader, Pool: TransactionPool> + EthApiTypes,
+    Eth: RpcNodeCoreExt<Provider: BlockIdReader + LogIndexReader, Pool: TransactionPool>
+        + EthApiTypes,
 {
     /// Access the underlying provider.
     fn provider(&self) -> &Eth::Provider {
@@ -476,8 +477,12 @@ where
 
     /// Returns all logs in the given _inclusive_ range that match the filter
     ///
+    /// The part of the range that is covered by the log index is answered from the index, the
+    /// remaining blocks are scanned by checking the logs bloom of their headers.
+    ///
     /// Returns an error if:
     ///  - underlying database error
+    ///  - amount of scanned blocks exceeds configured limit
     ///  - amount of matches exceeds configured limit
     async fn get_logs_in_block_range(
         &self,
@@ -492,22 +497,144 @@ where
             return Err(EthFilterError::InvalidBlockRangeParams)
         }
 
-        if to_block - from_block > self.max_blocks_per_filter {
+        // the log index can only narrow down the blocks if the filter restricts the logs
+        let indexed_range =
+            if filter.address.is_empty() && filter.topics.iter().all(|topic| topic.is_empty()) {
+                None
+            } else {
+                self.provider()
+                    .log_index_range()?
+                    .map(|range| (*range.start()).max(from_block)..=(*range.end()).min(to_block))
+                    .filter(|range| !range.is_empty())
+            };
+
+        // only blocks that are not covered by the log index count towards the limit
+        let scanned_blocks = match &indexed_range {
+            Some(range) => to_block - from_block - (range.end() - range.start()),
+            None => to_block - from_block,
+        };
+        if scanned_blocks > self.max_blocks_per_filter {
             return Err(EthFilterError::QueryExceedsMaxBlocks(self.max_blocks_per_filter))
         }
 
         let mut all_logs = Vec::new();
         let filter_params = FilteredParams::new(Some(filter.clone()));
+        let block_range = from_block..=to_block;
+
+        let Some(indexed_range) = indexed_range else {
+            self.append_logs_in_scanned_range(
+                &mut all_logs,
+                filter,
+                &filter_params,
+                block_range.clone(),
+                &block_range,
+                chain_info,
+            )
+            .await?;
+            return Ok(all_logs)
+        };
+
+        // blocks below the log index
+        if from_block < *indexed_range.start() {
+            self.append_logs_in_scanned_range(
+                &mut all_logs,
+                filter,
+                &filter_params,
+                from_block..=indexed_range.start() - 1,
+                &block_range,
+                chain_info,
+            )
+            .await?;
+        }
+
+        // blocks covered by the log index
+        for block_number in self.indexed_blocks(filter, indexed_range.clone())? {
+            let header = self
+                .provider()
+                .sealed_header(block_number)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+            self.append_block_logs(
+                &mut all_logs,
+                &filter_params,
+                BlockNumHash::new(block_number, header.hash()),
+                header.timestamp(),
+                &block_range,
+                chain_info,
+            )
+            .await?;
+        }
+
+        // blocks above the log index, e.g. blocks that are not persisted yet
+        if *indexed_range.end() < to_block {
+            self.append_logs_in_scanned_range(
+                &mut all_logs,
+                filter,
+                &filter_params,
+                indexed_range.end() + 1..=to_block,
+                &block_range,
+                chain_info,
+            )
+            .await?;
+        }
+
+        Ok(all_logs)
+    }
 
+    /// Returns the blocks in the given range that may contain logs matching the filter, in
+    /// ascending order.
+    ///
+    /// The log index doesn't record the position of the topics, so the blocks are a superset of
+    /// the blocks with matching logs.
+    fn indexed_blocks(
+        &self,
+        filter: &Filter,
+        range: RangeInclusive<u64>,
+    ) -> Result<Vec<u64>, EthFilterError> {
+        let mut blocks: Option<BTreeSet<u64>> = None;
+        let mut intersect = |matching: BTreeSet<u64>| {
+            blocks = Some(match blocks.take() {
+                Some(blocks) => blocks.intersection(&matching).copied().collect(),
+                None => matching,
+            });
+        };
+
+        if !filter.address.is_empty() {
+            let mut matching = BTreeSet::new();
+            for address in filter.address.iter() {
+                matching.extend(self.provider().log_address_blocks(*address, range.clone())?);
+            }
+            intersect(matching);
+        }
+
+        for topic in filter.topics.iter().filter(|topic| !topic.is_empty()) {
+            let mut matching = BTreeSet::new();
+            for value in topic.iter() {
+                matching.extend(self.provider().log_topic_blocks(*value, range.clone())?);
+            }
+            intersect(matching);
+        }
+
+        Ok(blocks.unwrap_or_default().into_iter().collect())
+    }
+
+    /// Appends all logs in the given _inclusive_ range that match the filter, by checking the logs
+    /// bloom of every header in the range.
+    async fn append_logs_in_scanned_range(
+        &self,
+        all_logs: &mut Vec<Log>,
+        filter: &Filter,
+        filter_params: &FilteredParams,
+        range: RangeInclusive<u64>,
+        block_range: &RangeInclusive<u64>,
+        chain_info: ChainInfo,
+    ) -> Result<(), EthFilterError> {
         // derive bloom filters from filter input, so we can check headers for matching logs
         let address_filter = FilteredParams::address_filter(&filter.address);
         let topics_filter = FilteredParams::topics_filter(&filter.topics);
 
         // loop over the range of new blocks and check logs if the filter matches the log's bloom
         // filter
-        for (from, to) in
-            BlockRangeInclusiveIter::new(from_block..=to_block, self.max_headers_range)
-        {
+        for (from, to) in BlockRangeInclusiveIter::new(range, self.max_headers_range) {
             let headers = self.provider().headers_range(from..=to)?;
 
             for (idx, header) in headers.iter().enumerate() {
@@ -526,37 +653,63 @@ where
                     };
 
                     let num_hash = BlockNumHash::new(header.number(), block_hash);
-                    if let Some((receipts, maybe_block)) =
-                        self.receipts_and_maybe_block(&num_hash, chain_info.best_number).await?
-                    {
-                        append_matching_block_logs(
-                            &mut all_logs,
-                            maybe_block
-                                .map(ProviderOrBlock::Block)
-                                .unwrap_or_else(|| ProviderOrBlock::Provider(self.provider())),
-                            &filter_params,
-                            num_hash,
-                            &receipts,
-                            false,
-                            header.timestamp(),
-                        )?;
-
-                        // size check but only if range is multiple blocks, so we always return all
-                        // logs of a single block
-                        let is_multi_block_range = from_block != to_block;
-                        if is_multi_block_range && all_logs.len() > self.max_logs_per_response {
-                            return Err(EthFilterError::QueryExceedsMaxResults {
-                                max_logs: self.max_logs_per_response,
-                                from_block,
-                                to_block: num_hash.number.saturating_sub(1),
-                            });
-                        }
-                    }
+                    self.append_block_logs(
+                        all_logs,
+                        filter_params,
+                        num_hash,
+                        header.timestamp(),
+                        block_range,
+                        chain_info,
+                    )
+                    .await?;
                 }
             }
         }
 
-        Ok(all_logs)
+        Ok(())
+    }
+
+    /// Appends the logs of the block that match the filter.
+    ///
+    /// Returns an error if the amount of matches in the queried block range exceeds the configured
+    /// limit.
+    async fn append_block_logs(
+        &self,
+        all_logs: &mut Vec<Log>,
+        filter_params: &FilteredParams,
+        num_hash: BlockNumHash,
+        timestamp: u64,
+        block_range: &RangeInclusive<u64>,
+        chain_info: ChainInfo,
+    ) -> Result<(), EthFilterError> {
+        if let Some((receipts, maybe_block)) =
+            self.receipts_and_maybe_block(&num_hash, chain_info.best_number).await?
+        {
+            append_matching_block_logs(
+                all_logs,
+                maybe_block
+                    .map(ProviderOrBlock::Block)
+                    .unwrap_or_else(|| ProviderOrBlock::Provider(self.provider())),
+                filter_params,
+                num_hash,
+                &receipts,
+                false,
+                timestamp,
+            )?;
+
+            // size check but only if range is multiple blocks, so we always return all
+            // logs of a single block
+            let is_multi_block_range = block_range.start() != block_range.end();
+            if is_multi_block_range && all_logs.len() > self.max_logs_per_response {
+                return Err(EthFilterError::QueryExceedsMaxResults {
+                    max_logs: self.max_logs_per_response,
+                    from_block: *block_range.start(),
+                    to_block: num_hash.number.saturating_sub(1),
+                });
+            }
+        }
+
+        Ok(())
     }
 
     /// Retrieves receipts and block from cache if near the tip (4 blocks), otherwise only receipts.
@@ -8,7 +8,10 @@ use reth_rpc_eth_api::{
     FromEthApiError, FullEthApiTypes, RpcNodeCore, RpcNodeCoreExt,
 };
 use reth_rpc_eth_types::utils::recover_raw_transaction;
-use reth_transaction_pool::{PoolTransaction, TransactionOrigin, TransactionPool};
+use reth_transaction_pool::{
+    admission::AdmissionSource, PoolTransaction, TransactionOrigin, TransactionPool,
+};
+use std::net::IpAddr;
 
 impl<Provider, Pool, Network, EvmConfig> EthTransactions
     for EthApi<Provider, Pool, Network, EvmConfig>
@@ -24,7 +27,11 @@ where
     /// Decodes and recovers the transaction and submits it to the pool.
     ///
     /// Returns the hash of the transaction.
-    async fn send_raw_transaction(&self, tx: Bytes) -> Result<B256, Self::Error> {
+    async fn send_raw_transaction_from(
+        &self,
+        tx: Bytes,
+        client: Option<IpAddr>,
+    ) -> Result<B256, Self::Error> {
         let recovered = recover_raw_transaction(&tx)?;
 
         // broadcast raw transaction to subscribers if there is any.
@@ -33,11 +40,19 @@ where
         let pool_transaction = <Self::Pool as TransactionPool>::Transaction::from_pooled(recovered);
 
         // submit the transaction to the pool with a `Local` origin
-        let hash = self
-            .pool()
-            .add_transaction(TransactionOrigin::Local, pool_transaction)
-            .await
-            .map_err(Self::Error::from_eth_err)?;
+        let hash = match client {
+            Some(ip) => {
+                self.pool()
+                    .add_transaction_from(
+                        TransactionOrigin::Local,
+                        AdmissionSource::Remote(ip),
+                        pool_transaction,
+                    )
+                    .await
+            }
+            None => self.pool().add_transaction(TransactionOrigin::Local, pool_transaction).await,
+        }
+        .map_err(Self::Error::from_eth_err)?;
 
         Ok(hash)
     }
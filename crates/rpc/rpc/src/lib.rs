@@ -33,6 +33,7 @@ use pin_project as _;
 use tower as _;
 
 mod admin;
+mod anvil;
 mod debug;
 mod engine;
 pub mod eth;
@@ -47,7 +48,8 @@ mod validation;
 mod web3;
 
 pub use admin::AdminApi;
-pub use debug::DebugApi;
+pub use anvil::{AnvilApi, ImpersonatedSigner};
+pub use debug::{BadBlockStore, DebugApi, DebugApiConfig};
 pub use engine::{EngineApi, EngineEthApi};
 pub use eth::{EthApi, EthApiBuilder, EthBundle, EthFilter, EthPubSub};
 pub use miner::MinerApi;
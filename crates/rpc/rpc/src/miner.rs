@@ -1,25 +1,114 @@
+use alloy_consensus::constants::MAXIMUM_EXTRA_DATA_SIZE;
 use alloy_primitives::{Bytes, U128};
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
+use reth_payload_primitives::MinerSettings;
+use reth_primitives_traits::constants::MINIMUM_GAS_LIMIT;
 use reth_rpc_api::MinerApiServer;
+use reth_rpc_server_types::result::invalid_params_rpc_err;
+use std::sync::Arc;
+
+/// The maximum gas limit of a block, see [EIP-1985](https://eips.ethereum.org/EIPS/eip-1985).
+const MAXIMUM_GAS_LIMIT: u64 = i64::MAX as u64;
 
 /// `miner` API implementation.
 ///
 /// This type provides the functionality for handling `miner` related requests.
+///
+/// The settings are applied to the [`MinerSettings`] of the local payload builder, if configured.
+/// Otherwise all methods return `false`.
 #[derive(Clone, Debug, Default)]
-pub struct MinerApi {}
+pub struct MinerApi {
+    /// Settings of the local payload builder.
+    settings: Option<Arc<dyn MinerSettings>>,
+}
+
+impl MinerApi {
+    /// Creates a new instance that updates the given payload builder settings.
+    pub fn new(settings: Arc<dyn MinerSettings>) -> Self {
+        Self { settings: Some(settings) }
+    }
+}
 
 #[async_trait]
 impl MinerApiServer for MinerApi {
-    fn set_extra(&self, _record: Bytes) -> RpcResult<bool> {
-        Ok(false)
+    fn set_extra(&self, record: Bytes) -> RpcResult<bool> {
+        let Some(settings) = &self.settings else { return Ok(false) };
+        if record.len() > MAXIMUM_EXTRA_DATA_SIZE {
+            return Err(invalid_params_rpc_err(format!(
+                "extra data exceeds {MAXIMUM_EXTRA_DATA_SIZE}-byte limit"
+            )))
+        }
+        settings.set_extra_data(record);
+        Ok(true)
+    }
+
+    fn set_gas_price(&self, gas_price: U128) -> RpcResult<bool> {
+        let Some(settings) = &self.settings else { return Ok(false) };
+        settings.set_min_priority_fee(gas_price.to());
+        Ok(true)
+    }
+
+    fn set_gas_limit(&self, gas_limit: U128) -> RpcResult<bool> {
+        let Some(settings) = &self.settings else { return Ok(false) };
+        let gas_limit = u64::try_from(gas_limit)
+            .ok()
+            .filter(|gas_limit| (MINIMUM_GAS_LIMIT..=MAXIMUM_GAS_LIMIT).contains(gas_limit))
+            .ok_or_else(|| {
+                invalid_params_rpc_err(format!(
+                    "gas limit must be between {MINIMUM_GAS_LIMIT} and {MAXIMUM_GAS_LIMIT}"
+                ))
+            })?;
+        settings.set_gas_limit(gas_limit);
+        Ok(true)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+
+    #[derive(Debug, Default)]
+    struct TestSettings {
+        extra_data: Mutex<Bytes>,
+        min_priority_fee: Mutex<u128>,
+        gas_limit: Mutex<u64>,
+    }
+
+    impl MinerSettings for TestSettings {
+        fn set_extra_data(&self, extra_data: Bytes) {
+            *self.extra_data.lock() = extra_data;
+        }
 
-    fn set_gas_price(&self, _gas_price: U128) -> RpcResult<bool> {
-        Ok(false)
+        fn set_min_priority_fee(&self, min_priority_fee: u128) {
+            *self.min_priority_fee.lock() = min_priority_fee;
+        }
+
+        fn set_gas_limit(&self, gas_limit: u64) {
+            *self.gas_limit.lock() = gas_limit;
+        }
     }
 
-    fn set_gas_limit(&self, _gas_price: U128) -> RpcResult<bool> {
-        Ok(false)
+    #[test]
+    fn update_miner_settings() {
+        let settings = Arc::new(TestSettings::default());
+        let api = MinerApi::new(settings.clone());
+
+        assert!(api.set_extra(Bytes::from_static(b"reth")).unwrap());
+        assert_eq!(*settings.extra_data.lock(), Bytes::from_static(b"reth"));
+        assert!(api.set_extra(vec![0; MAXIMUM_EXTRA_DATA_SIZE + 1].into()).is_err());
+
+        assert!(api.set_gas_price(U128::from(1_000_000_000)).unwrap());
+        assert_eq!(*settings.min_priority_fee.lock(), 1_000_000_000);
+
+        assert!(api.set_gas_limit(U128::from(36_000_000)).unwrap());
+        assert_eq!(*settings.gas_limit.lock(), 36_000_000);
+        assert!(api.set_gas_limit(U128::from(MINIMUM_GAS_LIMIT - 1)).is_err());
+        assert!(api.set_gas_limit(U128::from(MAXIMUM_GAS_LIMIT) + U128::from(1)).is_err());
+        assert!(api.set_gas_limit(U128::MAX).is_err());
+        assert_eq!(*settings.gas_limit.lock(), 36_000_000);
+
+        assert!(!MinerApi::default().set_gas_limit(U128::from(1)).unwrap());
     }
 }
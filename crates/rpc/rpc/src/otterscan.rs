@@ -1,21 +1,26 @@
 use alloy_consensus::{BlockHeader, Transaction, Typed2718};
 use alloy_eips::{BlockId, BlockNumberOrTag};
 use alloy_network::{ReceiptResponse, TransactionResponse};
-use alloy_primitives::{Address, Bytes, TxHash, B256, U256};
-use alloy_rpc_types_eth::{BlockTransactions, TransactionReceipt};
+use alloy_primitives::{Address, BlockNumber, Bytes, TxHash, TxNumber, B256, U256};
+use alloy_rpc_types_eth::{BlockTransactions, TransactionInfo, TransactionReceipt};
 use alloy_rpc_types_trace::{
     otterscan::{
         BlockDetails, ContractCreator, InternalOperation, OperationType, OtsBlockTransactions,
-        OtsReceipt, OtsTransactionReceipt, TraceEntry, TransactionsWithReceipts,
+        OtsReceipt, OtsTransactionReceipt, TraceEntry,
     },
     parity::{Action, CreateAction, CreateOutput, TraceOutput},
 };
 use async_trait::async_trait;
 use jsonrpsee::{core::RpcResult, types::ErrorObjectOwned};
-use reth_rpc_api::{EthApiServer, OtterscanServer};
+use reth_primitives_traits::SignedTransaction;
+use reth_provider::{
+    AddressTransactionsReader, BlockReader, StageCheckpointReader, ADDRESS_INDEX_STAGE_ID,
+};
+use reth_rpc_api::{EthApiServer, OtterscanServer, TransactionsWithReceipts};
 use reth_rpc_eth_api::{
-    helpers::{EthTransactions, TraceExt},
-    FullEthApiTypes, RpcBlock, RpcHeader, RpcReceipt, RpcTransaction, TransactionCompat,
+    helpers::{EthTransactions, LoadBlock, TraceExt},
+    FullEthApiTypes, RpcBlock, RpcHeader, RpcNodeCore, RpcReceipt, RpcTransaction,
+    TransactionCompat,
 };
 use reth_rpc_eth_types::{utils::binary_search, EthApiError};
 use reth_rpc_server_types::result::internal_rpc_err;
@@ -24,9 +29,20 @@ use revm_inspectors::{
     tracing::{types::CallTraceNode, TracingInspectorConfig},
     transfer::{TransferInspector, TransferKind},
 };
+use std::ops::Range;
 
 const API_LEVEL: u64 = 8;
 
+/// Maximum number of transactions in a page of `ots_getBlockTransactions`.
+const MAX_BLOCK_TRANSACTIONS_PAGE_SIZE: usize = 100;
+
+/// Maximum number of transactions of an address in a page of `ots_searchTransactionsBefore` and
+/// `ots_searchTransactionsAfter`.
+///
+/// A page may contain more transactions, since it always ends with all transactions of the last
+/// block.
+const MAX_SEARCH_PAGE_SIZE: usize = 25;
+
 /// Otterscan API.
 #[derive(Debug)]
 pub struct OtterscanApi<Eth> {
@@ -60,6 +76,139 @@ where
 
         Ok(BlockDetails::new(block, Default::default(), U256::from(total_fees)))
     }
+
+    /// Constructs an `OtsTransactionReceipt` from a receipt, the type of its transaction and the
+    /// timestamp of its block.
+    fn ots_receipt(
+        receipt: &RpcReceipt<Eth::NetworkTypes>,
+        tx_ty: u8,
+        timestamp: Option<u64>,
+    ) -> OtsTransactionReceipt {
+        let inner = OtsReceipt {
+            status: receipt.status(),
+            cumulative_gas_used: receipt.cumulative_gas_used(),
+            logs: None,
+            logs_bloom: None,
+            r#type: tx_ty,
+        };
+
+        let receipt = TransactionReceipt {
+            inner,
+            transaction_hash: receipt.transaction_hash(),
+            transaction_index: receipt.transaction_index(),
+            block_hash: receipt.block_hash(),
+            block_number: receipt.block_number(),
+            gas_used: receipt.gas_used(),
+            effective_gas_price: receipt.effective_gas_price(),
+            blob_gas_used: receipt.blob_gas_used(),
+            blob_gas_price: receipt.blob_gas_price(),
+            from: receipt.from(),
+            to: receipt.to(),
+            contract_address: receipt.contract_address(),
+        };
+
+        OtsTransactionReceipt { receipt, timestamp }
+    }
+}
+
+impl<Eth> OtterscanApi<Eth>
+where
+    Eth: EthApiServer<
+            RpcTransaction<Eth::NetworkTypes>,
+            RpcBlock<Eth::NetworkTypes>,
+            RpcReceipt<Eth::NetworkTypes>,
+            RpcHeader<Eth::NetworkTypes>,
+        > + FullEthApiTypes
+        + LoadBlock
+        + RpcNodeCore<Provider: AddressTransactionsReader + StageCheckpointReader + BlockReader>,
+{
+    /// Returns an error if the address index is not maintained by the node.
+    fn ensure_address_index(&self) -> RpcResult<()> {
+        let checkpoint = self
+            .eth
+            .provider()
+            .get_stage_checkpoint(ADDRESS_INDEX_STAGE_ID)
+            .map_err(EthApiError::from)?;
+        if checkpoint.is_none() {
+            return Err(internal_rpc_err(
+                "address index is not enabled, start the node with `--rpc.address-index`",
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns the number of the block that contains the given transaction and the range of
+    /// transaction numbers in that block.
+    fn block_tx_range(&self, tx_number: TxNumber) -> RpcResult<(BlockNumber, Range<TxNumber>)> {
+        let provider = self.eth.provider();
+        let block_number =
+            provider.transaction_block(tx_number).map_err(EthApiError::from)?.ok_or_else(|| {
+                internal_rpc_err(format!("block of transaction {tx_number} not found"))
+            })?;
+        let indices = provider
+            .block_body_indices(block_number)
+            .map_err(EthApiError::from)?
+            .ok_or(EthApiError::HeaderNotFound(block_number.into()))?;
+        Ok((block_number, indices.tx_num_range()))
+    }
+
+    /// Loads the transactions with the given numbers together with their receipts.
+    ///
+    /// The transactions are returned in the same order as the transaction numbers.
+    async fn transactions_with_receipts(
+        &self,
+        tx_numbers: Vec<TxNumber>,
+        first_page: bool,
+        last_page: bool,
+    ) -> RpcResult<TransactionsWithReceipts<RpcTransaction<Eth::NetworkTypes>>> {
+        let mut txs = Vec::with_capacity(tx_numbers.len());
+        let mut receipts = Vec::with_capacity(tx_numbers.len());
+
+        let mut tx_numbers = tx_numbers.into_iter().peekable();
+        while let Some(&tx_number) = tx_numbers.peek() {
+            let (block_number, tx_range) = self.block_tx_range(tx_number)?;
+
+            // retrieve the block with its senders and its receipts
+            let block_id = block_number.into();
+            let block = async { self.eth.block_with_senders(block_id).await.map_err(Into::into) };
+            let block_receipts = self.eth.block_receipts(block_id);
+            let (block, block_receipts) = futures::try_join!(block, block_receipts)?;
+
+            let block = block.ok_or(EthApiError::HeaderNotFound(block_id))?;
+            let block_receipts = block_receipts.ok_or(EthApiError::ReceiptsNotFound(block_id))?;
+            let transactions = block.transactions_recovered().collect::<Vec<_>>();
+            let block_hash = Some(block.hash());
+            let base_fee = block.header().base_fee_per_gas();
+            let timestamp = Some(block.header().timestamp());
+
+            // consume all requested transactions of this block
+            while let Some(tx_number) = tx_numbers.next_if(|tx_number| tx_range.contains(tx_number))
+            {
+                let index = (tx_number - tx_range.start) as usize;
+                let (Some(tx), Some(receipt)) =
+                    (transactions.get(index).cloned(), block_receipts.get(index))
+                else {
+                    return Err(internal_rpc_err(format!(
+                        "transaction {index} of block {block_number} not found"
+                    )))
+                };
+
+                receipts.push(Self::ots_receipt(receipt, tx.ty(), timestamp));
+                let tx_info = TransactionInfo {
+                    hash: Some(*tx.tx_hash()),
+                    block_hash,
+                    block_number: Some(block_number),
+                    base_fee,
+                    index: Some(index as u64),
+                };
+                txs.push(
+                    self.eth.tx_resp_builder().fill(tx.cloned(), tx_info).map_err(Into::into)?,
+                );
+            }
+        }
+
+        Ok(TransactionsWithReceipts { txs, receipts, first_page, last_page })
+    }
 }
 
 #[async_trait]
@@ -74,6 +223,7 @@ where
         > + EthTransactions
         + TraceExt
         + 'static,
+    Eth::Provider: AddressTransactionsReader + StageCheckpointReader,
 {
     /// Handler for `ots_getHeaderByNumber` and `erigon_getHeaderByNumber`
     async fn get_header_by_number(
@@ -235,7 +385,8 @@ where
         };
 
         // Crop page
-        let page_end = tx_len.saturating_sub(page_number * page_size);
+        let page_size = page_size.min(MAX_BLOCK_TRANSACTIONS_PAGE_SIZE);
+        let page_end = tx_len.saturating_sub(page_number.saturating_mul(page_size));
         let page_start = page_end.saturating_sub(page_size);
 
         // Crop transactions
@@ -255,32 +406,7 @@ where
         let receipts = receipts
             .drain(page_start..page_end)
             .zip(transactions.iter().map(Typed2718::ty))
-            .map(|(receipt, tx_ty)| {
-                let inner = OtsReceipt {
-                    status: receipt.status(),
-                    cumulative_gas_used: receipt.cumulative_gas_used(),
-                    logs: None,
-                    logs_bloom: None,
-                    r#type: tx_ty,
-                };
-
-                let receipt = TransactionReceipt {
-                    inner,
-                    transaction_hash: receipt.transaction_hash(),
-                    transaction_index: receipt.transaction_index(),
-                    block_hash: receipt.block_hash(),
-                    block_number: receipt.block_number(),
-                    gas_used: receipt.gas_used(),
-                    effective_gas_price: receipt.effective_gas_price(),
-                    blob_gas_used: receipt.blob_gas_used(),
-                    blob_gas_price: receipt.blob_gas_price(),
-                    from: receipt.from(),
-                    to: receipt.to(),
-                    contract_address: receipt.contract_address(),
-                };
-
-                OtsTransactionReceipt { receipt, timestamp }
-            })
+            .map(|(receipt, tx_ty)| Self::ots_receipt(&receipt, tx_ty, timestamp))
             .collect();
 
         // use `transaction_count` to indicate the paginate information
@@ -292,21 +418,100 @@ where
     /// Handler for `ots_searchTransactionsBefore`
     async fn search_transactions_before(
         &self,
-        _address: Address,
-        _block_number: u64,
-        _page_size: usize,
-    ) -> RpcResult<TransactionsWithReceipts> {
-        Err(internal_rpc_err("unimplemented"))
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> RpcResult<TransactionsWithReceipts<RpcTransaction<Eth::NetworkTypes>>> {
+        self.ensure_address_index()?;
+        let provider = self.eth.provider();
+        let page_size = page_size.min(MAX_SEARCH_PAGE_SIZE);
+
+        // `0` means that the search starts at the most recent block
+        let before = if block_number == 0 {
+            TxNumber::MAX
+        } else {
+            provider
+                .block_body_indices(block_number)
+                .map_err(EthApiError::from)?
+                .map_or(TxNumber::MAX, |indices| indices.first_tx_num())
+        };
+
+        let mut tx_numbers = provider
+            .address_transactions_before(address, before, page_size)
+            .map_err(EthApiError::from)?;
+
+        // a page always contains all appearances in a block, so the remaining appearances in the
+        // block of the oldest transaction are added as well
+        if let Some(oldest) = tx_numbers.last().copied().filter(|_| tx_numbers.len() == page_size) {
+            let (_, tx_range) = self.block_tx_range(oldest)?;
+            let block_first_tx = tx_range.start;
+            let remaining = provider
+                .address_transactions_before(address, oldest, (oldest - block_first_tx) as usize)
+                .map_err(EthApiError::from)?;
+            tx_numbers.extend(remaining.into_iter().filter(|tx| *tx >= block_first_tx));
+        }
+
+        let last_page = match tx_numbers.last() {
+            Some(&oldest) => provider
+                .address_transactions_before(address, oldest, 1)
+                .map_err(EthApiError::from)?
+                .is_empty(),
+            None => true,
+        };
+
+        self.transactions_with_receipts(tx_numbers, block_number == 0, last_page).await
     }
 
     /// Handler for `ots_searchTransactionsAfter`
     async fn search_transactions_after(
         &self,
-        _address: Address,
-        _block_number: u64,
-        _page_size: usize,
-    ) -> RpcResult<TransactionsWithReceipts> {
-        Err(internal_rpc_err("unimplemented"))
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> RpcResult<TransactionsWithReceipts<RpcTransaction<Eth::NetworkTypes>>> {
+        self.ensure_address_index()?;
+        let provider = self.eth.provider();
+        let page_size = page_size.min(MAX_SEARCH_PAGE_SIZE);
+
+        let Some(from) = provider
+            .block_body_indices(block_number)
+            .map_err(EthApiError::from)?
+            .map(|indices| indices.next_tx_num())
+        else {
+            return Ok(TransactionsWithReceipts {
+                txs: Vec::new(),
+                receipts: Vec::new(),
+                first_page: true,
+                last_page: block_number == 0,
+            })
+        };
+
+        let mut tx_numbers = provider
+            .address_transactions_from(address, from, page_size)
+            .map_err(EthApiError::from)?;
+
+        // a page always contains all appearances in a block, so the remaining appearances in the
+        // block of the newest transaction are added as well
+        if let Some(newest) = tx_numbers.last().copied().filter(|_| tx_numbers.len() == page_size) {
+            let (_, tx_range) = self.block_tx_range(newest)?;
+            let block_last_tx = tx_range.end - 1;
+            let remaining = provider
+                .address_transactions_from(address, newest + 1, (block_last_tx - newest) as usize)
+                .map_err(EthApiError::from)?;
+            tx_numbers.extend(remaining.into_iter().filter(|tx| *tx <= block_last_tx));
+        }
+
+        let first_page = match tx_numbers.last() {
+            Some(&newest) => provider
+                .address_transactions_from(address, newest + 1, 1)
+                .map_err(EthApiError::from)?
+                .is_empty(),
+            None => true,
+        };
+
+        // results are always returned from the newest to the oldest transaction
+        tx_numbers.reverse();
+        self.transactions_with_receipts(tx_numbers, first_page, block_number == 0).await
     }
 
     /// Handler for `ots_getTransactionBySenderAndNonce`
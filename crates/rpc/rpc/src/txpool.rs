@@ -2,19 +2,28 @@ use core::fmt;
 use std::collections::BTreeMap;
 
 use alloy_consensus::Transaction;
-use alloy_primitives::Address;
+use alloy_primitives::{Address, TxHash, U128, U64};
 use alloy_rpc_types_txpool::{
     TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolInspectSummary, TxpoolStatus,
 };
 use async_trait::async_trait;
-use jsonrpsee::core::RpcResult;
-use reth_rpc_api::TxPoolApiServer;
+use futures::StreamExt;
+use jsonrpsee::{core::RpcResult, PendingSubscriptionSink};
+use reth_rpc_api::{
+    TxPoolApiServer, TxpoolEvent, TxpoolFeeSimulation, TxpoolHistoryEntry, TxpoolParkedReason,
+    TxpoolPropagateKind, TxpoolSubPool, TxpoolTransactionEvent, TxpoolTransactionStatus,
+};
+use reth_rpc_server_types::result::internal_rpc_err;
 use reth_rpc_types_compat::TransactionCompat;
+use reth_tasks::pool::{BlockingTaskGuard, BlockingTaskPool};
 use reth_transaction_pool::{
-    AllPoolTransactions, PoolConsensusTx, PoolTransaction, TransactionPool,
+    AllPoolTransactions, ParkedReason, PoolConsensusTx, PoolTransaction, PoolTransactionEvent,
+    PoolTransactionStatus, PropagateKind, SubPool, TransactionEvent, TransactionPool,
 };
 use tracing::trace;
 
+use crate::eth::pubsub::pipe_from_stream;
+
 /// `txpool` API implementation.
 ///
 /// This type provides the functionality for handling `txpool` related requests.
@@ -23,12 +32,21 @@ pub struct TxPoolApi<Pool, Eth> {
     /// An interface to interact with the pool
     pool: Pool,
     tx_resp_builder: Eth,
+    /// The thread pool the fee simulations are executed on.
+    blocking_task_pool: BlockingTaskPool,
+    /// Limits the fee simulations to one at a time.
+    simulation_guard: BlockingTaskGuard,
 }
 
 impl<Pool, Eth> TxPoolApi<Pool, Eth> {
     /// Creates a new instance of `TxpoolApi`.
-    pub const fn new(pool: Pool, tx_resp_builder: Eth) -> Self {
-        Self { pool, tx_resp_builder }
+    pub fn new(pool: Pool, tx_resp_builder: Eth, blocking_task_pool: BlockingTaskPool) -> Self {
+        Self {
+            pool,
+            tx_resp_builder,
+            blocking_task_pool,
+            simulation_guard: BlockingTaskGuard::new(1),
+        }
     }
 }
 
@@ -142,6 +160,123 @@ where
         trace!(target: "rpc::eth", "Serving txpool_content");
         Ok(self.content().map_err(Into::into)?)
     }
+
+    /// Handler for `txpool_transactionStatus`
+    async fn txpool_transaction_status(
+        &self,
+        hash: TxHash,
+    ) -> RpcResult<Option<TxpoolTransactionStatus>> {
+        trace!(target: "rpc::eth", ?hash, "Serving txpool_transactionStatus");
+        Ok(self.pool.transaction_status(hash).map(to_rpc_status))
+    }
+
+    /// Handler for `txpool_statusFrom`
+    async fn txpool_status_from(&self, from: Address) -> RpcResult<Vec<TxpoolTransactionStatus>> {
+        trace!(target: "rpc::eth", ?from, "Serving txpool_statusFrom");
+        Ok(self.pool.transaction_statuses_by_sender(from).into_iter().map(to_rpc_status).collect())
+    }
+
+    /// Handler for `txpool_simulateFees`
+    async fn txpool_simulate_fees(
+        &self,
+        base_fee: U64,
+        blob_fee: Option<U128>,
+    ) -> RpcResult<TxpoolFeeSimulation> {
+        trace!(target: "rpc::eth", ?base_fee, ?blob_fee, "Serving txpool_simulateFees");
+        let mut block_info = self.pool.block_info();
+        block_info.pending_basefee = base_fee.to();
+        if let Some(blob_fee) = blob_fee {
+            block_info.pending_blob_fee = Some(blob_fee.to());
+        }
+
+        // a simulation copies every transaction of the pool, so they are executed one at a time
+        // and off the async workers
+        let _permit = self
+            .simulation_guard
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|err| internal_rpc_err(err.to_string()))?;
+        let pool = self.pool.clone();
+        let simulation = self
+            .blocking_task_pool
+            .spawn(move || pool.simulate_block_info(block_info))
+            .await
+            .map_err(|_| internal_rpc_err("fee simulation failed"))?;
+        Ok(TxpoolFeeSimulation {
+            pending: simulation.pending.iter().map(|tx| *tx.hash()).collect(),
+            evicted: simulation.evicted.iter().map(|tx| *tx.hash()).collect(),
+        })
+    }
+
+    /// Handler for `txpool_subscribeTransactionEvents`
+    async fn txpool_subscribe_transaction_events(
+        &self,
+        pending: PendingSubscriptionSink,
+    ) -> jsonrpsee::core::SubscriptionResult {
+        trace!(target: "rpc::eth", "Serving txpool_subscribeTransactionEvents");
+        let sink = pending.accept().await?;
+        let events = self.pool.all_transactions_event_listener().map(|event| {
+            let PoolTransactionEvent { hash, event } = event.into();
+            TxpoolTransactionEvent { hash, event: to_rpc_event(event) }
+        });
+        let _ = pipe_from_stream(sink, events).await;
+        Ok(())
+    }
+}
+
+/// Converts the status of a transaction in the pool to its RPC representation.
+fn to_rpc_status(status: PoolTransactionStatus) -> TxpoolTransactionStatus {
+    TxpoolTransactionStatus {
+        hash: status.hash,
+        subpool: status.subpool.map(|subpool| match subpool {
+            SubPool::Queued => TxpoolSubPool::Queued,
+            SubPool::Pending => TxpoolSubPool::Pending,
+            SubPool::BaseFee => TxpoolSubPool::BaseFee,
+            SubPool::Blob => TxpoolSubPool::Blob,
+        }),
+        parked_reasons: status
+            .parked_reasons
+            .into_iter()
+            .map(|reason| match reason {
+                ParkedReason::ParkedAncestor => TxpoolParkedReason::ParkedAncestor,
+                ParkedReason::NonceGap => TxpoolParkedReason::NonceGap,
+                ParkedReason::InsufficientBalance => TxpoolParkedReason::InsufficientBalance,
+                ParkedReason::ExceedsBlockGasLimit => TxpoolParkedReason::ExceedsBlockGasLimit,
+                ParkedReason::FeeCapBelowBaseFee => TxpoolParkedReason::FeeCapBelowBaseFee,
+                ParkedReason::BlobFeeCapBelowBlobFee => TxpoolParkedReason::BlobFeeCapBelowBlobFee,
+            })
+            .collect(),
+        history: status
+            .history
+            .into_iter()
+            .map(|entry| TxpoolHistoryEntry {
+                timestamp: entry.timestamp,
+                event: to_rpc_event(entry.event),
+            })
+            .collect(),
+    }
+}
+
+/// Converts an event of a transaction in the pool to its RPC representation.
+fn to_rpc_event(event: TransactionEvent) -> TxpoolEvent {
+    match event {
+        TransactionEvent::Pending => TxpoolEvent::Pending,
+        TransactionEvent::Queued => TxpoolEvent::Queued,
+        TransactionEvent::Mined(block_hash) => TxpoolEvent::Mined(block_hash),
+        TransactionEvent::Replaced(replaced_by) => TxpoolEvent::Replaced(replaced_by),
+        TransactionEvent::Discarded => TxpoolEvent::Discarded,
+        TransactionEvent::Invalid => TxpoolEvent::Invalid,
+        TransactionEvent::Propagated(peers) => TxpoolEvent::Propagated(
+            peers
+                .iter()
+                .map(|kind| match *kind {
+                    PropagateKind::Full(peer) => TxpoolPropagateKind::Full(peer),
+                    PropagateKind::Hash(peer) => TxpoolPropagateKind::Hash(peer),
+                })
+                .collect(),
+        ),
+    }
 }
 
 impl<Pool, Eth> fmt::Debug for TxPoolApi<Pool, Eth> {
@@ -0,0 +1,487 @@
+use super::load_history_indices;
+use alloy_consensus::TxReceipt;
+use alloy_primitives::{Address, BlockNumber, B256};
+use reth_config::config::{EtlConfig, IndexHistoryConfig};
+use reth_db::{tables, BlockNumberList};
+use reth_db_api::{
+    models::ShardedKey,
+    table::{Decode, Key},
+    transaction::DbTxMut,
+};
+use reth_etl::Collector;
+use reth_provider::{
+    BlockReader, DBProvider, LogIndexWriter, PruneCheckpointReader, PruneCheckpointWriter,
+};
+use reth_prune_types::{PruneCheckpoint, PruneMode, PrunePurpose, PruneSegment};
+use reth_stages_api::{
+    ExecInput, ExecOutput, Stage, StageCheckpoint, StageError, StageId, UnwindInput, UnwindOutput,
+};
+use std::{collections::HashMap, hash::Hash, ops::RangeInclusive};
+use tracing::info;
+
+/// Number of blocks whose receipts are read at once.
+const RECEIPTS_BATCH_SIZE: u64 = 1_000;
+
+/// Number of blocks before pushing indices from cache to [`Collector`].
+const DEFAULT_CACHE_THRESHOLD: u64 = 100_000;
+
+/// Stage is indexing the logs of the receipts generated in
+/// [`ExecutionStage`][crate::stages::ExecutionStage] by their address and topics. For more
+/// information on the index take a look at [`tables::LogAddressIndex`] and
+/// [`tables::LogTopicIndex`].
+///
+/// The stage is not part of the [`DefaultStages`](crate::sets::DefaultStages), it's only added to
+/// the pipeline if the log index is enabled.
+#[derive(Debug)]
+pub struct IndexLogsStage {
+    /// Number of blocks after which the control
+    /// flow will be returned to the pipeline for commit.
+    pub commit_threshold: u64,
+    /// Pruning configuration.
+    pub prune_mode: Option<PruneMode>,
+    /// ETL configuration
+    pub etl_config: EtlConfig,
+}
+
+impl IndexLogsStage {
+    /// Create new instance of [`IndexLogsStage`].
+    pub const fn new(
+        config: IndexHistoryConfig,
+        etl_config: EtlConfig,
+        prune_mode: Option<PruneMode>,
+    ) -> Self {
+        Self { commit_threshold: config.commit_threshold, etl_config, prune_mode }
+    }
+}
+
+impl Default for IndexLogsStage {
+    fn default() -> Self {
+        Self { commit_threshold: 100_000, prune_mode: None, etl_config: EtlConfig::default() }
+    }
+}
+
+impl<Provider> Stage<Provider> for IndexLogsStage
+where
+    Provider: DBProvider<Tx: DbTxMut>
+        + BlockReader
+        + LogIndexWriter
+        + PruneCheckpointReader
+        + PruneCheckpointWriter,
+{
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::IndexLogs
+    }
+
+    /// Execute the stage.
+    fn execute(
+        &mut self,
+        provider: &Provider,
+        mut input: ExecInput,
+    ) -> Result<ExecOutput, StageError> {
+        if let Some((target_prunable_block, prune_mode)) = self
+            .prune_mode
+            .map(|mode| {
+                mode.prune_target_block(input.target(), PruneSegment::LogIndex, PrunePurpose::User)
+            })
+            .transpose()?
+            .flatten()
+        {
+            if target_prunable_block > input.checkpoint().block_number {
+                input.checkpoint = Some(StageCheckpoint::new(target_prunable_block));
+
+                // Save prune checkpoint only if we don't have one already.
+                // Otherwise, pruner may skip the unpruned range of blocks.
+                if provider.get_prune_checkpoint(PruneSegment::LogIndex)?.is_none() {
+                    provider.save_prune_checkpoint(
+                        PruneSegment::LogIndex,
+                        PruneCheckpoint {
+                            block_number: Some(target_prunable_block),
+                            tx_number: None,
+                            prune_mode,
+                        },
+                    )?;
+                }
+            }
+        }
+
+        // Logs can't be indexed for blocks whose receipts were already pruned, so we skip them and
+        // mark them as pruned.
+        if let Some(receipts_checkpoint) = provider.get_prune_checkpoint(PruneSegment::Receipts)? {
+            if let Some(pruned_block) = receipts_checkpoint
+                .block_number
+                .filter(|block_number| *block_number > input.checkpoint().block_number)
+            {
+                input.checkpoint = Some(StageCheckpoint::new(pruned_block));
+
+                let log_index_checkpoint = provider.get_prune_checkpoint(PruneSegment::LogIndex)?;
+                if log_index_checkpoint
+                    .and_then(|checkpoint| checkpoint.block_number)
+                    .is_none_or(|block_number| block_number < pruned_block)
+                {
+                    provider.save_prune_checkpoint(
+                        PruneSegment::LogIndex,
+                        PruneCheckpoint {
+                            block_number: Some(pruned_block),
+                            tx_number: None,
+                            prune_mode: log_index_checkpoint
+                                .map_or(receipts_checkpoint.prune_mode, |checkpoint| {
+                                    checkpoint.prune_mode
+                                }),
+                        },
+                    )?;
+                }
+            }
+        }
+
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
+
+        let (mut range, is_final_range) =
+            input.next_block_range_with_threshold(self.commit_threshold);
+        let end_block = *range.end();
+        let first_sync = input.checkpoint().block_number == 0;
+
+        // On first sync we might have indices coming from genesis. We clear the tables since it's
+        // faster to rebuild from scratch.
+        if first_sync {
+            provider.tx_ref().clear::<tables::LogAddressIndex>()?;
+            provider.tx_ref().clear::<tables::LogTopicIndex>()?;
+            range = 0..=end_block;
+        }
+
+        info!(target: "sync::stages::index_logs::exec", ?first_sync, ?range, "Collecting indices");
+        let (address_collector, topic_collector) =
+            collect_log_indices(provider, range, &self.etl_config)?;
+
+        info!(target: "sync::stages::index_logs::exec", "Loading address indices into database");
+        load_history_indices::<_, tables::LogAddressIndex, _>(
+            provider,
+            address_collector,
+            first_sync,
+            ShardedKey::new,
+            ShardedKey::<Address>::decode_owned,
+            |key| key.key,
+        )?;
+
+        info!(target: "sync::stages::index_logs::exec", "Loading topic indices into database");
+        load_history_indices::<_, tables::LogTopicIndex, _>(
+            provider,
+            topic_collector,
+            first_sync,
+            ShardedKey::new,
+            ShardedKey::<B256>::decode_owned,
+            |key| key.key,
+        )?;
+
+        Ok(ExecOutput { checkpoint: StageCheckpoint::new(end_block), done: is_final_range })
+    }
+
+    /// Unwind the stage.
+    fn unwind(
+        &mut self,
+        provider: &Provider,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        let (range, unwind_progress, _) =
+            input.unwind_block_range_with_threshold(self.commit_threshold);
+
+        provider.unwind_log_indices_range(range)?;
+
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_progress) })
+    }
+}
+
+/// Collects the address and topic indices of all logs emitted in the block range and stores them
+/// in two [`Collector`]s.
+///
+/// Works the same way as [`collect_history_indices`](super::collect_history_indices), except that
+/// the indices are read from the receipts instead of a changeset table.
+fn collect_log_indices<Provider>(
+    provider: &Provider,
+    range: RangeInclusive<BlockNumber>,
+    etl_config: &EtlConfig,
+) -> Result<
+    (Collector<ShardedKey<Address>, BlockNumberList>, Collector<ShardedKey<B256>, BlockNumberList>),
+    StageError,
+>
+where
+    Provider: DBProvider + BlockReader,
+{
+    let mut address_collector = Collector::new(etl_config.file_size, etl_config.dir.clone());
+    let mut topic_collector = Collector::new(etl_config.file_size, etl_config.dir.clone());
+    let mut address_cache: HashMap<Address, Vec<BlockNumber>> = HashMap::default();
+    let mut topic_cache: HashMap<B256, Vec<BlockNumber>> = HashMap::default();
+
+    // observability
+    let total_blocks = range.end() - range.start() + 1;
+    let interval = (total_blocks / 100).max(1);
+
+    let mut flush_counter = 0;
+    let mut batch_start = *range.start();
+    while batch_start <= *range.end() {
+        let batch_end = (batch_start + RECEIPTS_BATCH_SIZE - 1).min(*range.end());
+        let body_indices = provider.block_body_indices_range(batch_start..=batch_end)?;
+
+        // Receipts of all blocks in the batch are read at once and split by the transaction count
+        // of each block.
+        let mut receipts = match (body_indices.first(), body_indices.last()) {
+            (Some(first), Some(last)) if last.next_tx_num() > first.first_tx_num() => {
+                provider.receipts_by_tx_range(first.first_tx_num()..last.next_tx_num())?.into_iter()
+            }
+            _ => Vec::new().into_iter(),
+        };
+
+        for (block_number, indices) in (batch_start..=batch_end).zip(body_indices) {
+            if (block_number - range.start()) % interval == 0 && total_blocks > 100 {
+                info!(target: "sync::stages::index_logs", progress = %format!("{:.2}%", ((block_number - range.start()) as f64 / total_blocks as f64) * 100.0), "Collecting indices");
+            }
+
+            for receipt in receipts.by_ref().take(indices.tx_count() as usize) {
+                for log in receipt.logs() {
+                    insert_block(&mut address_cache, log.address, block_number);
+                    for topic in log.topics() {
+                        insert_block(&mut topic_cache, *topic, block_number);
+                    }
+                }
+            }
+
+            // Make sure we only flush the cache every DEFAULT_CACHE_THRESHOLD blocks.
+            flush_counter += 1;
+            if flush_counter > DEFAULT_CACHE_THRESHOLD {
+                flush_cache(&mut address_cache, &mut address_collector)?;
+                flush_cache(&mut topic_cache, &mut topic_collector)?;
+                flush_counter = 0;
+            }
+        }
+
+        batch_start = batch_end + 1;
+    }
+    flush_cache(&mut address_cache, &mut address_collector)?;
+    flush_cache(&mut topic_cache, &mut topic_collector)?;
+
+    Ok((address_collector, topic_collector))
+}
+
+/// Adds the block to the indices of the key, unless it was already added.
+fn insert_block<K: Eq + Hash>(
+    cache: &mut HashMap<K, Vec<BlockNumber>>,
+    key: K,
+    block_number: BlockNumber,
+) {
+    let blocks = cache.entry(key).or_default();
+    if blocks.last() != Some(&block_number) {
+        blocks.push(block_number);
+    }
+}
+
+/// Moves the contents of the cache to the collector, keyed by the highest block number of each
+/// entry.
+fn flush_cache<K>(
+    cache: &mut HashMap<K, Vec<BlockNumber>>,
+    collector: &mut Collector<ShardedKey<K>, BlockNumberList>,
+) -> Result<(), StageError>
+where
+    ShardedKey<K>: Key,
+{
+    for (key, indices) in cache.drain() {
+        let last = indices.last().expect("qed");
+        collector.insert(ShardedKey::new(key, *last), BlockNumberList::new_pre_sorted(indices))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestStageDB;
+    use alloy_primitives::{address, b256, Log};
+    use reth_db_api::models::StoredBlockBodyIndices;
+    use reth_primitives::Receipt;
+    use reth_provider::DatabaseProviderFactory;
+    use std::collections::BTreeMap;
+
+    const ADDRESS: Address = address!("0000000000000000000000000000000000000001");
+    const TOPIC: B256 = b256!("0000000000000000000000000000000000000000000000000000000000000002");
+    const MAX_BLOCK: BlockNumber = 10;
+
+    const fn address_shard(shard_index: u64) -> ShardedKey<Address> {
+        ShardedKey { key: ADDRESS, highest_block_number: shard_index }
+    }
+
+    const fn topic_shard(shard_index: u64) -> ShardedKey<B256> {
+        ShardedKey { key: TOPIC, highest_block_number: shard_index }
+    }
+
+    fn list(list: &[u64]) -> BlockNumberList {
+        BlockNumberList::new(list.iter().copied()).unwrap()
+    }
+
+    fn cast<K: Ord>(
+        table: Vec<(ShardedKey<K>, BlockNumberList)>,
+    ) -> BTreeMap<ShardedKey<K>, Vec<u64>> {
+        table
+            .into_iter()
+            .map(|(k, v)| {
+                let v = v.iter().collect();
+                (k, v)
+            })
+            .collect()
+    }
+
+    /// Every block has two transactions. Both receipts of even blocks contain a log emitted by
+    /// [`ADDRESS`] with [`TOPIC`], receipts of odd blocks contain no logs.
+    fn partial_setup(db: &TestStageDB) {
+        db.commit(|tx| {
+            for block in 0..=MAX_BLOCK {
+                tx.put::<tables::BlockBodyIndices>(
+                    block,
+                    StoredBlockBodyIndices { first_tx_num: block * 2, tx_count: 2 },
+                )?;
+                for tx_num in block * 2..block * 2 + 2 {
+                    let logs = if block % 2 == 0 {
+                        vec![Log::new_unchecked(ADDRESS, vec![TOPIC], Default::default())]
+                    } else {
+                        vec![]
+                    };
+                    tx.put::<tables::Receipts>(
+                        tx_num,
+                        Receipt { success: true, logs, ..Default::default() },
+                    )?;
+                }
+            }
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    fn run(
+        db: &TestStageDB,
+        stage: &mut IndexLogsStage,
+        run_to: u64,
+        input_checkpoint: Option<BlockNumber>,
+    ) {
+        let input = ExecInput {
+            target: Some(run_to),
+            checkpoint: input_checkpoint
+                .map(|block_number| StageCheckpoint { block_number, stage_checkpoint: None }),
+        };
+        let provider = db.factory.database_provider_rw().unwrap();
+        let out = stage.execute(&provider, input).unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(run_to), done: true });
+        provider.commit().unwrap();
+    }
+
+    fn unwind(db: &TestStageDB, unwind_from: u64, unwind_to: u64) {
+        let input = UnwindInput {
+            checkpoint: StageCheckpoint::new(unwind_from),
+            unwind_to,
+            ..Default::default()
+        };
+        let mut stage = IndexLogsStage::default();
+        let provider = db.factory.database_provider_rw().unwrap();
+        let out = stage.unwind(&provider, input).unwrap();
+        assert_eq!(out, UnwindOutput { checkpoint: StageCheckpoint::new(unwind_to) });
+        provider.commit().unwrap();
+    }
+
+    #[tokio::test]
+    async fn insert_index_to_genesis() {
+        // init
+        let db = TestStageDB::default();
+
+        // setup
+        partial_setup(&db);
+
+        // run
+        run(&db, &mut IndexLogsStage::default(), 5, None);
+
+        // verify
+        let table = cast(db.table::<tables::LogAddressIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(address_shard(u64::MAX), vec![0, 2, 4])]));
+        let table = cast(db.table::<tables::LogTopicIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(topic_shard(u64::MAX), vec![0, 2, 4])]));
+
+        // unwind
+        unwind(&db, 5, 2);
+
+        // verify
+        let table = cast(db.table::<tables::LogAddressIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(address_shard(u64::MAX), vec![0, 2])]));
+        let table = cast(db.table::<tables::LogTopicIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(topic_shard(u64::MAX), vec![0, 2])]));
+
+        // unwind
+        unwind(&db, 2, 0);
+
+        // verify initial state
+        let table = cast(db.table::<tables::LogAddressIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(address_shard(u64::MAX), vec![0])]));
+        let table = cast(db.table::<tables::LogTopicIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(topic_shard(u64::MAX), vec![0])]));
+    }
+
+    #[tokio::test]
+    async fn insert_index_to_not_empty_shard() {
+        // init
+        let db = TestStageDB::default();
+
+        // setup
+        partial_setup(&db);
+        db.commit(|tx| {
+            tx.put::<tables::LogAddressIndex>(address_shard(u64::MAX), list(&[0, 2, 4])).unwrap();
+            tx.put::<tables::LogTopicIndex>(topic_shard(u64::MAX), list(&[0, 2, 4])).unwrap();
+            Ok(())
+        })
+        .unwrap();
+
+        // run
+        run(&db, &mut IndexLogsStage::default(), MAX_BLOCK, Some(5));
+
+        // verify
+        let table = cast(db.table::<tables::LogAddressIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(address_shard(u64::MAX), vec![0, 2, 4, 6, 8, 10])]));
+        let table = cast(db.table::<tables::LogTopicIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(topic_shard(u64::MAX), vec![0, 2, 4, 6, 8, 10])]));
+
+        // unwind
+        unwind(&db, MAX_BLOCK, 5);
+
+        // verify initial state
+        let table = cast(db.table::<tables::LogAddressIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(address_shard(u64::MAX), vec![0, 2, 4])]));
+        let table = cast(db.table::<tables::LogTopicIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(topic_shard(u64::MAX), vec![0, 2, 4])]));
+    }
+
+    #[tokio::test]
+    async fn skip_pruned_blocks() {
+        // init
+        let db = TestStageDB::default();
+
+        // setup
+        partial_setup(&db);
+
+        // run
+        let mut stage =
+            IndexLogsStage { prune_mode: Some(PruneMode::Before(5)), ..IndexLogsStage::default() };
+        run(&db, &mut stage, MAX_BLOCK, None);
+
+        // verify
+        let table = cast(db.table::<tables::LogAddressIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(address_shard(u64::MAX), vec![6, 8, 10])]));
+        let table = cast(db.table::<tables::LogTopicIndex>().unwrap());
+        assert_eq!(table, BTreeMap::from([(topic_shard(u64::MAX), vec![6, 8, 10])]));
+
+        let provider = db.factory.provider().unwrap();
+        assert_eq!(
+            provider.get_prune_checkpoint(PruneSegment::LogIndex).unwrap(),
+            Some(PruneCheckpoint {
+                block_number: Some(4),
+                tx_number: None,
+                prune_mode: PruneMode::Before(5)
+            })
+        );
+    }
+}
@@ -1,5 +1,7 @@
-use alloy_primitives::B256;
+use alloy_primitives::{BlockNumber, B256};
 use reth_fs_util::FsPathError;
+use reth_primitives::StaticFileSegment;
+use reth_stages_api::StageError;
 
 /// Possible downloader error variants.
 #[derive(Debug, thiserror::Error)]
@@ -16,6 +18,28 @@ pub enum DownloaderError {
     // Empty content length returned from the server.
     #[error("metadata got an empty content length from server")]
     EmptyContentLength,
+    /// The downloaded static files of a block range don't continue the local chain or are
+    /// inconsistent between each other.
+    #[error("invalid static files for blocks {start}..={end}: {reason}")]
+    InvalidStaticFiles {
+        /// First block of the range.
+        start: BlockNumber,
+        /// Last block of the range.
+        end: BlockNumber,
+        /// Why the static files are invalid.
+        reason: String,
+    },
+    /// The local node already has static files of the segment in the block range, which the
+    /// downloaded ones would replace.
+    #[error("{segment} static files already exist for blocks {start}..={end}")]
+    ExistingStaticFiles {
+        /// The segment with existing static files.
+        segment: StaticFileSegment,
+        /// First block of the range.
+        start: BlockNumber,
+        /// Last block of the range.
+        end: BlockNumber,
+    },
     /// Reqwest error
     #[error(transparent)]
     FsPath(#[from] FsPathError),
@@ -28,4 +52,20 @@ pub enum DownloaderError {
     /// Bincode error
     #[error(transparent)]
     Bincode(#[from] bincode::Error),
+    /// Json error
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+impl From<DownloaderError> for StageError {
+    fn from(err: DownloaderError) -> Self {
+        match err {
+            // The remote server serves invalid data, or the local static files need to be removed
+            // first, so retrying won't help.
+            DownloaderError::InvalidFileHash(..) |
+            DownloaderError::InvalidStaticFiles { .. } |
+            DownloaderError::ExistingStaticFiles { .. } => Self::Fatal(Box::new(err)),
+            err => Self::Recoverable(Box::new(err)),
+        }
+    }
 }
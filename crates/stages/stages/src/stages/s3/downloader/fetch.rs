@@ -1,4 +1,7 @@
-use crate::stages::s3::downloader::{worker::spawn_workers, RemainingChunkRange};
+use crate::stages::s3::{
+    downloader::{worker::spawn_workers, RemainingChunkRange},
+    filelist::DownloadFileList,
+};
 
 use super::{
     error::DownloaderError,
@@ -46,15 +49,13 @@ pub async fn fetch(
 
     let data_file = download_dir.join(filename);
     let mut metadata = metadata(&data_file, url).await?;
-    if metadata.is_done() {
-        return Ok(())
-    }
 
-    // Ensure the file is preallocated so we can download it concurrently
+    // Ensure the file is preallocated so we can download it concurrently. It must not be
+    // truncated, since it may hold the chunks of a previously interrupted download.
     {
         let file = OpenOptions::new()
             .create(true)
-            .truncate(true)
+            .truncate(false)
             .read(true)
             .write(true)
             .open(&data_file)?;
@@ -114,7 +115,12 @@ pub async fn fetch(
 
     if let Some(file_hash) = file_hash {
         info!(target: "sync::stages::s3::downloader", ?filename, "Checking file integrity.");
-        check_file_hash(&data_file, &file_hash)?;
+        if let Err(err) = check_file_hash(&data_file, &file_hash) {
+            // Start over on the next attempt, instead of failing on the same file again.
+            metadata.delete()?;
+            reth_fs_util::remove_file(&data_file)?;
+            return Err(err)
+        }
     }
 
     // No longer need the metadata file.
@@ -150,8 +156,14 @@ async fn metadata(data_file: &Path, url: &str) -> Result<Metadata, DownloaderErr
     Metadata::builder(data_file).with_total_size(total_length).build()
 }
 
+/// Downloads and parses the list of files to be downloaded from url.
+pub(crate) async fn fetch_file_list(url: &str) -> Result<DownloadFileList, DownloaderError> {
+    let response = Client::new().get(url).send().await?.error_for_status()?;
+    Ok(serde_json::from_slice(&response.bytes().await?)?)
+}
+
 /// Ensures the file on path has the expected blake3 hash.
-fn check_file_hash(path: &Path, expected: &B256) -> Result<(), DownloaderError> {
+pub(crate) fn check_file_hash(path: &Path, expected: &B256) -> Result<(), DownloaderError> {
     let mut reader = BufReader::new(File::open(path)?);
     let mut hasher = blake3::Hasher::new();
     std::io::copy(&mut reader, &mut hasher)?;
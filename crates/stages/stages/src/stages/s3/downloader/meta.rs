@@ -144,7 +144,8 @@ impl MetadataBuilder {
     /// Returns a [Metadata] if
     pub fn build(&self) -> Result<Metadata, DownloaderError> {
         match &self.total_size {
-            Some(total_size) if *total_size > 0 => {
+            // An empty file has no chunks, and is done right away.
+            Some(total_size) => {
                 let chunks = (0..*total_size)
                     .step_by(self.chunk_size)
                     .map(|start| {
@@ -6,26 +6,28 @@ mod fetch;
 mod meta;
 mod worker;
 
+use reth_primitives::static_file::SegmentRangeInclusive;
+
 pub(crate) use error::DownloaderError;
 pub use fetch::fetch;
+pub(crate) use fetch::{check_file_hash, fetch_file_list};
 pub use meta::Metadata;
 
 /// Response sent by the fetch task to `S3Stage` once it has downloaded all files of a block
 /// range.
+#[derive(Debug)]
 pub(crate) enum S3DownloaderResponse {
-    /// A new block range was downloaded.
-    AddedNewRange,
-    /// The last requested block range was downloaded.
+    /// All files of a new block range were downloaded.
+    AddedNewRange {
+        /// The downloaded block range.
+        block_range: SegmentRangeInclusive,
+        /// The names of the downloaded files.
+        files: Vec<String>,
+    },
+    /// All requested block ranges were downloaded.
     Done,
 }
 
-impl S3DownloaderResponse {
-    /// Whether the downloaded block range is the last requested one.
-    pub(crate) const fn is_done(&self) -> bool {
-        matches!(self, Self::Done)
-    }
-}
-
 /// Chunk nth remaining range to be downloaded.
 #[derive(Debug)]
 pub struct RemainingChunkRange {
@@ -1,21 +1,76 @@
 use alloy_primitives::B256;
+use reth_primitives::{static_file::SegmentRangeInclusive, StaticFileSegment};
+use serde::{Deserialize, Serialize};
 
-/// File list to be downloaded with their hashes.
-pub(crate) static DOWNLOAD_FILE_LIST: [[(&str, B256); 3]; 2] = [
-    [
-        ("static_file_transactions_0_499999", B256::ZERO),
-        ("static_file_transactions_0_499999.off", B256::ZERO),
-        ("static_file_transactions_0_499999.conf", B256::ZERO),
-        // ("static_file_blockmeta_0_499999", B256::ZERO),
-        // ("static_file_blockmeta_0_499999.off", B256::ZERO),
-        // ("static_file_blockmeta_0_499999.conf", B256::ZERO),
-    ],
-    [
-        ("static_file_transactions_500000_999999", B256::ZERO),
-        ("static_file_transactions_500000_999999.off", B256::ZERO),
-        ("static_file_transactions_500000_999999.conf", B256::ZERO),
-        // ("static_file_blockmeta_500000_999999", B256::ZERO),
-        // ("static_file_blockmeta_500000_999999.off", B256::ZERO),
-        // ("static_file_blockmeta_500000_999999.conf", B256::ZERO),
-    ],
-];
+/// Name of the file served next to the static files, which lists them with their hashes.
+pub(crate) const DOWNLOAD_FILE_LIST_NAME: &str = "filelist.json";
+
+/// The segments that are downloaded by the S3 stage.
+///
+/// Receipts are not downloaded, since the execution stage and the consistency check on startup
+/// prune receipts past the execution checkpoint before the blocks are executed.
+pub(crate) const DOWNLOAD_SEGMENTS: [StaticFileSegment; 3] =
+    [StaticFileSegment::Headers, StaticFileSegment::BlockMeta, StaticFileSegment::Transactions];
+
+/// A static file to be downloaded with its blake3 hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadFile {
+    /// The name of the file, e.g. `static_file_headers_0_499999.off`.
+    pub name: String,
+    /// The blake3 hash of the file.
+    pub hash: B256,
+}
+
+impl DownloadFile {
+    /// Returns the segment and block range of the static file, if it's a static file.
+    fn segment_and_range(&self) -> Option<(StaticFileSegment, SegmentRangeInclusive)> {
+        // Strip the extension of the offsets and configuration files of the static file
+        StaticFileSegment::parse_filename(self.name.split('.').next()?)
+    }
+}
+
+/// List of files to be downloaded with their hashes, grouped by block range.
+///
+/// This is served as JSON by the remote server in [`DOWNLOAD_FILE_LIST_NAME`].
+pub type DownloadFileList = Vec<Vec<DownloadFile>>;
+
+/// Returns the block ranges of the list that continue the chain from `next_block` and end at or
+/// below `target`, in ascending order, along with the files of the downloaded segments.
+///
+/// Block ranges that end before `next_block` are skipped, and the first block range that doesn't
+/// start at the expected block stops the list.
+pub(crate) fn block_ranges_to_download(
+    list: DownloadFileList,
+    mut next_block: u64,
+    target: u64,
+) -> Vec<(SegmentRangeInclusive, Vec<DownloadFile>)> {
+    let mut ranges = list
+        .into_iter()
+        .filter_map(|files| {
+            let files = files
+                .into_iter()
+                .filter(|file| {
+                    file.segment_and_range()
+                        .is_some_and(|(segment, _)| DOWNLOAD_SEGMENTS.contains(&segment))
+                })
+                .collect::<Vec<_>>();
+            let (_, block_range) = files.first()?.segment_and_range()?;
+            Some((block_range, files))
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_unstable_by_key(|(block_range, _)| block_range.start());
+
+    let mut requests = Vec::new();
+    for (block_range, files) in ranges {
+        if block_range.end() < next_block {
+            continue
+        }
+        if block_range.start() != next_block || block_range.end() > target {
+            break
+        }
+
+        next_block = block_range.end() + 1;
+        requests.push((block_range, files));
+    }
+    requests
+}
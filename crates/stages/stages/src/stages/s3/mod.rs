@@ -1,29 +1,74 @@
 mod downloader;
+use downloader::{check_file_hash, fetch_file_list, DownloaderError, S3DownloaderResponse};
 pub use downloader::{fetch, Metadata};
-use downloader::{DownloaderError, S3DownloaderResponse};
 
 mod filelist;
-use filelist::DOWNLOAD_FILE_LIST;
-
-use reth_db::transaction::DbTxMut;
-use reth_primitives::StaticFileSegment;
+use filelist::{block_ranges_to_download, DOWNLOAD_FILE_LIST_NAME, DOWNLOAD_SEGMENTS};
+pub use filelist::{DownloadFile, DownloadFileList};
+
+use alloy_consensus::BlockHeader;
+use alloy_primitives::{BlockNumber, TxNumber, B256};
+use reth_db::{
+    static_file::{BodyIndicesMask, HeaderWithHashMask, TransactionMask},
+    table::Value,
+    tables,
+    transaction::DbTx,
+};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbCursorRW},
+    transaction::DbTxMut,
+};
+use reth_primitives::{
+    static_file::SegmentRangeInclusive, NodePrimitives, SealedHeader, StaticFileSegment,
+};
+use reth_primitives_traits::proofs::calculate_transaction_root;
 use reth_provider::{
-    DBProvider, StageCheckpointReader, StageCheckpointWriter, StaticFileProviderFactory,
+    providers::{StaticFileJarProvider, StaticFileProvider},
+    BlockHashReader, DBProvider, ProviderError, StageCheckpointReader, StageCheckpointWriter,
+    StaticFileProviderFactory,
 };
 use reth_stages_api::{
     ExecInput, ExecOutput, Stage, StageCheckpoint, StageError, StageId, UnwindInput, UnwindOutput,
 };
 use std::{
-    path::PathBuf,
+    path::{Path, PathBuf},
     task::{ready, Context, Poll},
 };
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+use tracing::*;
 
 /// S3 `StageId`
 const S3_STAGE_ID: StageId = StageId::Other("S3");
 
-/// The S3 stage.
-#[derive(Default, Debug)]
+/// Name of the directory inside the static files directory, where the files are downloaded to
+/// before being imported.
+const STAGING_DIRECTORY_NAME: &str = "s3";
+
+/// The S3 stage downloads the headers, block meta and transactions static files from a remote
+/// server, and imports them as if they were synced by the [`HeaderStage`](super::HeaderStage) and
+/// [`BodyStage`](super::BodyStage).
+///
+/// The server lists its static files in [`DOWNLOAD_FILE_LIST_NAME`] as a [`DownloadFileList`].
+/// The stage only runs while the headers and bodies stages are at the same block, and downloads
+/// the block ranges that continue the local chain from there. Receipts static files are not
+/// downloaded, the [`ExecutionStage`](super::ExecutionStage) writes the receipts as it executes
+/// the blocks.
+///
+/// Files are downloaded to `{static_files}/s3`, and a block range is only imported once all of
+/// its files were downloaded and their hashes verified. Before importing, the headers are checked
+/// to link to the local chain and the transactions to match the transaction roots of the
+/// headers. Block ranges are never
+/// imported over local static files, except for the genesis block, which needs to match. An
+/// interrupted download resumes where it left off.
+///
+/// Once the remote server has no more block ranges for the local chain, the stage does nothing
+/// until the node is restarted.
+///
+/// # Tables
+///
+/// The headers are indexed in [`HeaderNumbers`](tables::HeaderNumbers), and the transactions in
+/// [`TransactionBlocks`](tables::TransactionBlocks).
+#[derive(Debug)]
 #[non_exhaustive]
 pub struct S3Stage {
     /// Static file directory.
@@ -32,16 +77,49 @@ pub struct S3Stage {
     url: String,
     /// Maximum number of connections per download.
     max_concurrent_requests: u64,
+    /// The first block of the next block range to download, set once the stage is running.
+    next_block: Option<BlockNumber>,
+    /// A downloaded block range waiting to be imported, with the names of its files.
+    downloaded: Option<(SegmentRangeInclusive, Vec<String>)>,
+    /// Whether the remote server has no more block ranges to download.
+    is_done: bool,
     /// Channel to receive the downloaded ranges from the fetch task.
     fetch_rx: Option<UnboundedReceiver<Result<S3DownloaderResponse, DownloaderError>>>,
 }
 
+impl S3Stage {
+    /// Creates a new [`S3Stage`] that downloads into the given static files directory from the
+    /// remote server at `url`.
+    pub fn new(
+        static_file_directory: PathBuf,
+        url: impl Into<String>,
+        max_concurrent_requests: u64,
+    ) -> Self {
+        Self {
+            static_file_directory,
+            url: url.into().trim_end_matches('/').to_string(),
+            max_concurrent_requests,
+            next_block: None,
+            downloaded: None,
+            is_done: false,
+            fetch_rx: None,
+        }
+    }
+
+    /// Returns the directory where the files are downloaded to before being imported.
+    fn staging_directory(&self) -> PathBuf {
+        self.static_file_directory.join(STAGING_DIRECTORY_NAME)
+    }
+}
+
 impl<Provider> Stage<Provider> for S3Stage
 where
     Provider: DBProvider<Tx: DbTxMut>
         + StaticFileProviderFactory
         + StageCheckpointReader
         + StageCheckpointWriter,
+    <Provider::Primitives as NodePrimitives>::BlockHeader: Value,
+    <Provider::Primitives as NodePrimitives>::SignedTx: Value,
 {
     fn id(&self) -> StageId {
         S3_STAGE_ID
@@ -52,68 +130,85 @@ where
         cx: &mut Context<'_>,
         input: ExecInput,
     ) -> Poll<Result<(), StageError>> {
-        loop {
-            // We are currently fetching and may have downloaded ranges that we can process.
-            if let Some(rx) = &mut self.fetch_rx {
-                // Whether we have downloaded all the required files.
-                let mut is_done = false;
-
-                let response = match ready!(rx.poll_recv(cx)) {
-                    Some(Ok(response)) => {
-                        is_done = response.is_done();
-                        Ok(())
-                    }
-                    Some(Err(_)) => todo!(), // TODO: DownloaderError -> StageError
-                    None => Err(StageError::ChannelClosed),
-                };
+        if self.downloaded.is_some() || self.is_done {
+            return Poll::Ready(Ok(()))
+        }
 
-                if is_done {
-                    self.fetch_rx = None;
-                }
+        // The stage decides whether it runs on its first execution.
+        let Some(next_block) = self.next_block else { return Poll::Ready(Ok(())) };
+
+        // The S3 stage is the first one in the pipeline, so it has no target unless there is a
+        // maximum block.
+        let target = input.target.unwrap_or(u64::MAX);
+        let rx = self.fetch_rx.get_or_insert_with(|| {
+            spawn_fetch(
+                self.staging_directory(),
+                self.url.clone(),
+                self.max_concurrent_requests,
+                next_block,
+                target,
+            )
+        });
 
-                return Poll::Ready(response)
+        let response = match ready!(rx.poll_recv(cx)) {
+            Some(Ok(S3DownloaderResponse::AddedNewRange { block_range, files })) => {
+                self.downloaded = Some((block_range, files));
+                Ok(())
             }
-
-            // Spawns the downloader task if there are any missing files
-            if let Some(fetch_rx) = self.maybe_spawn_fetch(input) {
-                self.fetch_rx = Some(fetch_rx);
-
-                // Polls fetch_rx & registers waker
-                continue
+            Some(Ok(S3DownloaderResponse::Done)) => {
+                self.is_done = true;
+                self.fetch_rx = None;
+                Ok(())
             }
+            Some(Err(err)) => {
+                // The next attempt spawns a new fetch task, which resumes the downloads.
+                self.fetch_rx = None;
+                Err(err.into())
+            }
+            None => {
+                self.fetch_rx = None;
+                Err(StageError::ChannelClosed)
+            }
+        };
 
-            // No files to be downloaded
-            return Poll::Ready(Ok(()))
-        }
+        Poll::Ready(response)
     }
 
-    fn execute(&mut self, provider: &Provider, input: ExecInput) -> Result<ExecOutput, StageError>
-    where
-        Provider: DBProvider<Tx: DbTxMut>
-            + StaticFileProviderFactory
-            + StageCheckpointReader
-            + StageCheckpointWriter,
-    {
-        // Re-initializes the provider to detect the new additions
-        provider.static_file_provider().initialize_index()?;
-
-        // TODO logic for appending tx_block
+    fn execute(&mut self, provider: &Provider, input: ExecInput) -> Result<ExecOutput, StageError> {
+        if self.is_done {
+            self.next_block = None;
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
 
-        // let (_, _to_block) = input.next_block_range().into_inner();
-        // let static_file_provider = provider.static_file_provider();
-        // let mut _tx_block_cursor =
-        // provider.tx_ref().cursor_write::<tables::TransactionBlocks>()?;
+        if let Some((block_range, files)) = self.downloaded.take() {
+            if let Err(err) = self.import_block_range(provider, block_range, &files) {
+                // The next block ranges need to be fetched again after this one.
+                self.fetch_rx = None;
+                return Err(err)
+            }
+            self.next_block = Some(block_range.end() + 1);
 
-        // tx_block_cursor.append(indice.last_tx_num(), &block_number)?;
+            return Ok(ExecOutput {
+                checkpoint: StageCheckpoint::new(block_range.end()),
+                done: false,
+            })
+        }
 
-        // let checkpoint = StageCheckpoint { block_number: highest_block, stage_checkpoint: None };
-        // provider.save_stage_checkpoint(StageId::Bodies, checkpoint)?;
-        // provider.save_stage_checkpoint(S3_STAGE_ID, checkpoint)?;
+        // First execution: static files can only be imported on top of a chain with matching
+        // headers and bodies.
+        let headers = provider.get_stage_checkpoint(StageId::Headers)?.unwrap_or_default();
+        let bodies = provider.get_stage_checkpoint(StageId::Bodies)?.unwrap_or_default();
+        if headers.block_number != bodies.block_number {
+            debug!(target: "sync::stages::s3", ?headers, ?bodies, "Headers and bodies are not in sync, skipping");
+            self.is_done = true;
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
 
-        // // TODO: verify input.target according to s3 stage specifications
-        // let done = highest_block == to_block;
+        // The genesis block is part of the first block range.
+        let next_block = if headers.block_number == 0 { 0 } else { headers.block_number + 1 };
+        self.next_block = Some(next_block);
 
-        Ok(ExecOutput { checkpoint: StageCheckpoint::new(input.target()), done: true })
+        Ok(ExecOutput { checkpoint: input.checkpoint(), done: false })
     }
 
     fn unwind(
@@ -121,174 +216,688 @@ where
         _provider: &Provider,
         input: UnwindInput,
     ) -> Result<UnwindOutput, StageError> {
-        // TODO
+        // The imported data is unwound by the headers and bodies stages.
         Ok(UnwindOutput { checkpoint: StageCheckpoint::new(input.unwind_to) })
     }
 }
 
 impl S3Stage {
-    /// It will only spawn a task to fetch files from the remote server, it there are any missing
-    /// static files.
+    /// Verifies the downloaded static files of the block range, moves them into the static files
+    /// directory and indexes them in the database.
     ///
-    /// Every time a block range is ready with all the necessary files, it sends a
-    /// [`S3DownloaderResponse`] to `self.fetch_rx`. If it's the last requested block range, the
-    /// response will have `is_done` set to true.
-    fn maybe_spawn_fetch(
+    /// The checkpoints of the headers and bodies stages are moved to the end of the block range.
+    fn import_block_range<Provider>(
         &self,
-        input: ExecInput,
-    ) -> Option<UnboundedReceiver<Result<S3DownloaderResponse, DownloaderError>>> {
-        let checkpoint = input.checkpoint();
-        // TODO: input target can only be certain numbers. eg. 499_999 , 999_999 etc.
-
-        // Create a list of all the missing files per block range that need to be downloaded.
-        let mut requests = vec![];
-        for block_range_files in &DOWNLOAD_FILE_LIST {
-            let (_, block_range) =
-                StaticFileSegment::parse_filename(block_range_files[0].0).expect("qed");
-
-            if block_range.end() <= checkpoint.block_number {
-                continue
+        provider: &Provider,
+        block_range: SegmentRangeInclusive,
+        files: &[String],
+    ) -> Result<(), StageError>
+    where
+        Provider: DBProvider<Tx: DbTxMut> + StaticFileProviderFactory + StageCheckpointWriter,
+        <Provider::Primitives as NodePrimitives>::BlockHeader: Value,
+        <Provider::Primitives as NodePrimitives>::SignedTx: Value,
+    {
+        let staging_directory = self.staging_directory();
+        let static_file_provider = provider.static_file_provider();
+
+        info!(target: "sync::stages::s3", ?block_range, "Importing static files");
+
+        // The downloaded static files replace the local ones with the same block range, which is
+        // only fine for the genesis block that is verified to match below.
+        for segment in DOWNLOAD_SEGMENTS {
+            if let Some(highest) = static_file_provider.get_highest_static_file_block(segment) {
+                if highest >= block_range.start() && !(block_range.start() == 0 && highest == 0) {
+                    return Err(DownloaderError::ExistingStaticFiles {
+                        segment,
+                        start: block_range.start(),
+                        end: highest,
+                    }
+                    .into())
+                }
             }
+        }
 
-            let mut block_range_requests = vec![];
-            for (filename, file_hash) in block_range_files {
-                // If the file already exists, then we are resuming a previously interrupted stage
-                // run.
-                if self.static_file_directory.join(filename).exists() {
-                    // TODO: check hash if the file already exists
-                    continue
+        // Headers need to link to the local chain, and bodies to continue its transactions. The
+        // first block range starts with the genesis block, which needs to match the local one.
+        let parent_hash =
+            static_file_provider.block_hash(block_range.start().saturating_sub(1))?.ok_or(
+                ProviderError::HeaderNotFound(block_range.start().saturating_sub(1).into()),
+            )?;
+        let next_tx_num = provider
+            .tx_ref()
+            .cursor_read::<tables::TransactionBlocks>()?
+            .last()?
+            .map(|(id, _)| id + 1)
+            .unwrap_or_default();
+
+        let (hash_to_number, tx_blocks) = match verify_block_range::<Provider::Primitives>(
+            &staging_directory,
+            block_range,
+            parent_hash,
+            next_tx_num,
+        ) {
+            Ok(indices) => indices,
+            Err(err) => {
+                // Download them again on the next run.
+                for file in files {
+                    reth_fs_util::remove_file(staging_directory.join(file))
+                        .map_err(DownloaderError::from)?;
                 }
-
-                block_range_requests.push((filename, file_hash));
+                return Err(err.into())
             }
+        };
 
-            requests.push((block_range, block_range_requests));
+        // The writers of the segments hold the static files that are about to be replaced.
+        for segment in DOWNLOAD_SEGMENTS {
+            static_file_provider.reset_writer(segment)?;
         }
 
-        // Return None, if we have downloaded all the files that are required.
-        if requests.is_empty() {
-            return None
+        for file in files {
+            reth_fs_util::rename(
+                staging_directory.join(file),
+                self.static_file_directory.join(file),
+            )
+            .map_err(DownloaderError::from)?;
         }
 
-        let static_file_directory = self.static_file_directory.clone();
-        let url = self.url.clone();
-        let max_concurrent_requests = self.max_concurrent_requests;
+        // Re-initializes the provider to detect the new additions
+        static_file_provider.initialize_index()?;
 
-        let (fetch_tx, fetch_rx) = unbounded_channel();
-        tokio::spawn(async move {
-            let mut requests_iter = requests.into_iter().peekable();
-
-            while let Some((_, file_requests)) = requests_iter.next() {
-                for (filename, file_hash) in file_requests {
-                    if let Err(err) = fetch(
-                        filename,
-                        &static_file_directory,
-                        &format!("{}/{filename}", url),
-                        max_concurrent_requests,
-                        Some(*file_hash),
-                    )
-                    .await
-                    {
-                        let _ = fetch_tx.send(Err(err));
-                        return
-                    }
+        let mut header_numbers = provider.tx_ref().cursor_write::<tables::HeaderNumbers>()?;
+        for (hash, number) in hash_to_number {
+            header_numbers.upsert(hash, &number)?;
+        }
+
+        let mut transaction_blocks =
+            provider.tx_ref().cursor_write::<tables::TransactionBlocks>()?;
+        for (last_tx_num, block_number) in tx_blocks {
+            transaction_blocks.append(last_tx_num, &block_number)?;
+        }
+
+        let checkpoint = StageCheckpoint::new(block_range.end());
+        provider.save_stage_checkpoint(StageId::Headers, checkpoint)?;
+        provider.save_stage_checkpoint(StageId::Bodies, checkpoint)?;
+
+        Ok(())
+    }
+}
+
+/// Spawns a task which downloads the block ranges that continue the chain from `next_block`.
+///
+/// Every time all files of a block range are downloaded, it sends a
+/// [`S3DownloaderResponse::AddedNewRange`], and a [`S3DownloaderResponse::Done`] once there are
+/// no more block ranges.
+fn spawn_fetch(
+    staging_directory: PathBuf,
+    url: String,
+    max_concurrent_requests: u64,
+    next_block: BlockNumber,
+    target: BlockNumber,
+) -> UnboundedReceiver<Result<S3DownloaderResponse, DownloaderError>> {
+    let (fetch_tx, fetch_rx) = unbounded_channel();
+    tokio::spawn(async move {
+        let list = match fetch_file_list(&format!("{url}/{DOWNLOAD_FILE_LIST_NAME}")).await {
+            Ok(list) => list,
+            Err(err) => {
+                let _ = fetch_tx.send(Err(err));
+                return
+            }
+        };
+
+        for (block_range, mut files) in block_ranges_to_download(list, next_block, target) {
+            // Data files are named after the static file, and are downloaded after their
+            // configuration and offsets, so a static file in the staging directory is complete.
+            files.sort_by_key(|file| !file.name.contains('.'));
+
+            for file in &files {
+                if let Err(err) =
+                    fetch_file(file, &staging_directory, &url, max_concurrent_requests).await
+                {
+                    let _ = fetch_tx.send(Err(err));
+                    return
                 }
+            }
+
+            let files = files.into_iter().map(|file| file.name).collect();
+            if fetch_tx
+                .send(Ok(S3DownloaderResponse::AddedNewRange { block_range, files }))
+                .is_err()
+            {
+                return
+            }
+        }
 
-                let response = if requests_iter.peek().is_none() {
-                    S3DownloaderResponse::Done
-                } else {
-                    S3DownloaderResponse::AddedNewRange
-                };
+        let _ = fetch_tx.send(Ok(S3DownloaderResponse::Done));
+    });
 
-                let _ = fetch_tx.send(Ok(response));
+    fetch_rx
+}
+
+/// Downloads a file to the staging directory, unless it was already downloaded by a previously
+/// interrupted run.
+async fn fetch_file(
+    file: &DownloadFile,
+    staging_directory: &Path,
+    url: &str,
+    max_concurrent_requests: u64,
+) -> Result<(), DownloaderError> {
+    let path = staging_directory.join(&file.name);
+    if path.exists() {
+        if check_file_hash(&path, &file.hash).is_ok() {
+            return Ok(())
+        }
+        reth_fs_util::remove_file(&path)?;
+    }
+
+    fetch(
+        &file.name,
+        staging_directory,
+        &format!("{url}/{}", file.name),
+        max_concurrent_requests,
+        Some(file.hash),
+    )
+    .await
+}
+
+/// Verifies that the staged static files of the block range continue the local chain, whose last
+/// block has the given hash, or which is the genesis block for the first block range.
+///
+/// Returns the `(hash, number)` pairs of the headers, sorted by hash, and the `(last tx number,
+/// block number)` pairs of the blocks with transactions.
+#[allow(clippy::type_complexity)]
+fn verify_block_range<N>(
+    staging_directory: &Path,
+    block_range: SegmentRangeInclusive,
+    mut parent_hash: B256,
+    mut next_tx_num: TxNumber,
+) -> Result<(Vec<(B256, BlockNumber)>, Vec<(TxNumber, BlockNumber)>), DownloaderError>
+where
+    N: NodePrimitives<BlockHeader: Value, SignedTx: Value>,
+{
+    let invalid = |reason: String| DownloaderError::InvalidStaticFiles {
+        start: block_range.start(),
+        end: block_range.end(),
+        reason,
+    };
+    let provider_err = |err: ProviderError| invalid(err.to_string());
+
+    let staged =
+        StaticFileProvider::<N>::read_only(staging_directory, false).map_err(provider_err)?;
+    let headers = staged_segment(&staged, StaticFileSegment::Headers, block_range)?;
+    let block_meta = staged_segment(&staged, StaticFileSegment::BlockMeta, block_range)?;
+    let transactions = staged_segment(&staged, StaticFileSegment::Transactions, block_range)?;
+
+    let first_tx_num = next_tx_num;
+    let mut headers_cursor = headers.cursor().map_err(provider_err)?;
+    let mut block_meta_cursor = block_meta.cursor().map_err(provider_err)?;
+    let mut transactions_cursor = transactions.cursor().map_err(provider_err)?;
+
+    let mut hash_to_number =
+        Vec::with_capacity((block_range.end() - block_range.start() + 1) as usize);
+    let mut tx_blocks = Vec::new();
+    for number in block_range.start()..=block_range.end() {
+        let (header, hash) = headers_cursor
+            .get_two::<HeaderWithHashMask<N::BlockHeader>>(number.into())
+            .map_err(provider_err)?
+            .ok_or_else(|| invalid(format!("missing header {number}")))?;
+        let header = SealedHeader::seal_slow(header);
+
+        if header.number() != number || header.hash() != hash {
+            return Err(invalid(format!("invalid header {number}")))
+        }
+        if number == 0 {
+            if hash != parent_hash {
+                return Err(invalid(format!("genesis {hash} does not match {parent_hash}")))
             }
-        });
+        } else if header.parent_hash() != parent_hash {
+            return Err(invalid(format!("header {number} does not link to its parent")))
+        }
+        parent_hash = hash;
+        hash_to_number.push((hash, number));
+
+        let indices = block_meta_cursor
+            .get_one::<BodyIndicesMask>(number.into())
+            .map_err(provider_err)?
+            .ok_or_else(|| invalid(format!("missing body indices {number}")))?;
+        if indices.first_tx_num() != next_tx_num {
+            return Err(invalid(format!("body indices {number} are not continuous")))
+        }
+
+        let block_transactions = indices
+            .tx_num_range()
+            .map(|tx_num| {
+                transactions_cursor
+                    .get_one::<TransactionMask<N::SignedTx>>(tx_num.into())
+                    .map_err(provider_err)?
+                    .ok_or_else(|| invalid(format!("missing transaction {tx_num}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if calculate_transaction_root(&block_transactions) != header.transactions_root() {
+            return Err(invalid(format!("transactions of block {number} do not match its header")))
+        }
+
+        if !indices.is_empty() {
+            tx_blocks.push((indices.last_tx_num(), number));
+        }
+        next_tx_num = indices.next_tx_num();
+    }
 
-        Some(fetch_rx)
+    let expected_tx_range = (next_tx_num > first_tx_num)
+        .then(|| SegmentRangeInclusive::new(first_tx_num, next_tx_num - 1));
+    if transactions.user_header().tx_range() != expected_tx_range.as_ref() {
+        return Err(invalid("transactions do not match the body indices".to_string()))
     }
+
+    hash_to_number.sort_unstable_by_key(|(hash, _)| *hash);
+
+    Ok((hash_to_number, tx_blocks))
+}
+
+/// Returns the staged static file of the segment, ensuring it holds the block range.
+fn staged_segment<N: NodePrimitives>(
+    staged: &StaticFileProvider<N>,
+    segment: StaticFileSegment,
+    block_range: SegmentRangeInclusive,
+) -> Result<StaticFileJarProvider<'_, N>, DownloaderError> {
+    let invalid = |reason: String| DownloaderError::InvalidStaticFiles {
+        start: block_range.start(),
+        end: block_range.end(),
+        reason,
+    };
+
+    let jar = staged
+        .get_segment_provider(segment, || Some(block_range), None)
+        .map_err(|err| invalid(err.to_string()))?
+        .ok_or_else(|| invalid(format!("missing {segment} static file")))?;
+    if jar.user_header().block_range() != Some(&block_range) {
+        return Err(invalid(format!("{segment} static file has a different block range")))
+    }
+
+    Ok(jar)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{
-        ExecuteStageTestRunner, StageTestRunner, TestRunnerError, TestStageDB,
-        UnwindStageTestRunner,
+    use crate::test_utils::{StorageKind, TestStageDB};
+    use alloy_consensus::Transaction as _;
+    use rand::rngs::StdRng;
+    use reth_chainspec::MAINNET;
+    use reth_db::{
+        test_utils::{create_test_rw_db, create_test_static_files_dir, TempDatabase},
+        Database, DatabaseEnv,
     };
-    use reth_primitives::SealedHeader;
-    use reth_testing_utils::{
-        generators,
-        generators::{random_header, random_header_range},
+    use reth_db_api::models::{StoredBlockBodyIndices, StoredBlockOmmers, StoredBlockWithdrawals};
+    use reth_primitives::{Receipt, SealedBlock};
+    use reth_provider::{
+        providers::StaticFileWriter, test_utils::MockNodeTypesWithDB, BlockBodyIndicesProvider,
+        BlockNumReader, DatabaseProvider, HeaderProvider, ProviderFactory, TransactionsProvider,
+    };
+    use reth_stages_api::StageExt;
+    use reth_testing_utils::generators::{
+        self, random_block, random_block_range, BlockParams, BlockRangeParams,
+    };
+    use std::collections::BTreeMap;
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+        net::{TcpListener, TcpStream},
     };
 
-    // stage_test_suite_ext!(S3TestRunner, s3);
+    type TestProvider =
+        DatabaseProvider<<TempDatabase<DatabaseEnv> as Database>::TXMut, MockNodeTypesWithDB>;
+
+    /// Number of blocks per static file, so that the tests span multiple block ranges.
+    const BLOCKS_PER_FILE: u64 = 10;
+
+    fn test_db() -> TestStageDB {
+        let (static_dir, static_dir_path) = create_test_static_files_dir();
+        TestStageDB {
+            temp_static_files_dir: static_dir,
+            factory: ProviderFactory::new(
+                create_test_rw_db(),
+                MAINNET.clone(),
+                StaticFileProvider::read_write(static_dir_path)
+                    .unwrap()
+                    .with_custom_blocks_per_file(BLOCKS_PER_FILE),
+            ),
+        }
+    }
 
-    #[derive(Default)]
-    struct S3TestRunner {
-        db: TestStageDB,
+    /// Returns a chain of blocks, starting with a genesis block without transactions.
+    fn random_chain(rng: &mut StdRng, tip: BlockNumber) -> Vec<SealedBlock> {
+        let genesis = random_block(
+            rng,
+            0,
+            BlockParams { tx_count: Some(0), ommers_count: Some(0), ..Default::default() },
+        );
+        let parent = Some(genesis.hash());
+        let mut blocks = vec![genesis];
+        blocks.extend(random_block_range(
+            rng,
+            1..=tip,
+            BlockRangeParams { parent, tx_count: 0..3, ..Default::default() },
+        ));
+        blocks
     }
 
-    impl StageTestRunner for S3TestRunner {
-        type S = S3Stage;
+    /// Returns the receipts of the block, which match the gas used and the empty logs bloom of the
+    /// blocks of [`random_chain`].
+    fn block_receipts(block: &SealedBlock) -> Vec<Receipt> {
+        let mut cumulative_gas_used = 0;
+        block
+            .body()
+            .transactions
+            .iter()
+            .map(|tx| {
+                cumulative_gas_used += tx.gas_limit();
+                Receipt {
+                    tx_type: tx.transaction().tx_type(),
+                    success: true,
+                    cumulative_gas_used,
+                    logs: Vec::new(),
+                }
+            })
+            .collect()
+    }
 
-        fn db(&self) -> &TestStageDB {
-            &self.db
+    /// Writes the static files of the blocks, including receipts that the stage skips, and the
+    /// file list that serves them, to the directory of the remote server.
+    fn write_remote(blocks: &[SealedBlock]) -> TestStageDB {
+        let remote = test_db();
+        remote.insert_blocks(blocks.iter(), StorageKind::Static).unwrap();
+
+        let static_file_provider = remote.factory.static_file_provider();
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::BlockMeta).unwrap();
+        let mut next_tx_num = 0;
+        for block in blocks {
+            let indices = StoredBlockBodyIndices {
+                first_tx_num: next_tx_num,
+                tx_count: block.body().transactions.len() as u64,
+            };
+            next_tx_num = indices.next_tx_num();
+            writer
+                .append_eth_block_meta(
+                    &indices,
+                    &StoredBlockOmmers { ommers: block.body().ommers.clone() },
+                    &StoredBlockWithdrawals::default(),
+                    block.number,
+                )
+                .unwrap();
         }
-
-        fn stage(&self) -> Self::S {
-            S3Stage::default()
+        writer.commit().unwrap();
+        drop(writer);
+
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::Receipts).unwrap();
+        let mut next_tx_num = 0;
+        for block in blocks {
+            writer.increment_block(block.number).unwrap();
+            for receipt in block_receipts(block) {
+                writer.append_receipt(next_tx_num, &receipt).unwrap();
+                next_tx_num += 1;
+            }
         }
+        writer.commit().unwrap();
+        drop(writer);
+
+        let directory = static_file_provider.directory();
+        let mut list = BTreeMap::<_, Vec<_>>::new();
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let name = entry.unwrap().file_name().to_string_lossy().into_owned();
+            let Some((_, block_range)) =
+                name.split('.').next().and_then(StaticFileSegment::parse_filename)
+            else {
+                continue
+            };
+            let hash = blake3_hash(&directory.join(&name));
+            list.entry(block_range.start()).or_default().push(DownloadFile { name, hash });
+        }
+        let list = list.into_values().collect::<DownloadFileList>();
+        std::fs::write(directory.join(DOWNLOAD_FILE_LIST_NAME), serde_json::to_vec(&list).unwrap())
+            .unwrap();
+
+        remote
     }
 
-    impl ExecuteStageTestRunner for S3TestRunner {
-        type Seed = Vec<SealedHeader>;
+    fn blake3_hash(path: &Path) -> B256 {
+        B256::from(*blake3::hash(&std::fs::read(path).unwrap()).as_bytes())
+    }
 
-        fn seed_execution(&mut self, input: ExecInput) -> Result<Self::Seed, TestRunnerError> {
-            let start = input.checkpoint().block_number;
-            let mut rng = generators::rng();
-            let head = random_header(&mut rng, start, None);
-            self.db.insert_headers_with_td(std::iter::once(&head))?;
+    /// Serves the files of the directory over HTTP, with support for `HEAD` and range requests.
+    async fn serve_directory(directory: PathBuf) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve_connection(stream, directory.clone()));
+            }
+        });
+        url
+    }
 
-            // use previous progress as seed size
-            let end = input.target.unwrap_or_default() + 1;
+    async fn serve_connection(mut stream: TcpStream, directory: PathBuf) -> std::io::Result<()> {
+        let (reader, mut writer) = stream.split();
+        let mut reader = BufReader::new(reader);
+        loop {
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).await? == 0 {
+                return Ok(())
+            }
+            let mut parts = request_line.split_whitespace();
+            let method = parts.next().unwrap_or_default().to_string();
+            let path = parts.next().unwrap_or_default().trim_start_matches('/').to_string();
+
+            let mut range = None;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).await?;
+                let line = line.trim_end().to_ascii_lowercase();
+                if line.is_empty() {
+                    break
+                }
+                if let Some((start, end)) =
+                    line.strip_prefix("range: bytes=").and_then(|range| range.split_once('-'))
+                {
+                    range = Some((start.parse::<usize>().unwrap(), end.parse::<usize>().unwrap()));
+                }
+            }
 
-            if start + 1 >= end {
-                return Ok(Vec::default())
+            let Ok(data) = std::fs::read(directory.join(path)) else {
+                writer.write_all(b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n\r\n").await?;
+                continue
+            };
+            let (status, body) = match range {
+                Some((start, end)) => ("206 Partial Content", &data[start..=end]),
+                None => ("200 OK", &data[..]),
+            };
+            let head = format!("HTTP/1.1 {status}\r\ncontent-length: {}\r\n\r\n", body.len());
+            writer.write_all(head.as_bytes()).await?;
+            if method != "HEAD" {
+                writer.write_all(body).await?;
             }
+        }
+    }
 
-            let mut headers = random_header_range(&mut rng, start + 1..end, head.hash());
-            self.db.insert_headers_with_td(headers.iter())?;
-            headers.insert(0, head);
-            Ok(headers)
+    /// Runs the stage until it's done, committing after every execution like the pipeline.
+    async fn run_stage(db: &TestStageDB, stage: &mut S3Stage) -> Result<ExecOutput, StageError> {
+        loop {
+            let checkpoint = db.factory.provider()?.get_stage_checkpoint(S3_STAGE_ID)?;
+            let input = ExecInput { target: None, checkpoint };
+            StageExt::<TestProvider>::execute_ready(stage, input).await?;
+
+            let provider = db.factory.provider_rw()?;
+            let output = Stage::<TestProvider>::execute(stage, &provider, input)?;
+            provider.save_stage_checkpoint(S3_STAGE_ID, output.checkpoint)?;
+            provider.commit()?;
+
+            if output.done {
+                return Ok(output)
+            }
         }
+    }
+
+    #[tokio::test]
+    async fn download_and_import() {
+        reth_tracing::init_test_tracing();
+
+        let tip = 2 * BLOCKS_PER_FILE - 1;
+        let blocks = random_chain(&mut generators::rng(), tip);
+        let remote = write_remote(&blocks);
+        let url = serve_directory(remote.factory.static_file_provider().directory().into()).await;
+
+        let local = test_db();
+        local.insert_blocks(blocks.iter().take(1), StorageKind::Static).unwrap();
+        let static_file_directory = local.factory.static_file_provider().directory().to_path_buf();
 
-        fn validate_execution(
-            &self,
-            input: ExecInput,
-            output: Option<ExecOutput>,
-        ) -> Result<(), TestRunnerError> {
-            if let Some(output) = output {
-                assert!(output.done, "stage should always be done");
+        let mut stage = S3Stage::new(static_file_directory.clone(), url.clone(), 2);
+        let output = run_stage(&local, &mut stage).await.unwrap();
+        assert_eq!(output.checkpoint.block_number, tip);
+
+        let provider = local.factory.provider().unwrap();
+        for stage_id in [StageId::Headers, StageId::Bodies] {
+            assert_eq!(provider.get_stage_checkpoint(stage_id).unwrap().unwrap().block_number, tip);
+        }
+
+        let mut next_tx_num = 0;
+        for block in &blocks {
+            assert_eq!(
+                provider.sealed_header(block.number).unwrap().as_ref(),
+                Some(block.sealed_header())
+            );
+            assert_eq!(provider.block_number(block.hash()).unwrap(), Some(block.number));
+
+            let indices = provider.block_body_indices(block.number).unwrap().unwrap();
+            assert_eq!(indices.first_tx_num(), next_tx_num);
+            assert_eq!(
+                provider.transactions_by_block(block.number.into()).unwrap().unwrap(),
+                block.body().transactions
+            );
+            if !indices.is_empty() {
                 assert_eq!(
-                    output.checkpoint.block_number,
-                    input.target(),
-                    "stage progress should always match progress of previous stage"
+                    provider.transaction_block(indices.last_tx_num()).unwrap(),
+                    Some(block.number)
                 );
             }
-            Ok(())
+            next_tx_num = indices.next_tx_num();
         }
+        // Receipts are left to the execution stage.
+        assert_eq!(
+            provider
+                .static_file_provider()
+                .get_highest_static_file_block(StaticFileSegment::Receipts),
+            None
+        );
+        drop(provider);
+
+        // Nothing is left in the staging directory.
+        assert!(std::fs::read_dir(static_file_directory.join(STAGING_DIRECTORY_NAME))
+            .unwrap()
+            .all(|entry| entry.unwrap().file_type().unwrap().is_dir()));
+
+        // The remote server has no more block ranges for the local chain.
+        let mut stage = S3Stage::new(static_file_directory, url, 2);
+        let output = run_stage(&local, &mut stage).await.unwrap();
+        assert_eq!(output.checkpoint.block_number, tip);
     }
 
-    impl UnwindStageTestRunner for S3TestRunner {
-        fn validate_unwind(&self, _input: UnwindInput) -> Result<(), TestRunnerError> {
-            Ok(())
-        }
+    #[tokio::test]
+    async fn reject_other_chain() {
+        reth_tracing::init_test_tracing();
+
+        let mut rng = generators::rng();
+        let remote = write_remote(&random_chain(&mut rng, BLOCKS_PER_FILE - 1));
+        let url = serve_directory(remote.factory.static_file_provider().directory().into()).await;
+
+        let local = test_db();
+        local.insert_blocks(random_chain(&mut rng, 0).iter(), StorageKind::Static).unwrap();
+        let static_file_directory = local.factory.static_file_provider().directory().to_path_buf();
+
+        let mut stage = S3Stage::new(static_file_directory.clone(), url, 2);
+        assert_matches::assert_matches!(
+            run_stage(&local, &mut stage).await,
+            Err(StageError::Fatal(err)) if matches!(
+                err.downcast_ref::<DownloaderError>(),
+                Some(DownloaderError::InvalidStaticFiles { start: 0, .. })
+            )
+        );
+
+        // The local chain is untouched, and the static files are downloaded again on the next
+        // run.
+        let provider = local.factory.provider().unwrap();
+        assert_eq!(provider.get_stage_checkpoint(StageId::Headers).unwrap(), None);
+        assert_eq!(provider.last_block_number().unwrap(), 0);
+        assert!(std::fs::read_dir(static_file_directory.join(STAGING_DIRECTORY_NAME))
+            .unwrap()
+            .all(|entry| entry.unwrap().file_type().unwrap().is_dir()));
+    }
+
+    #[tokio::test]
+    async fn reject_existing_static_files() {
+        reth_tracing::init_test_tracing();
+
+        let blocks = random_chain(&mut generators::rng(), BLOCKS_PER_FILE - 1);
+        let remote = write_remote(&blocks);
+        let url = serve_directory(remote.factory.static_file_provider().directory().into()).await;
+
+        // The local static files already hold blocks of the first block range, even though the
+        // headers and bodies stages are still at the genesis block.
+        let local = test_db();
+        local.insert_blocks(blocks.iter().take(5), StorageKind::Static).unwrap();
+        let static_file_directory = local.factory.static_file_provider().directory().to_path_buf();
+        let headers_file = static_file_directory
+            .join(StaticFileSegment::Headers.filename(&SegmentRangeInclusive::new(0, 9)));
+        let headers_hash = blake3_hash(&headers_file);
+
+        let mut stage = S3Stage::new(static_file_directory, url, 2);
+        assert_matches::assert_matches!(
+            run_stage(&local, &mut stage).await,
+            Err(StageError::Fatal(err)) if matches!(
+                err.downcast_ref::<DownloaderError>(),
+                Some(DownloaderError::ExistingStaticFiles {
+                    segment: StaticFileSegment::Headers,
+                    start: 0,
+                    end: 4,
+                })
+            )
+        );
+
+        // The local static files are untouched.
+        assert_eq!(blake3_hash(&headers_file), headers_hash);
+        let provider = local.factory.provider().unwrap();
+        assert_eq!(provider.get_stage_checkpoint(StageId::Headers).unwrap(), None);
+        assert_eq!(provider.last_block_number().unwrap(), 4);
     }
 
     #[test]
-    fn parse_files() {
-        for block_range_files in &DOWNLOAD_FILE_LIST {
-            let (_, _) = StaticFileSegment::parse_filename(block_range_files[0].0).expect("qed");
-        }
+    fn block_ranges() {
+        let file = |segment: StaticFileSegment, start, end| DownloadFile {
+            name: segment.filename(&SegmentRangeInclusive::new(start, end)),
+            hash: B256::ZERO,
+        };
+        let list = vec![
+            vec![
+                file(StaticFileSegment::Headers, 10, 19),
+                file(StaticFileSegment::BlockMeta, 10, 19),
+                file(StaticFileSegment::Receipts, 10, 19),
+                DownloadFile { name: "README".to_string(), hash: B256::ZERO },
+            ],
+            vec![file(StaticFileSegment::Headers, 0, 9)],
+            vec![file(StaticFileSegment::Headers, 20, 29)],
+            vec![file(StaticFileSegment::Headers, 40, 49)],
+        ];
+
+        let ranges = |next_block, target| {
+            block_ranges_to_download(list.clone(), next_block, target)
+                .into_iter()
+                .map(|(block_range, files)| (block_range.start(), files.len()))
+                .collect::<Vec<_>>()
+        };
+
+        // Receipts and files that aren't static files are skipped and ranges are sorted.
+        assert_eq!(ranges(0, u64::MAX), vec![(0, 1), (10, 2), (20, 1)]);
+        // Ranges are only downloaded if they continue the local chain and end at the target.
+        assert_eq!(ranges(10, u64::MAX), vec![(10, 2), (20, 1)]);
+        assert_eq!(ranges(0, 25), vec![(0, 1), (10, 2)]);
+        assert_eq!(ranges(5, u64::MAX), vec![]);
+        assert_eq!(ranges(30, u64::MAX), vec![]);
     }
 }
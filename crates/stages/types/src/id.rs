@@ -20,6 +20,7 @@ pub enum StageId {
     TransactionLookup,
     IndexStorageHistory,
     IndexAccountHistory,
+    IndexLogs,
     Prune,
     Finish,
     /// Other custom stage with a provided string identifier.
@@ -28,6 +29,9 @@ pub enum StageId {
 
 impl StageId {
     /// All supported Stages
+    ///
+    /// Doesn't include [`StageId::IndexLogs`], which is only part of the pipeline if the log index
+    /// is enabled.
     pub const ALL: [Self; 14] = [
         Self::Headers,
         Self::Bodies,
@@ -75,6 +79,7 @@ impl StageId {
             Self::TransactionLookup => "TransactionLookup",
             Self::IndexAccountHistory => "IndexAccountHistory",
             Self::IndexStorageHistory => "IndexStorageHistory",
+            Self::IndexLogs => "IndexLogs",
             Self::Prune => "Prune",
             Self::Finish => "Finish",
             Self::Other(s) => s,
@@ -119,6 +124,7 @@ mod tests {
         assert_eq!(StageId::MerkleExecute.to_string(), "MerkleExecute");
         assert_eq!(StageId::IndexAccountHistory.to_string(), "IndexAccountHistory");
         assert_eq!(StageId::IndexStorageHistory.to_string(), "IndexStorageHistory");
+        assert_eq!(StageId::IndexLogs.to_string(), "IndexLogs");
         assert_eq!(StageId::TransactionLookup.to_string(), "TransactionLookup");
         assert_eq!(StageId::Finish.to_string(), "Finish");
 
@@ -602,21 +602,22 @@ where
     }
 }
 
-/// Type to deserialize state root from state dump file.
+/// The first line of a state dump file, containing the state root.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
-struct StateRoot {
-    root: B256,
+pub struct StateRoot {
+    /// The state root of the block the state was dumped at.
+    pub root: B256,
 }
 
 /// An account as in the state dump file. This contains a [`GenesisAccount`] and the account's
 /// address.
 #[derive(Debug, Serialize, Deserialize)]
-struct GenesisAccountWithAddress {
+pub struct GenesisAccountWithAddress {
     /// The account's balance, nonce, code, and storage.
     #[serde(flatten)]
-    genesis_account: GenesisAccount,
+    pub genesis_account: GenesisAccount,
     /// The account's address.
-    address: Address,
+    pub address: Address,
 }
 
 #[cfg(test)]
@@ -501,6 +501,51 @@ tables! {
         type Value = Address;
     }
 
+    /// Stores pointers to the transactions in which an address appears, either as the sender,
+    /// the recipient or a participant of an internal call.
+    ///
+    /// Shards are keyed by `Address | highest transaction number` and follow the same layout as
+    /// [`AccountsHistory`], with the difference that the list holds transaction numbers instead of
+    /// block numbers. The last shard of an address has `u64::MAX` as its highest number.
+    ///
+    /// This table is optional and only populated when the address index is enabled.
+    table AddressTransactions {
+        type Key = ShardedKey<Address>;
+        type Value = TransactionNumberList;
+    }
+
+    /// Stores the addresses that appear in each transaction indexed in [`AddressTransactions`].
+    ///
+    /// Used to unwind and prune [`AddressTransactions`] without walking the whole index.
+    table AddressAppearances {
+        type Key = TxNumber;
+        type Value = Address;
+        type SubKey = Address;
+    }
+
+    /// Stores pointers to the blocks that contain logs emitted by an address.
+    ///
+    /// Shards are keyed by `Address | highest block number` and follow the same layout as
+    /// [`AccountsHistory`]. The last shard of an address has `u64::MAX` as its highest block
+    /// number.
+    ///
+    /// Used by `eth_getLogs` to skip blocks that can't match an address filter.
+    table LogAddressIndex {
+        type Key = ShardedKey<Address>;
+        type Value = BlockNumberList;
+    }
+
+    /// Stores pointers to the blocks that contain logs with a topic, at any position.
+    ///
+    /// Shards are keyed by `Topic | highest block number` and follow the same layout as
+    /// [`AccountsHistory`]. The last shard of a topic has `u64::MAX` as its highest block number.
+    ///
+    /// Used by `eth_getLogs` to skip blocks that can't match a topics filter.
+    table LogTopicIndex {
+        type Key = ShardedKey<B256>;
+        type Value = BlockNumberList;
+    }
+
     /// Stores the highest synced block number and stage-specific checkpoint of each stage.
     table StageCheckpoints {
         type Key = StageId;
@@ -564,9 +609,12 @@ impl Decode for ChainStateKey {
 
 // Alias types.
 
-/// List with transaction numbers.
+/// List with block numbers.
 pub type BlockNumberList = IntegerList;
 
+/// List with transaction numbers.
+pub type TransactionNumberList = IntegerList;
+
 /// Encoded stage id.
 pub type StageId = String;
 
@@ -225,6 +225,44 @@ impl Environment {
 
         Ok(freelist)
     }
+
+    /// Copies the environment to a new file at the given path, which must not exist yet.
+    ///
+    /// If `compact` is set, free pages are omitted from the copy and all pages are renumbered
+    /// sequentially, so the copy only takes the space of the pages in use. The copy is made from
+    /// a read transaction, so the environment can be written to concurrently.
+    ///
+    /// The copy is always resizable, regardless of the geometry of the environment.
+    pub fn copy(&self, dest: &Path, compact: bool) -> Result<()> {
+        let dest = path_to_cstring(dest)?;
+        let mut flags = ffi::MDBX_CP_FORCE_DYNAMIC_SIZE;
+        if compact {
+            flags |= ffi::MDBX_CP_COMPACT;
+        }
+        mdbx_result(unsafe { ffi::mdbx_env_copy(self.env_ptr(), dest.as_ptr(), flags) })?;
+        Ok(())
+    }
+}
+
+/// Converts the path to a C string, which is required by the MDBX functions that take paths.
+///
+/// The path may not contain the null character.
+fn path_to_cstring(path: &Path) -> Result<CString> {
+    #[cfg(unix)]
+    fn path_to_bytes<P: AsRef<Path>>(path: P) -> Vec<u8> {
+        use std::os::unix::ffi::OsStrExt;
+        path.as_ref().as_os_str().as_bytes().to_vec()
+    }
+
+    #[cfg(windows)]
+    fn path_to_bytes<P: AsRef<Path>>(path: P) -> Vec<u8> {
+        // On Windows, could use std::os::windows::ffi::OsStrExt to encode_wide(),
+        // but we end up with a Vec<u16> instead of a Vec<u8>, so that doesn't
+        // really help.
+        path.as_ref().to_string_lossy().to_string().into_bytes()
+    }
+
+    CString::new(path_to_bytes(path)).map_err(|_| Error::Invalid)
 }
 
 /// Container type for Environment internals.
@@ -298,6 +336,24 @@ pub(crate) struct EnvPtr(pub(crate) *mut ffi::MDBX_env);
 unsafe impl Send for EnvPtr {}
 unsafe impl Sync for EnvPtr {}
 
+/// Page usage of a database.
+///
+/// See [`Transaction::page_usage`](crate::Transaction::page_usage).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PageUsage {
+    /// Number of pages of the database.
+    pub pages: usize,
+    /// Bytes of the pages that are used by keys and values.
+    pub payload_bytes: usize,
+    /// Bytes of the pages that are used by page headers.
+    pub header_bytes: usize,
+    /// Bytes of the pages that are unused.
+    ///
+    /// This space is only reused by subsequent writes to the same pages, copying the environment
+    /// with compaction doesn't reclaim it.
+    pub unused_bytes: usize,
+}
+
 /// Environment statistics.
 ///
 /// Contains information about the size and layout of an MDBX environment or database.
@@ -363,6 +419,12 @@ impl GeometryInfo {
     pub const fn min(&self) -> u64 {
         self.0.lower
     }
+
+    /// Current size of the database file.
+    #[inline]
+    pub const fn current(&self) -> u64 {
+        self.0.current
+    }
 }
 
 /// Environment information.
@@ -690,24 +752,7 @@ impl EnvironmentBuilder {
                     ))?;
                 }
 
-                #[cfg(unix)]
-                fn path_to_bytes<P: AsRef<Path>>(path: P) -> Vec<u8> {
-                    use std::os::unix::ffi::OsStrExt;
-                    path.as_ref().as_os_str().as_bytes().to_vec()
-                }
-
-                #[cfg(windows)]
-                fn path_to_bytes<P: AsRef<Path>>(path: P) -> Vec<u8> {
-                    // On Windows, could use std::os::windows::ffi::OsStrExt to encode_wide(),
-                    // but we end up with a Vec<u16> instead of a Vec<u8>, so that doesn't
-                    // really help.
-                    path.as_ref().to_string_lossy().to_string().into_bytes()
-                }
-
-                let path = match CString::new(path_to_bytes(path)) {
-                    Ok(path) => path,
-                    Err(_) => return Err(Error::Invalid),
-                };
+                let path = path_to_cstring(path)?;
                 mdbx_result(ffi::mdbx_env_open(
                     env,
                     path.as_ptr(),
@@ -16,7 +16,7 @@ pub use crate::{
     database::Database,
     environment::{
         Environment, EnvironmentBuilder, EnvironmentKind, Geometry, HandleSlowReadersCallback,
-        HandleSlowReadersReturnCode, Info, PageSize, Stat,
+        HandleSlowReadersReturnCode, Info, PageSize, PageUsage, Stat,
     },
     error::{Error, Result},
     flags::*,
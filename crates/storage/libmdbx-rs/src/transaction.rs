@@ -4,13 +4,14 @@ use crate::{
     error::{mdbx_result, Result},
     flags::{DatabaseFlags, WriteFlags},
     txn_manager::{TxnManagerMessage, TxnPtr},
-    Cursor, Error, Stat, TableObject,
+    Cursor, Error, PageUsage, Stat, TableObject,
 };
 use ffi::{MDBX_txn_flags_t, MDBX_TXN_RDONLY, MDBX_TXN_READWRITE};
 use indexmap::IndexSet;
 use parking_lot::{Mutex, MutexGuard};
 use std::{
-    ffi::{c_uint, c_void},
+    collections::BTreeMap,
+    ffi::{c_int, c_uint, c_void},
     fmt::{self, Debug},
     mem::size_of,
     ptr, slice,
@@ -264,6 +265,24 @@ where
         }
     }
 
+    /// Traverses the B-trees of all named databases and returns their page usage, keyed by the
+    /// database name.
+    ///
+    /// This visits every page of the environment, so it may take a long time on large
+    /// environments.
+    pub fn page_usage(&self) -> Result<BTreeMap<String, PageUsage>> {
+        let mut usage = BTreeMap::<String, PageUsage>::new();
+        self.txn_execute(|txn| unsafe {
+            mdbx_result(ffi::mdbx_env_pgwalk(
+                txn,
+                Some(visit_page),
+                &mut usage as *mut BTreeMap<String, PageUsage> as *mut c_void,
+                false,
+            ))
+        })??;
+        Ok(usage)
+    }
+
     /// Open a new cursor on the given database.
     pub fn cursor(&self, db: &Database) -> Result<Cursor<K>> {
         Cursor::new(self.clone(), db.dbi())
@@ -283,6 +302,40 @@ where
     }
 }
 
+/// Page visitor of [`Transaction::page_usage`], accumulates the pages of each named database into
+/// the map behind `ctx`.
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn visit_page(
+    _pgno: u64,
+    number: c_uint,
+    ctx: *mut c_void,
+    _deep: c_int,
+    dbi_name: *const ffi::MDBX_val,
+    _page_size: usize,
+    _page_type: ffi::MDBX_page_type_t,
+    _err: ffi::MDBX_error_t,
+    _entries: usize,
+    payload_bytes: usize,
+    header_bytes: usize,
+    unused_bytes: usize,
+) -> c_int {
+    // The main, GC and meta pseudo-databases are passed as null or negative pointers, and
+    // sub-pages (`number == 0`) are nested in leaf pages that were already visited.
+    if dbi_name as isize <= 0 || number == 0 {
+        return ffi::MDBX_SUCCESS
+    }
+
+    let usage = &mut *(ctx as *mut BTreeMap<String, PageUsage>);
+    let name = slice::from_raw_parts((*dbi_name).iov_base as *const u8, (*dbi_name).iov_len);
+    let db = usage.entry(String::from_utf8_lossy(name).into_owned()).or_default();
+    db.pages += number as usize;
+    db.payload_bytes += payload_bytes;
+    db.header_bytes += header_bytes;
+    db.unused_bytes += unused_bytes;
+
+    ffi::MDBX_SUCCESS
+}
+
 impl<K> Clone for Transaction<K>
 where
     K: TransactionKind,
@@ -167,3 +167,40 @@ fn test_freelist() {
     freelist = env.freelist().unwrap();
     assert!(freelist > 0);
 }
+
+#[test]
+fn test_copy_compact() {
+    let dir = tempdir().unwrap();
+    let env = Environment::builder().open(dir.path()).unwrap();
+
+    // Write a few values and delete half of them to populate the freelist.
+    for i in 0..1024 {
+        let mut value = [0u8; 256];
+        LittleEndian::write_u64(&mut value, i);
+        let tx = env.begin_rw_txn().expect("begin_rw_txn");
+        tx.put(tx.open_db(None).unwrap().dbi(), &value[..8], value, WriteFlags::default())
+            .expect("tx.put");
+        tx.commit().expect("tx.commit");
+    }
+    let tx = env.begin_rw_txn().expect("begin_rw_txn");
+    let dbi = tx.open_db(None).unwrap().dbi();
+    for i in (0..1024).step_by(2) {
+        let mut key = [0u8; 8];
+        LittleEndian::write_u64(&mut key, i);
+        tx.del(dbi, key, None).expect("tx.del");
+    }
+    tx.commit().expect("tx.commit");
+    assert!(env.freelist().unwrap() > 0);
+
+    let copy_dir = tempdir().unwrap();
+    env.copy(&copy_dir.path().join("mdbx.dat"), true).unwrap();
+
+    // The copy shouldn't contain any free pages, but all remaining values.
+    let copy = Environment::builder().open(copy_dir.path()).unwrap();
+    assert_eq!(copy.freelist().unwrap(), 0);
+    assert!(copy.info().unwrap().last_pgno() < env.info().unwrap().last_pgno());
+    assert_eq!(copy.stat().unwrap().entries(), 512);
+
+    // The destination must not exist.
+    assert!(env.copy(&copy_dir.path().join("mdbx.dat"), true).is_err());
+}
@@ -373,3 +373,31 @@ fn test_stat_dupsort() {
         assert_eq!(stat.entries(), 8);
     }
 }
+
+#[test]
+fn test_page_usage() {
+    let dir = tempdir().unwrap();
+    let env = Environment::builder().set_max_dbs(2).open(dir.path()).unwrap();
+
+    let txn = env.begin_rw_txn().unwrap();
+    let db = txn.create_db(Some("test"), DatabaseFlags::empty()).unwrap();
+    for i in 0..64u64 {
+        txn.put(db.dbi(), i.to_be_bytes(), [0u8; 128], WriteFlags::empty()).unwrap();
+    }
+    txn.create_db(Some("empty"), DatabaseFlags::empty()).unwrap();
+    txn.commit().unwrap();
+
+    let txn = env.begin_ro_txn().unwrap();
+    let usage = txn.page_usage().unwrap();
+    let stat = txn.db_stat(&txn.open_db(Some("test")).unwrap()).unwrap();
+
+    // Empty databases don't have any pages.
+    assert_eq!(usage.keys().collect::<Vec<_>>(), vec!["test"]);
+    let test = usage["test"];
+    assert_eq!(test.pages, stat.branch_pages() + stat.leaf_pages() + stat.overflow_pages());
+    assert!(
+        test.payload_bytes + test.header_bytes + test.unused_bytes <=
+            test.pages * stat.page_size() as usize
+    );
+    assert!(test.payload_bytes >= 64 * (8 + 128));
+}
@@ -49,6 +49,28 @@ impl<H: NippyJarHeader> NippyJarChecker<H> {
         self.handle_consistency(ConsistencyFailStrategy::Heal)
     }
 
+    /// It will throw an error if the [`NippyJar`] is in a inconsistent state, or if any offset is
+    /// smaller than the previous one or points beyond the data file.
+    ///
+    /// Unlike [`Self::check_consistency`], which only checks the last offset, this reads the whole
+    /// offsets file.
+    pub fn check_offsets(&mut self) -> Result<(), NippyJarError> {
+        self.check_consistency()?;
+
+        let reader = self.jar.open_data_reader()?;
+        let data_file_len = reader.size() as u64;
+        let mut previous_offset = 0;
+        for index in 0..reader.offsets_count()? {
+            let offset = reader.offset(index)?;
+            if offset < previous_offset || offset > data_file_len {
+                return Err(NippyJarError::InvalidOffset { index, offset })
+            }
+            previous_offset = offset;
+        }
+
+        Ok(())
+    }
+
     fn handle_consistency(&mut self, mode: ConsistencyFailStrategy) -> Result<(), NippyJarError> {
         self.load_files(mode)?;
         let mut reader = self.jar.open_data_reader()?;
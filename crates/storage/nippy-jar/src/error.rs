@@ -65,6 +65,15 @@ pub enum NippyJarError {
         index: usize,
     },
 
+    /// An offset is smaller than the previous one, or points beyond the data file.
+    #[error("offset {index} is out of order or beyond the data file: {offset}")]
+    InvalidOffset {
+        /// The index of the offset.
+        index: usize,
+        /// The read offset.
+        offset: u64,
+    },
+
     /// The output buffer is too small for the compression or decompression operation.
     #[error("compression or decompression requires a bigger destination output")]
     OutputTooSmall,
@@ -423,7 +423,11 @@ mod tests {
     use super::*;
     use compression::Compression;
     use rand::{rngs::SmallRng, seq::SliceRandom, RngCore, SeedableRng};
-    use std::{fs::OpenOptions, io::Read};
+    use std::{
+        fs::OpenOptions,
+        io::{Read, Seek, SeekFrom, Write},
+    };
+    use writer::OFFSET_SIZE_BYTES;
 
     type ColumnResults<T> = Vec<ColumnResult<T>>;
     type ColumnValues = Vec<Vec<u8>>;
@@ -815,6 +819,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_check_offsets() {
+        let (col1, col2) = test_data(None);
+        let num_columns = 2;
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+
+        append_two_rows(num_columns, file_path.path(), &col1, &col2);
+
+        let nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        NippyJarChecker::new(nippy).check_offsets().unwrap();
+
+        // Corrupt the second offset, which isn't covered by the consistency check since it only
+        // looks at the last offset
+        let nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        {
+            let mut offsets_file =
+                OpenOptions::new().write(true).open(nippy.offsets_path()).unwrap();
+            offsets_file.seek(SeekFrom::Start(1 + OFFSET_SIZE_BYTES as u64)).unwrap();
+            offsets_file.write_all(&u64::MAX.to_le_bytes()).unwrap();
+        }
+
+        let mut checker = NippyJarChecker::new(nippy);
+        checker.check_consistency().unwrap();
+        assert!(matches!(
+            checker.check_offsets(),
+            Err(NippyJarError::InvalidOffset { index: 1, offset: u64::MAX })
+        ));
+    }
+
     fn test_append_consistency_partial_commit(
         file_path: &Path,
         col1: &[Vec<u8>],
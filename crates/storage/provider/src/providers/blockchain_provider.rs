@@ -1,10 +1,11 @@
 #![allow(unused)]
 use crate::{
     providers::{ConsistentProvider, ProviderNodeTypes, StaticFileProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    BlockSource, CanonChainTracker, CanonStateNotifications, CanonStateSubscriptions,
-    ChainSpecProvider, ChainStateBlockReader, ChangeSetReader, DatabaseProvider,
-    DatabaseProviderFactory, FullProvider, HashedPostStateProvider, HeaderProvider, ProviderError,
+    AccountReader, AddressTransactionsReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, BlockSource, CanonChainTracker, CanonStateNotifications,
+    CanonStateSubscriptions, ChainSpecProvider, ChainStateBlockReader, ChangeSetReader,
+    DatabaseProvider, DatabaseProviderFactory, FullProvider, HashedPostStateProvider,
+    HashedStateRangeReader, HashedStateRanges, HeaderProvider, LogIndexReader, ProviderError,
     ProviderFactory, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
     StageCheckpointReader, StateProviderBox, StateProviderFactory, StateReader,
     StaticFileProviderFactory, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
@@ -259,6 +260,10 @@ impl<N: ProviderNodeTypes> BlockNumReader for BlockchainProvider<N> {
         self.database.last_block_number()
     }
 
+    fn earliest_block_number(&self) -> ProviderResult<BlockNumber> {
+        self.database.earliest_block_number()
+    }
+
     fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
         self.consistent_provider()?.block_number(hash)
     }
@@ -478,6 +483,66 @@ impl<N: ProviderNodeTypes> BlockBodyIndicesProvider for BlockchainProvider<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> AddressTransactionsReader for BlockchainProvider<N> {
+    fn address_transactions_before(
+        &self,
+        address: Address,
+        before: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        self.database.address_transactions_before(address, before, limit)
+    }
+
+    fn address_transactions_from(
+        &self,
+        address: Address,
+        from: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        self.database.address_transactions_from(address, from, limit)
+    }
+}
+
+impl<N: ProviderNodeTypes> LogIndexReader for BlockchainProvider<N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.database.log_index_range()
+    }
+
+    fn log_address_blocks(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.database.log_address_blocks(address, range)
+    }
+
+    fn log_topic_blocks(
+        &self,
+        topic: B256,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.database.log_topic_blocks(topic, range)
+    }
+}
+
+impl<N: ProviderNodeTypes> HashedStateRangeReader for BlockchainProvider<N> {
+    fn with_hashed_state_at<R>(
+        &self,
+        block_number: BlockNumber,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        self.consistent_provider()?.with_hashed_state_at(block_number, f)
+    }
+
+    fn with_hashed_state_at_hash<R>(
+        &self,
+        block_hash: B256,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        self.consistent_provider()?.with_hashed_state_at_hash(block_hash, f)
+    }
+}
+
 impl<N: ProviderNodeTypes> StageCheckpointReader for BlockchainProvider<N> {
     fn get_stage_checkpoint(&self, id: StageId) -> ProviderResult<Option<StageCheckpoint>> {
         self.consistent_provider()?.get_stage_checkpoint(id)
@@ -1,10 +1,11 @@
 use super::{DatabaseProviderRO, ProviderFactory, ProviderNodeTypes};
 use crate::{
-    providers::StaticFileProvider, AccountReader, BlockHashReader, BlockIdReader, BlockNumReader,
-    BlockReader, BlockReaderIdExt, BlockSource, ChainSpecProvider, ChangeSetReader, HeaderProvider,
-    ProviderError, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
-    StageCheckpointReader, StateReader, StaticFileProviderFactory, TransactionVariant,
-    TransactionsProvider, WithdrawalsProvider,
+    providers::{state::range::OverlayHashedStateRanges, StaticFileProvider},
+    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
+    BlockSource, ChainSpecProvider, ChangeSetReader, DBProvider, HashedStateRangeReader,
+    HashedStateRanges, HeaderProvider, ProviderError, PruneCheckpointReader, ReceiptProvider,
+    ReceiptProviderIdExt, StageCheckpointReader, StateReader, StaticFileProviderFactory,
+    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use alloy_consensus::{transaction::TransactionMeta, BlockHeader};
 use alloy_eips::{
@@ -30,6 +31,7 @@ use reth_storage_api::{
     StateProvider, StorageChangeSetReader,
 };
 use reth_storage_errors::provider::ProviderResult;
+use reth_trie::HashedPostState;
 use revm_database::states::PlainStorageRevert;
 use std::{
     ops::{Add, Bound, RangeBounds, RangeInclusive, Sub},
@@ -393,6 +395,16 @@ impl<N: ProviderNodeTypes> ConsistentProvider<N> {
         Ok(items)
     }
 
+    /// Returns the hashed state of the in-memory blocks up to and including the given block, which
+    /// is applied on top of the state in the database.
+    fn in_memory_hashed_state(state: &BlockState<N::Primitives>) -> HashedPostState {
+        let mut hashed_state = HashedPostState::default();
+        for block_state in state.chain().collect::<Vec<_>>().into_iter().rev() {
+            hashed_state.extend_ref(block_state.block_ref().hashed_state());
+        }
+        hashed_state
+    }
+
     /// This uses a given [`BlockState`] to initialize a state provider for that block.
     fn block_state_provider_ref(
         &self,
@@ -765,6 +777,10 @@ impl<N: ProviderNodeTypes> BlockNumReader for ConsistentProvider<N> {
         self.storage_provider.last_block_number()
     }
 
+    fn earliest_block_number(&self) -> ProviderResult<BlockNumber> {
+        self.storage_provider.earliest_block_number()
+    }
+
     fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
         self.get_in_memory_or_storage_by_block(
             hash.into(),
@@ -1440,6 +1456,40 @@ impl<N: ProviderNodeTypes> ChangeSetReader for ConsistentProvider<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> HashedStateRangeReader for ConsistentProvider<N> {
+    fn with_hashed_state_at<R>(
+        &self,
+        block_number: BlockNumber,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        if let Some(Some(block_state)) =
+            self.head_block.as_ref().map(|b| b.block_on_chain(block_number.into()))
+        {
+            return f(&OverlayHashedStateRanges::new(
+                self.storage_provider.tx_ref(),
+                Self::in_memory_hashed_state(block_state),
+            ))
+        }
+        self.storage_provider.with_hashed_state_at(block_number, f)
+    }
+
+    fn with_hashed_state_at_hash<R>(
+        &self,
+        block_hash: B256,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        if let Some(Some(block_state)) =
+            self.head_block.as_ref().map(|b| b.block_on_chain(block_hash.into()))
+        {
+            return f(&OverlayHashedStateRanges::new(
+                self.storage_provider.tx_ref(),
+                Self::in_memory_hashed_state(block_state),
+            ))
+        }
+        self.storage_provider.with_hashed_state_at_hash(block_hash, f)
+    }
+}
+
 impl<N: ProviderNodeTypes> AccountReader for ConsistentProvider<N> {
     /// Get basic account information.
     fn basic_account(&self, address: &Address) -> ProviderResult<Option<Account>> {
@@ -2,8 +2,9 @@ use crate::{
     providers::{state::latest::LatestStateProvider, StaticFileProvider},
     to_range,
     traits::{BlockSource, ReceiptProvider},
-    BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider, DatabaseProviderFactory,
-    HashedPostStateProvider, HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider, ProviderError,
+    AddressTransactionsReader, BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider,
+    DatabaseProviderFactory, HashedPostStateProvider, HashedStateRangeReader, HashedStateRanges,
+    HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider, LogIndexReader, ProviderError,
     PruneCheckpointReader, StageCheckpointReader, StateProviderBox, StaticFileProviderFactory,
     TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
@@ -62,6 +63,8 @@ pub struct ProviderFactory<N: NodeTypesWithDB> {
     static_file_provider: StaticFileProvider<N::Primitives>,
     /// Optional pruning configuration
     prune_modes: PruneModes,
+    /// Whether the logs of appended blocks are indexed.
+    log_index: bool,
     /// The node storage handler.
     storage: Arc<N::Storage>,
 }
@@ -85,6 +88,7 @@ impl<N: NodeTypesWithDB> ProviderFactory<N> {
             chain_spec,
             static_file_provider,
             prune_modes: PruneModes::none(),
+            log_index: false,
             storage: Default::default(),
         }
     }
@@ -101,6 +105,19 @@ impl<N: NodeTypesWithDB> ProviderFactory<N> {
         self
     }
 
+    /// Sets whether the logs of appended blocks are indexed, see [`LogIndexWriter`].
+    ///
+    /// [`LogIndexWriter`]: crate::LogIndexWriter
+    pub const fn with_log_index(mut self, log_index: bool) -> Self {
+        self.log_index = log_index;
+        self
+    }
+
+    /// Returns whether the logs of appended blocks are indexed.
+    pub const fn log_index(&self) -> bool {
+        self.log_index
+    }
+
     /// Returns reference to the underlying database.
     pub const fn db_ref(&self) -> &N::DB {
         &self.db
@@ -127,6 +144,7 @@ impl<N: NodeTypesWithDB<DB = Arc<DatabaseEnv>>> ProviderFactory<N> {
             chain_spec,
             static_file_provider,
             prune_modes: PruneModes::none(),
+            log_index: false,
             storage: Default::default(),
         })
     }
@@ -147,7 +165,8 @@ impl<N: ProviderNodeTypes> ProviderFactory<N> {
             self.static_file_provider.clone(),
             self.prune_modes.clone(),
             self.storage.clone(),
-        ))
+        )
+        .with_log_index(self.log_index))
     }
 
     /// Returns a provider with a created `DbTxMut` inside, which allows fetching and updating
@@ -156,13 +175,16 @@ impl<N: ProviderNodeTypes> ProviderFactory<N> {
     /// open.
     #[track_caller]
     pub fn provider_rw(&self) -> ProviderResult<DatabaseProviderRW<N::DB, N>> {
-        Ok(DatabaseProviderRW(DatabaseProvider::new_rw(
-            self.db.tx_mut()?,
-            self.chain_spec.clone(),
-            self.static_file_provider.clone(),
-            self.prune_modes.clone(),
-            self.storage.clone(),
-        )))
+        Ok(DatabaseProviderRW(
+            DatabaseProvider::new_rw(
+                self.db.tx_mut()?,
+                self.chain_spec.clone(),
+                self.static_file_provider.clone(),
+                self.prune_modes.clone(),
+                self.storage.clone(),
+            )
+            .with_log_index(self.log_index),
+        ))
     }
 
     /// State provider for latest block
@@ -356,6 +378,10 @@ impl<N: ProviderNodeTypes> BlockNumReader for ProviderFactory<N> {
         self.provider()?.last_block_number()
     }
 
+    fn earliest_block_number(&self) -> ProviderResult<BlockNumber> {
+        self.provider()?.earliest_block_number()
+    }
+
     fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
         self.provider()?.block_number(hash)
     }
@@ -584,6 +610,66 @@ impl<N: ProviderNodeTypes> BlockBodyIndicesProvider for ProviderFactory<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> AddressTransactionsReader for ProviderFactory<N> {
+    fn address_transactions_before(
+        &self,
+        address: Address,
+        before: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        self.provider()?.address_transactions_before(address, before, limit)
+    }
+
+    fn address_transactions_from(
+        &self,
+        address: Address,
+        from: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        self.provider()?.address_transactions_from(address, from, limit)
+    }
+}
+
+impl<N: ProviderNodeTypes> LogIndexReader for ProviderFactory<N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.provider()?.log_index_range()
+    }
+
+    fn log_address_blocks(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.provider()?.log_address_blocks(address, range)
+    }
+
+    fn log_topic_blocks(
+        &self,
+        topic: B256,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.provider()?.log_topic_blocks(topic, range)
+    }
+}
+
+impl<N: ProviderNodeTypes> HashedStateRangeReader for ProviderFactory<N> {
+    fn with_hashed_state_at<R>(
+        &self,
+        block_number: BlockNumber,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        self.provider()?.with_hashed_state_at(block_number, f)
+    }
+
+    fn with_hashed_state_at_hash<R>(
+        &self,
+        block_hash: B256,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        self.provider()?.with_hashed_state_at_hash(block_hash, f)
+    }
+}
+
 impl<N: ProviderNodeTypes> StageCheckpointReader for ProviderFactory<N> {
     fn get_stage_checkpoint(&self, id: StageId) -> ProviderResult<Option<StageCheckpoint>> {
         self.provider()?.get_stage_checkpoint(id)
@@ -631,12 +717,13 @@ where
     N: NodeTypesWithDB<DB: fmt::Debug, ChainSpec: fmt::Debug, Storage: fmt::Debug>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self { db, chain_spec, static_file_provider, prune_modes, storage } = self;
+        let Self { db, chain_spec, static_file_provider, prune_modes, log_index, storage } = self;
         f.debug_struct("ProviderFactory")
             .field("db", &db)
             .field("chain_spec", &chain_spec)
             .field("static_file_provider", &static_file_provider)
             .field("prune_modes", &prune_modes)
+            .field("log_index", &log_index)
             .field("storage", &storage)
             .finish()
     }
@@ -649,6 +736,7 @@ impl<N: NodeTypesWithDB> Clone for ProviderFactory<N> {
             chain_spec: self.chain_spec.clone(),
             static_file_provider: self.static_file_provider.clone(),
             prune_modes: self.prune_modes.clone(),
+            log_index: self.log_index,
             storage: self.storage.clone(),
         }
     }
@@ -660,10 +748,12 @@ mod tests {
     use crate::{
         providers::{StaticFileProvider, StaticFileWriter},
         test_utils::{blocks::TEST_BLOCK, create_test_provider_factory, MockNodeTypesWithDB},
-        BlockHashReader, BlockNumReader, BlockWriter, DBProvider, HeaderSyncGapProvider,
-        StorageLocation, TransactionsProvider,
+        AddressTransactionsReader, AddressTransactionsWriter, BlockHashReader, BlockNumReader,
+        BlockWriter, DBProvider, HashedStateRangeReader, HeaderSyncGapProvider, LogIndexWriter,
+        PruneCheckpointWriter, StageCheckpointWriter, StorageLocation, TransactionsProvider,
     };
-    use alloy_primitives::{TxNumber, B256, U256};
+    use alloy_consensus::constants::EMPTY_ROOT_HASH;
+    use alloy_primitives::{Address, Bytes, Log, TxNumber, B256, U256};
     use assert_matches::assert_matches;
     use rand::Rng;
     use reth_chainspec::ChainSpecBuilder;
@@ -672,12 +762,14 @@ mod tests {
         tables,
         test_utils::{create_test_static_files_dir, ERROR_TEMPDIR},
     };
-    use reth_primitives::StaticFileSegment;
+    use reth_db_api::transaction::DbTxMut;
+    use reth_primitives::{Account, StaticFileSegment, StorageEntry};
     use reth_primitives_traits::SignedTransaction;
-    use reth_prune_types::{PruneMode, PruneModes};
+    use reth_prune_types::{PruneMode, PruneModes, MINIMUM_PRUNING_DISTANCE};
     use reth_storage_errors::provider::ProviderError;
     use reth_testing_utils::generators::{self, random_block, random_header, BlockParams};
-    use std::{ops::RangeInclusive, sync::Arc};
+    use reth_trie::test_utils::storage_root_prehashed;
+    use std::{collections::BTreeSet, ops::RangeInclusive, sync::Arc};
     use tokio::sync::watch;
 
     #[test]
@@ -834,4 +926,187 @@ mod tests {
         assert_eq!(gap.local_head, head);
         assert_eq!(gap.target.tip(), consensus_tip.into());
     }
+
+    #[test]
+    fn address_transactions() {
+        let factory = create_test_provider_factory();
+
+        let sender = Address::random();
+        let recipient = Address::random();
+        // the sender appears in every transaction, the recipient in every third one, which spans
+        // multiple shards for the sender
+        let tx_count = 5000;
+        let appearances = (0..tx_count).map(|tx_number| {
+            let mut addresses = BTreeSet::from([sender]);
+            if tx_number % 3 == 0 {
+                addresses.insert(recipient);
+            }
+            (tx_number, addresses)
+        });
+
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.insert_address_appearances(appearances).unwrap();
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.address_transactions_before(sender, 4002, 3).unwrap(),
+            vec![4001, 4000, 3999]
+        );
+        assert_eq!(
+            provider.address_transactions_before(recipient, 10, 10).unwrap(),
+            vec![9, 6, 3, 0]
+        );
+        assert_eq!(provider.address_transactions_before(sender, 0, 10).unwrap(), vec![]);
+        assert_eq!(
+            provider.address_transactions_from(sender, 1999, 3).unwrap(),
+            vec![1999, 2000, 2001]
+        );
+        assert_eq!(
+            provider.address_transactions_from(recipient, 4990, 10).unwrap(),
+            vec![4992, 4995, 4998]
+        );
+        assert_eq!(
+            provider.address_transactions_from(Address::random(), 0, 10).unwrap(),
+            Vec::<TxNumber>::new()
+        );
+
+        // unwinding removes all appearances from the given transaction onwards
+        let provider_rw = factory.provider_rw().unwrap();
+        assert_eq!(provider_rw.unwind_address_appearances(1000).unwrap(), 4000 + 1333);
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.address_transactions_from(sender, 998, 10).unwrap(), vec![998, 999]);
+        assert_eq!(
+            provider.address_transactions_before(recipient, TxNumber::MAX, 2).unwrap(),
+            vec![999, 996]
+        );
+    }
+
+    #[test]
+    fn append_log_indices() {
+        let factory = create_test_provider_factory();
+        let address = Address::random();
+        let log = Log::new_unchecked(address, vec![B256::random()], Bytes::new());
+
+        // the log index is disabled by default
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.save_stage_checkpoint(StageId::IndexLogs, StageCheckpoint::new(0)).unwrap();
+        provider_rw.append_log_indices(1, [&log]).unwrap();
+        assert_eq!(
+            provider_rw.get_stage_checkpoint(StageId::IndexLogs).unwrap().unwrap().block_number,
+            0
+        );
+        provider_rw.commit().unwrap();
+        assert_eq!(factory.log_index_range().unwrap(), None);
+
+        let factory = factory.with_log_index(true);
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.append_log_indices(1, [&log]).unwrap();
+        provider_rw.append_log_indices(2, []).unwrap();
+        // blocks above a gap are left to the stage
+        provider_rw.append_log_indices(4, [&log]).unwrap();
+        provider_rw.commit().unwrap();
+
+        assert_eq!(factory.log_index_range().unwrap(), Some(0..=2));
+        assert_eq!(factory.log_address_blocks(address, 0..=10).unwrap(), vec![1]);
+        assert_eq!(factory.log_topic_blocks(log.topics()[0], 0..=10).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn earliest_block_number() {
+        let factory = create_test_provider_factory();
+        assert_eq!(factory.earliest_block_number().unwrap(), 0);
+
+        // receipts are pruned up to the checkpoint
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(100_000)).unwrap();
+        provider_rw
+            .save_prune_checkpoint(
+                PruneSegment::Receipts,
+                PruneCheckpoint {
+                    block_number: Some(9),
+                    tx_number: None,
+                    prune_mode: PruneMode::Before(10),
+                },
+            )
+            .unwrap();
+        provider_rw.commit().unwrap();
+        assert_eq!(factory.earliest_block_number().unwrap(), 10);
+
+        // and up to the target of the prune mode, before the pruner caught up
+        let factory = factory.with_prune_modes(PruneModes {
+            receipts: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
+            ..PruneModes::none()
+        });
+        assert_eq!(
+            factory.earliest_block_number().unwrap(),
+            100_000 - MINIMUM_PRUNING_DISTANCE + 1
+        );
+    }
+
+    #[test]
+    fn hashed_state_range() {
+        let factory = create_test_provider_factory();
+
+        let mut accounts = (0..10)
+            .map(|nonce| (B256::random(), Account { nonce, ..Default::default() }))
+            .collect::<Vec<_>>();
+        accounts.sort_unstable_by_key(|(hashed_address, _)| *hashed_address);
+        let mut storage =
+            (1..=5).map(|value| (B256::random(), U256::from(value))).collect::<Vec<_>>();
+        storage.sort_unstable_by_key(|(hashed_slot, _)| *hashed_slot);
+        let storage_address = accounts[3].0;
+
+        let provider_rw = factory.provider_rw().unwrap();
+        for (hashed_address, account) in &accounts {
+            provider_rw.tx_ref().put::<tables::HashedAccounts>(*hashed_address, *account).unwrap();
+        }
+        for (hashed_slot, value) in &storage {
+            provider_rw
+                .tx_ref()
+                .put::<tables::HashedStorages>(
+                    storage_address,
+                    StorageEntry { key: *hashed_slot, value: *value },
+                )
+                .unwrap();
+        }
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let range = provider.hashed_accounts_range(0, accounts[2].0, 3).unwrap();
+        assert_eq!(
+            range.iter().map(|(hashed_address, _)| *hashed_address).collect::<Vec<_>>(),
+            accounts[2..5].iter().map(|(hashed_address, _)| *hashed_address).collect::<Vec<_>>()
+        );
+        assert_eq!(range[0].1.nonce, accounts[2].1.nonce);
+        assert_eq!(range[0].1.storage_root, EMPTY_ROOT_HASH);
+        // there are no storage trie nodes in the database, so the root is computed from the slots
+        assert_eq!(range[1].1.storage_root, storage_root_prehashed(storage.clone()));
+
+        assert_eq!(
+            provider.hashed_storage_range(0, storage_address, storage[1].0, 10).unwrap(),
+            storage[1..]
+        );
+        assert_eq!(
+            provider.hashed_storage_range(0, storage_address, B256::ZERO, 2).unwrap(),
+            storage[..2]
+        );
+        assert_eq!(
+            provider.hashed_storage_range(0, accounts[0].0, B256::ZERO, 2).unwrap(),
+            Vec::<(B256, U256)>::new()
+        );
+
+        assert_matches!(
+            provider.hashed_accounts_range(1, B256::ZERO, 10),
+            Err(ProviderError::HeaderNotFound(_))
+        );
+        assert_matches!(
+            provider.with_hashed_state_at_hash(B256::random(), |state| {
+                state.accounts_range(B256::ZERO, 10)
+            }),
+            Err(ProviderError::BlockHashNotFound(_))
+        );
+    }
 }
@@ -2,6 +2,7 @@ use crate::{
     bundle_state::StorageRevertsIter,
     providers::{
         database::{chain::ChainStorage, metrics},
+        state::range::OverlayHashedStateRanges,
         static_file::StaticFileWriter,
         NodeTypesForProvider, StaticFileProvider,
     },
@@ -9,14 +10,15 @@ use crate::{
     traits::{
         AccountExtReader, BlockSource, ChangeSetReader, ReceiptProvider, StageCheckpointWriter,
     },
-    AccountReader, BlockBodyWriter, BlockExecutionWriter, BlockHashReader, BlockNumReader,
-    BlockReader, BlockWriter, BundleStateInit, ChainStateBlockReader, ChainStateBlockWriter,
-    DBProvider, HashingWriter, HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider,
+    AccountReader, AddressTransactionsReader, AddressTransactionsWriter, BlockBodyWriter,
+    BlockExecutionWriter, BlockHashReader, BlockNumReader, BlockReader, BlockWriter,
+    BundleStateInit, ChainStateBlockReader, ChainStateBlockWriter, DBProvider,
+    HashedStateRangeReader, HashingWriter, HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider,
     HistoricalStateProvider, HistoricalStateProviderRef, HistoryWriter, LatestStateProvider,
-    LatestStateProviderRef, OriginalValuesKnown, ProviderError, PruneCheckpointReader,
-    PruneCheckpointWriter, RevertsInit, StageCheckpointReader, StateCommitmentProvider,
-    StateProviderBox, StateWriter, StaticFileProviderFactory, StatsReader, StorageLocation,
-    StorageReader, StorageTrieWriter, TransactionVariant, TransactionsProvider,
+    LatestStateProviderRef, LogIndexReader, LogIndexWriter, OriginalValuesKnown, ProviderError,
+    PruneCheckpointReader, PruneCheckpointWriter, RevertsInit, StageCheckpointReader,
+    StateCommitmentProvider, StateProviderBox, StateWriter, StaticFileProviderFactory, StatsReader,
+    StorageLocation, StorageReader, StorageTrieWriter, TransactionVariant, TransactionsProvider,
     TransactionsProviderExt, TrieWriter, WithdrawalsProvider,
 };
 use alloy_consensus::{transaction::TransactionMeta, BlockHeader, Header, TxReceipt};
@@ -24,7 +26,7 @@ use alloy_eips::{eip2718::Encodable2718, eip4895::Withdrawals, BlockHashOrNumber
 use alloy_primitives::{
     keccak256,
     map::{hash_map, B256Map, HashMap, HashSet},
-    Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256,
+    Address, BlockHash, BlockNumber, Log, TxHash, TxNumber, B256, U256,
 };
 use itertools::Itertools;
 use rayon::slice::ParallelSliceMut;
@@ -52,18 +54,18 @@ use reth_primitives::{
 };
 use reth_primitives_traits::{Block as _, BlockBody as _, SignedTransaction};
 use reth_prune_types::{
-    PruneCheckpoint, PruneMode, PruneModes, PruneSegment, MINIMUM_PRUNING_DISTANCE,
+    PruneCheckpoint, PruneMode, PruneModes, PrunePurpose, PruneSegment, MINIMUM_PRUNING_DISTANCE,
 };
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::{
-    BlockBodyIndicesProvider, BlockBodyReader, NodePrimitivesProvider, OmmersProvider,
-    StateProvider, StorageChangeSetReader, TryIntoHistoricalStateProvider,
+    BlockBodyIndicesProvider, BlockBodyReader, HashedStateRanges, NodePrimitivesProvider,
+    OmmersProvider, StateProvider, StorageChangeSetReader, TryIntoHistoricalStateProvider,
 };
 use reth_storage_errors::provider::{ProviderResult, RootMismatch};
 use reth_trie::{
     prefix_set::{PrefixSet, PrefixSetMut, TriePrefixSets},
     updates::{StorageTrieUpdates, TrieUpdates},
-    HashedPostStateSorted, Nibbles, StateRoot, StoredNibbles,
+    HashedPostState, HashedPostStateSorted, Nibbles, StateRoot, StoredNibbles,
 };
 use reth_trie_db::{DatabaseStateRoot, DatabaseStorageTrieCursor};
 use revm_database::states::{
@@ -145,6 +147,8 @@ pub struct DatabaseProvider<TX, N: NodeTypes> {
     static_file_provider: StaticFileProvider<N::Primitives>,
     /// Pruning configuration
     prune_modes: PruneModes,
+    /// Whether the logs of appended blocks are indexed.
+    log_index: bool,
     /// Node storage handler.
     storage: Arc<N::Storage>,
 }
@@ -154,6 +158,12 @@ impl<TX, N: NodeTypes> DatabaseProvider<TX, N> {
     pub const fn prune_modes_ref(&self) -> &PruneModes {
         &self.prune_modes
     }
+
+    /// Sets whether the logs of appended blocks are indexed.
+    pub const fn with_log_index(mut self, log_index: bool) -> Self {
+        self.log_index = log_index;
+        self
+    }
 }
 
 impl<TX: DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
@@ -179,6 +189,17 @@ impl<TX: DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
         // +1 as the changeset that we want is the one that was applied after this block.
         block_number += 1;
 
+        Ok(Box::new(self.history_by_block_number_ref(block_number)?))
+    }
+
+    /// Historical state provider for the state at the start of the given block number.
+    ///
+    /// The lowest blocks at which the state is available are set from the prune checkpoints of the
+    /// account and storage history.
+    fn history_by_block_number_ref(
+        &self,
+        block_number: BlockNumber,
+    ) -> ProviderResult<HistoricalStateProviderRef<'_, Self>> {
         let account_history_prune_checkpoint =
             self.get_prune_checkpoint(PruneSegment::AccountHistory)?;
         let storage_history_prune_checkpoint =
@@ -203,7 +224,17 @@ impl<TX: DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
             );
         }
 
-        Ok(Box::new(state_provider))
+        Ok(state_provider)
+    }
+
+    /// Returns `true` if the state in the database is the state after the given block.
+    fn is_latest_state(&self, block_number: BlockNumber) -> ProviderResult<bool> {
+        let last_block_number = self.last_block_number()?;
+        if block_number > last_block_number {
+            return Err(ProviderError::HeaderNotFound(block_number.into()))
+        }
+
+        Ok(block_number == self.best_block_number()? && block_number == last_block_number)
     }
 
     #[cfg(feature = "test-utils")]
@@ -243,7 +274,7 @@ impl<TX: DbTxMut, N: NodeTypes> DatabaseProvider<TX, N> {
         prune_modes: PruneModes,
         storage: Arc<N::Storage>,
     ) -> Self {
-        Self { tx, chain_spec, static_file_provider, prune_modes, storage }
+        Self { tx, chain_spec, static_file_provider, prune_modes, log_index: false, storage }
     }
 }
 
@@ -501,6 +532,45 @@ where
     Ok(Vec::new())
 }
 
+/// For a given key, returns all block numbers of a block index that are within the given range.
+///
+/// K - Sharded key subtype.
+/// T - Table to walk over.
+/// C - Cursor implementation.
+///
+/// This function walks the shards of the key, starting from the first shard that can contain the
+/// start of the range.
+fn index_blocks_in_range<K, T, C>(
+    cursor: &mut C,
+    key: K,
+    range: RangeInclusive<BlockNumber>,
+) -> ProviderResult<Vec<BlockNumber>>
+where
+    K: Clone + PartialEq,
+    T: Table<Key = ShardedKey<K>, Value = BlockNumberList>,
+    C: DbCursorRO<T>,
+{
+    let mut blocks = Vec::new();
+    let mut shard = cursor.seek(ShardedKey::new(key.clone(), *range.start()))?;
+    while let Some((sharded_key, list)) = shard {
+        // If the shard does not belong to the key, break.
+        if sharded_key.key != key {
+            break
+        }
+
+        for block in list.iter().skip_while(|block| block < range.start()) {
+            if block > *range.end() {
+                return Ok(blocks)
+            }
+            blocks.push(block);
+        }
+
+        shard = cursor.next()?;
+    }
+
+    Ok(blocks)
+}
+
 impl<TX: DbTx + 'static, N: NodeTypesForProvider> DatabaseProvider<TX, N> {
     /// Creates a provider with an inner read-only transaction.
     pub const fn new(
@@ -510,7 +580,7 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> DatabaseProvider<TX, N> {
         prune_modes: PruneModes,
         storage: Arc<N::Storage>,
     ) -> Self {
-        Self { tx, chain_spec, static_file_provider, prune_modes, storage }
+        Self { tx, chain_spec, static_file_provider, prune_modes, log_index: false, storage }
     }
 
     /// Consume `DbTx` or `DbTxMut`.
@@ -936,6 +1006,144 @@ impl<TX: DbTx, N: NodeTypes> ChangeSetReader for DatabaseProvider<TX, N> {
     }
 }
 
+impl<TX: DbTx, N: NodeTypes> AddressTransactionsReader for DatabaseProvider<TX, N> {
+    fn address_transactions_before(
+        &self,
+        address: Address,
+        before: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        let mut transactions = Vec::new();
+        if before == 0 || limit == 0 {
+            return Ok(transactions)
+        }
+
+        let mut cursor = self.tx.cursor_read::<tables::AddressTransactions>()?;
+        // The first shard with the highest number greater than or equal to `before - 1` contains
+        // the closest appearance, all earlier shards are visited by walking backwards.
+        let mut shard = cursor.seek(ShardedKey::new(address, before - 1))?;
+        while let Some((sharded_key, list)) = shard {
+            if sharded_key.key != address {
+                break
+            }
+
+            let shard_transactions = list.iter().take_while(|tx| *tx < before).collect::<Vec<_>>();
+            for tx in shard_transactions.into_iter().rev() {
+                transactions.push(tx);
+                if transactions.len() == limit {
+                    return Ok(transactions)
+                }
+            }
+
+            shard = cursor.prev()?;
+        }
+
+        Ok(transactions)
+    }
+
+    fn address_transactions_from(
+        &self,
+        address: Address,
+        from: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        let mut transactions = Vec::new();
+        if limit == 0 {
+            return Ok(transactions)
+        }
+
+        let mut cursor = self.tx.cursor_read::<tables::AddressTransactions>()?;
+        let mut shard = cursor.seek(ShardedKey::new(address, from))?;
+        while let Some((sharded_key, list)) = shard {
+            if sharded_key.key != address {
+                break
+            }
+
+            for tx in list.iter().skip_while(|tx| *tx < from) {
+                transactions.push(tx);
+                if transactions.len() == limit {
+                    return Ok(transactions)
+                }
+            }
+
+            shard = cursor.next()?;
+        }
+
+        Ok(transactions)
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypes> LogIndexReader for DatabaseProvider<TX, N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        // Blocks are not indexed when the whole log index is pruned, even though the stage
+        // checkpoint keeps moving.
+        if !self.log_index || self.prune_modes.log_index.is_some_and(|mode| mode.is_full()) {
+            return Ok(None)
+        }
+
+        let Some(checkpoint) = self.get_stage_checkpoint(StageId::IndexLogs)? else {
+            return Ok(None)
+        };
+        // Indexed blocks are only useful as long as their receipts are available.
+        let mut highest_pruned_block = None;
+        for segment in [PruneSegment::LogIndex, PruneSegment::Receipts] {
+            let block_number =
+                self.get_prune_checkpoint(segment)?.and_then(|checkpoint| checkpoint.block_number);
+            highest_pruned_block = highest_pruned_block.max(block_number);
+        }
+        let lowest_available_block =
+            highest_pruned_block.map_or(0, |block_number| block_number + 1);
+
+        Ok((lowest_available_block <= checkpoint.block_number)
+            .then(|| lowest_available_block..=checkpoint.block_number))
+    }
+
+    fn log_address_blocks(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        let mut cursor = self.tx.cursor_read::<tables::LogAddressIndex>()?;
+        index_blocks_in_range(&mut cursor, address, range)
+    }
+
+    fn log_topic_blocks(
+        &self,
+        topic: B256,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        let mut cursor = self.tx.cursor_read::<tables::LogTopicIndex>()?;
+        index_blocks_in_range(&mut cursor, topic, range)
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypes> HashedStateRangeReader for DatabaseProvider<TX, N> {
+    fn with_hashed_state_at<R>(
+        &self,
+        block_number: BlockNumber,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        if self.is_latest_state(block_number)? {
+            return f(&OverlayHashedStateRanges::new(&self.tx, HashedPostState::default()))
+        }
+
+        // +1 as the changeset that we want is the one that was applied after this block.
+        self.history_by_block_number_ref(block_number + 1)?.with_hashed_state_ranges(f)
+    }
+
+    fn with_hashed_state_at_hash<R>(
+        &self,
+        block_hash: B256,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        let block_number = self
+            .tx
+            .get::<tables::HeaderNumbers>(block_hash)?
+            .ok_or(ProviderError::BlockHashNotFound(block_hash))?;
+        self.with_hashed_state_at(block_number, f)
+    }
+}
+
 impl<TX: DbTx + 'static, N: NodeTypesForProvider> HeaderSyncGapProvider
     for DatabaseProvider<TX, N>
 {
@@ -1143,6 +1351,34 @@ impl<TX: DbTx + 'static, N: NodeTypes> BlockNumReader for DatabaseProvider<TX, N
             .unwrap_or_default())
     }
 
+    fn earliest_block_number(&self) -> ProviderResult<BlockNumber> {
+        // bodies and receipts below the lowest static files are not available
+        let mut earliest = [StaticFileSegment::Transactions, StaticFileSegment::Receipts]
+            .into_iter()
+            .filter_map(|segment| self.static_file_provider.get_lowest_static_file_block(segment))
+            .max()
+            .unwrap_or_default();
+
+        // receipts are pruned up to the checkpoints, and soon up to the target of the prune mode
+        for segment in [PruneSegment::Receipts, PruneSegment::ContractLogs] {
+            if let Some(block) =
+                self.get_prune_checkpoint(segment)?.and_then(|checkpoint| checkpoint.block_number)
+            {
+                earliest = earliest.max(block + 1);
+            }
+        }
+        if let Some(mode) = self.prune_modes.receipts {
+            let tip = self.best_block_number()?;
+            if let Some((block, _)) =
+                mode.prune_target_block(tip, PruneSegment::Receipts, PrunePurpose::User)?
+            {
+                earliest = earliest.max(block + 1);
+            }
+        }
+
+        Ok(earliest)
+    }
+
     fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
         Ok(self.tx.get::<tables::HeaderNumbers>(hash)?)
     }
@@ -2608,6 +2844,190 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HashingWriter for DatabaseProvi
     }
 }
 
+impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> AddressTransactionsWriter
+    for DatabaseProvider<TX, N>
+{
+    fn insert_address_appearances(
+        &self,
+        appearances: impl IntoIterator<Item = (TxNumber, BTreeSet<Address>)>,
+    ) -> ProviderResult<()> {
+        let mut appearances_cursor = self.tx.cursor_dup_write::<tables::AddressAppearances>()?;
+        let mut index_updates = BTreeMap::<Address, Vec<TxNumber>>::new();
+        for (tx, addresses) in appearances {
+            for address in addresses {
+                appearances_cursor.append_dup(tx, address)?;
+                index_updates.entry(address).or_default().push(tx);
+            }
+        }
+
+        self.append_history_index::<_, tables::AddressTransactions>(index_updates, ShardedKey::new)
+    }
+
+    fn unwind_address_appearances(&self, from_tx: TxNumber) -> ProviderResult<usize> {
+        // Lowest removed transaction number for each address.
+        let mut lowest_removed = BTreeMap::<Address, TxNumber>::new();
+        let removed = self.take::<tables::AddressAppearances>(from_tx..)?;
+        for (tx, address) in &removed {
+            lowest_removed.entry(*address).or_insert(*tx);
+        }
+
+        let mut cursor = self.tx.cursor_write::<tables::AddressTransactions>()?;
+        for (address, rem_index) in lowest_removed {
+            let partial_shard = unwind_history_shards::<_, tables::AddressTransactions, _>(
+                &mut cursor,
+                ShardedKey::last(address),
+                rem_index,
+                |sharded_key| sharded_key.key == address,
+            )?;
+
+            // Check the last returned partial shard.
+            // If it's not empty, the shard needs to be reinserted.
+            if !partial_shard.is_empty() {
+                cursor.insert(
+                    ShardedKey::last(address),
+                    &BlockNumberList::new_pre_sorted(partial_shard),
+                )?;
+            }
+        }
+
+        Ok(removed.len())
+    }
+}
+
+impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> LogIndexWriter
+    for DatabaseProvider<TX, N>
+{
+    fn insert_log_indices<'a>(
+        &self,
+        logs: impl IntoIterator<Item = (BlockNumber, &'a Log)>,
+    ) -> ProviderResult<()> {
+        let mut address_updates = BTreeMap::<Address, Vec<BlockNumber>>::new();
+        let mut topic_updates = BTreeMap::<B256, Vec<BlockNumber>>::new();
+        for (block_number, log) in logs {
+            let blocks = address_updates.entry(log.address).or_default();
+            if blocks.last() != Some(&block_number) {
+                blocks.push(block_number);
+            }
+            for topic in log.topics() {
+                let blocks = topic_updates.entry(*topic).or_default();
+                if blocks.last() != Some(&block_number) {
+                    blocks.push(block_number);
+                }
+            }
+        }
+
+        self.append_history_index::<_, tables::LogAddressIndex>(address_updates, ShardedKey::new)?;
+        self.append_history_index::<_, tables::LogTopicIndex>(topic_updates, ShardedKey::new)
+    }
+
+    fn unwind_log_indices_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<usize> {
+        // Lowest unwound block number for each address and topic.
+        let mut lowest_addresses = BTreeMap::<Address, BlockNumber>::new();
+        let mut lowest_topics = BTreeMap::<B256, BlockNumber>::new();
+        let mut logs = 0;
+        for (block_number, body_indices) in range.clone().zip(self.block_body_indices_range(range)?)
+        {
+            for receipt in self.receipts_by_tx_range(body_indices.tx_num_range())? {
+                for log in receipt.logs() {
+                    lowest_addresses.entry(log.address).or_insert(block_number);
+                    for topic in log.topics() {
+                        lowest_topics.entry(*topic).or_insert(block_number);
+                    }
+                    logs += 1;
+                }
+            }
+        }
+
+        let mut cursor = self.tx.cursor_write::<tables::LogAddressIndex>()?;
+        for (address, rem_index) in lowest_addresses {
+            let partial_shard = unwind_history_shards::<_, tables::LogAddressIndex, _>(
+                &mut cursor,
+                ShardedKey::last(address),
+                rem_index,
+                |sharded_key| sharded_key.key == address,
+            )?;
+
+            // Check the last returned partial shard.
+            // If it's not empty, the shard needs to be reinserted.
+            if !partial_shard.is_empty() {
+                cursor.insert(
+                    ShardedKey::last(address),
+                    &BlockNumberList::new_pre_sorted(partial_shard),
+                )?;
+            }
+        }
+
+        let mut cursor = self.tx.cursor_write::<tables::LogTopicIndex>()?;
+        for (topic, rem_index) in lowest_topics {
+            let partial_shard = unwind_history_shards::<_, tables::LogTopicIndex, _>(
+                &mut cursor,
+                ShardedKey::last(topic),
+                rem_index,
+                |sharded_key| sharded_key.key == topic,
+            )?;
+
+            // Check the last returned partial shard.
+            // If it's not empty, the shard needs to be reinserted.
+            if !partial_shard.is_empty() {
+                cursor.insert(
+                    ShardedKey::last(topic),
+                    &BlockNumberList::new_pre_sorted(partial_shard),
+                )?;
+            }
+        }
+
+        Ok(logs)
+    }
+
+    fn append_log_indices<'a>(
+        &self,
+        block_number: BlockNumber,
+        logs: impl IntoIterator<Item = &'a Log>,
+    ) -> ProviderResult<()> {
+        if !self.log_index {
+            return Ok(())
+        }
+
+        // The block can only be indexed if all blocks below it are, otherwise the gap is left to
+        // the stage.
+        let Some(checkpoint) = self
+            .get_stage_checkpoint(StageId::IndexLogs)?
+            .filter(|checkpoint| checkpoint.block_number + 1 == block_number)
+        else {
+            return Ok(())
+        };
+
+        // Blocks are not indexed when the whole log index is pruned, even though the stage
+        // checkpoint keeps moving.
+        if !self.prune_modes.log_index.is_some_and(|mode| mode.is_full()) {
+            self.insert_log_indices(logs.into_iter().map(|log| (block_number, log)))?;
+        }
+
+        self.save_stage_checkpoint(
+            StageId::IndexLogs,
+            StageCheckpoint { block_number, ..checkpoint },
+        )
+    }
+
+    fn unwind_log_indices_above(&self, block_number: BlockNumber) -> ProviderResult<()> {
+        let Some(checkpoint) = self
+            .get_stage_checkpoint(StageId::IndexLogs)?
+            .filter(|checkpoint| checkpoint.block_number > block_number)
+        else {
+            return Ok(())
+        };
+
+        self.unwind_log_indices_range(block_number + 1..=checkpoint.block_number)?;
+        self.save_stage_checkpoint(
+            StageId::IndexLogs,
+            StageCheckpoint { block_number, ..checkpoint },
+        )
+    }
+}
+
 impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HistoryWriter for DatabaseProvider<TX, N> {
     fn unwind_account_history_indices<'a>(
         &self,
@@ -2754,6 +3174,9 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockExecu
 
         self.unwind_trie_state_range(range.clone())?;
 
+        // must be unwound before the receipts are removed
+        self.unwind_log_indices_above(block)?;
+
         // get execution res
         let execution_state = self.take_state_above(block, remove_from)?;
 
@@ -2778,6 +3201,9 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockExecu
 
         self.unwind_trie_state_range(range)?;
 
+        // must be unwound before the receipts are removed
+        self.unwind_log_indices_above(block)?;
+
         // remove execution res
         self.remove_state_above(block, remove_from)?;
 
@@ -1,6 +1,7 @@
 use crate::{
-    providers::state::macros::delegate_provider_impls, AccountReader, BlockHashReader,
-    HashedPostStateProvider, ProviderError, StateProvider, StateRootProvider,
+    providers::state::{macros::delegate_provider_impls, range::OverlayHashedStateRanges},
+    AccountReader, BlockHashReader, HashedPostStateProvider, ProviderError, StateProvider,
+    StateRootProvider,
 };
 use alloy_eips::merge::EPOCH_SLOTS;
 use alloy_primitives::{map::B256Map, Address, BlockNumber, Bytes, StorageKey, StorageValue, B256};
@@ -13,7 +14,8 @@ use reth_db_api::{
 };
 use reth_primitives::{Account, Bytecode};
 use reth_storage_api::{
-    BlockNumReader, DBProvider, StateCommitmentProvider, StateProofProvider, StorageRootProvider,
+    BlockNumReader, DBProvider, HashedStateRanges, StateCommitmentProvider, StateProofProvider,
+    StorageRootProvider,
 };
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::{
@@ -110,6 +112,16 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
         )
     }
 
+    /// Calls `f` with the hashed state at the start of the block.
+    ///
+    /// The revert state is only calculated once for all ranges that are read.
+    pub fn with_hashed_state_ranges<R>(
+        &self,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        f(&OverlayHashedStateRanges::new(self.tx(), self.revert_state()?))
+    }
+
     /// Checks and returns `true` if distance to historical block exceeds the provided limit.
     fn check_distance_against_limit(&self, limit: u64) -> ProviderResult<bool> {
         let tip = self.provider.last_block_number()?;
@@ -0,0 +1,110 @@
+//! Iteration over ranges of the hashed state.
+
+use alloy_primitives::{B256, U256};
+use reth_db_api::transaction::DbTx;
+use reth_storage_api::HashedStateRanges;
+use reth_storage_errors::provider::{ProviderError, ProviderResult};
+use reth_trie::{
+    hashed_cursor::{HashedCursor, HashedCursorFactory, HashedPostStateCursorFactory},
+    prefix_set::TriePrefixSetsMut,
+    proof::Proof,
+    HashedPostState, HashedPostStateSorted, MultiProof, MultiProofTargets, StorageRoot,
+    TrieAccount,
+};
+use reth_trie_db::{DatabaseHashedCursorFactory, DatabaseStorageRoot, DatabaseTrieCursorFactory};
+
+/// The hashed state in the database with an overlay applied on top.
+///
+/// The storage roots of the accounts are calculated from the storage tries in the database, with
+/// the slots changed by the overlay being recomputed.
+pub(crate) struct OverlayHashedStateRanges<'a, TX> {
+    tx: &'a TX,
+    prefix_sets: TriePrefixSetsMut,
+    overlay: HashedPostStateSorted,
+}
+
+impl<'a, TX: DbTx> OverlayHashedStateRanges<'a, TX> {
+    /// Creates the ranges of the hashed state in the database with the overlay applied on top.
+    pub(crate) fn new(tx: &'a TX, overlay: HashedPostState) -> Self {
+        let prefix_sets = overlay.construct_prefix_sets();
+        Self { tx, prefix_sets, overlay: overlay.into_sorted() }
+    }
+}
+
+impl<TX: DbTx> HashedStateRanges for OverlayHashedStateRanges<'_, TX> {
+    fn accounts_range(
+        &self,
+        start: B256,
+        limit: usize,
+    ) -> ProviderResult<Vec<(B256, TrieAccount)>> {
+        let cursor_factory = HashedPostStateCursorFactory::new(
+            DatabaseHashedCursorFactory::new(self.tx),
+            &self.overlay,
+        );
+
+        let mut accounts = Vec::new();
+        let mut cursor = cursor_factory.hashed_account_cursor()?;
+        let mut entry = cursor.seek(start)?;
+        while let Some((hashed_address, account)) = entry {
+            if accounts.len() == limit {
+                break
+            }
+
+            let prefix_set = self
+                .prefix_sets
+                .storage_prefix_sets
+                .get(&hashed_address)
+                .map(|prefix_set| prefix_set.clone().freeze())
+                .unwrap_or_default();
+            let storage_root = StorageRoot::from_tx_hashed(self.tx, hashed_address)
+                .with_hashed_cursor_factory(cursor_factory.clone())
+                .with_prefix_set(prefix_set)
+                .root()
+                .map_err(|err| ProviderError::Database(err.into()))?;
+            accounts.push((hashed_address, account.into_trie_account(storage_root)));
+
+            entry = cursor.next()?;
+        }
+
+        Ok(accounts)
+    }
+
+    fn storage_range(
+        &self,
+        hashed_address: B256,
+        start: B256,
+        limit: usize,
+    ) -> ProviderResult<Vec<(B256, U256)>> {
+        let cursor_factory = HashedPostStateCursorFactory::new(
+            DatabaseHashedCursorFactory::new(self.tx),
+            &self.overlay,
+        );
+
+        let mut slots = Vec::new();
+        let mut cursor = cursor_factory.hashed_storage_cursor(hashed_address)?;
+        let mut entry = cursor.seek(start)?;
+        while let Some(slot) = entry {
+            if slots.len() == limit {
+                break
+            }
+
+            slots.push(slot);
+            entry = cursor.next()?;
+        }
+
+        Ok(slots)
+    }
+
+    fn state_multiproof(&self, targets: MultiProofTargets) -> ProviderResult<MultiProof> {
+        Proof::new(
+            DatabaseTrieCursorFactory::new(self.tx),
+            HashedPostStateCursorFactory::new(
+                DatabaseHashedCursorFactory::new(self.tx),
+                &self.overlay,
+            ),
+        )
+        .with_prefix_sets_mut(self.prefix_sets.clone())
+        .multiproof(targets)
+        .map_err(ProviderError::from)
+    }
+}
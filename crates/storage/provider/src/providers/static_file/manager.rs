@@ -225,6 +225,8 @@ pub struct StaticFileProviderInner<N> {
     /// Maintains a map which allows for concurrent access to different `NippyJars`, over different
     /// segments and ranges.
     map: DashMap<(BlockNumber, StaticFileSegment), LoadedJar>,
+    /// Min static file block for each segment
+    static_files_min_block: RwLock<HashMap<StaticFileSegment, u64>>,
     /// Max static file block for each segment
     static_files_max_block: RwLock<HashMap<StaticFileSegment, u64>>,
     /// Available static file block ranges on disk indexed by max transactions.
@@ -257,6 +259,7 @@ impl<N: NodePrimitives> StaticFileProviderInner<N> {
         let provider = Self {
             map: Default::default(),
             writers: Default::default(),
+            static_files_min_block: Default::default(),
             static_files_max_block: Default::default(),
             static_files_tx_index: Default::default(),
             path: path.as_ref().to_path_buf(),
@@ -522,6 +525,7 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         segment: StaticFileSegment,
         segment_max_block: Option<BlockNumber>,
     ) -> ProviderResult<()> {
+        let mut min_block = self.static_files_min_block.write();
         let mut max_block = self.static_files_max_block.write();
         let mut tx_index = self.static_files_tx_index.write();
 
@@ -531,6 +535,10 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
                 max_block.insert(segment, segment_max_block);
                 let fixed_range = self.find_fixed_range(segment_max_block);
 
+                // Static files are only added and removed at the top, so the min block only
+                // changes if this is the first static file of the segment
+                min_block.entry(segment).or_insert_with(|| fixed_range.start());
+
                 let jar = NippyJar::<SegmentHeader>::load(
                     &self.path.join(segment.filename(&fixed_range)),
                 )
@@ -584,6 +592,7 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
             }
             None => {
                 tx_index.remove(&segment);
+                min_block.remove(&segment);
                 max_block.remove(&segment);
             }
         };
@@ -593,14 +602,19 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
 
     /// Initializes the inner transaction and block index
     pub fn initialize_index(&self) -> ProviderResult<()> {
+        let mut min_block = self.static_files_min_block.write();
         let mut max_block = self.static_files_max_block.write();
         let mut tx_index = self.static_files_tx_index.write();
 
+        min_block.clear();
         max_block.clear();
         tx_index.clear();
 
         for (segment, ranges) in iter_static_files(&self.path).map_err(ProviderError::other)? {
-            // Update last block for each segment
+            // Update first and last block for each segment
+            if let Some((block_range, _)) = ranges.first() {
+                min_block.insert(segment, block_range.start());
+            }
             if let Some((block_range, _)) = ranges.last() {
                 max_block.insert(segment, block_range.end());
             }
@@ -823,6 +837,21 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         Ok(())
     }
 
+    /// Checks that every offset of the static file segment containing the given block is in order
+    /// and points into its data file, and throws an error if at fault. Unlike
+    /// [`Self::check_segment_consistency`], this reads the whole offsets file. Read-only.
+    pub fn check_jar_offsets(
+        &self,
+        segment: StaticFileSegment,
+        block: BlockNumber,
+    ) -> ProviderResult<()> {
+        let file_path = self.directory().join(segment.filename(&self.find_fixed_range(block)));
+
+        let jar = NippyJar::<SegmentHeader>::load(&file_path).map_err(ProviderError::other)?;
+
+        NippyJarChecker::new(jar).check_offsets().map_err(ProviderError::other)
+    }
+
     /// Check invariants for each corresponding table and static file segment:
     ///
     /// * the corresponding database table should overlap or have continuity in their keys
@@ -935,6 +964,22 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         Ok(None)
     }
 
+    /// Drops the writer of the segment, so that it's recreated from the static files on its next
+    /// use.
+    ///
+    /// This needs to be called after static files of the segment were replaced on disk, since the
+    /// writer keeps its static file open. Fails if the writer has uncommitted changes.
+    pub fn reset_writer(&self, segment: StaticFileSegment) -> ProviderResult<()> {
+        self.writers.remove(segment)
+    }
+
+    /// Gets the lowest static file block if it exists for a static file segment.
+    ///
+    /// If there is nothing on disk for the given segment, this will return [`None`].
+    pub fn get_lowest_static_file_block(&self, segment: StaticFileSegment) -> Option<BlockNumber> {
+        self.static_files_min_block.read().get(&segment).copied()
+    }
+
     /// Gets the highest static file block if it exists for a static file segment.
     ///
     /// If there is nothing on disk for the given segment, this will return [`None`].
@@ -67,6 +67,28 @@ impl<N: NodePrimitives> StaticFileWriters<N> {
         Ok(StaticFileProviderRWRefMut(write_guard))
     }
 
+    /// Drops the writer of the segment, so that it's recreated from the static files on its next
+    /// use.
+    ///
+    /// Fails if the writer has uncommitted changes.
+    pub(crate) fn remove(&self, segment: StaticFileSegment) -> ProviderResult<()> {
+        let mut write_guard = match segment {
+            StaticFileSegment::Headers => self.headers.write(),
+            StaticFileSegment::Transactions => self.transactions.write(),
+            StaticFileSegment::Receipts => self.receipts.write(),
+            StaticFileSegment::BlockMeta => self.block_meta.write(),
+        };
+
+        if write_guard.as_ref().is_some_and(|writer| writer.has_uncommitted_changes()) {
+            return Err(ProviderError::other(StaticFileWriterError::new(format!(
+                "{segment} static file writer has uncommitted changes"
+            ))))
+        }
+        write_guard.take();
+
+        Ok(())
+    }
+
     pub(crate) fn commit(&self) -> ProviderResult<()> {
         for writer_lock in [&self.headers, &self.transactions, &self.receipts] {
             let mut writer = writer_lock.write();
@@ -220,6 +242,11 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
         Ok(())
     }
 
+    /// Returns `true` if there are changes that were not committed yet.
+    fn has_uncommitted_changes(&self) -> bool {
+        self.writer.is_dirty() || self.prune_on_commit.is_some()
+    }
+
     /// Commits configuration changes to disk and updates the reader index with the new changes.
     pub fn commit(&mut self) -> ProviderResult<()> {
         let start = Instant::now();
@@ -1,7 +1,8 @@
 use crate::{
     traits::{BlockSource, ReceiptProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    ChainSpecProvider, ChangeSetReader, DatabaseProvider, EthStorage, HeaderProvider,
+    AccountReader, AddressTransactionsReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, DatabaseProvider,
+    EthStorage, HashedStateRangeReader, HashedStateRanges, HeaderProvider, LogIndexReader,
     ReceiptProviderIdExt, StateProvider, StateProviderBox, StateProviderFactory, StateReader,
     StateRootProvider, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
@@ -17,23 +18,25 @@ use alloy_primitives::{
 use parking_lot::Mutex;
 use reth_chainspec::{ChainInfo, EthChainSpec};
 use reth_db::mock::{DatabaseMock, TxMock};
-use reth_db_api::models::{AccountBeforeTx, StoredBlockBodyIndices};
+use reth_db_api::models::{AccountBeforeTx, BlockNumberAddress, StoredBlockBodyIndices};
 use reth_execution_types::ExecutionOutcome;
 use reth_node_types::NodeTypes;
 use reth_primitives::{
     Account, Block, Bytecode, EthPrimitives, GotExpected, Receipt, RecoveredBlock, SealedBlock,
-    SealedHeader, TransactionSigned,
+    SealedHeader, StorageEntry, TransactionSigned,
 };
 use reth_primitives_traits::SignedTransaction;
+use reth_prune_types::{PruneCheckpoint, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::{
     BlockBodyIndicesProvider, DatabaseProviderFactory, HashedPostStateProvider, OmmersProvider,
-    StageCheckpointReader, StateCommitmentProvider, StateProofProvider, StorageRootProvider,
+    PruneCheckpointReader, StageCheckpointReader, StateCommitmentProvider, StateProofProvider,
+    StorageChangeSetReader, StorageRootProvider,
 };
 use reth_storage_errors::provider::{ConsistentViewError, ProviderError, ProviderResult};
 use reth_trie::{
     updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, MultiProof,
-    MultiProofTargets, StorageMultiProof, StorageProof, TrieInput,
+    MultiProofTargets, StorageMultiProof, StorageProof, TrieAccount, TrieInput,
 };
 use reth_trie_db::MerklePatriciaTrie;
 use std::{
@@ -646,6 +649,21 @@ impl<T: Transaction, ChainSpec: EthChainSpec> StageCheckpointReader
     }
 }
 
+impl<T: Transaction, ChainSpec: EthChainSpec> PruneCheckpointReader
+    for MockEthProvider<T, ChainSpec>
+{
+    fn get_prune_checkpoint(
+        &self,
+        _segment: PruneSegment,
+    ) -> ProviderResult<Option<PruneCheckpoint>> {
+        Ok(None)
+    }
+
+    fn get_prune_checkpoints(&self) -> ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>> {
+        Ok(Vec::new())
+    }
+}
+
 impl<T: Transaction, ChainSpec: EthChainSpec> StateRootProvider for MockEthProvider<T, ChainSpec> {
     fn state_root(&self, _state: HashedPostState) -> ProviderResult<B256> {
         Ok(self.state_roots.lock().pop().unwrap_or_default())
@@ -857,6 +875,104 @@ impl<T: Transaction, ChainSpec: EthChainSpec> ChangeSetReader for MockEthProvide
     }
 }
 
+impl<T: Transaction, ChainSpec: EthChainSpec> StorageChangeSetReader
+    for MockEthProvider<T, ChainSpec>
+{
+    fn storage_changeset(
+        &self,
+        _block_number: BlockNumber,
+    ) -> ProviderResult<Vec<(BlockNumberAddress, StorageEntry)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<T: Transaction, ChainSpec: EthChainSpec> AddressTransactionsReader
+    for MockEthProvider<T, ChainSpec>
+{
+    fn address_transactions_before(
+        &self,
+        _address: Address,
+        _before: TxNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        Ok(Vec::default())
+    }
+
+    fn address_transactions_from(
+        &self,
+        _address: Address,
+        _from: TxNumber,
+        _limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<T: Transaction, ChainSpec: EthChainSpec> HashedStateRangeReader
+    for MockEthProvider<T, ChainSpec>
+{
+    fn with_hashed_state_at<R>(
+        &self,
+        _block_number: BlockNumber,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        f(self)
+    }
+
+    fn with_hashed_state_at_hash<R>(
+        &self,
+        _block_hash: B256,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R> {
+        f(self)
+    }
+}
+
+impl<T: Transaction, ChainSpec: EthChainSpec> HashedStateRanges for MockEthProvider<T, ChainSpec> {
+    fn accounts_range(
+        &self,
+        _start: B256,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(B256, TrieAccount)>> {
+        Ok(Vec::default())
+    }
+
+    fn storage_range(
+        &self,
+        _hashed_address: B256,
+        _start: B256,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(B256, U256)>> {
+        Ok(Vec::default())
+    }
+
+    fn state_multiproof(&self, _targets: MultiProofTargets) -> ProviderResult<MultiProof> {
+        Ok(MultiProof::default())
+    }
+}
+
+impl<T: Transaction, ChainSpec: EthChainSpec> LogIndexReader for MockEthProvider<T, ChainSpec> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn log_address_blocks(
+        &self,
+        _address: Address,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::default())
+    }
+
+    fn log_topic_blocks(
+        &self,
+        _topic: B256,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::default())
+    }
+}
+
 impl<T: Transaction, ChainSpec: EthChainSpec> StateReader for MockEthProvider<T, ChainSpec> {
     type Receipt = Receipt;
 
@@ -1,9 +1,10 @@
 //! Helper provider traits to encapsulate all provider traits for simplicity.
 
 use crate::{
-    AccountReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, DatabaseProviderFactory,
-    HeaderProvider, StageCheckpointReader, StateProviderFactory, StaticFileProviderFactory,
-    TransactionsProvider,
+    AccountReader, AddressTransactionsReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader,
+    DatabaseProviderFactory, HashedStateRangeReader, HeaderProvider, LogIndexReader,
+    PruneCheckpointReader, StageCheckpointReader, StateProviderFactory, StaticFileProviderFactory,
+    StorageChangeSetReader, TransactionsProvider,
 };
 use reth_chain_state::{CanonStateSubscriptions, ForkChoiceSubscriptions};
 use reth_chainspec::EthereumHardforks;
@@ -24,9 +25,14 @@ pub trait FullProvider<N: NodeTypesWithDB>:
     + StateProviderFactory
     + ChainSpecProvider<ChainSpec = N::ChainSpec>
     + ChangeSetReader
+    + StorageChangeSetReader
+    + AddressTransactionsReader
+    + LogIndexReader
+    + HashedStateRangeReader
     + CanonStateSubscriptions
     + ForkChoiceSubscriptions<Header = HeaderTy<N>>
     + StageCheckpointReader
+    + PruneCheckpointReader
     + Clone
     + Unpin
     + 'static
@@ -46,9 +52,14 @@ impl<T, N: NodeTypesWithDB> FullProvider<N> for T where
         + StateProviderFactory
         + ChainSpecProvider<ChainSpec = N::ChainSpec>
         + ChangeSetReader
+        + StorageChangeSetReader
+        + AddressTransactionsReader
+        + LogIndexReader
+        + HashedStateRangeReader
         + CanonStateSubscriptions
         + ForkChoiceSubscriptions<Header = HeaderTy<N>>
         + StageCheckpointReader
+        + PruneCheckpointReader
         + Clone
         + Unpin
         + 'static
@@ -1,7 +1,7 @@
 use crate::{
     providers::{StaticFileProvider, StaticFileWriter as SfWriter},
-    BlockExecutionWriter, BlockWriter, HistoryWriter, StateWriter, StaticFileProviderFactory,
-    StorageLocation, TrieWriter,
+    BlockExecutionWriter, BlockWriter, HistoryWriter, LogIndexWriter, StateWriter,
+    StaticFileProviderFactory, StorageLocation, TrieWriter,
 };
 use alloy_consensus::BlockHeader;
 use reth_chain_state::{ExecutedBlock, ExecutedBlockWithTrieUpdates};
@@ -126,6 +126,7 @@ where
         + TrieWriter
         + StateWriter
         + HistoryWriter
+        + LogIndexWriter
         + StageCheckpointWriter
         + BlockExecutionWriter
         + AsRef<ProviderDB>
@@ -165,6 +166,7 @@ where
             trie,
         } in blocks
         {
+            let block_number = recovered_block.number();
             self.database()
                 .insert_block(Arc::unwrap_or_clone(recovered_block), StorageLocation::Both)?;
 
@@ -176,6 +178,11 @@ where
                 StorageLocation::StaticFiles,
             )?;
 
+            self.database().append_log_indices(
+                block_number,
+                execution_output.logs(block_number).into_iter().flatten(),
+            )?;
+
             // insert hashes and intermediate merkle nodes
             self.database()
                 .write_hashed_state(&Arc::unwrap_or_clone(hashed_state).into_sorted())?;
@@ -0,0 +1,53 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+use alloy_primitives::{Address, TxNumber};
+use auto_impl::auto_impl;
+use reth_stages_types::StageId;
+use reth_storage_errors::provider::ProviderResult;
+
+/// Identifier under which the progress of the address index is stored in the stage checkpoints
+/// table.
+///
+/// The address index is not part of the pipeline, so it uses a custom [`StageId`]. The absence of
+/// a checkpoint means that the index is disabled.
+pub const ADDRESS_INDEX_STAGE_ID: StageId = StageId::Other("AddressIndex");
+
+/// Reader for the address appearance index.
+#[auto_impl(&, Arc, Box)]
+pub trait AddressTransactionsReader: Send + Sync {
+    /// Returns up to `limit` transaction numbers in which the address appears that are strictly
+    /// lower than `before`, in descending order.
+    fn address_transactions_before(
+        &self,
+        address: Address,
+        before: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>>;
+
+    /// Returns up to `limit` transaction numbers in which the address appears that are higher than
+    /// or equal to `from`, in ascending order.
+    fn address_transactions_from(
+        &self,
+        address: Address,
+        from: TxNumber,
+        limit: usize,
+    ) -> ProviderResult<Vec<TxNumber>>;
+}
+
+/// Writer for the address appearance index.
+#[auto_impl(&, Arc, Box)]
+pub trait AddressTransactionsWriter: Send + Sync {
+    /// Inserts the addresses that appear in each transaction.
+    ///
+    /// Transactions must be provided in ascending order and must be higher than any transaction
+    /// that is already indexed.
+    fn insert_address_appearances(
+        &self,
+        appearances: impl IntoIterator<Item = (TxNumber, BTreeSet<Address>)>,
+    ) -> ProviderResult<()>;
+
+    /// Removes all address appearances of transactions with a number greater than or equal to
+    /// `from_tx`.
+    ///
+    /// Returns the number of appearances that were removed.
+    fn unwind_address_appearances(&self, from_tx: TxNumber) -> ProviderResult<usize>;
+}
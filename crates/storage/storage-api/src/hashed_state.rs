@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+use alloy_primitives::{BlockNumber, B256, U256};
+use auto_impl::auto_impl;
+use reth_storage_errors::provider::ProviderResult;
+use reth_trie::{MultiProof, MultiProofTargets, TrieAccount};
+
+/// Ranges of the hashed state after a block, see [`HashedStateRangeReader::with_hashed_state_at`].
+pub trait HashedStateRanges {
+    /// Returns up to `limit` accounts with a hashed address greater than or equal to `start`, in
+    /// ascending order of their hashed addresses.
+    ///
+    /// The storage roots of the returned accounts are the ones at the block as well.
+    fn accounts_range(&self, start: B256, limit: usize)
+        -> ProviderResult<Vec<(B256, TrieAccount)>>;
+
+    /// Returns up to `limit` storage slots of the account with the given hashed address with a
+    /// hashed key greater than or equal to `start`, in ascending order of their hashed keys.
+    fn storage_range(
+        &self,
+        hashed_address: B256,
+        start: B256,
+        limit: usize,
+    ) -> ProviderResult<Vec<(B256, U256)>>;
+
+    /// Returns the multiproof of the given targets against the state root at the block.
+    fn state_multiproof(&self, targets: MultiProofTargets) -> ProviderResult<MultiProof>;
+}
+
+/// Reader for ranges of the hashed state at historical blocks.
+///
+/// Entries are ordered by their hashed keys, which is the order of the state trie. The hashed
+/// state does not store the preimages of the keys.
+#[auto_impl(&, Arc, Box)]
+pub trait HashedStateRangeReader: Send + Sync {
+    /// Calls `f` with the hashed state after the given block.
+    ///
+    /// The state of a historical block is only reconstructed once, so all ranges that are read
+    /// through the same [`HashedStateRanges`] share it.
+    fn with_hashed_state_at<R>(
+        &self,
+        block_number: BlockNumber,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R>;
+
+    /// Calls `f` with the hashed state after the block with the given hash.
+    ///
+    /// Unlike [`Self::with_hashed_state_at`], the state is addressed by the block itself, so it
+    /// can not belong to a different block at the same height after a reorg.
+    fn with_hashed_state_at_hash<R>(
+        &self,
+        block_hash: B256,
+        f: impl FnOnce(&dyn HashedStateRanges) -> ProviderResult<R>,
+    ) -> ProviderResult<R>;
+
+    /// Returns up to `limit` accounts of the state after the given block with a hashed address
+    /// greater than or equal to `start`, in ascending order of their hashed addresses.
+    ///
+    /// The storage roots of the returned accounts are the ones at the given block as well.
+    fn hashed_accounts_range(
+        &self,
+        block_number: BlockNumber,
+        start: B256,
+        limit: usize,
+    ) -> ProviderResult<Vec<(B256, TrieAccount)>> {
+        self.with_hashed_state_at(block_number, |state| state.accounts_range(start, limit))
+    }
+
+    /// Returns up to `limit` storage slots of the account with the given hashed address in the
+    /// state after the given block with a hashed key greater than or equal to `start`, in
+    /// ascending order of their hashed keys.
+    fn hashed_storage_range(
+        &self,
+        block_number: BlockNumber,
+        hashed_address: B256,
+        start: B256,
+        limit: usize,
+    ) -> ProviderResult<Vec<(B256, U256)>> {
+        self.with_hashed_state_at(block_number, |state| {
+            state.storage_range(hashed_address, start, limit)
+        })
+    }
+}
@@ -16,6 +16,9 @@ pub use reth_storage_errors as errors;
 mod account;
 pub use account::*;
 
+mod address_transactions;
+pub use address_transactions::*;
+
 mod block;
 pub use block::*;
 
@@ -31,6 +34,12 @@ pub use chain::*;
 mod header;
 pub use header::*;
 
+mod hashed_state;
+pub use hashed_state::*;
+
+mod log_index;
+pub use log_index::*;
+
 mod prune_checkpoint;
 pub use prune_checkpoint::*;
 
@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+use alloy_primitives::{Address, BlockNumber, Log, B256};
+use auto_impl::auto_impl;
+use core::ops::RangeInclusive;
+use reth_storage_errors::provider::ProviderResult;
+
+/// Reader for the log index.
+///
+/// The log index maps log addresses and topics to the blocks that contain them. It's a superset
+/// of the exact matches: a block is returned for a topic regardless of the position of the topic
+/// in the log.
+#[auto_impl(&, Arc, Box)]
+pub trait LogIndexReader: Send + Sync {
+    /// Returns the range of blocks that are covered by the log index.
+    ///
+    /// Returns `None` if the log index is disabled, empty or was pruned entirely.
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>>;
+
+    /// Returns the blocks in the given range that contain logs emitted by the address, in
+    /// ascending order.
+    fn log_address_blocks(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>>;
+
+    /// Returns the blocks in the given range that contain logs with the topic, in ascending order.
+    fn log_topic_blocks(
+        &self,
+        topic: B256,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>>;
+}
+
+/// Writer for the log index.
+#[auto_impl(&, Arc, Box)]
+pub trait LogIndexWriter: Send + Sync {
+    /// Inserts the logs of blocks into the log index.
+    ///
+    /// Logs must be provided in ascending block order and must belong to blocks that are higher
+    /// than any block that is already indexed.
+    fn insert_log_indices<'a>(
+        &self,
+        logs: impl IntoIterator<Item = (BlockNumber, &'a Log)>,
+    ) -> ProviderResult<()>;
+
+    /// Unwind and clear log indices in a given block range.
+    ///
+    /// The receipts of the blocks in the range must still be available.
+    ///
+    /// Returns number of logs walked.
+    fn unwind_log_indices_range(&self, range: RangeInclusive<BlockNumber>)
+        -> ProviderResult<usize>;
+
+    /// Inserts the logs of a block that is appended to the chain into the log index and advances
+    /// the [`StageId::IndexLogs`](reth_stages_types::StageId::IndexLogs) checkpoint to it.
+    ///
+    /// Does nothing if the log index is disabled, or if it doesn't cover all blocks below the
+    /// appended one, in which case the stage has to catch up first.
+    fn append_log_indices<'a>(
+        &self,
+        block_number: BlockNumber,
+        logs: impl IntoIterator<Item = &'a Log>,
+    ) -> ProviderResult<()>;
+
+    /// Unwinds the log indices of all indexed blocks above the given block, and lowers the
+    /// [`StageId::IndexLogs`](reth_stages_types::StageId::IndexLogs) checkpoint to it.
+    ///
+    /// The receipts of the unwound blocks must still be available.
+    fn unwind_log_indices_above(&self, block_number: BlockNumber) -> ProviderResult<()>;
+}
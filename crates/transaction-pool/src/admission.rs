@@ -0,0 +1,477 @@
+//! Admission policies for transactions entering the pool.
+//!
+//! An [`AdmissionPolicy`] is consulted for every validated transaction before it is inserted into
+//! the pool, in addition to the pool's own limits. The default policy is [`AdmissionRules`], which
+//! enforces the declarative rules of an [`AdmissionConfig`].
+
+use crate::{PoolTransaction, TransactionOrigin, ValidPoolTransaction};
+use alloy_primitives::{map::HashMap, Address, Selector};
+use parking_lot::{Mutex, RwLock};
+use reth_network_peers::PeerId;
+use std::{
+    collections::HashSet,
+    fmt,
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+/// A policy that decides whether a transaction is admitted into the pool.
+pub trait AdmissionPolicy<T: PoolTransaction>: fmt::Debug + Send + Sync {
+    /// Checks whether the given transaction may enter the pool.
+    ///
+    /// This is called after the transaction was validated, with the current usage of the pool.
+    fn check(
+        &self,
+        transaction: &ValidPoolTransaction<T>,
+        ctx: &AdmissionContext,
+    ) -> Result<(), AdmissionError>;
+
+    /// Called after the transaction was inserted into the pool, with the context it was checked
+    /// with.
+    fn on_inserted(&self, _transaction: &ValidPoolTransaction<T>, _ctx: &AdmissionContext) {}
+}
+
+/// Where a transaction was received from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AdmissionSource {
+    /// The transaction was received from a peer of the p2p network.
+    Peer(PeerId),
+    /// The transaction was submitted by an RPC client with the given address.
+    Remote(IpAddr),
+}
+
+impl fmt::Display for AdmissionSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Peer(peer_id) => write!(f, "peer {peer_id}"),
+            Self::Remote(ip) => write!(f, "remote {ip}"),
+        }
+    }
+}
+
+/// The state of the pool a transaction is admitted into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdmissionContext {
+    /// Usage of the pool by transactions of the same origin.
+    pub origin_usage: OriginUsage,
+    /// Number of transactions in the pool.
+    pub pool_txs: usize,
+    /// Maximum number of transactions across all sub-pools.
+    pub pool_capacity: usize,
+    /// Whether the transaction replaces a transaction that is already in the pool.
+    pub is_replacement: bool,
+    /// Where the transaction was received from, if known.
+    pub source: Option<AdmissionSource>,
+}
+
+/// Number of transactions of an origin in the pool and their combined gas limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OriginUsage {
+    /// Number of transactions.
+    pub txs: usize,
+    /// Combined gas limit of the transactions.
+    pub gas: u64,
+}
+
+/// Declarative admission rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AdmissionConfig {
+    /// Senders whose transactions are rejected.
+    pub deny_senders: HashSet<Address>,
+    /// Recipients, transactions calling these addresses are rejected.
+    pub deny_recipients: HashSet<Address>,
+    /// Function selectors, transactions calling these functions are rejected.
+    pub deny_selectors: HashSet<Selector>,
+    /// Senders that may use the reserved capacity of the pool.
+    pub reserved_senders: HashSet<Address>,
+    /// Number of transaction slots that are reserved for
+    /// [`reserved_senders`](Self::reserved_senders).
+    ///
+    /// Transactions of other senders are rejected if the pool has less free slots.
+    pub reserved_slots: usize,
+    /// Quota for [`TransactionOrigin::Local`] transactions.
+    pub local: OriginQuota,
+    /// Quota for [`TransactionOrigin::External`] transactions.
+    pub external: OriginQuota,
+    /// Quota for [`TransactionOrigin::Private`] transactions.
+    pub private: OriginQuota,
+}
+
+impl AdmissionConfig {
+    /// Returns the quota for transactions of the given origin.
+    pub const fn quota(&self, origin: TransactionOrigin) -> &OriginQuota {
+        match origin {
+            TransactionOrigin::Local => &self.local,
+            TransactionOrigin::External => &self.external,
+            TransactionOrigin::Private => &self.private,
+        }
+    }
+}
+
+/// Limits for transactions of one [`TransactionOrigin`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct OriginQuota {
+    /// Maximum number of transactions of the origin in the pool.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub max_txs: Option<usize>,
+    /// Maximum combined gas limit of the transactions of the origin in the pool.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub max_gas: Option<u64>,
+    /// Maximum rate at which a single [`AdmissionSource`] may submit transactions of the origin.
+    ///
+    /// Transactions without a known source share a single limit.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub rate_limit: Option<RateLimit>,
+}
+
+/// Allows up to `max_txs` transactions per `interval`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateLimit {
+    /// Maximum number of transactions per interval.
+    pub max_txs: u32,
+    /// Length of the interval.
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub interval: Duration,
+}
+
+/// Reasons a transaction is not admitted into the pool.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AdmissionError {
+    /// The sender is denied.
+    #[error("sender {0} is denied")]
+    DeniedSender(Address),
+    /// The recipient is denied.
+    #[error("recipient {0} is denied")]
+    DeniedRecipient(Address),
+    /// The called function is denied.
+    #[error("function selector {0} is denied")]
+    DeniedSelector(Selector),
+    /// The remaining capacity of the pool is reserved for other senders.
+    #[error("remaining pool capacity is reserved")]
+    ReservedCapacity,
+    /// The origin exceeds its maximum number of transactions.
+    #[error("{origin:?} transactions exceed the limit of {max_txs}")]
+    OriginTxsExceeded {
+        /// Origin of the transaction.
+        origin: TransactionOrigin,
+        /// Maximum number of transactions of the origin.
+        max_txs: usize,
+    },
+    /// The origin exceeds its maximum combined gas limit.
+    #[error("{origin:?} transactions exceed the gas limit of {max_gas}")]
+    OriginGasExceeded {
+        /// Origin of the transaction.
+        origin: TransactionOrigin,
+        /// Maximum combined gas limit of the origin.
+        max_gas: u64,
+    },
+    /// The source submits transactions too fast.
+    #[error(
+        "{origin:?} transactions{} are rate limited",
+        .received_from.map(|from| format!(" from {from}")).unwrap_or_default()
+    )]
+    RateLimited {
+        /// Origin of the transaction.
+        origin: TransactionOrigin,
+        /// Where the transaction was received from.
+        received_from: Option<AdmissionSource>,
+    },
+}
+
+/// The default [`AdmissionPolicy`] that enforces an [`AdmissionConfig`].
+#[derive(Debug, Default)]
+pub struct AdmissionRules {
+    /// The configured rules.
+    config: RwLock<AdmissionConfig>,
+    /// Number of inserted transactions per origin and source in the current rate limit window.
+    rate_limits: Mutex<HashMap<(TransactionOrigin, Option<AdmissionSource>), RateLimitWindow>>,
+}
+
+impl AdmissionRules {
+    /// Creates new rules from the given config.
+    pub fn new(config: AdmissionConfig) -> Self {
+        Self { config: RwLock::new(config), rate_limits: Default::default() }
+    }
+
+    /// Returns the configured rules.
+    pub fn config(&self) -> AdmissionConfig {
+        self.config.read().clone()
+    }
+
+    /// Replaces the configured rules.
+    ///
+    /// The rate limit windows of the sources are kept.
+    pub fn set_config(&self, config: AdmissionConfig) {
+        *self.config.write() = config;
+    }
+
+    /// Returns an error if the source already used up its rate limit.
+    fn ensure_rate_limit(
+        &self,
+        origin: TransactionOrigin,
+        source: Option<AdmissionSource>,
+        limit: RateLimit,
+    ) -> Result<(), AdmissionError> {
+        let now = Instant::now();
+        let rate_limits = self.rate_limits.lock();
+        if let Some(window) = rate_limits.get(&(origin, source)) {
+            if now.duration_since(window.start) < limit.interval && window.txs >= limit.max_txs {
+                return Err(AdmissionError::RateLimited { origin, received_from: source })
+            }
+        }
+        Ok(())
+    }
+
+    /// Counts an inserted transaction against the source's rate limit.
+    fn charge_rate_limit(
+        &self,
+        origin: TransactionOrigin,
+        source: Option<AdmissionSource>,
+        limit: RateLimit,
+    ) {
+        let now = Instant::now();
+        let mut rate_limits = self.rate_limits.lock();
+
+        let window =
+            rate_limits.entry((origin, source)).or_insert(RateLimitWindow { start: now, txs: 0 });
+        if now.duration_since(window.start) >= limit.interval {
+            *window = RateLimitWindow { start: now, txs: 0 };
+        }
+        window.txs = window.txs.saturating_add(1);
+
+        // drop expired windows of other sources once in a while
+        if rate_limits.len() > MAX_RATE_LIMITED_SOURCES {
+            rate_limits.retain(|_, window| now.duration_since(window.start) < limit.interval);
+        }
+    }
+}
+
+impl<T: PoolTransaction> AdmissionPolicy<T> for AdmissionRules {
+    fn check(
+        &self,
+        transaction: &ValidPoolTransaction<T>,
+        ctx: &AdmissionContext,
+    ) -> Result<(), AdmissionError> {
+        let config = self.config.read();
+        let sender = transaction.sender();
+        if config.deny_senders.contains(&sender) {
+            return Err(AdmissionError::DeniedSender(sender))
+        }
+
+        if let Some(to) = transaction.to() {
+            if config.deny_recipients.contains(&to) {
+                return Err(AdmissionError::DeniedRecipient(to))
+            }
+            if let Some(selector) = transaction.transaction.input().get(..4) {
+                let selector = Selector::from_slice(selector);
+                if config.deny_selectors.contains(&selector) {
+                    return Err(AdmissionError::DeniedSelector(selector))
+                }
+            }
+        }
+
+        if config.reserved_slots > 0 &&
+            !ctx.is_replacement &&
+            !config.reserved_senders.contains(&sender) &&
+            ctx.pool_txs >= ctx.pool_capacity.saturating_sub(config.reserved_slots)
+        {
+            return Err(AdmissionError::ReservedCapacity)
+        }
+
+        let origin = transaction.origin;
+        let quota = config.quota(origin);
+        if let Some(max_txs) = quota.max_txs {
+            if !ctx.is_replacement && ctx.origin_usage.txs >= max_txs {
+                return Err(AdmissionError::OriginTxsExceeded { origin, max_txs })
+            }
+        }
+        if let Some(max_gas) = quota.max_gas {
+            if ctx.origin_usage.gas.saturating_add(transaction.gas_limit()) > max_gas {
+                return Err(AdmissionError::OriginGasExceeded { origin, max_gas })
+            }
+        }
+        if let Some(limit) = quota.rate_limit {
+            self.ensure_rate_limit(origin, ctx.source, limit)?;
+        }
+
+        Ok(())
+    }
+
+    fn on_inserted(&self, transaction: &ValidPoolTransaction<T>, ctx: &AdmissionContext) {
+        let origin = transaction.origin;
+        let limit = self.config.read().quota(origin).rate_limit;
+        if let Some(limit) = limit {
+            self.charge_rate_limit(origin, ctx.source, limit);
+        }
+    }
+}
+
+/// Number of tracked sources after which expired rate limit windows are dropped.
+const MAX_RATE_LIMITED_SOURCES: usize = 10_000;
+
+/// Rate limit window of a source.
+#[derive(Debug, Clone, Copy)]
+struct RateLimitWindow {
+    /// When the window started.
+    start: Instant,
+    /// Number of transactions in the window.
+    txs: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{MockTransaction, MockTransactionFactory};
+    use alloy_consensus::Transaction;
+    use alloy_primitives::Bytes;
+
+    fn ctx() -> AdmissionContext {
+        AdmissionContext {
+            origin_usage: OriginUsage::default(),
+            pool_txs: 0,
+            pool_capacity: 100,
+            is_replacement: false,
+            source: None,
+        }
+    }
+
+    fn check(
+        rules: &AdmissionRules,
+        tx: MockTransaction,
+        ctx: AdmissionContext,
+    ) -> Result<(), AdmissionError> {
+        let mut f = MockTransactionFactory::default();
+        rules.check(&f.validated(tx), &ctx)
+    }
+
+    #[test]
+    fn deny_lists() {
+        let tx = MockTransaction::eip1559()
+            .with_input(Bytes::from_static(&[0xa9, 0x05, 0x9c, 0xbb, 0x00]));
+
+        let rules = AdmissionRules::default();
+        assert_eq!(check(&rules, tx.clone(), ctx()), Ok(()));
+
+        let rules = AdmissionRules::new(AdmissionConfig {
+            deny_senders: HashSet::from([tx.sender()]),
+            ..Default::default()
+        });
+        assert_eq!(
+            check(&rules, tx.clone(), ctx()),
+            Err(AdmissionError::DeniedSender(tx.sender()))
+        );
+
+        let to = tx.to().unwrap();
+        let rules = AdmissionRules::new(AdmissionConfig {
+            deny_recipients: HashSet::from([to]),
+            ..Default::default()
+        });
+        assert_eq!(check(&rules, tx.clone(), ctx()), Err(AdmissionError::DeniedRecipient(to)));
+
+        let selector = Selector::from([0xa9, 0x05, 0x9c, 0xbb]);
+        let rules = AdmissionRules::new(AdmissionConfig {
+            deny_selectors: HashSet::from([selector]),
+            ..Default::default()
+        });
+        assert_eq!(check(&rules, tx, ctx()), Err(AdmissionError::DeniedSelector(selector)));
+    }
+
+    #[test]
+    fn reserved_capacity() {
+        let tx = MockTransaction::eip1559();
+        let full = AdmissionContext { pool_txs: 90, ..ctx() };
+
+        let rules =
+            AdmissionRules::new(AdmissionConfig { reserved_slots: 10, ..Default::default() });
+        assert_eq!(check(&rules, tx.clone(), ctx()), Ok(()));
+        assert_eq!(check(&rules, tx.clone(), full), Err(AdmissionError::ReservedCapacity));
+        assert_eq!(
+            check(&rules, tx.clone(), AdmissionContext { is_replacement: true, ..full }),
+            Ok(())
+        );
+
+        let rules = AdmissionRules::new(AdmissionConfig {
+            reserved_slots: 10,
+            reserved_senders: HashSet::from([tx.sender()]),
+            ..Default::default()
+        });
+        assert_eq!(check(&rules, tx, full), Ok(()));
+    }
+
+    #[test]
+    fn origin_quota() {
+        let tx = MockTransaction::eip1559().with_gas_limit(100);
+        let rules = AdmissionRules::new(AdmissionConfig {
+            external: OriginQuota { max_txs: Some(1), max_gas: Some(150), rate_limit: None },
+            ..Default::default()
+        });
+        let used = AdmissionContext { origin_usage: OriginUsage { txs: 1, gas: 100 }, ..ctx() };
+
+        assert_eq!(check(&rules, tx.clone(), ctx()), Ok(()));
+        assert_eq!(
+            check(&rules, tx.clone(), used),
+            Err(AdmissionError::OriginTxsExceeded {
+                origin: TransactionOrigin::External,
+                max_txs: 1
+            })
+        );
+        assert_eq!(
+            check(&rules, tx, AdmissionContext { is_replacement: true, ..used }),
+            Err(AdmissionError::OriginGasExceeded {
+                origin: TransactionOrigin::External,
+                max_gas: 150
+            })
+        );
+    }
+
+    #[test]
+    fn rate_limit() {
+        let mut f = MockTransactionFactory::default();
+        let config = AdmissionConfig {
+            external: OriginQuota {
+                rate_limit: Some(RateLimit { max_txs: 2, interval: Duration::from_secs(60) }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let rules = AdmissionRules::new(config.clone());
+        let peer = AdmissionContext {
+            source: Some(AdmissionSource::Peer(PeerId::with_last_byte(1))),
+            ..ctx()
+        };
+
+        // only inserted transactions are counted
+        let tx = f.validated(MockTransaction::eip1559());
+        for _ in 0..3 {
+            assert_eq!(rules.check(&tx, &peer), Ok(()));
+        }
+        rules.on_inserted(&tx, &peer);
+        rules.on_inserted(&tx, &peer);
+        let limited = Err(AdmissionError::RateLimited {
+            origin: TransactionOrigin::External,
+            received_from: peer.source,
+        });
+        assert_eq!(rules.check(&tx, &peer), limited);
+
+        // the limit applies to all senders of the source
+        let other_sender = f.validated(MockTransaction::eip1559().with_sender(Address::random()));
+        assert_eq!(rules.check(&other_sender, &peer), limited);
+
+        // other sources are not affected
+        let remote = AdmissionContext {
+            source: Some(AdmissionSource::Remote([127, 0, 0, 1].into())),
+            ..ctx()
+        };
+        assert_eq!(rules.check(&tx, &remote), Ok(()));
+        assert_eq!(rules.check(&tx, &ctx()), Ok(()));
+
+        // reloading the config keeps the windows
+        rules.set_config(config);
+        assert_eq!(rules.check(&tx, &peer), limited);
+    }
+}
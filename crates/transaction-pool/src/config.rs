@@ -1,4 +1,5 @@
 use crate::{
+    admission::AdmissionConfig,
     maintain::MAX_QUEUED_TRANSACTION_LIFETIME,
     pool::{NEW_TX_LISTENER_BUFFER_SIZE, PENDING_TX_LISTENER_BUFFER_SIZE},
     PoolSize, TransactionOrigin,
@@ -61,6 +62,8 @@ pub struct PoolConfig {
     pub max_new_pending_txs_notifications: usize,
     /// Maximum lifetime for transactions in the pool
     pub max_queued_lifetime: Duration,
+    /// Rules for admitting transactions into the pool.
+    pub admission: AdmissionConfig,
 }
 
 impl PoolConfig {
@@ -72,6 +75,16 @@ impl PoolConfig {
             self.basefee_limit.is_exceeded(pool_size.basefee, pool_size.basefee_size) ||
             self.queued_limit.is_exceeded(pool_size.queued, pool_size.queued_size)
     }
+
+    /// Returns the maximum number of transactions across all sub-pools.
+    #[inline]
+    pub const fn max_txs(&self) -> usize {
+        self.pending_limit
+            .max_txs
+            .saturating_add(self.basefee_limit.max_txs)
+            .saturating_add(self.queued_limit.max_txs)
+            .saturating_add(self.blob_limit.max_txs)
+    }
 }
 
 impl Default for PoolConfig {
@@ -90,6 +103,7 @@ impl Default for PoolConfig {
             new_tx_listener_buffer_size: NEW_TX_LISTENER_BUFFER_SIZE,
             max_new_pending_txs_notifications: MAX_NEW_PENDING_TXS_NOTIFICATIONS,
             max_queued_lifetime: MAX_QUEUED_TRANSACTION_LIFETIME,
+            admission: Default::default(),
         }
     }
 }
@@ -2,6 +2,7 @@
 
 use std::any::Any;
 
+use crate::admission::AdmissionError;
 use alloy_eips::eip4844::BlobTransactionValidationError;
 use alloy_primitives::{Address, TxHash, U256};
 use reth_primitives::InvalidTransactionError;
@@ -232,6 +233,10 @@ pub enum InvalidPoolTransactionError {
     /// invocation.
     #[error("intrinsic gas too low")]
     IntrinsicGasTooLow,
+    /// Thrown if the transaction is rejected by the pool's
+    /// [`AdmissionPolicy`](crate::admission::AdmissionPolicy).
+    #[error(transparent)]
+    Admission(#[from] AdmissionError),
 }
 
 // === impl InvalidPoolTransactionError ===
@@ -289,6 +294,10 @@ impl InvalidPoolTransactionError {
             }
             Self::IntrinsicGasTooLow => true,
             Self::Overdraft { .. } => false,
+            Self::Admission(_) => {
+                // local setting
+                false
+            }
             Self::Other(err) => err.is_bad_transaction(),
             Self::Eip4844(eip4844_err) => {
                 match eip4844_err {
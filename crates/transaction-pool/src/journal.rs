@@ -0,0 +1,695 @@
+//! Append-only journal of pool transactions for crash recovery.
+//!
+//! The [`TransactionJournal`] records inserts and removals of transactions as they happen, so that
+//! the transactions survive an unclean shutdown of the node. The journal is regularly compacted by
+//! rewriting it with the transactions that are currently in the pool, and replayed through the
+//! validator on startup.
+
+use crate::{
+    maintain::TransactionsBackupError, traits::TransactionPool, FullTransactionEvent,
+    PoolTransaction, SubPool, TransactionOrigin,
+};
+use alloy_primitives::TxHash;
+use alloy_rlp::{BufMut, Decodable, Encodable, Header};
+use futures_util::StreamExt;
+use reth_fs_util::FsPathError;
+use reth_primitives::transaction::SignedTransactionIntoRecoveredExt;
+use reth_primitives_traits::SignedTransaction;
+use std::{
+    collections::HashSet,
+    fs::{File, OpenOptions},
+    io::Write,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    pin::pin,
+    sync::mpsc,
+    time::Duration,
+};
+use tokio::{
+    sync::oneshot,
+    time::{interval_at, Instant},
+};
+use tracing::{debug, error, info, warn};
+
+/// Default interval at which the journal is compacted.
+pub const DEFAULT_REJOURNAL_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Which transactions are recorded in the journal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JournalScope {
+    /// Only transactions submitted as [`TransactionOrigin::Local`].
+    #[default]
+    Local,
+    /// Local transactions and all transactions in the pending sub-pool.
+    AllPending,
+}
+
+impl JournalScope {
+    /// Returns true if a transaction of the given origin in the given sub-pool should be recorded.
+    pub const fn includes(&self, origin: TransactionOrigin, subpool: SubPool) -> bool {
+        match self {
+            Self::Local => origin.is_local(),
+            Self::AllPending => origin.is_local() || subpool.is_pending(),
+        }
+    }
+}
+
+/// Settings for the transaction journal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionJournalConfig {
+    /// Path to the journal file.
+    pub path: PathBuf,
+    /// How often the journal is compacted.
+    pub rejournal_interval: Duration,
+    /// Which transactions are recorded.
+    pub scope: JournalScope,
+}
+
+impl TransactionJournalConfig {
+    /// Creates a new config that journals local transactions to the given path.
+    pub const fn new(path: PathBuf) -> Self {
+        Self { path, rejournal_interval: DEFAULT_REJOURNAL_INTERVAL, scope: JournalScope::Local }
+    }
+
+    /// Sets the interval at which the journal is compacted.
+    pub const fn with_rejournal_interval(mut self, rejournal_interval: Duration) -> Self {
+        self.rejournal_interval = rejournal_interval;
+        self
+    }
+
+    /// Sets which transactions are recorded.
+    pub const fn with_scope(mut self, scope: JournalScope) -> Self {
+        self.scope = scope;
+        self
+    }
+}
+
+/// An entry of the transaction journal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JournalEntry<T> {
+    /// A transaction was inserted into the pool.
+    Insert {
+        /// Where the transaction originates from.
+        origin: TransactionOrigin,
+        /// The inserted transaction.
+        transaction: T,
+    },
+    /// The transaction with the given hash was removed from the pool.
+    Remove(TxHash),
+}
+
+impl<T> JournalEntry<T> {
+    const INSERT: u8 = 0;
+    const REMOVE: u8 = 1;
+
+    fn payload_length(&self) -> usize
+    where
+        T: Encodable,
+    {
+        match self {
+            Self::Insert { origin, transaction } => {
+                Self::INSERT.length() + encode_origin(*origin).length() + transaction.length()
+            }
+            Self::Remove(hash) => Self::REMOVE.length() + hash.length(),
+        }
+    }
+}
+
+impl<T: Encodable> Encodable for JournalEntry<T> {
+    fn encode(&self, out: &mut dyn BufMut) {
+        Header { list: true, payload_length: self.payload_length() }.encode(out);
+        match self {
+            Self::Insert { origin, transaction } => {
+                Self::INSERT.encode(out);
+                encode_origin(*origin).encode(out);
+                transaction.encode(out);
+            }
+            Self::Remove(hash) => {
+                Self::REMOVE.encode(out);
+                hash.encode(out);
+            }
+        }
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.payload_length();
+        Header { list: true, payload_length }.length() + payload_length
+    }
+}
+
+impl<T: Decodable> Decodable for JournalEntry<T> {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString)
+        }
+        let remaining = buf.len();
+
+        let entry = match u8::decode(buf)? {
+            Self::INSERT => Self::Insert {
+                origin: decode_origin(u8::decode(buf)?)?,
+                transaction: T::decode(buf)?,
+            },
+            Self::REMOVE => Self::Remove(TxHash::decode(buf)?),
+            _ => return Err(alloy_rlp::Error::Custom("unknown journal entry")),
+        };
+
+        if remaining - buf.len() != header.payload_length {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: header.payload_length,
+                got: remaining - buf.len(),
+            })
+        }
+
+        Ok(entry)
+    }
+}
+
+const fn encode_origin(origin: TransactionOrigin) -> u8 {
+    match origin {
+        TransactionOrigin::Local => 0,
+        TransactionOrigin::External => 1,
+        TransactionOrigin::Private => 2,
+    }
+}
+
+const fn decode_origin(origin: u8) -> alloy_rlp::Result<TransactionOrigin> {
+    match origin {
+        0 => Ok(TransactionOrigin::Local),
+        1 => Ok(TransactionOrigin::External),
+        2 => Ok(TransactionOrigin::Private),
+        _ => Err(alloy_rlp::Error::Custom("unknown transaction origin")),
+    }
+}
+
+/// An append-only journal of transactions.
+///
+/// Every insert and removal is appended to the journal file as a [`JournalEntry`]. Replaying the
+/// entries yields the transactions that were in the pool when the journal was last written to.
+#[derive(Debug)]
+pub struct TransactionJournal<T> {
+    /// Path to the journal file.
+    path: PathBuf,
+    /// Handle to the journal file, opened in append mode.
+    file: File,
+    /// Hashes of the transactions that are currently journaled.
+    journaled: HashSet<TxHash>,
+    _tx: PhantomData<T>,
+}
+
+impl<T: SignedTransaction> TransactionJournal<T> {
+    /// Opens the journal at the given path, creating it if it doesn't exist.
+    ///
+    /// Returns the journal and the transactions that were recorded and not removed, in the order
+    /// they were inserted.
+    ///
+    /// A truncated trailing entry, as left behind by a crash during a write, is ignored.
+    pub fn open(
+        path: &Path,
+    ) -> Result<(Self, Vec<(TransactionOrigin, T)>), TransactionsBackupError> {
+        let data = if path.exists() { reth_fs_util::read(path)? } else { Vec::new() };
+
+        let mut entries = Vec::<Option<(TransactionOrigin, T)>>::new();
+        let mut buf = data.as_slice();
+        while !buf.is_empty() {
+            match JournalEntry::<T>::decode(&mut buf) {
+                Ok(JournalEntry::Insert { origin, transaction }) => {
+                    // a re-inserted transaction takes the position of the latest insert
+                    let hash = *transaction.tx_hash();
+                    remove_entry(&mut entries, hash);
+                    entries.push(Some((origin, transaction)));
+                }
+                Ok(JournalEntry::Remove(hash)) => remove_entry(&mut entries, hash),
+                Err(err) => {
+                    warn!(target: "txpool", %err, journal=?path, "Discarding corrupted tail of transaction journal");
+                    break
+                }
+            }
+        }
+        let transactions = entries.into_iter().flatten().collect::<Vec<_>>();
+
+        if let Some(parent) = path.parent() {
+            reth_fs_util::create_dir_all(parent)?;
+        }
+        let file = open_append(path)?;
+        let journaled = transactions.iter().map(|(_, tx)| *tx.tx_hash()).collect();
+
+        Ok((Self { path: path.to_path_buf(), file, journaled, _tx: PhantomData }, transactions))
+    }
+
+    /// Returns the number of transactions that are currently journaled.
+    pub fn len(&self) -> usize {
+        self.journaled.len()
+    }
+
+    /// Returns true if no transactions are journaled.
+    pub fn is_empty(&self) -> bool {
+        self.journaled.is_empty()
+    }
+
+    /// Returns true if the transaction with the given hash is journaled.
+    pub fn contains(&self, hash: &TxHash) -> bool {
+        self.journaled.contains(hash)
+    }
+
+    /// Records the insert of a transaction.
+    ///
+    /// Does nothing if the transaction is already journaled.
+    pub fn insert(&mut self, origin: TransactionOrigin, transaction: T) -> Result<(), FsPathError> {
+        let hash = *transaction.tx_hash();
+        if !self.journaled.insert(hash) {
+            return Ok(())
+        }
+        self.append(&JournalEntry::Insert { origin, transaction })
+    }
+
+    /// Records the removal of a transaction.
+    ///
+    /// Does nothing if the transaction is not journaled.
+    pub fn remove(&mut self, hash: TxHash) -> Result<(), FsPathError> {
+        if !self.journaled.remove(&hash) {
+            return Ok(())
+        }
+        self.append(&JournalEntry::<T>::Remove(hash))
+    }
+
+    /// Compacts the journal by atomically replacing it with the given transactions.
+    pub fn rotate(
+        &mut self,
+        transactions: impl IntoIterator<Item = (TransactionOrigin, T)>,
+    ) -> Result<(), FsPathError> {
+        let mut journaled = HashSet::new();
+        let mut buf = Vec::new();
+        for (origin, transaction) in transactions {
+            if journaled.insert(*transaction.tx_hash()) {
+                JournalEntry::Insert { origin, transaction }.encode(&mut buf);
+            }
+        }
+
+        reth_fs_util::atomic_write_file(&self.path, |file| file.write_all(&buf))?;
+        self.file = open_append(&self.path)?;
+        self.journaled = journaled;
+        Ok(())
+    }
+
+    fn append(&mut self, entry: &JournalEntry<T>) -> Result<(), FsPathError> {
+        let mut buf = Vec::with_capacity(entry.length());
+        entry.encode(&mut buf);
+        // the entry has to survive a crash of the process or the machine
+        self.file
+            .write_all(&buf)
+            .and_then(|_| self.file.sync_data())
+            .map_err(|err| FsPathError::write(err, &self.path))
+    }
+}
+
+fn remove_entry<T: SignedTransaction>(
+    entries: &mut [Option<(TransactionOrigin, T)>],
+    hash: TxHash,
+) {
+    if let Some(entry) = entries
+        .iter_mut()
+        .rev()
+        .find(|entry| entry.as_ref().is_some_and(|(_, tx)| *tx.tx_hash() == hash))
+    {
+        *entry = None;
+    }
+}
+
+fn open_append(path: &Path) -> Result<File, FsPathError> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| FsPathError::open(err, path))
+}
+
+/// Replays the journal into the pool and keeps it up to date with the pool's inserts and removals
+/// until the node shuts down.
+///
+/// The journal is compacted after the replay, every
+/// [`rejournal_interval`](TransactionJournalConfig::rejournal_interval) and on shutdown. The
+/// journal file is written on a dedicated thread, see [`spawn_journal_writer`].
+pub(crate) async fn run_transaction_journal<P>(
+    shutdown: reth_tasks::shutdown::GracefulShutdown,
+    pool: P,
+    config: TransactionJournalConfig,
+) where
+    P: TransactionPool<Transaction: PoolTransaction<Consensus: SignedTransaction>>,
+{
+    let (journal, transactions) = match TransactionJournal::open(&config.path) {
+        Ok(res) => res,
+        Err(err) => {
+            error!(target: "txpool", %err, journal=?config.path, "Failed to open transaction journal");
+            return
+        }
+    };
+    let writer = match spawn_journal_writer(journal) {
+        Ok(writer) => writer,
+        Err(err) => {
+            error!(target: "txpool", %err, "Failed to spawn transaction journal thread");
+            return
+        }
+    };
+
+    let num_txs = transactions.len();
+    reinsert_transactions(&pool, transactions).await;
+    info!(target: "txpool", journal=?config.path, %num_txs, "Replayed transaction journal");
+
+    // Inserts and removals are taken from a single ordered event stream, so that a removal is
+    // never recorded before the insert of the same transaction. Subscribing before the compaction
+    // ensures that every insert after the snapshot of the pool reaches the journal.
+    let mut events = pool.all_transactions_event_listener();
+    // drops everything the pool rejected on replay
+    let _ = writer.send(JournalWrite::Rotate(journal_snapshot(&pool, config.scope), None));
+
+    let mut rejournal =
+        interval_at(Instant::now() + config.rejournal_interval, config.rejournal_interval);
+    let mut shutdown = pin!(shutdown);
+
+    loop {
+        tokio::select! {
+            guard = &mut shutdown => {
+                let (done_tx, done_rx) = oneshot::channel();
+                let snapshot = journal_snapshot(&pool, config.scope);
+                if writer.send(JournalWrite::Rotate(snapshot, Some(done_tx))).is_ok() {
+                    let _ = done_rx.await;
+                }
+                drop(guard);
+                break
+            }
+            _ = rejournal.tick() => {
+                let snapshot = journal_snapshot(&pool, config.scope);
+                let _ = writer.send(JournalWrite::Rotate(snapshot, None));
+            }
+            Some(event) = events.next() => {
+                let write = match event {
+                    // promoted transactions are journaled once they become pending
+                    FullTransactionEvent::Pending(hash) => {
+                        journal_insert(&pool, hash, SubPool::Pending, config.scope)
+                    }
+                    FullTransactionEvent::Queued(hash) => {
+                        journal_insert(&pool, hash, SubPool::Queued, config.scope)
+                    }
+                    FullTransactionEvent::Mined { tx_hash: hash, .. } |
+                    FullTransactionEvent::Discarded(hash) |
+                    FullTransactionEvent::Invalid(hash) => Some(JournalWrite::Remove(hash)),
+                    FullTransactionEvent::Replaced { transaction, .. } => {
+                        Some(JournalWrite::Remove(*transaction.hash()))
+                    }
+                    FullTransactionEvent::Propagated { .. } => None,
+                };
+                if let Some(write) = write {
+                    let _ = writer.send(write);
+                }
+            }
+        }
+    }
+}
+
+/// A write to the transaction journal.
+#[derive(Debug)]
+enum JournalWrite<T> {
+    /// Records the insert of a transaction.
+    Insert(TransactionOrigin, T),
+    /// Records the removal of a transaction.
+    Remove(TxHash),
+    /// Compacts the journal, notifying the sender once done.
+    Rotate(Vec<(TransactionOrigin, T)>, Option<oneshot::Sender<()>>),
+}
+
+/// Spawns the thread that applies the writes to the journal in the order they are sent.
+///
+/// The journal file is synced after every write, which must not block the async runtime. The
+/// thread exits once the returned sender is dropped.
+fn spawn_journal_writer<T: SignedTransaction>(
+    mut journal: TransactionJournal<T>,
+) -> std::io::Result<mpsc::Sender<JournalWrite<T>>> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::Builder::new().name("txpool-journal".to_string()).spawn(move || {
+        for write in rx {
+            match write {
+                JournalWrite::Insert(origin, transaction) => {
+                    if let Err(err) = journal.insert(origin, transaction) {
+                        warn!(target: "txpool", %err, "Failed to journal transaction");
+                    }
+                }
+                JournalWrite::Remove(hash) => {
+                    if let Err(err) = journal.remove(hash) {
+                        warn!(target: "txpool", %err, "Failed to journal transaction removal");
+                    }
+                }
+                JournalWrite::Rotate(transactions, done) => {
+                    match journal.rotate(transactions) {
+                        Ok(()) => {
+                            debug!(target: "txpool", num_txs=%journal.len(), "Compacted transaction journal")
+                        }
+                        Err(err) => {
+                            warn!(target: "txpool", %err, "Failed to compact transaction journal")
+                        }
+                    }
+                    if let Some(done) = done {
+                        let _ = done.send(());
+                    }
+                }
+            }
+        }
+    })?;
+    Ok(tx)
+}
+
+/// Returns the write that records the insert of the transaction with the given hash, if it's still
+/// in the pool and in scope of the journal.
+///
+/// Transactions that are already journaled are ignored by the journal.
+fn journal_insert<P>(
+    pool: &P,
+    hash: TxHash,
+    subpool: SubPool,
+    scope: JournalScope,
+) -> Option<JournalWrite<<P::Transaction as PoolTransaction>::Consensus>>
+where
+    P: TransactionPool<Transaction: PoolTransaction<Consensus: SignedTransaction>>,
+{
+    let tx = pool.get(&hash)?;
+    scope
+        .includes(tx.origin, subpool)
+        .then(|| JournalWrite::Insert(tx.origin, tx.transaction.clone_into_consensus().into_tx()))
+}
+
+/// Returns the transactions of the pool that are in scope of the journal.
+fn journal_snapshot<P>(
+    pool: &P,
+    scope: JournalScope,
+) -> Vec<(TransactionOrigin, <P::Transaction as PoolTransaction>::Consensus)>
+where
+    P: TransactionPool<Transaction: PoolTransaction<Consensus: SignedTransaction>>,
+{
+    let mut transactions = pool.get_local_transactions();
+    if scope == JournalScope::AllPending {
+        transactions.extend(pool.pending_transactions());
+    }
+    transactions
+        .into_iter()
+        .map(|tx| (tx.origin, tx.transaction.clone_into_consensus().into_tx()))
+        .collect()
+}
+
+/// Inserts the journaled transactions into the pool, validating them again.
+async fn reinsert_transactions<P>(
+    pool: &P,
+    transactions: Vec<(TransactionOrigin, <P::Transaction as PoolTransaction>::Consensus)>,
+) where
+    P: TransactionPool<Transaction: PoolTransaction<Consensus: SignedTransaction>>,
+{
+    for origin in
+        [TransactionOrigin::Local, TransactionOrigin::External, TransactionOrigin::Private]
+    {
+        let pool_transactions = transactions
+            .iter()
+            .filter(|(tx_origin, _)| *tx_origin == origin)
+            .filter_map(|(_, tx)| tx.try_clone_into_recovered().ok())
+            .filter_map(|tx| <P::Transaction as PoolTransaction>::try_from_consensus(tx).ok())
+            .collect::<Vec<_>>();
+        if !pool_transactions.is_empty() {
+            pool.add_transactions(origin, pool_transactions).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        blobstore::InMemoryBlobStore, validate::EthTransactionValidatorBuilder,
+        CoinbaseTipOrdering, EthPooledTransaction, Pool,
+    };
+    use alloy_eips::eip2718::Decodable2718;
+    use alloy_primitives::{hex, Address, U256};
+    use reth_primitives::{PooledTransaction, TransactionSigned};
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_tasks::TaskManager;
+
+    fn pooled_transaction() -> PooledTransaction {
+        let tx_bytes = hex!("02f87201830655c2808505ef61f08482565f94388c818ca8b9251b393131c08a736a67ccb192978801049e39c4b5b1f580c001a01764ace353514e8abdfb92446de356b260e3c1225b73fc4c8876a6258d12a129a04f02294aa61ca7676061cd99f29275491218b4754b46a0248e5e42bc5091f507");
+        PooledTransaction::decode_2718(&mut &tx_bytes[..]).unwrap()
+    }
+
+    fn transaction() -> TransactionSigned {
+        pooled_transaction().into()
+    }
+
+    /// Returns the hashes of the transactions that are currently journaled at the given path.
+    fn journaled(path: &Path) -> Vec<TxHash> {
+        let (_, txs) = TransactionJournal::<TransactionSigned>::open(path).unwrap();
+        txs.iter().map(|(_, tx)| *tx.tx_hash()).collect()
+    }
+
+    /// Waits until the condition holds, failing after a timeout.
+    async fn wait_until(mut condition: impl FnMut() -> bool) {
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !condition() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("condition not met in time");
+    }
+
+    #[test]
+    fn journal_entry_roundtrip() {
+        let tx = transaction();
+        for entry in [
+            JournalEntry::Insert { origin: TransactionOrigin::Local, transaction: tx.clone() },
+            JournalEntry::Insert { origin: TransactionOrigin::Private, transaction: tx.clone() },
+            JournalEntry::Remove(*tx.tx_hash()),
+        ] {
+            let encoded = alloy_rlp::encode(&entry);
+            assert_eq!(encoded.len(), entry.length());
+            let decoded =
+                JournalEntry::<TransactionSigned>::decode(&mut encoded.as_slice()).unwrap();
+            assert_eq!(decoded, entry);
+        }
+    }
+
+    #[test]
+    fn replay_journal() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("journal.rlp");
+        let tx = transaction();
+        let hash = *tx.tx_hash();
+
+        let (mut journal, txs) = TransactionJournal::<TransactionSigned>::open(&path).unwrap();
+        assert!(txs.is_empty());
+
+        journal.insert(TransactionOrigin::Local, tx.clone()).unwrap();
+        // duplicate inserts are not recorded
+        journal.insert(TransactionOrigin::Local, tx.clone()).unwrap();
+        drop(journal);
+
+        let (mut journal, txs) = TransactionJournal::<TransactionSigned>::open(&path).unwrap();
+        assert_eq!(txs, vec![(TransactionOrigin::Local, tx.clone())]);
+        assert!(journal.contains(&hash));
+
+        journal.remove(hash).unwrap();
+        drop(journal);
+
+        let (journal, txs) = TransactionJournal::<TransactionSigned>::open(&path).unwrap();
+        assert!(txs.is_empty());
+        assert!(journal.is_empty());
+    }
+
+    #[test]
+    fn replay_journal_with_truncated_tail() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("journal.rlp");
+        let tx = transaction();
+
+        let (mut journal, _) = TransactionJournal::<TransactionSigned>::open(&path).unwrap();
+        journal.insert(TransactionOrigin::Local, tx.clone()).unwrap();
+        drop(journal);
+
+        // simulate a crash in the middle of writing an entry
+        let mut data = std::fs::read(&path).unwrap();
+        let entry = alloy_rlp::encode(JournalEntry::<TransactionSigned>::Remove(*tx.tx_hash()));
+        data.extend_from_slice(&entry[..entry.len() / 2]);
+        std::fs::write(&path, data).unwrap();
+
+        let (_, txs) = TransactionJournal::<TransactionSigned>::open(&path).unwrap();
+        assert_eq!(txs, vec![(TransactionOrigin::Local, tx)]);
+    }
+
+    #[test]
+    fn compact_journal() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("journal.rlp");
+        let tx = transaction();
+
+        let (mut journal, _) = TransactionJournal::<TransactionSigned>::open(&path).unwrap();
+        journal.insert(TransactionOrigin::Local, tx.clone()).unwrap();
+        journal.remove(*tx.tx_hash()).unwrap();
+        journal.insert(TransactionOrigin::Local, tx.clone()).unwrap();
+        let uncompacted = std::fs::metadata(&path).unwrap().len();
+
+        journal.rotate([(TransactionOrigin::Local, tx.clone())]).unwrap();
+        assert!(std::fs::metadata(&path).unwrap().len() < uncompacted);
+
+        // appends continue after the rotation
+        journal.remove(*tx.tx_hash()).unwrap();
+        drop(journal);
+
+        let (_, txs) = TransactionJournal::<TransactionSigned>::open(&path).unwrap();
+        assert!(txs.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn journal_pool_transactions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("journal.rlp");
+        let config = TransactionJournalConfig::new(path.clone());
+        let transaction =
+            EthPooledTransaction::from_pooled(pooled_transaction().try_into_recovered().unwrap());
+        let hash = *transaction.hash();
+        let new_pool = || {
+            let provider = MockEthProvider::default();
+            let sender: Address = hex!("1f9090aaE28b8a3dCeaDf281B0F12828e676c326").into();
+            provider.add_account(sender, ExtendedAccount::new(42, U256::MAX));
+            let blob_store = InMemoryBlobStore::default();
+            Pool::new(
+                EthTransactionValidatorBuilder::new(provider).build(blob_store.clone()),
+                CoinbaseTipOrdering::default(),
+                blob_store,
+                Default::default(),
+            )
+        };
+        let manager = TaskManager::new(tokio::runtime::Handle::current());
+
+        // inserts are journaled while the node runs
+        let pool = new_pool();
+        let journal_task = manager.executor().spawn_with_graceful_shutdown_signal(|shutdown| {
+            run_transaction_journal(shutdown, pool.clone(), config.clone())
+        });
+        pool.add_transaction(TransactionOrigin::Local, transaction).await.unwrap();
+        wait_until(|| journaled(&path) == [hash]).await;
+
+        // the node crashes without compacting the journal
+        journal_task.abort();
+        let _ = journal_task.await;
+        drop(pool);
+
+        // the journal is replayed into the pool of the restarted node
+        let pool = new_pool();
+        manager.executor().spawn_with_graceful_shutdown_signal(|shutdown| {
+            run_transaction_journal(shutdown, pool.clone(), config.clone())
+        });
+        wait_until(|| pool.get(&hash).is_some()).await;
+        assert_eq!(pool.get(&hash).unwrap().origin, TransactionOrigin::Local);
+
+        // removals are journaled as well
+        pool.remove_transactions(vec![hash]);
+        wait_until(|| journaled(&path).is_empty()).await;
+
+        manager.graceful_shutdown();
+        assert!(journaled(&path).is_empty());
+    }
+}
@@ -152,6 +152,11 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
+use crate::{
+    admission::{AdmissionConfig, AdmissionPolicy, AdmissionSource},
+    identifier::TransactionId,
+    pool::PoolInner,
+};
 pub use crate::{
     blobstore::{BlobStore, BlobStoreError},
     config::{
@@ -163,8 +168,10 @@ pub use crate::{
     error::PoolResult,
     ordering::{CoinbaseTipOrdering, Priority, TransactionOrdering},
     pool::{
-        blob_tx_priority, fee_delta, state::SubPool, AllTransactionsEvents, FullTransactionEvent,
-        TransactionEvent, TransactionEvents,
+        blob_tx_priority, fee_delta,
+        state::{ParkedReason, SubPool},
+        AllTransactionsEvents, FullTransactionEvent, PoolTransactionEvent, TransactionEvent,
+        TransactionEvents, TransactionHistoryEntry,
     },
     traits::*,
     validate::{
@@ -172,7 +179,6 @@ pub use crate::{
         TransactionValidator, ValidPoolTransaction,
     },
 };
-use crate::{identifier::TransactionId, pool::PoolInner};
 use alloy_eips::eip4844::{BlobAndProofV1, BlobTransactionSidecar};
 use alloy_primitives::{Address, TxHash, B256, U256};
 use aquamarine as _;
@@ -186,7 +192,9 @@ use std::{collections::HashSet, sync::Arc};
 use tokio::sync::mpsc::Receiver;
 use tracing::{instrument, trace};
 
+pub mod admission;
 pub mod error;
+pub mod journal;
 pub mod maintain;
 pub mod metrics;
 pub mod noop;
@@ -240,6 +248,21 @@ where
         self.inner().config()
     }
 
+    /// Replaces the policy that decides whether new transactions are admitted into the pool.
+    ///
+    /// Transactions that are already in the pool are not affected.
+    pub fn set_admission_policy(&self, admission: Arc<dyn AdmissionPolicy<V::Transaction>>) {
+        self.pool.set_admission_policy(admission)
+    }
+
+    /// Replaces the config of the pool's admission rules with the given [`AdmissionConfig`].
+    ///
+    /// This can be used to reload the rules at runtime, the rate limits of the rules are kept. A
+    /// policy installed with [`Self::set_admission_policy`] is not replaced.
+    pub fn set_admission_config(&self, config: AdmissionConfig) {
+        self.pool.set_admission_config(config)
+    }
+
     /// Returns future that validates all transactions in the given iterator.
     ///
     /// This returns the validated transactions in the iterator's order.
@@ -367,6 +390,18 @@ where
         results.pop().expect("result length is the same as the input")
     }
 
+    async fn add_transaction_from(
+        &self,
+        origin: TransactionOrigin,
+        source: AdmissionSource,
+        transaction: Self::Transaction,
+    ) -> PoolResult<TxHash> {
+        let (_, tx) = self.validate(origin, transaction).await;
+        let mut results =
+            self.pool.add_transactions_from(origin, Some(source), std::iter::once(tx));
+        results.pop().expect("result length is the same as the input")
+    }
+
     async fn add_transactions(
         &self,
         origin: TransactionOrigin,
@@ -380,6 +415,24 @@ where
         self.pool.add_transactions(origin, validated.into_iter().map(|(_, tx)| tx))
     }
 
+    async fn add_transactions_from(
+        &self,
+        origin: TransactionOrigin,
+        source: AdmissionSource,
+        transactions: Vec<Self::Transaction>,
+    ) -> Vec<PoolResult<TxHash>> {
+        if transactions.is_empty() {
+            return Vec::new()
+        }
+        let validated = self.validate_all(origin, transactions).await;
+
+        self.pool.add_transactions_from(
+            origin,
+            Some(source),
+            validated.into_iter().map(|(_, tx)| tx),
+        )
+    }
+
     fn transaction_event_listener(&self, tx_hash: TxHash) -> Option<TransactionEvents> {
         self.pool.add_transaction_event_listener(tx_hash)
     }
@@ -451,6 +504,10 @@ where
         self.pool.best_transactions_with_attributes(best_transactions_attributes)
     }
 
+    fn simulate_block_info(&self, info: BlockInfo) -> PoolFeeSimulation<Self::Transaction> {
+        self.pool.simulate_block_info(info)
+    }
+
     fn pending_transactions(&self) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
         self.pool.pending_transactions()
     }
@@ -517,6 +574,14 @@ where
         self.pool.get_transactions_by_sender(sender)
     }
 
+    fn transaction_status(&self, tx_hash: TxHash) -> Option<PoolTransactionStatus> {
+        self.pool.transaction_status(tx_hash)
+    }
+
+    fn transaction_statuses_by_sender(&self, sender: Address) -> Vec<PoolTransactionStatus> {
+        self.pool.transaction_statuses_by_sender(sender)
+    }
+
     fn get_pending_transactions_with_predicate(
         &self,
         predicate: impl FnMut(&ValidPoolTransaction<Self::Transaction>) -> bool,
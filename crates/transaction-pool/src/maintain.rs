@@ -3,6 +3,7 @@
 use crate::{
     blobstore::{BlobStoreCanonTracker, BlobStoreUpdates},
     error::PoolError,
+    journal::{run_transaction_journal, TransactionJournalConfig},
     metrics::MaintainPoolMetrics,
     traits::{CanonicalStateUpdate, EthPoolTransaction, TransactionPool, TransactionPoolExt},
     BlockInfo, PoolTransaction, PoolUpdateKind,
@@ -72,12 +73,23 @@ impl Default for MaintainPoolConfig {
 pub struct LocalTransactionBackupConfig {
     /// Path to transactions backup file
     pub transactions_path: Option<PathBuf>,
+    /// Settings for the continuous transaction journal, if enabled.
+    ///
+    /// If set, the journal replaces the backup written on shutdown. An existing backup file is
+    /// still reinserted on startup.
+    pub journal: Option<TransactionJournalConfig>,
 }
 
 impl LocalTransactionBackupConfig {
     /// Receive path to transactions backup and return initialized config
     pub const fn with_local_txs_backup(transactions_path: PathBuf) -> Self {
-        Self { transactions_path: Some(transactions_path) }
+        Self { transactions_path: Some(transactions_path), journal: None }
+    }
+
+    /// Enables the continuous transaction journal with the given settings.
+    pub fn with_journal(mut self, journal: TransactionJournalConfig) -> Self {
+        self.journal = Some(journal);
+        self
     }
 }
 
@@ -297,9 +309,14 @@ pub async fn maintain_transaction_pool<N, Client, P, St, Tasks>(
                 let new_first = new_blocks.first();
                 let old_first = old_blocks.first();
 
+                // the canonical head was unwound to one of its ancestors, e.g. a dev chain was
+                // reverted to a snapshot, in which case the new chain only consists of the new head
+                let is_unwind = new_tip.hash() == old_first.parent_hash();
+
                 // check if the reorg is not canonical with the pool's block
                 if !(old_first.parent_hash() == pool_info.last_seen_block_hash ||
-                    new_first.parent_hash() == pool_info.last_seen_block_hash)
+                    new_first.parent_hash() == pool_info.last_seen_block_hash ||
+                    (is_unwind && old_blocks.tip().hash() == pool_info.last_seen_block_hash))
                 {
                     // the new block points to a higher block than the oldest block in the old chain
                     maintained_state = MaintainedPoolState::Drifted;
@@ -358,9 +375,11 @@ pub async fn maintain_transaction_pool<N, Client, P, St, Tasks>(
                 let new_mined_transactions: HashSet<_> = new_blocks.transaction_hashes().collect();
 
                 // update the pool then re-inject the pruned transactions
-                // find all transactions that were mined in the old chain but not in the new chain
+                // find all transactions that were mined in the old chain but not in the new chain,
+                // the transactions of unwound blocks are dropped
                 let pruned_old_transactions = old_blocks
                     .transactions_ecrecovered()
+                    .filter(|_| !is_unwind)
                     .filter(|tx| !new_mined_transactions.contains(tx.tx_hash()))
                     .filter_map(|tx| {
                         if tx.is_eip4844() {
@@ -676,6 +695,9 @@ pub enum TransactionsBackupError {
 
 /// Task which manages saving local transactions to the persistent file in case of shutdown.
 /// Reloads the transactions from the file on the boot up and inserts them into the pool.
+///
+/// If a [`TransactionJournalConfig`] is configured, transactions are journaled continuously
+/// instead, so they also survive a crash of the node.
 pub async fn backup_local_transactions_task<P>(
     shutdown: reth_tasks::shutdown::GracefulShutdown,
     pool: P,
@@ -683,15 +705,21 @@ pub async fn backup_local_transactions_task<P>(
 ) where
     P: TransactionPool<Transaction: PoolTransaction<Consensus: SignedTransaction>> + Clone,
 {
+    if let Some(transactions_path) = &config.transactions_path {
+        if let Err(err) = load_and_reinsert_transactions(pool.clone(), transactions_path).await {
+            error!(target: "txpool", "{}", err)
+        }
+    }
+
+    if let Some(journal) = config.journal {
+        return run_transaction_journal(shutdown, pool, journal).await
+    }
+
     let Some(transactions_path) = config.transactions_path else {
         // nothing to do
         return
     };
 
-    if let Err(err) = load_and_reinsert_transactions(pool.clone(), &transactions_path).await {
-        error!(target: "txpool", "{}", err)
-    }
-
     let graceful_guard = shutdown.await;
 
     // write transactions to disk
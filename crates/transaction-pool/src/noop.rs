@@ -12,9 +12,10 @@ use crate::{
     },
     validate::ValidTransaction,
     AllPoolTransactions, AllTransactionsEvents, BestTransactions, BlockInfo, EthPoolTransaction,
-    EthPooledTransaction, NewTransactionEvent, PoolResult, PoolSize, PoolTransaction,
-    PropagatedTransactions, TransactionEvents, TransactionOrigin, TransactionPool,
-    TransactionValidationOutcome, TransactionValidator, ValidPoolTransaction,
+    EthPooledTransaction, NewTransactionEvent, PoolFeeSimulation, PoolResult, PoolSize,
+    PoolTransaction, PoolTransactionStatus, PropagatedTransactions, TransactionEvents,
+    TransactionOrigin, TransactionPool, TransactionValidationOutcome, TransactionValidator,
+    ValidPoolTransaction,
 };
 use alloy_eips::{
     eip1559::ETHEREUM_BLOCK_GAS_LIMIT_30M,
@@ -160,6 +161,10 @@ impl TransactionPool for NoopTransactionPool {
         Box::new(std::iter::empty())
     }
 
+    fn simulate_block_info(&self, _info: BlockInfo) -> PoolFeeSimulation<Self::Transaction> {
+        Default::default()
+    }
+
     fn pending_transactions(&self) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
         vec![]
     }
@@ -223,6 +228,14 @@ impl TransactionPool for NoopTransactionPool {
         vec![]
     }
 
+    fn transaction_status(&self, _tx_hash: TxHash) -> Option<PoolTransactionStatus> {
+        None
+    }
+
+    fn transaction_statuses_by_sender(&self, _sender: Address) -> Vec<PoolTransactionStatus> {
+        vec![]
+    }
+
     fn get_pending_transactions_with_predicate(
         &self,
         _predicate: impl FnMut(&ValidPoolTransaction<Self::Transaction>) -> bool,
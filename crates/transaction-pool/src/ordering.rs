@@ -24,7 +24,7 @@ impl<T: Ord + Clone> From<Option<T>> for Priority<T> {
 /// Decides how transactions should be ordered within the pool, depending on a `Priority` value.
 ///
 /// The returned priority must reflect [total order](https://en.wikipedia.org/wiki/Total_order).
-pub trait TransactionOrdering: Send + Sync + 'static {
+pub trait TransactionOrdering: Clone + Send + Sync + 'static {
     /// Priority of a transaction.
     ///
     /// Higher is better.
@@ -33,7 +33,40 @@ pub enum FullTransactionEvent<T: PoolTransaction> {
     /// Transaction became invalid indefinitely.
     Invalid(TxHash),
     /// Transaction was propagated to peers.
-    Propagated(Arc<Vec<PropagateKind>>),
+    Propagated {
+        /// The hash of the propagated transaction.
+        tx_hash: TxHash,
+        /// The peers the transaction was propagated to.
+        peers: Arc<Vec<PropagateKind>>,
+    },
+}
+
+impl<T: PoolTransaction> FullTransactionEvent<T> {
+    /// Returns the hash of the transaction this event belongs to.
+    pub fn hash(&self) -> TxHash {
+        match self {
+            Self::Pending(hash) |
+            Self::Queued(hash) |
+            Self::Discarded(hash) |
+            Self::Invalid(hash) |
+            Self::Mined { tx_hash: hash, .. } |
+            Self::Propagated { tx_hash: hash, .. } => *hash,
+            Self::Replaced { transaction, .. } => *transaction.hash(),
+        }
+    }
+
+    /// Returns the [`TransactionEvent`] this event corresponds to, without the transaction body.
+    pub fn to_transaction_event(&self) -> TransactionEvent {
+        match self {
+            Self::Pending(_) => TransactionEvent::Pending,
+            Self::Queued(_) => TransactionEvent::Queued,
+            Self::Mined { block_hash, .. } => TransactionEvent::Mined(*block_hash),
+            Self::Replaced { replaced_by, .. } => TransactionEvent::Replaced(*replaced_by),
+            Self::Discarded(_) => TransactionEvent::Discarded,
+            Self::Invalid(_) => TransactionEvent::Invalid,
+            Self::Propagated { peers, .. } => TransactionEvent::Propagated(Arc::clone(peers)),
+        }
+    }
 }
 
 impl<T: PoolTransaction> Clone for FullTransactionEvent<T> {
@@ -49,7 +82,9 @@ impl<T: PoolTransaction> Clone for FullTransactionEvent<T> {
             }
             Self::Discarded(hash) => Self::Discarded(*hash),
             Self::Invalid(hash) => Self::Invalid(*hash),
-            Self::Propagated(propagated) => Self::Propagated(Arc::clone(propagated)),
+            Self::Propagated { tx_hash, peers } => {
+                Self::Propagated { tx_hash: *tx_hash, peers: Arc::clone(peers) }
+            }
         }
     }
 }
@@ -83,3 +118,31 @@ impl TransactionEvent {
         matches!(self, Self::Replaced(_) | Self::Mined(_) | Self::Discarded)
     }
 }
+
+/// A [`FullTransactionEvent`] without the transaction body, suitable for external subscribers.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PoolTransactionEvent {
+    /// The hash of the transaction the event belongs to.
+    pub hash: TxHash,
+    /// What happened to the transaction.
+    pub event: TransactionEvent,
+}
+
+impl<T: PoolTransaction> From<FullTransactionEvent<T>> for PoolTransactionEvent {
+    fn from(event: FullTransactionEvent<T>) -> Self {
+        Self { hash: event.hash(), event: event.to_transaction_event() }
+    }
+}
+
+/// A recorded [`TransactionEvent`] in the history of a transaction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TransactionHistoryEntry {
+    /// Unix timestamp in seconds at which the event happened.
+    pub timestamp: u64,
+    /// What happened to the transaction.
+    pub event: TransactionEvent,
+}
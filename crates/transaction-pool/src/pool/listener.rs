@@ -1,17 +1,19 @@
 //! Listeners for the transaction-pool
 
 use crate::{
-    pool::events::{FullTransactionEvent, TransactionEvent},
+    pool::events::{FullTransactionEvent, TransactionEvent, TransactionHistoryEntry},
     traits::PropagateKind,
     PoolTransaction, ValidPoolTransaction,
 };
 use alloy_primitives::{TxHash, B256};
 use futures_util::Stream;
+use schnellru::{ByLength, LruMap};
 use std::{
     collections::{hash_map::Entry, HashMap},
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::{SystemTime, UNIX_EPOCH},
 };
 use tokio::sync::mpsc::{
     error::TrySendError, Receiver, Sender, UnboundedReceiver, UnboundedSender,
@@ -20,6 +22,12 @@ use tokio::sync::mpsc::{
 /// The size of the event channel used to propagate transaction events.
 const TX_POOL_EVENT_CHANNEL_SIZE: usize = 1024;
 
+/// The number of transactions for which the event history is tracked.
+const TX_HISTORY_MAX_TRANSACTIONS: u32 = 10_000;
+
+/// The maximum number of history entries tracked per transaction.
+const TX_HISTORY_MAX_ENTRIES: usize = 32;
+
 /// A Stream that receives [`TransactionEvent`] only for the transaction with the given hash.
 #[derive(Debug)]
 #[must_use = "streams do nothing unless polled"]
@@ -78,6 +86,8 @@ pub(crate) struct PoolEventBroadcast<T: PoolTransaction> {
     all_events_broadcaster: AllPoolEventsBroadcaster<T>,
     /// All listeners for events for a certain transaction hash.
     broadcasters_by_hash: HashMap<TxHash, PoolEventBroadcaster>,
+    /// Recent event history of the most recently updated transactions.
+    history: LruMap<TxHash, Vec<TransactionHistoryEntry>, ByLength>,
 }
 
 impl<T: PoolTransaction> Default for PoolEventBroadcast<T> {
@@ -85,6 +95,7 @@ impl<T: PoolTransaction> Default for PoolEventBroadcast<T> {
         Self {
             all_events_broadcaster: AllPoolEventsBroadcaster::default(),
             broadcasters_by_hash: HashMap::default(),
+            history: LruMap::new(ByLength::new(TX_HISTORY_MAX_TRANSACTIONS)),
         }
     }
 }
@@ -97,6 +108,8 @@ impl<T: PoolTransaction> PoolEventBroadcast<T> {
         event: TransactionEvent,
         pool_event: FullTransactionEvent<T>,
     ) {
+        self.record_history(hash, event.clone());
+
         // Broadcast to all listeners for the transaction hash.
         if let Entry::Occupied(mut sink) = self.broadcasters_by_hash.entry(*hash) {
             sink.get_mut().broadcast(event.clone());
@@ -110,6 +123,23 @@ impl<T: PoolTransaction> PoolEventBroadcast<T> {
         self.all_events_broadcaster.broadcast(pool_event);
     }
 
+    /// Appends the event to the history of the transaction, dropping the oldest entry if the
+    /// history is full.
+    fn record_history(&mut self, hash: &TxHash, event: TransactionEvent) {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        if let Some(entries) = self.history.get_or_insert(*hash, Vec::new) {
+            if entries.len() >= TX_HISTORY_MAX_ENTRIES {
+                entries.remove(0);
+            }
+            entries.push(TransactionHistoryEntry { timestamp, event });
+        }
+    }
+
+    /// Returns the recorded event history of the transaction, oldest first.
+    pub(crate) fn history(&self, hash: &TxHash) -> Vec<TransactionHistoryEntry> {
+        self.history.peek(hash).cloned().unwrap_or_default()
+    }
+
     /// Create a new subscription for the given transaction hash.
     pub(crate) fn subscribe(&mut self, tx_hash: TxHash) -> TransactionEvents {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
@@ -163,7 +193,7 @@ impl<T: PoolTransaction> PoolEventBroadcast<T> {
         self.broadcast_event(
             tx,
             TransactionEvent::Propagated(Arc::clone(&peers)),
-            FullTransactionEvent::Propagated(peers),
+            FullTransactionEvent::Propagated { tx_hash: *tx, peers },
         );
     }
 
@@ -227,3 +257,41 @@ impl PoolEventBroadcaster {
         self.senders.retain(|sender| sender.send(event.clone()).is_ok())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockTransaction;
+
+    #[test]
+    fn records_transaction_history() {
+        let mut broadcast = PoolEventBroadcast::<MockTransaction>::default();
+        let hash = TxHash::random();
+        let other = TxHash::random();
+
+        broadcast.queued(&hash);
+        broadcast.pending(&hash, None);
+        broadcast.discarded(&other);
+
+        let history = broadcast.history(&hash);
+        let events = history.into_iter().map(|entry| entry.event).collect::<Vec<_>>();
+        assert_eq!(events, vec![TransactionEvent::Queued, TransactionEvent::Pending]);
+        assert_eq!(broadcast.history(&other).len(), 1);
+        assert!(broadcast.history(&TxHash::random()).is_empty());
+    }
+
+    #[test]
+    fn bounds_transaction_history() {
+        let mut broadcast = PoolEventBroadcast::<MockTransaction>::default();
+        let hash = TxHash::random();
+
+        for _ in 0..TX_HISTORY_MAX_ENTRIES {
+            broadcast.queued(&hash);
+        }
+        broadcast.pending(&hash, None);
+
+        let history = broadcast.history(&hash);
+        assert_eq!(history.len(), TX_HISTORY_MAX_ENTRIES);
+        assert_eq!(history.last().unwrap().event, TransactionEvent::Pending);
+    }
+}
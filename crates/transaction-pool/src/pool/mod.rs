@@ -66,16 +66,18 @@
 //!    category (2.) and become pending.
 
 use crate::{
+    admission::{AdmissionConfig, AdmissionPolicy, AdmissionSource},
     error::{PoolError, PoolErrorKind, PoolResult},
     identifier::{SenderId, SenderIdentifiers, TransactionId},
     pool::{
         listener::PoolEventBroadcast,
-        state::SubPool,
+        state::{SubPool, TxState},
         txpool::{SenderInfo, TxPool},
     },
     traits::{
-        AllPoolTransactions, BestTransactionsAttributes, BlockInfo, NewTransactionEvent, PoolSize,
-        PoolTransaction, PropagatedTransactions, TransactionOrigin,
+        AllPoolTransactions, BestTransactionsAttributes, BlockInfo, NewTransactionEvent,
+        PoolFeeSimulation, PoolSize, PoolTransaction, PoolTransactionStatus,
+        PropagatedTransactions, TransactionOrigin,
     },
     validate::{TransactionValidationOutcome, ValidPoolTransaction},
     CanonicalStateUpdate, EthPoolTransaction, PoolConfig, TransactionOrdering,
@@ -103,7 +105,9 @@ use crate::{
 };
 pub use best::{BestTransactionFilter, BestTransactionsWithPrioritizedSenders};
 pub use blob::{blob_tx_priority, fee_delta};
-pub use events::{FullTransactionEvent, TransactionEvent};
+pub use events::{
+    FullTransactionEvent, PoolTransactionEvent, TransactionEvent, TransactionHistoryEntry,
+};
 pub use listener::{AllTransactionsEvents, TransactionEvents};
 pub use parked::{BasefeeOrd, ParkedOrd, ParkedPool, QueuedOrd};
 pub use pending::PendingPool;
@@ -196,6 +200,16 @@ where
         self.pool.write().set_block_info(info)
     }
 
+    /// Replaces the policy that decides whether new transactions are admitted into the pool.
+    pub fn set_admission_policy(&self, admission: Arc<dyn AdmissionPolicy<T::Transaction>>) {
+        self.pool.write().set_admission_policy(admission)
+    }
+
+    /// Updates the config of the pool's admission rules.
+    pub fn set_admission_config(&self, config: AdmissionConfig) {
+        self.pool.write().set_admission_config(config)
+    }
+
     /// Returns the internal [`SenderId`] for this address
     pub fn get_sender_id(&self, addr: Address) -> SenderId {
         self.identifiers.write().sender_id_or_create(addr)
@@ -431,6 +445,7 @@ where
         &self,
         pool: &mut RwLockWriteGuard<'_, TxPool<T>>,
         origin: TransactionOrigin,
+        source: Option<AdmissionSource>,
         tx: TransactionValidationOutcome<T::Transaction>,
     ) -> PoolResult<TxHash> {
         match tx {
@@ -463,7 +478,7 @@ where
                     origin,
                 };
 
-                let added = pool.add_transaction(tx, balance, state_nonce)?;
+                let added = pool.add_transaction_from(tx, balance, state_nonce, source)?;
                 let hash = *added.hash();
 
                 // transaction was successfully inserted into the pool
@@ -534,13 +549,24 @@ where
         &self,
         origin: TransactionOrigin,
         transactions: impl IntoIterator<Item = TransactionValidationOutcome<T::Transaction>>,
+    ) -> Vec<PoolResult<TxHash>> {
+        self.add_transactions_from(origin, None, transactions)
+    }
+
+    /// Adds all transactions in the iterator that were received from the given source to the
+    /// pool, see [`Self::add_transactions`].
+    pub fn add_transactions_from(
+        &self,
+        origin: TransactionOrigin,
+        source: Option<AdmissionSource>,
+        transactions: impl IntoIterator<Item = TransactionValidationOutcome<T::Transaction>>,
     ) -> Vec<PoolResult<TxHash>> {
         // Add the transactions and enforce the pool size limits in one write lock
         let (mut added, discarded) = {
             let mut pool = self.pool.write();
             let added = transactions
                 .into_iter()
-                .map(|tx| self.add_transaction(&mut pool, origin, tx))
+                .map(|tx| self.add_transaction(&mut pool, origin, source, tx))
                 .collect::<Vec<_>>();
 
             // Enforce the pool size limits if at least one transaction was added successfully
@@ -688,6 +714,14 @@ where
         self.get_pool_data().best_transactions()
     }
 
+    /// Projects the pool onto the given block info without modifying it.
+    ///
+    /// The pool is only locked while the transactions are snapshotted, not during the projection.
+    pub fn simulate_block_info(&self, info: BlockInfo) -> PoolFeeSimulation<T::Transaction> {
+        let snapshot = self.get_pool_data().fee_simulation_snapshot();
+        snapshot.simulate(info)
+    }
+
     /// Returns an iterator that yields transactions that are ready to be included in the block with
     /// the given base fee and optional blob fee attributes.
     pub fn best_transactions_with_attributes(
@@ -801,6 +835,51 @@ where
         self.get_pool_data().get_transactions_by_sender(sender_id)
     }
 
+    /// Returns the status of the transaction with the given hash, if it is in the pool or its
+    /// history is still tracked after it left the pool.
+    pub fn transaction_status(&self, tx_hash: TxHash) -> Option<PoolTransactionStatus> {
+        let state = self.get_pool_data().transaction_state(&tx_hash);
+        if let Some((subpool, state)) = state {
+            return Some(self.transaction_status_with_history(tx_hash, subpool, state))
+        }
+
+        // the transaction left the pool, e.g. because it was mined, replaced or discarded
+        let history = self.event_listener.read().history(&tx_hash);
+        (!history.is_empty()).then(|| PoolTransactionStatus {
+            hash: tx_hash,
+            subpool: None,
+            parked_reasons: Vec::new(),
+            history,
+        })
+    }
+
+    /// Returns the status of all transactions of the address.
+    pub fn transaction_statuses_by_sender(&self, sender: Address) -> Vec<PoolTransactionStatus> {
+        let sender_id = self.get_sender_id(sender);
+        let states = self.get_pool_data().transaction_states_by_sender(sender_id);
+        states
+            .into_iter()
+            .map(|(hash, subpool, state)| {
+                self.transaction_status_with_history(hash, subpool, state)
+            })
+            .collect()
+    }
+
+    /// Assembles the [`PoolTransactionStatus`] of a transaction, including its recorded history.
+    fn transaction_status_with_history(
+        &self,
+        hash: TxHash,
+        subpool: SubPool,
+        state: TxState,
+    ) -> PoolTransactionStatus {
+        PoolTransactionStatus {
+            hash,
+            subpool: Some(subpool),
+            parked_reasons: state.parked_reasons(),
+            history: self.event_listener.read().history(&hash),
+        }
+    }
+
     /// Returns all queued transactions of the address by sender
     pub fn get_queued_transactions_by_sender(
         &self,
@@ -67,6 +67,11 @@ impl<T: TransactionOrdering> PendingPool<T> {
         }
     }
 
+    /// Returns the ordering of the pool.
+    pub(crate) const fn ordering(&self) -> &T {
+        &self.ordering
+    }
+
     /// Clear all transactions from the pool without resetting other values.
     /// Used for atomic reordering during basefee update.
     ///
@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 bitflags::bitflags! {
     /// Marker to represents the current state of a transaction in the pool and from which the corresponding sub-pool is derived, depending on what bits are set.
     ///
@@ -68,10 +71,61 @@ impl TxState {
     pub(crate) const fn has_nonce_gap(&self) -> bool {
         !self.intersects(Self::NO_NONCE_GAPS)
     }
+
+    /// Returns the reasons why a transaction in this state is not pending.
+    ///
+    /// This is empty if the transaction is pending.
+    pub(crate) fn parked_reasons(&self) -> Vec<ParkedReason> {
+        let mut reasons = Vec::new();
+        if self.is_pending() {
+            return reasons
+        }
+        if !self.contains(Self::NO_PARKED_ANCESTORS) {
+            reasons.push(ParkedReason::ParkedAncestor);
+        }
+        if self.has_nonce_gap() {
+            reasons.push(ParkedReason::NonceGap);
+        }
+        if !self.contains(Self::ENOUGH_BALANCE) {
+            reasons.push(ParkedReason::InsufficientBalance);
+        }
+        if !self.contains(Self::NOT_TOO_MUCH_GAS) {
+            reasons.push(ParkedReason::ExceedsBlockGasLimit);
+        }
+        if !self.contains(Self::ENOUGH_FEE_CAP_BLOCK) {
+            reasons.push(ParkedReason::FeeCapBelowBaseFee);
+        }
+        if !self.contains(Self::ENOUGH_BLOB_FEE_CAP_BLOCK) {
+            reasons.push(ParkedReason::BlobFeeCapBelowBlobFee);
+        }
+        reasons
+    }
+}
+
+/// The reason why a transaction is parked and not included in the pending sub-pool.
+///
+/// Derived from the [`TxState`] bits that are _not_ set for the transaction.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum ParkedReason {
+    /// A transaction of the same sender with a lower nonce is parked.
+    ParkedAncestor,
+    /// There is a gap between the sender's on chain nonce and the transaction's nonce.
+    NonceGap,
+    /// The sender's balance does not cover the cumulative cost of this and prior transactions.
+    InsufficientBalance,
+    /// The transaction's gas limit exceeds the block gas limit.
+    ExceedsBlockGasLimit,
+    /// The transaction's `maxFeePerGas` is below the base fee of the pending block.
+    FeeCapBelowBaseFee,
+    /// The transaction's `maxFeePerBlobGas` is below the blob fee of the pending block.
+    BlobFeeCapBelowBlobFee,
 }
 
 /// Identifier for the transaction Sub-pool
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(u8)]
 pub enum SubPool {
     /// The queued sub-pool contains transactions that are not ready to be included in the next
@@ -234,6 +288,27 @@ mod tests {
         assert!(state.contains(TxState::ENOUGH_FEE_CAP_BLOCK));
     }
 
+    #[test]
+    fn test_parked_reasons() {
+        assert!(TxState::PENDING_POOL_BITS.parked_reasons().is_empty());
+
+        let state = TxState::BASE_FEE_POOL_BITS | TxState::ENOUGH_BLOB_FEE_CAP_BLOCK;
+        assert_eq!(state.parked_reasons(), vec![ParkedReason::FeeCapBelowBaseFee]);
+
+        let state = TxState::NO_PARKED_ANCESTORS |
+            TxState::NOT_TOO_MUCH_GAS |
+            TxState::ENOUGH_FEE_CAP_BLOCK |
+            TxState::ENOUGH_BLOB_FEE_CAP_BLOCK;
+        assert_eq!(
+            state.parked_reasons(),
+            vec![ParkedReason::NonceGap, ParkedReason::InsufficientBalance]
+        );
+
+        let mut state = TxState::PENDING_POOL_BITS | TxState::BLOB_TRANSACTION;
+        state.remove(TxState::ENOUGH_BLOB_FEE_CAP_BLOCK);
+        assert_eq!(state.parked_reasons(), vec![ParkedReason::BlobFeeCapBelowBlobFee]);
+    }
+
     #[test]
     fn test_tx_base_fee() {
         let state = TxState::BASE_FEE_POOL_BITS;
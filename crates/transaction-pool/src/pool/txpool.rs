@@ -1,6 +1,10 @@
 //! The internal transaction pool implementation.
 
 use crate::{
+    admission::{
+        AdmissionConfig, AdmissionContext, AdmissionPolicy, AdmissionRules, AdmissionSource,
+        OriginUsage,
+    },
     config::{LocalTransactionConfig, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER},
     error::{Eip4844PoolTransactionError, InvalidPoolTransactionError, PoolError, PoolErrorKind},
     identifier::{SenderId, TransactionId},
@@ -14,9 +18,9 @@ use crate::{
         update::{Destination, PoolUpdate},
         AddedPendingTransaction, AddedTransaction, OnNewCanonicalStateOutcome,
     },
-    traits::{BestTransactionsAttributes, BlockInfo, PoolSize},
+    traits::{BestTransactionsAttributes, BlockInfo, PoolFeeSimulation, PoolSize},
     PoolConfig, PoolResult, PoolTransaction, PoolUpdateKind, PriceBumpConfig, TransactionOrdering,
-    ValidPoolTransaction, U256,
+    TransactionOrigin, ValidPoolTransaction, U256,
 };
 use alloy_consensus::constants::{
     EIP1559_TX_TYPE_ID, EIP2930_TX_TYPE_ID, EIP4844_TX_TYPE_ID, EIP7702_TX_TYPE_ID,
@@ -28,6 +32,7 @@ use alloy_eips::{
     Typed2718,
 };
 use alloy_primitives::{Address, TxHash, B256};
+use reth_metrics::metrics::{with_local_recorder, NoopRecorder};
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use std::{
@@ -90,6 +95,10 @@ pub struct TxPool<T: TransactionOrdering> {
     pending_pool: PendingPool<T>,
     /// Pool settings to enforce limits etc.
     config: PoolConfig,
+    /// Decides whether new transactions are admitted into the pool.
+    admission: Arc<dyn AdmissionPolicy<T::Transaction>>,
+    /// The configurable admission rules, these are the admission policy unless replaced.
+    admission_rules: Arc<AdmissionRules>,
     /// queued subpool
     ///
     /// Holds all parked transactions that depend on external changes from the sender:
@@ -122,6 +131,7 @@ pub struct TxPool<T: TransactionOrdering> {
 impl<T: TransactionOrdering> TxPool<T> {
     /// Create a new graph pool instance.
     pub fn new(ordering: T, config: PoolConfig) -> Self {
+        let admission_rules = Arc::new(AdmissionRules::new(config.admission.clone()));
         Self {
             sender_info: Default::default(),
             pending_pool: PendingPool::new(ordering),
@@ -129,12 +139,46 @@ impl<T: TransactionOrdering> TxPool<T> {
             basefee_pool: Default::default(),
             blob_pool: Default::default(),
             all_transactions: AllTransactions::new(&config),
+            admission: admission_rules.clone(),
+            admission_rules,
             config,
             metrics: Default::default(),
             latest_update_kind: None,
         }
     }
 
+    /// Replaces the policy that decides whether new transactions are admitted into the pool.
+    pub(crate) fn set_admission_policy(
+        &mut self,
+        admission: Arc<dyn AdmissionPolicy<T::Transaction>>,
+    ) {
+        self.admission = admission;
+    }
+
+    /// Updates the config of the [`AdmissionRules`].
+    ///
+    /// The rate limit state of the rules is kept. A policy installed with
+    /// [`Self::set_admission_policy`] stays in place, the config only applies to the rules.
+    pub(crate) fn set_admission_config(&mut self, config: AdmissionConfig) {
+        self.admission_rules.set_config(config.clone());
+        self.config.admission = config;
+    }
+
+    /// Returns the state of the pool the given transaction would be admitted into.
+    fn admission_context(
+        &self,
+        tx: &ValidPoolTransaction<T::Transaction>,
+        source: Option<AdmissionSource>,
+    ) -> AdmissionContext {
+        AdmissionContext {
+            origin_usage: self.all_transactions.origin_usage(tx.origin),
+            pool_txs: self.all_transactions.len(),
+            pool_capacity: self.config.max_txs(),
+            is_replacement: self.all_transactions.txs.contains_key(tx.id()),
+            source,
+        }
+    }
+
     /// Retrieves the highest nonce for a specific sender from the transaction pool.
     pub fn get_highest_nonce_by_sender(&self, sender: SenderId) -> Option<u64> {
         self.all().txs_iter(sender).last().map(|(_, tx)| tx.transaction.nonce())
@@ -393,6 +437,32 @@ impl<T: TransactionOrdering> TxPool<T> {
         }
     }
 
+    /// Returns a snapshot of the pool that can be projected onto a different [`BlockInfo`], see
+    /// [`FeeSimulationSnapshot::simulate`].
+    ///
+    /// This only clones the [`Arc`]s of the transactions, so that the pool doesn't need to be
+    /// locked while the projection is computed.
+    pub(crate) fn fee_simulation_snapshot(&self) -> FeeSimulationSnapshot<T> {
+        FeeSimulationSnapshot {
+            ordering: self.pending_pool.ordering().clone(),
+            config: self.config.clone(),
+            block_info: self.block_info(),
+            transactions: self
+                .all_transactions
+                .txs
+                .values()
+                .map(|tx| {
+                    let sender_info = self
+                        .sender_info
+                        .get(&tx.transaction.sender_id())
+                        .cloned()
+                        .unwrap_or_default();
+                    (tx.transaction.clone(), sender_info)
+                })
+                .collect(),
+        }
+    }
+
     /// Returns all transactions from the pending sub-pool
     pub(crate) fn pending_transactions(&self) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         self.pending_pool.all().collect()
@@ -494,6 +564,23 @@ impl<T: TransactionOrdering> TxPool<T> {
         self.all_transactions.txs_iter(sender).map(|(_, tx)| Arc::clone(&tx.transaction)).collect()
     }
 
+    /// Returns the sub-pool and state of the transaction with the given hash, if it exists.
+    pub(crate) fn transaction_state(&self, tx_hash: &TxHash) -> Option<(SubPool, TxState)> {
+        let id = self.all_transactions.by_hash.get(tx_hash)?.id();
+        self.all_transactions.get(id).map(|tx| (tx.subpool, tx.state))
+    }
+
+    /// Returns the hash, sub-pool and state of all transactions of the sender.
+    pub(crate) fn transaction_states_by_sender(
+        &self,
+        sender: SenderId,
+    ) -> Vec<(TxHash, SubPool, TxState)> {
+        self.all_transactions
+            .txs_iter(sender)
+            .map(|(_, tx)| (*tx.transaction.hash(), tx.subpool, tx.state))
+            .collect()
+    }
+
     /// Updates the transactions for the changed senders.
     pub(crate) fn update_accounts(
         &mut self,
@@ -619,11 +706,33 @@ impl<T: TransactionOrdering> TxPool<T> {
         tx: ValidPoolTransaction<T::Transaction>,
         on_chain_balance: U256,
         on_chain_nonce: u64,
+    ) -> PoolResult<AddedTransaction<T::Transaction>> {
+        self.add_transaction_from(tx, on_chain_balance, on_chain_nonce, None)
+    }
+
+    /// Adds the transaction into the pool, see [`Self::add_transaction`].
+    ///
+    /// The source the transaction was received from is passed to the admission policy, which is
+    /// notified if the transaction was inserted.
+    pub(crate) fn add_transaction_from(
+        &mut self,
+        tx: ValidPoolTransaction<T::Transaction>,
+        on_chain_balance: U256,
+        on_chain_nonce: u64,
+        source: Option<AdmissionSource>,
     ) -> PoolResult<AddedTransaction<T::Transaction>> {
         if self.contains(tx.hash()) {
             return Err(PoolError::new(*tx.hash(), PoolErrorKind::AlreadyImported))
         }
 
+        let admission_ctx = self.admission_context(&tx, source);
+        if let Err(err) = self.admission.check(&tx, &admission_ctx) {
+            return Err(PoolError::new(
+                *tx.hash(),
+                PoolErrorKind::InvalidTransaction(InvalidPoolTransactionError::Admission(err)),
+            ))
+        }
+
         // Update sender info with balance and nonce
         self.sender_info
             .entry(tx.sender_id())
@@ -632,6 +741,8 @@ impl<T: TransactionOrdering> TxPool<T> {
 
         match self.all_transactions.insert_tx(tx, on_chain_balance, on_chain_nonce) {
             Ok(InsertOk { transaction, move_to, replaced_tx, updates, .. }) => {
+                self.admission.on_inserted(&transaction, &admission_ctx);
+
                 // replace the new tx and remove the replaced in the subpool(s)
                 self.add_new_transaction(transaction.clone(), replaced_tx.clone(), move_to);
                 // Update inserted transactions metric
@@ -1104,6 +1215,8 @@ pub(crate) struct AllTransactions<T: PoolTransaction> {
     txs: BTreeMap<TransactionId, PoolInternalTransaction<T>>,
     /// Tracks the number of transactions by sender that are currently in the pool.
     tx_counter: FxHashMap<SenderId, usize>,
+    /// Tracks the number of transactions and their gas by origin.
+    origin_usage: HashMap<TransactionOrigin, OriginUsage>,
     /// The current block number the pool keeps track of.
     last_seen_block_number: u64,
     /// The current block hash the pool keeps track of.
@@ -1175,6 +1288,26 @@ impl<T: PoolTransaction> AllTransactions<T> {
         }
     }
 
+    /// Returns the number of transactions and their gas of the given origin.
+    pub(crate) fn origin_usage(&self, origin: TransactionOrigin) -> OriginUsage {
+        self.origin_usage.get(&origin).copied().unwrap_or_default()
+    }
+
+    /// Adds the transaction to the usage of its origin
+    fn origin_usage_inc(&mut self, tx: &ValidPoolTransaction<T>) {
+        let usage = self.origin_usage.entry(tx.origin).or_default();
+        usage.txs += 1;
+        usage.gas = usage.gas.saturating_add(tx.gas_limit());
+    }
+
+    /// Removes the transaction from the usage of its origin
+    fn origin_usage_decr(&mut self, tx: &ValidPoolTransaction<T>) {
+        if let Some(usage) = self.origin_usage.get_mut(&tx.origin) {
+            usage.txs = usage.txs.saturating_sub(1);
+            usage.gas = usage.gas.saturating_sub(tx.gas_limit());
+        }
+    }
+
     /// Updates the block specific info
     fn set_block_info(&mut self, block_info: BlockInfo) {
         let BlockInfo {
@@ -1453,6 +1586,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
         let internal = self.txs.remove(&tx.transaction_id)?;
         // decrement the counter for the sender.
         self.tx_decr(tx.sender_id());
+        self.origin_usage_decr(&tx);
         self.update_size_metrics();
         Some((tx, internal.subpool))
     }
@@ -1470,6 +1604,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
 
         // decrement the counter for the sender.
         self.tx_decr(internal.transaction.sender_id());
+        self.origin_usage_decr(&internal.transaction);
 
         let result =
             self.by_hash.remove(internal.transaction.hash()).map(|tx| (tx, internal.subpool));
@@ -1794,6 +1929,10 @@ impl<T: PoolTransaction> AllTransactions<T> {
         if replaced_tx.is_none() {
             self.tx_inc(inserted_tx_id.sender);
         }
+        self.origin_usage_inc(&transaction);
+        if let Some((replaced, _)) = &replaced_tx {
+            self.origin_usage_decr(replaced);
+        }
 
         self.update_size_metrics();
 
@@ -1836,6 +1975,7 @@ impl<T: PoolTransaction> Default for AllTransactions<T> {
             by_hash: Default::default(),
             txs: Default::default(),
             tx_counter: Default::default(),
+            origin_usage: Default::default(),
             last_seen_block_number: Default::default(),
             last_seen_block_hash: Default::default(),
             pending_fees: Default::default(),
@@ -1953,6 +2093,52 @@ impl<T: PoolTransaction> Default for UpdateOutcome<T> {
     }
 }
 
+/// A copy of the transactions of a [`TxPool`], see [`TxPool::fee_simulation_snapshot`].
+pub(crate) struct FeeSimulationSnapshot<T: TransactionOrdering> {
+    /// The ordering of the pool.
+    ordering: T,
+    /// The config of the pool.
+    config: PoolConfig,
+    /// The block info of the pool.
+    block_info: BlockInfo,
+    /// All transactions of the pool, ordered by sender and nonce, with the info of their sender.
+    transactions: Vec<(Arc<ValidPoolTransaction<T::Transaction>>, SenderInfo)>,
+}
+
+// === impl FeeSimulationSnapshot ===
+
+impl<T: TransactionOrdering> FeeSimulationSnapshot<T> {
+    /// Projects the snapshotted pool onto the given [`BlockInfo`].
+    ///
+    /// All transactions are replayed into a scratch pool with the same ordering and limits, which
+    /// then applies the block info and enforces the sub-pool limits. This is expensive because it
+    /// copies every transaction in the pool.
+    pub(crate) fn simulate(self, info: BlockInfo) -> PoolFeeSimulation<T::Transaction> {
+        let Self { ordering, config, block_info, transactions } = self;
+
+        // the scratch pool must not touch the metrics of the live pool, and it accepts every
+        // transaction of the live pool regardless of the admission rules
+        let mut pool = with_local_recorder(&NoopRecorder, || {
+            TxPool::new(ordering, PoolConfig { admission: Default::default(), ..config })
+        });
+        pool.set_block_info(block_info);
+
+        // transactions are ordered by sender and nonce, so they can be inserted in order
+        for (tx, SenderInfo { state_nonce, balance }) in transactions {
+            let _ = pool.add_transaction(Arc::unwrap_or_clone(tx), balance, state_nonce);
+        }
+
+        pool.set_block_info(info);
+        let evicted = pool.discard_worst();
+
+        PoolFeeSimulation {
+            pending: pool.best_transactions().collect(),
+            evicted,
+            size: pool.size(),
+        }
+    }
+}
+
 /// Stores relevant context about a sender.
 #[derive(Debug, Clone, Default)]
 pub(crate) struct SenderInfo {
@@ -1975,13 +2161,16 @@ impl SenderInfo {
 mod tests {
     use super::*;
     use crate::{
+        admission::{AdmissionError, OriginQuota, RateLimit},
         test_utils::{MockOrdering, MockTransaction, MockTransactionFactory, MockTransactionSet},
         traits::TransactionOrigin,
         SubPoolLimit,
     };
     use alloy_consensus::Transaction;
     use alloy_primitives::address;
+    use reth_network_peers::PeerId;
     use reth_primitives::TxType;
+    use std::time::Duration;
 
     #[test]
     fn test_insert_blob() {
@@ -2780,6 +2969,68 @@ mod tests {
         assert_eq!(pool.all_transactions.txs.get(&id).unwrap().subpool, SubPool::BaseFee)
     }
 
+    #[test]
+    fn simulate_block_info_does_not_modify_pool() {
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let tx = MockTransaction::eip1559().inc_price_by(10);
+        let blob = MockTransaction::eip4844().inc_price().inc_limit();
+        pool.add_transaction(f.validated(tx.clone()), U256::MAX, 0).unwrap();
+        pool.add_transaction(f.validated(blob.clone()), U256::MAX, 0).unwrap();
+        assert_eq!(pool.pending_pool.len(), 2);
+
+        // a higher blob fee only parks the blob transaction
+        let mut block_info = pool.block_info();
+        block_info.pending_blob_fee = Some(blob.max_fee_per_blob_gas().unwrap() + 1);
+        let simulation = pool.fee_simulation_snapshot().simulate(block_info);
+        assert_eq!(simulation.pending.len(), 1);
+        assert_eq!(simulation.pending[0].hash(), tx.get_hash());
+        assert!(simulation.evicted.is_empty());
+        assert_eq!(simulation.size.blob, 1);
+
+        // a higher base fee parks both transactions
+        let mut block_info = pool.block_info();
+        block_info.pending_basefee = (tx.max_fee_per_gas().max(blob.max_fee_per_gas()) + 1) as u64;
+        let simulation = pool.fee_simulation_snapshot().simulate(block_info);
+        assert!(simulation.pending.is_empty());
+        assert_eq!(simulation.size.basefee, 1);
+        assert_eq!(simulation.size.blob, 1);
+
+        // the live pool is unchanged
+        assert_eq!(pool.pending_pool.len(), 2);
+        assert!(pool.basefee_pool.is_empty());
+        assert!(pool.blob_pool.is_empty());
+        pool.assert_invariants();
+    }
+
+    #[test]
+    fn simulate_block_info_evictions() {
+        let mut f = MockTransactionFactory::default();
+        let config = PoolConfig {
+            basefee_limit: SubPoolLimit { max_txs: 1, max_size: usize::MAX },
+            ..Default::default()
+        };
+        let mut pool = TxPool::new(MockOrdering::default(), config);
+
+        let a = MockTransaction::eip1559().inc_price_by(10);
+        let b = MockTransaction::eip1559().inc_price_by(20);
+        pool.add_transaction(f.validated(a.clone()), U256::MAX, 0).unwrap();
+        pool.add_transaction(f.validated(b.clone()), U256::MAX, 0).unwrap();
+
+        // both transactions move to the basefee pool which only fits one of them
+        let mut block_info = pool.block_info();
+        block_info.pending_basefee = (a.max_fee_per_gas().max(b.max_fee_per_gas()) + 1) as u64;
+        let simulation = pool.fee_simulation_snapshot().simulate(block_info);
+        assert!(simulation.pending.is_empty());
+        assert_eq!(simulation.evicted.len(), 1);
+        assert_eq!(simulation.size.basefee, 1);
+        assert_eq!(simulation.size.total, 1);
+
+        assert_eq!(pool.pending_pool.len(), 2);
+        assert_eq!(pool.len(), 2);
+    }
+
     #[test]
     fn get_highest_transaction_by_sender_and_nonce() {
         // Set up a mock transaction factory and a new transaction pool.
@@ -3366,4 +3617,140 @@ mod tests {
 
         assert_eq!(pool.pending_pool.independent().len(), 1);
     }
+
+    #[test]
+    fn test_admission_origin_quota() {
+        let on_chain_balance = U256::from(10_000);
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let config = PoolConfig {
+            admission: AdmissionConfig {
+                external: OriginQuota { max_txs: Some(2), ..Default::default() },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut pool = TxPool::new(MockOrdering::default(), config);
+
+        let tx_0 = MockTransaction::eip1559().with_gas_limit(21_000);
+        let tx_1 = tx_0.next();
+        let tx_2 = tx_1.next();
+        let v0 = f.validated(tx_0);
+        let v1 = f.validated(tx_1);
+        let v2 = f.validated(tx_2);
+
+        pool.add_transaction(v0.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        pool.add_transaction(v1, on_chain_balance, on_chain_nonce).unwrap();
+        assert_eq!(
+            pool.all_transactions.origin_usage(TransactionOrigin::External),
+            OriginUsage { txs: 2, gas: 42_000 }
+        );
+
+        let err = pool.add_transaction(v2.clone(), on_chain_balance, on_chain_nonce).unwrap_err();
+        assert!(matches!(
+            err.kind,
+            PoolErrorKind::InvalidTransaction(InvalidPoolTransactionError::Admission(
+                AdmissionError::OriginTxsExceeded { max_txs: 2, .. }
+            ))
+        ));
+
+        // replacements don't count against the quota
+        let replacement = f.validated(v0.transaction.clone().rng_hash().inc_price());
+        pool.add_transaction(replacement, on_chain_balance, on_chain_nonce).unwrap();
+        assert_eq!(pool.all_transactions.origin_usage(TransactionOrigin::External).txs, 2);
+
+        pool.remove_transaction(v0.id());
+        assert_eq!(
+            pool.all_transactions.origin_usage(TransactionOrigin::External),
+            OriginUsage { txs: 1, gas: 21_000 }
+        );
+        pool.add_transaction(v2, on_chain_balance, on_chain_nonce).unwrap();
+    }
+
+    #[test]
+    fn test_admission_rate_limit_charged_on_insert() {
+        let on_chain_balance = U256::from(10_000);
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let config = PoolConfig {
+            admission: AdmissionConfig {
+                external: OriginQuota {
+                    rate_limit: Some(RateLimit { max_txs: 2, interval: Duration::from_secs(60) }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut pool = TxPool::new(MockOrdering::default(), config);
+        let source = Some(AdmissionSource::Peer(PeerId::with_last_byte(1)));
+
+        let tx_0 = MockTransaction::eip1559();
+        let tx_1 = tx_0.next();
+        let tx_2 = tx_1.next();
+        let v0 = f.validated(tx_0.clone());
+        pool.add_transaction_from(v0, on_chain_balance, on_chain_nonce, source).unwrap();
+
+        // a rejected transaction doesn't count against the rate limit
+        let underpriced = f.validated(tx_0.rng_hash().decr_price());
+        let err = pool
+            .add_transaction_from(underpriced, on_chain_balance, on_chain_nonce, source)
+            .unwrap_err();
+        assert!(matches!(err.kind, PoolErrorKind::ReplacementUnderpriced));
+
+        let v1 = f.validated(tx_1);
+        pool.add_transaction_from(v1, on_chain_balance, on_chain_nonce, source).unwrap();
+
+        let v2 = f.validated(tx_2);
+        let err = pool
+            .add_transaction_from(v2.clone(), on_chain_balance, on_chain_nonce, source)
+            .unwrap_err();
+        assert!(matches!(
+            err.kind,
+            PoolErrorKind::InvalidTransaction(InvalidPoolTransactionError::Admission(
+                AdmissionError::RateLimited { .. }
+            ))
+        ));
+
+        // reloading the rules keeps the rate limit, other peers are not affected
+        pool.set_admission_config(pool.config.admission.clone());
+        assert!(pool
+            .add_transaction_from(v2.clone(), on_chain_balance, on_chain_nonce, source)
+            .is_err());
+        let other = Some(AdmissionSource::Peer(PeerId::with_last_byte(2)));
+        pool.add_transaction_from(v2, on_chain_balance, on_chain_nonce, other).unwrap();
+    }
+
+    #[test]
+    fn test_admission_config_keeps_custom_policy() {
+        #[derive(Debug)]
+        struct DenyAll;
+
+        impl AdmissionPolicy<MockTransaction> for DenyAll {
+            fn check(
+                &self,
+                _transaction: &ValidPoolTransaction<MockTransaction>,
+                _ctx: &AdmissionContext,
+            ) -> Result<(), AdmissionError> {
+                Err(AdmissionError::ReservedCapacity)
+            }
+        }
+
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+        pool.set_admission_policy(Arc::new(DenyAll));
+
+        let config = AdmissionConfig { reserved_slots: 1, ..Default::default() };
+        pool.set_admission_config(config.clone());
+        assert_eq!(pool.config.admission, config);
+
+        let tx = f.validated(MockTransaction::eip1559());
+        let err = pool.add_transaction(tx, U256::from(10_000), 0).unwrap_err();
+        assert!(matches!(
+            err.kind,
+            PoolErrorKind::InvalidTransaction(InvalidPoolTransactionError::Admission(
+                AdmissionError::ReservedCapacity
+            ))
+        ));
+    }
 }
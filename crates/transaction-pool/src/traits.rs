@@ -1,7 +1,11 @@
 use crate::{
+    admission::AdmissionSource,
     blobstore::BlobStoreError,
     error::{InvalidPoolTransactionError, PoolResult},
-    pool::{state::SubPool, BestTransactionFilter, TransactionEvents},
+    pool::{
+        state::{ParkedReason, SubPool},
+        BestTransactionFilter, TransactionEvents, TransactionHistoryEntry,
+    },
     validate::ValidPoolTransaction,
     AllTransactionsEvents,
 };
@@ -110,6 +114,22 @@ pub trait TransactionPool: Send + Sync + Clone {
         transaction: Self::Transaction,
     ) -> impl Future<Output = PoolResult<TxHash>> + Send;
 
+    /// Adds an _unvalidated_ transaction that was received from the given source into the pool.
+    ///
+    /// The source is used by the admission rules of the pool, e.g. for rate limits. By default
+    /// the source is ignored.
+    ///
+    /// Consumer: RPC
+    fn add_transaction_from(
+        &self,
+        origin: TransactionOrigin,
+        source: AdmissionSource,
+        transaction: Self::Transaction,
+    ) -> impl Future<Output = PoolResult<TxHash>> + Send {
+        let _ = source;
+        self.add_transaction(origin, transaction)
+    }
+
     /// Adds the given _unvalidated_ transaction into the pool.
     ///
     /// Returns a list of results.
@@ -121,6 +141,23 @@ pub trait TransactionPool: Send + Sync + Clone {
         transactions: Vec<Self::Transaction>,
     ) -> impl Future<Output = Vec<PoolResult<TxHash>>> + Send;
 
+    /// Adds the given _unvalidated_ transactions that were received from the given source into
+    /// the pool.
+    ///
+    /// The source is used by the admission rules of the pool, e.g. for rate limits. By default
+    /// the source is ignored.
+    ///
+    /// Consumer: P2P
+    fn add_transactions_from(
+        &self,
+        origin: TransactionOrigin,
+        source: AdmissionSource,
+        transactions: Vec<Self::Transaction>,
+    ) -> impl Future<Output = Vec<PoolResult<TxHash>>> + Send {
+        let _ = source;
+        self.add_transactions(origin, transactions)
+    }
+
     /// Returns a new transaction change event stream for the given transaction.
     ///
     /// Returns `None` if the transaction is not in the pool.
@@ -273,6 +310,15 @@ pub trait TransactionPool: Send + Sync + Clone {
         best_transactions_attributes: BestTransactionsAttributes,
     ) -> Box<dyn BestTransactions<Item = Arc<ValidPoolTransaction<Self::Transaction>>>>;
 
+    /// Projects the pool onto the given [`BlockInfo`], e.g. a hypothetical base fee or blob fee.
+    ///
+    /// Returns the transactions that would be pending, in the order they would be yielded by
+    /// [`Self::best_transactions`], and the transactions that would be evicted because the
+    /// sub-pool limits are exceeded after transactions moved between sub-pools.
+    ///
+    /// This does not modify the pool.
+    fn simulate_block_info(&self, info: BlockInfo) -> PoolFeeSimulation<Self::Transaction>;
+
     /// Returns all transactions that can be included in the next block.
     ///
     /// This is primarily used for the `txpool_` RPC namespace:
@@ -367,6 +413,19 @@ pub trait TransactionPool: Send + Sync + Clone {
         sender: Address,
     ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>>;
 
+    /// Returns the [`PoolTransactionStatus`] of the transaction with the given hash.
+    ///
+    /// This includes the sub-pool the transaction is in, why it is parked if it is not pending
+    /// and its recent event history, e.g. replacements.
+    ///
+    /// The history of a transaction that left the pool, e.g. because it was mined, is still
+    /// returned while it is tracked. Returns `None` if the transaction is neither in the pool nor
+    /// tracked.
+    fn transaction_status(&self, tx_hash: TxHash) -> Option<PoolTransactionStatus>;
+
+    /// Returns the [`PoolTransactionStatus`] of all transactions sent by a given user.
+    fn transaction_statuses_by_sender(&self, sender: Address) -> Vec<PoolTransactionStatus>;
+
     /// Returns all pending transactions filtered by predicate
     fn get_pending_transactions_with_predicate(
         &self,
@@ -593,6 +652,39 @@ impl<T: PoolTransaction> Default for AllPoolTransactions<T> {
     }
 }
 
+/// The projected state of the pool for a [`BlockInfo`], see
+/// [`TransactionPool::simulate_block_info`].
+#[derive(Debug)]
+pub struct PoolFeeSimulation<T: PoolTransaction> {
+    /// Transactions that would be pending, in the order they would be included.
+    pub pending: Vec<Arc<ValidPoolTransaction<T>>>,
+    /// Transactions that would be evicted from the pool.
+    pub evicted: Vec<Arc<ValidPoolTransaction<T>>>,
+    /// The size of the pool after the evictions.
+    pub size: PoolSize,
+}
+
+impl<T: PoolTransaction> Default for PoolFeeSimulation<T> {
+    fn default() -> Self {
+        Self { pending: Vec::new(), evicted: Vec::new(), size: Default::default() }
+    }
+}
+
+/// Detailed status of a transaction in the pool.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PoolTransactionStatus {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// The sub-pool the transaction is currently in, `None` if it left the pool.
+    pub subpool: Option<SubPool>,
+    /// The reasons why the transaction is parked, empty if the transaction is pending.
+    pub parked_reasons: Vec<ParkedReason>,
+    /// Recently recorded events of the transaction, oldest first.
+    pub history: Vec<TransactionHistoryEntry>,
+}
+
 /// Represents a transaction that was propagated over the network.
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
 pub struct PropagatedTransactions(pub HashMap<TxHash, Vec<PropagateKind>>);
@@ -668,7 +760,7 @@ pub struct NewBlobSidecar {
 ///
 /// Depending on where the transaction was picked up, it affects how the transaction is handled
 /// internally, e.g. limits for simultaneous transaction of one sender.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
 pub enum TransactionOrigin {
     /// Transaction is coming from a local source.
     #[default]
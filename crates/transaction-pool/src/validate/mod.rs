@@ -125,6 +125,14 @@ impl<T: PoolTransaction> ValidTransaction<T> {
         }
     }
 
+    /// Returns a mutable reference to the transaction.
+    #[inline]
+    pub fn transaction_mut(&mut self) -> &mut T {
+        match self {
+            Self::Valid(transaction) | Self::ValidWithSidecar { transaction, .. } => transaction,
+        }
+    }
+
     /// Consumes the wrapper and returns the transaction.
     pub fn into_transaction(self) -> T {
         match self {
@@ -2,7 +2,7 @@ use assert_matches::assert_matches;
 use reth_transaction_pool::{
     noop::MockTransactionValidator,
     test_utils::{MockTransactionFactory, TestPoolBuilder},
-    FullTransactionEvent, TransactionEvent, TransactionListenerKind, TransactionOrigin,
+    FullTransactionEvent, SubPool, TransactionEvent, TransactionListenerKind, TransactionOrigin,
     TransactionPool,
 };
 use std::{future::poll_fn, task::Poll};
@@ -89,3 +89,27 @@ async fn txpool_listener_new_propagate_only() {
     })
     .await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_status_after_removal() {
+    let txpool = TestPoolBuilder::default();
+    let mut mock_tx_factory = MockTransactionFactory::default();
+    let transaction = mock_tx_factory.create_eip1559();
+    let hash = *transaction.hash();
+
+    txpool
+        .add_transaction(TransactionOrigin::External, transaction.transaction.clone())
+        .await
+        .unwrap();
+    let status = txpool.transaction_status(hash).unwrap();
+    assert_eq!(status.subpool, Some(SubPool::Pending));
+
+    // the history is still returned after the transaction left the pool
+    txpool.remove_transactions(vec![hash]);
+    let status = txpool.transaction_status(hash).unwrap();
+    assert_eq!(status.subpool, None);
+    let events = status.history.into_iter().map(|entry| entry.event).collect::<Vec<_>>();
+    assert_eq!(events, vec![TransactionEvent::Pending, TransactionEvent::Discarded]);
+
+    assert!(txpool.transaction_status(Default::default()).is_none());
+}
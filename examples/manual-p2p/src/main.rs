@@ -14,7 +14,8 @@ use reth_chainspec::{Chain, MAINNET};
 use reth_discv4::{DiscoveryUpdate, Discv4, Discv4ConfigBuilder, DEFAULT_DISCOVERY_ADDRESS};
 use reth_ecies::stream::ECIESStream;
 use reth_eth_wire::{
-    EthMessage, EthStream, HelloMessage, P2PStream, Status, UnauthedEthStream, UnauthedP2PStream,
+    EthMessage, EthStream, HelloMessage, P2PStream, Status, StatusMessage, UnauthedEthStream,
+    UnauthedP2PStream,
 };
 use reth_network::{config::rng_secret_key, EthNetworkPrimitives};
 use reth_network_peers::{mainnet_nodes, pk2id, NodeRecord};
@@ -68,7 +69,10 @@ async fn main() -> eyre::Result<()> {
 
                 println!(
                     "Successfully connected to a peer at {}:{} ({}) using eth-wire version eth/{}",
-                    peer.address, peer.tcp_port, their_hello.client_version, their_status.version
+                    peer.address,
+                    peer.tcp_port,
+                    their_hello.client_version,
+                    their_status.version()
                 );
 
                 snoop(peer, eth_stream).await;
@@ -94,7 +98,9 @@ async fn handshake_p2p(
 }
 
 // Perform a ETH Wire handshake with a peer
-async fn handshake_eth(p2p_stream: AuthedP2PStream) -> eyre::Result<(AuthedEthStream, Status)> {
+async fn handshake_eth(
+    p2p_stream: AuthedP2PStream,
+) -> eyre::Result<(AuthedEthStream, StatusMessage)> {
     let fork_filter = MAINNET.fork_filter(Head {
         timestamp: MAINNET.fork(EthereumHardfork::Shanghai).as_timestamp().unwrap(),
         ..Default::default()
@@ -109,7 +115,8 @@ async fn handshake_eth(p2p_stream: AuthedP2PStream) -> eyre::Result<(AuthedEthSt
     let status =
         Status { version: p2p_stream.shared_capabilities().eth()?.version().try_into()?, ..status };
     let eth_unauthed = UnauthedEthStream::new(p2p_stream);
-    Ok(eth_unauthed.handshake(status, fork_filter).await?)
+    // this node doesn't serve any blocks, so it advertises an empty range to eth/69 peers
+    Ok(eth_unauthed.handshake(StatusMessage::new(status, 0, 0), fork_filter).await?)
 }
 
 // Snoop by greedily capturing all broadcasts that the peer emits
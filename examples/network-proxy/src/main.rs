@@ -77,6 +77,7 @@ async fn main() -> eyre::Result<()> {
                         IncomingEthRequest::GetBlockBodies { .. } => {}
                         IncomingEthRequest::GetNodeData { .. } => {}
                         IncomingEthRequest::GetReceipts { .. } => {}
+                        IncomingEthRequest::GetReceipts69 { .. } => {}
                     }
              }
              transaction_message = transactions_rx.recv() => {